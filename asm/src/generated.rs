@@ -8,17 +8,17 @@ fn gen_add(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000214 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -28,17 +28,17 @@ fn gen_addc(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000014 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -48,17 +48,17 @@ fn gen_adde(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000114 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -68,17 +68,17 @@ fn gen_addi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x38000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = parse_signed(args, 2, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 2, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -88,17 +88,17 @@ fn gen_addic(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x30000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = parse_signed(args, 2, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 2, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -108,17 +108,17 @@ fn gen_addic_(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x34000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = parse_signed(args, 2, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 2, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -128,17 +128,17 @@ fn gen_addis(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x3c000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // uimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0xffff)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0xffff, ArgKind::Immediate)?;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -148,12 +148,12 @@ fn gen_addme(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0001d4 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -163,12 +163,12 @@ fn gen_addze(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000194 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -178,17 +178,17 @@ fn gen_and(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000038 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -198,17 +198,17 @@ fn gen_andc(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000078 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -218,17 +218,17 @@ fn gen_andi_(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x70000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // uimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0xffff)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0xffff, ArgKind::Immediate)?;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -238,17 +238,17 @@ fn gen_andis_(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x74000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // uimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0xffff)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0xffff, ArgKind::Immediate)?;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -258,7 +258,8 @@ fn gen_b(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x48000000 | modifiers;
     // LI
     {
-        let arg = parse_signed(args, 0, -0x2000000, 0x2000000)? as u32;
+        let arg = parse_signed(args, 0, -0x2000000, 0x2000000, ArgKind::Immediate)?
+            as u32;
         code |= arg & 0x3fffffc;
     }
     Ok(code)
@@ -268,17 +269,17 @@ fn gen_bc(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x40000000 | modifiers;
     // BO
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 21;
     }
     // BI
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // BD
     {
-        let arg = parse_signed(args, 2, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 2, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xfffc;
     }
     Ok(code)
@@ -288,12 +289,12 @@ fn gen_bcctr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000420 | modifiers;
     // BO
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 21;
     }
     // BI
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -303,12 +304,12 @@ fn gen_bclr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000020 | modifiers;
     // BO
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 21;
     }
     // BI
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -323,7 +324,7 @@ fn gen_bdnz(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x42000000 | modifiers;
     // BD
     {
-        let arg = parse_signed(args, 0, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 0, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xfffc;
     }
     Ok(code)
@@ -333,12 +334,12 @@ fn gen_bdnzf(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x40000000 | modifiers;
     // BI
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // BD
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xfffc;
     }
     Ok(code)
@@ -348,7 +349,7 @@ fn gen_bdnzflr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000020 | modifiers;
     // BI
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -363,12 +364,12 @@ fn gen_bdnzt(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x41000000 | modifiers;
     // BI
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // BD
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xfffc;
     }
     Ok(code)
@@ -378,7 +379,7 @@ fn gen_bdnztlr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4d000020 | modifiers;
     // BI
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -388,7 +389,7 @@ fn gen_bdz(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x42400000 | modifiers;
     // BD
     {
-        let arg = parse_signed(args, 0, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 0, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xfffc;
     }
     Ok(code)
@@ -398,12 +399,12 @@ fn gen_bdzf(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x40400000 | modifiers;
     // BI
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // BD
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xfffc;
     }
     Ok(code)
@@ -413,7 +414,7 @@ fn gen_bdzflr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000020 | modifiers;
     // BI
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -428,12 +429,12 @@ fn gen_bdzt(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x41400000 | modifiers;
     // BI
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // BD
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xfffc;
     }
     Ok(code)
@@ -443,7 +444,7 @@ fn gen_bdztlr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4d400020 | modifiers;
     // BI
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -454,7 +455,8 @@ fn gen_beq(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x41820000 | modifiers;
             // BD
             {
-                let arg = parse_signed(args, 0, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 0, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -463,12 +465,13 @@ fn gen_beq(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x41820000 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             // BD
             {
-                let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -491,7 +494,7 @@ fn gen_beqctr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4d820420 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -514,7 +517,7 @@ fn gen_beqlr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4d820020 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -533,7 +536,8 @@ fn gen_bge(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x40800000 | modifiers;
             // BD
             {
-                let arg = parse_signed(args, 0, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 0, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -542,12 +546,13 @@ fn gen_bge(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x40800000 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             // BD
             {
-                let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -570,7 +575,7 @@ fn gen_bgectr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4c800420 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -593,7 +598,7 @@ fn gen_bgelr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4c800020 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -612,7 +617,8 @@ fn gen_bgt(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x41810000 | modifiers;
             // BD
             {
-                let arg = parse_signed(args, 0, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 0, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -621,12 +627,13 @@ fn gen_bgt(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x41810000 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             // BD
             {
-                let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -649,7 +656,7 @@ fn gen_bgtctr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4d810420 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -672,7 +679,7 @@ fn gen_bgtlr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4d810020 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -691,7 +698,8 @@ fn gen_ble(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x40810000 | modifiers;
             // BD
             {
-                let arg = parse_signed(args, 0, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 0, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -700,12 +708,13 @@ fn gen_ble(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x40810000 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             // BD
             {
-                let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -728,7 +737,7 @@ fn gen_blectr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4c810420 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -751,7 +760,7 @@ fn gen_blelr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4c810020 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -775,7 +784,8 @@ fn gen_blt(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x41800000 | modifiers;
             // BD
             {
-                let arg = parse_signed(args, 0, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 0, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -784,12 +794,13 @@ fn gen_blt(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x41800000 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             // BD
             {
-                let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -812,7 +823,7 @@ fn gen_bltctr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4d800420 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -835,7 +846,7 @@ fn gen_bltlr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4d800020 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -854,7 +865,8 @@ fn gen_bne(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x40820000 | modifiers;
             // BD
             {
-                let arg = parse_signed(args, 0, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 0, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -863,12 +875,13 @@ fn gen_bne(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x40820000 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             // BD
             {
-                let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -891,7 +904,7 @@ fn gen_bnectr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4c820420 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -914,7 +927,7 @@ fn gen_bnelr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4c820020 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -933,7 +946,8 @@ fn gen_bns(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x40830000 | modifiers;
             // BD
             {
-                let arg = parse_signed(args, 0, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 0, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -942,12 +956,13 @@ fn gen_bns(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x40830000 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             // BD
             {
-                let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -970,7 +985,7 @@ fn gen_bnsctr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4c830420 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -993,7 +1008,7 @@ fn gen_bnslr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4c830020 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -1012,7 +1027,8 @@ fn gen_bso(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x41830000 | modifiers;
             // BD
             {
-                let arg = parse_signed(args, 0, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 0, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -1021,12 +1037,13 @@ fn gen_bso(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x41830000 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             // BD
             {
-                let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xfffc;
             }
             Ok(code)
@@ -1049,7 +1066,7 @@ fn gen_bsoctr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4d830420 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -1072,7 +1089,7 @@ fn gen_bsolr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x4d830020 | modifiers;
             // crfS
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 18;
             }
             Ok(code)
@@ -1090,28 +1107,28 @@ fn gen_clrlslwi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x54000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // MB
     {
-        let arg = (parse_unsigned(args, 2, 0x0, 0x1f)?
-            - parse_unsigned(args, 3, 0x0, 0x1f)?);
+        let arg = (parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?
+            - parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::Immediate)?);
         code |= (arg & 0x1f) << 6;
     }
     // SH
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 11;
     }
     // ME
     {
-        let arg = (31 - parse_unsigned(args, 3, 0x0, 0x1f)?);
+        let arg = (31 - parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::Immediate)?);
         code |= (arg & 0x1f) << 1;
     }
     Ok(code)
@@ -1121,17 +1138,17 @@ fn gen_clrlwi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x5400003e | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // MB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -1141,17 +1158,17 @@ fn gen_clrrwi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x54000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // ME
     {
-        let arg = (31 - parse_unsigned(args, 2, 0x0, 0x1f)?);
+        let arg = (31 - parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?);
         code |= (arg & 0x1f) << 1;
     }
     Ok(code)
@@ -1161,22 +1178,22 @@ fn gen_cmp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000000 | modifiers;
     // crfD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 23;
     }
     // L
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1, ArgKind::Immediate)?;
         code |= (arg & 0x1) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1187,12 +1204,12 @@ fn gen_cmpd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x7c200000 | modifiers;
             // rA
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // rB
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 11;
             }
             Ok(code)
@@ -1201,17 +1218,17 @@ fn gen_cmpd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x7c200000 | modifiers;
             // crfD
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 23;
             }
             // rA
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // rB
             {
-                let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 11;
             }
             Ok(code)
@@ -1230,12 +1247,13 @@ fn gen_cmpdi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x2c200000 | modifiers;
             // rA
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // simm
             {
-                let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xffff;
             }
             Ok(code)
@@ -1244,17 +1262,18 @@ fn gen_cmpdi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x2c200000 | modifiers;
             // crfD
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 23;
             }
             // rA
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // simm
             {
-                let arg = parse_signed(args, 2, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 2, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xffff;
             }
             Ok(code)
@@ -1272,22 +1291,22 @@ fn gen_cmpi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x2c000000 | modifiers;
     // crfD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 23;
     }
     // L
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1, ArgKind::Immediate)?;
         code |= (arg & 0x1) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = parse_signed(args, 3, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 3, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -1297,22 +1316,22 @@ fn gen_cmpl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000040 | modifiers;
     // crfD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 23;
     }
     // L
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1, ArgKind::Immediate)?;
         code |= (arg & 0x1) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1323,12 +1342,12 @@ fn gen_cmpld(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x7c200040 | modifiers;
             // rA
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // rB
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 11;
             }
             Ok(code)
@@ -1337,17 +1356,17 @@ fn gen_cmpld(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x7c200040 | modifiers;
             // crfD
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 23;
             }
             // rA
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // rB
             {
-                let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 11;
             }
             Ok(code)
@@ -1366,12 +1385,12 @@ fn gen_cmpldi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x28200000 | modifiers;
             // rA
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // uimm
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0xffff)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0xffff, ArgKind::Immediate)?;
                 code |= arg & 0xffff;
             }
             Ok(code)
@@ -1380,17 +1399,17 @@ fn gen_cmpldi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x28200000 | modifiers;
             // crfD
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 23;
             }
             // rA
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // uimm
             {
-                let arg = parse_unsigned(args, 2, 0x0, 0xffff)?;
+                let arg = parse_unsigned(args, 2, 0x0, 0xffff, ArgKind::Immediate)?;
                 code |= arg & 0xffff;
             }
             Ok(code)
@@ -1408,22 +1427,22 @@ fn gen_cmpli(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x28000000 | modifiers;
     // crfD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 23;
     }
     // L
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1, ArgKind::Immediate)?;
         code |= (arg & 0x1) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // uimm
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0xffff)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0xffff, ArgKind::Immediate)?;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -1434,12 +1453,12 @@ fn gen_cmplw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x7c000040 | modifiers;
             // rA
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // rB
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 11;
             }
             Ok(code)
@@ -1448,17 +1467,17 @@ fn gen_cmplw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x7c000040 | modifiers;
             // crfD
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 23;
             }
             // rA
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // rB
             {
-                let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 11;
             }
             Ok(code)
@@ -1477,12 +1496,12 @@ fn gen_cmplwi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x28000000 | modifiers;
             // rA
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // uimm
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0xffff)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0xffff, ArgKind::Immediate)?;
                 code |= arg & 0xffff;
             }
             Ok(code)
@@ -1491,17 +1510,17 @@ fn gen_cmplwi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x28000000 | modifiers;
             // crfD
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 23;
             }
             // rA
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // uimm
             {
-                let arg = parse_unsigned(args, 2, 0x0, 0xffff)?;
+                let arg = parse_unsigned(args, 2, 0x0, 0xffff, ArgKind::Immediate)?;
                 code |= arg & 0xffff;
             }
             Ok(code)
@@ -1520,12 +1539,12 @@ fn gen_cmpw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x7c000000 | modifiers;
             // rA
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // rB
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 11;
             }
             Ok(code)
@@ -1534,17 +1553,17 @@ fn gen_cmpw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x7c000000 | modifiers;
             // crfD
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 23;
             }
             // rA
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // rB
             {
-                let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 11;
             }
             Ok(code)
@@ -1563,12 +1582,13 @@ fn gen_cmpwi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x2c000000 | modifiers;
             // rA
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // simm
             {
-                let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xffff;
             }
             Ok(code)
@@ -1577,17 +1597,18 @@ fn gen_cmpwi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x2c000000 | modifiers;
             // crfD
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
                 code |= (arg & 0x7) << 23;
             }
             // rA
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
                 code |= (arg & 0x1f) << 16;
             }
             // simm
             {
-                let arg = parse_signed(args, 2, -0x8000, 0x8000)? as u32;
+                let arg = parse_signed(args, 2, -0x8000, 0x8000, ArgKind::Immediate)?
+                    as u32;
                 code |= arg & 0xffff;
             }
             Ok(code)
@@ -1605,12 +1626,12 @@ fn gen_cntlzd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000074 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -1620,12 +1641,12 @@ fn gen_cntlzw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000034 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -1635,17 +1656,17 @@ fn gen_crand(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000202 | modifiers;
     // crbD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 21;
     }
     // crbA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // crbB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1655,17 +1676,17 @@ fn gen_crandc(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000102 | modifiers;
     // crbD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 21;
     }
     // crbA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // crbB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1675,17 +1696,17 @@ fn gen_crclr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000182 | modifiers;
     // crbD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 21;
     }
     // crbA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // crbB
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1695,17 +1716,17 @@ fn gen_creqv(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000242 | modifiers;
     // crbD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 21;
     }
     // crbA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // crbB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1715,17 +1736,17 @@ fn gen_crmove(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000382 | modifiers;
     // crbD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 21;
     }
     // crbA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // crbB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1735,17 +1756,17 @@ fn gen_crnand(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c0001c2 | modifiers;
     // crbD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 21;
     }
     // crbA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // crbB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1755,17 +1776,17 @@ fn gen_crnor(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000042 | modifiers;
     // crbD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 21;
     }
     // crbA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // crbB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1775,17 +1796,17 @@ fn gen_crnot(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000042 | modifiers;
     // crbD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 21;
     }
     // crbA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // crbB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1795,17 +1816,17 @@ fn gen_cror(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000382 | modifiers;
     // crbD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 21;
     }
     // crbA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // crbB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1815,17 +1836,17 @@ fn gen_crorc(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000342 | modifiers;
     // crbD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 21;
     }
     // crbA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // crbB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1835,17 +1856,17 @@ fn gen_crset(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000242 | modifiers;
     // crbD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 21;
     }
     // crbA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // crbB
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1855,17 +1876,17 @@ fn gen_crxor(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000182 | modifiers;
     // crbD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 21;
     }
     // crbA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 16;
     }
     // crbB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1875,12 +1896,12 @@ fn gen_dcbf(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0000ac | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1890,12 +1911,12 @@ fn gen_dcbi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0003ac | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1905,12 +1926,12 @@ fn gen_dcbst(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00006c | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1920,12 +1941,12 @@ fn gen_dcbt(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00022c | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1935,12 +1956,12 @@ fn gen_dcbtst(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0001ec | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1950,12 +1971,12 @@ fn gen_dcbz(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0007ec | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1965,12 +1986,12 @@ fn gen_dcbz_l(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100007ec | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1980,12 +2001,12 @@ fn gen_dcbzl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c2007ec | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -1995,17 +2016,17 @@ fn gen_divd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0003d2 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2015,17 +2036,17 @@ fn gen_divdu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000392 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2035,17 +2056,17 @@ fn gen_divw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0003d6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2055,17 +2076,17 @@ fn gen_divwu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000396 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2076,12 +2097,12 @@ fn gen_dss(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x7c00066c | modifiers;
             // STRM
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x3)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x3, ArgKind::Immediate)?;
                 code |= (arg & 0x3) << 21;
             }
             // ds_A
             {
-                let arg = parse_unsigned(args, 1, 0x0, 0x1)?;
+                let arg = parse_unsigned(args, 1, 0x0, 0x1, ArgKind::Immediate)?;
                 code |= (arg & 0x1) << 25;
             }
             Ok(code)
@@ -2090,7 +2111,7 @@ fn gen_dss(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x7c00066c | modifiers;
             // STRM
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x3)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x3, ArgKind::Immediate)?;
                 code |= (arg & 0x3) << 21;
             }
             Ok(code)
@@ -2113,17 +2134,17 @@ fn gen_dst(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0002ac | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     // STRM
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x3, ArgKind::Immediate)?;
         code |= (arg & 0x3) << 21;
     }
     Ok(code)
@@ -2133,17 +2154,17 @@ fn gen_dstst(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0002ec | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     // STRM
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x3, ArgKind::Immediate)?;
         code |= (arg & 0x3) << 21;
     }
     Ok(code)
@@ -2153,17 +2174,17 @@ fn gen_eciwx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00026c | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2173,17 +2194,17 @@ fn gen_ecowx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00036c | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2198,17 +2219,17 @@ fn gen_eqv(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000238 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2218,22 +2239,22 @@ fn gen_extlwi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x54000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // ME
     {
-        let arg = (parse_unsigned(args, 2, 0x0, 0x1f)? - 1);
+        let arg = (parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)? - 1);
         code |= (arg & 0x1f) << 1;
     }
     // SH
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2243,23 +2264,23 @@ fn gen_extrwi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x5400003e | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // MB
     {
-        let arg = (32 - parse_unsigned(args, 2, 0x0, 0x1f)?);
+        let arg = (32 - parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?);
         code |= (arg & 0x1f) << 6;
     }
     // SH
     {
-        let arg = (parse_unsigned(args, 3, 0x0, 0x1f)?
-            + parse_unsigned(args, 2, 0x0, 0x1f)?);
+        let arg = (parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::Immediate)?
+            + parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?);
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2269,12 +2290,12 @@ fn gen_extsb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000774 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -2284,12 +2305,12 @@ fn gen_extsh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000734 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -2299,12 +2320,12 @@ fn gen_extsw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0007b4 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -2314,12 +2335,12 @@ fn gen_fabs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc000210 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2329,17 +2350,17 @@ fn gen_fadd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00002a | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2349,17 +2370,17 @@ fn gen_fadds(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xec00002a | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2369,12 +2390,12 @@ fn gen_fcfid(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00069c | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2384,17 +2405,17 @@ fn gen_fcmpo(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc000040 | modifiers;
     // crfD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 23;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2404,17 +2425,17 @@ fn gen_fcmpu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc000000 | modifiers;
     // crfD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 23;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2424,12 +2445,12 @@ fn gen_fctid(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00065c | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2439,12 +2460,12 @@ fn gen_fctidz(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00065e | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2454,12 +2475,12 @@ fn gen_fctiw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00001c | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2469,12 +2490,12 @@ fn gen_fctiwz(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00001e | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2484,17 +2505,17 @@ fn gen_fdiv(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc000024 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2504,17 +2525,17 @@ fn gen_fdivs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xec000024 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2524,22 +2545,22 @@ fn gen_fmadd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00003a | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2549,22 +2570,22 @@ fn gen_fmadds(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xec00003a | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2574,12 +2595,12 @@ fn gen_fmr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc000090 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2589,22 +2610,22 @@ fn gen_fmsub(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc000038 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2614,22 +2635,22 @@ fn gen_fmsubs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xec000038 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2639,17 +2660,17 @@ fn gen_fmul(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc000032 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -2659,17 +2680,17 @@ fn gen_fmuls(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xec000032 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -2679,12 +2700,12 @@ fn gen_fnabs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc000110 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2694,12 +2715,12 @@ fn gen_fneg(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc000050 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2709,22 +2730,22 @@ fn gen_fnmadd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00003e | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2734,22 +2755,22 @@ fn gen_fnmadds(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xec00003e | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2759,22 +2780,22 @@ fn gen_fnmsub(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00003c | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2784,22 +2805,22 @@ fn gen_fnmsubs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xec00003c | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2809,12 +2830,12 @@ fn gen_fres(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xec000030 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2824,12 +2845,12 @@ fn gen_frsp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc000018 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2839,12 +2860,12 @@ fn gen_frsqrte(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc000034 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2854,22 +2875,22 @@ fn gen_fsel(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00002e | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2879,12 +2900,12 @@ fn gen_fsqrt(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00002c | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2894,12 +2915,12 @@ fn gen_fsqrts(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xec00002c | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2909,17 +2930,17 @@ fn gen_fsub(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc000028 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2929,17 +2950,17 @@ fn gen_fsubs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xec000028 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2949,12 +2970,12 @@ fn gen_icbi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0007ac | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -2969,17 +2990,17 @@ fn gen_lbz(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x88000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -2989,17 +3010,17 @@ fn gen_lbzu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x8c000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -3009,17 +3030,17 @@ fn gen_lbzux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0000ee | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3029,17 +3050,17 @@ fn gen_lbzx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0000ae | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3049,17 +3070,17 @@ fn gen_ld(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xe8000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // ds
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xfffc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -3069,17 +3090,17 @@ fn gen_ldarx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0000a8 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3089,17 +3110,17 @@ fn gen_ldu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xe8000001 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // ds
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xfffc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -3109,17 +3130,17 @@ fn gen_ldux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00006a | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3129,17 +3150,17 @@ fn gen_ldx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00002a | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3149,17 +3170,17 @@ fn gen_lfd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xc8000000 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -3169,17 +3190,17 @@ fn gen_lfdu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xcc000000 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -3189,17 +3210,17 @@ fn gen_lfdux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0004ee | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3209,17 +3230,17 @@ fn gen_lfdx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0004ae | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3229,17 +3250,17 @@ fn gen_lfs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xc0000000 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -3249,17 +3270,17 @@ fn gen_lfsu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xc4000000 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -3269,17 +3290,17 @@ fn gen_lfsux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00046e | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3289,17 +3310,17 @@ fn gen_lfsx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00042e | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3309,17 +3330,17 @@ fn gen_lha(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xa8000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -3329,17 +3350,17 @@ fn gen_lhau(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xac000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -3349,17 +3370,17 @@ fn gen_lhaux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0002ee | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3369,17 +3390,17 @@ fn gen_lhax(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0002ae | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3389,17 +3410,17 @@ fn gen_lhbrx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00062c | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3409,17 +3430,17 @@ fn gen_lhz(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xa0000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -3429,17 +3450,17 @@ fn gen_lhzu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xa4000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -3449,17 +3470,17 @@ fn gen_lhzux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00026e | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3469,17 +3490,17 @@ fn gen_lhzx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00022e | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3489,12 +3510,12 @@ fn gen_li(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x38000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // simm
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -3504,12 +3525,12 @@ fn gen_lis(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x3c000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // uimm
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0xffff)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0xffff, ArgKind::Immediate)?;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -3519,17 +3540,17 @@ fn gen_lmw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xb8000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -3539,17 +3560,17 @@ fn gen_lswi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0004aa | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // NB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3559,17 +3580,17 @@ fn gen_lswx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00042a | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3579,17 +3600,17 @@ fn gen_lvebx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00000e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3599,17 +3620,17 @@ fn gen_lvehx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00004e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3619,17 +3640,17 @@ fn gen_lvewx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00008e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3639,18 +3660,18 @@ fn gen_lvewx128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000083 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3660,17 +3681,17 @@ fn gen_lvlx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00040e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3680,18 +3701,18 @@ fn gen_lvlx128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000403 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3701,17 +3722,17 @@ fn gen_lvlxl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00060e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3721,18 +3742,18 @@ fn gen_lvlxl128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000603 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3742,17 +3763,17 @@ fn gen_lvrx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00044e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3762,18 +3783,18 @@ fn gen_lvrx128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000443 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3783,17 +3804,17 @@ fn gen_lvrxl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00064e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3803,18 +3824,18 @@ fn gen_lvrxl128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000643 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3824,17 +3845,17 @@ fn gen_lvsl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00000c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3844,18 +3865,18 @@ fn gen_lvsl128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000003 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3865,17 +3886,17 @@ fn gen_lvsr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00004c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3885,18 +3906,18 @@ fn gen_lvsr128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000043 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3906,17 +3927,17 @@ fn gen_lvx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0000ce | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3926,18 +3947,18 @@ fn gen_lvx128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100000c3 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3947,17 +3968,17 @@ fn gen_lvxl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0002ce | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3967,18 +3988,18 @@ fn gen_lvxl128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100002c3 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -3988,17 +4009,17 @@ fn gen_lwa(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xe8000002 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // ds
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xfffc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -4008,17 +4029,17 @@ fn gen_lwarx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000028 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4028,17 +4049,17 @@ fn gen_lwaux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0002ea | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4048,17 +4069,17 @@ fn gen_lwax(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0002aa | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4068,17 +4089,17 @@ fn gen_lwbrx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00042c | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4093,17 +4114,17 @@ fn gen_lwz(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x80000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -4113,17 +4134,17 @@ fn gen_lwzu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x84000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -4133,17 +4154,17 @@ fn gen_lwzux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00006e | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4153,17 +4174,17 @@ fn gen_lwzx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00002e | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4173,12 +4194,12 @@ fn gen_mcrf(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x4c000000 | modifiers;
     // crfD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 23;
     }
     // crfS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 18;
     }
     Ok(code)
@@ -4188,12 +4209,12 @@ fn gen_mcrfs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc000080 | modifiers;
     // crfD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 23;
     }
     // crfS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 18;
     }
     Ok(code)
@@ -4203,7 +4224,7 @@ fn gen_mcrxr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000400 | modifiers;
     // crfD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 23;
     }
     Ok(code)
@@ -4213,7 +4234,7 @@ fn gen_mfcr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000026 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4223,7 +4244,7 @@ fn gen_mfctr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0902a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4233,7 +4254,7 @@ fn gen_mfdar(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1302a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4243,12 +4264,12 @@ fn gen_mfdbatl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1982a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // spr_BAT
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x3, ArgKind::Immediate)?;
         code |= (arg & 0x3) << 17;
     }
     Ok(code)
@@ -4258,12 +4279,12 @@ fn gen_mfdbatu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1882a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // spr_BAT
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x3, ArgKind::Immediate)?;
         code |= (arg & 0x3) << 17;
     }
     Ok(code)
@@ -4273,7 +4294,7 @@ fn gen_mfdec(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1602a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4283,7 +4304,7 @@ fn gen_mfdsisr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1202a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4293,7 +4314,7 @@ fn gen_mfear(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1a42a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4303,7 +4324,7 @@ fn gen_mffs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00048e | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4313,12 +4334,12 @@ fn gen_mfibatl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1182a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // spr_BAT
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x3, ArgKind::Immediate)?;
         code |= (arg & 0x3) << 17;
     }
     Ok(code)
@@ -4328,12 +4349,12 @@ fn gen_mfibatu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1082a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // spr_BAT
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x3, ArgKind::Immediate)?;
         code |= (arg & 0x3) << 17;
     }
     Ok(code)
@@ -4343,7 +4364,7 @@ fn gen_mflr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0802a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4353,7 +4374,7 @@ fn gen_mfmsr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0000a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4363,12 +4384,12 @@ fn gen_mfocrf(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c100026 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // crm
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0xff)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0xff, ArgKind::Immediate)?;
         code |= (arg & 0xff) << 12;
     }
     Ok(code)
@@ -4378,7 +4399,7 @@ fn gen_mfsdr1(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1902a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4388,12 +4409,12 @@ fn gen_mfspr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0002a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // spr
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x3ff)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x3ff, ArgKind::SPR)?;
         code |= (arg & 0x1f) << 16;
         code |= (arg & 0x3e0) << 6;
     }
@@ -4404,12 +4425,12 @@ fn gen_mfsprg(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1042a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // spr_SPRG
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x3, ArgKind::Immediate)?;
         code |= (arg & 0x3) << 16;
     }
     Ok(code)
@@ -4419,12 +4440,12 @@ fn gen_mfsr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0004a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // sr
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0xf)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0xf, ArgKind::SR)?;
         code |= (arg & 0xf) << 16;
     }
     Ok(code)
@@ -4434,12 +4455,12 @@ fn gen_mfsrin(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000526 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4449,7 +4470,7 @@ fn gen_mfsrr0(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1a02a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4459,7 +4480,7 @@ fn gen_mfsrr1(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1b02a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4469,12 +4490,12 @@ fn gen_mftb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0002e6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // tbr
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x3ff)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x3ff, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 16;
         code |= (arg & 0x3e0) << 6;
     }
@@ -4485,7 +4506,7 @@ fn gen_mfvscr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000604 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4495,7 +4516,7 @@ fn gen_mfxer(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0102a6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4505,17 +4526,17 @@ fn gen_mr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000378 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4525,12 +4546,12 @@ fn gen_mtcrf(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000120 | modifiers;
     // crm
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0xff)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0xff, ArgKind::Immediate)?;
         code |= (arg & 0xff) << 12;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4540,7 +4561,7 @@ fn gen_mtctr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0903a6 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4550,7 +4571,7 @@ fn gen_mtdar(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1303a6 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4560,12 +4581,12 @@ fn gen_mtdbatl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1983a6 | modifiers;
     // spr_BAT
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x3, ArgKind::Immediate)?;
         code |= (arg & 0x3) << 17;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4575,12 +4596,12 @@ fn gen_mtdbatu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1883a6 | modifiers;
     // spr_BAT
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x3, ArgKind::Immediate)?;
         code |= (arg & 0x3) << 17;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4590,7 +4611,7 @@ fn gen_mtdec(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1603a6 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4600,7 +4621,7 @@ fn gen_mtdsisr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1203a6 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4610,7 +4631,7 @@ fn gen_mtear(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1a43a6 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4620,7 +4641,7 @@ fn gen_mtfsb0(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00008c | modifiers;
     // crbD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4630,7 +4651,7 @@ fn gen_mtfsb1(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00004c | modifiers;
     // crbD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::CRBit)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4640,12 +4661,12 @@ fn gen_mtfsf(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00058e | modifiers;
     // mtfsf_FM
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0xff)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0xff, ArgKind::Immediate)?;
         code |= (arg & 0xff) << 17;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4655,12 +4676,12 @@ fn gen_mtfsfi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfc00010c | modifiers;
     // crfD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 23;
     }
     // mtfsf_IMM
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0xf)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0xf, ArgKind::Immediate)?;
         code |= (arg & 0xf) << 12;
     }
     Ok(code)
@@ -4670,12 +4691,12 @@ fn gen_mtibatl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1183a6 | modifiers;
     // spr_BAT
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x3, ArgKind::Immediate)?;
         code |= (arg & 0x3) << 17;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4685,12 +4706,12 @@ fn gen_mtibatu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1083a6 | modifiers;
     // spr_BAT
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x3, ArgKind::Immediate)?;
         code |= (arg & 0x3) << 17;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4700,7 +4721,7 @@ fn gen_mtlr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0803a6 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4710,7 +4731,7 @@ fn gen_mtmsr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000124 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4720,12 +4741,12 @@ fn gen_mtmsrd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000164 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // mtmsrd_L
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1, ArgKind::Immediate)?;
         code |= (arg & 0x1) << 16;
     }
     Ok(code)
@@ -4735,7 +4756,7 @@ fn gen_mtsdr1(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1903a6 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4745,13 +4766,13 @@ fn gen_mtspr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0003a6 | modifiers;
     // spr
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x3ff)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x3ff, ArgKind::SPR)?;
         code |= (arg & 0x1f) << 16;
         code |= (arg & 0x3e0) << 6;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4761,12 +4782,12 @@ fn gen_mtsprg(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1043a6 | modifiers;
     // spr_SPRG
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x3, ArgKind::Immediate)?;
         code |= (arg & 0x3) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4776,12 +4797,12 @@ fn gen_mtsr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0001a4 | modifiers;
     // sr
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0xf)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0xf, ArgKind::SR)?;
         code |= (arg & 0xf) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4791,12 +4812,12 @@ fn gen_mtsrd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0000a4 | modifiers;
     // sr
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0xf)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0xf, ArgKind::SR)?;
         code |= (arg & 0xf) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4806,12 +4827,12 @@ fn gen_mtsrdin(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0000e4 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4821,12 +4842,12 @@ fn gen_mtsrin(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0001e4 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4836,7 +4857,7 @@ fn gen_mtsrr0(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1a03a6 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4846,7 +4867,7 @@ fn gen_mtsrr1(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1b03a6 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4856,7 +4877,7 @@ fn gen_mttbl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1c43a6 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4866,7 +4887,7 @@ fn gen_mttbu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c1d43a6 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4876,7 +4897,7 @@ fn gen_mtvscr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000644 | modifiers;
     // vB
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4886,7 +4907,7 @@ fn gen_mtxer(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0103a6 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     Ok(code)
@@ -4896,17 +4917,17 @@ fn gen_mulhd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000092 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4916,17 +4937,17 @@ fn gen_mulhdu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000012 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4936,17 +4957,17 @@ fn gen_mulhw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000096 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4956,17 +4977,17 @@ fn gen_mulhwu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000016 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4976,17 +4997,17 @@ fn gen_mulld(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0001d2 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -4996,17 +5017,17 @@ fn gen_mulli(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1c000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = parse_signed(args, 2, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 2, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -5016,17 +5037,17 @@ fn gen_mullw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0001d6 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5036,17 +5057,17 @@ fn gen_nand(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0003b8 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5056,12 +5077,12 @@ fn gen_neg(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0000d0 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -5076,17 +5097,17 @@ fn gen_nor(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0000f8 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5096,17 +5117,17 @@ fn gen_or(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000378 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5116,17 +5137,17 @@ fn gen_orc(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000338 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5136,17 +5157,17 @@ fn gen_ori(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x60000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // uimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0xffff)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0xffff, ArgKind::Immediate)?;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -5156,17 +5177,17 @@ fn gen_oris(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x64000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // uimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0xffff)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0xffff, ArgKind::Immediate)?;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -5176,12 +5197,12 @@ fn gen_ps_abs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000210 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5191,17 +5212,17 @@ fn gen_ps_add(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000002a | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5211,17 +5232,17 @@ fn gen_ps_cmpo0(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000040 | modifiers;
     // crfD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 23;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5231,17 +5252,17 @@ fn gen_ps_cmpo1(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x100000c0 | modifiers;
     // crfD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 23;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5251,17 +5272,17 @@ fn gen_ps_cmpu0(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000000 | modifiers;
     // crfD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 23;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5271,17 +5292,17 @@ fn gen_ps_cmpu1(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000080 | modifiers;
     // crfD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7, ArgKind::CRField)?;
         code |= (arg & 0x7) << 23;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5291,17 +5312,17 @@ fn gen_ps_div(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000024 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5311,22 +5332,22 @@ fn gen_ps_madd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000003a | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5336,22 +5357,22 @@ fn gen_ps_madds0(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x1000001c | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5361,22 +5382,22 @@ fn gen_ps_madds1(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x1000001e | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5386,17 +5407,17 @@ fn gen_ps_merge00(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x10000420 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5406,17 +5427,17 @@ fn gen_ps_merge01(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x10000460 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5426,17 +5447,17 @@ fn gen_ps_merge10(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x100004a0 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5446,17 +5467,17 @@ fn gen_ps_merge11(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x100004e0 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5466,12 +5487,12 @@ fn gen_ps_mr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000090 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5481,22 +5502,22 @@ fn gen_ps_msub(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000038 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5506,17 +5527,17 @@ fn gen_ps_mul(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000032 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -5526,17 +5547,17 @@ fn gen_ps_muls0(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000018 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -5546,17 +5567,17 @@ fn gen_ps_muls1(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x1000001a | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -5566,12 +5587,12 @@ fn gen_ps_nabs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000110 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5581,12 +5602,12 @@ fn gen_ps_neg(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000050 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5596,22 +5617,22 @@ fn gen_ps_nmadd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x1000003e | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5621,22 +5642,22 @@ fn gen_ps_nmsub(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x1000003c | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5646,12 +5667,12 @@ fn gen_ps_res(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000030 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5661,12 +5682,12 @@ fn gen_ps_rsqrte(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000034 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5676,22 +5697,22 @@ fn gen_ps_sel(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000002e | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5701,17 +5722,17 @@ fn gen_ps_sub(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000028 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5721,22 +5742,22 @@ fn gen_ps_sum0(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000014 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5746,22 +5767,22 @@ fn gen_ps_sum1(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000016 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // frA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // frC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 6;
     }
     // frB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -5771,27 +5792,27 @@ fn gen_psq_l(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xe0000000 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // ps_offset
     {
-        let arg = parse_signed(args, 1, -0x800, 0x800)? as u32;
+        let arg = parse_signed(args, 1, -0x800, 0x800, ArgKind::Immediate)? as u32;
         code |= arg & 0xfff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // ps_W
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1, ArgKind::Immediate)?;
         code |= (arg & 0x1) << 15;
     }
     // ps_I
     {
-        let arg = parse_unsigned(args, 4, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 4, 0x0, 0x7, ArgKind::GQR)?;
         code |= (arg & 0x7) << 12;
     }
     Ok(code)
@@ -5801,27 +5822,27 @@ fn gen_psq_lu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xe4000000 | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // ps_offset
     {
-        let arg = parse_signed(args, 1, -0x800, 0x800)? as u32;
+        let arg = parse_signed(args, 1, -0x800, 0x800, ArgKind::Immediate)? as u32;
         code |= arg & 0xfff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // ps_W
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1, ArgKind::Immediate)?;
         code |= (arg & 0x1) << 15;
     }
     // ps_I
     {
-        let arg = parse_unsigned(args, 4, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 4, 0x0, 0x7, ArgKind::GQR)?;
         code |= (arg & 0x7) << 12;
     }
     Ok(code)
@@ -5831,27 +5852,27 @@ fn gen_psq_lux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000004c | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     // ps_WX
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1, ArgKind::Immediate)?;
         code |= (arg & 0x1) << 10;
     }
     // ps_IX
     {
-        let arg = parse_unsigned(args, 4, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 4, 0x0, 0x7, ArgKind::GQR)?;
         code |= (arg & 0x7) << 7;
     }
     Ok(code)
@@ -5861,27 +5882,27 @@ fn gen_psq_lx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000000c | modifiers;
     // frD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     // ps_WX
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1, ArgKind::Immediate)?;
         code |= (arg & 0x1) << 10;
     }
     // ps_IX
     {
-        let arg = parse_unsigned(args, 4, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 4, 0x0, 0x7, ArgKind::GQR)?;
         code |= (arg & 0x7) << 7;
     }
     Ok(code)
@@ -5891,27 +5912,27 @@ fn gen_psq_st(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xf0000000 | modifiers;
     // frS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // ps_offset
     {
-        let arg = parse_signed(args, 1, -0x800, 0x800)? as u32;
+        let arg = parse_signed(args, 1, -0x800, 0x800, ArgKind::Immediate)? as u32;
         code |= arg & 0xfff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // ps_W
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1, ArgKind::Immediate)?;
         code |= (arg & 0x1) << 15;
     }
     // ps_I
     {
-        let arg = parse_unsigned(args, 4, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 4, 0x0, 0x7, ArgKind::GQR)?;
         code |= (arg & 0x7) << 12;
     }
     Ok(code)
@@ -5921,27 +5942,27 @@ fn gen_psq_stu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xf4000000 | modifiers;
     // frS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // ps_offset
     {
-        let arg = parse_signed(args, 1, -0x800, 0x800)? as u32;
+        let arg = parse_signed(args, 1, -0x800, 0x800, ArgKind::Immediate)? as u32;
         code |= arg & 0xfff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // ps_W
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1, ArgKind::Immediate)?;
         code |= (arg & 0x1) << 15;
     }
     // ps_I
     {
-        let arg = parse_unsigned(args, 4, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 4, 0x0, 0x7, ArgKind::GQR)?;
         code |= (arg & 0x7) << 12;
     }
     Ok(code)
@@ -5951,27 +5972,27 @@ fn gen_psq_stux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x1000004e | modifiers;
     // frS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     // ps_WX
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1, ArgKind::Immediate)?;
         code |= (arg & 0x1) << 10;
     }
     // ps_IX
     {
-        let arg = parse_unsigned(args, 4, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 4, 0x0, 0x7, ArgKind::GQR)?;
         code |= (arg & 0x7) << 7;
     }
     Ok(code)
@@ -5981,27 +6002,27 @@ fn gen_psq_stx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000000e | modifiers;
     // frS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     // ps_WX
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1, ArgKind::Immediate)?;
         code |= (arg & 0x1) << 10;
     }
     // ps_IX
     {
-        let arg = parse_unsigned(args, 4, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 4, 0x0, 0x7, ArgKind::GQR)?;
         code |= (arg & 0x7) << 7;
     }
     Ok(code)
@@ -6026,22 +6047,22 @@ fn gen_rldcl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x78000010 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     // MB64
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x3f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x3f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 6;
         code |= arg & 0x20;
     }
@@ -6052,22 +6073,22 @@ fn gen_rldcr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x78000012 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     // ME64
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x3f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x3f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 6;
         code |= arg & 0x20;
     }
@@ -6078,23 +6099,23 @@ fn gen_rldic(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x78000008 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // SH64
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x3f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x3f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 4) & 0x2;
     }
     // MB64
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x3f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x3f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 6;
         code |= arg & 0x20;
     }
@@ -6105,23 +6126,23 @@ fn gen_rldicl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x78000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // SH64
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x3f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x3f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 4) & 0x2;
     }
     // MB64
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x3f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x3f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 6;
         code |= arg & 0x20;
     }
@@ -6132,23 +6153,23 @@ fn gen_rldicr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x78000004 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // SH64
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x3f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x3f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 4) & 0x2;
     }
     // ME64
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x3f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x3f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 6;
         code |= arg & 0x20;
     }
@@ -6159,23 +6180,23 @@ fn gen_rldimi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7800000c | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // SH64
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x3f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x3f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 4) & 0x2;
     }
     // MB64
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x3f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x3f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 6;
         code |= arg & 0x20;
     }
@@ -6186,27 +6207,27 @@ fn gen_rlwimi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x50000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // SH
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 11;
     }
     // MB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 6;
     }
     // ME
     {
-        let arg = parse_unsigned(args, 4, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 4, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 1;
     }
     Ok(code)
@@ -6216,27 +6237,27 @@ fn gen_rlwinm(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x54000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // SH
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 11;
     }
     // MB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 6;
     }
     // ME
     {
-        let arg = parse_unsigned(args, 4, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 4, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 1;
     }
     Ok(code)
@@ -6246,27 +6267,27 @@ fn gen_rlwnm(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x5c000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     // MB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 6;
     }
     // ME
     {
-        let arg = parse_unsigned(args, 4, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 4, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 1;
     }
     Ok(code)
@@ -6276,17 +6297,17 @@ fn gen_rotld(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x78000010 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6296,17 +6317,17 @@ fn gen_rotlw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x5c00003e | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6316,17 +6337,17 @@ fn gen_rotlwi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x5400003e | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // SH
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6336,17 +6357,17 @@ fn gen_rotrwi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x5400003e | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // SH
     {
-        let arg = (32 - parse_unsigned(args, 2, 0x0, 0x1f)?);
+        let arg = (32 - parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?);
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6366,7 +6387,7 @@ fn gen_slbie(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000364 | modifiers;
     // rB
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6376,17 +6397,17 @@ fn gen_sld(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000036 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6396,17 +6417,17 @@ fn gen_slw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000030 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6416,22 +6437,22 @@ fn gen_slwi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x54000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // SH
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 11;
     }
     // ME
     {
-        let arg = (31 - parse_unsigned(args, 2, 0x0, 0x1f)?);
+        let arg = (31 - parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?);
         code |= (arg & 0x1f) << 1;
     }
     Ok(code)
@@ -6441,17 +6462,17 @@ fn gen_srad(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000634 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6461,17 +6482,17 @@ fn gen_sradi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000674 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // SH64
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x3f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x3f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 4) & 0x2;
     }
@@ -6482,17 +6503,17 @@ fn gen_sraw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000630 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6502,17 +6523,17 @@ fn gen_srawi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000670 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // SH
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6522,17 +6543,17 @@ fn gen_srd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000436 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6542,17 +6563,17 @@ fn gen_srw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000430 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6562,22 +6583,22 @@ fn gen_srwi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x5400003e | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // MB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 6;
     }
     // SH
     {
-        let arg = (32 - parse_unsigned(args, 2, 0x0, 0x1f)?);
+        let arg = (32 - parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?);
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6587,17 +6608,17 @@ fn gen_stb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x98000000 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -6607,17 +6628,17 @@ fn gen_stbu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x9c000000 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -6627,17 +6648,17 @@ fn gen_stbux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0001ee | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6647,17 +6668,17 @@ fn gen_stbx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0001ae | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6667,17 +6688,17 @@ fn gen_std(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xf8000000 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // ds
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xfffc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -6687,17 +6708,17 @@ fn gen_stdcx_(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0001ad | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6707,17 +6728,17 @@ fn gen_stdu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xf8000001 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // ds
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xfffc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -6727,17 +6748,17 @@ fn gen_stdux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00016a | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6747,17 +6768,17 @@ fn gen_stdx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00012a | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6767,17 +6788,17 @@ fn gen_stfd(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xd8000000 | modifiers;
     // frS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -6787,17 +6808,17 @@ fn gen_stfdu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xdc000000 | modifiers;
     // frS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -6807,17 +6828,17 @@ fn gen_stfdux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0005ee | modifiers;
     // frS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6827,17 +6848,17 @@ fn gen_stfdx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0005ae | modifiers;
     // frS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6847,17 +6868,17 @@ fn gen_stfiwx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0007ae | modifiers;
     // frS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6867,17 +6888,17 @@ fn gen_stfs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xd0000000 | modifiers;
     // frS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -6887,17 +6908,17 @@ fn gen_stfsu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xd4000000 | modifiers;
     // frS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -6907,17 +6928,17 @@ fn gen_stfsux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00056e | modifiers;
     // frS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6927,17 +6948,17 @@ fn gen_stfsx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00052e | modifiers;
     // frS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::FPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6947,17 +6968,17 @@ fn gen_sth(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xb0000000 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -6967,17 +6988,17 @@ fn gen_sthbrx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00072c | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -6987,17 +7008,17 @@ fn gen_sthu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xb4000000 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -7007,17 +7028,17 @@ fn gen_sthux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00036e | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7027,17 +7048,17 @@ fn gen_sthx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00032e | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7047,17 +7068,17 @@ fn gen_stmw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xbc000000 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -7067,17 +7088,17 @@ fn gen_stswi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0005aa | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // NB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7087,17 +7108,17 @@ fn gen_stswx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00052a | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7107,17 +7128,17 @@ fn gen_stvebx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00010e | modifiers;
     // vS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7127,17 +7148,17 @@ fn gen_stvehx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00014e | modifiers;
     // vS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7147,17 +7168,17 @@ fn gen_stvewx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00018e | modifiers;
     // vS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7167,18 +7188,18 @@ fn gen_stvewx128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000183 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7188,17 +7209,17 @@ fn gen_stvlx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00050e | modifiers;
     // vS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7208,18 +7229,18 @@ fn gen_stvlx128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000503 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7229,17 +7250,17 @@ fn gen_stvlxl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00070e | modifiers;
     // vS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7249,18 +7270,18 @@ fn gen_stvlxl128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000703 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7270,17 +7291,17 @@ fn gen_stvrx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00054e | modifiers;
     // vS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7290,18 +7311,18 @@ fn gen_stvrx128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000543 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7311,17 +7332,17 @@ fn gen_stvrxl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00074e | modifiers;
     // vS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7331,18 +7352,18 @@ fn gen_stvrxl128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000743 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7352,17 +7373,17 @@ fn gen_stvx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0001ce | modifiers;
     // vS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7372,18 +7393,18 @@ fn gen_stvx128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100001c3 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7393,17 +7414,17 @@ fn gen_stvxl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0003ce | modifiers;
     // vS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7413,18 +7434,18 @@ fn gen_stvxl128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x100003c3 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7434,17 +7455,17 @@ fn gen_stw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x90000000 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -7454,17 +7475,17 @@ fn gen_stwbrx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00052c | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7474,17 +7495,17 @@ fn gen_stwcx_(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00012d | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7494,17 +7515,17 @@ fn gen_stwu(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x94000000 | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // offset
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -7514,17 +7535,17 @@ fn gen_stwux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00016e | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7534,17 +7555,17 @@ fn gen_stwx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c00012e | modifiers;
     // rS
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7554,17 +7575,17 @@ fn gen_subf(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000050 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7574,17 +7595,17 @@ fn gen_subfc(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000010 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7594,17 +7615,17 @@ fn gen_subfe(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000110 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7614,17 +7635,17 @@ fn gen_subfic(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x20000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = parse_signed(args, 2, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 2, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -7634,12 +7655,12 @@ fn gen_subfme(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c0001d0 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -7649,12 +7670,12 @@ fn gen_subfze(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000190 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -7664,17 +7685,17 @@ fn gen_subi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x38000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = (-parse_signed(args, 2, -0x8000, 0x8000)?) as u32;
+        let arg = (-parse_signed(args, 2, -0x8000, 0x8000, ArgKind::Immediate)?) as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -7684,17 +7705,17 @@ fn gen_subic(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x30000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = (-parse_signed(args, 2, -0x8000, 0x8000)?) as u32;
+        let arg = (-parse_signed(args, 2, -0x8000, 0x8000, ArgKind::Immediate)?) as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -7704,17 +7725,17 @@ fn gen_subic_(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x34000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = (-parse_signed(args, 2, -0x8000, 0x8000)?) as u32;
+        let arg = (-parse_signed(args, 2, -0x8000, 0x8000, ArgKind::Immediate)?) as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -7724,17 +7745,17 @@ fn gen_subis(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x3c000000 | modifiers;
     // rD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = (-parse_signed(args, 2, -0x8000, 0x8000)?) as u32;
+        let arg = (-parse_signed(args, 2, -0x8000, 0x8000, ArgKind::Immediate)?) as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -7745,7 +7766,7 @@ fn gen_sync(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
             let mut code = 0x7c0004ac | modifiers;
             // sync_L
             {
-                let arg = parse_unsigned(args, 0, 0x0, 0x3)?;
+                let arg = parse_unsigned(args, 0, 0x0, 0x3, ArgKind::Immediate)?;
                 code |= (arg & 0x3) << 21;
             }
             Ok(code)
@@ -7767,17 +7788,17 @@ fn gen_td(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000088 | modifiers;
     // TO
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7787,12 +7808,12 @@ fn gen_tdge(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7d800088 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7802,17 +7823,17 @@ fn gen_tdi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x8000000 | modifiers;
     // TO
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = parse_signed(args, 2, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 2, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -7822,12 +7843,12 @@ fn gen_tdlnl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7ca00088 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7837,12 +7858,12 @@ fn gen_tdlti(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xa000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -7852,12 +7873,12 @@ fn gen_tdnei(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xb000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -7867,7 +7888,7 @@ fn gen_tlbie(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000264 | modifiers;
     // rB
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7887,17 +7908,17 @@ fn gen_tw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000008 | modifiers;
     // TO
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7907,12 +7928,12 @@ fn gen_tweq(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c800008 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7922,12 +7943,12 @@ fn gen_twgti(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xd000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -7937,17 +7958,17 @@ fn gen_twi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xc000000 | modifiers;
     // TO
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 21;
     }
     // rA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = parse_signed(args, 2, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 2, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -7957,12 +7978,12 @@ fn gen_twlge(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7ca00008 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -7972,12 +7993,12 @@ fn gen_twllei(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xcc00000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -7987,12 +8008,12 @@ fn gen_twui(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0xfe00000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // simm
     {
-        let arg = parse_signed(args, 1, -0x8000, 0x8000)? as u32;
+        let arg = parse_signed(args, 1, -0x8000, 0x8000, ArgKind::Immediate)? as u32;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -8002,17 +8023,17 @@ fn gen_vaddcuw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000180 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8022,17 +8043,17 @@ fn gen_vaddfp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000000a | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8042,20 +8063,20 @@ fn gen_vaddfp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x14000010 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -8066,17 +8087,17 @@ fn gen_vaddsbs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000300 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8086,17 +8107,17 @@ fn gen_vaddshs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000340 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8106,17 +8127,17 @@ fn gen_vaddsws(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000380 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8126,17 +8147,17 @@ fn gen_vaddubm(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000000 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8146,17 +8167,17 @@ fn gen_vaddubs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000200 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8166,17 +8187,17 @@ fn gen_vadduhm(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000040 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8186,17 +8207,17 @@ fn gen_vadduhs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000240 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8206,17 +8227,17 @@ fn gen_vadduwm(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000080 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8226,17 +8247,17 @@ fn gen_vadduws(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000280 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8246,17 +8267,17 @@ fn gen_vand(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000404 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8266,20 +8287,20 @@ fn gen_vand128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x14000210 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -8290,17 +8311,17 @@ fn gen_vandc(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000444 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8310,20 +8331,20 @@ fn gen_vandc128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x14000250 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -8334,17 +8355,17 @@ fn gen_vavgsb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000502 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8354,17 +8375,17 @@ fn gen_vavgsh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000542 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8374,17 +8395,17 @@ fn gen_vavgsw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000582 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8394,17 +8415,17 @@ fn gen_vavgub(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000402 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8414,17 +8435,17 @@ fn gen_vavguh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000442 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8434,17 +8455,17 @@ fn gen_vavguw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000482 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8454,17 +8475,17 @@ fn gen_vcfsx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000034a | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vuimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -8474,19 +8495,19 @@ fn gen_vcfsx128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x180002b0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
     // vsimm
     {
-        let arg = parse_signed(args, 2, -0x10, 0x10)? as u32;
+        let arg = parse_signed(args, 2, -0x10, 0x10, ArgKind::Immediate)? as u32;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -8496,17 +8517,17 @@ fn gen_vcfux(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000030a | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vuimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -8516,19 +8537,19 @@ fn gen_vcfux128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x180002f0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
     // vuimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -8538,17 +8559,17 @@ fn gen_vcmpbfp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100003c6 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8558,20 +8579,20 @@ fn gen_vcmpbfp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x18000180 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -8582,17 +8603,17 @@ fn gen_vcmpeqfp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x100000c6 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8602,20 +8623,20 @@ fn gen_vcmpeqfp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentErro
     let mut code = 0x18000000 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -8626,17 +8647,17 @@ fn gen_vcmpequb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000006 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8646,17 +8667,17 @@ fn gen_vcmpequh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000046 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8666,17 +8687,17 @@ fn gen_vcmpequw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000086 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8686,20 +8707,20 @@ fn gen_vcmpequw128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentErro
     let mut code = 0x18000200 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -8710,17 +8731,17 @@ fn gen_vcmpgefp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x100001c6 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8730,20 +8751,20 @@ fn gen_vcmpgefp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentErro
     let mut code = 0x18000080 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -8754,17 +8775,17 @@ fn gen_vcmpgtfp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x100002c6 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8774,20 +8795,20 @@ fn gen_vcmpgtfp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentErro
     let mut code = 0x18000100 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -8798,17 +8819,17 @@ fn gen_vcmpgtsb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000306 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8818,17 +8839,17 @@ fn gen_vcmpgtsh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000346 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8838,17 +8859,17 @@ fn gen_vcmpgtsw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000386 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8858,17 +8879,17 @@ fn gen_vcmpgtub(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000206 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8878,17 +8899,17 @@ fn gen_vcmpgtuh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000246 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8898,17 +8919,17 @@ fn gen_vcmpgtuw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000286 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -8918,17 +8939,17 @@ fn gen_vctsxs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100003ca | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vuimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -8938,19 +8959,19 @@ fn gen_vctsxs128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x18000230 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
     // vsimm
     {
-        let arg = parse_signed(args, 2, -0x10, 0x10)? as u32;
+        let arg = parse_signed(args, 2, -0x10, 0x10, ArgKind::Immediate)? as u32;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -8960,17 +8981,17 @@ fn gen_vctuxs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000038a | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vuimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -8980,19 +9001,19 @@ fn gen_vctuxs128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x18000270 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
     // vuimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -9002,12 +9023,12 @@ fn gen_vexptefp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x1000018a | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9017,13 +9038,13 @@ fn gen_vexptefp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentErro
     let mut code = 0x180006b0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -9034,12 +9055,12 @@ fn gen_vlogefp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100001ca | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9049,13 +9070,13 @@ fn gen_vlogefp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x180006f0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -9066,20 +9087,20 @@ fn gen_vmaddcfp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentErro
     let mut code = 0x14000110 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -9090,22 +9111,22 @@ fn gen_vmaddfp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000002e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 6;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9115,20 +9136,20 @@ fn gen_vmaddfp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x140000d0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -9139,17 +9160,17 @@ fn gen_vmaxfp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000040a | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9159,20 +9180,20 @@ fn gen_vmaxfp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x18000280 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -9183,17 +9204,17 @@ fn gen_vmaxsb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000102 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9203,17 +9224,17 @@ fn gen_vmaxsh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000142 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9223,17 +9244,17 @@ fn gen_vmaxsw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000182 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9243,17 +9264,17 @@ fn gen_vmaxub(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000002 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9263,17 +9284,17 @@ fn gen_vmaxuh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000042 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9283,17 +9304,17 @@ fn gen_vmaxuw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000082 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9303,22 +9324,22 @@ fn gen_vmhaddshs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000020 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vC
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -9328,22 +9349,22 @@ fn gen_vmhraddshs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x10000021 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vC
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -9353,17 +9374,17 @@ fn gen_vminfp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000044a | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9373,20 +9394,20 @@ fn gen_vminfp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x180002c0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -9397,17 +9418,17 @@ fn gen_vminsb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000302 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9417,17 +9438,17 @@ fn gen_vminsh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000342 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9437,17 +9458,17 @@ fn gen_vminsw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000382 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9457,17 +9478,17 @@ fn gen_vminub(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000202 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9477,17 +9498,17 @@ fn gen_vminuh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000242 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9497,17 +9518,17 @@ fn gen_vminuw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000282 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9517,22 +9538,22 @@ fn gen_vmladduhm(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000022 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vC
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -9542,17 +9563,17 @@ fn gen_vmr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000484 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9562,17 +9583,17 @@ fn gen_vmrghb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000000c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9582,17 +9603,17 @@ fn gen_vmrghh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000004c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9602,17 +9623,17 @@ fn gen_vmrghw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000008c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9622,20 +9643,20 @@ fn gen_vmrghw128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x18000300 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -9646,17 +9667,17 @@ fn gen_vmrglb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000010c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9666,17 +9687,17 @@ fn gen_vmrglh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000014c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9686,17 +9707,17 @@ fn gen_vmrglw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000018c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9706,20 +9727,20 @@ fn gen_vmrglw128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x18000340 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -9730,20 +9751,20 @@ fn gen_vmsum3fp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentErro
     let mut code = 0x14000190 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -9754,20 +9775,20 @@ fn gen_vmsum4fp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentErro
     let mut code = 0x140001d0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -9778,22 +9799,22 @@ fn gen_vmsummbm(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000025 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vC
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -9803,22 +9824,22 @@ fn gen_vmsumshm(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000028 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vC
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -9828,22 +9849,22 @@ fn gen_vmsumshs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000029 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vC
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -9853,22 +9874,22 @@ fn gen_vmsumubm(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000024 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vC
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -9878,22 +9899,22 @@ fn gen_vmsumuhm(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000026 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vC
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -9903,22 +9924,22 @@ fn gen_vmsumuhs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000027 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vC
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -9928,17 +9949,17 @@ fn gen_vmulesb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000308 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9948,17 +9969,17 @@ fn gen_vmulesh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000348 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9968,17 +9989,17 @@ fn gen_vmuleub(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000208 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -9988,17 +10009,17 @@ fn gen_vmuleuh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000248 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10008,20 +10029,20 @@ fn gen_vmulfp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x14000090 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10032,17 +10053,17 @@ fn gen_vmulosb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000108 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10052,17 +10073,17 @@ fn gen_vmulosh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000148 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10072,17 +10093,17 @@ fn gen_vmuloub(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000008 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10092,17 +10113,17 @@ fn gen_vmulouh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000048 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10112,22 +10133,22 @@ fn gen_vnmsubfp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x1000002f | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vC
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 6;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10137,20 +10158,20 @@ fn gen_vnmsubfp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentErro
     let mut code = 0x14000150 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10161,17 +10182,17 @@ fn gen_vnor(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000504 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10181,20 +10202,20 @@ fn gen_vnor128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x14000290 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10205,17 +10226,17 @@ fn gen_vnot(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000504 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10225,17 +10246,17 @@ fn gen_vor(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000484 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10245,20 +10266,20 @@ fn gen_vor128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x140002d0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10269,22 +10290,22 @@ fn gen_vperm(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000002b | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vC
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -10294,26 +10315,26 @@ fn gen_vperm128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x14000000 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
     // VC128
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x7, ArgKind::VR)?;
         code |= (arg & 0x7) << 6;
     }
     Ok(code)
@@ -10323,19 +10344,19 @@ fn gen_vpermwi128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x18000210 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
     // PERM
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0xff)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0xff, ArgKind::Swizzle)?;
         code |= (arg & 0x1f) << 16;
         code |= (arg & 0xe0) << 1;
     }
@@ -10346,29 +10367,29 @@ fn gen_vpkd3d128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x18000610 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
     // D3DType
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7, ArgKind::D3DType)?;
         code |= (arg & 0x7) << 18;
     }
     // VMASK
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x3, ArgKind::PackMask)?;
         code |= (arg & 0x3) << 16;
     }
     // Zimm
     {
-        let arg = parse_unsigned(args, 4, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 4, 0x0, 0x3, ArgKind::Immediate)?;
         code |= (arg & 0x3) << 6;
     }
     Ok(code)
@@ -10378,17 +10399,17 @@ fn gen_vpkpx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000030e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10398,17 +10419,17 @@ fn gen_vpkshss(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000018e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10418,20 +10439,20 @@ fn gen_vpkshss128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x14000200 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10442,17 +10463,17 @@ fn gen_vpkshus(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000010e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10462,20 +10483,20 @@ fn gen_vpkshus128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x14000240 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10486,17 +10507,17 @@ fn gen_vpkswss(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100001ce | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10506,20 +10527,20 @@ fn gen_vpkswss128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x14000280 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10530,17 +10551,17 @@ fn gen_vpkswus(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000014e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10550,20 +10571,20 @@ fn gen_vpkswus128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x140002c0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10574,17 +10595,17 @@ fn gen_vpkuhum(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000000e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10594,20 +10615,20 @@ fn gen_vpkuhum128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x14000300 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10618,17 +10639,17 @@ fn gen_vpkuhus(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000008e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10638,20 +10659,20 @@ fn gen_vpkuhus128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x14000340 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10662,17 +10683,17 @@ fn gen_vpkuwum(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000004e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10682,20 +10703,20 @@ fn gen_vpkuwum128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x14000380 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10706,17 +10727,17 @@ fn gen_vpkuwus(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100000ce | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10726,20 +10747,20 @@ fn gen_vpkuwus128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x140003c0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10750,12 +10771,12 @@ fn gen_vrefp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000010a | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10765,13 +10786,13 @@ fn gen_vrefp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x18000630 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10782,12 +10803,12 @@ fn gen_vrfim(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100002ca | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10797,13 +10818,13 @@ fn gen_vrfim128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x18000330 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10814,12 +10835,12 @@ fn gen_vrfin(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000020a | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10829,13 +10850,13 @@ fn gen_vrfin128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x18000370 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10846,12 +10867,12 @@ fn gen_vrfip(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000028a | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10861,13 +10882,13 @@ fn gen_vrfip128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x180003b0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10878,12 +10899,12 @@ fn gen_vrfiz(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000024a | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10893,13 +10914,13 @@ fn gen_vrfiz128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x180003f0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -10910,17 +10931,17 @@ fn gen_vrlb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000004 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10930,17 +10951,17 @@ fn gen_vrlh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000044 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10950,24 +10971,24 @@ fn gen_vrlimi128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x18000710 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
     // vuimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 16;
     }
     // Zimm
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x3)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x3, ArgKind::Immediate)?;
         code |= (arg & 0x3) << 6;
     }
     Ok(code)
@@ -10977,17 +10998,17 @@ fn gen_vrlw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000084 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -10997,20 +11018,20 @@ fn gen_vrlw128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x18000050 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -11021,12 +11042,12 @@ fn gen_vrsqrtefp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x1000014a | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11036,13 +11057,13 @@ fn gen_vrsqrtefp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentErr
     let mut code = 0x18000670 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -11053,22 +11074,22 @@ fn gen_vsel(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000002a | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vC
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 6;
     }
     Ok(code)
@@ -11078,20 +11099,20 @@ fn gen_vsel128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x14000350 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -11102,17 +11123,17 @@ fn gen_vsl(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100001c4 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11122,17 +11143,17 @@ fn gen_vslb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000104 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11142,22 +11163,22 @@ fn gen_vsldoi(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000002c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // SHB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0xf)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0xf, ArgKind::Immediate)?;
         code |= (arg & 0xf) << 6;
     }
     Ok(code)
@@ -11167,26 +11188,26 @@ fn gen_vsldoi128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000010 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
     // SHB
     {
-        let arg = parse_unsigned(args, 3, 0x0, 0xf)?;
+        let arg = parse_unsigned(args, 3, 0x0, 0xf, ArgKind::Immediate)?;
         code |= (arg & 0xf) << 6;
     }
     Ok(code)
@@ -11196,17 +11217,17 @@ fn gen_vslh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000144 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11216,17 +11237,17 @@ fn gen_vslo(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000040c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11236,20 +11257,20 @@ fn gen_vslo128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x14000390 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -11260,17 +11281,17 @@ fn gen_vslw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000184 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11280,20 +11301,20 @@ fn gen_vslw128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x180000d0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -11304,17 +11325,17 @@ fn gen_vspltb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000020c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vuimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -11324,17 +11345,17 @@ fn gen_vsplth(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000024c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vuimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -11344,12 +11365,12 @@ fn gen_vspltisb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x1000030c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vsimm
     {
-        let arg = parse_signed(args, 1, -0x10, 0x10)? as u32;
+        let arg = parse_signed(args, 1, -0x10, 0x10, ArgKind::Immediate)? as u32;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -11359,12 +11380,12 @@ fn gen_vspltish(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x1000034c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vsimm
     {
-        let arg = parse_signed(args, 1, -0x10, 0x10)? as u32;
+        let arg = parse_signed(args, 1, -0x10, 0x10, ArgKind::Immediate)? as u32;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -11374,12 +11395,12 @@ fn gen_vspltisw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x1000038c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vsimm
     {
-        let arg = parse_signed(args, 1, -0x10, 0x10)? as u32;
+        let arg = parse_signed(args, 1, -0x10, 0x10, ArgKind::Immediate)? as u32;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -11389,19 +11410,19 @@ fn gen_vspltisw128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentErro
     let mut code = 0x18000770 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
     // vsimm
     {
-        let arg = parse_signed(args, 2, -0x10, 0x10)? as u32;
+        let arg = parse_signed(args, 2, -0x10, 0x10, ArgKind::Immediate)? as u32;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -11411,17 +11432,17 @@ fn gen_vspltw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000028c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     // vuimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -11431,19 +11452,19 @@ fn gen_vspltw128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x18000730 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
     // vuimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -11453,17 +11474,17 @@ fn gen_vsr(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100002c4 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11473,17 +11494,17 @@ fn gen_vsrab(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000304 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11493,17 +11514,17 @@ fn gen_vsrah(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000344 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11513,17 +11534,17 @@ fn gen_vsraw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000384 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11533,20 +11554,20 @@ fn gen_vsraw128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x18000150 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -11557,17 +11578,17 @@ fn gen_vsrb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000204 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11577,17 +11598,17 @@ fn gen_vsrh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000244 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11597,17 +11618,17 @@ fn gen_vsro(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000044c | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11617,20 +11638,20 @@ fn gen_vsro128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x140003d0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -11641,17 +11662,17 @@ fn gen_vsrw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000284 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11661,20 +11682,20 @@ fn gen_vsrw128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x180001d0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -11685,17 +11706,17 @@ fn gen_vsubcuw(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000580 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11705,17 +11726,17 @@ fn gen_vsubfp(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000004a | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11725,20 +11746,20 @@ fn gen_vsubfp128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x14000050 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -11749,17 +11770,17 @@ fn gen_vsubsbs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000700 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11769,17 +11790,17 @@ fn gen_vsubshs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000740 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11789,17 +11810,17 @@ fn gen_vsubsws(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000780 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11809,17 +11830,17 @@ fn gen_vsububm(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000400 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11829,17 +11850,17 @@ fn gen_vsububs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000600 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11849,17 +11870,17 @@ fn gen_vsubuhm(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000440 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11869,17 +11890,17 @@ fn gen_vsubuhs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000640 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11889,17 +11910,17 @@ fn gen_vsubuwm(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000480 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11909,17 +11930,17 @@ fn gen_vsubuws(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000680 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11929,17 +11950,17 @@ fn gen_vsum2sws(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000688 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11949,17 +11970,17 @@ fn gen_vsum4sbs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000708 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11969,17 +11990,17 @@ fn gen_vsum4shs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000648 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -11989,17 +12010,17 @@ fn gen_vsum4ubs(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError>
     let mut code = 0x10000608 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -12009,17 +12030,17 @@ fn gen_vsumsws(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x10000788 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -12029,19 +12050,19 @@ fn gen_vupkd3d128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x180007f0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
     // vuimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::Immediate)?;
         code |= (arg & 0x1f) << 16;
     }
     Ok(code)
@@ -12051,12 +12072,12 @@ fn gen_vupkhpx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000034e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -12066,12 +12087,12 @@ fn gen_vupkhsb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000020e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -12081,13 +12102,13 @@ fn gen_vupkhsb128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x18000380 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -12098,12 +12119,12 @@ fn gen_vupkhsh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000024e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -12113,13 +12134,13 @@ fn gen_vupkhsh128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x180007a0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -12130,12 +12151,12 @@ fn gen_vupklpx(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100003ce | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -12145,12 +12166,12 @@ fn gen_vupklsb(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x1000028e | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -12160,13 +12181,13 @@ fn gen_vupklsb128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x180003c0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -12177,12 +12198,12 @@ fn gen_vupklsh(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100002ce | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -12192,13 +12213,13 @@ fn gen_vupklsh128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError
     let mut code = 0x180007e0 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -12209,17 +12230,17 @@ fn gen_vxor(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x100004c4 | modifiers;
     // vD
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
     }
     // vA
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
     }
     // vB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -12229,20 +12250,20 @@ fn gen_vxor128(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x14000310 | modifiers;
     // VDS128
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 21;
         code |= (arg >> 3) & 0xc;
     }
     // VA128
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 16;
         code |= arg & 0x20;
         code |= (arg & 0x40) << 4;
     }
     // VB128
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x7f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x7f, ArgKind::VR)?;
         code |= (arg & 0x1f) << 11;
         code |= (arg >> 5) & 0x3;
     }
@@ -12253,17 +12274,17 @@ fn gen_xor(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x7c000278 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // rB
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 11;
     }
     Ok(code)
@@ -12273,17 +12294,17 @@ fn gen_xori(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x68000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // uimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0xffff)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0xffff, ArgKind::Immediate)?;
         code |= arg & 0xffff;
     }
     Ok(code)
@@ -12293,23 +12314,25 @@ fn gen_xoris(args: &Arguments, modifiers: u32) -> Result<u32, ArgumentError> {
     let mut code = 0x6c000000 | modifiers;
     // rA
     {
-        let arg = parse_unsigned(args, 0, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 0, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 16;
     }
     // rS
     {
-        let arg = parse_unsigned(args, 1, 0x0, 0x1f)?;
+        let arg = parse_unsigned(args, 1, 0x0, 0x1f, ArgKind::GPR)?;
         code |= (arg & 0x1f) << 21;
     }
     // uimm
     {
-        let arg = parse_unsigned(args, 2, 0x0, 0xffff)?;
+        let arg = parse_unsigned(args, 2, 0x0, 0xffff, ArgKind::Immediate)?;
         code |= arg & 0xffff;
     }
     Ok(code)
 }
 type MnemonicFn = fn(&Arguments, u32) -> Result<u32, ArgumentError>;
-static MNEMONIC_MAP: phf::Map<&'static str, (MnemonicFn, u32)> = ::phf::Map {
+// Displacement range of the branch target, if any.
+type BranchRange = Option<(i32, i32)>;
+static MNEMONIC_MAP: phf::Map<&'static str, (MnemonicFn, u32, BranchRange)> = ::phf::Map {
     key: 16287231350648472473,
     disps: &[
         (0, 75),
@@ -12524,1060 +12547,1080 @@ static MNEMONIC_MAP: phf::Map<&'static str, (MnemonicFn, u32)> = ::phf::Map {
         (23, 704),
     ],
     entries: &[
-        ("dstst", (gen_dstst, 0x0)),
-        ("rotld", (gen_rotld, 0x0)),
-        ("vrfin128", (gen_vrfin128, 0x0)),
-        ("bdzla", (gen_bdz, 0x3)),
-        ("bnectr", (gen_bnectr, 0x0)),
-        ("blelrl+", (gen_blelr, 0x200001)),
-        ("vcmpbfp.", (gen_vcmpbfp, 0x400)),
-        ("bdnztlr+", (gen_bdnztlr, 0x200000)),
-        ("vperm128", (gen_vperm128, 0x0)),
-        ("beqa+", (gen_beq, 0x200002)),
-        ("ldux", (gen_ldux, 0x0)),
-        ("neg.", (gen_neg, 0x1)),
-        ("ps_merge00", (gen_ps_merge00, 0x0)),
-        ("tdlti", (gen_tdlti, 0x0)),
-        ("ps_nmsub", (gen_ps_nmsub, 0x0)),
-        ("psq_lux", (gen_psq_lux, 0x0)),
-        ("lswi", (gen_lswi, 0x0)),
-        ("vminsh", (gen_vminsh, 0x0)),
-        ("bgtlr", (gen_bgtlr, 0x0)),
-        ("mtvscr", (gen_mtvscr, 0x0)),
-        ("fmadd.", (gen_fmadd, 0x1)),
-        ("ldu", (gen_ldu, 0x0)),
-        ("lhzux", (gen_lhzux, 0x0)),
-        ("crmove", (gen_crmove, 0x0)),
-        ("ps_muls1", (gen_ps_muls1, 0x0)),
-        ("addme.", (gen_addme, 0x1)),
-        ("bnel-", (gen_bne, 0x200001)),
-        ("ps_sel.", (gen_ps_sel, 0x1)),
-        ("dcbst", (gen_dcbst, 0x0)),
-        ("dssall", (gen_dssall, 0x0)),
-        ("mfsr", (gen_mfsr, 0x0)),
-        ("vsubuws", (gen_vsubuws, 0x0)),
-        ("fsqrts.", (gen_fsqrts, 0x1)),
-        ("bgta+", (gen_bgt, 0x200002)),
-        ("vrlimi128", (gen_vrlimi128, 0x0)),
-        ("fctid.", (gen_fctid, 0x1)),
-        ("dcbf", (gen_dcbf, 0x0)),
-        ("cmpldi", (gen_cmpldi, 0x0)),
-        ("bctr", (gen_bctr, 0x0)),
-        ("vmulfp128", (gen_vmulfp128, 0x0)),
-        ("bdnztla", (gen_bdnzt, 0x3)),
-        ("bcl-", (gen_bc, 0x200001)),
-        ("bgea+", (gen_bge, 0x200002)),
-        ("bgel-", (gen_bge, 0x200001)),
-        ("twllei", (gen_twllei, 0x0)),
-        ("mtsrd", (gen_mtsrd, 0x0)),
-        ("vmulosb", (gen_vmulosb, 0x0)),
-        ("addme", (gen_addme, 0x0)),
-        ("divd.", (gen_divd, 0x1)),
-        ("lhz", (gen_lhz, 0x0)),
-        ("vmhaddshs", (gen_vmhaddshs, 0x0)),
-        ("creqv", (gen_creqv, 0x0)),
-        ("vsldoi", (gen_vsldoi, 0x0)),
-        ("bgtctr+", (gen_bgtctr, 0x200000)),
-        ("bdnzfl+", (gen_bdnzf, 0x200001)),
-        ("stvx", (gen_stvx, 0x0)),
-        ("stdcx.", (gen_stdcx_, 0x0)),
-        ("vmladduhm", (gen_vmladduhm, 0x0)),
-        ("beqctr", (gen_beqctr, 0x0)),
-        ("stfiwx", (gen_stfiwx, 0x0)),
-        ("addco", (gen_addc, 0x400)),
-        ("ps_msub.", (gen_ps_msub, 0x1)),
-        ("vcmpgtuh", (gen_vcmpgtuh, 0x0)),
-        ("subfze.", (gen_subfze, 0x1)),
-        ("ps_merge10.", (gen_ps_merge10, 0x1)),
-        ("ps_rsqrte", (gen_ps_rsqrte, 0x0)),
-        ("fdivs", (gen_fdivs, 0x0)),
-        ("bgta", (gen_bgt, 0x2)),
-        ("divwuo.", (gen_divwu, 0x401)),
-        ("subic", (gen_subic, 0x0)),
-        ("lwzu", (gen_lwzu, 0x0)),
-        ("fadd", (gen_fadd, 0x0)),
-        ("lwax", (gen_lwax, 0x0)),
-        ("fabs", (gen_fabs, 0x0)),
-        ("beq+", (gen_beq, 0x200000)),
-        ("bdnzla+", (gen_bdnz, 0x200003)),
-        ("vmaxuw", (gen_vmaxuw, 0x0)),
-        ("lvlx128", (gen_lvlx128, 0x0)),
-        ("fsubs", (gen_fsubs, 0x0)),
-        ("vmaxsw", (gen_vmaxsw, 0x0)),
-        ("vmsumubm", (gen_vmsumubm, 0x0)),
-        ("fcfid", (gen_fcfid, 0x0)),
-        ("bnela+", (gen_bne, 0x200003)),
-        ("addis", (gen_addis, 0x0)),
-        ("vmulosh", (gen_vmulosh, 0x0)),
-        ("bdztlrl+", (gen_bdztlr, 0x200001)),
-        ("ps_madds1", (gen_ps_madds1, 0x0)),
-        ("bdzlr", (gen_bdzlr, 0x0)),
-        ("vmaddfp", (gen_vmaddfp, 0x0)),
-        ("vcmpequb.", (gen_vcmpequb, 0x400)),
-        ("bgel+", (gen_bge, 0x200001)),
-        ("bnelr", (gen_bnelr, 0x0)),
-        ("stwx", (gen_stwx, 0x0)),
-        ("lbzx", (gen_lbzx, 0x0)),
-        ("vmulesb", (gen_vmulesb, 0x0)),
-        ("bdza", (gen_bdz, 0x2)),
-        ("lvewx128", (gen_lvewx128, 0x0)),
-        ("psq_lx", (gen_psq_lx, 0x0)),
-        ("psq_stu", (gen_psq_stu, 0x0)),
-        ("vsububm", (gen_vsububm, 0x0)),
-        ("srawi.", (gen_srawi, 0x1)),
-        ("subfic", (gen_subfic, 0x0)),
-        ("vcfsx", (gen_vcfsx, 0x0)),
-        ("ps_mul", (gen_ps_mul, 0x0)),
-        ("rldimi", (gen_rldimi, 0x0)),
-        ("stwux", (gen_stwux, 0x0)),
-        ("fres", (gen_fres, 0x0)),
-        ("vsel", (gen_vsel, 0x0)),
-        ("bdzta+", (gen_bdzt, 0x200002)),
-        ("vrsqrtefp", (gen_vrsqrtefp, 0x0)),
-        ("dcbz", (gen_dcbz, 0x0)),
-        ("vspltisw", (gen_vspltisw, 0x0)),
-        ("vcmpequh.", (gen_vcmpequh, 0x400)),
-        ("ps_mr.", (gen_ps_mr, 0x1)),
-        ("rlwnm", (gen_rlwnm, 0x0)),
-        ("rldicr", (gen_rldicr, 0x0)),
-        ("vmaxsh", (gen_vmaxsh, 0x0)),
-        ("bgectr", (gen_bgectr, 0x0)),
-        ("subfco", (gen_subfc, 0x400)),
-        ("stvx128", (gen_stvx128, 0x0)),
-        ("fdiv", (gen_fdiv, 0x0)),
-        ("fsqrts", (gen_fsqrts, 0x0)),
-        ("rldicr.", (gen_rldicr, 0x1)),
-        ("vsum4ubs", (gen_vsum4ubs, 0x0)),
-        ("bge-", (gen_bge, 0x200000)),
-        ("ps_abs.", (gen_ps_abs, 0x1)),
-        ("lvewx", (gen_lvewx, 0x0)),
-        ("lhzx", (gen_lhzx, 0x0)),
-        ("cmpl", (gen_cmpl, 0x0)),
-        ("bnsa-", (gen_bns, 0x200002)),
-        ("mtfsf.", (gen_mtfsf, 0x1)),
-        ("blelr+", (gen_blelr, 0x200000)),
-        ("bltlr", (gen_bltlr, 0x0)),
-        ("bdnzfa+", (gen_bdnzf, 0x200002)),
-        ("vcmpequw128.", (gen_vcmpequw128, 0x40)),
-        ("vcmpgtfp128.", (gen_vcmpgtfp128, 0x40)),
-        ("bgtctr", (gen_bgtctr, 0x0)),
-        ("bsol-", (gen_bso, 0x200001)),
-        ("nand", (gen_nand, 0x0)),
-        ("or.", (gen_or, 0x1)),
-        ("beqctrl", (gen_beqctr, 0x1)),
-        ("std", (gen_std, 0x0)),
-        ("dcbtst", (gen_dcbtst, 0x0)),
-        ("mfsprg", (gen_mfsprg, 0x0)),
-        ("beqlr", (gen_beqlr, 0x0)),
-        ("bltla", (gen_blt, 0x3)),
-        ("vcmpequb", (gen_vcmpequb, 0x0)),
-        ("lfsx", (gen_lfsx, 0x0)),
-        ("fsub.", (gen_fsub, 0x1)),
-        ("vsubsbs", (gen_vsubsbs, 0x0)),
-        ("bdnzta+", (gen_bdnzt, 0x200002)),
-        ("cntlzd", (gen_cntlzd, 0x0)),
-        ("lvlx", (gen_lvlx, 0x0)),
-        ("bgea-", (gen_bge, 0x200002)),
-        ("vsubuwm", (gen_vsubuwm, 0x0)),
-        ("bgtlrl", (gen_bgtlr, 0x1)),
-        ("bca+", (gen_bc, 0x200002)),
-        ("mulldo", (gen_mulld, 0x400)),
-        ("bgtla", (gen_bgt, 0x3)),
-        ("ps_msub", (gen_ps_msub, 0x0)),
-        ("divw", (gen_divw, 0x0)),
-        ("bnsl+", (gen_bns, 0x200001)),
-        ("eqv", (gen_eqv, 0x0)),
-        ("and.", (gen_and, 0x1)),
-        ("dcbt", (gen_dcbt, 0x0)),
-        ("vmaxuh", (gen_vmaxuh, 0x0)),
-        ("bdnztla-", (gen_bdnzt, 0x200003)),
-        ("bdzlrl", (gen_bdzlr, 0x1)),
-        ("bgela-", (gen_bge, 0x200003)),
-        ("lis", (gen_lis, 0x0)),
-        ("bdztlr+", (gen_bdztlr, 0x200000)),
-        ("vsubfp", (gen_vsubfp, 0x0)),
-        ("bdnzla", (gen_bdnz, 0x3)),
-        ("vminuh", (gen_vminuh, 0x0)),
-        ("ps_neg", (gen_ps_neg, 0x0)),
-        ("vpkuwus", (gen_vpkuwus, 0x0)),
-        ("bdnza-", (gen_bdnz, 0x200002)),
-        ("tdge", (gen_tdge, 0x0)),
-        ("subfc.", (gen_subfc, 0x1)),
-        ("ps_sub.", (gen_ps_sub, 0x1)),
-        ("frsp", (gen_frsp, 0x0)),
-        ("stdux", (gen_stdux, 0x0)),
-        ("bdnztlr", (gen_bdnztlr, 0x0)),
-        ("vsplth", (gen_vsplth, 0x0)),
-        ("vminfp128", (gen_vminfp128, 0x0)),
-        ("mfsrin", (gen_mfsrin, 0x0)),
-        ("twlge", (gen_twlge, 0x0)),
-        ("bgtctrl", (gen_bgtctr, 0x1)),
-        ("sradi", (gen_sradi, 0x0)),
-        ("vupkhsh128", (gen_vupkhsh128, 0x0)),
-        ("fnmadd.", (gen_fnmadd, 0x1)),
-        ("mtibatl", (gen_mtibatl, 0x0)),
-        ("bnsla-", (gen_bns, 0x200003)),
-        ("vaddfp128", (gen_vaddfp128, 0x0)),
-        ("ps_abs", (gen_ps_abs, 0x0)),
-        ("tweq", (gen_tweq, 0x0)),
-        ("mfmsr", (gen_mfmsr, 0x0)),
-        ("vsubuhm", (gen_vsubuhm, 0x0)),
-        ("vmulesh", (gen_vmulesh, 0x0)),
-        ("bdnz-", (gen_bdnz, 0x200000)),
-        ("bdnzfla+", (gen_bdnzf, 0x200003)),
-        ("bdzflrl+", (gen_bdzflr, 0x200001)),
-        ("mtcrf", (gen_mtcrf, 0x0)),
-        ("mfsrr1", (gen_mfsrr1, 0x0)),
-        ("fadds", (gen_fadds, 0x0)),
-        ("addco.", (gen_addc, 0x401)),
-        ("divdo.", (gen_divd, 0x401)),
-        ("fmsubs.", (gen_fmsubs, 0x1)),
-        ("psq_stux", (gen_psq_stux, 0x0)),
-        ("lvx", (gen_lvx, 0x0)),
-        ("lhau", (gen_lhau, 0x0)),
-        ("rlwimi.", (gen_rlwimi, 0x1)),
-        ("bnectrl", (gen_bnectr, 0x1)),
-        ("rlwinm", (gen_rlwinm, 0x0)),
-        ("bdzfa-", (gen_bdzf, 0x200002)),
-        ("mr.", (gen_mr, 0x1)),
-        ("eieio", (gen_eieio, 0x0)),
-        ("vmrglw", (gen_vmrglw, 0x0)),
-        ("vmsum4fp128", (gen_vmsum4fp128, 0x0)),
-        ("clrlslwi", (gen_clrlslwi, 0x0)),
-        ("fmsub", (gen_fmsub, 0x0)),
-        ("vmaxfp", (gen_vmaxfp, 0x0)),
-        ("addmeo", (gen_addme, 0x400)),
-        ("b", (gen_b, 0x0)),
-        ("bdzt+", (gen_bdzt, 0x200000)),
-        ("vminuw", (gen_vminuw, 0x0)),
-        ("subis", (gen_subis, 0x0)),
-        ("vmrghw128", (gen_vmrghw128, 0x0)),
-        ("lvrx", (gen_lvrx, 0x0)),
-        ("lwa", (gen_lwa, 0x0)),
-        ("ps_madds1.", (gen_ps_madds1, 0x1)),
-        ("rfi", (gen_rfi, 0x0)),
-        ("vsro", (gen_vsro, 0x0)),
-        ("bctrl", (gen_bctr, 0x1)),
-        ("stswi", (gen_stswi, 0x0)),
-        ("bnsl-", (gen_bns, 0x200001)),
-        ("bdzfla", (gen_bdzf, 0x3)),
-        ("rldcr", (gen_rldcr, 0x0)),
-        ("bltlrl", (gen_bltlr, 0x1)),
-        ("cmplwi", (gen_cmplwi, 0x0)),
-        ("ps_add.", (gen_ps_add, 0x1)),
-        ("clrlwi", (gen_clrlwi, 0x0)),
-        ("crnor", (gen_crnor, 0x0)),
-        ("blel+", (gen_ble, 0x200001)),
-        ("tdnei", (gen_tdnei, 0x0)),
-        ("cmpw", (gen_cmpw, 0x0)),
-        ("divw.", (gen_divw, 0x1)),
-        ("blela+", (gen_ble, 0x200003)),
-        ("bsoa", (gen_bso, 0x2)),
-        ("vpkuhus128", (gen_vpkuhus128, 0x0)),
-        ("mfdbatu", (gen_mfdbatu, 0x0)),
-        ("srwi", (gen_srwi, 0x0)),
-        ("mtxer", (gen_mtxer, 0x0)),
-        ("mtsrr0", (gen_mtsrr0, 0x0)),
-        ("mullw", (gen_mullw, 0x0)),
-        ("mfctr", (gen_mfctr, 0x0)),
-        ("bdzfla+", (gen_bdzf, 0x200003)),
-        ("lvsl", (gen_lvsl, 0x0)),
-        ("bltl", (gen_blt, 0x1)),
-        ("subfe.", (gen_subfe, 0x1)),
-        ("mulhdu", (gen_mulhdu, 0x0)),
-        ("fcmpu", (gen_fcmpu, 0x0)),
-        ("blea", (gen_ble, 0x2)),
-        ("bdnzf+", (gen_bdnzf, 0x200000)),
-        ("vctsxs", (gen_vctsxs, 0x0)),
-        ("subfzeo.", (gen_subfze, 0x401)),
-        ("bdztlrl", (gen_bdztlr, 0x1)),
-        ("vcmpgtsh", (gen_vcmpgtsh, 0x0)),
-        ("stfdx", (gen_stfdx, 0x0)),
-        ("add", (gen_add, 0x0)),
-        ("bnsa+", (gen_bns, 0x200002)),
-        ("ps_sub", (gen_ps_sub, 0x0)),
-        ("bdnzflr+", (gen_bdnzflr, 0x200000)),
-        ("bdza+", (gen_bdz, 0x200002)),
-        ("blectr+", (gen_blectr, 0x200000)),
-        ("vcfux128", (gen_vcfux128, 0x0)),
-        ("bdzta-", (gen_bdzt, 0x200002)),
-        ("lbz", (gen_lbz, 0x0)),
-        ("beql-", (gen_beq, 0x200001)),
-        ("bdzl+", (gen_bdz, 0x200001)),
-        ("fnmadds.", (gen_fnmadds, 0x1)),
-        ("crand", (gen_crand, 0x0)),
-        ("lmw", (gen_lmw, 0x0)),
-        ("beqla", (gen_beq, 0x3)),
-        ("beqlr+", (gen_beqlr, 0x200000)),
-        ("bdnzfa-", (gen_bdnzf, 0x200002)),
-        ("vmulouh", (gen_vmulouh, 0x0)),
-        ("bdzfl", (gen_bdzf, 0x1)),
-        ("bdnzfl", (gen_bdnzf, 0x1)),
-        ("bsoa+", (gen_bso, 0x200002)),
-        ("bge+", (gen_bge, 0x200000)),
-        ("vpkshus128", (gen_vpkshus128, 0x0)),
-        ("bltctr+", (gen_bltctr, 0x200000)),
-        ("vcmpeqfp128", (gen_vcmpeqfp128, 0x0)),
-        ("stdx", (gen_stdx, 0x0)),
-        ("fneg", (gen_fneg, 0x0)),
-        ("subic.", (gen_subic_, 0x0)),
-        ("vupklsh", (gen_vupklsh, 0x0)),
-        ("vmsumuhm", (gen_vmsumuhm, 0x0)),
-        ("mtdsisr", (gen_mtdsisr, 0x0)),
-        ("mulhw", (gen_mulhw, 0x0)),
-        ("vcmpgtuw", (gen_vcmpgtuw, 0x0)),
-        ("ps_mr", (gen_ps_mr, 0x0)),
-        ("bgtla-", (gen_bgt, 0x200003)),
-        ("subfeo.", (gen_subfe, 0x401)),
-        ("twgti", (gen_twgti, 0x0)),
-        ("blrl", (gen_blr, 0x1)),
-        ("vpermwi128", (gen_vpermwi128, 0x0)),
-        ("extlwi.", (gen_extlwi, 0x1)),
-        ("vcmpgtsb", (gen_vcmpgtsb, 0x0)),
-        ("rlwnm.", (gen_rlwnm, 0x1)),
-        ("vslw128", (gen_vslw128, 0x0)),
-        ("rldcr.", (gen_rldcr, 0x1)),
-        ("vpkswus128", (gen_vpkswus128, 0x0)),
-        ("addeo", (gen_adde, 0x400)),
-        ("bnsctrl", (gen_bnsctr, 0x1)),
-        ("beqa", (gen_beq, 0x2)),
-        ("bc+", (gen_bc, 0x200000)),
-        ("lvrxl", (gen_lvrxl, 0x0)),
-        ("vsubshs", (gen_vsubshs, 0x0)),
-        ("bnel", (gen_bne, 0x1)),
-        ("mttbu", (gen_mttbu, 0x0)),
-        ("tlbie", (gen_tlbie, 0x0)),
-        ("bso+", (gen_bso, 0x200000)),
-        ("mtsrr1", (gen_mtsrr1, 0x0)),
-        ("mtsprg", (gen_mtsprg, 0x0)),
-        ("fsqrt", (gen_fsqrt, 0x0)),
-        ("mftb", (gen_mftb, 0x0)),
-        ("bdzt", (gen_bdzt, 0x0)),
-        ("addze", (gen_addze, 0x0)),
-        ("stvewx", (gen_stvewx, 0x0)),
-        ("rlwimi", (gen_rlwimi, 0x0)),
-        ("mfibatl", (gen_mfibatl, 0x0)),
-        ("bdzf-", (gen_bdzf, 0x200000)),
-        ("vctsxs128", (gen_vctsxs128, 0x0)),
-        ("vrfin", (gen_vrfin, 0x0)),
-        ("cmplw", (gen_cmplw, 0x0)),
-        ("twui", (gen_twui, 0x0)),
-        ("xori", (gen_xori, 0x0)),
-        ("lwzux", (gen_lwzux, 0x0)),
-        ("bdnzfla", (gen_bdnzf, 0x3)),
-        ("bgtl", (gen_bgt, 0x1)),
-        ("fadds.", (gen_fadds, 0x1)),
-        ("vsrah", (gen_vsrah, 0x0)),
-        ("ps_nmadd", (gen_ps_nmadd, 0x0)),
-        ("ps_merge01", (gen_ps_merge01, 0x0)),
-        ("lwsync", (gen_lwsync, 0x0)),
-        ("oris", (gen_oris, 0x0)),
-        ("icbi.", (gen_icbi, 0x1)),
-        ("lvsr", (gen_lvsr, 0x0)),
-        ("vcmpgtsw.", (gen_vcmpgtsw, 0x400)),
-        ("srw", (gen_srw, 0x0)),
-        ("cntlzw", (gen_cntlzw, 0x0)),
-        ("bgectr+", (gen_bgectr, 0x200000)),
-        ("mtfsb1", (gen_mtfsb1, 0x0)),
-        ("vspltw128", (gen_vspltw128, 0x0)),
-        ("bnela-", (gen_bne, 0x200003)),
-        ("vspltisw128", (gen_vspltisw128, 0x0)),
-        ("vupkhsb", (gen_vupkhsb, 0x0)),
-        ("vsr", (gen_vsr, 0x0)),
-        ("vmrghh", (gen_vmrghh, 0x0)),
-        ("mtdbatl", (gen_mtdbatl, 0x0)),
-        ("sthu", (gen_sthu, 0x0)),
-        ("ps_res", (gen_ps_res, 0x0)),
-        ("vxor128", (gen_vxor128, 0x0)),
-        ("vcmpgefp.", (gen_vcmpgefp, 0x400)),
-        ("sld", (gen_sld, 0x0)),
-        ("bdnzta-", (gen_bdnzt, 0x200002)),
-        ("ps_madd", (gen_ps_madd, 0x0)),
-        ("icbi", (gen_icbi, 0x0)),
-        ("vaddshs", (gen_vaddshs, 0x0)),
-        ("bne-", (gen_bne, 0x200000)),
-        ("bdnz", (gen_bdnz, 0x0)),
-        ("sync", (gen_sync, 0x0)),
-        ("mtsrdin", (gen_mtsrdin, 0x0)),
-        ("bca-", (gen_bc, 0x200002)),
-        ("bdztl", (gen_bdzt, 0x1)),
-        ("extsh", (gen_extsh, 0x0)),
-        ("extlwi", (gen_extlwi, 0x0)),
-        ("beqla-", (gen_beq, 0x200003)),
-        ("divwo", (gen_divw, 0x400)),
-        ("vmrglw128", (gen_vmrglw128, 0x0)),
-        ("vupklpx", (gen_vupklpx, 0x0)),
-        ("ble+", (gen_ble, 0x200000)),
-        ("dcbz_l", (gen_dcbz_l, 0x0)),
-        ("stfs", (gen_stfs, 0x0)),
-        ("mulhwu", (gen_mulhwu, 0x0)),
-        ("fcfid.", (gen_fcfid, 0x1)),
-        ("vpkshss", (gen_vpkshss, 0x0)),
-        ("mtfsf", (gen_mtfsf, 0x0)),
-        ("fnabs", (gen_fnabs, 0x0)),
-        ("vspltisb", (gen_vspltisb, 0x0)),
-        ("andis.", (gen_andis_, 0x0)),
-        ("vcmpbfp128.", (gen_vcmpbfp128, 0x40)),
-        ("extsw.", (gen_extsw, 0x1)),
-        ("crnand", (gen_crnand, 0x0)),
-        ("mtdec", (gen_mtdec, 0x0)),
-        ("stvlx128", (gen_stvlx128, 0x0)),
-        ("vnor128", (gen_vnor128, 0x0)),
-        ("fnmsub", (gen_fnmsub, 0x0)),
-        ("dcbi", (gen_dcbi, 0x0)),
-        ("vminsb", (gen_vminsb, 0x0)),
-        ("bdnzflrl", (gen_bdnzflr, 0x1)),
-        ("ble-", (gen_ble, 0x200000)),
-        ("subfe", (gen_subfe, 0x0)),
-        ("addic", (gen_addic, 0x0)),
-        ("cmpld", (gen_cmpld, 0x0)),
-        ("vexptefp128", (gen_vexptefp128, 0x0)),
-        ("fmsub.", (gen_fmsub, 0x1)),
-        ("mtfsfi", (gen_mtfsfi, 0x0)),
-        ("bnea-", (gen_bne, 0x200002)),
-        ("ps_merge00.", (gen_ps_merge00, 0x1)),
-        ("stfsu", (gen_stfsu, 0x0)),
-        ("beqlrl+", (gen_beqlr, 0x200001)),
-        ("beql", (gen_beq, 0x1)),
-        ("slwi.", (gen_slwi, 0x1)),
-        ("mtfsb0", (gen_mtfsb0, 0x0)),
-        ("ps_merge11.", (gen_ps_merge11, 0x1)),
-        ("vcmpgtfp128", (gen_vcmpgtfp128, 0x0)),
-        ("vctuxs128", (gen_vctuxs128, 0x0)),
-        ("mcrfs", (gen_mcrfs, 0x0)),
-        ("bnsctrl+", (gen_bnsctr, 0x200001)),
-        ("addo", (gen_add, 0x400)),
-        ("ptesync", (gen_ptesync, 0x0)),
-        ("bdnztla+", (gen_bdnzt, 0x200003)),
-        ("sthx", (gen_sthx, 0x0)),
-        ("bgelrl+", (gen_bgelr, 0x200001)),
-        ("fctid", (gen_fctid, 0x0)),
-        ("extsw", (gen_extsw, 0x0)),
-        ("trap", (gen_trap, 0x0)),
-        ("vlogefp", (gen_vlogefp, 0x0)),
-        ("fcmpo", (gen_fcmpo, 0x0)),
-        ("fnmsubs.", (gen_fnmsubs, 0x1)),
-        ("vrefp", (gen_vrefp, 0x0)),
-        ("bdzfa+", (gen_bdzf, 0x200002)),
-        ("add.", (gen_add, 0x1)),
-        ("vsubcuw", (gen_vsubcuw, 0x0)),
-        ("fctiw.", (gen_fctiw, 0x1)),
-        ("srad.", (gen_srad, 0x1)),
-        ("lvxl", (gen_lvxl, 0x0)),
-        ("stfsx", (gen_stfsx, 0x0)),
-        ("rldic", (gen_rldic, 0x0)),
-        ("vand", (gen_vand, 0x0)),
-        ("vupklsh128", (gen_vupklsh128, 0x0)),
-        ("lfsu", (gen_lfsu, 0x0)),
-        ("fsub", (gen_fsub, 0x0)),
-        ("frsp.", (gen_frsp, 0x1)),
-        ("bgectrl", (gen_bgectr, 0x1)),
-        ("bdzflr", (gen_bdzflr, 0x0)),
-        ("bca", (gen_bc, 0x2)),
-        ("bdz+", (gen_bdz, 0x200000)),
-        ("vpkuwum128", (gen_vpkuwum128, 0x0)),
-        ("mtdbatu", (gen_mtdbatu, 0x0)),
-        ("bdztl+", (gen_bdzt, 0x200001)),
-        ("subfeo", (gen_subfe, 0x400)),
-        ("bgelr", (gen_bgelr, 0x0)),
-        ("vupklsb128", (gen_vupklsb128, 0x0)),
-        ("lwaux", (gen_lwaux, 0x0)),
-        ("tw", (gen_tw, 0x0)),
-        ("stdu", (gen_stdu, 0x0)),
-        ("bltlrl+", (gen_bltlr, 0x200001)),
-        ("fadd.", (gen_fadd, 0x1)),
-        ("divwo.", (gen_divw, 0x401)),
-        ("cmpdi", (gen_cmpdi, 0x0)),
-        ("psq_lu", (gen_psq_lu, 0x0)),
-        ("mfspr", (gen_mfspr, 0x0)),
-        ("bgtctrl+", (gen_bgtctr, 0x200001)),
-        ("fnmsubs", (gen_fnmsubs, 0x0)),
-        ("ps_mul.", (gen_ps_mul, 0x1)),
-        ("bgectrl+", (gen_bgectr, 0x200001)),
-        ("rldic.", (gen_rldic, 0x1)),
-        ("ps_merge11", (gen_ps_merge11, 0x0)),
-        ("bdnzl+", (gen_bdnz, 0x200001)),
-        ("mfsrr0", (gen_mfsrr0, 0x0)),
-        ("crorc", (gen_crorc, 0x0)),
-        ("vcmpgtuh.", (gen_vcmpgtuh, 0x400)),
-        ("dstt", (gen_dst, 0x2000000)),
-        ("fnmsub.", (gen_fnmsub, 0x1)),
-        ("bclr+", (gen_bclr, 0x200000)),
-        ("stwbrx", (gen_stwbrx, 0x0)),
-        ("vspltw", (gen_vspltw, 0x0)),
-        ("vcmpgtsw", (gen_vcmpgtsw, 0x0)),
-        ("subf", (gen_subf, 0x0)),
-        ("addc", (gen_addc, 0x0)),
-        ("lvsl128", (gen_lvsl128, 0x0)),
-        ("bgt", (gen_bgt, 0x0)),
-        ("subfmeo.", (gen_subfme, 0x401)),
-        ("bltctrl+", (gen_bltctr, 0x200001)),
-        ("vcmpgtsb.", (gen_vcmpgtsb, 0x400)),
-        ("fnabs.", (gen_fnabs, 0x1)),
-        ("subfo", (gen_subf, 0x400)),
-        ("fabs.", (gen_fabs, 0x1)),
-        ("bgelr+", (gen_bgelr, 0x200000)),
-        ("bsola", (gen_bso, 0x3)),
-        ("bcctr+", (gen_bcctr, 0x200000)),
-        ("bne", (gen_bne, 0x0)),
-        ("bgtlrl+", (gen_bgtlr, 0x200001)),
-        ("fneg.", (gen_fneg, 0x1)),
-        ("ecowx", (gen_ecowx, 0x0)),
-        ("lfdx", (gen_lfdx, 0x0)),
-        ("vcmpgtuw.", (gen_vcmpgtuw, 0x400)),
-        ("bdzlrl+", (gen_bdzlr, 0x200001)),
-        ("rldicl", (gen_rldicl, 0x0)),
-        ("bnelrl+", (gen_bnelr, 0x200001)),
-        ("bns+", (gen_bns, 0x200000)),
-        ("fctidz", (gen_fctidz, 0x0)),
-        ("vmrghw", (gen_vmrghw, 0x0)),
-        ("bsoctrl", (gen_bsoctr, 0x1)),
-        ("bcctrl", (gen_bcctr, 0x1)),
-        ("bgela+", (gen_bge, 0x200003)),
-        ("bdnzta", (gen_bdnzt, 0x2)),
-        ("fmadds", (gen_fmadds, 0x0)),
-        ("fmuls", (gen_fmuls, 0x0)),
-        ("bdztl-", (gen_bdzt, 0x200001)),
-        ("bdnza+", (gen_bdnz, 0x200002)),
-        ("bnslrl", (gen_bnslr, 0x1)),
-        ("bcla", (gen_bc, 0x3)),
-        ("mfibatu", (gen_mfibatu, 0x0)),
-        ("mtear", (gen_mtear, 0x0)),
-        ("vsrw128", (gen_vsrw128, 0x0)),
-        ("beq", (gen_beq, 0x0)),
-        ("lvlxl", (gen_lvlxl, 0x0)),
-        ("mullw.", (gen_mullw, 0x1)),
-        ("bdzlr+", (gen_bdzlr, 0x200000)),
-        ("bcl+", (gen_bc, 0x200001)),
-        ("bla", (gen_b, 0x3)),
-        ("vupkd3d128", (gen_vupkd3d128, 0x0)),
-        ("tdlnl", (gen_tdlnl, 0x0)),
-        ("vspltish", (gen_vspltish, 0x0)),
-        ("lfd", (gen_lfd, 0x0)),
-        ("ps_div.", (gen_ps_div, 0x1)),
-        ("lfsux", (gen_lfsux, 0x0)),
-        ("divduo.", (gen_divdu, 0x401)),
-        ("addeo.", (gen_adde, 0x401)),
-        ("rotlwi", (gen_rotlwi, 0x0)),
-        ("ps_sel", (gen_ps_sel, 0x0)),
-        ("stvehx", (gen_stvehx, 0x0)),
-        ("clrrwi", (gen_clrrwi, 0x0)),
-        ("nego.", (gen_neg, 0x401)),
-        ("twi", (gen_twi, 0x0)),
-        ("bdnztl-", (gen_bdnzt, 0x200001)),
-        ("vsrw", (gen_vsrw, 0x0)),
-        ("fnmadd", (gen_fnmadd, 0x0)),
-        ("vperm", (gen_vperm, 0x0)),
-        ("vaddfp", (gen_vaddfp, 0x0)),
-        ("mffs", (gen_mffs, 0x0)),
-        ("mflr", (gen_mflr, 0x0)),
-        ("bdzla+", (gen_bdz, 0x200003)),
-        ("crset", (gen_crset, 0x0)),
-        ("bsolrl", (gen_bsolr, 0x1)),
-        ("bdzla-", (gen_bdz, 0x200003)),
-        ("mulhd", (gen_mulhd, 0x0)),
-        ("blt+", (gen_blt, 0x200000)),
-        ("cntlzd.", (gen_cntlzd, 0x1)),
-        ("addic.", (gen_addic_, 0x0)),
-        ("bsoctr", (gen_bsoctr, 0x0)),
-        ("vmaxfp128", (gen_vmaxfp128, 0x0)),
-        ("vpkshss128", (gen_vpkshss128, 0x0)),
-        ("vmrglb", (gen_vmrglb, 0x0)),
-        ("bdzl-", (gen_bdz, 0x200001)),
-        ("xor.", (gen_xor, 0x1)),
-        ("vrfiz", (gen_vrfiz, 0x0)),
-        ("slbia", (gen_slbia, 0x0)),
-        ("stvlxl", (gen_stvlxl, 0x0)),
-        ("mtctr", (gen_mtctr, 0x0)),
-        ("dss", (gen_dss, 0x0)),
-        ("mullwo", (gen_mullw, 0x400)),
-        ("stvlxl128", (gen_stvlxl128, 0x0)),
-        ("vcmpgtub.", (gen_vcmpgtub, 0x400)),
-        ("bso", (gen_bso, 0x0)),
-        ("stbu", (gen_stbu, 0x0)),
-        ("bdnzlr+", (gen_bdnzlr, 0x200000)),
-        ("vupkhpx", (gen_vupkhpx, 0x0)),
-        ("bdztla-", (gen_bdzt, 0x200003)),
-        ("vsrb", (gen_vsrb, 0x0)),
-        ("bdnzt", (gen_bdnzt, 0x0)),
-        ("rotrwi", (gen_rotrwi, 0x0)),
-        ("divwu", (gen_divwu, 0x0)),
-        ("vcmpequw", (gen_vcmpequw, 0x0)),
-        ("bc", (gen_bc, 0x0)),
-        ("mtmsrd", (gen_mtmsrd, 0x0)),
-        ("bsola+", (gen_bso, 0x200003)),
-        ("mtfsfi.", (gen_mtfsfi, 0x1)),
-        ("rldimi.", (gen_rldimi, 0x1)),
-        ("bcctrl+", (gen_bcctr, 0x200001)),
-        ("bnelrl", (gen_bnelr, 0x1)),
-        ("bclr", (gen_bclr, 0x0)),
-        ("psq_l", (gen_psq_l, 0x0)),
-        ("nand.", (gen_nand, 0x1)),
-        ("bdzflr+", (gen_bdzflr, 0x200000)),
-        ("clrrwi.", (gen_clrrwi, 0x1)),
-        ("mulhd.", (gen_mulhd, 0x1)),
-        ("clrlslwi.", (gen_clrlslwi, 0x1)),
-        ("vnor", (gen_vnor, 0x0)),
-        ("bltlr+", (gen_bltlr, 0x200000)),
-        ("vadduwm", (gen_vadduwm, 0x0)),
-        ("ps_rsqrte.", (gen_ps_rsqrte, 0x1)),
-        ("srw.", (gen_srw, 0x1)),
-        ("vcmpbfp", (gen_vcmpbfp, 0x0)),
-        ("subfmeo", (gen_subfme, 0x400)),
-        ("bdnztlrl", (gen_bdnztlr, 0x1)),
-        ("bdnza", (gen_bdnz, 0x2)),
-        ("or", (gen_or, 0x0)),
-        ("rotld.", (gen_rotld, 0x1)),
-        ("bgtl+", (gen_bgt, 0x200001)),
-        ("lvrxl128", (gen_lvrxl128, 0x0)),
-        ("bsoctrl+", (gen_bsoctr, 0x200001)),
-        ("addzeo.", (gen_addze, 0x401)),
-        ("beqlrl", (gen_beqlr, 0x1)),
-        ("bgta-", (gen_bgt, 0x200002)),
-        ("mtmsr", (gen_mtmsr, 0x0)),
-        ("fmul", (gen_fmul, 0x0)),
-        ("ldarx", (gen_ldarx, 0x0)),
-        ("vaddsws", (gen_vaddsws, 0x0)),
-        ("adde.", (gen_adde, 0x1)),
-        ("mfcr", (gen_mfcr, 0x0)),
-        ("divduo", (gen_divdu, 0x400)),
-        ("vpkpx", (gen_vpkpx, 0x0)),
-        ("mfdsisr", (gen_mfdsisr, 0x0)),
-        ("blea+", (gen_ble, 0x200002)),
-        ("mfdbatl", (gen_mfdbatl, 0x0)),
-        ("mulli", (gen_mulli, 0x0)),
-        ("blea-", (gen_ble, 0x200002)),
-        ("cror", (gen_cror, 0x0)),
-        ("bgea", (gen_bge, 0x2)),
-        ("vrfiz128", (gen_vrfiz128, 0x0)),
-        ("bdzt-", (gen_bdzt, 0x200000)),
-        ("sraw", (gen_sraw, 0x0)),
-        ("vpkshus", (gen_vpkshus, 0x0)),
-        ("vsel128", (gen_vsel128, 0x0)),
-        ("vnmsubfp", (gen_vnmsubfp, 0x0)),
-        ("vrlb", (gen_vrlb, 0x0)),
-        ("tlbsync", (gen_tlbsync, 0x0)),
-        ("sradi.", (gen_sradi, 0x1)),
-        ("vmaxub", (gen_vmaxub, 0x0)),
-        ("fdiv.", (gen_fdiv, 0x1)),
-        ("vslw", (gen_vslw, 0x0)),
-        ("subfme", (gen_subfme, 0x0)),
-        ("mfear", (gen_mfear, 0x0)),
-        ("rldicl.", (gen_rldicl, 0x1)),
-        ("beqctrl+", (gen_beqctr, 0x200001)),
-        ("vminsw", (gen_vminsw, 0x0)),
-        ("ps_cmpo0", (gen_ps_cmpo0, 0x0)),
-        ("vrlw", (gen_vrlw, 0x0)),
-        ("mtsrin", (gen_mtsrin, 0x0)),
-        ("fsubs.", (gen_fsubs, 0x1)),
-        ("rldcl", (gen_rldcl, 0x0)),
-        ("bdnztl", (gen_bdnzt, 0x1)),
-        ("fctidz.", (gen_fctidz, 0x1)),
-        ("ps_sum0", (gen_ps_sum0, 0x0)),
-        ("frsqrte.", (gen_frsqrte, 0x1)),
-        ("bsolr+", (gen_bsolr, 0x200000)),
-        ("vaddsbs", (gen_vaddsbs, 0x0)),
-        ("bnela", (gen_bne, 0x3)),
-        ("rotlwi.", (gen_rotlwi, 0x1)),
-        ("vsum4sbs", (gen_vsum4sbs, 0x0)),
-        ("vmuloub", (gen_vmuloub, 0x0)),
-        ("vpkswus", (gen_vpkswus, 0x0)),
-        ("bgtl-", (gen_bgt, 0x200001)),
-        ("vcmpgefp", (gen_vcmpgefp, 0x0)),
-        ("dcbzl", (gen_dcbzl, 0x0)),
-        ("mulld.", (gen_mulld, 0x1)),
-        ("slbie", (gen_slbie, 0x0)),
-        ("subfo.", (gen_subf, 0x401)),
-        ("bnsl", (gen_bns, 0x1)),
-        ("stbx", (gen_stbx, 0x0)),
-        ("mulldo.", (gen_mulld, 0x401)),
-        ("vrfim128", (gen_vrfim128, 0x0)),
-        ("vcmpgefp128.", (gen_vcmpgefp128, 0x40)),
-        ("mfvscr", (gen_mfvscr, 0x0)),
-        ("mfdar", (gen_mfdar, 0x0)),
-        ("vsro128", (gen_vsro128, 0x0)),
-        ("mulld", (gen_mulld, 0x0)),
-        ("vandc", (gen_vandc, 0x0)),
-        ("bdzta", (gen_bdzt, 0x2)),
-        ("stwu", (gen_stwu, 0x0)),
-        ("addc.", (gen_addc, 0x1)),
-        ("tdi", (gen_tdi, 0x0)),
-        ("ps_muls1.", (gen_ps_muls1, 0x1)),
-        ("bgt-", (gen_bgt, 0x200000)),
-        ("vaddcuw", (gen_vaddcuw, 0x0)),
-        ("lvebx", (gen_lvebx, 0x0)),
-        ("vadduws", (gen_vadduws, 0x0)),
-        ("bdnzflr", (gen_bdnzflr, 0x0)),
-        ("vminfp", (gen_vminfp, 0x0)),
-        ("lbzux", (gen_lbzux, 0x0)),
-        ("vcmpeqfp128.", (gen_vcmpeqfp128, 0x40)),
-        ("blectr", (gen_blectr, 0x0)),
-        ("addmeo.", (gen_addme, 0x401)),
-        ("vmsumuhs", (gen_vmsumuhs, 0x0)),
-        ("vavguh", (gen_vavguh, 0x0)),
-        ("vrefp128", (gen_vrefp128, 0x0)),
-        ("rotrwi.", (gen_rotrwi, 0x1)),
-        ("bsol", (gen_bso, 0x1)),
-        ("orc", (gen_orc, 0x0)),
-        ("vslo", (gen_vslo, 0x0)),
-        ("vsrh", (gen_vsrh, 0x0)),
-        ("vslb", (gen_vslb, 0x0)),
-        ("addi", (gen_addi, 0x0)),
-        ("lhzu", (gen_lhzu, 0x0)),
-        ("eqv.", (gen_eqv, 0x1)),
-        ("fctiwz", (gen_fctiwz, 0x0)),
-        ("vsraw128", (gen_vsraw128, 0x0)),
-        ("cmpli", (gen_cmpli, 0x0)),
-        ("vrlw128", (gen_vrlw128, 0x0)),
-        ("ps_madd.", (gen_ps_madd, 0x1)),
-        ("bnel+", (gen_bne, 0x200001)),
-        ("ps_madds0.", (gen_ps_madds0, 0x1)),
-        ("vcfux", (gen_vcfux, 0x0)),
-        ("bns", (gen_bns, 0x0)),
-        ("bgt+", (gen_bgt, 0x200000)),
-        ("extrwi.", (gen_extrwi, 0x1)),
-        ("bnslrl+", (gen_bnslr, 0x200001)),
-        ("lfdu", (gen_lfdu, 0x0)),
-        ("vrfip128", (gen_vrfip128, 0x0)),
-        ("vmsum3fp128", (gen_vmsum3fp128, 0x0)),
-        ("bnsla", (gen_bns, 0x3)),
-        ("vandc128", (gen_vandc128, 0x0)),
-        ("lha", (gen_lha, 0x0)),
-        ("stvxl", (gen_stvxl, 0x0)),
-        ("bgelrl", (gen_bgelr, 0x1)),
-        ("ps_res.", (gen_ps_res, 0x1)),
-        ("bcla-", (gen_bc, 0x200003)),
-        ("bdz-", (gen_bdz, 0x200000)),
-        ("lbzu", (gen_lbzu, 0x0)),
-        ("lwzx", (gen_lwzx, 0x0)),
-        ("bdzfa", (gen_bdzf, 0x2)),
-        ("vsl", (gen_vsl, 0x0)),
-        ("vslo128", (gen_vslo128, 0x0)),
-        ("bsolrl+", (gen_bsolr, 0x200001)),
-        ("ps_nmsub.", (gen_ps_nmsub, 0x1)),
-        ("bgtla+", (gen_bgt, 0x200003)),
-        ("subfzeo", (gen_subfze, 0x400)),
-        ("bsolr", (gen_bsolr, 0x0)),
-        ("ps_div", (gen_ps_div, 0x0)),
-        ("cmpi", (gen_cmpi, 0x0)),
-        ("srd.", (gen_srd, 0x1)),
-        ("vpkd3d128", (gen_vpkd3d128, 0x0)),
-        ("crnot", (gen_crnot, 0x0)),
-        ("blela-", (gen_ble, 0x200003)),
-        ("vavgsw", (gen_vavgsw, 0x0)),
-        ("addze.", (gen_addze, 0x1)),
-        ("psq_st", (gen_psq_st, 0x0)),
-        ("bdzflrl", (gen_bdzflr, 0x1)),
-        ("stvrx", (gen_stvrx, 0x0)),
-        ("xor", (gen_xor, 0x0)),
-        ("bnea+", (gen_bne, 0x200002)),
-        ("bdnzf-", (gen_bdnzf, 0x200000)),
-        ("addzeo", (gen_addze, 0x400)),
-        ("divdu", (gen_divdu, 0x0)),
-        ("blr", (gen_blr, 0x0)),
-        ("lvrx128", (gen_lvrx128, 0x0)),
-        ("srwi.", (gen_srwi, 0x1)),
-        ("bdnzt+", (gen_bdnzt, 0x200000)),
-        ("andc", (gen_andc, 0x0)),
-        ("vminub", (gen_vminub, 0x0)),
-        ("vnot", (gen_vnot, 0x0)),
-        ("mulhdu.", (gen_mulhdu, 0x1)),
-        ("bnectr+", (gen_bnectr, 0x200000)),
-        ("vsum4shs", (gen_vsum4shs, 0x0)),
-        ("bdz", (gen_bdz, 0x0)),
-        ("bdztla+", (gen_bdzt, 0x200003)),
-        ("vsldoi128", (gen_vsldoi128, 0x0)),
-        ("nor", (gen_nor, 0x0)),
-        ("blelrl", (gen_blelr, 0x1)),
-        ("lvlxl128", (gen_lvlxl128, 0x0)),
-        ("stvrxl", (gen_stvrxl, 0x0)),
-        ("isync", (gen_isync, 0x0)),
-        ("ps_cmpu1", (gen_ps_cmpu1, 0x0)),
-        ("mtspr", (gen_mtspr, 0x0)),
-        ("mttbl", (gen_mttbl, 0x0)),
-        ("vsububs", (gen_vsububs, 0x0)),
-        ("bdnzfla-", (gen_bdnzf, 0x200003)),
-        ("crclr", (gen_crclr, 0x0)),
-        ("crandc", (gen_crandc, 0x0)),
-        ("extsb.", (gen_extsb, 0x1)),
-        ("mfsdr1", (gen_mfsdr1, 0x0)),
-        ("ps_sum1.", (gen_ps_sum1, 0x1)),
-        ("bcla+", (gen_bc, 0x200003)),
-        ("lvx128", (gen_lvx128, 0x0)),
-        ("bdzl", (gen_bdz, 0x1)),
-        ("sthbrx", (gen_sthbrx, 0x0)),
-        ("vavgub", (gen_vavgub, 0x0)),
-        ("bgtlr+", (gen_bgtlr, 0x200000)),
-        ("mullwo.", (gen_mullw, 0x401)),
-        ("bdnzlrl", (gen_bdnzlr, 0x1)),
-        ("rldcl.", (gen_rldcl, 0x1)),
-        ("bsoa-", (gen_bso, 0x200002)),
-        ("blt-", (gen_blt, 0x200000)),
-        ("vrfim", (gen_vrfim, 0x0)),
-        ("ps_merge01.", (gen_ps_merge01, 0x1)),
-        ("lvehx", (gen_lvehx, 0x0)),
-        ("blela", (gen_ble, 0x3)),
-        ("vsubsws", (gen_vsubsws, 0x0)),
-        ("vadduhm", (gen_vadduhm, 0x0)),
-        ("fmuls.", (gen_fmuls, 0x1)),
-        ("andi.", (gen_andi_, 0x0)),
-        ("bdnzlr", (gen_bdnzlr, 0x0)),
-        ("lhax", (gen_lhax, 0x0)),
-        ("vor128", (gen_vor128, 0x0)),
-        ("stswx", (gen_stswx, 0x0)),
-        ("vmaddcfp128", (gen_vmaddcfp128, 0x0)),
-        ("mr", (gen_mr, 0x0)),
-        ("vavgsb", (gen_vavgsb, 0x0)),
-        ("nop", (gen_nop, 0x0)),
-        ("stfd", (gen_stfd, 0x0)),
-        ("vmaddfp128", (gen_vmaddfp128, 0x0)),
-        ("stfdu", (gen_stfdu, 0x0)),
-        ("nego", (gen_neg, 0x400)),
-        ("mtsdr1", (gen_mtsdr1, 0x0)),
-        ("blta-", (gen_blt, 0x200002)),
-        ("ps_neg.", (gen_ps_neg, 0x1)),
-        ("andc.", (gen_andc, 0x1)),
-        ("subi", (gen_subi, 0x0)),
-        ("bsola-", (gen_bso, 0x200003)),
-        ("vmhraddshs", (gen_vmhraddshs, 0x0)),
-        ("ldx", (gen_ldx, 0x0)),
-        ("bcctr", (gen_bcctr, 0x0)),
-        ("vcmpgtfp", (gen_vcmpgtfp, 0x0)),
-        ("addo.", (gen_add, 0x401)),
-        ("vmsumshm", (gen_vmsumshm, 0x0)),
-        ("bltl+", (gen_blt, 0x200001)),
-        ("vupkhsh", (gen_vupkhsh, 0x0)),
-        ("bltctr", (gen_bltctr, 0x0)),
-        ("fmr.", (gen_fmr, 0x1)),
-        ("vmsummbm", (gen_vmsummbm, 0x0)),
-        ("vmr", (gen_vmr, 0x0)),
-        ("bdnzf", (gen_bdnzf, 0x0)),
-        ("bdnztl+", (gen_bdnzt, 0x200001)),
-        ("frsqrte", (gen_frsqrte, 0x0)),
-        ("vslh", (gen_vslh, 0x0)),
-        ("rotlw", (gen_rotlw, 0x0)),
-        ("rotlw.", (gen_rotlw, 0x1)),
-        ("mffs.", (gen_mffs, 0x1)),
-        ("srad", (gen_srad, 0x0)),
-        ("ps_madds0", (gen_ps_madds0, 0x0)),
-        ("bdzfl-", (gen_bdzf, 0x200001)),
-        ("slwi", (gen_slwi, 0x0)),
-        ("lfdux", (gen_lfdux, 0x0)),
-        ("xoris", (gen_xoris, 0x0)),
-        ("sld.", (gen_sld, 0x1)),
-        ("bdnztlrl+", (gen_bdnztlr, 0x200001)),
-        ("fdivs.", (gen_fdivs, 0x1)),
-        ("divwu.", (gen_divwu, 0x1)),
-        ("vcmpequw128", (gen_vcmpequw128, 0x0)),
-        ("rfid", (gen_rfid, 0x0)),
-        ("bclrl", (gen_bclr, 0x1)),
-        ("vsubfp128", (gen_vsubfp128, 0x0)),
-        ("mulhwu.", (gen_mulhwu, 0x1)),
-        ("bns-", (gen_bns, 0x200000)),
-        ("blectrl+", (gen_blectr, 0x200001)),
-        ("bdzfla-", (gen_bdzf, 0x200003)),
-        ("blelr", (gen_blelr, 0x0)),
-        ("stvlx", (gen_stvlx, 0x0)),
-        ("ps_nabs.", (gen_ps_nabs, 0x1)),
-        ("stwcx.", (gen_stwcx_, 0x0)),
-        ("vpkuwus128", (gen_vpkuwus128, 0x0)),
-        ("nor.", (gen_nor, 0x1)),
-        ("blel", (gen_ble, 0x1)),
-        ("slw.", (gen_slw, 0x1)),
-        ("stvewx128", (gen_stvewx128, 0x0)),
-        ("bgela", (gen_bge, 0x3)),
-        ("ba", (gen_b, 0x2)),
-        ("ps_muls0", (gen_ps_muls0, 0x0)),
-        ("vmuleuh", (gen_vmuleuh, 0x0)),
-        ("subfze", (gen_subfze, 0x0)),
-        ("vcmpgtsh.", (gen_vcmpgtsh, 0x400)),
-        ("bnelr+", (gen_bnelr, 0x200000)),
-        ("mulhw.", (gen_mulhw, 0x1)),
-        ("bsol+", (gen_bso, 0x200001)),
-        ("fmadd", (gen_fmadd, 0x0)),
-        ("bdnzlrl+", (gen_bdnzlr, 0x200001)),
-        ("vspltb", (gen_vspltb, 0x0)),
-        ("bdnzfa", (gen_bdnzf, 0x2)),
-        ("beqa-", (gen_beq, 0x200002)),
-        ("vsubuhs", (gen_vsubuhs, 0x0)),
-        ("stb", (gen_stb, 0x0)),
-        ("vadduhs", (gen_vadduhs, 0x0)),
-        ("lhaux", (gen_lhaux, 0x0)),
-        ("bgel", (gen_bge, 0x1)),
-        ("vrfip", (gen_vrfip, 0x0)),
-        ("vrsqrtefp128", (gen_vrsqrtefp128, 0x0)),
-        ("beq-", (gen_beq, 0x200000)),
-        ("stvrxl128", (gen_stvrxl128, 0x0)),
-        ("bge", (gen_bge, 0x0)),
-        ("bdzf", (gen_bdzf, 0x0)),
-        ("vpkuhus", (gen_vpkuhus, 0x0)),
-        ("dststt", (gen_dstst, 0x2000000)),
-        ("bltla+", (gen_blt, 0x200003)),
-        ("bdnzl-", (gen_bdnz, 0x200001)),
-        ("ori", (gen_ori, 0x0)),
-        ("vpkswss", (gen_vpkswss, 0x0)),
-        ("bdzf+", (gen_bdzf, 0x200000)),
-        ("bnectrl+", (gen_bnectr, 0x200001)),
-        ("divd", (gen_divd, 0x0)),
-        ("blectrl", (gen_blectr, 0x1)),
-        ("subfco.", (gen_subfc, 0x401)),
-        ("vcmpgtfp.", (gen_vcmpgtfp, 0x400)),
-        ("ps_add", (gen_ps_add, 0x0)),
-        ("vavgsh", (gen_vavgsh, 0x0)),
-        ("bdnzfl-", (gen_bdnzf, 0x200001)),
-        ("stvebx", (gen_stvebx, 0x0)),
-        ("ps_cmpo1", (gen_ps_cmpo1, 0x0)),
-        ("subfc", (gen_subfc, 0x0)),
-        ("bnslr", (gen_bnslr, 0x0)),
-        ("bdztlr", (gen_bdztlr, 0x0)),
-        ("bdzfl+", (gen_bdzf, 0x200001)),
-        ("bltl-", (gen_blt, 0x200001)),
-        ("bltla-", (gen_blt, 0x200003)),
-        ("mtibatu", (gen_mtibatu, 0x0)),
-        ("ps_cmpu0", (gen_ps_cmpu0, 0x0)),
-        ("crxor", (gen_crxor, 0x0)),
-        ("fmsubs", (gen_fmsubs, 0x0)),
-        ("bltctrl", (gen_bltctr, 0x1)),
-        ("vcmpeqfp", (gen_vcmpeqfp, 0x0)),
-        ("vpkuhum", (gen_vpkuhum, 0x0)),
-        ("vsrab", (gen_vsrab, 0x0)),
-        ("lvxl128", (gen_lvxl128, 0x0)),
-        ("adde", (gen_adde, 0x0)),
-        ("vcfsx128", (gen_vcfsx128, 0x0)),
-        ("vcmpgtub", (gen_vcmpgtub, 0x0)),
-        ("vaddubs", (gen_vaddubs, 0x0)),
-        ("cmpd", (gen_cmpd, 0x0)),
-        ("blta", (gen_blt, 0x2)),
-        ("bc-", (gen_bc, 0x200000)),
-        ("vupklsb", (gen_vupklsb, 0x0)),
-        ("vor", (gen_vor, 0x0)),
-        ("lwz", (gen_lwz, 0x0)),
-        ("neg", (gen_neg, 0x0)),
-        ("fsqrt.", (gen_fsqrt, 0x1)),
-        ("extsh.", (gen_extsh, 0x1)),
-        ("orc.", (gen_orc, 0x1)),
-        ("vpkuwum", (gen_vpkuwum, 0x0)),
-        ("srd", (gen_srd, 0x0)),
-        ("extsb", (gen_extsb, 0x0)),
-        ("vcmpgefp128", (gen_vcmpgefp128, 0x0)),
-        ("sraw.", (gen_sraw, 0x1)),
-        ("bl", (gen_b, 0x1)),
-        ("bnsctr+", (gen_bnsctr, 0x200000)),
-        ("mtfsb1.", (gen_mtfsb1, 0x1)),
-        ("vcmpequh", (gen_vcmpequh, 0x0)),
-        ("bdnzt-", (gen_bdnzt, 0x200000)),
-        ("vpkswss128", (gen_vpkswss128, 0x0)),
-        ("fmr", (gen_fmr, 0x0)),
-        ("subfme.", (gen_subfme, 0x1)),
-        ("bcl", (gen_bc, 0x1)),
-        ("clrlwi.", (gen_clrlwi, 0x1)),
-        ("mfxer", (gen_mfxer, 0x0)),
-        ("ld", (gen_ld, 0x0)),
-        ("td", (gen_td, 0x0)),
-        ("ble", (gen_ble, 0x0)),
-        ("blt", (gen_blt, 0x0)),
-        ("and", (gen_and, 0x0)),
-        ("mtlr", (gen_mtlr, 0x0)),
-        ("vxor", (gen_vxor, 0x0)),
-        ("fsel", (gen_fsel, 0x0)),
-        ("mfdec", (gen_mfdec, 0x0)),
-        ("ps_merge10", (gen_ps_merge10, 0x0)),
-        ("vsraw", (gen_vsraw, 0x0)),
-        ("bdnz+", (gen_bdnz, 0x200000)),
-        ("lswx", (gen_lswx, 0x0)),
-        ("vsum2sws", (gen_vsum2sws, 0x0)),
-        ("bsoctr+", (gen_bsoctr, 0x200000)),
-        ("bnslr+", (gen_bnslr, 0x200000)),
-        ("ps_nabs", (gen_ps_nabs, 0x0)),
-        ("lwarx", (gen_lwarx, 0x0)),
-        ("vaddubm", (gen_vaddubm, 0x0)),
-        ("lhbrx", (gen_lhbrx, 0x0)),
-        ("stvrx128", (gen_stvrx128, 0x0)),
-        ("stvxl128", (gen_stvxl128, 0x0)),
-        ("divdu.", (gen_divdu, 0x1)),
-        ("bso-", (gen_bso, 0x200000)),
-        ("bclrl+", (gen_bclr, 0x200001)),
-        ("fres.", (gen_fres, 0x1)),
-        ("divwuo", (gen_divwu, 0x400)),
-        ("bdnzl", (gen_bdnz, 0x1)),
-        ("beqctr+", (gen_beqctr, 0x200000)),
-        ("stmw", (gen_stmw, 0x0)),
-        ("cmp", (gen_cmp, 0x0)),
-        ("vpkuhum128", (gen_vpkuhum128, 0x0)),
-        ("bdnzflrl+", (gen_bdnzflr, 0x200001)),
-        ("vcmpbfp128", (gen_vcmpbfp128, 0x0)),
-        ("vsumsws", (gen_vsumsws, 0x0)),
-        ("extrwi", (gen_extrwi, 0x0)),
-        ("li", (gen_li, 0x0)),
-        ("ps_sum0.", (gen_ps_sum0, 0x1)),
-        ("stw", (gen_stw, 0x0)),
-        ("vcmpeqfp.", (gen_vcmpeqfp, 0x400)),
-        ("sc", (gen_sc, 0x0)),
-        ("mtfsb0.", (gen_mtfsb0, 0x1)),
-        ("vctuxs", (gen_vctuxs, 0x0)),
-        ("subf.", (gen_subf, 0x1)),
-        ("mcrf", (gen_mcrf, 0x0)),
-        ("blel-", (gen_ble, 0x200001)),
-        ("lfs", (gen_lfs, 0x0)),
-        ("mfocrf", (gen_mfocrf, 0x0)),
-        ("sthux", (gen_sthux, 0x0)),
-        ("lvsr128", (gen_lvsr128, 0x0)),
-        ("beqla+", (gen_beq, 0x200003)),
-        ("fmadds.", (gen_fmadds, 0x1)),
-        ("vexptefp", (gen_vexptefp, 0x0)),
-        ("stbux", (gen_stbux, 0x0)),
-        ("ps_nmadd.", (gen_ps_nmadd, 0x1)),
-        ("mcrxr", (gen_mcrxr, 0x0)),
-        ("bdnzla-", (gen_bdnz, 0x200003)),
-        ("bnea", (gen_bne, 0x2)),
-        ("ps_muls0.", (gen_ps_muls0, 0x1)),
-        ("cntlzw.", (gen_cntlzw, 0x1)),
-        ("mtsr", (gen_mtsr, 0x0)),
-        ("vlogefp128", (gen_vlogefp128, 0x0)),
-        ("vcmpequw.", (gen_vcmpequw, 0x400)),
-        ("bnsa", (gen_bns, 0x2)),
-        ("blta+", (gen_blt, 0x200002)),
-        ("divdo", (gen_divd, 0x400)),
-        ("vavguw", (gen_vavguw, 0x0)),
-        ("vmaxsb", (gen_vmaxsb, 0x0)),
-        ("vand128", (gen_vand128, 0x0)),
-        ("vnmsubfp128", (gen_vnmsubfp128, 0x0)),
-        ("fctiwz.", (gen_fctiwz, 0x1)),
-        ("vrlh", (gen_vrlh, 0x0)),
-        ("stfdux", (gen_stfdux, 0x0)),
-        ("vupkhsb128", (gen_vupkhsb128, 0x0)),
-        ("vmrglh", (gen_vmrglh, 0x0)),
-        ("bdztla", (gen_bdzt, 0x3)),
-        ("bnsctr", (gen_bnsctr, 0x0)),
-        ("fnmadds", (gen_fnmadds, 0x0)),
-        ("slw", (gen_slw, 0x0)),
-        ("stfsux", (gen_stfsux, 0x0)),
-        ("cmpwi", (gen_cmpwi, 0x0)),
-        ("rlwinm.", (gen_rlwinm, 0x1)),
-        ("beql+", (gen_beq, 0x200001)),
-        ("fmul.", (gen_fmul, 0x1)),
-        ("bdza-", (gen_bdz, 0x200002)),
-        ("fsel.", (gen_fsel, 0x1)),
-        ("mtdar", (gen_mtdar, 0x0)),
-        ("lwbrx", (gen_lwbrx, 0x0)),
-        ("eciwx", (gen_eciwx, 0x0)),
-        ("vmrghb", (gen_vmrghb, 0x0)),
-        ("psq_stx", (gen_psq_stx, 0x0)),
-        ("vmsumshs", (gen_vmsumshs, 0x0)),
-        ("bne+", (gen_bne, 0x200000)),
-        ("bnsla+", (gen_bns, 0x200003)),
-        ("sth", (gen_sth, 0x0)),
-        ("fctiw", (gen_fctiw, 0x0)),
-        ("dst", (gen_dst, 0x0)),
-        ("vmuleub", (gen_vmuleub, 0x0)),
-        ("srawi", (gen_srawi, 0x0)),
-        ("ps_sum1", (gen_ps_sum1, 0x0)),
+        ("dstst", (gen_dstst, 0x0, None)),
+        ("rotld", (gen_rotld, 0x0, None)),
+        ("vrfin128", (gen_vrfin128, 0x0, None)),
+        ("bdzla", (gen_bdz, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("bnectr", (gen_bnectr, 0x0, None)),
+        ("blelrl+", (gen_blelr, 0x200001, None)),
+        ("vcmpbfp.", (gen_vcmpbfp, 0x400, None)),
+        ("bdnztlr+", (gen_bdnztlr, 0x200000, None)),
+        ("vperm128", (gen_vperm128, 0x0, None)),
+        ("beqa+", (gen_beq, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("ldux", (gen_ldux, 0x0, None)),
+        ("neg.", (gen_neg, 0x1, None)),
+        ("ps_merge00", (gen_ps_merge00, 0x0, None)),
+        ("tdlti", (gen_tdlti, 0x0, None)),
+        ("ps_nmsub", (gen_ps_nmsub, 0x0, None)),
+        ("psq_lux", (gen_psq_lux, 0x0, None)),
+        ("lswi", (gen_lswi, 0x0, None)),
+        ("vminsh", (gen_vminsh, 0x0, None)),
+        ("bgtlr", (gen_bgtlr, 0x0, None)),
+        ("mtvscr", (gen_mtvscr, 0x0, None)),
+        ("fmadd.", (gen_fmadd, 0x1, None)),
+        ("ldu", (gen_ldu, 0x0, None)),
+        ("lhzux", (gen_lhzux, 0x0, None)),
+        ("crmove", (gen_crmove, 0x0, None)),
+        ("ps_muls1", (gen_ps_muls1, 0x0, None)),
+        ("addme.", (gen_addme, 0x1, None)),
+        ("bnel-", (gen_bne, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("ps_sel.", (gen_ps_sel, 0x1, None)),
+        ("dcbst", (gen_dcbst, 0x0, None)),
+        ("dssall", (gen_dssall, 0x0, None)),
+        ("mfsr", (gen_mfsr, 0x0, None)),
+        ("vsubuws", (gen_vsubuws, 0x0, None)),
+        ("fsqrts.", (gen_fsqrts, 0x1, None)),
+        ("bgta+", (gen_bgt, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("vrlimi128", (gen_vrlimi128, 0x0, None)),
+        ("fctid.", (gen_fctid, 0x1, None)),
+        ("dcbf", (gen_dcbf, 0x0, None)),
+        ("cmpldi", (gen_cmpldi, 0x0, None)),
+        ("bctr", (gen_bctr, 0x0, None)),
+        ("vmulfp128", (gen_vmulfp128, 0x0, None)),
+        ("bdnztla", (gen_bdnzt, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("bcl-", (gen_bc, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("bgea+", (gen_bge, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("bgel-", (gen_bge, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("twllei", (gen_twllei, 0x0, None)),
+        ("mtsrd", (gen_mtsrd, 0x0, None)),
+        ("vmulosb", (gen_vmulosb, 0x0, None)),
+        ("addme", (gen_addme, 0x0, None)),
+        ("divd.", (gen_divd, 0x1, None)),
+        ("lhz", (gen_lhz, 0x0, None)),
+        ("vmhaddshs", (gen_vmhaddshs, 0x0, None)),
+        ("creqv", (gen_creqv, 0x0, None)),
+        ("vsldoi", (gen_vsldoi, 0x0, None)),
+        ("bgtctr+", (gen_bgtctr, 0x200000, None)),
+        ("bdnzfl+", (gen_bdnzf, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("stvx", (gen_stvx, 0x0, None)),
+        ("stdcx.", (gen_stdcx_, 0x0, None)),
+        ("vmladduhm", (gen_vmladduhm, 0x0, None)),
+        ("beqctr", (gen_beqctr, 0x0, None)),
+        ("stfiwx", (gen_stfiwx, 0x0, None)),
+        ("addco", (gen_addc, 0x400, None)),
+        ("ps_msub.", (gen_ps_msub, 0x1, None)),
+        ("vcmpgtuh", (gen_vcmpgtuh, 0x0, None)),
+        ("subfze.", (gen_subfze, 0x1, None)),
+        ("ps_merge10.", (gen_ps_merge10, 0x1, None)),
+        ("ps_rsqrte", (gen_ps_rsqrte, 0x0, None)),
+        ("fdivs", (gen_fdivs, 0x0, None)),
+        ("bgta", (gen_bgt, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("divwuo.", (gen_divwu, 0x401, None)),
+        ("subic", (gen_subic, 0x0, None)),
+        ("lwzu", (gen_lwzu, 0x0, None)),
+        ("fadd", (gen_fadd, 0x0, None)),
+        ("lwax", (gen_lwax, 0x0, None)),
+        ("fabs", (gen_fabs, 0x0, None)),
+        ("beq+", (gen_beq, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("bdnzla+", (gen_bdnz, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("vmaxuw", (gen_vmaxuw, 0x0, None)),
+        ("lvlx128", (gen_lvlx128, 0x0, None)),
+        ("fsubs", (gen_fsubs, 0x0, None)),
+        ("vmaxsw", (gen_vmaxsw, 0x0, None)),
+        ("vmsumubm", (gen_vmsumubm, 0x0, None)),
+        ("fcfid", (gen_fcfid, 0x0, None)),
+        ("bnela+", (gen_bne, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("addis", (gen_addis, 0x0, None)),
+        ("vmulosh", (gen_vmulosh, 0x0, None)),
+        ("bdztlrl+", (gen_bdztlr, 0x200001, None)),
+        ("ps_madds1", (gen_ps_madds1, 0x0, None)),
+        ("bdzlr", (gen_bdzlr, 0x0, None)),
+        ("vmaddfp", (gen_vmaddfp, 0x0, None)),
+        ("vcmpequb.", (gen_vcmpequb, 0x400, None)),
+        ("bgel+", (gen_bge, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("bnelr", (gen_bnelr, 0x0, None)),
+        ("stwx", (gen_stwx, 0x0, None)),
+        ("lbzx", (gen_lbzx, 0x0, None)),
+        ("vmulesb", (gen_vmulesb, 0x0, None)),
+        ("bdza", (gen_bdz, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("lvewx128", (gen_lvewx128, 0x0, None)),
+        ("psq_lx", (gen_psq_lx, 0x0, None)),
+        ("psq_stu", (gen_psq_stu, 0x0, None)),
+        ("vsububm", (gen_vsububm, 0x0, None)),
+        ("srawi.", (gen_srawi, 0x1, None)),
+        ("subfic", (gen_subfic, 0x0, None)),
+        ("vcfsx", (gen_vcfsx, 0x0, None)),
+        ("ps_mul", (gen_ps_mul, 0x0, None)),
+        ("rldimi", (gen_rldimi, 0x0, None)),
+        ("stwux", (gen_stwux, 0x0, None)),
+        ("fres", (gen_fres, 0x0, None)),
+        ("vsel", (gen_vsel, 0x0, None)),
+        ("bdzta+", (gen_bdzt, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("vrsqrtefp", (gen_vrsqrtefp, 0x0, None)),
+        ("dcbz", (gen_dcbz, 0x0, None)),
+        ("vspltisw", (gen_vspltisw, 0x0, None)),
+        ("vcmpequh.", (gen_vcmpequh, 0x400, None)),
+        ("ps_mr.", (gen_ps_mr, 0x1, None)),
+        ("rlwnm", (gen_rlwnm, 0x0, None)),
+        ("rldicr", (gen_rldicr, 0x0, None)),
+        ("vmaxsh", (gen_vmaxsh, 0x0, None)),
+        ("bgectr", (gen_bgectr, 0x0, None)),
+        ("subfco", (gen_subfc, 0x400, None)),
+        ("stvx128", (gen_stvx128, 0x0, None)),
+        ("fdiv", (gen_fdiv, 0x0, None)),
+        ("fsqrts", (gen_fsqrts, 0x0, None)),
+        ("rldicr.", (gen_rldicr, 0x1, None)),
+        ("vsum4ubs", (gen_vsum4ubs, 0x0, None)),
+        ("bge-", (gen_bge, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("ps_abs.", (gen_ps_abs, 0x1, None)),
+        ("lvewx", (gen_lvewx, 0x0, None)),
+        ("lhzx", (gen_lhzx, 0x0, None)),
+        ("cmpl", (gen_cmpl, 0x0, None)),
+        ("bnsa-", (gen_bns, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("mtfsf.", (gen_mtfsf, 0x1, None)),
+        ("blelr+", (gen_blelr, 0x200000, None)),
+        ("bltlr", (gen_bltlr, 0x0, None)),
+        ("bdnzfa+", (gen_bdnzf, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("vcmpequw128.", (gen_vcmpequw128, 0x40, None)),
+        ("vcmpgtfp128.", (gen_vcmpgtfp128, 0x40, None)),
+        ("bgtctr", (gen_bgtctr, 0x0, None)),
+        ("bsol-", (gen_bso, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("nand", (gen_nand, 0x0, None)),
+        ("or.", (gen_or, 0x1, None)),
+        ("beqctrl", (gen_beqctr, 0x1, None)),
+        ("std", (gen_std, 0x0, None)),
+        ("dcbtst", (gen_dcbtst, 0x0, None)),
+        ("mfsprg", (gen_mfsprg, 0x0, None)),
+        ("beqlr", (gen_beqlr, 0x0, None)),
+        ("bltla", (gen_blt, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("vcmpequb", (gen_vcmpequb, 0x0, None)),
+        ("lfsx", (gen_lfsx, 0x0, None)),
+        ("fsub.", (gen_fsub, 0x1, None)),
+        ("vsubsbs", (gen_vsubsbs, 0x0, None)),
+        ("bdnzta+", (gen_bdnzt, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("cntlzd", (gen_cntlzd, 0x0, None)),
+        ("lvlx", (gen_lvlx, 0x0, None)),
+        ("bgea-", (gen_bge, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("vsubuwm", (gen_vsubuwm, 0x0, None)),
+        ("bgtlrl", (gen_bgtlr, 0x1, None)),
+        ("bca+", (gen_bc, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("mulldo", (gen_mulld, 0x400, None)),
+        ("bgtla", (gen_bgt, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("ps_msub", (gen_ps_msub, 0x0, None)),
+        ("divw", (gen_divw, 0x0, None)),
+        ("bnsl+", (gen_bns, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("eqv", (gen_eqv, 0x0, None)),
+        ("and.", (gen_and, 0x1, None)),
+        ("dcbt", (gen_dcbt, 0x0, None)),
+        ("vmaxuh", (gen_vmaxuh, 0x0, None)),
+        ("bdnztla-", (gen_bdnzt, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("bdzlrl", (gen_bdzlr, 0x1, None)),
+        ("bgela-", (gen_bge, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("lis", (gen_lis, 0x0, None)),
+        ("bdztlr+", (gen_bdztlr, 0x200000, None)),
+        ("vsubfp", (gen_vsubfp, 0x0, None)),
+        ("bdnzla", (gen_bdnz, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("vminuh", (gen_vminuh, 0x0, None)),
+        ("ps_neg", (gen_ps_neg, 0x0, None)),
+        ("vpkuwus", (gen_vpkuwus, 0x0, None)),
+        ("bdnza-", (gen_bdnz, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("tdge", (gen_tdge, 0x0, None)),
+        ("subfc.", (gen_subfc, 0x1, None)),
+        ("ps_sub.", (gen_ps_sub, 0x1, None)),
+        ("frsp", (gen_frsp, 0x0, None)),
+        ("stdux", (gen_stdux, 0x0, None)),
+        ("bdnztlr", (gen_bdnztlr, 0x0, None)),
+        ("vsplth", (gen_vsplth, 0x0, None)),
+        ("vminfp128", (gen_vminfp128, 0x0, None)),
+        ("mfsrin", (gen_mfsrin, 0x0, None)),
+        ("twlge", (gen_twlge, 0x0, None)),
+        ("bgtctrl", (gen_bgtctr, 0x1, None)),
+        ("sradi", (gen_sradi, 0x0, None)),
+        ("vupkhsh128", (gen_vupkhsh128, 0x0, None)),
+        ("fnmadd.", (gen_fnmadd, 0x1, None)),
+        ("mtibatl", (gen_mtibatl, 0x0, None)),
+        ("bnsla-", (gen_bns, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("vaddfp128", (gen_vaddfp128, 0x0, None)),
+        ("ps_abs", (gen_ps_abs, 0x0, None)),
+        ("tweq", (gen_tweq, 0x0, None)),
+        ("mfmsr", (gen_mfmsr, 0x0, None)),
+        ("vsubuhm", (gen_vsubuhm, 0x0, None)),
+        ("vmulesh", (gen_vmulesh, 0x0, None)),
+        ("bdnz-", (gen_bdnz, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("bdnzfla+", (gen_bdnzf, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("bdzflrl+", (gen_bdzflr, 0x200001, None)),
+        ("mtcrf", (gen_mtcrf, 0x0, None)),
+        ("mfsrr1", (gen_mfsrr1, 0x0, None)),
+        ("fadds", (gen_fadds, 0x0, None)),
+        ("addco.", (gen_addc, 0x401, None)),
+        ("divdo.", (gen_divd, 0x401, None)),
+        ("fmsubs.", (gen_fmsubs, 0x1, None)),
+        ("psq_stux", (gen_psq_stux, 0x0, None)),
+        ("lvx", (gen_lvx, 0x0, None)),
+        ("lhau", (gen_lhau, 0x0, None)),
+        ("rlwimi.", (gen_rlwimi, 0x1, None)),
+        ("bnectrl", (gen_bnectr, 0x1, None)),
+        ("rlwinm", (gen_rlwinm, 0x0, None)),
+        ("bdzfa-", (gen_bdzf, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("mr.", (gen_mr, 0x1, None)),
+        ("eieio", (gen_eieio, 0x0, None)),
+        ("vmrglw", (gen_vmrglw, 0x0, None)),
+        ("vmsum4fp128", (gen_vmsum4fp128, 0x0, None)),
+        ("clrlslwi", (gen_clrlslwi, 0x0, None)),
+        ("fmsub", (gen_fmsub, 0x0, None)),
+        ("vmaxfp", (gen_vmaxfp, 0x0, None)),
+        ("addmeo", (gen_addme, 0x400, None)),
+        ("b", (gen_b, 0x0, Some((-0x2000000, 0x1fffffc)))),
+        ("bdzt+", (gen_bdzt, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("vminuw", (gen_vminuw, 0x0, None)),
+        ("subis", (gen_subis, 0x0, None)),
+        ("vmrghw128", (gen_vmrghw128, 0x0, None)),
+        ("lvrx", (gen_lvrx, 0x0, None)),
+        ("lwa", (gen_lwa, 0x0, None)),
+        ("ps_madds1.", (gen_ps_madds1, 0x1, None)),
+        ("rfi", (gen_rfi, 0x0, None)),
+        ("vsro", (gen_vsro, 0x0, None)),
+        ("bctrl", (gen_bctr, 0x1, None)),
+        ("stswi", (gen_stswi, 0x0, None)),
+        ("bnsl-", (gen_bns, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("bdzfla", (gen_bdzf, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("rldcr", (gen_rldcr, 0x0, None)),
+        ("bltlrl", (gen_bltlr, 0x1, None)),
+        ("cmplwi", (gen_cmplwi, 0x0, None)),
+        ("ps_add.", (gen_ps_add, 0x1, None)),
+        ("clrlwi", (gen_clrlwi, 0x0, None)),
+        ("crnor", (gen_crnor, 0x0, None)),
+        ("blel+", (gen_ble, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("tdnei", (gen_tdnei, 0x0, None)),
+        ("cmpw", (gen_cmpw, 0x0, None)),
+        ("divw.", (gen_divw, 0x1, None)),
+        ("blela+", (gen_ble, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("bsoa", (gen_bso, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("vpkuhus128", (gen_vpkuhus128, 0x0, None)),
+        ("mfdbatu", (gen_mfdbatu, 0x0, None)),
+        ("srwi", (gen_srwi, 0x0, None)),
+        ("mtxer", (gen_mtxer, 0x0, None)),
+        ("mtsrr0", (gen_mtsrr0, 0x0, None)),
+        ("mullw", (gen_mullw, 0x0, None)),
+        ("mfctr", (gen_mfctr, 0x0, None)),
+        ("bdzfla+", (gen_bdzf, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("lvsl", (gen_lvsl, 0x0, None)),
+        ("bltl", (gen_blt, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("subfe.", (gen_subfe, 0x1, None)),
+        ("mulhdu", (gen_mulhdu, 0x0, None)),
+        ("fcmpu", (gen_fcmpu, 0x0, None)),
+        ("blea", (gen_ble, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("bdnzf+", (gen_bdnzf, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("vctsxs", (gen_vctsxs, 0x0, None)),
+        ("subfzeo.", (gen_subfze, 0x401, None)),
+        ("bdztlrl", (gen_bdztlr, 0x1, None)),
+        ("vcmpgtsh", (gen_vcmpgtsh, 0x0, None)),
+        ("stfdx", (gen_stfdx, 0x0, None)),
+        ("add", (gen_add, 0x0, None)),
+        ("bnsa+", (gen_bns, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("ps_sub", (gen_ps_sub, 0x0, None)),
+        ("bdnzflr+", (gen_bdnzflr, 0x200000, None)),
+        ("bdza+", (gen_bdz, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("blectr+", (gen_blectr, 0x200000, None)),
+        ("vcfux128", (gen_vcfux128, 0x0, None)),
+        ("bdzta-", (gen_bdzt, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("lbz", (gen_lbz, 0x0, None)),
+        ("beql-", (gen_beq, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("bdzl+", (gen_bdz, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("fnmadds.", (gen_fnmadds, 0x1, None)),
+        ("crand", (gen_crand, 0x0, None)),
+        ("lmw", (gen_lmw, 0x0, None)),
+        ("beqla", (gen_beq, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("beqlr+", (gen_beqlr, 0x200000, None)),
+        ("bdnzfa-", (gen_bdnzf, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("vmulouh", (gen_vmulouh, 0x0, None)),
+        ("bdzfl", (gen_bdzf, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("bdnzfl", (gen_bdnzf, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("bsoa+", (gen_bso, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("bge+", (gen_bge, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("vpkshus128", (gen_vpkshus128, 0x0, None)),
+        ("bltctr+", (gen_bltctr, 0x200000, None)),
+        ("vcmpeqfp128", (gen_vcmpeqfp128, 0x0, None)),
+        ("stdx", (gen_stdx, 0x0, None)),
+        ("fneg", (gen_fneg, 0x0, None)),
+        ("subic.", (gen_subic_, 0x0, None)),
+        ("vupklsh", (gen_vupklsh, 0x0, None)),
+        ("vmsumuhm", (gen_vmsumuhm, 0x0, None)),
+        ("mtdsisr", (gen_mtdsisr, 0x0, None)),
+        ("mulhw", (gen_mulhw, 0x0, None)),
+        ("vcmpgtuw", (gen_vcmpgtuw, 0x0, None)),
+        ("ps_mr", (gen_ps_mr, 0x0, None)),
+        ("bgtla-", (gen_bgt, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("subfeo.", (gen_subfe, 0x401, None)),
+        ("twgti", (gen_twgti, 0x0, None)),
+        ("blrl", (gen_blr, 0x1, None)),
+        ("vpermwi128", (gen_vpermwi128, 0x0, None)),
+        ("extlwi.", (gen_extlwi, 0x1, None)),
+        ("vcmpgtsb", (gen_vcmpgtsb, 0x0, None)),
+        ("rlwnm.", (gen_rlwnm, 0x1, None)),
+        ("vslw128", (gen_vslw128, 0x0, None)),
+        ("rldcr.", (gen_rldcr, 0x1, None)),
+        ("vpkswus128", (gen_vpkswus128, 0x0, None)),
+        ("addeo", (gen_adde, 0x400, None)),
+        ("bnsctrl", (gen_bnsctr, 0x1, None)),
+        ("beqa", (gen_beq, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("bc+", (gen_bc, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("lvrxl", (gen_lvrxl, 0x0, None)),
+        ("vsubshs", (gen_vsubshs, 0x0, None)),
+        ("bnel", (gen_bne, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("mttbu", (gen_mttbu, 0x0, None)),
+        ("tlbie", (gen_tlbie, 0x0, None)),
+        ("bso+", (gen_bso, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("mtsrr1", (gen_mtsrr1, 0x0, None)),
+        ("mtsprg", (gen_mtsprg, 0x0, None)),
+        ("fsqrt", (gen_fsqrt, 0x0, None)),
+        ("mftb", (gen_mftb, 0x0, None)),
+        ("bdzt", (gen_bdzt, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("addze", (gen_addze, 0x0, None)),
+        ("stvewx", (gen_stvewx, 0x0, None)),
+        ("rlwimi", (gen_rlwimi, 0x0, None)),
+        ("mfibatl", (gen_mfibatl, 0x0, None)),
+        ("bdzf-", (gen_bdzf, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("vctsxs128", (gen_vctsxs128, 0x0, None)),
+        ("vrfin", (gen_vrfin, 0x0, None)),
+        ("cmplw", (gen_cmplw, 0x0, None)),
+        ("twui", (gen_twui, 0x0, None)),
+        ("xori", (gen_xori, 0x0, None)),
+        ("lwzux", (gen_lwzux, 0x0, None)),
+        ("bdnzfla", (gen_bdnzf, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("bgtl", (gen_bgt, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("fadds.", (gen_fadds, 0x1, None)),
+        ("vsrah", (gen_vsrah, 0x0, None)),
+        ("ps_nmadd", (gen_ps_nmadd, 0x0, None)),
+        ("ps_merge01", (gen_ps_merge01, 0x0, None)),
+        ("lwsync", (gen_lwsync, 0x0, None)),
+        ("oris", (gen_oris, 0x0, None)),
+        ("icbi.", (gen_icbi, 0x1, None)),
+        ("lvsr", (gen_lvsr, 0x0, None)),
+        ("vcmpgtsw.", (gen_vcmpgtsw, 0x400, None)),
+        ("srw", (gen_srw, 0x0, None)),
+        ("cntlzw", (gen_cntlzw, 0x0, None)),
+        ("bgectr+", (gen_bgectr, 0x200000, None)),
+        ("mtfsb1", (gen_mtfsb1, 0x0, None)),
+        ("vspltw128", (gen_vspltw128, 0x0, None)),
+        ("bnela-", (gen_bne, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("vspltisw128", (gen_vspltisw128, 0x0, None)),
+        ("vupkhsb", (gen_vupkhsb, 0x0, None)),
+        ("vsr", (gen_vsr, 0x0, None)),
+        ("vmrghh", (gen_vmrghh, 0x0, None)),
+        ("mtdbatl", (gen_mtdbatl, 0x0, None)),
+        ("sthu", (gen_sthu, 0x0, None)),
+        ("ps_res", (gen_ps_res, 0x0, None)),
+        ("vxor128", (gen_vxor128, 0x0, None)),
+        ("vcmpgefp.", (gen_vcmpgefp, 0x400, None)),
+        ("sld", (gen_sld, 0x0, None)),
+        ("bdnzta-", (gen_bdnzt, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("ps_madd", (gen_ps_madd, 0x0, None)),
+        ("icbi", (gen_icbi, 0x0, None)),
+        ("vaddshs", (gen_vaddshs, 0x0, None)),
+        ("bne-", (gen_bne, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("bdnz", (gen_bdnz, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("sync", (gen_sync, 0x0, None)),
+        ("mtsrdin", (gen_mtsrdin, 0x0, None)),
+        ("bca-", (gen_bc, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("bdztl", (gen_bdzt, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("extsh", (gen_extsh, 0x0, None)),
+        ("extlwi", (gen_extlwi, 0x0, None)),
+        ("beqla-", (gen_beq, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("divwo", (gen_divw, 0x400, None)),
+        ("vmrglw128", (gen_vmrglw128, 0x0, None)),
+        ("vupklpx", (gen_vupklpx, 0x0, None)),
+        ("ble+", (gen_ble, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("dcbz_l", (gen_dcbz_l, 0x0, None)),
+        ("stfs", (gen_stfs, 0x0, None)),
+        ("mulhwu", (gen_mulhwu, 0x0, None)),
+        ("fcfid.", (gen_fcfid, 0x1, None)),
+        ("vpkshss", (gen_vpkshss, 0x0, None)),
+        ("mtfsf", (gen_mtfsf, 0x0, None)),
+        ("fnabs", (gen_fnabs, 0x0, None)),
+        ("vspltisb", (gen_vspltisb, 0x0, None)),
+        ("andis.", (gen_andis_, 0x0, None)),
+        ("vcmpbfp128.", (gen_vcmpbfp128, 0x40, None)),
+        ("extsw.", (gen_extsw, 0x1, None)),
+        ("crnand", (gen_crnand, 0x0, None)),
+        ("mtdec", (gen_mtdec, 0x0, None)),
+        ("stvlx128", (gen_stvlx128, 0x0, None)),
+        ("vnor128", (gen_vnor128, 0x0, None)),
+        ("fnmsub", (gen_fnmsub, 0x0, None)),
+        ("dcbi", (gen_dcbi, 0x0, None)),
+        ("vminsb", (gen_vminsb, 0x0, None)),
+        ("bdnzflrl", (gen_bdnzflr, 0x1, None)),
+        ("ble-", (gen_ble, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("subfe", (gen_subfe, 0x0, None)),
+        ("addic", (gen_addic, 0x0, None)),
+        ("cmpld", (gen_cmpld, 0x0, None)),
+        ("vexptefp128", (gen_vexptefp128, 0x0, None)),
+        ("fmsub.", (gen_fmsub, 0x1, None)),
+        ("mtfsfi", (gen_mtfsfi, 0x0, None)),
+        ("bnea-", (gen_bne, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("ps_merge00.", (gen_ps_merge00, 0x1, None)),
+        ("stfsu", (gen_stfsu, 0x0, None)),
+        ("beqlrl+", (gen_beqlr, 0x200001, None)),
+        ("beql", (gen_beq, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("slwi.", (gen_slwi, 0x1, None)),
+        ("mtfsb0", (gen_mtfsb0, 0x0, None)),
+        ("ps_merge11.", (gen_ps_merge11, 0x1, None)),
+        ("vcmpgtfp128", (gen_vcmpgtfp128, 0x0, None)),
+        ("vctuxs128", (gen_vctuxs128, 0x0, None)),
+        ("mcrfs", (gen_mcrfs, 0x0, None)),
+        ("bnsctrl+", (gen_bnsctr, 0x200001, None)),
+        ("addo", (gen_add, 0x400, None)),
+        ("ptesync", (gen_ptesync, 0x0, None)),
+        ("bdnztla+", (gen_bdnzt, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("sthx", (gen_sthx, 0x0, None)),
+        ("bgelrl+", (gen_bgelr, 0x200001, None)),
+        ("fctid", (gen_fctid, 0x0, None)),
+        ("extsw", (gen_extsw, 0x0, None)),
+        ("trap", (gen_trap, 0x0, None)),
+        ("vlogefp", (gen_vlogefp, 0x0, None)),
+        ("fcmpo", (gen_fcmpo, 0x0, None)),
+        ("fnmsubs.", (gen_fnmsubs, 0x1, None)),
+        ("vrefp", (gen_vrefp, 0x0, None)),
+        ("bdzfa+", (gen_bdzf, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("add.", (gen_add, 0x1, None)),
+        ("vsubcuw", (gen_vsubcuw, 0x0, None)),
+        ("fctiw.", (gen_fctiw, 0x1, None)),
+        ("srad.", (gen_srad, 0x1, None)),
+        ("lvxl", (gen_lvxl, 0x0, None)),
+        ("stfsx", (gen_stfsx, 0x0, None)),
+        ("rldic", (gen_rldic, 0x0, None)),
+        ("vand", (gen_vand, 0x0, None)),
+        ("vupklsh128", (gen_vupklsh128, 0x0, None)),
+        ("lfsu", (gen_lfsu, 0x0, None)),
+        ("fsub", (gen_fsub, 0x0, None)),
+        ("frsp.", (gen_frsp, 0x1, None)),
+        ("bgectrl", (gen_bgectr, 0x1, None)),
+        ("bdzflr", (gen_bdzflr, 0x0, None)),
+        ("bca", (gen_bc, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("bdz+", (gen_bdz, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("vpkuwum128", (gen_vpkuwum128, 0x0, None)),
+        ("mtdbatu", (gen_mtdbatu, 0x0, None)),
+        ("bdztl+", (gen_bdzt, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("subfeo", (gen_subfe, 0x400, None)),
+        ("bgelr", (gen_bgelr, 0x0, None)),
+        ("vupklsb128", (gen_vupklsb128, 0x0, None)),
+        ("lwaux", (gen_lwaux, 0x0, None)),
+        ("tw", (gen_tw, 0x0, None)),
+        ("stdu", (gen_stdu, 0x0, None)),
+        ("bltlrl+", (gen_bltlr, 0x200001, None)),
+        ("fadd.", (gen_fadd, 0x1, None)),
+        ("divwo.", (gen_divw, 0x401, None)),
+        ("cmpdi", (gen_cmpdi, 0x0, None)),
+        ("psq_lu", (gen_psq_lu, 0x0, None)),
+        ("mfspr", (gen_mfspr, 0x0, None)),
+        ("bgtctrl+", (gen_bgtctr, 0x200001, None)),
+        ("fnmsubs", (gen_fnmsubs, 0x0, None)),
+        ("ps_mul.", (gen_ps_mul, 0x1, None)),
+        ("bgectrl+", (gen_bgectr, 0x200001, None)),
+        ("rldic.", (gen_rldic, 0x1, None)),
+        ("ps_merge11", (gen_ps_merge11, 0x0, None)),
+        ("bdnzl+", (gen_bdnz, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("mfsrr0", (gen_mfsrr0, 0x0, None)),
+        ("crorc", (gen_crorc, 0x0, None)),
+        ("vcmpgtuh.", (gen_vcmpgtuh, 0x400, None)),
+        ("dstt", (gen_dst, 0x2000000, None)),
+        ("fnmsub.", (gen_fnmsub, 0x1, None)),
+        ("bclr+", (gen_bclr, 0x200000, None)),
+        ("stwbrx", (gen_stwbrx, 0x0, None)),
+        ("vspltw", (gen_vspltw, 0x0, None)),
+        ("vcmpgtsw", (gen_vcmpgtsw, 0x0, None)),
+        ("subf", (gen_subf, 0x0, None)),
+        ("addc", (gen_addc, 0x0, None)),
+        ("lvsl128", (gen_lvsl128, 0x0, None)),
+        ("bgt", (gen_bgt, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("subfmeo.", (gen_subfme, 0x401, None)),
+        ("bltctrl+", (gen_bltctr, 0x200001, None)),
+        ("vcmpgtsb.", (gen_vcmpgtsb, 0x400, None)),
+        ("fnabs.", (gen_fnabs, 0x1, None)),
+        ("subfo", (gen_subf, 0x400, None)),
+        ("fabs.", (gen_fabs, 0x1, None)),
+        ("bgelr+", (gen_bgelr, 0x200000, None)),
+        ("bsola", (gen_bso, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("bcctr+", (gen_bcctr, 0x200000, None)),
+        ("bne", (gen_bne, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("bgtlrl+", (gen_bgtlr, 0x200001, None)),
+        ("fneg.", (gen_fneg, 0x1, None)),
+        ("ecowx", (gen_ecowx, 0x0, None)),
+        ("lfdx", (gen_lfdx, 0x0, None)),
+        ("vcmpgtuw.", (gen_vcmpgtuw, 0x400, None)),
+        ("bdzlrl+", (gen_bdzlr, 0x200001, None)),
+        ("rldicl", (gen_rldicl, 0x0, None)),
+        ("bnelrl+", (gen_bnelr, 0x200001, None)),
+        ("bns+", (gen_bns, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("fctidz", (gen_fctidz, 0x0, None)),
+        ("vmrghw", (gen_vmrghw, 0x0, None)),
+        ("bsoctrl", (gen_bsoctr, 0x1, None)),
+        ("bcctrl", (gen_bcctr, 0x1, None)),
+        ("bgela+", (gen_bge, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("bdnzta", (gen_bdnzt, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("fmadds", (gen_fmadds, 0x0, None)),
+        ("fmuls", (gen_fmuls, 0x0, None)),
+        ("bdztl-", (gen_bdzt, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("bdnza+", (gen_bdnz, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("bnslrl", (gen_bnslr, 0x1, None)),
+        ("bcla", (gen_bc, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("mfibatu", (gen_mfibatu, 0x0, None)),
+        ("mtear", (gen_mtear, 0x0, None)),
+        ("vsrw128", (gen_vsrw128, 0x0, None)),
+        ("beq", (gen_beq, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("lvlxl", (gen_lvlxl, 0x0, None)),
+        ("mullw.", (gen_mullw, 0x1, None)),
+        ("bdzlr+", (gen_bdzlr, 0x200000, None)),
+        ("bcl+", (gen_bc, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("bla", (gen_b, 0x3, Some((-0x2000000, 0x1fffffc)))),
+        ("vupkd3d128", (gen_vupkd3d128, 0x0, None)),
+        ("tdlnl", (gen_tdlnl, 0x0, None)),
+        ("vspltish", (gen_vspltish, 0x0, None)),
+        ("lfd", (gen_lfd, 0x0, None)),
+        ("ps_div.", (gen_ps_div, 0x1, None)),
+        ("lfsux", (gen_lfsux, 0x0, None)),
+        ("divduo.", (gen_divdu, 0x401, None)),
+        ("addeo.", (gen_adde, 0x401, None)),
+        ("rotlwi", (gen_rotlwi, 0x0, None)),
+        ("ps_sel", (gen_ps_sel, 0x0, None)),
+        ("stvehx", (gen_stvehx, 0x0, None)),
+        ("clrrwi", (gen_clrrwi, 0x0, None)),
+        ("nego.", (gen_neg, 0x401, None)),
+        ("twi", (gen_twi, 0x0, None)),
+        ("bdnztl-", (gen_bdnzt, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("vsrw", (gen_vsrw, 0x0, None)),
+        ("fnmadd", (gen_fnmadd, 0x0, None)),
+        ("vperm", (gen_vperm, 0x0, None)),
+        ("vaddfp", (gen_vaddfp, 0x0, None)),
+        ("mffs", (gen_mffs, 0x0, None)),
+        ("mflr", (gen_mflr, 0x0, None)),
+        ("bdzla+", (gen_bdz, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("crset", (gen_crset, 0x0, None)),
+        ("bsolrl", (gen_bsolr, 0x1, None)),
+        ("bdzla-", (gen_bdz, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("mulhd", (gen_mulhd, 0x0, None)),
+        ("blt+", (gen_blt, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("cntlzd.", (gen_cntlzd, 0x1, None)),
+        ("addic.", (gen_addic_, 0x0, None)),
+        ("bsoctr", (gen_bsoctr, 0x0, None)),
+        ("vmaxfp128", (gen_vmaxfp128, 0x0, None)),
+        ("vpkshss128", (gen_vpkshss128, 0x0, None)),
+        ("vmrglb", (gen_vmrglb, 0x0, None)),
+        ("bdzl-", (gen_bdz, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("xor.", (gen_xor, 0x1, None)),
+        ("vrfiz", (gen_vrfiz, 0x0, None)),
+        ("slbia", (gen_slbia, 0x0, None)),
+        ("stvlxl", (gen_stvlxl, 0x0, None)),
+        ("mtctr", (gen_mtctr, 0x0, None)),
+        ("dss", (gen_dss, 0x0, None)),
+        ("mullwo", (gen_mullw, 0x400, None)),
+        ("stvlxl128", (gen_stvlxl128, 0x0, None)),
+        ("vcmpgtub.", (gen_vcmpgtub, 0x400, None)),
+        ("bso", (gen_bso, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("stbu", (gen_stbu, 0x0, None)),
+        ("bdnzlr+", (gen_bdnzlr, 0x200000, None)),
+        ("vupkhpx", (gen_vupkhpx, 0x0, None)),
+        ("bdztla-", (gen_bdzt, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("vsrb", (gen_vsrb, 0x0, None)),
+        ("bdnzt", (gen_bdnzt, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("rotrwi", (gen_rotrwi, 0x0, None)),
+        ("divwu", (gen_divwu, 0x0, None)),
+        ("vcmpequw", (gen_vcmpequw, 0x0, None)),
+        ("bc", (gen_bc, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("mtmsrd", (gen_mtmsrd, 0x0, None)),
+        ("bsola+", (gen_bso, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("mtfsfi.", (gen_mtfsfi, 0x1, None)),
+        ("rldimi.", (gen_rldimi, 0x1, None)),
+        ("bcctrl+", (gen_bcctr, 0x200001, None)),
+        ("bnelrl", (gen_bnelr, 0x1, None)),
+        ("bclr", (gen_bclr, 0x0, None)),
+        ("psq_l", (gen_psq_l, 0x0, None)),
+        ("nand.", (gen_nand, 0x1, None)),
+        ("bdzflr+", (gen_bdzflr, 0x200000, None)),
+        ("clrrwi.", (gen_clrrwi, 0x1, None)),
+        ("mulhd.", (gen_mulhd, 0x1, None)),
+        ("clrlslwi.", (gen_clrlslwi, 0x1, None)),
+        ("vnor", (gen_vnor, 0x0, None)),
+        ("bltlr+", (gen_bltlr, 0x200000, None)),
+        ("vadduwm", (gen_vadduwm, 0x0, None)),
+        ("ps_rsqrte.", (gen_ps_rsqrte, 0x1, None)),
+        ("srw.", (gen_srw, 0x1, None)),
+        ("vcmpbfp", (gen_vcmpbfp, 0x0, None)),
+        ("subfmeo", (gen_subfme, 0x400, None)),
+        ("bdnztlrl", (gen_bdnztlr, 0x1, None)),
+        ("bdnza", (gen_bdnz, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("or", (gen_or, 0x0, None)),
+        ("rotld.", (gen_rotld, 0x1, None)),
+        ("bgtl+", (gen_bgt, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("lvrxl128", (gen_lvrxl128, 0x0, None)),
+        ("bsoctrl+", (gen_bsoctr, 0x200001, None)),
+        ("addzeo.", (gen_addze, 0x401, None)),
+        ("beqlrl", (gen_beqlr, 0x1, None)),
+        ("bgta-", (gen_bgt, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("mtmsr", (gen_mtmsr, 0x0, None)),
+        ("fmul", (gen_fmul, 0x0, None)),
+        ("ldarx", (gen_ldarx, 0x0, None)),
+        ("vaddsws", (gen_vaddsws, 0x0, None)),
+        ("adde.", (gen_adde, 0x1, None)),
+        ("mfcr", (gen_mfcr, 0x0, None)),
+        ("divduo", (gen_divdu, 0x400, None)),
+        ("vpkpx", (gen_vpkpx, 0x0, None)),
+        ("mfdsisr", (gen_mfdsisr, 0x0, None)),
+        ("blea+", (gen_ble, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("mfdbatl", (gen_mfdbatl, 0x0, None)),
+        ("mulli", (gen_mulli, 0x0, None)),
+        ("blea-", (gen_ble, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("cror", (gen_cror, 0x0, None)),
+        ("bgea", (gen_bge, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("vrfiz128", (gen_vrfiz128, 0x0, None)),
+        ("bdzt-", (gen_bdzt, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("sraw", (gen_sraw, 0x0, None)),
+        ("vpkshus", (gen_vpkshus, 0x0, None)),
+        ("vsel128", (gen_vsel128, 0x0, None)),
+        ("vnmsubfp", (gen_vnmsubfp, 0x0, None)),
+        ("vrlb", (gen_vrlb, 0x0, None)),
+        ("tlbsync", (gen_tlbsync, 0x0, None)),
+        ("sradi.", (gen_sradi, 0x1, None)),
+        ("vmaxub", (gen_vmaxub, 0x0, None)),
+        ("fdiv.", (gen_fdiv, 0x1, None)),
+        ("vslw", (gen_vslw, 0x0, None)),
+        ("subfme", (gen_subfme, 0x0, None)),
+        ("mfear", (gen_mfear, 0x0, None)),
+        ("rldicl.", (gen_rldicl, 0x1, None)),
+        ("beqctrl+", (gen_beqctr, 0x200001, None)),
+        ("vminsw", (gen_vminsw, 0x0, None)),
+        ("ps_cmpo0", (gen_ps_cmpo0, 0x0, None)),
+        ("vrlw", (gen_vrlw, 0x0, None)),
+        ("mtsrin", (gen_mtsrin, 0x0, None)),
+        ("fsubs.", (gen_fsubs, 0x1, None)),
+        ("rldcl", (gen_rldcl, 0x0, None)),
+        ("bdnztl", (gen_bdnzt, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("fctidz.", (gen_fctidz, 0x1, None)),
+        ("ps_sum0", (gen_ps_sum0, 0x0, None)),
+        ("frsqrte.", (gen_frsqrte, 0x1, None)),
+        ("bsolr+", (gen_bsolr, 0x200000, None)),
+        ("vaddsbs", (gen_vaddsbs, 0x0, None)),
+        ("bnela", (gen_bne, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("rotlwi.", (gen_rotlwi, 0x1, None)),
+        ("vsum4sbs", (gen_vsum4sbs, 0x0, None)),
+        ("vmuloub", (gen_vmuloub, 0x0, None)),
+        ("vpkswus", (gen_vpkswus, 0x0, None)),
+        ("bgtl-", (gen_bgt, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("vcmpgefp", (gen_vcmpgefp, 0x0, None)),
+        ("dcbzl", (gen_dcbzl, 0x0, None)),
+        ("mulld.", (gen_mulld, 0x1, None)),
+        ("slbie", (gen_slbie, 0x0, None)),
+        ("subfo.", (gen_subf, 0x401, None)),
+        ("bnsl", (gen_bns, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("stbx", (gen_stbx, 0x0, None)),
+        ("mulldo.", (gen_mulld, 0x401, None)),
+        ("vrfim128", (gen_vrfim128, 0x0, None)),
+        ("vcmpgefp128.", (gen_vcmpgefp128, 0x40, None)),
+        ("mfvscr", (gen_mfvscr, 0x0, None)),
+        ("mfdar", (gen_mfdar, 0x0, None)),
+        ("vsro128", (gen_vsro128, 0x0, None)),
+        ("mulld", (gen_mulld, 0x0, None)),
+        ("vandc", (gen_vandc, 0x0, None)),
+        ("bdzta", (gen_bdzt, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("stwu", (gen_stwu, 0x0, None)),
+        ("addc.", (gen_addc, 0x1, None)),
+        ("tdi", (gen_tdi, 0x0, None)),
+        ("ps_muls1.", (gen_ps_muls1, 0x1, None)),
+        ("bgt-", (gen_bgt, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("vaddcuw", (gen_vaddcuw, 0x0, None)),
+        ("lvebx", (gen_lvebx, 0x0, None)),
+        ("vadduws", (gen_vadduws, 0x0, None)),
+        ("bdnzflr", (gen_bdnzflr, 0x0, None)),
+        ("vminfp", (gen_vminfp, 0x0, None)),
+        ("lbzux", (gen_lbzux, 0x0, None)),
+        ("vcmpeqfp128.", (gen_vcmpeqfp128, 0x40, None)),
+        ("blectr", (gen_blectr, 0x0, None)),
+        ("addmeo.", (gen_addme, 0x401, None)),
+        ("vmsumuhs", (gen_vmsumuhs, 0x0, None)),
+        ("vavguh", (gen_vavguh, 0x0, None)),
+        ("vrefp128", (gen_vrefp128, 0x0, None)),
+        ("rotrwi.", (gen_rotrwi, 0x1, None)),
+        ("bsol", (gen_bso, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("orc", (gen_orc, 0x0, None)),
+        ("vslo", (gen_vslo, 0x0, None)),
+        ("vsrh", (gen_vsrh, 0x0, None)),
+        ("vslb", (gen_vslb, 0x0, None)),
+        ("addi", (gen_addi, 0x0, None)),
+        ("lhzu", (gen_lhzu, 0x0, None)),
+        ("eqv.", (gen_eqv, 0x1, None)),
+        ("fctiwz", (gen_fctiwz, 0x0, None)),
+        ("vsraw128", (gen_vsraw128, 0x0, None)),
+        ("cmpli", (gen_cmpli, 0x0, None)),
+        ("vrlw128", (gen_vrlw128, 0x0, None)),
+        ("ps_madd.", (gen_ps_madd, 0x1, None)),
+        ("bnel+", (gen_bne, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("ps_madds0.", (gen_ps_madds0, 0x1, None)),
+        ("vcfux", (gen_vcfux, 0x0, None)),
+        ("bns", (gen_bns, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("bgt+", (gen_bgt, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("extrwi.", (gen_extrwi, 0x1, None)),
+        ("bnslrl+", (gen_bnslr, 0x200001, None)),
+        ("lfdu", (gen_lfdu, 0x0, None)),
+        ("vrfip128", (gen_vrfip128, 0x0, None)),
+        ("vmsum3fp128", (gen_vmsum3fp128, 0x0, None)),
+        ("bnsla", (gen_bns, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("vandc128", (gen_vandc128, 0x0, None)),
+        ("lha", (gen_lha, 0x0, None)),
+        ("stvxl", (gen_stvxl, 0x0, None)),
+        ("bgelrl", (gen_bgelr, 0x1, None)),
+        ("ps_res.", (gen_ps_res, 0x1, None)),
+        ("bcla-", (gen_bc, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("bdz-", (gen_bdz, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("lbzu", (gen_lbzu, 0x0, None)),
+        ("lwzx", (gen_lwzx, 0x0, None)),
+        ("bdzfa", (gen_bdzf, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("vsl", (gen_vsl, 0x0, None)),
+        ("vslo128", (gen_vslo128, 0x0, None)),
+        ("bsolrl+", (gen_bsolr, 0x200001, None)),
+        ("ps_nmsub.", (gen_ps_nmsub, 0x1, None)),
+        ("bgtla+", (gen_bgt, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("subfzeo", (gen_subfze, 0x400, None)),
+        ("bsolr", (gen_bsolr, 0x0, None)),
+        ("ps_div", (gen_ps_div, 0x0, None)),
+        ("cmpi", (gen_cmpi, 0x0, None)),
+        ("srd.", (gen_srd, 0x1, None)),
+        ("vpkd3d128", (gen_vpkd3d128, 0x0, None)),
+        ("crnot", (gen_crnot, 0x0, None)),
+        ("blela-", (gen_ble, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("vavgsw", (gen_vavgsw, 0x0, None)),
+        ("addze.", (gen_addze, 0x1, None)),
+        ("psq_st", (gen_psq_st, 0x0, None)),
+        ("bdzflrl", (gen_bdzflr, 0x1, None)),
+        ("stvrx", (gen_stvrx, 0x0, None)),
+        ("xor", (gen_xor, 0x0, None)),
+        ("bnea+", (gen_bne, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("bdnzf-", (gen_bdnzf, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("addzeo", (gen_addze, 0x400, None)),
+        ("divdu", (gen_divdu, 0x0, None)),
+        ("blr", (gen_blr, 0x0, None)),
+        ("lvrx128", (gen_lvrx128, 0x0, None)),
+        ("srwi.", (gen_srwi, 0x1, None)),
+        ("bdnzt+", (gen_bdnzt, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("andc", (gen_andc, 0x0, None)),
+        ("vminub", (gen_vminub, 0x0, None)),
+        ("vnot", (gen_vnot, 0x0, None)),
+        ("mulhdu.", (gen_mulhdu, 0x1, None)),
+        ("bnectr+", (gen_bnectr, 0x200000, None)),
+        ("vsum4shs", (gen_vsum4shs, 0x0, None)),
+        ("bdz", (gen_bdz, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("bdztla+", (gen_bdzt, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("vsldoi128", (gen_vsldoi128, 0x0, None)),
+        ("nor", (gen_nor, 0x0, None)),
+        ("blelrl", (gen_blelr, 0x1, None)),
+        ("lvlxl128", (gen_lvlxl128, 0x0, None)),
+        ("stvrxl", (gen_stvrxl, 0x0, None)),
+        ("isync", (gen_isync, 0x0, None)),
+        ("ps_cmpu1", (gen_ps_cmpu1, 0x0, None)),
+        ("mtspr", (gen_mtspr, 0x0, None)),
+        ("mttbl", (gen_mttbl, 0x0, None)),
+        ("vsububs", (gen_vsububs, 0x0, None)),
+        ("bdnzfla-", (gen_bdnzf, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("crclr", (gen_crclr, 0x0, None)),
+        ("crandc", (gen_crandc, 0x0, None)),
+        ("extsb.", (gen_extsb, 0x1, None)),
+        ("mfsdr1", (gen_mfsdr1, 0x0, None)),
+        ("ps_sum1.", (gen_ps_sum1, 0x1, None)),
+        ("bcla+", (gen_bc, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("lvx128", (gen_lvx128, 0x0, None)),
+        ("bdzl", (gen_bdz, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("sthbrx", (gen_sthbrx, 0x0, None)),
+        ("vavgub", (gen_vavgub, 0x0, None)),
+        ("bgtlr+", (gen_bgtlr, 0x200000, None)),
+        ("mullwo.", (gen_mullw, 0x401, None)),
+        ("bdnzlrl", (gen_bdnzlr, 0x1, None)),
+        ("rldcl.", (gen_rldcl, 0x1, None)),
+        ("bsoa-", (gen_bso, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("blt-", (gen_blt, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("vrfim", (gen_vrfim, 0x0, None)),
+        ("ps_merge01.", (gen_ps_merge01, 0x1, None)),
+        ("lvehx", (gen_lvehx, 0x0, None)),
+        ("blela", (gen_ble, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("vsubsws", (gen_vsubsws, 0x0, None)),
+        ("vadduhm", (gen_vadduhm, 0x0, None)),
+        ("fmuls.", (gen_fmuls, 0x1, None)),
+        ("andi.", (gen_andi_, 0x0, None)),
+        ("bdnzlr", (gen_bdnzlr, 0x0, None)),
+        ("lhax", (gen_lhax, 0x0, None)),
+        ("vor128", (gen_vor128, 0x0, None)),
+        ("stswx", (gen_stswx, 0x0, None)),
+        ("vmaddcfp128", (gen_vmaddcfp128, 0x0, None)),
+        ("mr", (gen_mr, 0x0, None)),
+        ("vavgsb", (gen_vavgsb, 0x0, None)),
+        ("nop", (gen_nop, 0x0, None)),
+        ("stfd", (gen_stfd, 0x0, None)),
+        ("vmaddfp128", (gen_vmaddfp128, 0x0, None)),
+        ("stfdu", (gen_stfdu, 0x0, None)),
+        ("nego", (gen_neg, 0x400, None)),
+        ("mtsdr1", (gen_mtsdr1, 0x0, None)),
+        ("blta-", (gen_blt, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("ps_neg.", (gen_ps_neg, 0x1, None)),
+        ("andc.", (gen_andc, 0x1, None)),
+        ("subi", (gen_subi, 0x0, None)),
+        ("bsola-", (gen_bso, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("vmhraddshs", (gen_vmhraddshs, 0x0, None)),
+        ("ldx", (gen_ldx, 0x0, None)),
+        ("bcctr", (gen_bcctr, 0x0, None)),
+        ("vcmpgtfp", (gen_vcmpgtfp, 0x0, None)),
+        ("addo.", (gen_add, 0x401, None)),
+        ("vmsumshm", (gen_vmsumshm, 0x0, None)),
+        ("bltl+", (gen_blt, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("vupkhsh", (gen_vupkhsh, 0x0, None)),
+        ("bltctr", (gen_bltctr, 0x0, None)),
+        ("fmr.", (gen_fmr, 0x1, None)),
+        ("vmsummbm", (gen_vmsummbm, 0x0, None)),
+        ("vmr", (gen_vmr, 0x0, None)),
+        ("bdnzf", (gen_bdnzf, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("bdnztl+", (gen_bdnzt, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("frsqrte", (gen_frsqrte, 0x0, None)),
+        ("vslh", (gen_vslh, 0x0, None)),
+        ("rotlw", (gen_rotlw, 0x0, None)),
+        ("rotlw.", (gen_rotlw, 0x1, None)),
+        ("mffs.", (gen_mffs, 0x1, None)),
+        ("srad", (gen_srad, 0x0, None)),
+        ("ps_madds0", (gen_ps_madds0, 0x0, None)),
+        ("bdzfl-", (gen_bdzf, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("slwi", (gen_slwi, 0x0, None)),
+        ("lfdux", (gen_lfdux, 0x0, None)),
+        ("xoris", (gen_xoris, 0x0, None)),
+        ("sld.", (gen_sld, 0x1, None)),
+        ("bdnztlrl+", (gen_bdnztlr, 0x200001, None)),
+        ("fdivs.", (gen_fdivs, 0x1, None)),
+        ("divwu.", (gen_divwu, 0x1, None)),
+        ("vcmpequw128", (gen_vcmpequw128, 0x0, None)),
+        ("rfid", (gen_rfid, 0x0, None)),
+        ("bclrl", (gen_bclr, 0x1, None)),
+        ("vsubfp128", (gen_vsubfp128, 0x0, None)),
+        ("mulhwu.", (gen_mulhwu, 0x1, None)),
+        ("bns-", (gen_bns, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("blectrl+", (gen_blectr, 0x200001, None)),
+        ("bdzfla-", (gen_bdzf, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("blelr", (gen_blelr, 0x0, None)),
+        ("stvlx", (gen_stvlx, 0x0, None)),
+        ("ps_nabs.", (gen_ps_nabs, 0x1, None)),
+        ("stwcx.", (gen_stwcx_, 0x0, None)),
+        ("vpkuwus128", (gen_vpkuwus128, 0x0, None)),
+        ("nor.", (gen_nor, 0x1, None)),
+        ("blel", (gen_ble, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("slw.", (gen_slw, 0x1, None)),
+        ("stvewx128", (gen_stvewx128, 0x0, None)),
+        ("bgela", (gen_bge, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("ba", (gen_b, 0x2, Some((-0x2000000, 0x1fffffc)))),
+        ("ps_muls0", (gen_ps_muls0, 0x0, None)),
+        ("vmuleuh", (gen_vmuleuh, 0x0, None)),
+        ("subfze", (gen_subfze, 0x0, None)),
+        ("vcmpgtsh.", (gen_vcmpgtsh, 0x400, None)),
+        ("bnelr+", (gen_bnelr, 0x200000, None)),
+        ("mulhw.", (gen_mulhw, 0x1, None)),
+        ("bsol+", (gen_bso, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("fmadd", (gen_fmadd, 0x0, None)),
+        ("bdnzlrl+", (gen_bdnzlr, 0x200001, None)),
+        ("vspltb", (gen_vspltb, 0x0, None)),
+        ("bdnzfa", (gen_bdnzf, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("beqa-", (gen_beq, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("vsubuhs", (gen_vsubuhs, 0x0, None)),
+        ("stb", (gen_stb, 0x0, None)),
+        ("vadduhs", (gen_vadduhs, 0x0, None)),
+        ("lhaux", (gen_lhaux, 0x0, None)),
+        ("bgel", (gen_bge, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("vrfip", (gen_vrfip, 0x0, None)),
+        ("vrsqrtefp128", (gen_vrsqrtefp128, 0x0, None)),
+        ("beq-", (gen_beq, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("stvrxl128", (gen_stvrxl128, 0x0, None)),
+        ("bge", (gen_bge, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("bdzf", (gen_bdzf, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("vpkuhus", (gen_vpkuhus, 0x0, None)),
+        ("dststt", (gen_dstst, 0x2000000, None)),
+        ("bltla+", (gen_blt, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("bdnzl-", (gen_bdnz, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("ori", (gen_ori, 0x0, None)),
+        ("vpkswss", (gen_vpkswss, 0x0, None)),
+        ("bdzf+", (gen_bdzf, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("bnectrl+", (gen_bnectr, 0x200001, None)),
+        ("divd", (gen_divd, 0x0, None)),
+        ("blectrl", (gen_blectr, 0x1, None)),
+        ("subfco.", (gen_subfc, 0x401, None)),
+        ("vcmpgtfp.", (gen_vcmpgtfp, 0x400, None)),
+        ("ps_add", (gen_ps_add, 0x0, None)),
+        ("vavgsh", (gen_vavgsh, 0x0, None)),
+        ("bdnzfl-", (gen_bdnzf, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("stvebx", (gen_stvebx, 0x0, None)),
+        ("ps_cmpo1", (gen_ps_cmpo1, 0x0, None)),
+        ("subfc", (gen_subfc, 0x0, None)),
+        ("bnslr", (gen_bnslr, 0x0, None)),
+        ("bdztlr", (gen_bdztlr, 0x0, None)),
+        ("bdzfl+", (gen_bdzf, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("bltl-", (gen_blt, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("bltla-", (gen_blt, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("mtibatu", (gen_mtibatu, 0x0, None)),
+        ("ps_cmpu0", (gen_ps_cmpu0, 0x0, None)),
+        ("crxor", (gen_crxor, 0x0, None)),
+        ("fmsubs", (gen_fmsubs, 0x0, None)),
+        ("bltctrl", (gen_bltctr, 0x1, None)),
+        ("vcmpeqfp", (gen_vcmpeqfp, 0x0, None)),
+        ("vpkuhum", (gen_vpkuhum, 0x0, None)),
+        ("vsrab", (gen_vsrab, 0x0, None)),
+        ("lvxl128", (gen_lvxl128, 0x0, None)),
+        ("adde", (gen_adde, 0x0, None)),
+        ("vcfsx128", (gen_vcfsx128, 0x0, None)),
+        ("vcmpgtub", (gen_vcmpgtub, 0x0, None)),
+        ("vaddubs", (gen_vaddubs, 0x0, None)),
+        ("cmpd", (gen_cmpd, 0x0, None)),
+        ("blta", (gen_blt, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("bc-", (gen_bc, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("vupklsb", (gen_vupklsb, 0x0, None)),
+        ("vor", (gen_vor, 0x0, None)),
+        ("lwz", (gen_lwz, 0x0, None)),
+        ("neg", (gen_neg, 0x0, None)),
+        ("fsqrt.", (gen_fsqrt, 0x1, None)),
+        ("extsh.", (gen_extsh, 0x1, None)),
+        ("orc.", (gen_orc, 0x1, None)),
+        ("vpkuwum", (gen_vpkuwum, 0x0, None)),
+        ("srd", (gen_srd, 0x0, None)),
+        ("extsb", (gen_extsb, 0x0, None)),
+        ("vcmpgefp128", (gen_vcmpgefp128, 0x0, None)),
+        ("sraw.", (gen_sraw, 0x1, None)),
+        ("bl", (gen_b, 0x1, Some((-0x2000000, 0x1fffffc)))),
+        ("bnsctr+", (gen_bnsctr, 0x200000, None)),
+        ("mtfsb1.", (gen_mtfsb1, 0x1, None)),
+        ("vcmpequh", (gen_vcmpequh, 0x0, None)),
+        ("bdnzt-", (gen_bdnzt, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("vpkswss128", (gen_vpkswss128, 0x0, None)),
+        ("fmr", (gen_fmr, 0x0, None)),
+        ("subfme.", (gen_subfme, 0x1, None)),
+        ("bcl", (gen_bc, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("clrlwi.", (gen_clrlwi, 0x1, None)),
+        ("mfxer", (gen_mfxer, 0x0, None)),
+        ("ld", (gen_ld, 0x0, None)),
+        ("td", (gen_td, 0x0, None)),
+        ("ble", (gen_ble, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("blt", (gen_blt, 0x0, Some((-0x8000, 0x7ffc)))),
+        ("and", (gen_and, 0x0, None)),
+        ("mtlr", (gen_mtlr, 0x0, None)),
+        ("vxor", (gen_vxor, 0x0, None)),
+        ("fsel", (gen_fsel, 0x0, None)),
+        ("mfdec", (gen_mfdec, 0x0, None)),
+        ("ps_merge10", (gen_ps_merge10, 0x0, None)),
+        ("vsraw", (gen_vsraw, 0x0, None)),
+        ("bdnz+", (gen_bdnz, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("lswx", (gen_lswx, 0x0, None)),
+        ("vsum2sws", (gen_vsum2sws, 0x0, None)),
+        ("bsoctr+", (gen_bsoctr, 0x200000, None)),
+        ("bnslr+", (gen_bnslr, 0x200000, None)),
+        ("ps_nabs", (gen_ps_nabs, 0x0, None)),
+        ("lwarx", (gen_lwarx, 0x0, None)),
+        ("vaddubm", (gen_vaddubm, 0x0, None)),
+        ("lhbrx", (gen_lhbrx, 0x0, None)),
+        ("stvrx128", (gen_stvrx128, 0x0, None)),
+        ("stvxl128", (gen_stvxl128, 0x0, None)),
+        ("divdu.", (gen_divdu, 0x1, None)),
+        ("bso-", (gen_bso, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("bclrl+", (gen_bclr, 0x200001, None)),
+        ("fres.", (gen_fres, 0x1, None)),
+        ("divwuo", (gen_divwu, 0x400, None)),
+        ("bdnzl", (gen_bdnz, 0x1, Some((-0x8000, 0x7ffc)))),
+        ("beqctr+", (gen_beqctr, 0x200000, None)),
+        ("stmw", (gen_stmw, 0x0, None)),
+        ("cmp", (gen_cmp, 0x0, None)),
+        ("vpkuhum128", (gen_vpkuhum128, 0x0, None)),
+        ("bdnzflrl+", (gen_bdnzflr, 0x200001, None)),
+        ("vcmpbfp128", (gen_vcmpbfp128, 0x0, None)),
+        ("vsumsws", (gen_vsumsws, 0x0, None)),
+        ("extrwi", (gen_extrwi, 0x0, None)),
+        ("li", (gen_li, 0x0, None)),
+        ("ps_sum0.", (gen_ps_sum0, 0x1, None)),
+        ("stw", (gen_stw, 0x0, None)),
+        ("vcmpeqfp.", (gen_vcmpeqfp, 0x400, None)),
+        ("sc", (gen_sc, 0x0, None)),
+        ("mtfsb0.", (gen_mtfsb0, 0x1, None)),
+        ("vctuxs", (gen_vctuxs, 0x0, None)),
+        ("subf.", (gen_subf, 0x1, None)),
+        ("mcrf", (gen_mcrf, 0x0, None)),
+        ("blel-", (gen_ble, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("lfs", (gen_lfs, 0x0, None)),
+        ("mfocrf", (gen_mfocrf, 0x0, None)),
+        ("sthux", (gen_sthux, 0x0, None)),
+        ("lvsr128", (gen_lvsr128, 0x0, None)),
+        ("beqla+", (gen_beq, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("fmadds.", (gen_fmadds, 0x1, None)),
+        ("vexptefp", (gen_vexptefp, 0x0, None)),
+        ("stbux", (gen_stbux, 0x0, None)),
+        ("ps_nmadd.", (gen_ps_nmadd, 0x1, None)),
+        ("mcrxr", (gen_mcrxr, 0x0, None)),
+        ("bdnzla-", (gen_bdnz, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("bnea", (gen_bne, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("ps_muls0.", (gen_ps_muls0, 0x1, None)),
+        ("cntlzw.", (gen_cntlzw, 0x1, None)),
+        ("mtsr", (gen_mtsr, 0x0, None)),
+        ("vlogefp128", (gen_vlogefp128, 0x0, None)),
+        ("vcmpequw.", (gen_vcmpequw, 0x400, None)),
+        ("bnsa", (gen_bns, 0x2, Some((-0x8000, 0x7ffc)))),
+        ("blta+", (gen_blt, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("divdo", (gen_divd, 0x400, None)),
+        ("vavguw", (gen_vavguw, 0x0, None)),
+        ("vmaxsb", (gen_vmaxsb, 0x0, None)),
+        ("vand128", (gen_vand128, 0x0, None)),
+        ("vnmsubfp128", (gen_vnmsubfp128, 0x0, None)),
+        ("fctiwz.", (gen_fctiwz, 0x1, None)),
+        ("vrlh", (gen_vrlh, 0x0, None)),
+        ("stfdux", (gen_stfdux, 0x0, None)),
+        ("vupkhsb128", (gen_vupkhsb128, 0x0, None)),
+        ("vmrglh", (gen_vmrglh, 0x0, None)),
+        ("bdztla", (gen_bdzt, 0x3, Some((-0x8000, 0x7ffc)))),
+        ("bnsctr", (gen_bnsctr, 0x0, None)),
+        ("fnmadds", (gen_fnmadds, 0x0, None)),
+        ("slw", (gen_slw, 0x0, None)),
+        ("stfsux", (gen_stfsux, 0x0, None)),
+        ("cmpwi", (gen_cmpwi, 0x0, None)),
+        ("rlwinm.", (gen_rlwinm, 0x1, None)),
+        ("beql+", (gen_beq, 0x200001, Some((-0x8000, 0x7ffc)))),
+        ("fmul.", (gen_fmul, 0x1, None)),
+        ("bdza-", (gen_bdz, 0x200002, Some((-0x8000, 0x7ffc)))),
+        ("fsel.", (gen_fsel, 0x1, None)),
+        ("mtdar", (gen_mtdar, 0x0, None)),
+        ("lwbrx", (gen_lwbrx, 0x0, None)),
+        ("eciwx", (gen_eciwx, 0x0, None)),
+        ("vmrghb", (gen_vmrghb, 0x0, None)),
+        ("psq_stx", (gen_psq_stx, 0x0, None)),
+        ("vmsumshs", (gen_vmsumshs, 0x0, None)),
+        ("bne+", (gen_bne, 0x200000, Some((-0x8000, 0x7ffc)))),
+        ("bnsla+", (gen_bns, 0x200003, Some((-0x8000, 0x7ffc)))),
+        ("sth", (gen_sth, 0x0, None)),
+        ("fctiw", (gen_fctiw, 0x0, None)),
+        ("dst", (gen_dst, 0x0, None)),
+        ("vmuleub", (gen_vmuleub, 0x0, None)),
+        ("srawi", (gen_srawi, 0x0, None)),
+        ("ps_sum1", (gen_ps_sum1, 0x0, None)),
     ],
 };
 pub fn assemble(mnemonic: &str, args: &Arguments) -> Result<u32, ArgumentError> {
-    if let Some(&(fn_ptr, modifiers)) = MNEMONIC_MAP.get(mnemonic) {
+    if let Some(&(fn_ptr, modifiers, _)) = MNEMONIC_MAP.get(mnemonic) {
         fn_ptr(args, modifiers)
     } else {
         Err(ArgumentError::UnknownMnemonic)
     }
 }
+/// Assembles an instruction located at `address`.
+///
+/// Branch targets are absolute addresses, and are converted to the
+/// displacement encoded by the instruction. (Unless it is an absolute branch)
+pub fn assemble_at(
+    address: u32,
+    mnemonic: &str,
+    args: &Arguments,
+) -> Result<u32, ArgumentError> {
+    if let Some(&(fn_ptr, modifiers, branch)) = MNEMONIC_MAP.get(mnemonic) {
+        let mut args = *args;
+        if let Some((min, max)) = branch {
+            let absolute = modifiers & 0x2 != 0;
+            resolve_branch(&mut args, address, absolute, min, max)?;
+        }
+        fn_ptr(&args, modifiers)
+    } else {
+        Err(ArgumentError::UnknownMnemonic)
+    }
+}
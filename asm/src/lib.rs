@@ -1,6 +1,10 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 mod generated;
+#[cfg(feature = "disasm")]
+mod parsed;
 mod types;
 
 pub use generated::*;
-pub use types::{Argument, ArgumentError};
+#[cfg(feature = "disasm")]
+pub use parsed::assemble_parsed;
+pub use types::{ArgKind, Argument, ArgumentError};
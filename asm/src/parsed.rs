@@ -0,0 +1,44 @@
+use crate::{assemble, Argument, ArgumentError, Arguments};
+
+impl From<powerpc::Argument> for Argument {
+    fn from(arg: powerpc::Argument) -> Self {
+        match arg {
+            powerpc::Argument::None => Argument::None,
+            powerpc::Argument::GPR(x) => Argument::GPR(x.0),
+            powerpc::Argument::FPR(x) => Argument::FPR(x.0),
+            powerpc::Argument::SR(x) => Argument::SR(x.0),
+            powerpc::Argument::SPR(x) => Argument::SPR(x.0),
+            powerpc::Argument::CRField(x) => Argument::CRField(x.0),
+            powerpc::Argument::CRBit(x) => Argument::CRBit(x.0),
+            powerpc::Argument::GQR(x) => Argument::GQR(x.0),
+            powerpc::Argument::Uimm(x) => Argument::Unsigned(x.0 as u32),
+            powerpc::Argument::Simm(x) => Argument::Signed(x.0 as i32),
+            powerpc::Argument::Offset(x) => Argument::Signed(x.0 as i32),
+            powerpc::Argument::BranchDest(x) => Argument::Signed(x.0),
+            powerpc::Argument::OpaqueU(x) => Argument::Unsigned(x.0 as u32),
+            powerpc::Argument::VR(x) => Argument::VR(x.0),
+            powerpc::Argument::D3DType(x) => Argument::D3DType(x.0),
+            powerpc::Argument::PackMask(x) => Argument::PackMask(x.0),
+            powerpc::Argument::Swizzle(x) => Argument::Swizzle(x.0),
+        }
+    }
+}
+
+/// Assembles a disassembled instruction, in either its basic or simplified form.
+///
+/// ```
+/// use powerpc::{Extensions, Ins};
+/// use powerpc_asm::assemble_parsed;
+///
+/// let ins = Ins::new(0x38049000, Extensions::none());
+/// assert_eq!(ins.simplified().to_string(), "subi r0, r4, 0x7000");
+/// assert_eq!(assemble_parsed(&ins.simplified()).unwrap(), 0x38049000);
+/// assert_eq!(assemble_parsed(&ins.basic()).unwrap(), 0x38049000);
+/// ```
+pub fn assemble_parsed(parsed: &powerpc::ParsedIns) -> Result<u32, ArgumentError> {
+    let mut args = Arguments::default();
+    for (out, &arg) in args.iter_mut().zip(parsed.args.iter()) {
+        *out = arg.into();
+    }
+    assemble(parsed.mnemonic, &args)
+}
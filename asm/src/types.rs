@@ -1,4 +1,5 @@
 use crate::Arguments;
+use core::fmt::{self, Display, Formatter};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -13,16 +14,162 @@ pub enum ArgumentError {
     ArgOutOfRangeSigned { index: usize, value: i32, start: i32, end: i32 },
     #[error("unexpected number of arguments: {value} (expected at most {expected})")]
     ArgCount { value: usize, expected: usize },
+    #[error("argument index {index} has the wrong kind: {found} (expected {expected})")]
+    ArgKindMismatch { index: usize, found: ArgKind, expected: ArgKind },
+    #[error(
+        "branch target {target:#x} is out of range (expected between {start:#x} and {end:#x})"
+    )]
+    BranchOutOfRange { target: u32, start: u32, end: u32 },
+    #[error("branch target {target:#x} is not a multiple of 4")]
+    BranchMisaligned { target: u32 },
     #[error("unknown instruction mnemonic")]
     UnknownMnemonic,
 }
 
+/// An instruction argument.
+///
+/// [Argument::Unsigned] and [Argument::Signed] are untyped and accepted for any field.
+/// The register variants are only accepted for fields of the same kind.
 #[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
 pub enum Argument {
     #[default]
     None,
     Unsigned(u32),
     Signed(i32),
+    GPR(u8),
+    FPR(u8),
+    VR(u8),
+    CRField(u8),
+    CRBit(u8),
+    SPR(u16),
+    GQR(u8),
+    SR(u8),
+    D3DType(u8),
+    PackMask(u8),
+    Swizzle(u8),
+}
+
+impl Argument {
+    /// The kind of the argument, or `None` if it is untyped.
+    pub const fn kind(self) -> Option<ArgKind> {
+        match self {
+            Argument::None | Argument::Unsigned(_) | Argument::Signed(_) => None,
+            Argument::GPR(_) => Some(ArgKind::GPR),
+            Argument::FPR(_) => Some(ArgKind::FPR),
+            Argument::VR(_) => Some(ArgKind::VR),
+            Argument::CRField(_) => Some(ArgKind::CRField),
+            Argument::CRBit(_) => Some(ArgKind::CRBit),
+            Argument::SPR(_) => Some(ArgKind::SPR),
+            Argument::GQR(_) => Some(ArgKind::GQR),
+            Argument::SR(_) => Some(ArgKind::SR),
+            Argument::D3DType(_) => Some(ArgKind::D3DType),
+            Argument::PackMask(_) => Some(ArgKind::PackMask),
+            Argument::Swizzle(_) => Some(ArgKind::Swizzle),
+        }
+    }
+
+    /// Converts a typed argument into its untyped value.
+    const fn untyped(self) -> Argument {
+        match self {
+            Argument::GPR(value)
+            | Argument::FPR(value)
+            | Argument::VR(value)
+            | Argument::CRField(value)
+            | Argument::CRBit(value)
+            | Argument::GQR(value)
+            | Argument::SR(value)
+            | Argument::D3DType(value)
+            | Argument::PackMask(value)
+            | Argument::Swizzle(value) => Argument::Unsigned(value as u32),
+            Argument::SPR(value) => Argument::Unsigned(value as u32),
+            arg => arg,
+        }
+    }
+
+    /// Parses a symbolic argument, as printed by the disassembler: a VMX128 D3D pack
+    /// format (e.g. `FLOAT16_2`), pack mask (e.g. `VPACK_64LO`) or word swizzle (e.g. `wzyx`).
+    pub fn from_name(name: &str) -> Option<Argument> {
+        if let Some(i) = D3D_TYPE_NAMES.iter().position(|&n| n == name) {
+            return Some(Argument::D3DType(i as u8));
+        }
+        if let Some(i) = PACK_MASK_NAMES.iter().position(|&n| n == name) {
+            return Some(Argument::PackMask(i as u8 + 1));
+        }
+        if name.len() != 4 {
+            return None;
+        }
+        let mut value = 0;
+        for c in name.bytes() {
+            let word = b"xyzw".iter().position(|&w| w == c)?;
+            value = value << 2 | word as u8;
+        }
+        Some(Argument::Swizzle(value))
+    }
+}
+
+/// Names of the VMX128 D3D pack formats.
+const D3D_TYPE_NAMES: [&str; 7] = [
+    "D3DCOLOR",
+    "NORMSHORT2",
+    "NORMPACKED32",
+    "FLOAT16_2",
+    "NORMSHORT4",
+    "FLOAT16_4",
+    "NORMPACKED64",
+];
+
+/// Names of the VMX128 pack masks, starting at 1.
+const PACK_MASK_NAMES: [&str; 3] = ["VPACK_32", "VPACK_64LO", "VPACK_64HI"];
+
+/// The kind of value expected by an instruction field.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum ArgKind {
+    /// An immediate value. (e.g. `simm`, `BD`, `SH`)
+    Immediate,
+    GPR,
+    FPR,
+    VR,
+    CRField,
+    CRBit,
+    SPR,
+    GQR,
+    SR,
+    D3DType,
+    PackMask,
+    Swizzle,
+}
+
+impl Display for ArgKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ArgKind::Immediate => "immediate",
+            ArgKind::GPR => "GPR",
+            ArgKind::FPR => "FPR",
+            ArgKind::VR => "VR",
+            ArgKind::CRField => "CR field",
+            ArgKind::CRBit => "CR bit",
+            ArgKind::SPR => "SPR",
+            ArgKind::GQR => "GQR",
+            ArgKind::SR => "SR",
+            ArgKind::D3DType => "D3D type",
+            ArgKind::PackMask => "pack mask",
+            ArgKind::Swizzle => "swizzle",
+        })
+    }
+}
+
+/// Checks that a typed argument matches the kind of the field.
+const fn check_kind(
+    args: &Arguments,
+    index: usize,
+    expected: ArgKind,
+) -> Result<(), ArgumentError> {
+    match args[index].kind() {
+        Some(found) if found as u8 != expected as u8 => {
+            Err(ArgumentError::ArgKindMismatch { index, found, expected })
+        }
+        _ => Ok(()),
+    }
 }
 
 pub const fn parse_unsigned(
@@ -30,8 +177,12 @@ pub const fn parse_unsigned(
     index: usize,
     start: u32,
     end: u32,
+    kind: ArgKind,
 ) -> Result<u32, ArgumentError> {
-    match args[index] {
+    if let Err(e) = check_kind(args, index, kind) {
+        return Err(e);
+    }
+    match args[index].untyped() {
         Argument::Unsigned(value) => {
             if value >= start && value <= end {
                 Ok(value)
@@ -46,7 +197,7 @@ pub const fn parse_unsigned(
                 Err(ArgumentError::ArgOutOfRangeUnsigned { index, value: value as u32, start, end })
             }
         }
-        Argument::None => Err(ArgumentError::ArgCount { value: index, expected: index + 1 }),
+        _ => Err(ArgumentError::ArgCount { value: index, expected: index + 1 }),
     }
 }
 
@@ -55,8 +206,12 @@ pub const fn parse_signed(
     index: usize,
     start: i32,
     end: i32,
+    kind: ArgKind,
 ) -> Result<i32, ArgumentError> {
-    match args[index] {
+    if let Err(e) = check_kind(args, index, kind) {
+        return Err(e);
+    }
+    match args[index].untyped() {
         Argument::Unsigned(value) => {
             if (start < 0 || value >= start as u32) && value <= end as u32 {
                 Ok(value as i32)
@@ -71,7 +226,7 @@ pub const fn parse_signed(
                 Err(ArgumentError::ArgOutOfRangeSigned { index, value, start, end })
             }
         }
-        Argument::None => Err(ArgumentError::ArgCount { value: index, expected: index + 1 }),
+        _ => Err(ArgumentError::ArgCount { value: index, expected: index + 1 }),
     }
 }
 
@@ -91,3 +246,43 @@ pub const fn check_arg_count(args: &Arguments, expected: usize) -> Result<(), Ar
         Err(ArgumentError::ArgCount { value, expected })
     }
 }
+
+/// Replaces the branch target (the last argument) with the displacement from `address`.
+///
+/// Absolute branches use the target itself, which must be reachable from address 0.
+pub(crate) const fn resolve_branch(
+    args: &mut Arguments,
+    address: u32,
+    absolute: bool,
+    min: i32,
+    max: i32,
+) -> Result<(), ArgumentError> {
+    let index = arg_count(args);
+    if index == 0 {
+        // Let the mnemonic report the missing argument
+        return Ok(());
+    }
+    let index = index - 1;
+    if let Err(e) = check_kind(args, index, ArgKind::Immediate) {
+        return Err(e);
+    }
+    let target = match args[index] {
+        Argument::Unsigned(value) => value,
+        Argument::Signed(value) => value as u32,
+        _ => unreachable!(), // Checked above
+    };
+    if target & 3 != 0 {
+        return Err(ArgumentError::BranchMisaligned { target });
+    }
+    let base = if absolute { 0 } else { address };
+    let value = target.wrapping_sub(base) as i32;
+    if value < min || value > max {
+        return Err(ArgumentError::BranchOutOfRange {
+            target,
+            start: base.wrapping_add(min as u32),
+            end: base.wrapping_add(max as u32),
+        });
+    }
+    args[index] = Argument::Signed(value);
+    Ok(())
+}
@@ -215,3 +215,123 @@ fn test_dss() {
     assert_asm!("dss", U(0), U(1), 0x7E00066C); // dss 0, 1
     assert_asm!("dssall", 0x7E00066C); // dssall
 }
+
+#[test]
+fn test_typed_args() {
+    use Argument::{CRField, FPR, GPR, SPR};
+    assert_asm!("lfs", FPR(1), S(8), GPR(3), 0xC0230008); // lfs f1, 0x8(r3)
+    assert_asm!("mfspr", GPR(3), SPR(1008), 0x7C70FAA6); // mfspr r3, HID0
+    assert_asm!("beq", CRField(1), S(8), 0x41860008); // beq cr1, 0x8
+
+    // Untyped arguments are accepted for any field
+    assert_asm!("lfs", U(1), S(8), GPR(3), 0xC0230008); // lfs f1, 0x8(r3)
+}
+
+#[test]
+fn test_typed_args_mismatch() {
+    use Argument::{CRField, FPR, GPR};
+    assert!(matches!(
+        assemble("lfs", &[GPR(1), S(8), GPR(3), N, N]),
+        Err(ArgumentError::ArgKindMismatch {
+            index: 0,
+            found: ArgKind::GPR,
+            expected: ArgKind::FPR
+        })
+    ));
+    assert!(matches!(
+        assemble("addi", &[GPR(1), GPR(3), GPR(4), N, N]),
+        Err(ArgumentError::ArgKindMismatch {
+            index: 2,
+            found: ArgKind::GPR,
+            expected: ArgKind::Immediate
+        })
+    ));
+    assert!(matches!(
+        assemble("beq", &[FPR(1), S(8), N, N, N]),
+        Err(ArgumentError::ArgKindMismatch {
+            index: 0,
+            found: ArgKind::FPR,
+            expected: ArgKind::CRField
+        })
+    ));
+    assert_eq!(
+        assemble("add", &[GPR(1), CRField(3), GPR(4), N, N]).unwrap_err().to_string(),
+        "argument index 1 has the wrong kind: CR field (expected GPR)"
+    );
+}
+
+#[test]
+fn test_symbolic_args() {
+    use Argument::{D3DType, PackMask, Swizzle, VR};
+    let name = |name| Argument::from_name(name).unwrap();
+    assert_eq!(name("FLOAT16_4"), D3DType(5));
+    assert_eq!(name("VPACK_64LO"), PackMask(2));
+    assert_eq!(name("wzyx"), Swizzle(0xE4));
+    assert_eq!(Argument::from_name("xyz"), None);
+    assert_eq!(Argument::from_name("VPACK_16"), None);
+    assert_asm!(
+        "vpkd3d128",
+        VR(105),
+        VR(27),
+        name("FLOAT16_4"),
+        name("VPACK_32"),
+        U(3),
+        0x1935DEDC // vpkd3d128 v105, v27, FLOAT16_4, VPACK_32, 3
+    );
+    assert_asm!("vpermwi128", VR(100), VR(98), name("wzyx"), 0x188413DF); // vpermwi128 v100, v98, wzyx
+    assert_asm!("vpermwi128", VR(110), VR(72), U(99), 0x19C342DE); // vpermwi128 v110, v72, yzxw
+    assert_eq!(
+        assemble("vpkd3d128", &[VR(1), VR(2), PackMask(1), U(1), U(0)]).unwrap_err().to_string(),
+        "argument index 2 has the wrong kind: pack mask (expected D3D type)"
+    );
+}
+
+#[test]
+fn test_assemble_at() {
+    let args = |target: u32| [U(target), N, N, N, N];
+    assert_eq!(assemble_at(0x80003100, "b", &args(0x80003200)).unwrap(), 0x48000100); // b 0x100
+    assert_eq!(assemble_at(0x80003100, "bl", &args(0x80003000)).unwrap(), 0x4BFFFF01); // bl -0x100
+    assert_eq!(assemble_at(0x80003100, "ba", &args(0x60)).unwrap(), 0x48000062); // ba 0x60
+    assert_eq!(assemble_at(0x80003100, "beq", &args(0x80003108)).unwrap(), 0x41820008); // beq 0x8
+    assert_eq!(
+        assemble_at(0x80003100, "bne", &[Argument::CRField(1), U(0x800030F0), N, N, N]).unwrap(),
+        0x4086FFF0 // bne cr1, -0x10
+    );
+    assert_eq!(
+        assemble_at(0x80003100, "bc", &[U(12), U(2), U(0x80003110), N, N]).unwrap(),
+        0x41820010 // bc 12, 2, 0x10
+    );
+    // bdnz -0x4
+    assert_eq!(assemble_at(0x80003100, "bdnz", &args(0x800030FC)).unwrap(), 0x4200FFFC);
+
+    // Non-branch mnemonics are unaffected
+    assert_eq!(assemble_at(0x80003100, "blr", &[N, N, N, N, N]).unwrap(), 0x4E800020);
+    assert_eq!(
+        assemble_at(0x80003100, "addi", &[U(3), U(0), S(1), N, N]).unwrap(),
+        0x38600001 // li r3, 0x1
+    );
+}
+
+#[test]
+fn test_assemble_at_errors() {
+    assert!(matches!(
+        assemble_at(0x80003100, "beq", &[U(0x80013100), N, N, N, N]),
+        Err(ArgumentError::BranchOutOfRange {
+            target: 0x80013100,
+            start: 0x7FFFB100,
+            end: 0x8000B0FC
+        })
+    ));
+    assert!(matches!(
+        assemble_at(0x80003100, "ba", &[U(0x80000000), N, N, N, N]),
+        Err(ArgumentError::BranchOutOfRange {
+            target: 0x80000000,
+            start: 0xFE000000,
+            end: 0x1FFFFFC
+        })
+    ));
+    assert!(matches!(
+        assemble_at(0x80003100, "b", &[U(0x80003102), N, N, N, N]),
+        Err(ArgumentError::BranchMisaligned { target: 0x80003102 })
+    ));
+}
@@ -0,0 +1,66 @@
+use powerpc::{Extensions, Ins, Opcode};
+use powerpc_asm::assemble_parsed;
+
+const EXTENSIONS: Extensions = Extensions::from_bitmask(u32::MAX);
+
+macro_rules! assert_round_trip {
+    ($code:literal, $simplified:literal) => {{
+        let ins = Ins::new($code, EXTENSIONS);
+        let simplified = ins.simplified();
+        assert_eq!(simplified.to_string(), $simplified);
+        assert_eq!(assemble_parsed(&simplified).unwrap(), $code, "{}", simplified);
+        let basic = ins.basic();
+        assert_eq!(assemble_parsed(&basic).unwrap(), $code, "{}", basic);
+    }};
+}
+
+#[test]
+fn test_round_trip_replace() {
+    assert_round_trip!(0x38049000, "subi r0, r4, 0x7000");
+    assert_round_trip!(0x3C04FFFF, "subis r0, r4, 0x1");
+    assert_round_trip!(0x3060FFFF, "subic r3, r0, 0x1");
+    assert_round_trip!(0x37E3FFFF, "subic. r31, r3, 0x1");
+    assert_round_trip!(0x54000034, "clrrwi r0, r0, 5");
+    assert_round_trip!(0x5400C83E, "rotrwi r0, r0, 7");
+    assert_round_trip!(0x54640FBC, "clrlslwi r4, r3, 31, 1");
+    assert_round_trip!(0x54096226, "clrlslwi r9, r0, 20, 12");
+    assert_round_trip!(0x5400401E, "extlwi r0, r0, 16, 8");
+    assert_round_trip!(0x5408C67E, "extrwi r8, r0, 7, 17");
+}
+
+#[test]
+fn test_round_trip_modifiers() {
+    assert_round_trip!(0x7F9DF615, "addo. r28, r29, r30");
+    assert_round_trip!(0x4BE03C99, "bl -0x1fc368");
+    assert_round_trip!(0x48000063, "bla 0x60");
+    assert_round_trip!(0x41A20008, "beq+ 0x8");
+    assert_round_trip!(0x4E800021, "blrl");
+}
+
+#[test]
+fn test_round_trip_symbolic() {
+    assert_round_trip!(0x1935DEDC, "vpkd3d128 v105, v27, FLOAT16_4, VPACK_32, 3");
+    assert_round_trip!(0x19C342DE, "vpermwi128 v110, v72, yzxw");
+}
+
+#[test]
+fn test_round_trip_all_opcodes() {
+    // Fill in the non-fixed bits of every opcode using a simple xorshift PRNG
+    let mut state = 0x12345678u32;
+    for op in (0..u16::MAX).map(Opcode::from).take_while(|&op| op != Opcode::Illegal) {
+        for _ in 0..256 {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let code = op.pattern() | (state & !op.bitmask());
+            let ins = Ins::new(code, EXTENSIONS);
+            if ins.op != op {
+                // Shadowed by another opcode
+                continue;
+            }
+            for parsed in [ins.basic(), ins.simplified()] {
+                assert_eq!(assemble_parsed(&parsed).ok(), Some(code), "{code:#010x} {parsed}");
+            }
+        }
+    }
+}
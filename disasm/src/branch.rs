@@ -0,0 +1,207 @@
+use crate::{CRBit, EncodeError, FieldId, Ins, Opcode};
+
+/// The condition of a conditional branch, decoded from its BO and BI fields.
+///
+/// ```
+/// use powerpc::{BranchCondition, CRBit, CrCondition, Extensions, Ins};
+///
+/// let ins = Ins::new(0x4082FFF0, Extensions::none()); // bne -0x10
+/// let cond = ins.branch_condition().unwrap();
+/// assert_eq!(cond.cr, Some(CrCondition { bit: CRBit(2), value: false }));
+/// assert_eq!(cond.invert().unwrap().encode(), Some((13, 2))); // beq-
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BranchCondition {
+    /// Decrement CTR, and test its new value.
+    pub ctr: Option<CtrCondition>,
+    /// Test a bit of the condition register.
+    pub cr: Option<CrCondition>,
+    /// The static branch prediction hint.
+    pub hint: BranchHint,
+}
+
+/// The CTR test of a conditional branch, performed after decrementing CTR.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum CtrCondition {
+    /// Branch if CTR is zero. (e.g. `bdz`)
+    Zero,
+    /// Branch if CTR is not zero. (e.g. `bdnz`)
+    NonZero,
+}
+
+/// The condition register test of a conditional branch.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct CrCondition {
+    /// The tested bit.
+    pub bit: CRBit,
+    /// The value of the bit required to branch.
+    pub value: bool,
+}
+
+/// A static branch prediction hint.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum BranchHint {
+    /// No hint: use the default prediction.
+    #[default]
+    None,
+    /// The `y` bit is set, reversing the default prediction.
+    ///
+    /// By default, `bc` with a negative displacement is predicted taken,
+    /// and everything else is predicted not taken.
+    Reverse,
+    /// The `at` hint `0b10`: predict not taken.
+    ///
+    /// Only encodable when exactly one of CTR or CR is tested.
+    NotTaken,
+    /// The `at` hint `0b11`: predict taken.
+    ///
+    /// Only encodable when exactly one of CTR or CR is tested.
+    Taken,
+}
+
+impl BranchCondition {
+    /// A condition that always branches. (BO = 20)
+    pub const ALWAYS: Self = Self { ctr: None, cr: None, hint: BranchHint::None };
+
+    /// Decodes the BO and BI fields of a conditional branch.
+    ///
+    /// Bits that are ignored by the processor (the `z` bits) are discarded.
+    pub const fn decode(bo: u8, bi: u8) -> Self {
+        let ctr = if bo & 0x4 == 0 {
+            Some(if bo & 0x2 != 0 { CtrCondition::Zero } else { CtrCondition::NonZero })
+        } else {
+            None
+        };
+        let cr = if bo & 0x10 == 0 {
+            Some(CrCondition { bit: CRBit(bi & 0x1f), value: bo & 0x8 != 0 })
+        } else {
+            None
+        };
+        // The `at` bits are BO[3:4] when only testing CR, and BO[1] and BO[4]
+        // when only testing CTR. Otherwise, only the `y` bit (BO[4]) is available.
+        let at = match (ctr.is_some(), cr.is_some()) {
+            (false, true) => bo & 0x3,
+            (true, false) => ((bo >> 2) & 0x2) | (bo & 0x1),
+            (true, true) => bo & 0x1,
+            (false, false) => 0,
+        };
+        let hint = match at {
+            0b01 => BranchHint::Reverse,
+            0b10 => BranchHint::NotTaken,
+            0b11 => BranchHint::Taken,
+            _ => BranchHint::None,
+        };
+        Self { ctr, cr, hint }
+    }
+
+    /// Encodes the condition into BO and BI fields.
+    ///
+    /// Returns `None` if the hint cannot be encoded for this kind of condition.
+    pub const fn encode(self) -> Option<(u8, u8)> {
+        let at = match self.hint {
+            BranchHint::None => 0b00,
+            BranchHint::Reverse => 0b01,
+            BranchHint::NotTaken => 0b10,
+            BranchHint::Taken => 0b11,
+        };
+        let mut bo = 0;
+        let mut bi = 0;
+        match self.ctr {
+            Some(CtrCondition::Zero) => bo |= 0x2,
+            Some(CtrCondition::NonZero) => {}
+            None => bo |= 0x4,
+        }
+        match self.cr {
+            Some(CrCondition { bit, value }) => {
+                bi = bit.0;
+                if value {
+                    bo |= 0x8;
+                }
+            }
+            None => bo |= 0x10,
+        }
+        match (self.ctr.is_some(), self.cr.is_some()) {
+            (false, true) => bo |= at,
+            (true, false) => bo |= ((at & 0x2) << 2) | (at & 0x1),
+            (true, true) if at <= 0b01 => bo |= at,
+            (false, false) if at == 0b00 => {}
+            _ => return None,
+        }
+        Some((bo, bi))
+    }
+
+    /// Whether the branch is always taken.
+    #[inline]
+    pub const fn always_taken(self) -> bool {
+        self.ctr.is_none() && self.cr.is_none()
+    }
+
+    /// Whether the branch decrements and tests CTR.
+    #[inline]
+    pub const fn uses_ctr(self) -> bool {
+        self.ctr.is_some()
+    }
+
+    /// Returns the opposite condition, if it can be expressed.
+    ///
+    /// Conditions testing both CTR and CR, or neither, cannot be inverted.
+    /// The hint is adjusted so that the same path remains predicted.
+    pub const fn invert(self) -> Option<Self> {
+        let (ctr, cr) = match (self.ctr, self.cr) {
+            (Some(CtrCondition::Zero), None) => (Some(CtrCondition::NonZero), None),
+            (Some(CtrCondition::NonZero), None) => (Some(CtrCondition::Zero), None),
+            (None, Some(CrCondition { bit, value })) => {
+                (None, Some(CrCondition { bit, value: !value }))
+            }
+            _ => return None,
+        };
+        let hint = match self.hint {
+            BranchHint::None => BranchHint::Reverse,
+            BranchHint::Reverse => BranchHint::None,
+            BranchHint::NotTaken => BranchHint::Taken,
+            BranchHint::Taken => BranchHint::NotTaken,
+        };
+        Some(Self { ctr, cr, hint })
+    }
+
+    /// Whether the branch is statically predicted taken.
+    ///
+    /// `backward` is whether the branch has a negative displacement. (Always false for
+    /// `bclr` and `bcctr`)
+    pub const fn predict_taken(self, backward: bool) -> bool {
+        if self.always_taken() {
+            return true;
+        }
+        match self.hint {
+            BranchHint::None => backward,
+            BranchHint::Reverse => !backward,
+            BranchHint::NotTaken => false,
+            BranchHint::Taken => true,
+        }
+    }
+}
+
+impl Ins {
+    /// Returns the condition of a `bc`, `bclr` or `bcctr` instruction.
+    pub fn branch_condition(&self) -> Option<BranchCondition> {
+        match self.op {
+            Opcode::Bc | Opcode::Bcctr | Opcode::Bclr => {
+                Some(BranchCondition::decode(self.field_bo(), self.field_bi()))
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns a copy of the conditional branch with its condition replaced.
+    pub fn with_branch_condition(self, cond: BranchCondition) -> Result<Self, EncodeError> {
+        if !matches!(self.op, Opcode::Bc | Opcode::Bcctr | Opcode::Bclr) {
+            return Err(EncodeError::InvalidField { opcode: self.op, field: FieldId::BO });
+        }
+        let Some((bo, bi)) = cond.encode() else {
+            return Err(EncodeError::InvalidBranchCondition);
+        };
+        let code = FieldId::BO.insert(self.code, bo as i32)?;
+        let code = FieldId::BI.insert(code, bi as i32)?;
+        self.redetect(code)
+    }
+}
@@ -1,8 +1,9 @@
 use crate::generated::{
-    parse_basic, parse_defs, parse_simplified, parse_uses, Arguments, Extension, Opcode, EMPTY_ARGS,
+    parse_basic, parse_defs, parse_simplified, parse_uses, Arguments, Extension, FieldId, Opcode,
+    EMPTY_ARGS, FIELD_INFO,
 };
 use core::{
-    fmt::{self, Display, Formatter, LowerHex},
+    fmt::{self, Display, Formatter, LowerHex, Write},
     hash::{Hash, Hasher},
     ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Not},
 };
@@ -77,6 +78,14 @@ impl Ins {
         out
     }
 
+    /// Returns an iterator over the arguments and modifiers of the instruction,
+    /// along with their decoded values.
+    ///
+    /// Modifiers are represented as [Argument::OpaqueU] with a value of 0 or 1.
+    pub fn fields(self) -> impl Iterator<Item = (FieldId, Argument)> {
+        self.op.fields().iter().chain(self.op.modifiers()).map(move |&id| (id, self.field(id)))
+    }
+
     /// Returns the relative branch offset of the instruction, if any.
     pub fn branch_offset(&self) -> Option<i32> {
         match self.op {
@@ -146,6 +155,141 @@ impl Hash for Opcode {
     }
 }
 
+/// A range of bits within an instruction, using big-endian bit numbering
+/// (bit 0 is the most significant bit).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BitRange(u8, u8);
+
+impl BitRange {
+    /// Creates a new bit range from `start` (inclusive) to `end` (exclusive).
+    #[inline]
+    pub const fn new(start: u8, end: u8) -> Self {
+        Self(start, end)
+    }
+
+    /// The first bit of the range.
+    #[inline]
+    pub const fn start(self) -> u8 {
+        self.0
+    }
+
+    /// The bit after the last bit of the range.
+    #[inline]
+    pub const fn end(self) -> u8 {
+        self.1
+    }
+
+    /// Number of bits in the range.
+    #[inline]
+    pub const fn len(self) -> u8 {
+        self.1 - self.0
+    }
+
+    /// Whether the range is empty.
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.1 <= self.0
+    }
+
+    /// Number of bits to shift a value to move it into the range.
+    #[inline]
+    pub const fn shift(self) -> u8 {
+        32 - self.1
+    }
+
+    /// The mask of the range within an instruction.
+    #[inline]
+    pub const fn mask(self) -> u32 {
+        (u32::MAX >> (32 - self.len() as u32)) << self.shift()
+    }
+}
+
+/// Metadata for an instruction field or modifier.
+pub(crate) struct FieldInfo {
+    pub(crate) name: &'static str,
+    pub(crate) desc: &'static str,
+    pub(crate) bits: &'static [BitRange],
+    pub(crate) signed: bool,
+    pub(crate) shift_left: u8,
+    pub(crate) modifier: bool,
+}
+
+impl FieldId {
+    #[inline]
+    fn info(self) -> &'static FieldInfo {
+        &FIELD_INFO[self as usize]
+    }
+
+    /// The name of the field, as written in isa.yaml.
+    #[inline]
+    pub fn name(self) -> &'static str {
+        self.info().name
+    }
+
+    /// A description of the field.
+    #[inline]
+    pub fn description(self) -> &'static str {
+        self.info().desc
+    }
+
+    /// The bit ranges of the field, from most to least significant.
+    #[inline]
+    pub fn bits(self) -> &'static [BitRange] {
+        self.info().bits
+    }
+
+    /// The mask of all bits of the field within an instruction.
+    pub fn mask(self) -> u32 {
+        self.bits().iter().fold(0, |acc, range| acc | range.mask())
+    }
+
+    /// The number of bits encoded in the instruction.
+    pub fn num_bits(self) -> u8 {
+        self.bits().iter().map(|range| range.len()).sum()
+    }
+
+    /// Whether the decoded value is sign-extended.
+    #[inline]
+    pub fn signed(self) -> bool {
+        self.info().signed
+    }
+
+    /// The number of bits the decoded value is shifted left by.
+    #[inline]
+    pub fn shift_left(self) -> u8 {
+        self.info().shift_left
+    }
+
+    /// Whether this is a modifier (a single bit that alters the mnemonic suffix).
+    #[inline]
+    pub fn is_modifier(self) -> bool {
+        self.info().modifier
+    }
+
+    /// Extracts the raw (unshifted, not sign-extended) bits of the field from an instruction.
+    pub fn extract(self, code: u32) -> u32 {
+        self.bits()
+            .iter()
+            .fold(0, |acc, range| (acc << range.len()) | ((code & range.mask()) >> range.shift()))
+    }
+}
+
+impl Hash for FieldId {
+    /// FieldId enum discriminants are not stable.
+    /// Instead, hash the field name.
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name().hash(state);
+    }
+}
+
+impl Display for FieldId {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
 macro_rules! field_arg_no_display {
     ($name:ident, $typ:ident) => {
         #[derive(Debug, Copy, Clone, Hash, Ord, PartialOrd, Eq, PartialEq)]
@@ -312,6 +456,68 @@ impl From<u8> for OpaqueU {
 }
 // Vector register.
 field_arg!(VR, u8, "v{}");
+// VMX128 D3D pack format.
+field_arg_no_display!(D3DType, u8);
+impl D3DType {
+    /// The name of the format, or `None` if it is reserved.
+    pub fn name(self) -> Option<&'static str> {
+        Some(match self.0 {
+            0 => "D3DCOLOR",
+            1 => "NORMSHORT2",
+            2 => "NORMPACKED32",
+            3 => "FLOAT16_2",
+            4 => "NORMSHORT4",
+            5 => "FLOAT16_4",
+            6 => "NORMPACKED64",
+            _ => return None,
+        })
+    }
+}
+impl Display for D3DType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+// VMX128 pack mask.
+field_arg_no_display!(PackMask, u8);
+impl PackMask {
+    /// The name of the mask, or `None` if it is reserved.
+    pub fn name(self) -> Option<&'static str> {
+        Some(match self.0 {
+            1 => "VPACK_32",
+            2 => "VPACK_64LO",
+            3 => "VPACK_64HI",
+            _ => return None,
+        })
+    }
+}
+impl Display for PackMask {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.name() {
+            Some(name) => f.write_str(name),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+// VMX128 word permutation, written as the source word (x, y, z or w) of each result word.
+field_arg_no_display!(Swizzle, u8);
+impl Swizzle {
+    /// The source word index of each result word.
+    pub fn words(self) -> [u8; 4] {
+        [self.0 >> 6, (self.0 >> 4) & 3, (self.0 >> 2) & 3, self.0 & 3]
+    }
+}
+impl Display for Swizzle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for word in self.words() {
+            f.write_char(char::from(b"xyzw"[word as usize]))?;
+        }
+        Ok(())
+    }
+}
 
 #[derive(Debug, Default, Copy, Clone, Eq, Hash, PartialEq)]
 pub enum Argument {
@@ -330,6 +536,9 @@ pub enum Argument {
     BranchDest(BranchDest),
     OpaqueU(OpaqueU),
     VR(VR),
+    D3DType(D3DType),
+    PackMask(PackMask),
+    Swizzle(Swizzle),
 }
 
 impl Display for Argument {
@@ -349,6 +558,9 @@ impl Display for Argument {
             Argument::BranchDest(x) => x.fmt(f),
             Argument::OpaqueU(x) => x.fmt(f),
             Argument::VR(x) => x.fmt(f),
+            Argument::D3DType(x) => x.fmt(f),
+            Argument::PackMask(x) => x.fmt(f),
+            Argument::Swizzle(x) => x.fmt(f),
         }
     }
 }
@@ -0,0 +1,201 @@
+use crate::{Extensions, FieldId, Ins, Opcode};
+use core::fmt::{self, Display, Formatter};
+
+/// An error encountered while encoding an instruction.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum EncodeError {
+    /// The opcode is illegal and cannot be encoded.
+    IllegalOpcode,
+    /// The opcode does not have the given argument or modifier.
+    InvalidField { opcode: Opcode, field: FieldId },
+    /// A required argument was not provided.
+    MissingField { opcode: Opcode, field: FieldId },
+    /// The value does not fit in the field.
+    OutOfRange { field: FieldId, value: i32, min: i32, max: i32 },
+    /// The value is not a multiple of the field's alignment. (e.g. branch destinations)
+    Misaligned { field: FieldId, value: i32, align: u32 },
+    /// The modified instruction no longer decodes as the same opcode.
+    OpcodeChanged { from: Opcode, to: Opcode },
+    /// The branch hint cannot be encoded for the branch condition.
+    InvalidBranchCondition,
+}
+
+impl Display for EncodeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            EncodeError::IllegalOpcode => f.write_str("illegal opcode"),
+            EncodeError::InvalidField { opcode, field } => {
+                write!(f, "opcode {} has no field {}", opcode.mnemonic(), field)
+            }
+            EncodeError::MissingField { opcode, field } => {
+                write!(f, "opcode {} requires field {}", opcode.mnemonic(), field)
+            }
+            EncodeError::OutOfRange { field, value, min, max } => write!(
+                f,
+                "value {value:#x} for field {field} is out of range (expected between {min:#x} and {max:#x})"
+            ),
+            EncodeError::Misaligned { field, value, align } => {
+                write!(f, "value {value:#x} for field {field} is not a multiple of {align}")
+            }
+            EncodeError::OpcodeChanged { from, to } => {
+                write!(f, "instruction changed from {} to {}", from.mnemonic(), to.mnemonic())
+            }
+            EncodeError::InvalidBranchCondition => {
+                f.write_str("branch hint cannot be encoded for the branch condition")
+            }
+        }
+    }
+}
+
+impl FieldId {
+    /// The range of values accepted by the field, after applying its left shift.
+    pub fn value_range(self) -> (i32, i32) {
+        let bits = self.num_bits() as u32;
+        let (min, max) = if self.signed() {
+            (-(1i64 << (bits - 1)), (1i64 << (bits - 1)) - 1)
+        } else {
+            (0, (1i64 << bits) - 1)
+        };
+        ((min << self.shift_left()) as i32, (max << self.shift_left()) as i32)
+    }
+
+    /// Encodes a value into the field's bits of the given instruction code,
+    /// returning the updated code.
+    pub fn insert(self, code: u32, value: i32) -> Result<u32, EncodeError> {
+        let align = 1u32 << self.shift_left();
+        if value as u32 & (align - 1) != 0 {
+            return Err(EncodeError::Misaligned { field: self, value, align });
+        }
+        let (min, max) = self.value_range();
+        if value < min || value > max {
+            return Err(EncodeError::OutOfRange { field: self, value, min, max });
+        }
+        let mut raw = (value >> self.shift_left()) as u32;
+        let mut code = code & !self.mask();
+        for range in self.bits().iter().rev() {
+            code |= (raw << range.shift()) & range.mask();
+            raw >>= range.len();
+        }
+        Ok(code)
+    }
+}
+
+/// A builder for encoding an instruction from typed arguments.
+///
+/// Setters for each argument and modifier are generated from isa.yaml.
+/// Errors are deferred until [InsBuilder::build] is called.
+///
+/// ```
+/// use powerpc::{Ins, Opcode, Simm, GPR};
+///
+/// let ins = Ins::builder(Opcode::Addi).rd(GPR(5)).ra(GPR(0)).simm(Simm(0)).build();
+/// assert_eq!(ins.unwrap().code, 0x38A00000);
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct InsBuilder {
+    op: Opcode,
+    code: u32,
+    /// Bitset of the provided arguments, indexed by their position in [Opcode::fields].
+    provided: u8,
+    error: Option<EncodeError>,
+}
+
+impl InsBuilder {
+    /// Creates a new builder for the given opcode, with all fields set to zero.
+    pub fn new(op: Opcode) -> Self {
+        let error = if op == Opcode::Illegal { Some(EncodeError::IllegalOpcode) } else { None };
+        Self { op, code: op.pattern(), provided: 0, error }
+    }
+
+    /// Sets an argument of the instruction from its raw value.
+    pub fn field(mut self, id: FieldId, value: i32) -> Self {
+        if self.error.is_some() {
+            return self;
+        }
+        let Some(idx) = self.op.fields().iter().position(|&f| f == id) else {
+            self.error = Some(EncodeError::InvalidField { opcode: self.op, field: id });
+            return self;
+        };
+        match id.insert(self.code, value) {
+            Ok(code) => {
+                self.code = code;
+                self.provided |= 1 << idx;
+            }
+            Err(e) => self.error = Some(e),
+        }
+        self
+    }
+
+    /// Sets or clears a modifier of the instruction.
+    pub fn modifier(mut self, id: FieldId, value: bool) -> Self {
+        if self.error.is_some() {
+            return self;
+        }
+        if !self.op.modifiers().contains(&id) {
+            self.error = Some(EncodeError::InvalidField { opcode: self.op, field: id });
+            return self;
+        }
+        let mask = id.mask();
+        self.code = if value { self.code | mask } else { self.code & !mask };
+        self
+    }
+
+    /// Finishes encoding the instruction.
+    ///
+    /// Fails if any argument was invalid or not provided. The opcode is not detected
+    /// again: arguments and modifiers never overlap the fixed bits of the opcode, so the
+    /// instruction decodes as the opcode with the extensions it requires.
+    pub fn build(self) -> Result<Ins, EncodeError> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        for (idx, &field) in self.op.fields().iter().enumerate() {
+            if self.provided & (1 << idx) == 0 {
+                return Err(EncodeError::MissingField { opcode: self.op, field });
+            }
+        }
+        Ok(Ins { code: self.code, op: self.op })
+    }
+}
+
+impl Ins {
+    /// Creates a builder for encoding an instruction with the given opcode.
+    #[inline]
+    pub fn builder(op: Opcode) -> InsBuilder {
+        InsBuilder::new(op)
+    }
+
+    /// Returns a copy of the instruction with the given field replaced by a raw value.
+    ///
+    /// The opcode must have the field, and the result is decoded again, and must
+    /// still be the same opcode.
+    pub fn with_field(self, id: FieldId, value: i32) -> Result<Self, EncodeError> {
+        if !self.op.fields().contains(&id) {
+            return Err(EncodeError::InvalidField { opcode: self.op, field: id });
+        }
+        let code = id.insert(self.code, value)?;
+        self.redetect(code)
+    }
+
+    /// Sets or clears a modifier of the instruction in place.
+    ///
+    /// The opcode must support the modifier. (e.g. `Rc` for `add`, but not for `addi`)
+    pub fn set_modifier(&mut self, id: FieldId, value: bool) -> Result<(), EncodeError> {
+        if !self.op.modifiers().contains(&id) {
+            return Err(EncodeError::InvalidField { opcode: self.op, field: id });
+        }
+        let mask = id.mask();
+        *self = self.redetect(if value { self.code | mask } else { self.code & !mask })?;
+        Ok(())
+    }
+
+    /// Decodes the updated code using the extensions required by the current opcode.
+    pub(crate) fn redetect(self, code: u32) -> Result<Self, EncodeError> {
+        let extensions = self.op.extension().map_or(Extensions::none(), Extensions::from_extension);
+        let ins = Ins::new(code, extensions);
+        if ins.op != self.op {
+            return Err(EncodeError::OpcodeChanged { from: self.op, to: ins.op });
+        }
+        Ok(ins)
+    }
+}
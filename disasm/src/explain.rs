@@ -0,0 +1,187 @@
+use crate::{Argument, BitRange, FieldId, Ins};
+use core::fmt::{self, Display, Formatter};
+
+/// A breakdown of how an instruction is encoded.
+///
+/// Displaying an explanation renders a bit diagram of the instruction,
+/// followed by one line per group of bits:
+///
+/// ```text
+/// 011111 00010 00011 00100 0 100001010 0
+///  0..6   011111     fixed
+///  6..11  00010      rD = r2
+/// 11..16  00011      rA = r3
+/// 16..21  00100      rB = r4
+/// 21      0          OE = 0
+/// 22..31  100001010  fixed
+/// 31      0          Rc = 0
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Explanation {
+    ins: Ins,
+}
+
+/// The encoding of a single argument or modifier.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct FieldEncoding {
+    /// The field or modifier.
+    pub id: FieldId,
+    /// The bit ranges of the field, from most to least significant.
+    pub bits: &'static [BitRange],
+    /// The raw (unshifted, not sign-extended) value of the bits.
+    pub raw: u32,
+    /// The decoded value.
+    pub argument: Argument,
+}
+
+/// What a bit of an instruction encodes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum BitOwner {
+    /// Part of the fixed opcode pattern.
+    Fixed,
+    /// Part of an argument or modifier.
+    Field(FieldId),
+    /// Not used by the instruction. (Only for illegal instructions)
+    Unused,
+}
+
+/// A contiguous group of bits with the same owner.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct BitSegment {
+    /// The range of bits.
+    pub range: BitRange,
+    /// What the bits encode.
+    pub owner: BitOwner,
+    /// The value of the bits.
+    pub raw: u32,
+}
+
+impl Explanation {
+    /// Creates an explanation for the given instruction.
+    #[inline]
+    pub fn new(ins: Ins) -> Self {
+        Self { ins }
+    }
+
+    /// The explained instruction.
+    #[inline]
+    pub fn ins(&self) -> Ins {
+        self.ins
+    }
+
+    /// The mask of the fixed opcode bits.
+    #[inline]
+    pub fn fixed_mask(&self) -> u32 {
+        self.ins.op.bitmask()
+    }
+
+    /// The value of the fixed opcode bits.
+    #[inline]
+    pub fn fixed_bits(&self) -> u32 {
+        self.ins.code & self.fixed_mask()
+    }
+
+    /// Returns the encoding of each argument and modifier of the instruction.
+    pub fn fields(&self) -> impl Iterator<Item = FieldEncoding> {
+        let ins = self.ins;
+        ins.fields().map(move |(id, argument)| FieldEncoding {
+            id,
+            bits: id.bits(),
+            raw: id.extract(ins.code),
+            argument,
+        })
+    }
+
+    /// Returns what the given bit (0 being the most significant) encodes.
+    ///
+    /// Arguments take precedence over modifiers sharing the same bits.
+    pub fn owner(&self, bit: u8) -> BitOwner {
+        let mask = BitRange::new(bit, bit + 1).mask();
+        let op = self.ins.op;
+        if let Some(&id) = op.fields().iter().chain(op.modifiers()).find(|id| id.mask() & mask != 0)
+        {
+            BitOwner::Field(id)
+        } else if op.bitmask() & mask != 0 {
+            BitOwner::Fixed
+        } else {
+            BitOwner::Unused
+        }
+    }
+
+    /// Returns the groups of consecutive bits with the same owner, in order.
+    pub fn segments(&self) -> impl Iterator<Item = BitSegment> + '_ {
+        let mut start = 0u8;
+        core::iter::from_fn(move || {
+            if start >= 32 {
+                return None;
+            }
+            let owner = self.owner(start);
+            let mut end = start + 1;
+            while end < 32 && self.owner(end) == owner {
+                end += 1;
+            }
+            let range = BitRange::new(start, end);
+            start = end;
+            Some(BitSegment { range, owner, raw: (self.ins.code & range.mask()) >> range.shift() })
+        })
+    }
+}
+
+impl Ins {
+    /// Returns a breakdown of how the instruction is encoded.
+    #[inline]
+    pub fn explain(self) -> Explanation {
+        Explanation::new(self)
+    }
+}
+
+impl BitSegment {
+    fn fmt_bits(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for i in (0..self.range.len()).rev() {
+            f.write_str(if self.raw & (1 << i) != 0 { "1" } else { "0" })?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for BitRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.len() == 1 {
+            write!(f, "{}", self.start())
+        } else {
+            write!(f, "{}..{}", self.start(), self.end())
+        }
+    }
+}
+
+impl Display for Explanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, segment) in self.segments().enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            segment.fmt_bits(f)?;
+        }
+        let width = self.segments().map(|s| s.range.len() as usize).max().unwrap_or(0);
+        for segment in self.segments() {
+            // Align the ranges on their ".." separator
+            let (start, end) = (segment.range.start(), segment.range.end());
+            let start_len = if start < 10 { 1 } else { 2 };
+            let end_len = match segment.range.len() {
+                1 => 0,
+                _ if end < 10 => 3,
+                _ => 4,
+            };
+            write!(f, "\n{:pad$}{}", "", segment.range, pad = 2 - start_len)?;
+            write!(f, "{:pad$}", "", pad = 6 - end_len)?;
+            segment.fmt_bits(f)?;
+            write!(f, "{:pad$}  ", "", pad = width - segment.range.len() as usize)?;
+            match segment.owner {
+                BitOwner::Fixed => f.write_str("fixed")?,
+                BitOwner::Unused => f.write_str("unused")?,
+                BitOwner::Field(id) => write!(f, "{} = {}", id.name(), self.ins.field(id))?,
+            }
+        }
+        Ok(())
+    }
+}
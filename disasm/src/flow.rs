@@ -0,0 +1,130 @@
+use crate::{BranchCondition, Ins, Opcode};
+
+/// How an instruction affects control flow.
+///
+/// ```
+/// use powerpc::{Extensions, FlowControl, Ins};
+///
+/// let ins = Ins::new(0x4D820020, Extensions::none()); // beqlr
+/// assert!(matches!(ins.flow_control(), FlowControl::Return { .. }));
+/// assert!(ins.flow_control().is_conditional());
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum FlowControl {
+    /// Execution continues with the next instruction.
+    Sequential,
+    /// A direct branch without link. (`b`, `bc`)
+    Branch { condition: BranchCondition },
+    /// A branch to CTR without link. (`bctr`, `bcctr`)
+    IndirectJump { condition: BranchCondition },
+    /// A branch with link. (`bl`, `bcl`, `bctrl`, `blrl`)
+    Call { condition: BranchCondition, indirect: bool },
+    /// A branch to LR without link. (`blr`, `beqlr`, `bclr`)
+    Return { condition: BranchCondition },
+    /// A conditional trap. (`tw`, `twi`, `td`, `tdi`)
+    Trap { condition: TrapCondition, doubleword: bool },
+    /// A system call. (`sc`)
+    SystemCall,
+    /// A return from interrupt. (`rfi`, `rfid`)
+    ExceptionReturn,
+}
+
+impl FlowControl {
+    /// The branch condition, if the instruction is a branch.
+    pub fn branch_condition(self) -> Option<BranchCondition> {
+        match self {
+            FlowControl::Branch { condition }
+            | FlowControl::IndirectJump { condition }
+            | FlowControl::Call { condition, .. }
+            | FlowControl::Return { condition } => Some(condition),
+            _ => None,
+        }
+    }
+
+    /// Whether execution may continue with the next instruction.
+    pub fn falls_through(self) -> bool {
+        match self {
+            FlowControl::Sequential | FlowControl::Call { .. } | FlowControl::SystemCall => true,
+            FlowControl::Trap { condition, .. } => !condition.always(),
+            FlowControl::ExceptionReturn => false,
+            _ => self.is_conditional(),
+        }
+    }
+
+    /// Whether the control flow change depends on a condition.
+    pub fn is_conditional(self) -> bool {
+        match self {
+            FlowControl::Trap { condition, .. } => !condition.always(),
+            _ => self.branch_condition().is_some_and(|c| !c.always_taken()),
+        }
+    }
+}
+
+/// The TO field of a trap instruction: a bitset of comparisons between
+/// `rA` and `rB` (or `simm`) that cause a trap.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct TrapCondition(pub u8);
+
+impl TrapCondition {
+    /// Trap if less than (signed).
+    pub const LT: u8 = 0x10;
+    /// Trap if greater than (signed).
+    pub const GT: u8 = 0x8;
+    /// Trap if equal.
+    pub const EQ: u8 = 0x4;
+    /// Trap if less than (unsigned).
+    pub const LLT: u8 = 0x2;
+    /// Trap if greater than (unsigned).
+    pub const LGT: u8 = 0x1;
+
+    /// Whether the trap is taken regardless of the operands. (e.g. `trap`)
+    #[inline]
+    pub const fn always(self) -> bool {
+        // Any two values are always either equal, less than or greater than each other
+        self.0 & Self::EQ != 0
+            && (self.0 & (Self::LT | Self::GT) == Self::LT | Self::GT
+                || self.0 & (Self::LLT | Self::LGT) == Self::LLT | Self::LGT)
+    }
+
+    /// Whether the trap is never taken.
+    #[inline]
+    pub const fn never(self) -> bool {
+        self.0 & 0x1f == 0
+    }
+
+    /// Whether the trap is taken for the given operands.
+    pub const fn test(self, a: i64, b: i64) -> bool {
+        (self.0 & Self::LT != 0 && a < b)
+            || (self.0 & Self::GT != 0 && a > b)
+            || (self.0 & Self::EQ != 0 && a == b)
+            || (self.0 & Self::LLT != 0 && (a as u64) < (b as u64))
+            || (self.0 & Self::LGT != 0 && (a as u64) > (b as u64))
+    }
+}
+
+impl Ins {
+    /// Returns how the instruction affects control flow.
+    pub fn flow_control(&self) -> FlowControl {
+        let condition = self.branch_condition().unwrap_or(BranchCondition::ALWAYS);
+        match self.op {
+            Opcode::B | Opcode::Bc if self.field_lk() => {
+                FlowControl::Call { condition, indirect: false }
+            }
+            Opcode::B | Opcode::Bc => FlowControl::Branch { condition },
+            Opcode::Bcctr | Opcode::Bclr if self.field_lk() => {
+                FlowControl::Call { condition, indirect: true }
+            }
+            Opcode::Bcctr => FlowControl::IndirectJump { condition },
+            Opcode::Bclr => FlowControl::Return { condition },
+            Opcode::Tw | Opcode::Twi => {
+                FlowControl::Trap { condition: TrapCondition(self.field_to()), doubleword: false }
+            }
+            Opcode::Td | Opcode::Tdi => {
+                FlowControl::Trap { condition: TrapCondition(self.field_to()), doubleword: true }
+            }
+            Opcode::Sc => FlowControl::SystemCall,
+            Opcode::Rfi | Opcode::Rfid => FlowControl::ExceptionReturn,
+            _ => FlowControl::Sequential,
+        }
+    }
+}
@@ -1,7 +1,7 @@
 #![allow(unused)]
 #![cfg_attr(rustfmt, rustfmt_skip)]
 // Code generated by powerpc-genisa. DO NOT EDIT.
-use crate::disasm::*;
+use crate::{disasm::*, encode::{EncodeError, InsBuilder}};
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 #[repr(u32)]
 pub enum Extension {
@@ -1661,6 +1661,1034 @@ static OPCODE_NAMES: [&str; 511] = [
     "fctidz",
     "fsqrt",
 ];
+/// The description of each opcode.
+static OPCODE_DESCRIPTIONS: [&str; 511] = [
+    "Trap Double Word Immediate",
+    "Trap Word Immediate",
+    "Data Cache Block Set to Zero Locked",
+    "Paired Single Quantized Load with Update Indexed",
+    "Paired Single Quantized Load Indexed",
+    "Paired Single Quantized Store with Update Indexed",
+    "Paired Single Quantized Store Indexed",
+    "Paired Single Absolute Value",
+    "Paired Single Add",
+    "Paired Singles Compare Ordered High",
+    "Paired Singles Compare Ordered Low",
+    "Paired Singles Compare Unordered High",
+    "Paired Singles Compare Unordered Low",
+    "Paired Single Divide",
+    "Paired Single Multiply-Add",
+    "Paired Single Multiply-Add Scalar high",
+    "Paired Single Multiply-Add Scalar low",
+    "Paired Single MERGE high",
+    "Paired Single MERGE direct",
+    "Paired Single MERGE swapped",
+    "Paired Single MERGE low",
+    "Paired Single Move Register",
+    "Paired Single Multiply-Subtract",
+    "Paired Single Multiply",
+    "Paired Single Multiply Scalar high",
+    "Paired Single Multiply Scalar low",
+    "Paired Single Negative Absolute Value",
+    "Paired Single Negate",
+    "Paired Single Negative Multiply-Add",
+    "Paired Single Negative Multiply-Subtract",
+    "Paired Single Reciprocal Estimate",
+    "Paired Single Reciprocal Square Root Estimate",
+    "Paired Single Select",
+    "Paired Single Subtract",
+    "Paired Single vector SUM high",
+    "Paired Single vector SUM low",
+    "Move from Vector Status and Control Register",
+    "Move to Vector Status and Control Register",
+    "Vector Add Carryout Unsigned Word",
+    "Vector Add Floating Point",
+    "Vector Add Signed Byte Saturate",
+    "Vector Add Signed Half Word Saturate",
+    "Vector Add Signed Word Saturate",
+    "Vector Add Unsigned Byte Modulo",
+    "Vector Add Unsigned Byte Saturate",
+    "Vector Add Unsigned Half Word Modulo",
+    "Vector Add Unsigned Half Word Saturate",
+    "Vector Add Unsigned Word Modulo",
+    "Vector Add Unsigned Word Saturate",
+    "Vector Logical AND",
+    "Vector Logical AND with Complement",
+    "Vector Average Signed Byte",
+    "Vector Average Signed Half Word",
+    "Vector Average Signed Word",
+    "Vector Average Unsigned Byte",
+    "Vector Average Unsigned Half Word",
+    "Vector Average Unsigned Word",
+    "Vector Convert from Signed Fixed-Point Word",
+    "Vector Convert from Unsigned Fixed-Point Word",
+    "Vector Compare Bounds Floating Point",
+    "Vector Compare Equal-to-Floating Point",
+    "Vector Compare Equal-to Unsigned Byte",
+    "Vector Compare Equal-to Unsigned Half Word",
+    "Vector Compare Equal-to Unsigned Word",
+    "Vector Compare Greater-Than-or-Equal-to Floating Point",
+    "Vector Compare Greater-Than Floating Point",
+    "Vector Compare Greater-Than Signed Byte",
+    "Vector Compare Greater-Than Condition Register Signed Half Word",
+    "Vector Compare Greater-Than Signed Word",
+    "Vector Compare Greater-Than Unsigned Byte",
+    "Vector Compare Greater-Than Unsigned Half Word",
+    "Vector Compare Greater-Than Unsigned Word",
+    "Vector Convert to Signed Fixed-Point Word Saturate",
+    "Vector Convert to Unsigned Fixed-Point Word Saturate",
+    "Vector 2 Raised to the Exponent Estimate Floating Point",
+    "Vector Log2 Estimate Floating Point",
+    "Vector Multiply Add Floating Point",
+    "Vector Maximum Floating Point",
+    "Vector Maximum Signed Byte",
+    "Vector Maximum Signed Half Word",
+    "Vector Maximum Signed Word",
+    "Vector Maximum Unsigned Byte",
+    "Vector Maximum Unsigned Half Word",
+    "Vector Maximum Unsigned Word",
+    "Vector Multiply High and Add Signed Half Word Saturate",
+    "Vector Multiply High Round and Add Signed Half Word Saturate",
+    "Vector Minimum Floating Point",
+    "Vector Minimum Signed Byte",
+    "Vector Minimum Signed Half Word",
+    "Vector Minimum Signed Word",
+    "Vector Minimum Unsigned Byte",
+    "Vector Minimum Unsigned Half Word",
+    "Vector Minimum Unsigned Word",
+    "Vector Multiply Low and Add Unsigned Half Word Modulo",
+    "Vector Merge High Byte",
+    "Vector Merge High Half Word",
+    "Vector Merge High Word",
+    "Vector Merge Low Byte",
+    "Vector Merge Low Half Word",
+    "Vector Merge Low Word",
+    "Vector Multiply Sum Mixed-Sign Byte Modulo",
+    "Vector Multiply Sum Signed Half Word Modulo",
+    "Vector Multiply Sum Signed Half Word Saturate",
+    "Vector Multiply Sum Unsigned Byte Modulo",
+    "Vector Multiply Sum Unsigned Half Word Modulo",
+    "Vector Multiply Sum Unsigned Half Word Saturate",
+    "Vector Multiply Even Signed Byte",
+    "Vector Multiply Even Signed Half Word",
+    "Vector Multiply Even Unsigned Byte",
+    "Vector Multiply Even Unsigned Half Word",
+    "Vector Multiply Odd Signed Byte",
+    "Vector Multiply Odd Signed Half Word",
+    "Vector Multiply Odd Unsigned Byte",
+    "Vector Multiply Odd Unsigned Half Word",
+    "Vector Negative Multiply-Subtract Floating Point",
+    "Vector Logical NOR",
+    "Vector Logical OR",
+    "Vector Permute",
+    "Vector Pack Pixel32",
+    "Vector Pack Signed Half Word Signed Saturate",
+    "Vector Pack Signed Half Word Unsigned Saturate",
+    "Vector Pack Signed Word Signed Saturate",
+    "Vector Pack Signed Word Unsigned Saturate",
+    "Vector Pack Unsigned Half Word Unsigned Modulo",
+    "Vector Pack Unsigned Half Word Unsigned Saturate",
+    "Vector Pack Unsigned Word Unsigned Modulo",
+    "Vector Pack Unsigned Word Unsigned Saturate",
+    "Vector Reciprocal Estimate Floating Point",
+    "Vector Round to Floating-Point Integer toward Minus Infinity",
+    "Vector Round to Floating-Point Integer Nearest",
+    "Vector Round to Floating-Point Integer toward Plus Infinity",
+    "Vector Round to Floating-Point Integer toward Zero",
+    "Vector Rotate Left Integer Byte",
+    "Vector Rotate Left Integer Half Word",
+    "Vector Rotate Left Integer Word",
+    "Vector Reciprocal Square Root Estimate Floating Point",
+    "Vector Conditional Select",
+    "Vector Shift Left",
+    "Vector Shift Left Integer Byte",
+    "Vector Shift Left Double by Octet Immediate",
+    "Vector Shift Left Integer Half Word",
+    "Vector Shift Left by Octet",
+    "Vector Shift Left Integer Word",
+    "Vector Splat Byte",
+    "Vector Splat Half Word",
+    "Vector Splat Immediate Signed Byte",
+    "Vector Splat Immediate Signed Half Word",
+    "Vector Splat Immediate Signed Word",
+    "Vector Splat Word",
+    "Vector Shift Right",
+    "Vector Shift Right Algebraic Byte",
+    "Vector Shift Right Algebraic Half Word",
+    "Vector Shift Right Algebraic Word",
+    "Vector Shift Right Byte",
+    "Vector Shift Right Half Word",
+    "Vector Shift Right by Octet",
+    "Vector Shift Right Word",
+    "Vector Subtract Carryout Unsigned Word",
+    "Vector Subtract Floating Point",
+    "Vector Subtract Signed Byte Saturate",
+    "Vector Subtract Signed Half Word Saturate",
+    "Vector Subtract Signed Word Saturate",
+    "Vector Subtract Unsigned Byte Modulo",
+    "Vector Subtract Unsigned Byte Saturate",
+    "Vector Subtract Unsigned Half Word Modulo",
+    "Vector Subtract Unsigned Half Word Saturate",
+    "Vector Subtract Unsigned Word Modulo",
+    "Vector Subtract Unsigned Word Saturate",
+    "Vector Sum Across Signed Word Saturate",
+    "Vector Sum Across Partial (1/2) Signed Word Saturate",
+    "Vector Sum Across Partial (1/4) Signed Byte Saturate",
+    "Vector Sum Across Partial (1/4) Signed Half Word Saturate",
+    "Vector Sum Across Partial (1/4) Unsigned Byte Saturate",
+    "Vector Unpack High Pixel16",
+    "Vector Unpack High Signed Byte",
+    "Vector Unpack High Signed Half Word",
+    "Vector Unpack Low Pixel16",
+    "Vector Unpack Low Signed Byte",
+    "Vector Unpack Low Signed Half Word",
+    "Vector Logical XOR",
+    "Load Vector128 Element Word Indexed",
+    "Load Vector128 Left Indexed",
+    "Load Vector128 Left Indexed LRU",
+    "Load Vector128 Right Indexed",
+    "Load Vector128 Right Indexed LRU",
+    "Load Vector128 for Shift Left",
+    "Load Vector128 for Shift Right",
+    "Load Vector128 Indexed",
+    "Load Vector128 Indexed LRU",
+    "Store Vector128 Element Word Indexed",
+    "Store Vector128 Left Indexed",
+    "Store Vector128 Left Indexed LRU",
+    "Store Vector128 Right Indexed",
+    "Store Vector128 Right Indexed LRU",
+    "Store Vector128 Indexed",
+    "Store Vector128 Indexed LRU",
+    "Vector128 Shift Left Double by Octet Immediate",
+    "Vector128 Add Floating Point",
+    "Vector128 Logical AND",
+    "Vector128 Logical AND with Complement",
+    "Vector128 Multiply Add Carryout Floating Point",
+    "Vector128 Multiply Add Floating Point",
+    "Vector128 Multiply Sum 3-way Floating Point",
+    "Vector128 Multiply Sum 4-way Floating Point",
+    "Vector128 Multiply Floating-Point",
+    "Vector128 Negative Multiply-Subtract Floating Point",
+    "Vector128 Logical NOR",
+    "Vector128 Logical OR",
+    "Vector128 Permutation",
+    "Vector128 Pack Signed Half Word Signed Saturate",
+    "Vector128 Pack Signed Half Word Unsigned Saturate",
+    "Vector128 Pack Signed Word Signed Saturate",
+    "Vector128 Pack Signed Word Unsigned Saturate",
+    "Vector128 Pack Unsigned Half Word Unsigned Modulo",
+    "Vector128 Pack Unsigned Half Word Unsigned Saturate",
+    "Vector128 Pack Unsigned Word Unsigned Modulo",
+    "Vector128 Pack Unsigned Word Unsigned Saturate",
+    "Vector128 Select",
+    "Vector128 Shift Left Octet",
+    "Vector128 Shift Right Octet",
+    "Vector128 Subtract Floating Point",
+    "Vector128 Logical XOR",
+    "Vector128 Convert to Signed Fixed-Point Word Saturate",
+    "Vector128 Convert to Unsigned Fixed-Point Word Saturate",
+    "Vector128 Compare Bounds Floating Point",
+    "Vector128 Compare Equal-to Floating Point",
+    "Vector128 Compare Equal-to Unsigned Word",
+    "Vector128 Compare Greater-Than-or-Equal-to Floating Point",
+    "Vector128 Compare Greater-Than Floating-Point",
+    "Vector128 Convert From Signed Fixed-Point Word",
+    "Vector128 Convert From Unsigned Fixed-Point Word",
+    "Vector128 2 Raised to the Exponent Estimate Floating Point",
+    "Vector128 Log2 Estimate Floating Point",
+    "Vector128 Maximum Floating Point",
+    "Vector128 Minimum Floating Point",
+    "Vector128 Merge High Word",
+    "Vector128 Merge Low Word",
+    "Vector128 Permutate Word Immediate",
+    "Vector128 Pack D3Dtype, Rotate Left Immediate and Mask Insert",
+    "Vector128 Reciprocal Estimate Floating Point",
+    "Vector128 Round to Floating-Point Integer toward Minus Infinity",
+    "Vector128 Round to Floating-Point Integer toward Nearest",
+    "Vector128 Round to Floating-Point Integer toward Plus Infinity",
+    "Vector128 Round to Floating-Point Integer toward Zero",
+    "Vector128 Rotate Left Immediate and Mask Insert",
+    "Vector128 Rotate Left Word",
+    "Vector128 Reciprocal Square Root Estimate Floating Point",
+    "Vector128 Shift Left Word",
+    "Vector128 Splat Immediate Signed Word",
+    "Vector128 Splat Word",
+    "Vector128 Shift Right Arithmetic Word",
+    "Vector128 Shift Right Word",
+    "Vector128 Unpack D3Dtype",
+    "Vector128 Unpack High Signed Byte",
+    "Vector128 Unpack High Signed Half Word",
+    "Vector128 Unpack Low Signed Byte",
+    "Vector128 Unpack Low Signed Half Word",
+    "Multiply Low Immediate",
+    "Subtract from Immediate Carrying",
+    "Compare Logical Immediate",
+    "Compare Immediate",
+    "Add Immediate Carrying",
+    "Add Immediate Carrying and Record",
+    "Add Immediate",
+    "Add Immediate Shifted",
+    "Branch Conditional",
+    "System Call",
+    "Branch",
+    "Branch Conditional to Count Register",
+    "Branch Conditional to Link Register",
+    "Condition Register AND",
+    "Condition Register AND with Complement",
+    "Condition Register Equivalent",
+    "Condition Register NAND",
+    "Condition Register NOR",
+    "Condition Register OR",
+    "Condition Register OR with Complement",
+    "Condition Register XOR",
+    "Instruction Synchronize",
+    "Move Condition Register Field",
+    "Return from Interrupt",
+    "Return from Interrupt Double Word",
+    "Rotate Left Word Immediate then Mask Insert",
+    "Rotate Left Word Immediate then AND with Mask",
+    "Rotate Left Word then AND with Mask",
+    "OR Immediate",
+    "OR Immediate Shifted",
+    "XOR Immediate",
+    "XOR Immediate Shifted",
+    "AND Immediate",
+    "AND Immediate Shifted",
+    "Rotate Left Double Word then Clear Left",
+    "Rotate Left Double Word then Clear Right",
+    "Rotate Left Double Word Immediate then Clear",
+    "Rotate Left Double Word Immediate then Clear Left",
+    "Rotate Left Double Word Immediate then Clear Right",
+    "Rotate Left Double Word Immediate then Mask Insert",
+    "Add",
+    "Add Carrying",
+    "Add Extended",
+    "Add to Minus One Extended",
+    "Add to Zero Extended",
+    "AND",
+    "AND with Complement",
+    "Compare",
+    "Compare Logical",
+    "Count Leading Zeros Word",
+    "Data Cache Block Flush",
+    "Data Cache Block Invalidate",
+    "Data Cache Block Store",
+    "Data Cache Block Touch",
+    "Data Cache Block Touch for Store",
+    "Data Cache Block Clear to Zero",
+    "Divide Word",
+    "Divide Word Unsigned",
+    "External Control In Word Indexed",
+    "External Control Out Word Indexed",
+    "Enforce In-Order Execution of I/O",
+    "Equivalent",
+    "Extend Sign Byte",
+    "Extend Sign Half Word",
+    "Instruction Cache Block Invalidate",
+    "Load Byte and Zero with Update Indexed",
+    "Load Byte and Zero Indexed",
+    "Load Floating-Point Double with Update Indexed",
+    "Load Floating-Point Double Indexed",
+    "Load Floating-Point Single with Update Indexed",
+    "Load Floating-Point Single Indexed",
+    "Load Half Word Algebraic with Update Indexed",
+    "Load Half Word Algebraic Indexed",
+    "Load Half Word Byte-Reverse Indexed",
+    "Load Half Word and Zero with Update Indexed",
+    "Load Half Word and Zero Indexed",
+    "Load String Word Immediate",
+    "Load String Word Indexed",
+    "Load String Word and Reverse Indexed",
+    "Load String Word and Byte-Reverse Indexed",
+    "Load Word and Zero with Update Indexed",
+    "Load Word and Zero Indexed",
+    "Move to Condition Register from XER",
+    "Move from Condition Register",
+    "Move from Machine State Register",
+    "Move from Special-Purpose Register",
+    "Move from Segment Register",
+    "Move from Segment Register Indirect",
+    "Move from Time Base",
+    "Move to Condition Register Fields",
+    "Move to Machine State Register",
+    "Move to Special-Purpose Register",
+    "Move to Segment Register",
+    "Move to Segment Register Indirect",
+    "Multiply High Word",
+    "Multiply High Word Unsigned",
+    "Multiply Low Word",
+    "NAND",
+    "Negate",
+    "NOR",
+    "OR",
+    "OR with Complement",
+    "Shift Left Word",
+    "Shift Right Algebraic Word",
+    "Shift Right Algebraic Word Immediate",
+    "Shift Right Word",
+    "Store Byte with Update Indexed",
+    "Store Byte Indexed",
+    "Store Floating-Point Double with Update Indexed",
+    "Store Floating-Point Double Indexed",
+    "Store Floating-Point as Integer Word Indexed",
+    "Store Floating-Point Single with Update Indexed",
+    "Store Floating-Point Single Indexed",
+    "Store Half Word Byte-Reverse Indexed",
+    "Store Half Word with Update Indexed",
+    "Store Half Word Indexed",
+    "Store String Word Immediate",
+    "Store String Word Indexed",
+    "Store Word Byte-Reverse Indexed",
+    "Store Word Conditional Indexed",
+    "Store Word Indexed",
+    "Store Word Indexed",
+    "Subtract From Carrying",
+    "Subtract from Carrying",
+    "Subtract from Extended",
+    "Subtract from Minus One Extended",
+    "Subtract from Zero Extended",
+    "Synchronize",
+    "Translation Lookaside Buffer Invalidate Entry",
+    "TLB Synchronize",
+    "Trap Word",
+    "XOR",
+    "Count Leading Zeros Double Word",
+    "Data Cache Block Clear to Zero (128 bytes)",
+    "Divide Double Word",
+    "Divide Double Word Unsigned",
+    "Extend Sign Word",
+    "Load Double Word and Reserve Indexed",
+    "Load Double Word with Update Indexed",
+    "Load Double Word Indexed",
+    "Load Word Algebraic with Update Indexed",
+    "Load Word Algebraic Indexed",
+    "Move from One Condition Register Field",
+    "Move to Machine State Register Double Word",
+    "Move to Segment Register Double Word",
+    "Move to Segment Register Double Word Indirect",
+    "Multiply High Double Word",
+    "Multiply High Double Word Unsigned",
+    "Multiply Low Double Word",
+    "SLB Invalidate All",
+    "SLB Invalidate Entry",
+    "Shift Left Double Word",
+    "Shift Right Algebraic Double Word",
+    "Shift Right Algebraic Double Word Immediate",
+    "Shift Right Double Word",
+    "Store Double Word Conditional Indexed",
+    "Store Double Word with Update Indexed",
+    "Store Double Word Indexed",
+    "Trap Double Word",
+    "Data Stream Stop",
+    "Data Stream Touch",
+    "Data Stream Touch for Store",
+    "Load Vector Element Byte Indexed",
+    "Load Vector Element Half Word Indexed",
+    "Load Vector Element Word Indexed",
+    "Load Vector Left Indexed",
+    "Load Vector Left Indexed Last",
+    "Load Vector Right Indexed",
+    "Load Vector Right Indexed Last",
+    "Load Vector for Shift Left",
+    "Load Vector for Shift Right",
+    "Load Vector Indexed",
+    "Load Vector Indexed LRU",
+    "Store Vector Element Byte Indexed",
+    "Store Vector Element Half Word Indexed",
+    "Store Vector Element Word Indexed",
+    "Store Vector Left Indexed",
+    "Store Vector Left Indexed Last",
+    "Store Vector Right Indexed",
+    "Store Vector Right Indexed Last",
+    "Store Vector Indexed",
+    "Store Vector Indexed LRU",
+    "Load Word and Zero",
+    "Load Word and Zero with Update",
+    "Load Byte and Zero",
+    "Load Byte and Zero with Update",
+    "Store Word",
+    "Store Word with Update",
+    "Store Byte",
+    "Store Byte with Update",
+    "Load Half Word and Zero",
+    "Load Half Word and Zero with Update",
+    "Load Half Word Algebraic",
+    "Load Half Word Algebraic with Update",
+    "Store Half Word",
+    "Store Half Word with Update",
+    "Load Multiple Word",
+    "Store Multiple Word",
+    "Load Floating-Point Single",
+    "Load Floating-Point Single with Update",
+    "Load Floating-Point Double",
+    "Load Floating-Point Double with Update",
+    "Store Floating-Point Single",
+    "Store Floating-Point Single with Update",
+    "Store Floating-Point Double",
+    "Store Floating-Point Double with Update",
+    "Paired Single Quantized Load",
+    "Paired Single Quantized Load with Update",
+    "Load Double Word",
+    "Load Double Word with Update",
+    "Load Word Algebraic",
+    "Floating Add (Single-Precision)",
+    "Floating Divide (Single-Precision)",
+    "Floating Multiply-Add (Single-Precision)",
+    "Floating Multiply-Subtract (Single-Precision)",
+    "Floating Multiply (Single-Precision)",
+    "Floating Negative Multiply-Add (Single-Precision)",
+    "Floating Negative Multiply-Subtract (Single-Precision)",
+    "Floating Reciprocal Estimate Single",
+    "Floating Subtract (Single-Precision)",
+    "Floating Square Root (Single-Precision)",
+    "Paired Single Quantized Store",
+    "Paired Single Quantized Store with Update",
+    "Store Double Word",
+    "Store Double Word with Update",
+    "Floating Absolute Value",
+    "Floating Add (Double-Precision)",
+    "Floating Compare Ordered",
+    "Floating Compare Unordered",
+    "Floating Convert to Integer Word",
+    "Floating Convert to Integer Word with Round toward Zero",
+    "Floating Divide (Double-Precision)",
+    "Floating Multiply-Add (Double-Precision)",
+    "Floating Move Register (Double-Precision)",
+    "Floating Multiply-Subtract (Double-Precision)",
+    "Floating Multiply (Double-Precision)",
+    "Floating Negative Absolute Value",
+    "Floating Negate",
+    "Floating Negative Multiply-Add (Double-Precision)",
+    "Floating Negative Multiply-Subtract (Double-Precision)",
+    "Floating Round to Single",
+    "Floating Reciprocal Square Root Estimate",
+    "Floating Select",
+    "Floating Subtract (Double-Precision)",
+    "Move to Condition Register from FPSCR",
+    "Move from FPSCR",
+    "Move to FPSCR Bit 0",
+    "Move to FPSCR Bit 1",
+    "Move to FPSCR Fields",
+    "Move to FPSCR Field Immediate",
+    "Floating Convert from Integer Double Word",
+    "Floating Convert to Integer Double Word",
+    "Floating Convert to Integer Double Word with Round toward Zero",
+    "Floating Square Root (Double-Precision)",
+];
+/// The extension each opcode belongs to, if any.
+static OPCODE_EXTENSIONS: [Option<Extension>; 511] = [
+    Some(Extension::Ppc64),
+    None,
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    Some(Extension::Vmx128),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(Extension::Ppc64),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    Some(Extension::AltiVec),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(Extension::Ppc64),
+    Some(Extension::PairedSingles),
+    Some(Extension::PairedSingles),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    None,
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+    Some(Extension::Ppc64),
+];
 #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 #[repr(u16)]
 #[non_exhaustive]
@@ -2695,6 +3723,30 @@ impl Opcode {
     pub fn mnemonic(self) -> &'static str {
         OPCODE_NAMES.get(self as usize).copied().unwrap_or("<illegal>")
     }
+    /// A description of the opcode.
+    pub fn description(self) -> &'static str {
+        OPCODE_DESCRIPTIONS.get(self as usize).copied().unwrap_or("Illegal instruction")
+    }
+    /// The extension the opcode belongs to, or `None` for the base instruction set.
+    pub fn extension(self) -> Option<Extension> {
+        OPCODE_EXTENSIONS.get(self as usize).copied().flatten()
+    }
+    /// The arguments of the opcode, in assembly order.
+    pub fn fields(self) -> &'static [FieldId] {
+        OPCODE_ARGS.get(self as usize).copied().unwrap_or(&[])
+    }
+    /// The modifiers the opcode accepts.
+    pub fn modifiers(self) -> &'static [FieldId] {
+        OPCODE_MODIFIERS.get(self as usize).copied().unwrap_or(&[])
+    }
+    /// The mask of the fixed bits of the opcode.
+    pub fn bitmask(self) -> u32 {
+        OPCODE_PATTERNS.get(self as usize).map_or(0, |op| op.bitmask)
+    }
+    /// The value of the fixed bits of the opcode.
+    pub fn pattern(self) -> u32 {
+        OPCODE_PATTERNS.get(self as usize).map_or(0, |op| op.pattern)
+    }
     pub fn detect(code: u32, extensions: Extensions) -> Self {
         let entry = OPCODE_ENTRIES[(code >> 26) as usize];
         for i in entry.0..entry.1 {
@@ -3098,7 +4150,2605 @@ impl Ins {
     pub const fn field_rc128(&self) -> bool {
         (self.code & 0x40) == 0x40
     }
+    /// simm: Signed Immediate
+    #[inline]
+    pub fn with_simm(self, value: Simm) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::Simm, i32::from(value.0))
+    }
+    /// uimm: Unsigned Immediate
+    #[inline]
+    pub fn with_uimm(self, value: Uimm) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::Uimm, i32::from(value.0))
+    }
+    /// offset: Branch Offset
+    #[inline]
+    pub fn with_offset(self, value: Offset) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::Offset, i32::from(value.0))
+    }
+    /// BO: Branch Options
+    #[inline]
+    pub fn with_bo(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::BO, i32::from(value.0))
+    }
+    /// BI: Branch Condition Register Bit
+    #[inline]
+    pub fn with_bi(self, value: CRBit) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::BI, i32::from(value.0))
+    }
+    /// BD: Branch Destination (14-bit)
+    #[inline]
+    pub fn with_bd(self, value: BranchDest) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::BD, value.0)
+    }
+    /// LI: Branch Destination (24-bit)
+    #[inline]
+    pub fn with_li(self, value: BranchDest) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::LI, value.0)
+    }
+    /// SH: Shift Amount
+    #[inline]
+    pub fn with_sh(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::SH, i32::from(value.0))
+    }
+    /// MB: Mask Begin
+    #[inline]
+    pub fn with_mb(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::MB, i32::from(value.0))
+    }
+    /// ME: Mask End
+    #[inline]
+    pub fn with_me(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::ME, i32::from(value.0))
+    }
+    /// rS: Source Register
+    #[inline]
+    pub fn with_rs(self, value: GPR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::RS, i32::from(value.0))
+    }
+    /// rD: Destination Register
+    #[inline]
+    pub fn with_rd(self, value: GPR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::RD, i32::from(value.0))
+    }
+    /// rA: Register A
+    #[inline]
+    pub fn with_ra(self, value: GPR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::RA, i32::from(value.0))
+    }
+    /// rB: Register B
+    #[inline]
+    pub fn with_rb(self, value: GPR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::RB, i32::from(value.0))
+    }
+    /// sr: Segment Register
+    #[inline]
+    pub fn with_sr(self, value: SR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::Sr, i32::from(value.0))
+    }
+    /// spr: Special Purpose Register
+    #[inline]
+    pub fn with_spr(self, value: SPR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::Spr, i32::from(value.0))
+    }
+    /// frS: Source Floating-Point Register
+    #[inline]
+    pub fn with_frs(self, value: FPR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::FrS, i32::from(value.0))
+    }
+    /// frD: Destination Floating-Point Register
+    #[inline]
+    pub fn with_frd(self, value: FPR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::FrD, i32::from(value.0))
+    }
+    /// frA: Floating-Point Register A
+    #[inline]
+    pub fn with_fra(self, value: FPR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::FrA, i32::from(value.0))
+    }
+    /// frB: Floating-Point Register B
+    #[inline]
+    pub fn with_frb(self, value: FPR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::FrB, i32::from(value.0))
+    }
+    /// frC: Floating-Point Register C
+    #[inline]
+    pub fn with_frc(self, value: FPR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::FrC, i32::from(value.0))
+    }
+    /// crbD: Condition Register Bit Destination
+    #[inline]
+    pub fn with_crbd(self, value: CRBit) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::CrbD, i32::from(value.0))
+    }
+    /// crbA: Condition Register Bit A
+    #[inline]
+    pub fn with_crba(self, value: CRBit) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::CrbA, i32::from(value.0))
+    }
+    /// crbB: Condition Register Bit B
+    #[inline]
+    pub fn with_crbb(self, value: CRBit) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::CrbB, i32::from(value.0))
+    }
+    /// crfD: Condition Register Field Destination
+    #[inline]
+    pub fn with_crfd(self, value: CRField) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::CrfD, i32::from(value.0))
+    }
+    /// crfS: Condition Register Field Source
+    #[inline]
+    pub fn with_crfs(self, value: CRField) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::CrfS, i32::from(value.0))
+    }
+    /// crm: Condition Register Mask
+    #[inline]
+    pub fn with_crm(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::Crm, i32::from(value.0))
+    }
+    /// NB
+    #[inline]
+    pub fn with_nb(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::NB, i32::from(value.0))
+    }
+    /// tbr: Time Base
+    #[inline]
+    pub fn with_tbr(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::Tbr, i32::from(value.0))
+    }
+    /// mtfsf_FM: Field Mask for mtfsf
+    #[inline]
+    pub fn with_mtfsf_fm(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::MtfsfFM, i32::from(value.0))
+    }
+    /// mtfsf_IMM: Immediate for mtfsfi
+    #[inline]
+    pub fn with_mtfsf_imm(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::MtfsfIMM, i32::from(value.0))
+    }
+    /// spr_SPRG: SPRG index for m[tf]sprg
+    #[inline]
+    pub fn with_spr_sprg(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::SprSPRG, i32::from(value.0))
+    }
+    /// spr_BAT: IBAT/DBAT index for m[tf][id]bat[ul]
+    #[inline]
+    pub fn with_spr_bat(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::SprBAT, i32::from(value.0))
+    }
+    /// TO: Bitset for tw and twi
+    #[inline]
+    pub fn with_to(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::TO, i32::from(value.0))
+    }
+    /// L: Bitset for cmp, cmpi, cmpl, cmpli
+    #[inline]
+    pub fn with_l(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::L, i32::from(value.0))
+    }
+    /// sync_L: L field for sync
+    #[inline]
+    pub fn with_sync_l(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::SyncL, i32::from(value.0))
+    }
+    /// ds: Load/Store Double Word Offset (for 64-bit instructions)
+    #[inline]
+    pub fn with_ds(self, value: Offset) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::Ds, i32::from(value.0))
+    }
+    /// SH64: Shift Amount (for 64-bit instructions)
+    #[inline]
+    pub fn with_sh64(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::SH64, i32::from(value.0))
+    }
+    /// MB64: Mask Begin (for 64-bit instructions)
+    #[inline]
+    pub fn with_mb64(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::MB64, i32::from(value.0))
+    }
+    /// ME64: Mask End (for 64-bit instructions)
+    #[inline]
+    pub fn with_me64(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::ME64, i32::from(value.0))
+    }
+    /// mtmsrd_L: L field for mtmsrd
+    #[inline]
+    pub fn with_mtmsrd_l(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::MtmsrdL, i32::from(value.0))
+    }
+    /// ps_offset: Paired Single Offset
+    #[inline]
+    pub fn with_ps_offset(self, value: Offset) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::PsOffset, i32::from(value.0))
+    }
+    /// ps_I
+    #[inline]
+    pub fn with_ps_i(self, value: GQR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::PsI, i32::from(value.0))
+    }
+    /// ps_IX
+    #[inline]
+    pub fn with_ps_ix(self, value: GQR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::PsIX, i32::from(value.0))
+    }
+    /// ps_W
+    #[inline]
+    pub fn with_ps_w(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::PsW, i32::from(value.0))
+    }
+    /// ps_WX
+    #[inline]
+    pub fn with_ps_wx(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::PsWX, i32::from(value.0))
+    }
+    /// vsimm: Vector Signed Immediate
+    #[inline]
+    pub fn with_vsimm(self, value: Simm) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::Vsimm, i32::from(value.0))
+    }
+    /// vuimm: Vector Unsigned Immediate
+    #[inline]
+    pub fn with_vuimm(self, value: Uimm) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::Vuimm, i32::from(value.0))
+    }
+    /// vS: Vector Source Register
+    #[inline]
+    pub fn with_vs(self, value: VR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::VS, i32::from(value.0))
+    }
+    /// vD: Vector Destination Register
+    #[inline]
+    pub fn with_vd(self, value: VR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::VD, i32::from(value.0))
+    }
+    /// vA: Vector Register A
+    #[inline]
+    pub fn with_va(self, value: VR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::VA, i32::from(value.0))
+    }
+    /// vB: Vector Register B
+    #[inline]
+    pub fn with_vb(self, value: VR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::VB, i32::from(value.0))
+    }
+    /// vC: Vector Register C
+    #[inline]
+    pub fn with_vc(self, value: VR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::VC, i32::from(value.0))
+    }
+    /// ds_A: All field for ds instructions
+    #[inline]
+    pub fn with_ds_a(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::DsA, i32::from(value.0))
+    }
+    /// STRM: Stream ID
+    #[inline]
+    pub fn with_strm(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::STRM, i32::from(value.0))
+    }
+    /// SHB
+    #[inline]
+    pub fn with_shb(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::SHB, i32::from(value.0))
+    }
+    /// VDS128: VMX128 Source/Dest Register
+    #[inline]
+    pub fn with_vds128(self, value: VR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::VDS128, i32::from(value.0))
+    }
+    /// VA128: VMX128 Register A
+    #[inline]
+    pub fn with_va128(self, value: VR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::VA128, i32::from(value.0))
+    }
+    /// VB128: VMX128 Register B
+    #[inline]
+    pub fn with_vb128(self, value: VR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::VB128, i32::from(value.0))
+    }
+    /// VC128: VMX128 Register C
+    #[inline]
+    pub fn with_vc128(self, value: VR) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::VC128, i32::from(value.0))
+    }
+    /// PERM: VMX128 Permutation
+    #[inline]
+    pub fn with_perm(self, value: Swizzle) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::PERM, i32::from(value.0))
+    }
+    /// D3DType: the packed data type
+    #[inline]
+    pub fn with_d3dtype(self, value: D3DType) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::D3DType, i32::from(value.0))
+    }
+    /// VMASK: the pack mask
+    #[inline]
+    pub fn with_vmask(self, value: PackMask) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::VMASK, i32::from(value.0))
+    }
+    /// Zimm: amount to rotate/shift left
+    #[inline]
+    pub fn with_zimm(self, value: OpaqueU) -> Result<Self, EncodeError> {
+        self.with_field(FieldId::Zimm, i32::from(value.0))
+    }
+    /// OE: Field used by XO-form instructions to enable setting OV and SO in the XER.
+    #[inline]
+    pub fn set_oe(&mut self, value: bool) -> Result<(), EncodeError> {
+        self.set_modifier(FieldId::OE, value)
+    }
+    /// Rc: Record Bit
+    #[inline]
+    pub fn set_rc(&mut self, value: bool) -> Result<(), EncodeError> {
+        self.set_modifier(FieldId::Rc, value)
+    }
+    /// LK: Link Bit
+    #[inline]
+    pub fn set_lk(&mut self, value: bool) -> Result<(), EncodeError> {
+        self.set_modifier(FieldId::LK, value)
+    }
+    /// AA: Absolute Address Bit
+    #[inline]
+    pub fn set_aa(&mut self, value: bool) -> Result<(), EncodeError> {
+        self.set_modifier(FieldId::AA, value)
+    }
+    /// BP: Predict branch to be taken
+    #[inline]
+    pub fn set_bp(&mut self, value: bool) -> Result<(), EncodeError> {
+        self.set_modifier(FieldId::BP, value)
+    }
+    /// BNP: Predict branch not to be taken (fall through)
+    #[inline]
+    pub fn set_bnp(&mut self, value: bool) -> Result<(), EncodeError> {
+        self.set_modifier(FieldId::BNP, value)
+    }
+    /// BP_ND: Predict branch to be taken (implicit dest for LR/CTR)
+    #[inline]
+    pub fn set_bp_nd(&mut self, value: bool) -> Result<(), EncodeError> {
+        self.set_modifier(FieldId::BPND, value)
+    }
+    /// T: Transient bit
+    #[inline]
+    pub fn set_t(&mut self, value: bool) -> Result<(), EncodeError> {
+        self.set_modifier(FieldId::T, value)
+    }
+    /// RcAV: Record Bit (AltiVec)
+    #[inline]
+    pub fn set_rcav(&mut self, value: bool) -> Result<(), EncodeError> {
+        self.set_modifier(FieldId::RcAV, value)
+    }
+    /// Rc128: Record Bit (VMX128)
+    #[inline]
+    pub fn set_rc128(&mut self, value: bool) -> Result<(), EncodeError> {
+        self.set_modifier(FieldId::Rc128, value)
+    }
+    /// Returns the value of the given field or modifier.
+    pub fn field(&self, id: FieldId) -> Argument {
+        let ins = self;
+        match id {
+            FieldId::Simm => Argument::Simm(Simm(ins.field_simm() as _)),
+            FieldId::Uimm => Argument::Uimm(Uimm(ins.field_uimm() as _)),
+            FieldId::Offset => Argument::Offset(Offset(ins.field_offset() as _)),
+            FieldId::BO => Argument::OpaqueU(OpaqueU(ins.field_bo() as _)),
+            FieldId::BI => Argument::CRBit(CRBit(ins.field_bi() as _)),
+            FieldId::BD => Argument::BranchDest(BranchDest(ins.field_bd() as _)),
+            FieldId::LI => Argument::BranchDest(BranchDest(ins.field_li() as _)),
+            FieldId::SH => Argument::OpaqueU(OpaqueU(ins.field_sh() as _)),
+            FieldId::MB => Argument::OpaqueU(OpaqueU(ins.field_mb() as _)),
+            FieldId::ME => Argument::OpaqueU(OpaqueU(ins.field_me() as _)),
+            FieldId::RS => Argument::GPR(GPR(ins.field_rs() as _)),
+            FieldId::RD => Argument::GPR(GPR(ins.field_rd() as _)),
+            FieldId::RA => Argument::GPR(GPR(ins.field_ra() as _)),
+            FieldId::RB => Argument::GPR(GPR(ins.field_rb() as _)),
+            FieldId::Sr => Argument::SR(SR(ins.field_sr() as _)),
+            FieldId::Spr => Argument::SPR(SPR(ins.field_spr() as _)),
+            FieldId::FrS => Argument::FPR(FPR(ins.field_frs() as _)),
+            FieldId::FrD => Argument::FPR(FPR(ins.field_frd() as _)),
+            FieldId::FrA => Argument::FPR(FPR(ins.field_fra() as _)),
+            FieldId::FrB => Argument::FPR(FPR(ins.field_frb() as _)),
+            FieldId::FrC => Argument::FPR(FPR(ins.field_frc() as _)),
+            FieldId::CrbD => Argument::CRBit(CRBit(ins.field_crbd() as _)),
+            FieldId::CrbA => Argument::CRBit(CRBit(ins.field_crba() as _)),
+            FieldId::CrbB => Argument::CRBit(CRBit(ins.field_crbb() as _)),
+            FieldId::CrfD => Argument::CRField(CRField(ins.field_crfd() as _)),
+            FieldId::CrfS => Argument::CRField(CRField(ins.field_crfs() as _)),
+            FieldId::Crm => Argument::OpaqueU(OpaqueU(ins.field_crm() as _)),
+            FieldId::NB => Argument::OpaqueU(OpaqueU(ins.field_nb() as _)),
+            FieldId::Tbr => Argument::OpaqueU(OpaqueU(ins.field_tbr() as _)),
+            FieldId::MtfsfFM => Argument::OpaqueU(OpaqueU(ins.field_mtfsf_fm() as _)),
+            FieldId::MtfsfIMM => Argument::OpaqueU(OpaqueU(ins.field_mtfsf_imm() as _)),
+            FieldId::SprSPRG => Argument::OpaqueU(OpaqueU(ins.field_spr_sprg() as _)),
+            FieldId::SprBAT => Argument::OpaqueU(OpaqueU(ins.field_spr_bat() as _)),
+            FieldId::TO => Argument::OpaqueU(OpaqueU(ins.field_to() as _)),
+            FieldId::L => Argument::OpaqueU(OpaqueU(ins.field_l() as _)),
+            FieldId::SyncL => Argument::OpaqueU(OpaqueU(ins.field_sync_l() as _)),
+            FieldId::Ds => Argument::Offset(Offset(ins.field_ds() as _)),
+            FieldId::SH64 => Argument::OpaqueU(OpaqueU(ins.field_sh64() as _)),
+            FieldId::MB64 => Argument::OpaqueU(OpaqueU(ins.field_mb64() as _)),
+            FieldId::ME64 => Argument::OpaqueU(OpaqueU(ins.field_me64() as _)),
+            FieldId::MtmsrdL => Argument::OpaqueU(OpaqueU(ins.field_mtmsrd_l() as _)),
+            FieldId::PsOffset => Argument::Offset(Offset(ins.field_ps_offset() as _)),
+            FieldId::PsI => Argument::GQR(GQR(ins.field_ps_i() as _)),
+            FieldId::PsIX => Argument::GQR(GQR(ins.field_ps_ix() as _)),
+            FieldId::PsW => Argument::OpaqueU(OpaqueU(ins.field_ps_w() as _)),
+            FieldId::PsWX => Argument::OpaqueU(OpaqueU(ins.field_ps_wx() as _)),
+            FieldId::Vsimm => Argument::Simm(Simm(ins.field_vsimm() as _)),
+            FieldId::Vuimm => Argument::Uimm(Uimm(ins.field_vuimm() as _)),
+            FieldId::VS => Argument::VR(VR(ins.field_vs() as _)),
+            FieldId::VD => Argument::VR(VR(ins.field_vd() as _)),
+            FieldId::VA => Argument::VR(VR(ins.field_va() as _)),
+            FieldId::VB => Argument::VR(VR(ins.field_vb() as _)),
+            FieldId::VC => Argument::VR(VR(ins.field_vc() as _)),
+            FieldId::DsA => Argument::OpaqueU(OpaqueU(ins.field_ds_a() as _)),
+            FieldId::STRM => Argument::OpaqueU(OpaqueU(ins.field_strm() as _)),
+            FieldId::SHB => Argument::OpaqueU(OpaqueU(ins.field_shb() as _)),
+            FieldId::VDS128 => Argument::VR(VR(ins.field_vds128() as _)),
+            FieldId::VA128 => Argument::VR(VR(ins.field_va128() as _)),
+            FieldId::VB128 => Argument::VR(VR(ins.field_vb128() as _)),
+            FieldId::VC128 => Argument::VR(VR(ins.field_vc128() as _)),
+            FieldId::PERM => Argument::Swizzle(Swizzle(ins.field_perm() as _)),
+            FieldId::D3DType => Argument::D3DType(D3DType(ins.field_d3dtype() as _)),
+            FieldId::VMASK => Argument::PackMask(PackMask(ins.field_vmask() as _)),
+            FieldId::Zimm => Argument::OpaqueU(OpaqueU(ins.field_zimm() as _)),
+            FieldId::OE => Argument::OpaqueU(OpaqueU(ins.field_oe() as u16)),
+            FieldId::Rc => Argument::OpaqueU(OpaqueU(ins.field_rc() as u16)),
+            FieldId::LK => Argument::OpaqueU(OpaqueU(ins.field_lk() as u16)),
+            FieldId::AA => Argument::OpaqueU(OpaqueU(ins.field_aa() as u16)),
+            FieldId::BP => Argument::OpaqueU(OpaqueU(ins.field_bp() as u16)),
+            FieldId::BNP => Argument::OpaqueU(OpaqueU(ins.field_bnp() as u16)),
+            FieldId::BPND => Argument::OpaqueU(OpaqueU(ins.field_bp_nd() as u16)),
+            FieldId::T => Argument::OpaqueU(OpaqueU(ins.field_t() as u16)),
+            FieldId::RcAV => Argument::OpaqueU(OpaqueU(ins.field_rcav() as u16)),
+            FieldId::Rc128 => Argument::OpaqueU(OpaqueU(ins.field_rc128() as u16)),
+        }
+    }
 }
+impl InsBuilder {
+    /// simm: Signed Immediate
+    #[inline]
+    pub fn simm(self, value: Simm) -> Self {
+        self.field(FieldId::Simm, i32::from(value.0))
+    }
+    /// uimm: Unsigned Immediate
+    #[inline]
+    pub fn uimm(self, value: Uimm) -> Self {
+        self.field(FieldId::Uimm, i32::from(value.0))
+    }
+    /// offset: Branch Offset
+    #[inline]
+    pub fn offset(self, value: Offset) -> Self {
+        self.field(FieldId::Offset, i32::from(value.0))
+    }
+    /// BO: Branch Options
+    #[inline]
+    pub fn bo(self, value: OpaqueU) -> Self {
+        self.field(FieldId::BO, i32::from(value.0))
+    }
+    /// BI: Branch Condition Register Bit
+    #[inline]
+    pub fn bi(self, value: CRBit) -> Self {
+        self.field(FieldId::BI, i32::from(value.0))
+    }
+    /// BD: Branch Destination (14-bit)
+    #[inline]
+    pub fn bd(self, value: BranchDest) -> Self {
+        self.field(FieldId::BD, value.0)
+    }
+    /// LI: Branch Destination (24-bit)
+    #[inline]
+    pub fn li(self, value: BranchDest) -> Self {
+        self.field(FieldId::LI, value.0)
+    }
+    /// SH: Shift Amount
+    #[inline]
+    pub fn sh(self, value: OpaqueU) -> Self {
+        self.field(FieldId::SH, i32::from(value.0))
+    }
+    /// MB: Mask Begin
+    #[inline]
+    pub fn mb(self, value: OpaqueU) -> Self {
+        self.field(FieldId::MB, i32::from(value.0))
+    }
+    /// ME: Mask End
+    #[inline]
+    pub fn me(self, value: OpaqueU) -> Self {
+        self.field(FieldId::ME, i32::from(value.0))
+    }
+    /// rS: Source Register
+    #[inline]
+    pub fn rs(self, value: GPR) -> Self {
+        self.field(FieldId::RS, i32::from(value.0))
+    }
+    /// rD: Destination Register
+    #[inline]
+    pub fn rd(self, value: GPR) -> Self {
+        self.field(FieldId::RD, i32::from(value.0))
+    }
+    /// rA: Register A
+    #[inline]
+    pub fn ra(self, value: GPR) -> Self {
+        self.field(FieldId::RA, i32::from(value.0))
+    }
+    /// rB: Register B
+    #[inline]
+    pub fn rb(self, value: GPR) -> Self {
+        self.field(FieldId::RB, i32::from(value.0))
+    }
+    /// sr: Segment Register
+    #[inline]
+    pub fn sr(self, value: SR) -> Self {
+        self.field(FieldId::Sr, i32::from(value.0))
+    }
+    /// spr: Special Purpose Register
+    #[inline]
+    pub fn spr(self, value: SPR) -> Self {
+        self.field(FieldId::Spr, i32::from(value.0))
+    }
+    /// frS: Source Floating-Point Register
+    #[inline]
+    pub fn frs(self, value: FPR) -> Self {
+        self.field(FieldId::FrS, i32::from(value.0))
+    }
+    /// frD: Destination Floating-Point Register
+    #[inline]
+    pub fn frd(self, value: FPR) -> Self {
+        self.field(FieldId::FrD, i32::from(value.0))
+    }
+    /// frA: Floating-Point Register A
+    #[inline]
+    pub fn fra(self, value: FPR) -> Self {
+        self.field(FieldId::FrA, i32::from(value.0))
+    }
+    /// frB: Floating-Point Register B
+    #[inline]
+    pub fn frb(self, value: FPR) -> Self {
+        self.field(FieldId::FrB, i32::from(value.0))
+    }
+    /// frC: Floating-Point Register C
+    #[inline]
+    pub fn frc(self, value: FPR) -> Self {
+        self.field(FieldId::FrC, i32::from(value.0))
+    }
+    /// crbD: Condition Register Bit Destination
+    #[inline]
+    pub fn crbd(self, value: CRBit) -> Self {
+        self.field(FieldId::CrbD, i32::from(value.0))
+    }
+    /// crbA: Condition Register Bit A
+    #[inline]
+    pub fn crba(self, value: CRBit) -> Self {
+        self.field(FieldId::CrbA, i32::from(value.0))
+    }
+    /// crbB: Condition Register Bit B
+    #[inline]
+    pub fn crbb(self, value: CRBit) -> Self {
+        self.field(FieldId::CrbB, i32::from(value.0))
+    }
+    /// crfD: Condition Register Field Destination
+    #[inline]
+    pub fn crfd(self, value: CRField) -> Self {
+        self.field(FieldId::CrfD, i32::from(value.0))
+    }
+    /// crfS: Condition Register Field Source
+    #[inline]
+    pub fn crfs(self, value: CRField) -> Self {
+        self.field(FieldId::CrfS, i32::from(value.0))
+    }
+    /// crm: Condition Register Mask
+    #[inline]
+    pub fn crm(self, value: OpaqueU) -> Self {
+        self.field(FieldId::Crm, i32::from(value.0))
+    }
+    /// NB
+    #[inline]
+    pub fn nb(self, value: OpaqueU) -> Self {
+        self.field(FieldId::NB, i32::from(value.0))
+    }
+    /// tbr: Time Base
+    #[inline]
+    pub fn tbr(self, value: OpaqueU) -> Self {
+        self.field(FieldId::Tbr, i32::from(value.0))
+    }
+    /// mtfsf_FM: Field Mask for mtfsf
+    #[inline]
+    pub fn mtfsf_fm(self, value: OpaqueU) -> Self {
+        self.field(FieldId::MtfsfFM, i32::from(value.0))
+    }
+    /// mtfsf_IMM: Immediate for mtfsfi
+    #[inline]
+    pub fn mtfsf_imm(self, value: OpaqueU) -> Self {
+        self.field(FieldId::MtfsfIMM, i32::from(value.0))
+    }
+    /// spr_SPRG: SPRG index for m[tf]sprg
+    #[inline]
+    pub fn spr_sprg(self, value: OpaqueU) -> Self {
+        self.field(FieldId::SprSPRG, i32::from(value.0))
+    }
+    /// spr_BAT: IBAT/DBAT index for m[tf][id]bat[ul]
+    #[inline]
+    pub fn spr_bat(self, value: OpaqueU) -> Self {
+        self.field(FieldId::SprBAT, i32::from(value.0))
+    }
+    /// TO: Bitset for tw and twi
+    #[inline]
+    pub fn to(self, value: OpaqueU) -> Self {
+        self.field(FieldId::TO, i32::from(value.0))
+    }
+    /// L: Bitset for cmp, cmpi, cmpl, cmpli
+    #[inline]
+    pub fn l(self, value: OpaqueU) -> Self {
+        self.field(FieldId::L, i32::from(value.0))
+    }
+    /// sync_L: L field for sync
+    #[inline]
+    pub fn sync_l(self, value: OpaqueU) -> Self {
+        self.field(FieldId::SyncL, i32::from(value.0))
+    }
+    /// ds: Load/Store Double Word Offset (for 64-bit instructions)
+    #[inline]
+    pub fn ds(self, value: Offset) -> Self {
+        self.field(FieldId::Ds, i32::from(value.0))
+    }
+    /// SH64: Shift Amount (for 64-bit instructions)
+    #[inline]
+    pub fn sh64(self, value: OpaqueU) -> Self {
+        self.field(FieldId::SH64, i32::from(value.0))
+    }
+    /// MB64: Mask Begin (for 64-bit instructions)
+    #[inline]
+    pub fn mb64(self, value: OpaqueU) -> Self {
+        self.field(FieldId::MB64, i32::from(value.0))
+    }
+    /// ME64: Mask End (for 64-bit instructions)
+    #[inline]
+    pub fn me64(self, value: OpaqueU) -> Self {
+        self.field(FieldId::ME64, i32::from(value.0))
+    }
+    /// mtmsrd_L: L field for mtmsrd
+    #[inline]
+    pub fn mtmsrd_l(self, value: OpaqueU) -> Self {
+        self.field(FieldId::MtmsrdL, i32::from(value.0))
+    }
+    /// ps_offset: Paired Single Offset
+    #[inline]
+    pub fn ps_offset(self, value: Offset) -> Self {
+        self.field(FieldId::PsOffset, i32::from(value.0))
+    }
+    /// ps_I
+    #[inline]
+    pub fn ps_i(self, value: GQR) -> Self {
+        self.field(FieldId::PsI, i32::from(value.0))
+    }
+    /// ps_IX
+    #[inline]
+    pub fn ps_ix(self, value: GQR) -> Self {
+        self.field(FieldId::PsIX, i32::from(value.0))
+    }
+    /// ps_W
+    #[inline]
+    pub fn ps_w(self, value: OpaqueU) -> Self {
+        self.field(FieldId::PsW, i32::from(value.0))
+    }
+    /// ps_WX
+    #[inline]
+    pub fn ps_wx(self, value: OpaqueU) -> Self {
+        self.field(FieldId::PsWX, i32::from(value.0))
+    }
+    /// vsimm: Vector Signed Immediate
+    #[inline]
+    pub fn vsimm(self, value: Simm) -> Self {
+        self.field(FieldId::Vsimm, i32::from(value.0))
+    }
+    /// vuimm: Vector Unsigned Immediate
+    #[inline]
+    pub fn vuimm(self, value: Uimm) -> Self {
+        self.field(FieldId::Vuimm, i32::from(value.0))
+    }
+    /// vS: Vector Source Register
+    #[inline]
+    pub fn vs(self, value: VR) -> Self {
+        self.field(FieldId::VS, i32::from(value.0))
+    }
+    /// vD: Vector Destination Register
+    #[inline]
+    pub fn vd(self, value: VR) -> Self {
+        self.field(FieldId::VD, i32::from(value.0))
+    }
+    /// vA: Vector Register A
+    #[inline]
+    pub fn va(self, value: VR) -> Self {
+        self.field(FieldId::VA, i32::from(value.0))
+    }
+    /// vB: Vector Register B
+    #[inline]
+    pub fn vb(self, value: VR) -> Self {
+        self.field(FieldId::VB, i32::from(value.0))
+    }
+    /// vC: Vector Register C
+    #[inline]
+    pub fn vc(self, value: VR) -> Self {
+        self.field(FieldId::VC, i32::from(value.0))
+    }
+    /// ds_A: All field for ds instructions
+    #[inline]
+    pub fn ds_a(self, value: OpaqueU) -> Self {
+        self.field(FieldId::DsA, i32::from(value.0))
+    }
+    /// STRM: Stream ID
+    #[inline]
+    pub fn strm(self, value: OpaqueU) -> Self {
+        self.field(FieldId::STRM, i32::from(value.0))
+    }
+    /// SHB
+    #[inline]
+    pub fn shb(self, value: OpaqueU) -> Self {
+        self.field(FieldId::SHB, i32::from(value.0))
+    }
+    /// VDS128: VMX128 Source/Dest Register
+    #[inline]
+    pub fn vds128(self, value: VR) -> Self {
+        self.field(FieldId::VDS128, i32::from(value.0))
+    }
+    /// VA128: VMX128 Register A
+    #[inline]
+    pub fn va128(self, value: VR) -> Self {
+        self.field(FieldId::VA128, i32::from(value.0))
+    }
+    /// VB128: VMX128 Register B
+    #[inline]
+    pub fn vb128(self, value: VR) -> Self {
+        self.field(FieldId::VB128, i32::from(value.0))
+    }
+    /// VC128: VMX128 Register C
+    #[inline]
+    pub fn vc128(self, value: VR) -> Self {
+        self.field(FieldId::VC128, i32::from(value.0))
+    }
+    /// PERM: VMX128 Permutation
+    #[inline]
+    pub fn perm(self, value: Swizzle) -> Self {
+        self.field(FieldId::PERM, i32::from(value.0))
+    }
+    /// D3DType: the packed data type
+    #[inline]
+    pub fn d3dtype(self, value: D3DType) -> Self {
+        self.field(FieldId::D3DType, i32::from(value.0))
+    }
+    /// VMASK: the pack mask
+    #[inline]
+    pub fn vmask(self, value: PackMask) -> Self {
+        self.field(FieldId::VMASK, i32::from(value.0))
+    }
+    /// Zimm: amount to rotate/shift left
+    #[inline]
+    pub fn zimm(self, value: OpaqueU) -> Self {
+        self.field(FieldId::Zimm, i32::from(value.0))
+    }
+    /// OE: Field used by XO-form instructions to enable setting OV and SO in the XER.
+    #[inline]
+    pub fn oe(self) -> Self {
+        self.modifier(FieldId::OE, true)
+    }
+    /// Rc: Record Bit
+    #[inline]
+    pub fn rc(self) -> Self {
+        self.modifier(FieldId::Rc, true)
+    }
+    /// LK: Link Bit
+    #[inline]
+    pub fn lk(self) -> Self {
+        self.modifier(FieldId::LK, true)
+    }
+    /// AA: Absolute Address Bit
+    #[inline]
+    pub fn aa(self) -> Self {
+        self.modifier(FieldId::AA, true)
+    }
+    /// BP: Predict branch to be taken
+    #[inline]
+    pub fn bp(self) -> Self {
+        self.modifier(FieldId::BP, true)
+    }
+    /// BNP: Predict branch not to be taken (fall through)
+    #[inline]
+    pub fn bnp(self) -> Self {
+        self.modifier(FieldId::BNP, true)
+    }
+    /// BP_ND: Predict branch to be taken (implicit dest for LR/CTR)
+    #[inline]
+    pub fn bp_nd(self) -> Self {
+        self.modifier(FieldId::BPND, true)
+    }
+    /// T: Transient bit
+    #[inline]
+    pub fn t(self) -> Self {
+        self.modifier(FieldId::T, true)
+    }
+    /// RcAV: Record Bit (AltiVec)
+    #[inline]
+    pub fn rcav(self) -> Self {
+        self.modifier(FieldId::RcAV, true)
+    }
+    /// Rc128: Record Bit (VMX128)
+    #[inline]
+    pub fn rc128(self) -> Self {
+        self.modifier(FieldId::Rc128, true)
+    }
+}
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[repr(u8)]
+#[non_exhaustive]
+pub enum FieldId {
+    /// simm: Signed Immediate
+    Simm = 0,
+    /// uimm: Unsigned Immediate
+    Uimm = 1,
+    /// offset: Branch Offset
+    Offset = 2,
+    /// BO: Branch Options
+    BO = 3,
+    /// BI: Branch Condition Register Bit
+    BI = 4,
+    /// BD: Branch Destination (14-bit)
+    BD = 5,
+    /// LI: Branch Destination (24-bit)
+    LI = 6,
+    /// SH: Shift Amount
+    SH = 7,
+    /// MB: Mask Begin
+    MB = 8,
+    /// ME: Mask End
+    ME = 9,
+    /// rS: Source Register
+    RS = 10,
+    /// rD: Destination Register
+    RD = 11,
+    /// rA: Register A
+    RA = 12,
+    /// rB: Register B
+    RB = 13,
+    /// sr: Segment Register
+    Sr = 14,
+    /// spr: Special Purpose Register
+    Spr = 15,
+    /// frS: Source Floating-Point Register
+    FrS = 16,
+    /// frD: Destination Floating-Point Register
+    FrD = 17,
+    /// frA: Floating-Point Register A
+    FrA = 18,
+    /// frB: Floating-Point Register B
+    FrB = 19,
+    /// frC: Floating-Point Register C
+    FrC = 20,
+    /// crbD: Condition Register Bit Destination
+    CrbD = 21,
+    /// crbA: Condition Register Bit A
+    CrbA = 22,
+    /// crbB: Condition Register Bit B
+    CrbB = 23,
+    /// crfD: Condition Register Field Destination
+    CrfD = 24,
+    /// crfS: Condition Register Field Source
+    CrfS = 25,
+    /// crm: Condition Register Mask
+    Crm = 26,
+    /// NB
+    NB = 27,
+    /// tbr: Time Base
+    Tbr = 28,
+    /// mtfsf_FM: Field Mask for mtfsf
+    MtfsfFM = 29,
+    /// mtfsf_IMM: Immediate for mtfsfi
+    MtfsfIMM = 30,
+    /// spr_SPRG: SPRG index for m[tf]sprg
+    SprSPRG = 31,
+    /// spr_BAT: IBAT/DBAT index for m[tf][id]bat[ul]
+    SprBAT = 32,
+    /// TO: Bitset for tw and twi
+    TO = 33,
+    /// L: Bitset for cmp, cmpi, cmpl, cmpli
+    L = 34,
+    /// sync_L: L field for sync
+    SyncL = 35,
+    /// ds: Load/Store Double Word Offset (for 64-bit instructions)
+    Ds = 36,
+    /// SH64: Shift Amount (for 64-bit instructions)
+    SH64 = 37,
+    /// MB64: Mask Begin (for 64-bit instructions)
+    MB64 = 38,
+    /// ME64: Mask End (for 64-bit instructions)
+    ME64 = 39,
+    /// mtmsrd_L: L field for mtmsrd
+    MtmsrdL = 40,
+    /// ps_offset: Paired Single Offset
+    PsOffset = 41,
+    /// ps_I
+    PsI = 42,
+    /// ps_IX
+    PsIX = 43,
+    /// ps_W
+    PsW = 44,
+    /// ps_WX
+    PsWX = 45,
+    /// vsimm: Vector Signed Immediate
+    Vsimm = 46,
+    /// vuimm: Vector Unsigned Immediate
+    Vuimm = 47,
+    /// vS: Vector Source Register
+    VS = 48,
+    /// vD: Vector Destination Register
+    VD = 49,
+    /// vA: Vector Register A
+    VA = 50,
+    /// vB: Vector Register B
+    VB = 51,
+    /// vC: Vector Register C
+    VC = 52,
+    /// ds_A: All field for ds instructions
+    DsA = 53,
+    /// STRM: Stream ID
+    STRM = 54,
+    /// SHB
+    SHB = 55,
+    /// VDS128: VMX128 Source/Dest Register
+    VDS128 = 56,
+    /// VA128: VMX128 Register A
+    VA128 = 57,
+    /// VB128: VMX128 Register B
+    VB128 = 58,
+    /// VC128: VMX128 Register C
+    VC128 = 59,
+    /// PERM: VMX128 Permutation
+    PERM = 60,
+    /// D3DType: the packed data type
+    D3DType = 61,
+    /// VMASK: the pack mask
+    VMASK = 62,
+    /// Zimm: amount to rotate/shift left
+    Zimm = 63,
+    /// OE: Field used by XO-form instructions to enable setting OV and SO in the XER.
+    OE = 64,
+    /// Rc: Record Bit
+    Rc = 65,
+    /// LK: Link Bit
+    LK = 66,
+    /// AA: Absolute Address Bit
+    AA = 67,
+    /// BP: Predict branch to be taken
+    BP = 68,
+    /// BNP: Predict branch not to be taken (fall through)
+    BNP = 69,
+    /// BP_ND: Predict branch to be taken (implicit dest for LR/CTR)
+    BPND = 70,
+    /// T: Transient bit
+    T = 71,
+    /// RcAV: Record Bit (AltiVec)
+    RcAV = 72,
+    /// Rc128: Record Bit (VMX128)
+    Rc128 = 73,
+}
+/// The metadata for each field and modifier.
+pub(crate) static FIELD_INFO: [FieldInfo; 74] = [
+    FieldInfo {
+        name: "simm",
+        desc: "Signed Immediate",
+        bits: &[BitRange::new(16, 32)],
+        signed: true,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "uimm",
+        desc: "Unsigned Immediate",
+        bits: &[BitRange::new(16, 32)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "offset",
+        desc: "Branch Offset",
+        bits: &[BitRange::new(16, 32)],
+        signed: true,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "BO",
+        desc: "Branch Options",
+        bits: &[BitRange::new(6, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "BI",
+        desc: "Branch Condition Register Bit",
+        bits: &[BitRange::new(11, 16)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "BD",
+        desc: "Branch Destination (14-bit)",
+        bits: &[BitRange::new(16, 30)],
+        signed: true,
+        shift_left: 2,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "LI",
+        desc: "Branch Destination (24-bit)",
+        bits: &[BitRange::new(6, 30)],
+        signed: true,
+        shift_left: 2,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "SH",
+        desc: "Shift Amount",
+        bits: &[BitRange::new(16, 21)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "MB",
+        desc: "Mask Begin",
+        bits: &[BitRange::new(21, 26)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "ME",
+        desc: "Mask End",
+        bits: &[BitRange::new(26, 31)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "rS",
+        desc: "Source Register",
+        bits: &[BitRange::new(6, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "rD",
+        desc: "Destination Register",
+        bits: &[BitRange::new(6, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "rA",
+        desc: "Register A",
+        bits: &[BitRange::new(11, 16)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "rB",
+        desc: "Register B",
+        bits: &[BitRange::new(16, 21)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "sr",
+        desc: "Segment Register",
+        bits: &[BitRange::new(12, 16)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "spr",
+        desc: "Special Purpose Register",
+        bits: &[BitRange::new(16, 21), BitRange::new(11, 16)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "frS",
+        desc: "Source Floating-Point Register",
+        bits: &[BitRange::new(6, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "frD",
+        desc: "Destination Floating-Point Register",
+        bits: &[BitRange::new(6, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "frA",
+        desc: "Floating-Point Register A",
+        bits: &[BitRange::new(11, 16)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "frB",
+        desc: "Floating-Point Register B",
+        bits: &[BitRange::new(16, 21)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "frC",
+        desc: "Floating-Point Register C",
+        bits: &[BitRange::new(21, 26)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "crbD",
+        desc: "Condition Register Bit Destination",
+        bits: &[BitRange::new(6, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "crbA",
+        desc: "Condition Register Bit A",
+        bits: &[BitRange::new(11, 16)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "crbB",
+        desc: "Condition Register Bit B",
+        bits: &[BitRange::new(16, 21)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "crfD",
+        desc: "Condition Register Field Destination",
+        bits: &[BitRange::new(6, 9)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "crfS",
+        desc: "Condition Register Field Source",
+        bits: &[BitRange::new(11, 14)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "crm",
+        desc: "Condition Register Mask",
+        bits: &[BitRange::new(12, 20)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "NB",
+        desc: "",
+        bits: &[BitRange::new(16, 21)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "tbr",
+        desc: "Time Base",
+        bits: &[BitRange::new(16, 21), BitRange::new(11, 16)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "mtfsf_FM",
+        desc: "Field Mask for mtfsf",
+        bits: &[BitRange::new(7, 15)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "mtfsf_IMM",
+        desc: "Immediate for mtfsfi",
+        bits: &[BitRange::new(16, 20)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "spr_SPRG",
+        desc: "SPRG index for m[tf]sprg",
+        bits: &[BitRange::new(14, 16)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "spr_BAT",
+        desc: "IBAT/DBAT index for m[tf][id]bat[ul]",
+        bits: &[BitRange::new(13, 15)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "TO",
+        desc: "Bitset for tw and twi",
+        bits: &[BitRange::new(6, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "L",
+        desc: "Bitset for cmp, cmpi, cmpl, cmpli",
+        bits: &[BitRange::new(10, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "sync_L",
+        desc: "L field for sync",
+        bits: &[BitRange::new(9, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "ds",
+        desc: "Load/Store Double Word Offset (for 64-bit instructions)",
+        bits: &[BitRange::new(16, 30)],
+        signed: true,
+        shift_left: 2,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "SH64",
+        desc: "Shift Amount (for 64-bit instructions)",
+        bits: &[BitRange::new(30, 31), BitRange::new(16, 21)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "MB64",
+        desc: "Mask Begin (for 64-bit instructions)",
+        bits: &[BitRange::new(26, 27), BitRange::new(21, 26)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "ME64",
+        desc: "Mask End (for 64-bit instructions)",
+        bits: &[BitRange::new(26, 27), BitRange::new(21, 26)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "mtmsrd_L",
+        desc: "L field for mtmsrd",
+        bits: &[BitRange::new(15, 16)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "ps_offset",
+        desc: "Paired Single Offset",
+        bits: &[BitRange::new(20, 32)],
+        signed: true,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "ps_I",
+        desc: "",
+        bits: &[BitRange::new(17, 20)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "ps_IX",
+        desc: "",
+        bits: &[BitRange::new(22, 25)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "ps_W",
+        desc: "",
+        bits: &[BitRange::new(16, 17)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "ps_WX",
+        desc: "",
+        bits: &[BitRange::new(21, 22)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "vsimm",
+        desc: "Vector Signed Immediate",
+        bits: &[BitRange::new(11, 16)],
+        signed: true,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "vuimm",
+        desc: "Vector Unsigned Immediate",
+        bits: &[BitRange::new(11, 16)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "vS",
+        desc: "Vector Source Register",
+        bits: &[BitRange::new(6, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "vD",
+        desc: "Vector Destination Register",
+        bits: &[BitRange::new(6, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "vA",
+        desc: "Vector Register A",
+        bits: &[BitRange::new(11, 16)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "vB",
+        desc: "Vector Register B",
+        bits: &[BitRange::new(16, 21)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "vC",
+        desc: "Vector Register C",
+        bits: &[BitRange::new(21, 26)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "ds_A",
+        desc: "All field for ds instructions",
+        bits: &[BitRange::new(6, 7)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "STRM",
+        desc: "Stream ID",
+        bits: &[BitRange::new(9, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "SHB",
+        desc: "",
+        bits: &[BitRange::new(22, 26)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "VDS128",
+        desc: "VMX128 Source/Dest Register",
+        bits: &[BitRange::new(28, 30), BitRange::new(6, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "VA128",
+        desc: "VMX128 Register A",
+        bits: &[BitRange::new(21, 22), BitRange::new(26, 27), BitRange::new(11, 16)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "VB128",
+        desc: "VMX128 Register B",
+        bits: &[BitRange::new(30, 32), BitRange::new(16, 21)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "VC128",
+        desc: "VMX128 Register C",
+        bits: &[BitRange::new(23, 26)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "PERM",
+        desc: "VMX128 Permutation",
+        bits: &[BitRange::new(23, 26), BitRange::new(11, 16)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "D3DType",
+        desc: "the packed data type",
+        bits: &[BitRange::new(11, 14)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "VMASK",
+        desc: "the pack mask",
+        bits: &[BitRange::new(14, 16)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "Zimm",
+        desc: "amount to rotate/shift left",
+        bits: &[BitRange::new(24, 26)],
+        signed: false,
+        shift_left: 0,
+        modifier: false,
+    },
+    FieldInfo {
+        name: "OE",
+        desc: "Field used by XO-form instructions to enable setting OV and SO in the XER.",
+        bits: &[BitRange::new(21, 22)],
+        signed: false,
+        shift_left: 0,
+        modifier: true,
+    },
+    FieldInfo {
+        name: "Rc",
+        desc: "Record Bit",
+        bits: &[BitRange::new(31, 32)],
+        signed: false,
+        shift_left: 0,
+        modifier: true,
+    },
+    FieldInfo {
+        name: "LK",
+        desc: "Link Bit",
+        bits: &[BitRange::new(31, 32)],
+        signed: false,
+        shift_left: 0,
+        modifier: true,
+    },
+    FieldInfo {
+        name: "AA",
+        desc: "Absolute Address Bit",
+        bits: &[BitRange::new(30, 31)],
+        signed: false,
+        shift_left: 0,
+        modifier: true,
+    },
+    FieldInfo {
+        name: "BP",
+        desc: "Predict branch to be taken",
+        bits: &[BitRange::new(10, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: true,
+    },
+    FieldInfo {
+        name: "BNP",
+        desc: "Predict branch not to be taken (fall through)",
+        bits: &[BitRange::new(10, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: true,
+    },
+    FieldInfo {
+        name: "BP_ND",
+        desc: "Predict branch to be taken (implicit dest for LR/CTR)",
+        bits: &[BitRange::new(10, 11)],
+        signed: false,
+        shift_left: 0,
+        modifier: true,
+    },
+    FieldInfo {
+        name: "T",
+        desc: "Transient bit",
+        bits: &[BitRange::new(6, 7)],
+        signed: false,
+        shift_left: 0,
+        modifier: true,
+    },
+    FieldInfo {
+        name: "RcAV",
+        desc: "Record Bit (AltiVec)",
+        bits: &[BitRange::new(21, 22)],
+        signed: false,
+        shift_left: 0,
+        modifier: true,
+    },
+    FieldInfo {
+        name: "Rc128",
+        desc: "Record Bit (VMX128)",
+        bits: &[BitRange::new(25, 26)],
+        signed: false,
+        shift_left: 0,
+        modifier: true,
+    },
+];
+/// The arguments of each opcode.
+static OPCODE_ARGS: [&[FieldId]; 511] = [
+    &[FieldId::TO, FieldId::RA, FieldId::Simm],
+    &[FieldId::TO, FieldId::RA, FieldId::Simm],
+    &[FieldId::RA, FieldId::RB],
+    &[FieldId::FrD, FieldId::RA, FieldId::RB, FieldId::PsWX, FieldId::PsIX],
+    &[FieldId::FrD, FieldId::RA, FieldId::RB, FieldId::PsWX, FieldId::PsIX],
+    &[FieldId::FrS, FieldId::RA, FieldId::RB, FieldId::PsWX, FieldId::PsIX],
+    &[FieldId::FrS, FieldId::RA, FieldId::RB, FieldId::PsWX, FieldId::PsIX],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::CrfD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::CrfD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::CrfD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::CrfD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::VD],
+    &[FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VB, FieldId::Vuimm],
+    &[FieldId::VD, FieldId::VB, FieldId::Vuimm],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VB, FieldId::Vuimm],
+    &[FieldId::VD, FieldId::VB, FieldId::Vuimm],
+    &[FieldId::VD, FieldId::VB],
+    &[FieldId::VD, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VC, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB, FieldId::VC],
+    &[FieldId::VD, FieldId::VA, FieldId::VB, FieldId::VC],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB, FieldId::VC],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB, FieldId::VC],
+    &[FieldId::VD, FieldId::VA, FieldId::VB, FieldId::VC],
+    &[FieldId::VD, FieldId::VA, FieldId::VB, FieldId::VC],
+    &[FieldId::VD, FieldId::VA, FieldId::VB, FieldId::VC],
+    &[FieldId::VD, FieldId::VA, FieldId::VB, FieldId::VC],
+    &[FieldId::VD, FieldId::VA, FieldId::VB, FieldId::VC],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VC, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB, FieldId::VC],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VB],
+    &[FieldId::VD, FieldId::VB],
+    &[FieldId::VD, FieldId::VB],
+    &[FieldId::VD, FieldId::VB],
+    &[FieldId::VD, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB, FieldId::VC],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB, FieldId::SHB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VB, FieldId::Vuimm],
+    &[FieldId::VD, FieldId::VB, FieldId::Vuimm],
+    &[FieldId::VD, FieldId::Vsimm],
+    &[FieldId::VD, FieldId::Vsimm],
+    &[FieldId::VD, FieldId::Vsimm],
+    &[FieldId::VD, FieldId::VB, FieldId::Vuimm],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VD, FieldId::VB],
+    &[FieldId::VD, FieldId::VB],
+    &[FieldId::VD, FieldId::VB],
+    &[FieldId::VD, FieldId::VB],
+    &[FieldId::VD, FieldId::VB],
+    &[FieldId::VD, FieldId::VB],
+    &[FieldId::VD, FieldId::VA, FieldId::VB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::RA, FieldId::RB],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128, FieldId::SHB],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128, FieldId::VC128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128, FieldId::Vsimm],
+    &[FieldId::VDS128, FieldId::VB128, FieldId::Vuimm],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128, FieldId::Vsimm],
+    &[FieldId::VDS128, FieldId::VB128, FieldId::Vuimm],
+    &[FieldId::VDS128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128, FieldId::PERM],
+    &[FieldId::VDS128, FieldId::VB128, FieldId::D3DType, FieldId::VMASK, FieldId::Zimm],
+    &[FieldId::VDS128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128, FieldId::Vuimm, FieldId::Zimm],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128, FieldId::Vsimm],
+    &[FieldId::VDS128, FieldId::VB128, FieldId::Vuimm],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VA128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128, FieldId::Vuimm],
+    &[FieldId::VDS128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128],
+    &[FieldId::VDS128, FieldId::VB128],
+    &[FieldId::RD, FieldId::RA, FieldId::Simm],
+    &[FieldId::RD, FieldId::RA, FieldId::Simm],
+    &[FieldId::CrfD, FieldId::L, FieldId::RA, FieldId::Uimm],
+    &[FieldId::CrfD, FieldId::L, FieldId::RA, FieldId::Simm],
+    &[FieldId::RD, FieldId::RA, FieldId::Simm],
+    &[FieldId::RD, FieldId::RA, FieldId::Simm],
+    &[FieldId::RD, FieldId::RA, FieldId::Simm],
+    &[FieldId::RD, FieldId::RA, FieldId::Uimm],
+    &[FieldId::BO, FieldId::BI, FieldId::BD],
+    &[],
+    &[FieldId::LI],
+    &[FieldId::BO, FieldId::BI],
+    &[FieldId::BO, FieldId::BI],
+    &[FieldId::CrbD, FieldId::CrbA, FieldId::CrbB],
+    &[FieldId::CrbD, FieldId::CrbA, FieldId::CrbB],
+    &[FieldId::CrbD, FieldId::CrbA, FieldId::CrbB],
+    &[FieldId::CrbD, FieldId::CrbA, FieldId::CrbB],
+    &[FieldId::CrbD, FieldId::CrbA, FieldId::CrbB],
+    &[FieldId::CrbD, FieldId::CrbA, FieldId::CrbB],
+    &[FieldId::CrbD, FieldId::CrbA, FieldId::CrbB],
+    &[FieldId::CrbD, FieldId::CrbA, FieldId::CrbB],
+    &[],
+    &[FieldId::CrfD, FieldId::CrfS],
+    &[],
+    &[],
+    &[FieldId::RA, FieldId::RS, FieldId::SH, FieldId::MB, FieldId::ME],
+    &[FieldId::RA, FieldId::RS, FieldId::SH, FieldId::MB, FieldId::ME],
+    &[FieldId::RA, FieldId::RS, FieldId::RB, FieldId::MB, FieldId::ME],
+    &[FieldId::RA, FieldId::RS, FieldId::Uimm],
+    &[FieldId::RA, FieldId::RS, FieldId::Uimm],
+    &[FieldId::RA, FieldId::RS, FieldId::Uimm],
+    &[FieldId::RA, FieldId::RS, FieldId::Uimm],
+    &[FieldId::RA, FieldId::RS, FieldId::Uimm],
+    &[FieldId::RA, FieldId::RS, FieldId::Uimm],
+    &[FieldId::RA, FieldId::RS, FieldId::RB, FieldId::MB64],
+    &[FieldId::RA, FieldId::RS, FieldId::RB, FieldId::ME64],
+    &[FieldId::RA, FieldId::RS, FieldId::SH64, FieldId::MB64],
+    &[FieldId::RA, FieldId::RS, FieldId::SH64, FieldId::MB64],
+    &[FieldId::RA, FieldId::RS, FieldId::SH64, FieldId::ME64],
+    &[FieldId::RA, FieldId::RS, FieldId::SH64, FieldId::MB64],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA],
+    &[FieldId::RD, FieldId::RA],
+    &[FieldId::RA, FieldId::RS, FieldId::RB],
+    &[FieldId::RA, FieldId::RS, FieldId::RB],
+    &[FieldId::CrfD, FieldId::L, FieldId::RA, FieldId::RB],
+    &[FieldId::CrfD, FieldId::L, FieldId::RA, FieldId::RB],
+    &[FieldId::RA, FieldId::RS],
+    &[FieldId::RA, FieldId::RB],
+    &[FieldId::RA, FieldId::RB],
+    &[FieldId::RA, FieldId::RB],
+    &[FieldId::RA, FieldId::RB],
+    &[FieldId::RA, FieldId::RB],
+    &[FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RS, FieldId::RA, FieldId::RB],
+    &[],
+    &[FieldId::RA, FieldId::RS, FieldId::RB],
+    &[FieldId::RA, FieldId::RS],
+    &[FieldId::RA, FieldId::RS],
+    &[FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::FrD, FieldId::RA, FieldId::RB],
+    &[FieldId::FrD, FieldId::RA, FieldId::RB],
+    &[FieldId::FrD, FieldId::RA, FieldId::RB],
+    &[FieldId::FrD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::NB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::CrfD],
+    &[FieldId::RD],
+    &[FieldId::RD],
+    &[FieldId::RD, FieldId::Spr],
+    &[FieldId::RD, FieldId::Sr],
+    &[FieldId::RD, FieldId::RB],
+    &[FieldId::RD, FieldId::Tbr],
+    &[FieldId::Crm, FieldId::RS],
+    &[FieldId::RS],
+    &[FieldId::Spr, FieldId::RS],
+    &[FieldId::Sr, FieldId::RS],
+    &[FieldId::RS, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RA, FieldId::RS, FieldId::RB],
+    &[FieldId::RD, FieldId::RA],
+    &[FieldId::RA, FieldId::RS, FieldId::RB],
+    &[FieldId::RA, FieldId::RS, FieldId::RB],
+    &[FieldId::RA, FieldId::RS, FieldId::RB],
+    &[FieldId::RA, FieldId::RS, FieldId::RB],
+    &[FieldId::RA, FieldId::RS, FieldId::RB],
+    &[FieldId::RA, FieldId::RS, FieldId::SH],
+    &[FieldId::RA, FieldId::RS, FieldId::RB],
+    &[FieldId::RS, FieldId::RA, FieldId::RB],
+    &[FieldId::RS, FieldId::RA, FieldId::RB],
+    &[FieldId::FrS, FieldId::RA, FieldId::RB],
+    &[FieldId::FrS, FieldId::RA, FieldId::RB],
+    &[FieldId::FrS, FieldId::RA, FieldId::RB],
+    &[FieldId::FrS, FieldId::RA, FieldId::RB],
+    &[FieldId::FrS, FieldId::RA, FieldId::RB],
+    &[FieldId::RS, FieldId::RA, FieldId::RB],
+    &[FieldId::RS, FieldId::RA, FieldId::RB],
+    &[FieldId::RS, FieldId::RA, FieldId::RB],
+    &[FieldId::RS, FieldId::RA, FieldId::NB],
+    &[FieldId::RS, FieldId::RA, FieldId::RB],
+    &[FieldId::RS, FieldId::RA, FieldId::RB],
+    &[FieldId::RS, FieldId::RA, FieldId::RB],
+    &[FieldId::RS, FieldId::RA, FieldId::RB],
+    &[FieldId::RS, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA],
+    &[FieldId::RD, FieldId::RA],
+    &[FieldId::SyncL],
+    &[FieldId::RB],
+    &[],
+    &[FieldId::TO, FieldId::RA, FieldId::RB],
+    &[FieldId::RA, FieldId::RS, FieldId::RB],
+    &[FieldId::RA, FieldId::RS],
+    &[FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RA, FieldId::RS],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::Crm],
+    &[FieldId::RS, FieldId::MtmsrdL],
+    &[FieldId::Sr, FieldId::RS],
+    &[FieldId::RS, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::RA, FieldId::RB],
+    &[],
+    &[FieldId::RB],
+    &[FieldId::RA, FieldId::RS, FieldId::RB],
+    &[FieldId::RA, FieldId::RS, FieldId::RB],
+    &[FieldId::RA, FieldId::RS, FieldId::SH64],
+    &[FieldId::RA, FieldId::RS, FieldId::RB],
+    &[FieldId::RS, FieldId::RA, FieldId::RB],
+    &[FieldId::RS, FieldId::RA, FieldId::RB],
+    &[FieldId::RS, FieldId::RA, FieldId::RB],
+    &[FieldId::TO, FieldId::RA, FieldId::RB],
+    &[FieldId::STRM, FieldId::DsA],
+    &[FieldId::RA, FieldId::RB, FieldId::STRM],
+    &[FieldId::RA, FieldId::RB, FieldId::STRM],
+    &[FieldId::VD, FieldId::RA, FieldId::RB],
+    &[FieldId::VD, FieldId::RA, FieldId::RB],
+    &[FieldId::VD, FieldId::RA, FieldId::RB],
+    &[FieldId::VD, FieldId::RA, FieldId::RB],
+    &[FieldId::VD, FieldId::RA, FieldId::RB],
+    &[FieldId::VD, FieldId::RA, FieldId::RB],
+    &[FieldId::VD, FieldId::RA, FieldId::RB],
+    &[FieldId::VD, FieldId::RA, FieldId::RB],
+    &[FieldId::VD, FieldId::RA, FieldId::RB],
+    &[FieldId::VD, FieldId::RA, FieldId::RB],
+    &[FieldId::VD, FieldId::RA, FieldId::RB],
+    &[FieldId::VS, FieldId::RA, FieldId::RB],
+    &[FieldId::VS, FieldId::RA, FieldId::RB],
+    &[FieldId::VS, FieldId::RA, FieldId::RB],
+    &[FieldId::VS, FieldId::RA, FieldId::RB],
+    &[FieldId::VS, FieldId::RA, FieldId::RB],
+    &[FieldId::VS, FieldId::RA, FieldId::RB],
+    &[FieldId::VS, FieldId::RA, FieldId::RB],
+    &[FieldId::VS, FieldId::RA, FieldId::RB],
+    &[FieldId::VS, FieldId::RA, FieldId::RB],
+    &[FieldId::RD, FieldId::Offset, FieldId::RA],
+    &[FieldId::RD, FieldId::Offset, FieldId::RA],
+    &[FieldId::RD, FieldId::Offset, FieldId::RA],
+    &[FieldId::RD, FieldId::Offset, FieldId::RA],
+    &[FieldId::RS, FieldId::Offset, FieldId::RA],
+    &[FieldId::RS, FieldId::Offset, FieldId::RA],
+    &[FieldId::RS, FieldId::Offset, FieldId::RA],
+    &[FieldId::RS, FieldId::Offset, FieldId::RA],
+    &[FieldId::RD, FieldId::Offset, FieldId::RA],
+    &[FieldId::RD, FieldId::Offset, FieldId::RA],
+    &[FieldId::RD, FieldId::Offset, FieldId::RA],
+    &[FieldId::RD, FieldId::Offset, FieldId::RA],
+    &[FieldId::RS, FieldId::Offset, FieldId::RA],
+    &[FieldId::RS, FieldId::Offset, FieldId::RA],
+    &[FieldId::RD, FieldId::Offset, FieldId::RA],
+    &[FieldId::RS, FieldId::Offset, FieldId::RA],
+    &[FieldId::FrD, FieldId::Offset, FieldId::RA],
+    &[FieldId::FrD, FieldId::Offset, FieldId::RA],
+    &[FieldId::FrD, FieldId::Offset, FieldId::RA],
+    &[FieldId::FrD, FieldId::Offset, FieldId::RA],
+    &[FieldId::FrS, FieldId::Offset, FieldId::RA],
+    &[FieldId::FrS, FieldId::Offset, FieldId::RA],
+    &[FieldId::FrS, FieldId::Offset, FieldId::RA],
+    &[FieldId::FrS, FieldId::Offset, FieldId::RA],
+    &[FieldId::FrD, FieldId::PsOffset, FieldId::RA, FieldId::PsW, FieldId::PsI],
+    &[FieldId::FrD, FieldId::PsOffset, FieldId::RA, FieldId::PsW, FieldId::PsI],
+    &[FieldId::RD, FieldId::Ds, FieldId::RA],
+    &[FieldId::RD, FieldId::Ds, FieldId::RA],
+    &[FieldId::RD, FieldId::Ds, FieldId::RA],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrS, FieldId::PsOffset, FieldId::RA, FieldId::PsW, FieldId::PsI],
+    &[FieldId::FrS, FieldId::PsOffset, FieldId::RA, FieldId::PsW, FieldId::PsI],
+    &[FieldId::RS, FieldId::Ds, FieldId::RA],
+    &[FieldId::RS, FieldId::Ds, FieldId::RA],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::CrfD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::CrfD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrC, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrA, FieldId::FrB],
+    &[FieldId::CrfD, FieldId::CrfS],
+    &[FieldId::FrD],
+    &[FieldId::CrbD],
+    &[FieldId::CrbD],
+    &[FieldId::MtfsfFM, FieldId::FrB],
+    &[FieldId::CrfD, FieldId::MtfsfIMM],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+    &[FieldId::FrD, FieldId::FrB],
+];
+/// The modifiers of each opcode.
+static OPCODE_MODIFIERS: [&[FieldId]; 511] = [
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::RcAV],
+    &[FieldId::RcAV],
+    &[FieldId::RcAV],
+    &[FieldId::RcAV],
+    &[FieldId::RcAV],
+    &[FieldId::RcAV],
+    &[FieldId::RcAV],
+    &[FieldId::RcAV],
+    &[FieldId::RcAV],
+    &[FieldId::RcAV],
+    &[FieldId::RcAV],
+    &[FieldId::RcAV],
+    &[FieldId::RcAV],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::Rc128],
+    &[FieldId::Rc128],
+    &[FieldId::Rc128],
+    &[FieldId::Rc128],
+    &[FieldId::Rc128],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::LK, FieldId::AA, FieldId::BP, FieldId::BNP],
+    &[],
+    &[FieldId::LK, FieldId::AA],
+    &[FieldId::LK, FieldId::BPND],
+    &[FieldId::LK, FieldId::BPND],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::OE, FieldId::Rc],
+    &[FieldId::OE, FieldId::Rc],
+    &[FieldId::OE, FieldId::Rc],
+    &[FieldId::OE, FieldId::Rc],
+    &[FieldId::OE, FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[],
+    &[],
+    &[FieldId::Rc],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::OE, FieldId::Rc],
+    &[FieldId::OE, FieldId::Rc],
+    &[],
+    &[],
+    &[],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::OE, FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::OE, FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::OE, FieldId::Rc],
+    &[FieldId::OE, FieldId::Rc],
+    &[FieldId::OE, FieldId::Rc],
+    &[FieldId::OE, FieldId::Rc],
+    &[FieldId::OE, FieldId::Rc],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[],
+    &[FieldId::OE, FieldId::Rc],
+    &[FieldId::OE, FieldId::Rc],
+    &[FieldId::Rc],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::OE, FieldId::Rc],
+    &[],
+    &[],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::T],
+    &[FieldId::T],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[],
+    &[],
+    &[],
+    &[],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[],
+    &[],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+    &[FieldId::Rc],
+];
 pub type Arguments = [Argument; 5];
 pub const EMPTY_ARGS: Arguments = [
     Argument::None,
@@ -6190,7 +9840,7 @@ fn basic_vpermwi128(out: &mut ParsedIns, ins: Ins) {
         args: [
             Argument::VR(VR(ins.field_vds128() as _)),
             Argument::VR(VR(ins.field_vb128() as _)),
-            Argument::OpaqueU(OpaqueU(ins.field_perm() as _)),
+            Argument::Swizzle(Swizzle(ins.field_perm() as _)),
             Argument::None,
             Argument::None,
         ],
@@ -6202,8 +9852,8 @@ fn basic_vpkd3d128(out: &mut ParsedIns, ins: Ins) {
         args: [
             Argument::VR(VR(ins.field_vds128() as _)),
             Argument::VR(VR(ins.field_vb128() as _)),
-            Argument::OpaqueU(OpaqueU(ins.field_d3dtype() as _)),
-            Argument::OpaqueU(OpaqueU(ins.field_vmask() as _)),
+            Argument::D3DType(D3DType(ins.field_d3dtype() as _)),
+            Argument::PackMask(PackMask(ins.field_vmask() as _)),
             Argument::OpaqueU(OpaqueU(ins.field_zimm() as _)),
         ],
     };
@@ -8471,7 +12121,7 @@ fn simplified_rlwinm(out: &mut ParsedIns, ins: Ins) {
         };
         return;
     }
-    if ins.field_sh() < 0x20 && ins.field_me() == 31 - ins.field_sh() {
+    if ins.field_mb() < 32 - ins.field_sh() && ins.field_me() == 31 - ins.field_sh() {
         *out = {
             static MODIFIERS: [&str; 2] = ["clrlslwi", "clrlslwi."];
             ParsedIns {
@@ -13503,8 +17153,8 @@ fn uses_vctsxs128(out: &mut Arguments, ins: Ins) {
 fn uses_vpkd3d128(out: &mut Arguments, ins: Ins) {
     *out = [
         Argument::VR(VR(ins.field_vb128() as _)),
-        Argument::OpaqueU(OpaqueU(ins.field_d3dtype() as _)),
-        Argument::OpaqueU(OpaqueU(ins.field_vmask() as _)),
+        Argument::D3DType(D3DType(ins.field_d3dtype() as _)),
+        Argument::PackMask(PackMask(ins.field_vmask() as _)),
         Argument::OpaqueU(OpaqueU(ins.field_zimm() as _)),
         Argument::None,
     ];
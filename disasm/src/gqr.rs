@@ -0,0 +1,322 @@
+use crate::{AccessFormat, AccessKind, Argument, FlowControl, Ins, Opcode, GPR, GQR, SPR};
+use core::fmt::{self, Display, Formatter};
+
+/// The configuration of a graphics quantization register. (GQR0-GQR7)
+///
+/// Paired-single loads and stores (`psq_l`, `psq_st`, ...) convert values
+/// between memory and the FPRs using the GQR selected by the instruction.
+///
+/// ```
+/// use powerpc::{GqrConfig, QuantizedType};
+///
+/// let config = GqrConfig::from_bits(0x0807_0807);
+/// assert_eq!(config.load.ty, QuantizedType::S16);
+/// assert_eq!(config.load.scale, 8);
+/// assert_eq!(config.to_bits(), 0x0807_0807);
+/// assert_eq!(config.to_string(), "load s16 scaled by 2^-8, store s16 scaled by 2^8");
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct GqrConfig {
+    /// The conversion used by loads. (LD_TYPE, LD_SCALE)
+    pub load: Quantization,
+    /// The conversion used by stores. (ST_TYPE, ST_SCALE)
+    pub store: Quantization,
+}
+
+/// The conversion of one half of a GQR.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Quantization {
+    /// The type of values in memory.
+    pub ty: QuantizedType,
+    /// The scale, from -32 to 31.
+    ///
+    /// Integers are multiplied by `2^-scale` when loaded and `2^scale` when stored.
+    /// Floats are not scaled.
+    pub scale: i8,
+}
+
+/// The type of quantized values in memory.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum QuantizedType {
+    /// A single-precision float. (Reserved types 1-3 also decode as floats)
+    #[default]
+    Float,
+    /// An unsigned 8-bit integer.
+    U8,
+    /// An unsigned 16-bit integer.
+    U16,
+    /// A signed 8-bit integer.
+    S8,
+    /// A signed 16-bit integer.
+    S16,
+}
+
+impl QuantizedType {
+    /// Decodes a 3-bit type field.
+    #[inline]
+    pub const fn from_bits(bits: u32) -> Self {
+        match bits & 7 {
+            4 => Self::U8,
+            5 => Self::U16,
+            6 => Self::S8,
+            7 => Self::S16,
+            _ => Self::Float,
+        }
+    }
+
+    /// Encodes the type as a 3-bit type field.
+    #[inline]
+    pub const fn to_bits(self) -> u32 {
+        match self {
+            Self::Float => 0,
+            Self::U8 => 4,
+            Self::U16 => 5,
+            Self::S8 => 6,
+            Self::S16 => 7,
+        }
+    }
+
+    /// The size of a value in memory, in bytes.
+    #[inline]
+    pub const fn size(self) -> u32 {
+        match self {
+            Self::Float => 4,
+            Self::U16 | Self::S16 => 2,
+            Self::U8 | Self::S8 => 1,
+        }
+    }
+
+    /// The name of the type, as used in C.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Float => "f32",
+            Self::U8 => "u8",
+            Self::U16 => "u16",
+            Self::S8 => "s8",
+            Self::S16 => "s16",
+        }
+    }
+}
+
+impl Display for QuantizedType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl Quantization {
+    /// Decodes a type field from bits 0-2 and a scale field from bits 8-13.
+    #[inline]
+    const fn from_bits(bits: u32) -> Self {
+        Self { ty: QuantizedType::from_bits(bits), scale: ((bits >> 8) as i8) << 2 >> 2 }
+    }
+
+    /// Encodes the type and scale fields into bits 0-2 and 8-13.
+    #[inline]
+    const fn to_bits(self) -> u32 {
+        self.ty.to_bits() | ((self.scale as u32) & 0x3F) << 8
+    }
+
+    /// The power of two that values are multiplied by, or 0 for floats.
+    #[inline]
+    pub const fn exponent(self, kind: AccessKind) -> i8 {
+        match (self.ty, kind) {
+            (QuantizedType::Float, _) => 0,
+            (_, AccessKind::Load) => -self.scale,
+            (_, AccessKind::Store) => self.scale,
+        }
+    }
+
+    fn fmt_as(&self, f: &mut Formatter<'_>, kind: AccessKind) -> fmt::Result {
+        write!(f, "{}", self.ty)?;
+        match self.exponent(kind) {
+            0 => Ok(()),
+            exponent => write!(f, " scaled by 2^{}", exponent),
+        }
+    }
+}
+
+impl GqrConfig {
+    /// Decodes a 32-bit GQR value. Reserved bits are ignored.
+    #[inline]
+    pub const fn from_bits(bits: u32) -> Self {
+        Self { load: Quantization::from_bits(bits >> 16), store: Quantization::from_bits(bits) }
+    }
+
+    /// Encodes the configuration as a 32-bit GQR value.
+    #[inline]
+    pub const fn to_bits(self) -> u32 {
+        self.load.to_bits() << 16 | self.store.to_bits()
+    }
+
+    /// Returns the conversion used by accesses of the given kind.
+    #[inline]
+    pub const fn quantization(self, kind: AccessKind) -> Quantization {
+        match kind {
+            AccessKind::Load => self.load,
+            AccessKind::Store => self.store,
+        }
+    }
+}
+
+impl Display for GqrConfig {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str("load ")?;
+        self.load.fmt_as(f, AccessKind::Load)?;
+        f.write_str(", store ")?;
+        self.store.fmt_as(f, AccessKind::Store)
+    }
+}
+
+/// A paired-single load or store with a known GQR configuration.
+///
+/// Displayed as a description of the conversion, such as `load 2 × s16 scaled by 2^-8`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct QuantizedAccess {
+    /// Whether memory is read or written.
+    pub kind: AccessKind,
+    /// The number of values accessed. (1 if the W bit is set, otherwise 2)
+    pub count: u32,
+    /// The conversion applied to each value.
+    pub quantization: Quantization,
+}
+
+impl QuantizedAccess {
+    /// The number of bytes accessed.
+    #[inline]
+    pub const fn size(&self) -> u32 {
+        self.count * self.quantization.ty.size()
+    }
+}
+
+impl Display for QuantizedAccess {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            AccessKind::Load => "load",
+            AccessKind::Store => "store",
+        };
+        write!(f, "{} {} × ", kind, self.count)?;
+        self.quantization.fmt_as(f, self.kind)
+    }
+}
+
+/// Tracks the values written to the GQRs by `mtspr` in a sequence of instructions.
+///
+/// GPRs set from immediates (`li`, `lis`, `addi`, `ori`, ...) are followed so that
+/// the value moved into a GQR is known. The analysis is linear: it does not follow
+/// branches, so callers should [reset](GqrTracker::reset) or seed the state at
+/// the start of each basic block as appropriate. Calls clobber the volatile GPRs
+/// (r0 and r3-r12), but the GQRs are assumed to be preserved.
+///
+/// ```
+/// use powerpc::{Extensions, GqrTracker, Ins};
+///
+/// let mut tracker = GqrTracker::new();
+/// for code in [
+///     0x3C600807, // lis r3, 0x807
+///     0x38630807, // addi r3, r3, 0x807
+///     0x7C72E3A6, // mtspr GQR2, r3
+/// ] {
+///     tracker.step(Ins::new(code, Extensions::gekko_broadway()));
+/// }
+/// let ins = Ins::new(0xE0232000, Extensions::gekko_broadway()); // psq_l f1, 0x0(r3), 0, qr2
+/// assert_eq!(tracker.annotate(ins).unwrap().to_string(), "load 2 × s16 scaled by 2^-8");
+/// ```
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct GqrTracker {
+    gprs: [Option<u32>; 32],
+    gqrs: [Option<GqrConfig>; 8],
+}
+
+impl GqrTracker {
+    /// Creates a tracker with all registers unknown.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forgets all register values.
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Returns the known configuration of a GQR.
+    #[inline]
+    pub fn gqr(&self, gqr: GQR) -> Option<GqrConfig> {
+        self.gqrs[gqr.0 as usize & 7]
+    }
+
+    /// Sets the known configuration of a GQR, such as one set up before the analyzed code.
+    #[inline]
+    pub fn set_gqr(&mut self, gqr: GQR, config: Option<GqrConfig>) {
+        self.gqrs[gqr.0 as usize & 7] = config;
+    }
+
+    /// Returns the known value of a GPR.
+    #[inline]
+    pub fn gpr(&self, gpr: GPR) -> Option<u32> {
+        self.gprs[gpr.0 as usize & 31]
+    }
+
+    /// Sets the known value of a GPR.
+    #[inline]
+    pub fn set_gpr(&mut self, gpr: GPR, value: Option<u32>) {
+        self.gprs[gpr.0 as usize & 31] = value;
+    }
+
+    /// Updates the tracked registers with the effects of an instruction.
+    pub fn step(&mut self, ins: Ins) {
+        if let FlowControl::Call { .. } = ins.flow_control() {
+            self.gprs[0] = None;
+            self.gprs[3..=12].fill(None);
+            return;
+        }
+        let ra = || if ins.field_ra() == 0 { Some(0) } else { self.gprs[ins.field_ra() as usize] };
+        let rs = self.gprs[ins.field_rs() as usize];
+        let simm = ins.field_simm() as i32 as u32;
+        let uimm = ins.field_uimm() as u32;
+        let value = match ins.op {
+            Opcode::Addi => ra().map(|a| a.wrapping_add(simm)),
+            Opcode::Addis => ra().map(|a| a.wrapping_add(simm << 16)),
+            Opcode::Ori => rs.map(|s| s | uimm),
+            Opcode::Oris => rs.map(|s| s | uimm << 16),
+            Opcode::Xori => rs.map(|s| s ^ uimm),
+            Opcode::Xoris => rs.map(|s| s ^ uimm << 16),
+            Opcode::Or if ins.field_rs() == ins.field_rb() => rs,
+            Opcode::Mtspr => {
+                if let 912..=919 = ins.field_spr() {
+                    self.gqrs[ins.field_spr() as usize - 912] = rs.map(GqrConfig::from_bits);
+                }
+                return;
+            }
+            _ => {
+                for arg in ins.defs() {
+                    match arg {
+                        Argument::GPR(gpr) => self.set_gpr(gpr, None),
+                        Argument::SPR(SPR(spr @ 912..=919)) => self.gqrs[spr as usize - 912] = None,
+                        _ => {}
+                    }
+                }
+                return;
+            }
+        };
+        let dest = match ins.op {
+            Opcode::Addi | Opcode::Addis => ins.field_rd(),
+            _ => ins.field_ra(),
+        };
+        self.gprs[dest as usize] = value;
+    }
+
+    /// Describes the conversion of a paired-single load or store, if its GQR is known.
+    pub fn annotate(&self, ins: Ins) -> Option<QuantizedAccess> {
+        let access = ins.memory_access()?;
+        let AccessFormat::Quantized { gqr, single } = access.format else {
+            return None;
+        };
+        let config = self.gqr(gqr)?;
+        Some(QuantizedAccess {
+            kind: access.kind,
+            count: if single { 1 } else { 2 },
+            quantization: config.quantization(access.kind),
+        })
+    }
+}
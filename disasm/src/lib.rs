@@ -1,9 +1,25 @@
 #![no_std]
+mod branch;
 mod disasm;
+mod encode;
+mod explain;
+mod flow;
 mod generated;
+mod gqr;
+mod memory;
+mod pseudo;
+mod rotate;
 
+pub use branch::{BranchCondition, BranchHint, CrCondition, CtrCondition};
 pub use disasm::{
-    Argument, BranchDest, CRBit, CRField, Extensions, Ins, InsIter, Offset, OpaqueU, ParsedIns,
-    Simm, Uimm, FPR, GPR, GQR, SPR, SR,
+    Argument, BitRange, BranchDest, CRBit, CRField, D3DType, Extensions, Ins, InsIter, Offset,
+    OpaqueU, PackMask, ParsedIns, Simm, Swizzle, Uimm, FPR, GPR, GQR, SPR, SR, VR,
 };
-pub use generated::{Arguments, Extension, Opcode};
+pub use encode::{EncodeError, InsBuilder};
+pub use explain::{BitOwner, BitSegment, Explanation, FieldEncoding};
+pub use flow::{FlowControl, TrapCondition};
+pub use generated::{Arguments, Extension, FieldId, Opcode};
+pub use gqr::{GqrConfig, GqrTracker, Quantization, QuantizedAccess, QuantizedType};
+pub use memory::{AccessFormat, AccessKind, AccessOffset, Alignment, MemoryAccess, RegisterReader};
+pub use pseudo::Pseudo;
+pub use rotate::{mask32, mask64, RotateAmount, RotateMask};
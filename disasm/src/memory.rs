@@ -0,0 +1,316 @@
+use crate::{Argument, FieldId, Ins, Opcode, GPR, GQR};
+
+/// A description of the memory accessed by a load or store instruction.
+///
+/// ```
+/// use powerpc::{AccessOffset, Argument, Extensions, Ins, GPR};
+///
+/// let ins = Ins::new(0x8061FFF8, Extensions::none()); // lwz r3, -0x8(r1)
+/// let access = ins.memory_access().unwrap();
+/// assert_eq!(access.reg, Argument::GPR(GPR(3)));
+/// assert_eq!(access.base, Some(GPR(1)));
+/// assert_eq!(access.offset, AccessOffset::Displacement(-8));
+/// assert_eq!(access.size, Some(4));
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct MemoryAccess {
+    /// Whether memory is read or written.
+    pub kind: AccessKind,
+    /// How the data is interpreted.
+    pub format: AccessFormat,
+    /// The register loaded or stored. (The first register for string and multiple forms)
+    pub reg: Argument,
+    /// The base register, or `None` if `rA` is 0 and used as the value 0.
+    pub base: Option<GPR>,
+    /// The offset added to the base register.
+    pub offset: AccessOffset,
+    /// The number of bytes accessed, if known statically.
+    pub size: Option<u32>,
+    /// Whether the loaded value is sign-extended.
+    pub signed: bool,
+    /// Whether the effective address is written back to the base register.
+    pub update: bool,
+    /// How the effective address is aligned.
+    pub alignment: Alignment,
+    /// Whether the bytes are reversed. (Little-endian access)
+    pub byte_reversed: bool,
+    /// Whether the access is part of an atomic sequence. (Load-reserve or store-conditional)
+    pub atomic: bool,
+}
+
+/// Whether memory is read or written.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum AccessKind {
+    Load,
+    Store,
+}
+
+/// How the accessed data is interpreted.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum AccessFormat {
+    /// An integer. (Including `stfiwx`)
+    Integer,
+    /// A single or double precision float, depending on the size.
+    Float,
+    /// One or two paired-single values, converted using a GQR.
+    Quantized {
+        /// The quantization register.
+        gqr: GQR,
+        /// Whether only one value is accessed. (The W bit)
+        single: bool,
+    },
+    /// A vector or vector element.
+    Vector,
+    /// Consecutive words loaded into or stored from consecutive GPRs. (`lmw`, `stmw`)
+    Multiple,
+    /// Consecutive bytes loaded into or stored from consecutive GPRs. (`lswi`, `stswx`)
+    String,
+    /// A cache block set to zero. (`dcbz`)
+    Zero,
+}
+
+/// The offset added to the base register to compute the effective address.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum AccessOffset {
+    /// No offset.
+    None,
+    /// An immediate displacement.
+    Displacement(i32),
+    /// An index register.
+    Index(GPR),
+}
+
+/// How the effective address of an access is aligned.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Alignment {
+    /// Any address is accepted.
+    Any,
+    /// The address must be a multiple of the given size, or an alignment exception occurs.
+    Required(u32),
+    /// The low bits of the address are ignored, aligning it down to the given size.
+    Truncated(u32),
+    /// Only the bytes from the address up to the next 16-byte boundary. (`lvlx`, `stvlx`)
+    Left,
+    /// Only the bytes from the previous 16-byte boundary up to the address. (`lvrx`, `stvrx`)
+    Right,
+}
+
+impl Ins {
+    /// Returns the memory accessed by a load or store instruction.
+    ///
+    /// Cache management instructions other than `dcbz` do not modify memory
+    /// and are not included, nor are `lvsl` and `lvsr`.
+    pub fn memory_access(&self) -> Option<MemoryAccess> {
+        use AccessFormat as F;
+        use AccessKind::{Load, Store};
+        use Alignment as A;
+
+        // A size of 0 is used when the size is only known at runtime
+        #[rustfmt::skip]
+        let (kind, format, size, signed, update, alignment) = match self.op {
+            // Integer
+            Opcode::Lbz | Opcode::Lbzx => (Load, F::Integer, 1, false, false, A::Any),
+            Opcode::Lbzu | Opcode::Lbzux => (Load, F::Integer, 1, false, true, A::Any),
+            Opcode::Lhz | Opcode::Lhzx | Opcode::Lhbrx => (Load, F::Integer, 2, false, false, A::Any),
+            Opcode::Lhzu | Opcode::Lhzux => (Load, F::Integer, 2, false, true, A::Any),
+            Opcode::Lha | Opcode::Lhax => (Load, F::Integer, 2, true, false, A::Any),
+            Opcode::Lhau | Opcode::Lhaux => (Load, F::Integer, 2, true, true, A::Any),
+            Opcode::Lwz | Opcode::Lwzx | Opcode::Lwbrx => (Load, F::Integer, 4, false, false, A::Any),
+            Opcode::Lwzu | Opcode::Lwzux => (Load, F::Integer, 4, false, true, A::Any),
+            Opcode::Lwa | Opcode::Lwax => (Load, F::Integer, 4, true, false, A::Any),
+            Opcode::Lwaux => (Load, F::Integer, 4, true, true, A::Any),
+            Opcode::Ld | Opcode::Ldx => (Load, F::Integer, 8, false, false, A::Any),
+            Opcode::Ldu | Opcode::Ldux => (Load, F::Integer, 8, false, true, A::Any),
+            Opcode::Lwarx | Opcode::Eciwx => (Load, F::Integer, 4, false, false, A::Required(4)),
+            Opcode::Ldarx => (Load, F::Integer, 8, false, false, A::Required(8)),
+            Opcode::Stb | Opcode::Stbx => (Store, F::Integer, 1, false, false, A::Any),
+            Opcode::Stbu | Opcode::Stbux => (Store, F::Integer, 1, false, true, A::Any),
+            Opcode::Sth | Opcode::Sthx | Opcode::Sthbrx => (Store, F::Integer, 2, false, false, A::Any),
+            Opcode::Sthu | Opcode::Sthux => (Store, F::Integer, 2, false, true, A::Any),
+            Opcode::Stw | Opcode::Stwx | Opcode::Stwbrx => (Store, F::Integer, 4, false, false, A::Any),
+            Opcode::Stwu | Opcode::Stwux => (Store, F::Integer, 4, false, true, A::Any),
+            Opcode::Std | Opcode::Stdx => (Store, F::Integer, 8, false, false, A::Any),
+            Opcode::Stdu | Opcode::Stdux => (Store, F::Integer, 8, false, true, A::Any),
+            Opcode::Stwcx_ | Opcode::Ecowx => (Store, F::Integer, 4, false, false, A::Required(4)),
+            Opcode::Stdcx_ => (Store, F::Integer, 8, false, false, A::Required(8)),
+            // Floating point
+            Opcode::Lfs | Opcode::Lfsx => (Load, F::Float, 4, false, false, A::Any),
+            Opcode::Lfsu | Opcode::Lfsux => (Load, F::Float, 4, false, true, A::Any),
+            Opcode::Lfd | Opcode::Lfdx => (Load, F::Float, 8, false, false, A::Any),
+            Opcode::Lfdu | Opcode::Lfdux => (Load, F::Float, 8, false, true, A::Any),
+            Opcode::Stfs | Opcode::Stfsx => (Store, F::Float, 4, false, false, A::Any),
+            Opcode::Stfsu | Opcode::Stfsux => (Store, F::Float, 4, false, true, A::Any),
+            Opcode::Stfd | Opcode::Stfdx => (Store, F::Float, 8, false, false, A::Any),
+            Opcode::Stfdu | Opcode::Stfdux => (Store, F::Float, 8, false, true, A::Any),
+            Opcode::Stfiwx => (Store, F::Integer, 4, false, false, A::Any),
+            // Paired singles
+            Opcode::PsqL | Opcode::PsqLu | Opcode::PsqLx | Opcode::PsqLux
+            | Opcode::PsqSt | Opcode::PsqStu | Opcode::PsqStx | Opcode::PsqStux => {
+                let (gqr, single) = if self.op.fields().contains(&FieldId::PsI) {
+                    (self.field_ps_i(), self.field_ps_w() != 0)
+                } else {
+                    (self.field_ps_ix(), self.field_ps_wx() != 0)
+                };
+                let kind = match self.op {
+                    Opcode::PsqL | Opcode::PsqLu | Opcode::PsqLx | Opcode::PsqLux => Load,
+                    _ => Store,
+                };
+                let update = matches!(
+                    self.op,
+                    Opcode::PsqLu | Opcode::PsqLux | Opcode::PsqStu | Opcode::PsqStux
+                );
+                let format = F::Quantized { gqr: GQR(gqr), single };
+                // The size depends on the type configured in the GQR
+                (kind, format, 0, false, update, A::Any)
+            }
+            // String and multiple
+            Opcode::Lmw => (Load, F::Multiple, 4 * (32 - self.field_rd() as u32), false, false, A::Required(4)),
+            Opcode::Stmw => (Store, F::Multiple, 4 * (32 - self.field_rs() as u32), false, false, A::Required(4)),
+            Opcode::Lswi => (Load, F::String, string_size(self.field_nb()), false, false, A::Any),
+            Opcode::Stswi => (Store, F::String, string_size(self.field_nb()), false, false, A::Any),
+            // The size is taken from XER
+            Opcode::Lswx => (Load, F::String, 0, false, false, A::Any),
+            Opcode::Stswx => (Store, F::String, 0, false, false, A::Any),
+            // AltiVec and VMX128
+            Opcode::Lvebx => (Load, F::Vector, 1, false, false, A::Any),
+            Opcode::Lvehx => (Load, F::Vector, 2, false, false, A::Truncated(2)),
+            Opcode::Lvewx | Opcode::Lvewx128 => (Load, F::Vector, 4, false, false, A::Truncated(4)),
+            Opcode::Lvx | Opcode::Lvxl | Opcode::Lvx128 | Opcode::Lvxl128 => {
+                (Load, F::Vector, 16, false, false, A::Truncated(16))
+            }
+            Opcode::Lvlx | Opcode::Lvlxl | Opcode::Lvlx128 | Opcode::Lvlxl128 => {
+                (Load, F::Vector, 0, false, false, A::Left)
+            }
+            Opcode::Lvrx | Opcode::Lvrxl | Opcode::Lvrx128 | Opcode::Lvrxl128 => {
+                (Load, F::Vector, 0, false, false, A::Right)
+            }
+            Opcode::Stvebx => (Store, F::Vector, 1, false, false, A::Any),
+            Opcode::Stvehx => (Store, F::Vector, 2, false, false, A::Truncated(2)),
+            Opcode::Stvewx | Opcode::Stvewx128 => (Store, F::Vector, 4, false, false, A::Truncated(4)),
+            Opcode::Stvx | Opcode::Stvxl | Opcode::Stvx128 | Opcode::Stvxl128 => {
+                (Store, F::Vector, 16, false, false, A::Truncated(16))
+            }
+            Opcode::Stvlx | Opcode::Stvlxl | Opcode::Stvlx128 | Opcode::Stvlxl128 => {
+                (Store, F::Vector, 0, false, false, A::Left)
+            }
+            Opcode::Stvrx | Opcode::Stvrxl | Opcode::Stvrx128 | Opcode::Stvrxl128 => {
+                (Store, F::Vector, 0, false, false, A::Right)
+            }
+            // Cache block zero
+            Opcode::Dcbz | Opcode::DcbzL => (Store, F::Zero, 32, false, false, A::Truncated(32)),
+            Opcode::Dcbzl => (Store, F::Zero, 128, false, false, A::Truncated(128)),
+            _ => return None,
+        };
+
+        let fields = self.op.fields();
+        let offset = if fields.contains(&FieldId::Offset) {
+            AccessOffset::Displacement(self.field_offset() as i32)
+        } else if fields.contains(&FieldId::Ds) {
+            AccessOffset::Displacement(self.field_ds() as i32)
+        } else if fields.contains(&FieldId::PsOffset) {
+            AccessOffset::Displacement(self.field_ps_offset() as i32)
+        } else if fields.contains(&FieldId::RB) {
+            AccessOffset::Index(GPR(self.field_rb()))
+        } else {
+            AccessOffset::None
+        };
+        let base = match self.field_ra() {
+            0 if !update => None,
+            ra => Some(GPR(ra)),
+        };
+        let reg = match fields.first() {
+            Some(&FieldId::RA) | None => Argument::None,
+            Some(&id) => self.field(id),
+        };
+        Some(MemoryAccess {
+            kind,
+            format,
+            reg,
+            base,
+            offset,
+            size: if size == 0 { None } else { Some(size) },
+            signed,
+            update,
+            alignment,
+            byte_reversed: matches!(
+                self.op,
+                Opcode::Lhbrx | Opcode::Lwbrx | Opcode::Sthbrx | Opcode::Stwbrx
+            ),
+            atomic: matches!(
+                self.op,
+                Opcode::Lwarx | Opcode::Ldarx | Opcode::Stwcx_ | Opcode::Stdcx_
+            ),
+        })
+    }
+}
+
+/// The number of bytes accessed by `lswi` and `stswi`.
+const fn string_size(nb: u8) -> u32 {
+    if nb == 0 {
+        32
+    } else {
+        nb as u32
+    }
+}
+
+/// Provides register values for computing effective addresses.
+pub trait RegisterReader {
+    /// Returns the value of a general-purpose register.
+    fn gpr(&self, reg: GPR) -> u64;
+
+    /// Whether effective addresses are 64-bit. Otherwise, they wrap around at 32 bits.
+    fn mode64(&self) -> bool {
+        true
+    }
+}
+
+impl RegisterReader for [u64; 32] {
+    fn gpr(&self, reg: GPR) -> u64 {
+        self[reg.0 as usize]
+    }
+}
+
+impl RegisterReader for [u32; 32] {
+    fn gpr(&self, reg: GPR) -> u64 {
+        self[reg.0 as usize] as u64
+    }
+
+    fn mode64(&self) -> bool {
+        false
+    }
+}
+
+impl MemoryAccess {
+    /// Computes the effective address of the access from register values.
+    ///
+    /// The address is not aligned: see [MemoryAccess::alignment].
+    pub fn effective_address(&self, regs: &dyn RegisterReader) -> u64 {
+        let base = self.base.map_or(0, |reg| regs.gpr(reg));
+        let ea = match self.offset {
+            AccessOffset::None => base,
+            AccessOffset::Displacement(offset) => base.wrapping_add(offset as i64 as u64),
+            AccessOffset::Index(reg) => base.wrapping_add(regs.gpr(reg)),
+        };
+        if regs.mode64() {
+            ea
+        } else {
+            ea & 0xFFFF_FFFF
+        }
+    }
+}
+
+impl Ins {
+    /// Computes the effective address of a load or store from register values.
+    ///
+    /// ```
+    /// use powerpc::{Extensions, Ins};
+    ///
+    /// let mut regs = [0u32; 32];
+    /// regs[1] = 0x80400000;
+    /// let ins = Ins::new(0x8061FFF8, Extensions::none()); // lwz r3, -0x8(r1)
+    /// assert_eq!(ins.effective_address(&regs), Some(0x803FFFF8));
+    /// ```
+    pub fn effective_address(&self, regs: &dyn RegisterReader) -> Option<u64> {
+        self.memory_access().map(|access| access.effective_address(regs))
+    }
+}
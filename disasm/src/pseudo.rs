@@ -0,0 +1,686 @@
+use crate::disasm::SignedHexLiteral;
+use crate::{
+    AccessFormat, AccessKind, AccessOffset, Alignment, Argument, BranchCondition, CRBit,
+    CtrCondition, FieldId, FlowControl, Ins, MemoryAccess, Opcode, ParsedIns, Simm, TrapCondition,
+    Uimm, FPR, GPR,
+};
+use core::fmt::{self, Display, Formatter, Write};
+
+/// Renders an instruction as a C-like statement.
+///
+/// Register names and immediates are taken from the basic form of the instruction
+/// ([Ins::basic]). Instructions without a dedicated rendering are shown as a call
+/// to an intrinsic named after the mnemonic, assigning the defined registers.
+///
+/// ```
+/// use powerpc::{Extensions, Ins};
+///
+/// let ins = Ins::new(0x80610008, Extensions::none()); // lwz r3, 0x8(r1)
+/// assert_eq!(ins.pseudo(0x80003100).to_string(), "r3 = *(u32*)(r1 + 0x8)");
+/// let ins = Ins::new(0x41820010, Extensions::none()); // beq 0x10
+/// assert_eq!(ins.pseudo(0x80003100).to_string(), "if (cr0.eq) goto 0x80003110");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Pseudo {
+    ins: Ins,
+    addr: u32,
+}
+
+impl Ins {
+    /// Returns a C-like rendering of the instruction located at `addr`.
+    #[inline]
+    pub fn pseudo(self, addr: u32) -> Pseudo {
+        Pseudo { ins: self, addr }
+    }
+}
+
+/// Returns the template for instructions rendered from their basic arguments.
+///
+/// `{N}` is replaced by argument N, `{N:ps}` by argument N as a paired-single register,
+/// `{N:+}` by `+ N` or `- N` depending on the sign, and `{N:hi}` by argument N shifted
+/// left by 16.
+///
+/// Vector operands are cast to their lanes (e.g. `(u8x16)`), `vA.u8[i]` is a lane, and
+/// `(vA:vB)` is the 32-byte concatenation of two registers.
+fn template(ins: Ins) -> Option<&'static str> {
+    Some(match ins.op {
+        // Integer arithmetic
+        Opcode::Add => "{0} = {1} + {2}",
+        Opcode::Addc => "{0} = {1} + {2}; xer.ca = carry",
+        Opcode::Adde => "{0} = {1} + {2} + xer.ca",
+        Opcode::Addi => "{0} = {1}{2:+}",
+        Opcode::Addic | Opcode::Addic_ => "{0} = {1}{2:+}; xer.ca = carry",
+        Opcode::Addis => "{0} = {1} + {2:hi}",
+        Opcode::Addme => "{0} = {1} + xer.ca - 1",
+        Opcode::Addze => "{0} = {1} + xer.ca",
+        Opcode::Subf => "{0} = {2} - {1}",
+        Opcode::Subfc => "{0} = {2} - {1}; xer.ca = carry",
+        Opcode::Subfe => "{0} = ~{1} + {2} + xer.ca",
+        Opcode::Subfic => "{0} = {2} - {1}; xer.ca = carry",
+        Opcode::Subfme => "{0} = ~{1} + xer.ca - 1",
+        Opcode::Subfze => "{0} = ~{1} + xer.ca",
+        Opcode::Neg => "{0} = -{1}",
+        Opcode::Mulli => "{0} = {1} * {2}",
+        Opcode::Mullw => "{0} = (s32){1} * (s32){2}",
+        Opcode::Mulld => "{0} = {1} * {2}",
+        Opcode::Mulhw => "{0} = ((s64)(s32){1} * (s32){2}) >> 32",
+        Opcode::Mulhwu => "{0} = ((u64)(u32){1} * (u32){2}) >> 32",
+        Opcode::Mulhd => "{0} = ((s128){1} * (s64){2}) >> 64",
+        Opcode::Mulhdu => "{0} = ((u128){1} * (u64){2}) >> 64",
+        Opcode::Divw => "{0} = (s32){1} / (s32){2}",
+        Opcode::Divwu => "{0} = (u32){1} / (u32){2}",
+        Opcode::Divd => "{0} = (s64){1} / (s64){2}",
+        Opcode::Divdu => "{0} = (u64){1} / (u64){2}",
+        // Integer logical
+        Opcode::And => "{0} = {1} & {2}",
+        Opcode::Andc => "{0} = {1} & ~{2}",
+        Opcode::Andi_ => "{0} = {1} & {2}",
+        Opcode::Andis_ => "{0} = {1} & {2:hi}",
+        Opcode::Or => "{0} = {1} | {2}",
+        Opcode::Orc => "{0} = {1} | ~{2}",
+        Opcode::Ori => "{0} = {1} | {2}",
+        Opcode::Oris => "{0} = {1} | {2:hi}",
+        Opcode::Xor => "{0} = {1} ^ {2}",
+        Opcode::Xori => "{0} = {1} ^ {2}",
+        Opcode::Xoris => "{0} = {1} ^ {2:hi}",
+        Opcode::Nand => "{0} = ~({1} & {2})",
+        Opcode::Nor => "{0} = ~({1} | {2})",
+        Opcode::Eqv => "{0} = ~({1} ^ {2})",
+        Opcode::Extsb => "{0} = (s8){1}",
+        Opcode::Extsh => "{0} = (s16){1}",
+        Opcode::Extsw => "{0} = (s32){1}",
+        Opcode::Cntlzw => "{0} = cntlzw({1})",
+        Opcode::Cntlzd => "{0} = cntlzd({1})",
+        // Integer shift
+        Opcode::Slw => "{0} = (u32){1} << ({2} & 0x3f)",
+        Opcode::Srw => "{0} = (u32){1} >> ({2} & 0x3f)",
+        Opcode::Sraw => "{0} = (s32){1} >> ({2} & 0x3f)",
+        Opcode::Srawi => "{0} = (s32){1} >> {2}",
+        Opcode::Sld => "{0} = {1} << ({2} & 0x7f)",
+        Opcode::Srd => "{0} = {1} >> ({2} & 0x7f)",
+        Opcode::Srad => "{0} = (s64){1} >> ({2} & 0x7f)",
+        Opcode::Sradi => "{0} = (s64){1} >> {2}",
+        // Integer compare
+        Opcode::Cmp if ins.field_l() != 0 => "{0} = cmp((s64){2}, (s64){3})",
+        Opcode::Cmpi if ins.field_l() != 0 => "{0} = cmp((s64){2}, {3})",
+        Opcode::Cmpl if ins.field_l() != 0 => "{0} = cmp((u64){2}, (u64){3})",
+        Opcode::Cmpli if ins.field_l() != 0 => "{0} = cmp((u64){2}, {3})",
+        Opcode::Cmp => "{0} = cmp((s32){2}, (s32){3})",
+        Opcode::Cmpi => "{0} = cmp((s32){2}, {3})",
+        Opcode::Cmpl => "{0} = cmp((u32){2}, (u32){3})",
+        Opcode::Cmpli => "{0} = cmp((u32){2}, {3})",
+        // Condition register
+        Opcode::Crand => "{0} = {1} & {2}",
+        Opcode::Crandc => "{0} = {1} & ~{2}",
+        Opcode::Creqv => "{0} = ~({1} ^ {2})",
+        Opcode::Crnand => "{0} = ~({1} & {2})",
+        Opcode::Crnor => "{0} = ~({1} | {2})",
+        Opcode::Cror => "{0} = {1} | {2}",
+        Opcode::Crorc => "{0} = {1} | ~{2}",
+        Opcode::Crxor => "{0} = {1} ^ {2}",
+        Opcode::Mcrf => "{0} = {1}",
+        Opcode::Mcrfs => "{0} = fpscr[{1}]",
+        Opcode::Mcrxr => "{0} = xer[0:3]; xer[0:3] = 0",
+        Opcode::Mfcr => "{0} = cr",
+        Opcode::Mfocrf => "{0} = cr & fxm({1})",
+        Opcode::Mtcrf => "cr = ({1} & fxm({0})) | (cr & ~fxm({0}))",
+        // Special registers
+        Opcode::Mfspr => "{0} = {1}",
+        Opcode::Mtspr => "{0} = {1}",
+        Opcode::Mftb => "{0} = tb[{1}]",
+        Opcode::Mfmsr => "{0} = msr",
+        Opcode::Mtmsr | Opcode::Mtmsrd => "msr = {0}",
+        Opcode::Mfsr => "{0} = sr[{1}]",
+        Opcode::Mtsr | Opcode::Mtsrd => "sr[{0}] = {1}",
+        Opcode::Mfsrin => "{0} = sr[{1} >> 28]",
+        Opcode::Mtsrin | Opcode::Mtsrdin => "sr[{1} >> 28] = {0}",
+        // Floating point
+        Opcode::Fadd => "{0} = {1} + {2}",
+        Opcode::Fadds => "{0} = (f32)({1} + {2})",
+        Opcode::Fsub => "{0} = {1} - {2}",
+        Opcode::Fsubs => "{0} = (f32)({1} - {2})",
+        Opcode::Fmul => "{0} = {1} * {2}",
+        Opcode::Fmuls => "{0} = (f32)({1} * {2})",
+        Opcode::Fdiv => "{0} = {1} / {2}",
+        Opcode::Fdivs => "{0} = (f32)({1} / {2})",
+        Opcode::Fmadd => "{0} = {1} * {2} + {3}",
+        Opcode::Fmadds => "{0} = (f32)({1} * {2} + {3})",
+        Opcode::Fmsub => "{0} = {1} * {2} - {3}",
+        Opcode::Fmsubs => "{0} = (f32)({1} * {2} - {3})",
+        Opcode::Fnmadd => "{0} = -({1} * {2} + {3})",
+        Opcode::Fnmadds => "{0} = (f32)-({1} * {2} + {3})",
+        Opcode::Fnmsub => "{0} = -({1} * {2} - {3})",
+        Opcode::Fnmsubs => "{0} = (f32)-({1} * {2} - {3})",
+        Opcode::Fabs => "{0} = fabs({1})",
+        Opcode::Fnabs => "{0} = -fabs({1})",
+        Opcode::Fneg => "{0} = -{1}",
+        Opcode::Fmr => "{0} = {1}",
+        Opcode::Frsp => "{0} = (f32){1}",
+        Opcode::Fres => "{0} = (f32)(1.0 / {1})",
+        Opcode::Frsqrte => "{0} = 1.0 / sqrt({1})",
+        Opcode::Fsqrt => "{0} = sqrt({1})",
+        Opcode::Fsqrts => "{0} = (f32)sqrt({1})",
+        Opcode::Fsel => "{0} = {1} >= 0.0 ? {2} : {3}",
+        Opcode::Fctiw => "{0} = (s32){1}",
+        Opcode::Fctiwz => "{0} = (s32)trunc({1})",
+        Opcode::Fctid => "{0} = (s64){1}",
+        Opcode::Fctidz => "{0} = (s64)trunc({1})",
+        Opcode::Fcfid => "{0} = (f64)(s64){1}",
+        Opcode::Fcmpu | Opcode::Fcmpo => "{0} = cmp({1}, {2})",
+        Opcode::Mffs => "{0} = fpscr",
+        Opcode::Mtfsf => "fpscr = ({1} & fm({0})) | (fpscr & ~fm({0}))",
+        Opcode::Mtfsfi => "fpscr[{0}] = {1}",
+        Opcode::Mtfsb0 => "fpscr[{0}] = 0",
+        Opcode::Mtfsb1 => "fpscr[{0}] = 1",
+        // Paired singles
+        Opcode::PsAdd => "{0:ps} = {1:ps} + {2:ps}",
+        Opcode::PsSub => "{0:ps} = {1:ps} - {2:ps}",
+        Opcode::PsMul => "{0:ps} = {1:ps} * {2:ps}",
+        Opcode::PsDiv => "{0:ps} = {1:ps} / {2:ps}",
+        Opcode::PsMuls0 => "{0:ps} = {1:ps} * {2:ps}.ps0",
+        Opcode::PsMuls1 => "{0:ps} = {1:ps} * {2:ps}.ps1",
+        Opcode::PsMadd => "{0:ps} = {1:ps} * {2:ps} + {3:ps}",
+        Opcode::PsMadds0 => "{0:ps} = {1:ps} * {2:ps}.ps0 + {3:ps}",
+        Opcode::PsMadds1 => "{0:ps} = {1:ps} * {2:ps}.ps1 + {3:ps}",
+        Opcode::PsMsub => "{0:ps} = {1:ps} * {2:ps} - {3:ps}",
+        Opcode::PsNmadd => "{0:ps} = -({1:ps} * {2:ps} + {3:ps})",
+        Opcode::PsNmsub => "{0:ps} = -({1:ps} * {2:ps} - {3:ps})",
+        Opcode::PsSum0 => "{0:ps} = {{{1:ps}.ps0 + {3:ps}.ps1, {2:ps}.ps1}}",
+        Opcode::PsSum1 => "{0:ps} = {{{2:ps}.ps0, {1:ps}.ps0 + {3:ps}.ps1}}",
+        Opcode::PsMerge00 => "{0:ps} = {{{1:ps}.ps0, {2:ps}.ps0}}",
+        Opcode::PsMerge01 => "{0:ps} = {{{1:ps}.ps0, {2:ps}.ps1}}",
+        Opcode::PsMerge10 => "{0:ps} = {{{1:ps}.ps1, {2:ps}.ps0}}",
+        Opcode::PsMerge11 => "{0:ps} = {{{1:ps}.ps1, {2:ps}.ps1}}",
+        Opcode::PsMr => "{0:ps} = {1:ps}",
+        Opcode::PsNeg => "{0:ps} = -{1:ps}",
+        Opcode::PsAbs => "{0:ps} = fabs({1:ps})",
+        Opcode::PsNabs => "{0:ps} = -fabs({1:ps})",
+        Opcode::PsRes => "{0:ps} = 1.0 / {1:ps}",
+        Opcode::PsRsqrte => "{0:ps} = 1.0 / sqrt({1:ps})",
+        Opcode::PsSel => "{0:ps} = {1:ps} >= 0.0 ? {2:ps} : {3:ps}",
+        Opcode::PsCmpu0 | Opcode::PsCmpo0 => "{0} = cmp({1:ps}.ps0, {2:ps}.ps0)",
+        Opcode::PsCmpu1 | Opcode::PsCmpo1 => "{0} = cmp({1:ps}.ps1, {2:ps}.ps1)",
+        // AltiVec and VMX128 integer arithmetic
+        Opcode::Vaddubm => "{0} = (u8x16){1} + (u8x16){2}",
+        Opcode::Vadduhm => "{0} = (u16x8){1} + (u16x8){2}",
+        Opcode::Vadduwm => "{0} = (u32x4){1} + (u32x4){2}",
+        Opcode::Vaddubs => "{0} = sat((u8x16){1} + (u8x16){2})",
+        Opcode::Vadduhs => "{0} = sat((u16x8){1} + (u16x8){2})",
+        Opcode::Vadduws => "{0} = sat((u32x4){1} + (u32x4){2})",
+        Opcode::Vaddsbs => "{0} = sat((s8x16){1} + (s8x16){2})",
+        Opcode::Vaddshs => "{0} = sat((s16x8){1} + (s16x8){2})",
+        Opcode::Vaddsws => "{0} = sat((s32x4){1} + (s32x4){2})",
+        Opcode::Vaddcuw => "{0} = carry((u32x4){1} + (u32x4){2})",
+        Opcode::Vsububm => "{0} = (u8x16){1} - (u8x16){2}",
+        Opcode::Vsubuhm => "{0} = (u16x8){1} - (u16x8){2}",
+        Opcode::Vsubuwm => "{0} = (u32x4){1} - (u32x4){2}",
+        Opcode::Vsububs => "{0} = sat((u8x16){1} - (u8x16){2})",
+        Opcode::Vsubuhs => "{0} = sat((u16x8){1} - (u16x8){2})",
+        Opcode::Vsubuws => "{0} = sat((u32x4){1} - (u32x4){2})",
+        Opcode::Vsubsbs => "{0} = sat((s8x16){1} - (s8x16){2})",
+        Opcode::Vsubshs => "{0} = sat((s16x8){1} - (s16x8){2})",
+        Opcode::Vsubsws => "{0} = sat((s32x4){1} - (s32x4){2})",
+        Opcode::Vsubcuw => "{0} = carry((u32x4){1} - (u32x4){2})",
+        // AltiVec and VMX128 logical
+        Opcode::Vand | Opcode::Vand128 => "{0} = {1} & {2}",
+        Opcode::Vandc | Opcode::Vandc128 => "{0} = {1} & ~{2}",
+        Opcode::Vor | Opcode::Vor128 => "{0} = {1} | {2}",
+        Opcode::Vnor | Opcode::Vnor128 => "{0} = ~({1} | {2})",
+        Opcode::Vxor | Opcode::Vxor128 => "{0} = {1} ^ {2}",
+        Opcode::Vsel => "{0} = ({1} & ~{3}) | ({2} & {3})",
+        Opcode::Vsel128 => "{0} = ({1} & ~{0}) | ({2} & {0})",
+        // AltiVec and VMX128 compare
+        Opcode::Vcmpequb => "{0} = (u8x16){1} == (u8x16){2}",
+        Opcode::Vcmpequh => "{0} = (u16x8){1} == (u16x8){2}",
+        Opcode::Vcmpequw | Opcode::Vcmpequw128 => "{0} = (u32x4){1} == (u32x4){2}",
+        Opcode::Vcmpgtub => "{0} = (u8x16){1} > (u8x16){2}",
+        Opcode::Vcmpgtuh => "{0} = (u16x8){1} > (u16x8){2}",
+        Opcode::Vcmpgtuw => "{0} = (u32x4){1} > (u32x4){2}",
+        Opcode::Vcmpgtsb => "{0} = (s8x16){1} > (s8x16){2}",
+        Opcode::Vcmpgtsh => "{0} = (s16x8){1} > (s16x8){2}",
+        Opcode::Vcmpgtsw => "{0} = (s32x4){1} > (s32x4){2}",
+        Opcode::Vcmpeqfp | Opcode::Vcmpeqfp128 => "{0} = (f32x4){1} == (f32x4){2}",
+        Opcode::Vcmpgefp | Opcode::Vcmpgefp128 => "{0} = (f32x4){1} >= (f32x4){2}",
+        Opcode::Vcmpgtfp | Opcode::Vcmpgtfp128 => "{0} = (f32x4){1} > (f32x4){2}",
+        // AltiVec and VMX128 merge, splat and permute
+        Opcode::Vmrghb => "{0} = {{{1}.u8[0], {2}.u8[0], ..., {1}.u8[7], {2}.u8[7]}}",
+        Opcode::Vmrghh => "{0} = {{{1}.u16[0], {2}.u16[0], ..., {1}.u16[3], {2}.u16[3]}}",
+        Opcode::Vmrghw | Opcode::Vmrghw128 => {
+            "{0} = {{{1}.u32[0], {2}.u32[0], {1}.u32[1], {2}.u32[1]}}"
+        }
+        Opcode::Vmrglb => "{0} = {{{1}.u8[8], {2}.u8[8], ..., {1}.u8[15], {2}.u8[15]}}",
+        Opcode::Vmrglh => "{0} = {{{1}.u16[4], {2}.u16[4], ..., {1}.u16[7], {2}.u16[7]}}",
+        Opcode::Vmrglw | Opcode::Vmrglw128 => {
+            "{0} = {{{1}.u32[2], {2}.u32[2], {1}.u32[3], {2}.u32[3]}}"
+        }
+        Opcode::Vspltb => "{0} = splat({1}.u8[{2}])",
+        Opcode::Vsplth => "{0} = splat({1}.u16[{2}])",
+        Opcode::Vspltw | Opcode::Vspltw128 => "{0} = splat({1}.u32[{2}])",
+        Opcode::Vspltisb => "{0} = splat((s8){1})",
+        Opcode::Vspltish => "{0} = splat((s16){1})",
+        Opcode::Vspltisw => "{0} = splat((s32){1})",
+        Opcode::Vspltisw128 => "{0} = splat((s32){2})",
+        Opcode::Vperm | Opcode::Vperm128 => "{0} = ({1}:{2}).u8[{3}.u8 & 0x1f]",
+        Opcode::Vsldoi | Opcode::Vsldoi128 => "{0} = ({1}:{2}).u8[{3}..{3} + 16]",
+        // AltiVec and VMX128 floating point
+        Opcode::Vaddfp | Opcode::Vaddfp128 => "{0} = {1} + {2}",
+        Opcode::Vsubfp | Opcode::Vsubfp128 => "{0} = {1} - {2}",
+        Opcode::Vmulfp128 => "{0} = {1} * {2}",
+        Opcode::Vmaddfp => "{0} = {1} * {2} + {3}",
+        Opcode::Vnmsubfp => "{0} = -({1} * {2} - {3})",
+        // System
+        Opcode::Sync => "sync()",
+        Opcode::Isync => "isync()",
+        Opcode::Eieio => "eieio()",
+        _ => return None,
+    })
+}
+
+/// Writes an argument of the basic form.
+fn fmt_arg(f: &mut Formatter<'_>, arg: Argument) -> fmt::Result {
+    match arg {
+        Argument::CRBit(bit) => fmt_crbit(f, bit),
+        arg => write!(f, "{}", arg),
+    }
+}
+
+fn fmt_crbit(f: &mut Formatter<'_>, bit: CRBit) -> fmt::Result {
+    const NAMES: [&str; 4] = ["lt", "gt", "eq", "so"];
+    write!(f, "cr{}.{}", bit.0 >> 2, NAMES[bit.0 as usize & 3])
+}
+
+fn fmt_template(f: &mut Formatter<'_>, template: &str, args: &ParsedIns) -> fmt::Result {
+    let mut rest = template;
+    while let Some(start) = rest.find(['{', '}']) {
+        f.write_str(&rest[..start])?;
+        let brace = rest.as_bytes()[start];
+        rest = &rest[start + 1..];
+        // "{{" and "}}" are literal braces
+        if brace == b'}' || rest.starts_with('{') {
+            f.write_char(brace as char)?;
+            rest = rest.strip_prefix(brace as char).unwrap_or(rest);
+            continue;
+        }
+        let end = rest.find('}').unwrap_or(rest.len());
+        let (index, format) = rest[..end].split_once(':').unwrap_or((&rest[..end], ""));
+        rest = rest.get(end + 1..).unwrap_or("");
+        let arg = args.args[index.parse::<usize>().unwrap_or(0)];
+        match (format, arg) {
+            ("ps", Argument::FPR(FPR(n))) => write!(f, "ps{}", n)?,
+            ("hi", Argument::Uimm(Uimm(n))) => write!(f, "{:#x}", (n as u32) << 16)?,
+            ("+", Argument::Simm(Simm(n))) if n < 0 => write!(f, " - {:#x}", -(n as i32))?,
+            ("+", Argument::Simm(Simm(n))) => write!(f, " + {:#x}", n)?,
+            ("+", arg) => {
+                f.write_str(" + ")?;
+                fmt_arg(f, arg)?;
+            }
+            (_, arg) => fmt_arg(f, arg)?,
+        }
+    }
+    f.write_str(rest)
+}
+
+impl Pseudo {
+    fn fmt_condition(&self, f: &mut Formatter<'_>, cond: BranchCondition) -> fmt::Result {
+        f.write_str("if (")?;
+        match cond.ctr {
+            Some(CtrCondition::Zero) => f.write_str("--ctr == 0")?,
+            Some(CtrCondition::NonZero) => f.write_str("--ctr != 0")?,
+            None => {}
+        }
+        if let Some(cr) = cond.cr {
+            if cond.ctr.is_some() {
+                f.write_str(" && ")?;
+            }
+            if !cr.value {
+                f.write_str("!")?;
+            }
+            fmt_crbit(f, cr.bit)?;
+        }
+        f.write_str(") ")
+    }
+
+    fn fmt_flow(&self, f: &mut Formatter<'_>, flow: FlowControl) -> fmt::Result {
+        if let Some(cond) = flow.branch_condition().filter(|c| !c.always_taken()) {
+            self.fmt_condition(f, cond)?;
+        }
+        let dest = self.ins.branch_dest(self.addr);
+        match (flow, dest) {
+            (FlowControl::Branch { .. }, Some(dest)) => write!(f, "goto {:#x}", dest),
+            (FlowControl::Call { indirect: false, .. }, Some(dest)) => {
+                write!(f, "call {:#x}", dest)
+            }
+            (FlowControl::Call { .. }, _) if self.ins.op == Opcode::Bclr => f.write_str("call *lr"),
+            (FlowControl::Call { .. }, _) => f.write_str("call *ctr"),
+            (FlowControl::IndirectJump { .. }, _) => f.write_str("goto *ctr"),
+            (FlowControl::Return { .. }, _) => f.write_str("return"),
+            (FlowControl::Trap { condition, doubleword }, _) => {
+                self.fmt_trap(f, condition, doubleword)
+            }
+            (FlowControl::SystemCall, _) => f.write_str("syscall()"),
+            (FlowControl::ExceptionReturn, _) => f.write_str("return_from_interrupt()"),
+            _ => f.write_str("nop"),
+        }
+    }
+
+    fn fmt_trap(&self, f: &mut Formatter<'_>, to: TrapCondition, doubleword: bool) -> fmt::Result {
+        if to.always() {
+            return f.write_str("trap()");
+        } else if to.never() {
+            return f.write_str("nop");
+        }
+        let basic = self.ins.basic();
+        let (a, b) = (basic.args[1], basic.args[2]);
+        let (signed, unsigned) = if doubleword { ("s64", "u64") } else { ("s32", "u32") };
+        const CONDITIONS: [(u8, bool, &str); 5] = [
+            (TrapCondition::LT, true, "<"),
+            (TrapCondition::GT, true, ">"),
+            (TrapCondition::EQ, true, "=="),
+            (TrapCondition::LLT, false, "<"),
+            (TrapCondition::LGT, false, ">"),
+        ];
+        f.write_str("if (")?;
+        let mut first = true;
+        for (bit, is_signed, op) in CONDITIONS {
+            if to.0 & bit == 0 {
+                continue;
+            }
+            if !first {
+                f.write_str(" || ")?;
+            }
+            first = false;
+            let ty = if is_signed { signed } else { unsigned };
+            write!(f, "({ty}){a} {op} ")?;
+            match b {
+                Argument::Simm(_) => write!(f, "{b}")?,
+                _ => write!(f, "({ty}){b}")?,
+            }
+        }
+        f.write_str(") trap()")
+    }
+
+    /// Writes the effective address of a memory access.
+    fn fmt_address(&self, f: &mut Formatter<'_>, access: &MemoryAccess) -> fmt::Result {
+        let aligned = match access.alignment {
+            Alignment::Truncated(n) if n > 1 => Some(n),
+            _ => None,
+        };
+        let paren =
+            aligned.is_some() && access.base.is_some() && access.offset != AccessOffset::None;
+        if paren {
+            f.write_str("(")?;
+        }
+        match (access.base, access.offset) {
+            (Some(base), AccessOffset::None) => write!(f, "{}", base)?,
+            (Some(base), AccessOffset::Displacement(d)) if d < 0 => {
+                write!(f, "{} - {:#x}", base, -(d as i64))?
+            }
+            (Some(base), AccessOffset::Displacement(d)) => write!(f, "{} + {:#x}", base, d)?,
+            (Some(base), AccessOffset::Index(index)) => write!(f, "{} + {}", base, index)?,
+            (None, AccessOffset::None) => f.write_str("0")?,
+            (None, AccessOffset::Displacement(d)) => write!(f, "{:#x}", SignedHexLiteral(d))?,
+            (None, AccessOffset::Index(index)) => write!(f, "{}", index)?,
+        }
+        if paren {
+            f.write_str(")")?;
+        }
+        if let Some(n) = aligned {
+            write!(f, " & ~{:#x}", n - 1)?;
+        }
+        Ok(())
+    }
+
+    /// Writes the effective address as a pointer to `ty`.
+    fn fmt_pointer(
+        &self,
+        f: &mut Formatter<'_>,
+        ty: impl Display,
+        access: &MemoryAccess,
+    ) -> fmt::Result {
+        let simple = (access.base.is_none() || access.offset == AccessOffset::None)
+            && !matches!(access.alignment, Alignment::Truncated(n) if n > 1);
+        if simple {
+            write!(f, "({ty}*)")?;
+            self.fmt_address(f, access)
+        } else {
+            write!(f, "({ty}*)(")?;
+            self.fmt_address(f, access)?;
+            f.write_str(")")
+        }
+    }
+
+    fn fmt_memory(&self, f: &mut Formatter<'_>, access: &MemoryAccess) -> fmt::Result {
+        self.fmt_access(f, access)?;
+        if access.update {
+            // The base register is updated after the access
+            if let Some(base) = access.base {
+                write!(f, "; {} = ", base)?;
+                self.fmt_address(f, access)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn fmt_access(&self, f: &mut Formatter<'_>, access: &MemoryAccess) -> fmt::Result {
+        let load = access.kind == AccessKind::Load;
+        let reg = access.reg;
+        let size = access.size.unwrap_or(0);
+        match access.format {
+            AccessFormat::Integer | AccessFormat::Float | AccessFormat::Vector
+                if matches!(access.alignment, Alignment::Left | Alignment::Right) =>
+            {
+                let side = if access.alignment == Alignment::Left { "left" } else { "right" };
+                if load {
+                    write!(f, "{reg} = load_{side}(")?;
+                    self.fmt_address(f, access)?;
+                    f.write_str(")")
+                } else {
+                    write!(f, "store_{side}(")?;
+                    self.fmt_address(f, access)?;
+                    write!(f, ", {reg})")
+                }
+            }
+            AccessFormat::Integer | AccessFormat::Float | AccessFormat::Vector => {
+                let ty = match (access.format, size, access.signed) {
+                    (AccessFormat::Float, 4, _) => "f32",
+                    (AccessFormat::Float, _, _) => "f64",
+                    (AccessFormat::Vector, 16, _) => "vec128",
+                    (_, 1, false) => "u8",
+                    (_, 1, true) => "s8",
+                    (_, 2, false) => "u16",
+                    (_, 2, true) => "s16",
+                    (_, 4, false) => "u32",
+                    (_, 4, true) => "s32",
+                    (_, _, false) => "u64",
+                    (_, _, true) => "s64",
+                };
+                let swap = if access.byte_reversed { size * 8 } else { 0 };
+                if access.atomic && !load {
+                    f.write_str("cr0.eq = store_conditional(")?;
+                    self.fmt_pointer(f, ty, access)?;
+                    return write!(f, ", {reg})");
+                }
+                if load {
+                    write!(f, "{reg} = ")?;
+                    if swap != 0 {
+                        write!(f, "bswap{swap}(")?;
+                    }
+                    f.write_str("*")?;
+                    self.fmt_pointer(f, ty, access)?;
+                    if swap != 0 {
+                        f.write_str(")")?;
+                    }
+                    if access.atomic {
+                        f.write_str("; reserve(")?;
+                        self.fmt_address(f, access)?;
+                        f.write_str(")")?;
+                    }
+                    Ok(())
+                } else {
+                    f.write_str("*")?;
+                    self.fmt_pointer(f, ty, access)?;
+                    match (swap, access.format, reg) {
+                        (0, AccessFormat::Integer, Argument::FPR(_)) => {
+                            write!(f, " = bits32({reg})")
+                        }
+                        (0, _, _) => write!(f, " = {reg}"),
+                        _ => write!(f, " = bswap{swap}({reg})"),
+                    }
+                }
+            }
+            AccessFormat::Quantized { gqr, single } => {
+                let Argument::FPR(FPR(n)) = reg else { return Ok(()) };
+                if load {
+                    write!(f, "ps{n} = ")?;
+                    if single {
+                        f.write_str("{")?;
+                    }
+                    f.write_str("dequantize(")?;
+                    self.fmt_address(f, access)?;
+                    write!(f, ", {gqr})")?;
+                    if single {
+                        f.write_str(", 1.0}")?;
+                    }
+                    Ok(())
+                } else {
+                    f.write_str("quantize(")?;
+                    self.fmt_address(f, access)?;
+                    let half = if single { ".ps0" } else { "" };
+                    write!(f, ", ps{n}{half}, {gqr})")
+                }
+            }
+            AccessFormat::Multiple => {
+                let Argument::GPR(GPR(first)) = reg else { return Ok(()) };
+                let count = 32 - first as u32;
+                if load {
+                    write!(f, "r{first}..r31 = ")?;
+                    f.write_str("*")?;
+                    self.fmt_pointer(f, format_args!("u32[{count}]"), access)
+                } else {
+                    f.write_str("*")?;
+                    self.fmt_pointer(f, format_args!("u32[{count}]"), access)?;
+                    write!(f, " = r{first}..r31")
+                }
+            }
+            AccessFormat::String => {
+                let name = if load { "load_string" } else { "store_string" };
+                write!(f, "{name}({reg}, ")?;
+                self.fmt_address(f, access)?;
+                match access.size {
+                    Some(size) => write!(f, ", {size})"),
+                    None => f.write_str(", xer[25:31])"),
+                }
+            }
+            AccessFormat::Zero => {
+                f.write_str("memset(")?;
+                self.fmt_address(f, access)?;
+                write!(f, ", 0, {size})")
+            }
+        }
+    }
+
+    fn fmt_generic(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let defs = self.ins.defs();
+        let uses = self.ins.uses();
+        let basic = self.ins.basic();
+        let mut first = true;
+        for def in defs.iter().filter(|a| !matches!(a, Argument::None)) {
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+            fmt_arg(f, *def)?;
+        }
+        if !first {
+            f.write_str(" = ")?;
+        }
+        write!(f, "{}(", self.ins.op.mnemonic())?;
+        // Prefer the instruction's own arguments, which include immediates
+        let args: &[Argument] = if basic.args.iter().any(|a| !matches!(a, Argument::None)) {
+            &basic.args[defs.iter().filter(|a| !matches!(a, Argument::None)).count().min(1)..]
+        } else {
+            &uses
+        };
+        let mut first = true;
+        for arg in args.iter().filter(|a| !matches!(a, Argument::None)) {
+            if !first {
+                f.write_str(", ")?;
+            }
+            first = false;
+            fmt_arg(f, *arg)?;
+        }
+        f.write_str(")")
+    }
+
+    /// Writes the side effects of the Rc and OE modifiers.
+    fn fmt_modifiers(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let set = |id: FieldId| id.extract(self.ins.code) != 0;
+        let modifiers = self.ins.op.modifiers();
+        if modifiers.contains(&FieldId::OE) && set(FieldId::OE) {
+            f.write_str("; xer.ov = overflow")?;
+        }
+        let basic = self.ins.basic();
+        // andi., andis. and addic. always update cr0
+        let record = matches!(self.ins.op, Opcode::Andi_ | Opcode::Andis_ | Opcode::Addic_);
+        if record || (modifiers.contains(&FieldId::Rc) && set(FieldId::Rc)) {
+            match basic.args[0] {
+                Argument::FPR(_) => f.write_str("; cr1 = fpscr[0:3]")?,
+                dest => {
+                    f.write_str("; cr0 = cmp(")?;
+                    fmt_arg(f, dest)?;
+                    f.write_str(", 0)")?;
+                }
+            }
+        } else if (modifiers.contains(&FieldId::RcAV) && set(FieldId::RcAV))
+            || (modifiers.contains(&FieldId::Rc128) && set(FieldId::Rc128))
+        {
+            f.write_str("; cr6 = all_any(")?;
+            fmt_arg(f, basic.args[0])?;
+            f.write_str(")")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Pseudo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let ins = self.ins;
+        if ins.op == Opcode::Illegal {
+            return write!(f, "illegal({:#010x})", ins.code);
+        }
+        // nop (ori r0, r0, 0)
+        if ins.code == 0x60000000 {
+            return f.write_str("nop");
+        }
+        let flow = ins.flow_control();
+        if flow != FlowControl::Sequential {
+            return self.fmt_flow(f, flow);
+        }
+        if let Some(access) = ins.memory_access() {
+            return self.fmt_memory(f, &access);
+        }
+        if let Some(rotate) = ins.rotate_mask() {
+            write!(f, "{} = {}", rotate.dest, rotate)?;
+            return self.fmt_modifiers(f);
+        }
+        let basic = ins.basic();
+        match ins.op {
+            // li, lis
+            Opcode::Addi if ins.field_ra() == 0 => {
+                write!(f, "{} = {}", basic.args[0], basic.args[2])?
+            }
+            Opcode::Addis if ins.field_ra() == 0 => {
+                write!(f, "{} = {:#x}", basic.args[0], (ins.field_uimm() as u32) << 16)?
+            }
+            // mr
+            Opcode::Or if ins.field_rs() == ins.field_rb() => {
+                write!(f, "{} = {}", basic.args[0], basic.args[1])?
+            }
+            _ => match template(ins) {
+                Some(template) => fmt_template(f, template, &basic)?,
+                None => self.fmt_generic(f)?,
+            },
+        }
+        self.fmt_modifiers(f)
+    }
+}
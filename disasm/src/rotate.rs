@@ -0,0 +1,210 @@
+use crate::{Ins, Opcode, GPR};
+use core::fmt::{self, Display, Formatter};
+
+/// The semantics of a rotate-and-mask instruction. (`rlwinm`, `rldicl`, ...)
+///
+/// The result is `rotl(rS, shift) & mask`, or for the insert forms (`rlwimi`, `rldimi`),
+/// `(rotl(rS, shift) & mask) | (rA & !mask)`.
+///
+/// Displaying a rotate mask renders a pseudo-expression, using shifts where
+/// the rotation is equivalent to one:
+///
+/// ```
+/// use powerpc::{Extensions, Ins};
+///
+/// let ins = Ins::new(0x54831C38, Extensions::none()); // rlwinm r3, r4, 3, 16, 28
+/// assert_eq!(ins.rotate_mask().unwrap().to_string(), "(r4 << 3) & 0x0000fff8");
+/// ```
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct RotateMask {
+    /// The rotated register. (rS)
+    pub source: GPR,
+    /// The destination register. (rA)
+    pub dest: GPR,
+    /// The rotation amount.
+    pub shift: RotateAmount,
+    /// The mask applied to the rotated value.
+    pub mask: u64,
+    /// Whether this is a 64-bit rotation. (`rld*`)
+    pub doubleword: bool,
+    /// Whether the masked bits are inserted into rA. (`rlwimi`, `rldimi`)
+    pub insert: bool,
+}
+
+/// The rotation amount of a rotate-and-mask instruction.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum RotateAmount {
+    /// An immediate rotation. (SH)
+    Immediate(u8),
+    /// The low bits of a register. (rB)
+    Register(GPR),
+}
+
+/// Returns the 32-bit mask with bits `mb` through `me` set, wrapping around if `mb > me`.
+pub const fn mask32(mb: u8, me: u8) -> u32 {
+    let begin = u32::MAX >> (mb & 31);
+    let end = u32::MAX << (31 - (me & 31));
+    if mb <= me {
+        begin & end
+    } else {
+        begin | end
+    }
+}
+
+/// Returns the 64-bit mask with bits `mb` through `me` set, wrapping around if `mb > me`.
+pub const fn mask64(mb: u8, me: u8) -> u64 {
+    let begin = u64::MAX >> (mb & 63);
+    let end = u64::MAX << (63 - (me & 63));
+    if mb <= me {
+        begin & end
+    } else {
+        begin | end
+    }
+}
+
+impl RotateMask {
+    /// The width of the rotation in bits.
+    #[inline]
+    pub const fn bits(&self) -> u8 {
+        if self.doubleword {
+            64
+        } else {
+            32
+        }
+    }
+
+    /// The mask of all bits in the rotation width.
+    #[inline]
+    const fn width_mask(&self) -> u64 {
+        if self.doubleword {
+            u64::MAX
+        } else {
+            u32::MAX as u64
+        }
+    }
+
+    /// Returns `n` if the instruction is equivalent to `(rS << n) & mask`.
+    ///
+    /// This is the case when the bits rotated in from the left are masked out.
+    pub const fn as_shift_left(&self) -> Option<u8> {
+        match self.shift {
+            RotateAmount::Immediate(n) if n != 0 && self.mask & ((1u64 << n) - 1) == 0 => Some(n),
+            _ => None,
+        }
+    }
+
+    /// Returns `n` if the instruction is equivalent to `(rS >> n) & mask`.
+    ///
+    /// This is the case when the bits rotated in from the right are masked out.
+    pub const fn as_shift_right(&self) -> Option<u8> {
+        match self.shift {
+            RotateAmount::Immediate(sh) if sh != 0 => {
+                let n = self.bits() - sh;
+                if self.mask & !(self.width_mask() >> n) == 0 {
+                    Some(n)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Evaluates the instruction with the given register values.
+    ///
+    /// `rb` is only used for register rotations, and `ra` for insert forms.
+    /// 32-bit rotations only use and produce the low 32 bits.
+    pub const fn evaluate(&self, rs: u64, rb: u64, ra: u64) -> u64 {
+        let n = match self.shift {
+            RotateAmount::Immediate(n) => n as u32,
+            RotateAmount::Register(_) => (rb as u32) & (self.bits() as u32 - 1),
+        };
+        let rotated =
+            if self.doubleword { rs.rotate_left(n) } else { (rs as u32).rotate_left(n) as u64 };
+        let result = rotated & self.mask;
+        if self.insert {
+            result | (ra & !self.mask & self.width_mask())
+        } else {
+            result
+        }
+    }
+
+    fn fmt_mask(&self, f: &mut Formatter<'_>, mask: u64) -> fmt::Result {
+        if self.doubleword {
+            write!(f, "{:#018x}", mask)
+        } else {
+            write!(f, "{:#010x}", mask)
+        }
+    }
+
+    fn fmt_value(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let rs = self.source.0;
+        let (left, right) = (self.as_shift_left(), self.as_shift_right());
+        // The bits that can be set after the rotation or shift
+        let full_mask = match (left, right) {
+            (Some(n), _) => (self.width_mask() << n) & self.width_mask(),
+            (None, Some(n)) => self.width_mask() >> n,
+            _ => self.width_mask(),
+        };
+        let masked = self.mask != full_mask;
+        let rotate = if self.doubleword { "rotl64" } else { "rotl32" };
+        match (left, right, self.shift) {
+            (Some(n), _, _) if masked => write!(f, "(r{rs} << {n})")?,
+            (Some(n), _, _) => write!(f, "r{rs} << {n}")?,
+            (None, Some(n), _) if masked => write!(f, "(r{rs} >> {n})")?,
+            (None, Some(n), _) => write!(f, "r{rs} >> {n}")?,
+            (_, _, RotateAmount::Immediate(0)) => write!(f, "r{rs}")?,
+            (_, _, RotateAmount::Immediate(n)) => write!(f, "{rotate}(r{rs}, {n})")?,
+            (_, _, RotateAmount::Register(rb)) => write!(f, "{rotate}(r{rs}, r{})", rb.0)?,
+        }
+        if masked {
+            f.write_str(" & ")?;
+            self.fmt_mask(f, self.mask)?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for RotateMask {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.insert {
+            write!(f, "(r{} & ", self.dest.0)?;
+            self.fmt_mask(f, !self.mask & self.width_mask())?;
+            f.write_str(") | (")?;
+            self.fmt_value(f)?;
+            f.write_str(")")
+        } else {
+            self.fmt_value(f)
+        }
+    }
+}
+
+impl Ins {
+    /// Returns the semantics of a rotate-and-mask instruction.
+    pub fn rotate_mask(&self) -> Option<RotateMask> {
+        let sh = RotateAmount::Immediate(self.field_sh());
+        let sh64 = RotateAmount::Immediate(self.field_sh64());
+        let rb = RotateAmount::Register(GPR(self.field_rb()));
+        let mask = mask32(self.field_mb(), self.field_me()) as u64;
+        let (shift, mask, doubleword, insert) = match self.op {
+            Opcode::Rlwinm => (sh, mask, false, false),
+            Opcode::Rlwnm => (rb, mask, false, false),
+            Opcode::Rlwimi => (sh, mask, false, true),
+            Opcode::Rldicl => (sh64, mask64(self.field_mb64(), 63), true, false),
+            Opcode::Rldicr => (sh64, mask64(0, self.field_me64()), true, false),
+            Opcode::Rldic => (sh64, mask64(self.field_mb64(), 63 - self.field_sh64()), true, false),
+            Opcode::Rldimi => (sh64, mask64(self.field_mb64(), 63 - self.field_sh64()), true, true),
+            Opcode::Rldcl => (rb, mask64(self.field_mb64(), 63), true, false),
+            Opcode::Rldcr => (rb, mask64(0, self.field_me64()), true, false),
+            _ => return None,
+        };
+        Some(RotateMask {
+            source: GPR(self.field_rs()),
+            dest: GPR(self.field_ra()),
+            shift,
+            mask,
+            doubleword,
+            insert,
+        })
+    }
+}
@@ -0,0 +1,31 @@
+//! Helpers for tests covering every opcode, also used by the assembler tests.
+#![allow(dead_code)]
+
+use powerpc::{Extensions, Ins, Opcode};
+
+/// Returns every opcode, in order.
+pub fn opcodes() -> impl Iterator<Item = Opcode> {
+    (0..u16::MAX).map(Opcode::from).take_while(|&op| op != Opcode::Illegal)
+}
+
+/// Returns `count` encodings of every opcode, filling in the non-fixed bits with a
+/// simple xorshift PRNG.
+///
+/// Encodings decoded as another opcode are skipped, so some opcodes have fewer.
+pub fn encodings(count: usize, extensions: Extensions) -> impl Iterator<Item = Ins> {
+    let mut state = 0x12345678u32;
+    opcodes().flat_map(move |op| {
+        let mut encodings = Vec::with_capacity(count);
+        for _ in 0..count {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            let ins = Ins::new(op.pattern() | (state & !op.bitmask()), extensions);
+            // Skip encodings shadowed by another opcode
+            if ins.op == op {
+                encodings.push(ins);
+            }
+        }
+        encodings
+    })
+}
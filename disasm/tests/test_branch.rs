@@ -0,0 +1,154 @@
+use powerpc::{
+    BranchCondition, BranchHint, CRBit, CrCondition, CtrCondition, EncodeError, Extensions,
+    FlowControl, Ins, Opcode, TrapCondition,
+};
+
+mod common;
+
+const EXTENSIONS: Extensions = Extensions::from_bitmask(u32::MAX);
+
+fn condition(code: u32) -> BranchCondition {
+    Ins::new(code, EXTENSIONS).branch_condition().unwrap()
+}
+
+#[test]
+fn test_branch_condition_decode() {
+    // blr
+    assert_eq!(condition(0x4E800020), BranchCondition::ALWAYS);
+    assert!(condition(0x4E800020).always_taken());
+    // bne -0x10
+    assert_eq!(
+        condition(0x4082FFF0),
+        BranchCondition {
+            ctr: None,
+            cr: Some(CrCondition { bit: CRBit(2), value: false }),
+            hint: BranchHint::None,
+        }
+    );
+    // beq+ 0x8
+    assert_eq!(
+        condition(0x41A20008),
+        BranchCondition {
+            ctr: None,
+            cr: Some(CrCondition { bit: CRBit(2), value: true }),
+            hint: BranchHint::Reverse,
+        }
+    );
+    // bdnz -0x4
+    let cond = condition(0x4200FFFC);
+    assert_eq!(
+        cond,
+        BranchCondition { ctr: Some(CtrCondition::NonZero), cr: None, hint: BranchHint::None }
+    );
+    assert!(cond.uses_ctr());
+    assert!(cond.predict_taken(true));
+    // bdzf 4*cr1+gt, 0x10
+    assert_eq!(
+        condition(0x40450010),
+        BranchCondition {
+            ctr: Some(CtrCondition::Zero),
+            cr: Some(CrCondition { bit: CRBit(5), value: false }),
+            hint: BranchHint::None,
+        }
+    );
+    // bgtlr cr7 with at = 0b11
+    let cond = condition(0x4DFD0020);
+    assert_eq!(cond.hint, BranchHint::Taken);
+    assert!(cond.predict_taken(false));
+    assert_eq!(Ins::new(0x38600000, EXTENSIONS).branch_condition(), None);
+}
+
+#[test]
+fn test_branch_condition_round_trip() {
+    for bo in 0..32u8 {
+        let cond = BranchCondition::decode(bo, 7);
+        let (bo2, bi) = cond.encode().unwrap();
+        assert_eq!(BranchCondition::decode(bo2, bi), cond, "BO {bo}");
+        if let Some(inverted) = cond.invert() {
+            assert_eq!(inverted.invert(), Some(cond), "BO {bo}");
+            assert_eq!(inverted.predict_taken(true), !cond.predict_taken(true), "BO {bo}");
+        }
+    }
+    let cond = BranchCondition { hint: BranchHint::Taken, ..BranchCondition::ALWAYS };
+    assert_eq!(cond.encode(), None);
+}
+
+#[test]
+fn test_branch_condition_patch() {
+    let ins = Ins::new(0x4082FFF0, EXTENSIONS);
+    let cond = ins.branch_condition().unwrap().invert().unwrap();
+    let ins = ins.with_branch_condition(cond).unwrap();
+    assert_eq!(ins.simplified().to_string(), "beq- -0x10");
+
+    let ins = ins.with_branch_condition(BranchCondition::ALWAYS).unwrap();
+    assert_eq!(ins.simplified().to_string(), "bc 20, lt, -0x10");
+
+    let cond = BranchCondition {
+        ctr: Some(CtrCondition::NonZero),
+        cr: Some(CrCondition { bit: CRBit(2), value: true }),
+        hint: BranchHint::Taken,
+    };
+    assert_eq!(ins.with_branch_condition(cond), Err(EncodeError::InvalidBranchCondition));
+    assert!(Ins::new(0x48000100, EXTENSIONS).with_branch_condition(cond).is_err());
+}
+
+#[test]
+fn test_flow_control() {
+    let flow = |code| Ins::new(code, EXTENSIONS).flow_control();
+    let always = BranchCondition::ALWAYS;
+    assert_eq!(flow(0x38600000), FlowControl::Sequential); // li r3, 0x0
+    assert_eq!(flow(0x48000100), FlowControl::Branch { condition: always }); // b 0x100
+    assert_eq!(flow(0x4BE03C99), FlowControl::Call { condition: always, indirect: false }); // bl
+    assert_eq!(flow(0x4E800421), FlowControl::Call { condition: always, indirect: true }); // bctrl
+    assert_eq!(flow(0x4E800021), FlowControl::Call { condition: always, indirect: true }); // blrl
+    assert_eq!(flow(0x4E800420), FlowControl::IndirectJump { condition: always }); // bctr
+    assert_eq!(flow(0x4E800020), FlowControl::Return { condition: always }); // blr
+    assert_eq!(flow(0x44000002), FlowControl::SystemCall); // sc
+    assert_eq!(flow(0x4C000064), FlowControl::ExceptionReturn); // rfi
+    assert_eq!(flow(0x4C000024), FlowControl::ExceptionReturn); // rfid
+
+    // Conditional forms
+    let ret = flow(0x4D820020); // beqlr
+    assert!(matches!(ret, FlowControl::Return { .. }));
+    assert!(ret.is_conditional() && ret.falls_through());
+    let ret = flow(0x4E000020); // bdnzlr
+    assert!(matches!(ret, FlowControl::Return { .. }));
+    assert!(ret.branch_condition().unwrap().uses_ctr());
+    assert!(!flow(0x4E800020).falls_through());
+    assert!(flow(0x4082FFF0).is_conditional()); // bne -0x10
+
+    // Traps
+    let FlowControl::Trap { condition, doubleword: false } = flow(0x7FE00008) else { panic!() }; // trap
+    assert!(condition.always() && !condition.never());
+    assert!(!flow(0x7FE00008).falls_through());
+    let FlowControl::Trap { condition, doubleword: false } = flow(0x0C830000) else { panic!() }; // tweqi r3, 0x0
+    assert_eq!(condition, TrapCondition(TrapCondition::EQ));
+    assert!(condition.test(0, 0) && !condition.test(1, 0));
+    let FlowControl::Trap { condition, doubleword: true } = flow(0x7CA41888) else { panic!() }; // tdlge r4, r3
+    assert!(condition.test(-1, 0) && !condition.test(0, -1));
+    // tdlgti r3, 0x1
+    assert!(matches!(flow(0x0A030001), FlowControl::Trap { doubleword: true, .. }));
+}
+
+#[test]
+fn test_flow_control_all_opcodes() {
+    for op in common::opcodes() {
+        let ins = Ins::new(op.pattern(), EXTENSIONS);
+        let expected = matches!(
+            op,
+            Opcode::B
+                | Opcode::Bc
+                | Opcode::Bcctr
+                | Opcode::Bclr
+                | Opcode::Tw
+                | Opcode::Twi
+                | Opcode::Td
+                | Opcode::Tdi
+                | Opcode::Sc
+                | Opcode::Rfi
+                | Opcode::Rfid
+        );
+        assert_eq!(ins.flow_control() != FlowControl::Sequential, expected, "{op:?}");
+        assert_eq!(ins.flow_control().branch_condition().is_some(), ins.is_branch(), "{op:?}");
+    }
+}
@@ -1,4 +1,7 @@
-use powerpc::{Argument, Extensions, Ins, InsIter, Opcode, GPR};
+use powerpc::{
+    Argument, BitOwner, BitRange, BranchDest, Extensions, FieldId, Ins, InsIter, OpaqueU, Opcode,
+    Simm, GPR, SPR,
+};
 
 const EXTENSIONS: Extensions = Extensions::none();
 
@@ -34,7 +37,16 @@ fn test_ins_add() {
 fn test_ins_addc() {
     let ins = Ins::new(0x7c002014, EXTENSIONS);
     assert_eq!(ins.op, Opcode::Addc);
-    // assert_eq!(ins.fields(), vec![rD(GPR(0)), rA(GPR(0)), rB(GPR(4))]);
+    assert_eq!(
+        ins.fields().collect::<Vec<_>>(),
+        vec![
+            (FieldId::RD, Argument::GPR(GPR(0))),
+            (FieldId::RA, Argument::GPR(GPR(0))),
+            (FieldId::RB, Argument::GPR(GPR(4))),
+            (FieldId::OE, Argument::OpaqueU(OpaqueU(0))),
+            (FieldId::Rc, Argument::OpaqueU(OpaqueU(0))),
+        ]
+    );
     assert_asm!(ins, "addc r0, r0, r4");
     assert_asm!(0x7C432014, "addc r2, r3, r4");
     assert_asm!(0x7CE62815, "addc. r7, r6, r5");
@@ -42,14 +54,32 @@ fn test_ins_addc() {
     assert_asm!(0x7F9DF415, "addco. r28, r29, r30");
 }
 
+#[test]
+fn test_opcode_metadata() {
+    assert_eq!(Opcode::Add.mnemonic(), "add");
+    assert_eq!(Opcode::Add.description(), "Add");
+    assert_eq!(Opcode::Add.extension(), None);
+    assert_eq!(Opcode::Add.fields(), &[FieldId::RD, FieldId::RA, FieldId::RB]);
+    assert_eq!(Opcode::Add.modifiers(), &[FieldId::OE, FieldId::Rc]);
+    assert_eq!(Opcode::Add.bitmask(), 0xfc0003fe);
+    assert_eq!(Opcode::Add.pattern(), 0x7c000214);
+    assert_eq!(Opcode::Illegal.description(), "Illegal instruction");
+    assert_eq!(Opcode::Illegal.fields(), &[]);
+    assert_eq!(Opcode::Illegal.bitmask(), 0);
+}
+
 #[test]
 fn test_ins_addi() {
     let ins = Ins::new(0x38010140, EXTENSIONS);
     assert_eq!(ins.op, Opcode::Addi);
-    // assert_eq!(
-    //     ins.fields(),
-    //     vec![rD(GPR(0)), rA(GPR(1)), simm(Simm(0x140))]
-    // );
+    assert_eq!(
+        ins.fields().collect::<Vec<_>>(),
+        vec![
+            (FieldId::RD, Argument::GPR(GPR(0))),
+            (FieldId::RA, Argument::GPR(GPR(1))),
+            (FieldId::Simm, Argument::Simm(Simm(0x140))),
+        ]
+    );
     assert_eq!(
         ins.defs(),
         [Argument::GPR(GPR(0)), Argument::None, Argument::None, Argument::None, Argument::None]
@@ -133,6 +163,24 @@ fn test_ins_b() {
     assert_asm!(0x48000002, "ba 0x0");
 }
 
+#[test]
+fn test_fields_b() {
+    let ins = Ins::new(0x4BE03C99, EXTENSIONS);
+    assert_eq!(
+        ins.fields().collect::<Vec<_>>(),
+        vec![
+            (FieldId::LI, Argument::BranchDest(BranchDest(-0x1fc368))),
+            (FieldId::LK, Argument::OpaqueU(OpaqueU(1))),
+            (FieldId::AA, Argument::OpaqueU(OpaqueU(0))),
+        ]
+    );
+    assert!(FieldId::LK.is_modifier());
+    assert_eq!(FieldId::LK.bits(), &[BitRange::new(31, 32)]);
+    assert_eq!(FieldId::LI.extract(ins.code), 0xF80F26);
+    assert!(FieldId::LI.signed());
+    assert_eq!(FieldId::LI.shift_left(), 2);
+}
+
 #[test]
 fn test_ins_bc() {
     assert_asm!(0x40800008, "bge 0x8");
@@ -599,6 +647,20 @@ fn test_ins_mfspr() {
     assert_asm!(0x7C7782A6, "mfibatl r3, 3");
 }
 
+#[test]
+fn test_field_spr() {
+    let ins = Ins::new(0x7C70FAA6, EXTENSIONS);
+    assert_eq!(ins.op, Opcode::Mfspr);
+    assert_eq!(ins.field(FieldId::Spr), Argument::SPR(SPR(1008)));
+    assert_eq!(FieldId::Spr.name(), "spr");
+    assert_eq!(FieldId::Spr.description(), "Special Purpose Register");
+    assert_eq!(FieldId::Spr.bits(), &[BitRange::new(16, 21), BitRange::new(11, 16)]);
+    assert_eq!(FieldId::Spr.mask(), 0x001FF800);
+    assert_eq!(FieldId::Spr.num_bits(), 10);
+    assert_eq!(FieldId::Spr.extract(ins.code), ins.field_spr() as u32);
+    assert!(!FieldId::Spr.is_modifier());
+}
+
 #[test]
 fn test_ins_mfsr() {
     assert_asm!(0x7E0004A6, "mfsr r16, 0");
@@ -744,6 +806,8 @@ fn test_ins_rlwinm() {
     assert_asm!(0x54640FBC, "clrlslwi r4, r3, 31, 1");
     assert_asm!(0x54092DB4, "clrlslwi r9, r0, 27, 5");
     assert_asm!(0x54096226, "clrlslwi r9, r0, 20, 12");
+    // Wrap-around mask, not representable as clrlslwi
+    assert_asm!(0x5557C48E, "rlwinm r23, r10, 24, 18, 7");
 }
 
 #[test]
@@ -913,6 +977,7 @@ fn test_ins_subfze() {
 }
 
 #[test]
+#[allow(clippy::mixed_case_hex_literals)]
 fn test_ins_sync() {
     assert_basic!(0x7c0004ac, "sync 0");
     assert_basic!(0x7c2004ac, "sync 1");
@@ -972,3 +1037,55 @@ fn test_ins_iter() {
     assert_eq!(iter.next(), Some((4, Ins::new(0x7E1A02A6, EXTENSIONS))));
     assert_eq!(iter.next(), None);
 }
+
+#[test]
+fn test_explain() {
+    let ins = Ins::new(0x7C432214, EXTENSIONS);
+    let explanation = ins.explain();
+    assert_eq!(explanation.fixed_mask(), 0xfc0003fe);
+    assert_eq!(explanation.fixed_bits(), 0x7c000214);
+    assert_eq!(explanation.owner(0), BitOwner::Fixed);
+    assert_eq!(explanation.owner(6), BitOwner::Field(FieldId::RD));
+    assert_eq!(explanation.owner(31), BitOwner::Field(FieldId::Rc));
+    let fields = explanation.fields().collect::<Vec<_>>();
+    assert_eq!(fields.len(), 5);
+    assert_eq!(fields[1].id, FieldId::RA);
+    assert_eq!(fields[1].bits, &[BitRange::new(11, 16)]);
+    assert_eq!(fields[1].raw, 3);
+    assert_eq!(fields[1].argument, Argument::GPR(GPR(3)));
+    assert_eq!(
+        explanation.to_string(),
+        "011111 00010 00011 00100 0 100001010 0
+ 0..6   011111     fixed
+ 6..11  00010      rD = r2
+11..16  00011      rA = r3
+16..21  00100      rB = r4
+21      0          OE = 0
+22..31  100001010  fixed
+31      0          Rc = 0"
+    );
+}
+
+#[test]
+fn test_explain_split_field() {
+    let explanation = Ins::new(0x4086FECC, EXTENSIONS).explain();
+    let bd = explanation.fields().find(|f| f.id == FieldId::BD).unwrap();
+    assert_eq!(bd.raw, 0x3FB3);
+    assert_eq!(bd.argument, Argument::BranchDest(BranchDest(-0x134)));
+
+    let explanation = Ins::new(0x7C70FAA6, EXTENSIONS).explain();
+    let segments = explanation.segments().collect::<Vec<_>>();
+    assert_eq!(segments[2].range, BitRange::new(11, 21));
+    assert_eq!(segments[2].owner, BitOwner::Field(FieldId::Spr));
+    assert_eq!(segments[2].raw, 0b1000011111);
+}
+
+#[test]
+fn test_explain_illegal() {
+    let explanation = Ins::new(0, EXTENSIONS).explain();
+    assert_eq!(explanation.fields().count(), 0);
+    assert_eq!(
+        explanation.to_string(),
+        "00000000000000000000000000000000\n 0..32  00000000000000000000000000000000  unused"
+    );
+}
@@ -0,0 +1,217 @@
+use powerpc::{
+    Argument, BranchDest, CRBit, CRField, D3DType, EncodeError, Extensions, FieldId, Ins, Offset,
+    OpaqueU, Opcode, PackMask, Simm, Swizzle, Uimm, FPR, GPR, GQR, SPR, VR,
+};
+
+mod common;
+
+const EXTENSIONS: Extensions = Extensions::from_bitmask(u32::MAX);
+
+/// Returns the raw value of a decoded argument.
+fn arg_value(arg: Argument) -> i32 {
+    match arg {
+        Argument::None => 0,
+        Argument::GPR(GPR(v))
+        | Argument::FPR(FPR(v))
+        | Argument::SR(powerpc::SR(v))
+        | Argument::CRField(CRField(v))
+        | Argument::CRBit(CRBit(v))
+        | Argument::GQR(GQR(v))
+        | Argument::VR(VR(v))
+        | Argument::D3DType(D3DType(v))
+        | Argument::PackMask(PackMask(v))
+        | Argument::Swizzle(Swizzle(v)) => v as i32,
+        Argument::SPR(SPR(v)) | Argument::Uimm(Uimm(v)) | Argument::OpaqueU(OpaqueU(v)) => v as i32,
+        Argument::Simm(Simm(v)) | Argument::Offset(Offset(v)) => v as i32,
+        Argument::BranchDest(BranchDest(v)) => v,
+    }
+}
+
+#[test]
+fn test_build_addi() {
+    let ins = Ins::builder(Opcode::Addi).rd(GPR(5)).ra(GPR(0)).simm(Simm(0)).build().unwrap();
+    assert_eq!(ins, Ins::new(0x38A00000, EXTENSIONS));
+    let ins = Ins::builder(Opcode::Addi).rd(GPR(0)).ra(GPR(4)).simm(Simm(-0x7000)).build().unwrap();
+    assert_eq!(ins.code, 0x38049000);
+}
+
+#[test]
+fn test_build_modifiers() {
+    let ins =
+        Ins::builder(Opcode::Add).rd(GPR(28)).ra(GPR(29)).rb(GPR(30)).oe().rc().build().unwrap();
+    assert_eq!(ins.code, 0x7F9DF615);
+    assert_eq!(ins.simplified().to_string(), "addo. r28, r29, r30");
+
+    let ins = Ins::builder(Opcode::B).li(BranchDest(-0x1fc368)).lk().build().unwrap();
+    assert_eq!(ins.code, 0x4BE03C99);
+    let ins = Ins::builder(Opcode::B).li(BranchDest(0x60)).lk().aa().build().unwrap();
+    assert_eq!(ins.code, 0x48000063);
+}
+
+#[test]
+fn test_build_split_fields() {
+    let ins = Ins::builder(Opcode::Mfspr).rd(GPR(3)).spr(SPR(1008)).build().unwrap();
+    assert_eq!(ins.code, 0x7C70FAA6);
+    let ins = Ins::builder(Opcode::PsqL)
+        .frd(FPR(10))
+        .ps_offset(Offset(0x10))
+        .ra(GPR(3))
+        .ps_w(OpaqueU(0))
+        .ps_i(GQR(5))
+        .build()
+        .unwrap();
+    assert_eq!(ins.code, 0xE1435010);
+    let ins = Ins::builder(Opcode::Vaddfp128)
+        .vds128(VR(72))
+        .va128(VR(30))
+        .vb128(VR(102))
+        .build()
+        .unwrap();
+    assert_eq!(ins.code, 0x151E301B);
+}
+
+#[test]
+fn test_build_errors() {
+    assert_eq!(
+        Ins::builder(Opcode::Addi).rd(GPR(32)).ra(GPR(0)).simm(Simm(0)).build(),
+        Err(EncodeError::OutOfRange { field: FieldId::RD, value: 32, min: 0, max: 31 })
+    );
+    assert_eq!(
+        Ins::builder(Opcode::Addi).rd(GPR(3)).simm(Simm(0)).build(),
+        Err(EncodeError::MissingField { opcode: Opcode::Addi, field: FieldId::RA })
+    );
+    assert_eq!(
+        Ins::builder(Opcode::Addi).rd(GPR(3)).ra(GPR(0)).simm(Simm(0)).rc().build(),
+        Err(EncodeError::InvalidField { opcode: Opcode::Addi, field: FieldId::Rc })
+    );
+    assert_eq!(
+        Ins::builder(Opcode::Addi).frd(FPR(3)).build(),
+        Err(EncodeError::InvalidField { opcode: Opcode::Addi, field: FieldId::FrD })
+    );
+    assert_eq!(
+        Ins::builder(Opcode::B).li(BranchDest(0x6)).build(),
+        Err(EncodeError::Misaligned { field: FieldId::LI, value: 6, align: 4 })
+    );
+    assert_eq!(
+        Ins::builder(Opcode::Bc).bo(OpaqueU(20)).bi(CRBit(0)).bd(BranchDest(0x8000)).build(),
+        Err(EncodeError::OutOfRange {
+            field: FieldId::BD,
+            value: 0x8000,
+            min: -0x8000,
+            max: 0x7ffc
+        })
+    );
+    assert_eq!(Ins::builder(Opcode::Illegal).build(), Err(EncodeError::IllegalOpcode));
+}
+
+#[test]
+fn test_build_round_trip() {
+    // Rebuild every opcode from its decoded fields
+    for ins in common::encodings(64, EXTENSIONS) {
+        let mut builder = Ins::builder(ins.op);
+        for (id, value) in ins.fields() {
+            if id.is_modifier() {
+                // Some modifiers share a bit (e.g. BP and BNP), so only set them
+                if value == Argument::OpaqueU(OpaqueU(1)) {
+                    builder = builder.modifier(id, true);
+                }
+            } else {
+                builder = builder.field(id, arg_value(value));
+            }
+        }
+        assert_eq!(builder.build(), Ok(ins), "{:?} {:#010x}", ins.op, ins.code);
+    }
+}
+
+#[test]
+fn test_build_same_opcode() {
+    // Any field values and modifiers keep the opcode
+    for op in common::opcodes() {
+        let extensions = op.extension().map_or(Extensions::none(), Extensions::from_extension);
+        for pick in [|(min, _)| min, |(_, max)| max] {
+            let mut builder = Ins::builder(op);
+            for &id in op.fields() {
+                builder = builder.field(id, pick(id.value_range()));
+            }
+            for &id in op.modifiers() {
+                builder = builder.modifier(id, true);
+            }
+            let ins = builder.build().unwrap();
+            assert_eq!(Ins::new(ins.code, extensions).op, op, "{:#010x}", ins.code);
+        }
+    }
+}
+
+#[test]
+fn test_patch_fields() {
+    // Retarget a branch
+    let ins = Ins::new(0x4BE03C99, EXTENSIONS);
+    let ins = ins.with_li(BranchDest(0xa5c8)).unwrap();
+    assert_eq!(ins.code, 0x4800A5C9);
+    assert_eq!(ins.simplified().to_string(), "bl 0xa5c8");
+
+    // Change an immediate and a register
+    let ins = Ins::new(0x38010140, EXTENSIONS);
+    let ins = ins.with_simm(Simm(-8)).unwrap().with_rd(GPR(31)).unwrap();
+    assert_eq!(ins.simplified().to_string(), "subi r31, r1, 0x8");
+    assert_eq!(ins.op, Opcode::Addi);
+
+    // Raw field access
+    let ins = Ins::new(0x7C70FAA6, EXTENSIONS).with_field(FieldId::Spr, 8).unwrap();
+    assert_eq!(ins.simplified().to_string(), "mflr r3");
+}
+
+#[test]
+fn test_patch_modifiers() {
+    let mut ins = Ins::new(0x7C432214, EXTENSIONS);
+    ins.set_rc(true).unwrap();
+    ins.set_oe(true).unwrap();
+    assert_eq!(ins.simplified().to_string(), "addo. r2, r3, r4");
+    ins.set_rc(false).unwrap();
+    assert_eq!(ins.simplified().to_string(), "addo r2, r3, r4");
+
+    let mut ins = Ins::new(0x38010140, EXTENSIONS);
+    assert_eq!(
+        ins.set_rc(true),
+        Err(EncodeError::InvalidField { opcode: Opcode::Addi, field: FieldId::Rc })
+    );
+    assert_eq!(ins.code, 0x38010140);
+}
+
+#[test]
+fn test_patch_errors() {
+    let ins = Ins::new(0x4BE03C99, EXTENSIONS);
+    assert_eq!(
+        ins.with_li(BranchDest(0x2000000)),
+        Err(EncodeError::OutOfRange {
+            field: FieldId::LI,
+            value: 0x2000000,
+            min: -0x2000000,
+            max: 0x1fffffc
+        })
+    );
+    assert_eq!(
+        ins.with_li(BranchDest(0x2)),
+        Err(EncodeError::Misaligned { field: FieldId::LI, value: 2, align: 4 })
+    );
+
+    // Fields of other opcodes are rejected, even where they do not overlap fixed bits
+    let ins = Ins::new(0x7C432214, EXTENSIONS);
+    assert_eq!(
+        ins.with_sh64(OpaqueU(0x20)),
+        Err(EncodeError::InvalidField { opcode: Opcode::Add, field: FieldId::SH64 })
+    );
+    let ins = Ins::new(0x38630001, EXTENSIONS);
+    assert_eq!(
+        ins.with_frd(FPR(5)),
+        Err(EncodeError::InvalidField { opcode: Opcode::Addi, field: FieldId::FrD })
+    );
+    assert_eq!(
+        ins.with_li(BranchDest(0x40)),
+        Err(EncodeError::InvalidField { opcode: Opcode::Addi, field: FieldId::LI })
+    );
+    assert_eq!(
+        ins.with_field(FieldId::Spr, 8),
+        Err(EncodeError::InvalidField { opcode: Opcode::Addi, field: FieldId::Spr })
+    );
+}
@@ -0,0 +1,152 @@
+use powerpc::{
+    AccessKind, Extensions, GqrConfig, GqrTracker, Ins, Quantization, QuantizedAccess,
+    QuantizedType, GPR, GQR,
+};
+
+const EXTENSIONS: Extensions = Extensions::gekko_broadway();
+
+fn run(tracker: &mut GqrTracker, codes: &[u32]) {
+    for &code in codes {
+        tracker.step(Ins::new(code, EXTENSIONS));
+    }
+}
+
+fn annotate(tracker: &GqrTracker, code: u32) -> Option<String> {
+    tracker.annotate(Ins::new(code, EXTENSIONS)).map(|a| a.to_string())
+}
+
+#[test]
+fn test_gqr_config() {
+    let config = GqrConfig::from_bits(0x0807_0405);
+    assert_eq!(
+        config,
+        GqrConfig {
+            load: Quantization { ty: QuantizedType::S16, scale: 8 },
+            store: Quantization { ty: QuantizedType::U16, scale: 4 },
+        }
+    );
+    assert_eq!(config.to_bits(), 0x0807_0405);
+    assert_eq!(config.to_string(), "load s16 scaled by 2^-8, store u16 scaled by 2^4");
+
+    // Scales are 6-bit signed values
+    let config = GqrConfig::from_bits(0x3E06_2004);
+    assert_eq!(config.load, Quantization { ty: QuantizedType::S8, scale: -2 });
+    assert_eq!(config.store, Quantization { ty: QuantizedType::U8, scale: -32 });
+    assert_eq!(config.to_bits(), 0x3E06_2004);
+    assert_eq!(config.to_string(), "load s8 scaled by 2^2, store u8 scaled by 2^-32");
+
+    // Floats are not scaled, reserved types decode as floats, and reserved bits are ignored
+    let config = GqrConfig::from_bits(0xC105_C203);
+    assert_eq!(config.load, Quantization { ty: QuantizedType::U16, scale: 1 });
+    assert_eq!(config.store, Quantization { ty: QuantizedType::Float, scale: 2 });
+    assert_eq!(config.to_bits(), 0x0105_0200);
+    assert_eq!(config.to_string(), "load u16 scaled by 2^-1, store f32");
+    assert_eq!(GqrConfig::default().to_string(), "load f32, store f32");
+
+    assert_eq!(config.quantization(AccessKind::Load).exponent(AccessKind::Load), -1);
+    assert_eq!(config.quantization(AccessKind::Store).exponent(AccessKind::Store), 0);
+    for (ty, size) in [
+        (QuantizedType::Float, 4),
+        (QuantizedType::U8, 1),
+        (QuantizedType::U16, 2),
+        (QuantizedType::S8, 1),
+        (QuantizedType::S16, 2),
+    ] {
+        assert_eq!(ty.size(), size);
+        assert_eq!(QuantizedType::from_bits(ty.to_bits()), ty);
+    }
+}
+
+#[test]
+fn test_gqr_tracker() {
+    let mut tracker = GqrTracker::new();
+    run(
+        &mut tracker,
+        &[
+            0x3C600807, // lis r3, 0x807
+            0x38630807, // addi r3, r3, 0x807
+            0x7C72E3A6, // mtspr GQR2, r3
+            0x38800404, // li r4, 0x404
+            0x7C93E3A6, // mtspr GQR3, r4
+        ],
+    );
+    assert_eq!(tracker.gpr(GPR(3)), Some(0x0807_0807));
+    assert_eq!(tracker.gqr(GQR(2)), Some(GqrConfig::from_bits(0x0807_0807)));
+    assert_eq!(
+        annotate(&tracker, 0xE0232000).as_deref(), // psq_l f1, 0x0(r3), 0, qr2
+        Some("load 2 × s16 scaled by 2^-8")
+    );
+    assert_eq!(
+        annotate(&tracker, 0xF023B000).as_deref(), // psq_st f1, 0x0(r3), 1, qr3
+        Some("store 1 × u8 scaled by 2^4")
+    );
+    let access = tracker.annotate(Ins::new(0xE0232000, EXTENSIONS)).unwrap();
+    assert_eq!(
+        access,
+        QuantizedAccess {
+            kind: AccessKind::Load,
+            count: 2,
+            quantization: Quantization { ty: QuantizedType::S16, scale: 8 },
+        }
+    );
+    assert_eq!(access.size(), 4);
+
+    // Unknown GQRs and other instructions are not annotated
+    assert_eq!(annotate(&tracker, 0xE0230010), None); // psq_l f1, 0x10(r3), 0, qr0
+    assert_eq!(annotate(&tracker, 0xC0230000), None); // lfs f1, 0x0(r3)
+    tracker.set_gqr(GQR(0), Some(GqrConfig::default()));
+    assert_eq!(
+        annotate(&tracker, 0xE0230010).as_deref(), // psq_l f1, 0x10(r3), 0, qr0
+        Some("load 2 × f32")
+    );
+
+    // Values are followed through register moves
+    run(
+        &mut tracker,
+        &[
+            0x3CA03E04, // lis r5, 0x3e04
+            0x7CA62B78, // mr r6, r5
+            0x7CD4E3A6, // mtspr GQR4, r6
+            0x7CB1E3A6, // mtspr GQR1, r5
+        ],
+    );
+    assert_eq!(tracker.gqr(GQR(4)), Some(GqrConfig::from_bits(0x3E04_0000)));
+    assert_eq!(
+        annotate(&tracker, 0xE0231008).as_deref(), // psq_l f1, 0x8(r3), 0, qr1
+        Some("load 2 × u8 scaled by 2^2")
+    );
+
+    // Overwritten GPRs become unknown, and so do GQRs set from them
+    run(
+        &mut tracker,
+        &[
+            0x80810000, // lwz r4, 0x0(r1)
+            0x7C93E3A6, // mtspr GQR3, r4
+        ],
+    );
+    assert_eq!(tracker.gpr(GPR(4)), None);
+    assert_eq!(tracker.gqr(GQR(3)), None);
+    assert_eq!(tracker.gqr(GQR(2)), Some(GqrConfig::from_bits(0x0807_0807)));
+
+    // Calls clobber the volatile GPRs, and keep the GQRs
+    for call in [0x48000101, 0x4E800421, 0x4D820021] {
+        run(
+            &mut tracker,
+            &[
+                0x38000001, // li r0, 0x1
+                0x38A00002, // li r5, 0x2
+                0x39800003, // li r12, 0x3
+                0x39C00004, // li r14, 0x4
+                call,       // bl 0x100, bctrl, beqlrl
+            ],
+        );
+        assert_eq!(tracker.gpr(GPR(0)), None);
+        assert_eq!(tracker.gpr(GPR(5)), None);
+        assert_eq!(tracker.gpr(GPR(12)), None);
+        assert_eq!(tracker.gpr(GPR(14)), Some(4));
+        assert_eq!(tracker.gqr(GQR(2)), Some(GqrConfig::from_bits(0x0807_0807)));
+    }
+
+    tracker.reset();
+    assert_eq!(tracker, GqrTracker::new());
+}
@@ -0,0 +1,166 @@
+use powerpc::{
+    AccessFormat, AccessKind, AccessOffset, Alignment, Argument, Extensions, Ins, MemoryAccess,
+    FPR, GPR, GQR, VR,
+};
+
+mod common;
+
+const EXTENSIONS: Extensions = Extensions::from_bitmask(u32::MAX);
+
+fn access(code: u32) -> MemoryAccess {
+    Ins::new(code, EXTENSIONS).memory_access().unwrap()
+}
+
+#[test]
+fn test_memory_access_integer() {
+    assert_eq!(
+        access(0x8061FFF8),
+        MemoryAccess {
+            kind: AccessKind::Load,
+            format: AccessFormat::Integer,
+            reg: Argument::GPR(GPR(3)),
+            base: Some(GPR(1)),
+            offset: AccessOffset::Displacement(-8),
+            size: Some(4),
+            signed: false,
+            update: false,
+            alignment: Alignment::Any,
+            byte_reversed: false,
+            atomic: false,
+        }
+    ); // lwz r3, -0x8(r1)
+
+    let a = access(0x9421FFC0); // stwu r1, -0x40(r1)
+    assert_eq!((a.kind, a.update, a.base), (AccessKind::Store, true, Some(GPR(1))));
+    let a = access(0x7C6322AE); // lhax r3, r3, r4
+    assert_eq!((a.size, a.signed, a.offset), (Some(2), true, AccessOffset::Index(GPR(4))));
+    let a = access(0x7C60242C); // lwbrx r3, r0, r4
+    assert_eq!((a.base, a.byte_reversed), (None, true));
+    let a = access(0x7C001828); // lwarx r0, r0, r3
+    assert_eq!((a.atomic, a.alignment), (true, Alignment::Required(4)));
+    let a = access(0x7C00192D); // stwcx. r0, r0, r3
+    assert_eq!((a.kind, a.atomic), (AccessKind::Store, true));
+    let a = access(0xE8610010); // ld r3, 0x10(r1)
+    assert_eq!((a.size, a.offset), (Some(8), AccessOffset::Displacement(0x10)));
+    let a = access(0xE8610012); // lwa r3, 0x10(r1)
+    assert_eq!((a.size, a.signed), (Some(4), true));
+}
+
+#[test]
+fn test_memory_access_float() {
+    let a = access(0xC0230008); // lfs f1, 0x8(r3)
+    assert_eq!((a.format, a.reg, a.size), (AccessFormat::Float, Argument::FPR(FPR(1)), Some(4)));
+    let a = access(0xDC3F0010); // stfdu f1, 0x10(r31)
+    assert_eq!((a.kind, a.size, a.update), (AccessKind::Store, Some(8), true));
+    let a = access(0x7C2027AE); // stfiwx f1, r0, r4
+    assert_eq!((a.format, a.size), (AccessFormat::Integer, Some(4)));
+}
+
+#[test]
+fn test_memory_access_string_multiple() {
+    let a = access(0xBBA1000C); // lmw r29, 0xc(r1)
+    assert_eq!(
+        (a.format, a.reg, a.size),
+        (AccessFormat::Multiple, Argument::GPR(GPR(29)), Some(12))
+    );
+    let a = access(0x7CA404AA); // lswi r5, r4, 0
+    assert_eq!((a.format, a.size, a.offset), (AccessFormat::String, Some(32), AccessOffset::None));
+    let a = access(0x7CA435AA); // stswi r5, r4, 6
+    assert_eq!((a.kind, a.size), (AccessKind::Store, Some(6)));
+    assert_eq!(access(0x7CA4342A).size, None); // lswx r5, r4, r6
+}
+
+#[test]
+fn test_memory_access_paired_single() {
+    let a = access(0xE1435010); // psq_l f10, 0x10(r3), 0, qr5
+    assert_eq!(a.format, AccessFormat::Quantized { gqr: GQR(5), single: false });
+    assert_eq!((a.offset, a.size), (AccessOffset::Displacement(0x10), None));
+    let a = access(0x1021F74E); // psq_stux f1, r1, r30, 1, qr6
+    assert_eq!(a.format, AccessFormat::Quantized { gqr: GQR(6), single: true });
+    assert_eq!(
+        (a.kind, a.update, a.offset),
+        (AccessKind::Store, true, AccessOffset::Index(GPR(30)))
+    );
+}
+
+#[test]
+fn test_memory_access_vector() {
+    let a = access(0x7C0018CE); // lvx v0, r0, r3
+    assert_eq!(
+        (a.reg, a.size, a.alignment),
+        (Argument::VR(VR(0)), Some(16), Alignment::Truncated(16))
+    );
+    let a = access(0x7C642C0E); // lvlx v3, r4, r5
+    assert_eq!((a.size, a.alignment), (None, Alignment::Left));
+    let a = access(0x7C642D4E); // stvrx v3, r4, r5
+    assert_eq!((a.kind, a.alignment), (AccessKind::Store, Alignment::Right));
+    let a = access(0x106428CF); // lvx128 v99, r4, r5
+    assert_eq!((a.reg, a.alignment), (Argument::VR(VR(99)), Alignment::Truncated(16)));
+    let a = access(0x7C0307EC); // dcbz r3, r0
+    assert_eq!((a.format, a.size, a.reg), (AccessFormat::Zero, Some(32), Argument::None));
+    assert_eq!(Ins::new(0x7C64200C, EXTENSIONS).memory_access(), None); // lvsl v3, r4, r4
+    assert_eq!(Ins::new(0x7C0018AC, EXTENSIONS).memory_access(), None); // dcbf r0, r3
+}
+
+#[test]
+fn test_memory_access_all_opcodes() {
+    for op in common::opcodes() {
+        let ins = Ins::new(op.pattern(), EXTENSIONS);
+        if ins.op != op {
+            // Shadowed by another opcode
+            continue;
+        }
+        let name = op.mnemonic();
+        let expected = (name.starts_with('l') || name.starts_with("st") || name.starts_with("psq"))
+            && !name.starts_with("lvs")
+            || name.starts_with("dcbz")
+            || name.starts_with("ec");
+        assert_eq!(ins.memory_access().is_some(), expected, "{name}");
+        if let Some(access) = ins.memory_access() {
+            assert_eq!(
+                access.update,
+                name.trim_end_matches('x').ends_with('u') && !name.starts_with("lv"),
+                "{name}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_effective_address() {
+    let mut regs = [0u64; 32];
+    regs[0] = 0x1000;
+    regs[1] = 0x80400000;
+    regs[3] = 0x80001000;
+    regs[4] = 0x20;
+    regs[5] = 0x3;
+    regs[30] = 0xFFFFFFFFFFFFFFF0;
+    let ea = |code: u32| Ins::new(code, EXTENSIONS).effective_address(&regs);
+    assert_eq!(ea(0x8061FFF8), Some(0x803FFFF8)); // lwz r3, -0x8(r1)
+    assert_eq!(ea(0x80600010), Some(0x10)); // lwz r3, 0x10(r0): rA|0
+    assert_eq!(ea(0x7C6322AE), Some(0x80001020)); // lhax r3, r3, r4
+    assert_eq!(ea(0x7C60242C), Some(0x20)); // lwbrx r3, r0, r4: rA|0
+    assert_eq!(ea(0xE8610012), Some(0x80400010)); // lwa r3, 0x10(r1): DS-form
+    assert_eq!(ea(0xE1435FF8), Some(0x80000FF8)); // psq_l f10, -0x8(r3), 0, qr5
+    assert_eq!(ea(0x1021F74E), Some(0x803FFFF0)); // psq_stux f1, r1, r30, 1, qr6
+    assert_eq!(ea(0x106428CF), Some(0x23)); // lvx128 v99, r4, r5 (unaligned EA)
+    assert_eq!(ea(0x7CA404AA), Some(0x20)); // lswi r5, r4, 0
+    assert_eq!(ea(0x38600000), None); // li r3, 0x0
+
+    let regs32 = [0x100u32; 32];
+    assert_eq!(Ins::new(0x8061FFF8, EXTENSIONS).effective_address(&regs32), Some(0xF8));
+
+    // 32-bit addresses wrap around in both directions
+    let mut regs32 = [0u32; 32];
+    regs32[3] = 0x8;
+    regs32[5] = 0xFFFFFFF8;
+    let ea = |code: u32| Ins::new(code, EXTENSIONS).effective_address(&regs32);
+    assert_eq!(ea(0x8083FFF0), Some(0xFFFFFFF8)); // lwz r4, -0x10(r3)
+    assert_eq!(ea(0x80850010), Some(0x8)); // lwz r4, 0x10(r5)
+    assert_eq!(ea(0x7C85182E), Some(0x0)); // lwzx r4, r5, r3
+
+    // 64-bit addresses do not
+    regs[5] = 0xFFFFFFF8;
+    let ea = |code: u32| Ins::new(code, EXTENSIONS).effective_address(&regs);
+    assert_eq!(ea(0x80850010), Some(0x100000008)); // lwz r4, 0x10(r5)
+}
@@ -1,4 +1,4 @@
-use powerpc::{Argument, Extension, Extensions, Ins, Opcode, FPR, GPR};
+use powerpc::{Argument, Extension, Extensions, FieldId, Ins, OpaqueU, Opcode, FPR, GPR, GQR};
 
 const EXTENSIONS: Extensions = Extensions::from_extension(Extension::PairedSingles);
 
@@ -31,16 +31,16 @@ fn test_ins_psq_lu() {
 fn test_ins_psq_lx() {
     let ins = Ins::new(0x1000000C, EXTENSIONS);
     assert_eq!(ins.op, Opcode::PsqLx);
-    // assert_eq!(
-    //     ins.fields(),
-    //     vec![
-    //         frD(FPR(0)),
-    //         rA(GPR(0)),
-    //         rB(GPR(0)),
-    //         ps_WX(OpaqueU(0)),
-    //         ps_IX(GQR(0)),
-    //     ]
-    // );
+    assert_eq!(
+        ins.fields().collect::<Vec<_>>(),
+        vec![
+            (FieldId::FrD, Argument::FPR(FPR(0))),
+            (FieldId::RA, Argument::GPR(GPR(0))),
+            (FieldId::RB, Argument::GPR(GPR(0))),
+            (FieldId::PsWX, Argument::OpaqueU(OpaqueU(0))),
+            (FieldId::PsIX, Argument::GQR(GQR(0))),
+        ]
+    );
     assert_eq!(
         ins.defs(),
         [Argument::FPR(FPR(0)), Argument::None, Argument::None, Argument::None, Argument::None]
@@ -0,0 +1,142 @@
+use powerpc::{Extensions, Ins};
+
+mod common;
+
+const EXTENSIONS: Extensions = Extensions::from_bitmask(u32::MAX);
+
+macro_rules! assert_pseudo {
+    ($code:expr, $expected:expr) => {
+        assert_pseudo!($code, $expected, EXTENSIONS)
+    };
+    ($code:expr, $expected:expr, $extensions:expr) => {{
+        let ins = Ins::new($code, $extensions);
+        assert_eq!(ins.pseudo(0x80001000).to_string(), $expected);
+    }};
+}
+
+#[test]
+fn test_pseudo_integer() {
+    assert_pseudo!(0x38600001, "r3 = 0x1"); // li r3, 0x1
+    assert_pseudo!(0x3C608000, "r3 = 0x80000000"); // lis r3, 0x8000
+    assert_pseudo!(0x38A00000, "r5 = 0x0"); // li r5, 0x0
+    assert_pseudo!(0x38A30010, "r5 = r3 + 0x10"); // addi r5, r3, 0x10
+    assert_pseudo!(0x38A3FFF0, "r5 = r3 - 0x10"); // addi r5, r3, -0x10
+    assert_pseudo!(0x7C632214, "r3 = r3 + r4"); // add r3, r3, r4
+    assert_pseudo!(0x7C632215, "r3 = r3 + r4; cr0 = cmp(r3, 0)"); // add. r3, r3, r4
+    assert_pseudo!(0x7C632614, "r3 = r3 + r4; xer.ov = overflow"); // addo r3, r3, r4
+    assert_pseudo!(0x7C641850, "r3 = r3 - r4"); // subf r3, r4, r3
+    assert_pseudo!(0x7C832378, "r3 = r4"); // mr r3, r4
+    assert_pseudo!(0x60000000, "nop");
+    assert_pseudo!(0x7C6300D0, "r3 = -r3"); // neg r3, r3
+    assert_pseudo!(0x2C030000, "cr0 = cmp((s32)r3, 0x0)"); // cmpwi r3, 0x0
+    assert_pseudo!(0x7CA41040, "cr1 = cmp((u64)r4, (u64)r2)"); // cmpld cr1, r4, r2
+    assert_pseudo!(0x7C0802A6, "r0 = LR"); // mflr r0
+    assert_pseudo!(0x7C0803A6, "LR = r0"); // mtlr r0
+    assert_pseudo!(0x4C221A02, "cr0.gt = cr0.eq & cr0.so"); // crand gt, eq, un
+    assert_pseudo!(0x708300FF, "r3 = r4 & 0xff; cr0 = cmp(r3, 0)"); // andi. r3, r4, 0xff
+    assert_pseudo!(0x74838000, "r3 = r4 & 0x80000000; cr0 = cmp(r3, 0)"); // andis. r3, r4, 0x8000
+                                                                          // addic. r3, r4, 0x1
+    assert_pseudo!(0x34640001, "r3 = r4 + 0x1; xer.ca = carry; cr0 = cmp(r3, 0)");
+}
+
+#[test]
+fn test_pseudo_rotate() {
+    assert_pseudo!(0x54631838, "r3 = r3 << 3"); // slwi r3, r3, 3
+    assert_pseudo!(0x54831C38, "r3 = (r4 << 3) & 0x0000fff8"); // rlwinm r3, r4, 3, 16, 28
+    assert_pseudo!(0x54831C39, "r3 = (r4 << 3) & 0x0000fff8; cr0 = cmp(r3, 0)");
+}
+
+#[test]
+fn test_pseudo_memory() {
+    assert_pseudo!(0x80610008, "r3 = *(u32*)(r1 + 0x8)"); // lwz r3, 0x8(r1)
+    assert_pseudo!(0x8061FFF8, "r3 = *(u32*)(r1 - 0x8)"); // lwz r3, -0x8(r1)
+    assert_pseudo!(0xA8640002, "r3 = *(s16*)(r4 + 0x2)"); // lha r3, 0x2(r4)
+    assert_pseudo!(0x7C63202E, "r3 = *(u32*)(r3 + r4)"); // lwzx r3, r3, r4
+    assert_pseudo!(0x7C642C2C, "r3 = bswap32(*(u32*)(r4 + r5))"); // lwbrx r3, r4, r5
+    assert_pseudo!(0xC0230004, "f1 = *(f32*)(r3 + 0x4)"); // lfs f1, 0x4(r3)
+    assert_pseudo!(0xBF61000C, "*(u32[5]*)(r1 + 0xc) = r27..r31"); // stmw r27, 0xc(r1)
+    assert_pseudo!(0x7C632028, "r3 = *(u32*)(r3 + r4); reserve(r3 + r4)"); // lwarx r3, r3, r4
+    assert_pseudo!(0x7C60212D, "cr0.eq = store_conditional((u32*)r4, r3)"); // stwcx. r3, r0, r4
+    assert_pseudo!(0x7C001FEC, "memset(r3 & ~0x1f, 0, 32)"); // dcbz r0, r3
+    assert_pseudo!(0x7C0018CE, "v0 = *(vec128*)(r3 & ~0xf)"); // lvx v0, r0, r3
+    assert_pseudo!(0x7C0428CE, "v0 = *(vec128*)((r4 + r5) & ~0xf)"); // lvx v0, r4, r5
+
+    // The base register is updated after the store, so the old value is stored
+    assert_pseudo!(0x9421FFF0, "*(u32*)(r1 - 0x10) = r1; r1 = r1 - 0x10"); // stwu r1, -0x10(r1)
+}
+
+#[test]
+fn test_pseudo_branch() {
+    assert_pseudo!(0x48000010, "goto 0x80001010"); // b 0x10
+    assert_pseudo!(0x48000011, "call 0x80001010"); // bl 0x10
+    assert_pseudo!(0x41820010, "if (cr0.eq) goto 0x80001010"); // beq 0x10
+    assert_pseudo!(0x4084FFF0, "if (!cr1.lt) goto 0x80000ff0"); // bge cr1, -0x10
+    assert_pseudo!(0x4200FFF8, "if (--ctr != 0) goto 0x80000ff8"); // bdnz -0x8
+    assert_pseudo!(0x4E800020, "return"); // blr
+    assert_pseudo!(0x4D820020, "if (cr0.eq) return"); // beqlr
+    assert_pseudo!(0x4E800420, "goto *ctr"); // bctr
+    assert_pseudo!(0x4E800421, "call *ctr"); // bctrl
+    assert_pseudo!(0x4E800021, "call *lr"); // blrl
+    assert_pseudo!(0x7FE00008, "trap()"); // trap
+    assert_pseudo!(0x0C830010, "if ((s32)r3 == 0x10) trap()"); // tweqi r3, 0x10
+    assert_pseudo!(0x7E432008, "if ((s32)r3 < (s32)r4 || (u32)r3 < (u32)r4) trap()");
+    assert_pseudo!(0x44000002, "syscall()"); // sc
+    assert_pseudo!(0x4C000064, "return_from_interrupt()"); // rfi
+}
+
+#[test]
+fn test_pseudo_float() {
+    assert_pseudo!(0xFC2220FA, "f1 = f2 * f3 + f4"); // fmadd f1, f2, f3, f4
+    assert_pseudo!(0xEC22182A, "f1 = (f32)(f2 + f3)"); // fadds f1, f2, f3
+    assert_pseudo!(0xFC201018, "f1 = (f32)f2"); // frsp f1, f2
+    assert_pseudo!(0xFC22182B, "f1 = f2 + f3; cr1 = fpscr[0:3]"); // fadd. f1, f2, f3
+}
+
+#[test]
+fn test_pseudo_paired_single() {
+    // psq_l f10, 0x10(r3), 1, qr5
+    assert_pseudo!(0xE143D010, "ps10 = {dequantize(r3 + 0x10, qr5), 1.0}");
+    assert_pseudo!(0xE1435010, "ps10 = dequantize(r3 + 0x10, qr5)"); // psq_l f10, 0x10(r3), 0, qr5
+    assert_pseudo!(0xF0230010, "quantize(r3 + 0x10, ps1, qr0)"); // psq_st f1, 0x10(r3), 0, qr0
+    assert_pseudo!(0x10221028, "ps1 = ps2 - ps2"); // ps_sub f1, f2, f2
+    assert_pseudo!(0x10411C20, "ps2 = {ps1.ps0, ps3.ps0}"); // ps_merge00 f2, f1, f3
+    assert_pseudo!(0x102220FA, "ps1 = ps2 * ps3 + ps4"); // ps_madd f1, f2, f3, f4
+}
+
+#[test]
+fn test_pseudo_vector() {
+    let ext = Extensions::xenon();
+    // vcmpequh. v0, v2, v1
+    assert_pseudo!(0x10020C46, "v0 = (u16x8)v2 == (u16x8)v1; cr6 = all_any(v0)", ext);
+    // vcmpgtsh. v5, v5, v20
+    assert_pseudo!(0x10A5A746, "v5 = (s16x8)v5 > (s16x8)v20; cr6 = all_any(v5)", ext);
+    // vsel v5, v5, v20, v22
+    assert_pseudo!(0x10A5A5AA, "v5 = (v5 & ~v22) | (v20 & v22)", ext);
+    // vmrghb v5, v5, v20
+    assert_pseudo!(0x10A5A00C, "v5 = {v5.u8[0], v20.u8[0], ..., v5.u8[7], v20.u8[7]}", ext);
+    // vmrghw128 v37, v101, v52
+    assert_pseudo!(0x18A5A725, "v37 = {v101.u32[0], v52.u32[0], v101.u32[1], v52.u32[1]}", ext);
+    // vperm128 v36, v2, v3, v3
+    assert_pseudo!(0x148218C4, "v36 = (v2:v3).u8[v3.u8 & 0x1f]", ext);
+    assert_pseudo!(0x10A5A1AC, "v5 = (v5:v20).u8[6..6 + 16]", ext); // vsldoi v5, v5, v20, 6
+    assert_pseudo!(0x10020880, "v0 = (u32x4)v2 + (u32x4)v1", ext); // vadduwm v0, v2, v1
+    assert_pseudo!(0x10020B00, "v0 = sat((s8x16)v2 + (s8x16)v1)", ext); // vaddsbs v0, v2, v1
+    assert_pseudo!(0x10020D80, "v0 = carry((u32x4)v2 - (u32x4)v1)", ext); // vsubcuw v0, v2, v1
+    assert_pseudo!(0x10020C84, "v0 = v2 | v1", ext); // vor v0, v2, v1
+    assert_pseudo!(0x10A5A20C, "v5 = splat(v20.u8[0x5])", ext); // vspltb v5, v20, 0x5
+    assert_pseudo!(0x10BF030C, "v5 = splat((s8)-0x1)", ext); // vspltisb v5, -0x1
+    assert_pseudo!(0x18A5A775, "v37 = splat((s32)0x5)", ext); // vspltisw128 v37, v52, 0x5
+
+    // Instructions without a template are shown as intrinsics
+    assert_pseudo!(0x10020C02, "v0 = vavgub(v2, v1)", ext); // vavgub v0, v2, v1
+}
+
+#[test]
+fn test_pseudo_all_opcodes() {
+    for op in common::opcodes() {
+        let ins = Ins::new(op.pattern(), EXTENSIONS);
+        let text = ins.pseudo(0x80001000).to_string();
+        assert!(!text.is_empty(), "{}", op.mnemonic());
+        assert!(!text.contains('{') || text.contains("= {"), "{}: {}", op.mnemonic(), text);
+    }
+}
@@ -0,0 +1,83 @@
+use powerpc::{mask32, mask64, Extensions, Ins, RotateAmount, RotateMask, GPR};
+
+const EXTENSIONS: Extensions = Extensions::from_bitmask(u32::MAX);
+
+fn rotate(code: u32) -> RotateMask {
+    Ins::new(code, EXTENSIONS).rotate_mask().unwrap()
+}
+
+#[test]
+fn test_masks() {
+    assert_eq!(mask32(0, 31), 0xFFFFFFFF);
+    assert_eq!(mask32(16, 28), 0x0000FFF8);
+    assert_eq!(mask32(31, 0), 0x80000001);
+    assert_eq!(mask32(24, 7), 0xFF0000FF);
+    assert_eq!(mask64(0, 63), u64::MAX);
+    assert_eq!(mask64(32, 63), 0xFFFFFFFF);
+    assert_eq!(mask64(63, 0), 0x8000000000000001);
+}
+
+#[test]
+fn test_rotate_mask_32() {
+    let r = rotate(0x54831C38); // rlwinm r3, r4, 3, 16, 28
+    assert_eq!(
+        r,
+        RotateMask {
+            source: GPR(4),
+            dest: GPR(3),
+            shift: RotateAmount::Immediate(3),
+            mask: 0x0000FFF8,
+            doubleword: false,
+            insert: false,
+        }
+    );
+    assert_eq!(r.as_shift_left(), Some(3));
+    assert_eq!(r.evaluate(0x12345678, 0, 0), 0xB3C0);
+
+    assert_eq!(rotate(0x5483103A).to_string(), "r4 << 2"); // slwi r3, r4, 2
+    assert_eq!(rotate(0x5483F0BE).to_string(), "r4 >> 2"); // srwi r3, r4, 2
+    assert_eq!(rotate(0x5483063E).to_string(), "r4 & 0x000000ff"); // clrlwi r3, r4, 24
+    assert_eq!(rotate(0x5557C48E).to_string(), "rotl32(r10, 24) & 0xff003fff"); // rlwinm r23, r10, 24, 18, 7
+    assert_eq!(rotate(0x5483403E).to_string(), "rotl32(r4, 8)"); // rotlwi r3, r4, 8
+    assert_eq!(rotate(0x5C83283E).to_string(), "rotl32(r4, r5)"); // rotlw r3, r4, r5
+    let r = rotate(0x5083442E); // rlwimi r3, r4, 8, 16, 23
+    assert_eq!(r.to_string(), "(r3 & 0xffff00ff) | ((r4 << 8) & 0x0000ff00)");
+    assert_eq!(r.evaluate(0x12345678, 0, 0xAAAAAAAA), 0xAAAA78AA);
+    assert_eq!(Ins::new(0x38600000, EXTENSIONS).rotate_mask(), None);
+}
+
+#[test]
+fn test_rotate_mask_64() {
+    let r = rotate(0x78C50020); // rldicl r5, r6, 0, 32
+    assert_eq!((r.mask, r.doubleword), (0xFFFFFFFF, true));
+    assert_eq!(r.to_string(), "r6 & 0x00000000ffffffff");
+    let r = rotate(0x78C51928); // rldic r5, r6, 3, 36
+    assert_eq!(r.mask, 0x0FFFFFF8);
+    assert_eq!(r.to_string(), "(r6 << 3) & 0x000000000ffffff8");
+    assert_eq!(r.evaluate(u64::MAX, 0, 0), 0x0FFFFFF8);
+    let r = rotate(0x780336D0); // rldcl r3, r0, r6, 27
+    assert_eq!(r.shift, RotateAmount::Register(GPR(6)));
+    assert_eq!(r.evaluate(1, 63, 0), 0x8000000000000000 & r.mask);
+}
+
+#[test]
+fn test_rotate_mask_shifts() {
+    // The shift simplifications must agree with the rotation for every rlwinm
+    for sh in 0..32u32 {
+        for mb in 0..32u32 {
+            for me in 0..32u32 {
+                let code = 0x54830000 | (sh << 11) | (mb << 6) | (me << 1);
+                let r = rotate(code);
+                for value in [0xFFFFFFFFu64, 0x12345678, 0x80000001] {
+                    let result = r.evaluate(value, 0, 0);
+                    if let Some(n) = r.as_shift_left() {
+                        assert_eq!(((value << n) & r.mask), result, "{code:#010x}");
+                    }
+                    if let Some(n) = r.as_shift_right() {
+                        assert_eq!((value >> n) & r.mask, result, "{code:#010x}");
+                    }
+                }
+            }
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use powerpc::{Extension, Extensions, Ins};
+use powerpc::{Extension, Extensions, FieldId, Ins, Opcode};
 
 const EXTENSIONS: Extensions = Extensions::from_extension(Extension::Vmx128);
 
@@ -18,6 +18,18 @@ fn test_vmx_enables_altivec() {
     assert!(extensions.contains(Extension::AltiVec));
 }
 
+#[test]
+fn test_vmx_opcode_metadata() {
+    assert_eq!(Opcode::Vpkd3d128.extension(), Some(Extension::Vmx128));
+    assert_eq!(Opcode::Vperm.extension(), Some(Extension::AltiVec));
+    assert_eq!(
+        Opcode::Vpkd3d128.fields(),
+        &[FieldId::VDS128, FieldId::VB128, FieldId::D3DType, FieldId::VMASK, FieldId::Zimm]
+    );
+    assert_eq!(Opcode::Vcmpeqfp128.modifiers(), &[FieldId::Rc128]);
+    assert_eq!(FieldId::Rc128.description(), "Record Bit (VMX128)");
+}
+
 #[test]
 fn test_extensions_remove_vmx() {
     let mut extensions = Extensions::from_extension(Extension::Vmx128);
@@ -258,12 +270,19 @@ fn test_vmx_vperm128() {
 
 #[test]
 fn test_vmx_vpermwi128() {
-    assert_asm!(0x19C342DE, "vpermwi128 v110, v72, 99");
+    assert_asm!(0x19C342DE, "vpermwi128 v110, v72, yzxw");
+    assert_asm!(0x189B121F, "vpermwi128 v100, v98, xyzw");
+    assert_asm!(0x188413DF, "vpermwi128 v100, v98, wzyx");
 }
 
 #[test]
 fn test_vmx_vpkd3d128() {
-    assert_asm!(0x1935DEDC, "vpkd3d128 v105, v27, 5, 1, 3");
+    assert_asm!(0x1935DEDC, "vpkd3d128 v105, v27, FLOAT16_4, VPACK_32, 3");
+    assert_asm!(0x1889161F, "vpkd3d128 v100, v98, NORMPACKED32, VPACK_32, 0");
+    assert_asm!(0x189316DF, "vpkd3d128 v100, v98, NORMSHORT4, VPACK_64HI, 3");
+    // Reserved values are printed as numbers
+    assert_asm!(0x189D161F, "vpkd3d128 v100, v98, 7, VPACK_32, 0");
+    assert_asm!(0x1880161F, "vpkd3d128 v100, v98, D3DCOLOR, 0, 0");
 }
 
 #[test]
@@ -77,7 +77,7 @@ pub fn gen_asm(isa: &Isa, max_args: usize) -> Result<TokenStream> {
         });
     }
 
-    for (mnemonic, modifiers) in mnemonic_map.iter().flat_map(|(_, mnemonics)| {
+    for (mnemonic, modifiers) in mnemonic_map.values().flat_map(|mnemonics| {
         let mnemonic = mnemonics.first().unwrap();
         let modifiers = match mnemonic {
             OpcodeOrMnemonic::Opcode(opcode) => &opcode.modifiers,
@@ -88,17 +88,26 @@ pub fn gen_asm(isa: &Isa, max_args: usize) -> Result<TokenStream> {
         };
         modifiers_iter(modifiers, isa).filter(|m| modifiers_valid(m)).map(move |m| (mnemonic, m))
     }) {
+        let branch = match branch_range(&mnemonic_map[mnemonic.name()], isa)? {
+            Some((min, max)) => format!("Some(({:#x}, {:#x}))", SignedHexLiteral(min), max),
+            None => "None".to_string(),
+        };
         let suffix = modifiers.iter().map(|m| m.suffix).collect::<String>();
         let mut pattern = 0;
         for modifier in &modifiers {
             pattern |= modifier.mask();
         }
         let name = format!("{}{}", mnemonic.name(), suffix);
-        func_map.entry(name, format!("(gen_{}, {:#x})", to_ident(mnemonic.name()), pattern));
+        func_map.entry(
+            name,
+            format!("(gen_{}, {:#x}, {})", to_ident(mnemonic.name()), pattern, branch),
+        );
     }
 
     let func_map = syn::parse_str::<TokenStream>(&func_map.build().to_string())?;
     let max_args = Literal::usize_unsuffixed(max_args);
+    let Some(aa) = isa.find_modifier("AA") else { bail!("Missing AA modifier") };
+    let aa_mask = HexLiteral(aa.mask());
     Ok(quote! {
         #![allow(unused)]
         #![cfg_attr(rustfmt, rustfmt_skip)]
@@ -107,29 +116,87 @@ pub fn gen_asm(isa: &Isa, max_args: usize) -> Result<TokenStream> {
         pub type Arguments = [Argument; #max_args];
         #functions
         type MnemonicFn = fn(&Arguments, u32) -> Result<u32, ArgumentError>;
-        static MNEMONIC_MAP: phf::Map<&'static str, (MnemonicFn, u32)> = #func_map;
+        #[comment = " Displacement range of the branch target, if any."]
+        type BranchRange = Option<(i32, i32)>;
+        static MNEMONIC_MAP: phf::Map<&'static str, (MnemonicFn, u32, BranchRange)> = #func_map;
         pub fn assemble(mnemonic: &str, args: &Arguments) -> Result<u32, ArgumentError> {
-            if let Some(&(fn_ptr, modifiers)) = MNEMONIC_MAP.get(mnemonic) {
+            if let Some(&(fn_ptr, modifiers, _)) = MNEMONIC_MAP.get(mnemonic) {
                 fn_ptr(args, modifiers)
             } else {
                 Err(ArgumentError::UnknownMnemonic)
             }
         }
+        #[doc = " Assembles an instruction located at `address`."]
+        #[doc = ""]
+        #[doc = " Branch targets are absolute addresses, and are converted to the"]
+        #[doc = " displacement encoded by the instruction. (Unless it is an absolute branch)"]
+        pub fn assemble_at(address: u32, mnemonic: &str, args: &Arguments) -> Result<u32, ArgumentError> {
+            if let Some(&(fn_ptr, modifiers, branch)) = MNEMONIC_MAP.get(mnemonic) {
+                let mut args = *args;
+                if let Some((min, max)) = branch {
+                    let absolute = modifiers & #aa_mask != 0;
+                    resolve_branch(&mut args, address, absolute, min, max)?;
+                }
+                fn_ptr(&args, modifiers)
+            } else {
+                Err(ArgumentError::UnknownMnemonic)
+            }
+        }
     })
 }
 
+/// Returns the displacement range of the branch target argument, if any.
+///
+/// The branch target must be the last argument of every form of the mnemonic.
+fn branch_range(mnemonics: &[OpcodeOrMnemonic], isa: &Isa) -> Result<Option<(i32, i32)>> {
+    let mut result = None;
+    for mnemonic in mnemonics {
+        let args = match mnemonic {
+            OpcodeOrMnemonic::Opcode(opcode) => &opcode.args,
+            OpcodeOrMnemonic::Mnemonic(mnemonic) => &mnemonic.args,
+        };
+        for (i, arg) in args.iter().enumerate() {
+            let Some(field) = isa.find_field(arg) else { bail!("Unknown field {}", arg) };
+            if field.arg.as_deref() != Some("BranchDest") {
+                continue;
+            }
+            if i != args.len() - 1 {
+                bail!("Branch target {} of {} is not the last argument", arg, mnemonic.name());
+            }
+            let Some(bits) = &field.bits else { bail!("Field {} has no bits", field.name) };
+            let max_value = 1i32 << (bits.len() - 1 + field.shift_left);
+            let range = (-max_value, max_value - (1 << field.shift_left));
+            if result.is_some_and(|r| r != range) {
+                bail!("Mismatched branch targets for {}", mnemonic.name());
+            }
+            result = Some(range);
+        }
+    }
+    Ok(result)
+}
+
 fn gen_parse_field(field: &Field, i: usize) -> Result<(TokenStream, bool)> {
+    gen_parse_field_as(field, field, i)
+}
+
+/// Parses argument `i` using the range of `field`, checking it against the kind of `arg`.
+fn gen_parse_field_as(field: &Field, arg: &Field, i: usize) -> Result<(TokenStream, bool)> {
     let Some(bits) = &field.bits else { bail!("Field {} has no bits", field.name) };
     let i = Literal::usize_unsuffixed(i);
+    let kind = match arg.arg.as_deref() {
+        None | Some("Simm" | "Uimm" | "Offset" | "BranchDest" | "OpaqueU") => "Immediate",
+        Some(kind) => kind,
+    };
+    let kind = format_ident!("{}", kind);
     Ok(if field.signed {
         let max_value = 1 << (bits.len() - 1 + field.shift_left);
         let min_value = SignedHexLiteral(-max_value);
         let max_value = SignedHexLiteral(max_value);
-        (quote! { parse_signed(args, #i, #min_value, #max_value)? }, true)
+        (quote! { parse_signed(args, #i, #min_value, #max_value, ArgKind::#kind)? }, true)
     } else {
         let min_value = HexLiteral(0);
         let max_value = HexLiteral(bits.max_value() << field.shift_left);
-        (quote! { parse_unsigned(args, #i, #min_value, #max_value)? }, false)
+        (quote! { parse_unsigned(args, #i, #min_value, #max_value, ArgKind::#kind)? }, false)
     })
 }
 
@@ -304,7 +371,7 @@ fn gen_argument(
             let arg_n = args.iter().position(|a| a == &f.name).with_context(|| {
                 format!("Field {} references unknown argument {}", field.name, f.name)
             })?;
-            let (parse, signed) = gen_parse_field(field, arg_n)?;
+            let (parse, signed) = gen_parse_field_as(field, f, arg_n)?;
             any_signed |= signed;
             Ok(parse)
         })?;
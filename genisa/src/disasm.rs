@@ -1,7 +1,7 @@
 use crate::{
     condition::{parse_conditions, replace_fields},
     ident,
-    isa::{modifiers_iter, modifiers_valid, HexLiteral, Isa, Opcode},
+    isa::{modifiers_iter, modifiers_valid, to_ident, to_variant, HexLiteral, Isa, Opcode},
 };
 use anyhow::{bail, ensure, Result};
 use proc_macro2::{Ident, Literal, TokenStream};
@@ -59,6 +59,8 @@ pub fn gen_disasm(isa: &Isa, max_args: usize) -> Result<TokenStream> {
     let mut opcode_patterns = TokenStream::new();
     let mut opcode_enum = TokenStream::new();
     let mut opcode_names = TokenStream::new();
+    let mut opcode_descriptions = TokenStream::new();
+    let mut opcode_extensions = TokenStream::new();
     for (idx, opcode) in sorted_ops.iter().enumerate() {
         let bitmask = HexLiteral(opcode.mask(isa));
         let pattern = HexLiteral(opcode.pattern);
@@ -69,8 +71,10 @@ pub fn gen_disasm(isa: &Isa, max_args: usize) -> Result<TokenStream> {
             isa.extensions.iter().find(|(_, e)| e.opcodes.iter().any(|o| o.name == opcode.name));
         let initializer = if let Some((id, _)) = extension {
             let ident = format_ident!("{id}");
+            opcode_extensions.extend(quote! { Some(Extension::#ident), });
             quote! { OpcodePattern::extension(#bitmask, #pattern, Extension::#ident) }
         } else {
+            opcode_extensions.extend(quote! { None, });
             quote! { OpcodePattern::base(#bitmask, #pattern) }
         };
         opcode_patterns.extend(quote! {
@@ -78,6 +82,8 @@ pub fn gen_disasm(isa: &Isa, max_args: usize) -> Result<TokenStream> {
             #initializer,
         });
         opcode_names.extend(quote! { #name, });
+        let desc = &opcode.desc;
+        opcode_descriptions.extend(quote! { #desc, });
         let doc = opcode.doc();
         let variant = opcode.variant();
         opcode_enum.extend(quote! {
@@ -192,6 +198,141 @@ pub fn gen_disasm(isa: &Isa, max_args: usize) -> Result<TokenStream> {
         });
     }
 
+    // Generate field identifiers, their metadata and the generic accessor
+    let mut field_ids = Vec::<(String, Ident)>::new();
+    let mut field_id_enum = TokenStream::new();
+    let mut field_infos = TokenStream::new();
+    let mut field_match = TokenStream::new();
+    let mut builder_setters = TokenStream::new();
+    let mut ins_setters = TokenStream::new();
+    for field in &isa.fields {
+        let Some(bits) = &field.bits else {
+            continue;
+        };
+        if field.name.ends_with(".nz") {
+            continue;
+        }
+        let variant = format_ident!("{}", to_variant(&field.name));
+        let ranges = bits.iter().map(|r| {
+            let start = Literal::u8_unsuffixed(r.start());
+            let end = Literal::u8_unsuffixed(r.end());
+            quote! { BitRange::new(#start, #end) }
+        });
+        let name = &field.name;
+        let desc = &field.desc;
+        let signed = field.signed;
+        let shift_left = Literal::u8_unsuffixed(field.shift_left);
+        field_infos.extend(quote! {
+            FieldInfo {
+                name: #name,
+                desc: #desc,
+                bits: &[#(#ranges),*],
+                signed: #signed,
+                shift_left: #shift_left,
+                modifier: false,
+            },
+        });
+        let arg = gen_argument(&field.name, isa, None)?;
+        field_match.extend(quote! { FieldId::#variant => #arg, });
+        if let Some(arg) = &field.arg {
+            let setter = format_ident!("{}", to_ident(&field.name));
+            // BranchDest is the only argument type that already holds an i32
+            let value = if arg == "BranchDest" {
+                quote! { value.0 }
+            } else {
+                quote! { i32::from(value.0) }
+            };
+            let arg = format_ident!("{}", arg);
+            let doc = field.doc();
+            builder_setters.extend(quote! {
+                #[doc = #doc]
+                #[inline]
+                pub fn #setter(self, value: #arg) -> Self {
+                    self.field(FieldId::#variant, #value)
+                }
+            });
+            let with_setter = format_ident!("with_{}", to_ident(&field.name));
+            ins_setters.extend(quote! {
+                #[doc = #doc]
+                #[inline]
+                pub fn #with_setter(self, value: #arg) -> Result<Self, EncodeError> {
+                    self.with_field(FieldId::#variant, #value)
+                }
+            });
+        }
+        field_ids.push((field.name.clone(), variant));
+    }
+    for modifier in &isa.modifiers {
+        let variant = format_ident!("{}", to_variant(&modifier.name));
+        let name = &modifier.name;
+        let desc = &modifier.desc;
+        let start = Literal::u8_unsuffixed(modifier.bit);
+        let end = Literal::u8_unsuffixed(modifier.bit + 1);
+        field_infos.extend(quote! {
+            FieldInfo {
+                name: #name,
+                desc: #desc,
+                bits: &[BitRange::new(#start, #end)],
+                signed: false,
+                shift_left: 0,
+                modifier: true,
+            },
+        });
+        field_match.extend(quote! {
+            FieldId::#variant => Argument::OpaqueU(OpaqueU(ins.#modifier() as u16)),
+        });
+        let setter = format_ident!("{}", to_ident(&modifier.name));
+        let doc = modifier.doc();
+        builder_setters.extend(quote! {
+            #[doc = #doc]
+            #[inline]
+            pub fn #setter(self) -> Self {
+                self.modifier(FieldId::#variant, true)
+            }
+        });
+        let set_setter = format_ident!("set_{}", to_ident(&modifier.name));
+        ins_setters.extend(quote! {
+            #[doc = #doc]
+            #[inline]
+            pub fn #set_setter(&mut self, value: bool) -> Result<(), EncodeError> {
+                self.set_modifier(FieldId::#variant, value)
+            }
+        });
+        field_ids.push((modifier.name.clone(), variant));
+    }
+    for (idx, (name, variant)) in field_ids.iter().enumerate() {
+        if field_ids[..idx].iter().any(|(_, v)| v == variant) {
+            bail!("Duplicate field identifier {} (from {})", variant, name);
+        }
+        let doc = isa
+            .find_field(name)
+            .map(|f| f.doc())
+            .or_else(|| isa.find_modifier(name).map(|m| m.doc()))
+            .unwrap();
+        let idx = Literal::u8_unsuffixed(idx as u8);
+        field_id_enum.extend(quote! {
+            #[doc = #doc]
+            #variant = #idx,
+        });
+    }
+    let find_field_id = |name: &str| -> Result<&Ident> {
+        match field_ids.iter().find(|(n, _)| n == name) {
+            Some((_, variant)) => Ok(variant),
+            None => bail!("Unknown field {}", name),
+        }
+    };
+
+    // Generate the field and modifier lists for each opcode
+    let mut opcode_args = TokenStream::new();
+    let mut opcode_modifiers = TokenStream::new();
+    for opcode in &sorted_ops {
+        let args = opcode.args.iter().map(|a| find_field_id(a)).collect::<Result<Vec<_>>>()?;
+        let modifiers =
+            opcode.modifiers.iter().map(|m| find_field_id(m)).collect::<Result<Vec<_>>>()?;
+        opcode_args.extend(quote! { &[#(FieldId::#args),*], });
+        opcode_modifiers.extend(quote! { &[#(FieldId::#modifiers),*], });
+    }
+
     // Generate simplified mnemonics
     let mut mnemonic_functions = TokenStream::new();
     let mut basic_functions_ref = TokenStream::new();
@@ -420,12 +561,16 @@ pub fn gen_disasm(isa: &Isa, max_args: usize) -> Result<TokenStream> {
 
     let entries_count = Literal::usize_unsuffixed(entries.len());
     let opcode_count = Literal::usize_unsuffixed(sorted_ops.len());
+    let field_count = Literal::usize_unsuffixed(field_ids.len());
     let max_args = Literal::usize_unsuffixed(max_args);
     Ok(quote! {
         #![allow(unused)]
         #![cfg_attr(rustfmt, rustfmt_skip)]
         #[comment = " Code generated by powerpc-genisa. DO NOT EDIT."]
-        use crate::disasm::*;
+        use crate::{
+            disasm::*,
+            encode::{EncodeError, InsBuilder},
+        };
         #extensions
         #[doc = " The entry table allows us to quickly find the range of possible opcodes for a"]
         #[doc = " given 6-bit prefix. 2*64 bytes should fit in a cache line (or two)."]
@@ -448,6 +593,10 @@ pub fn gen_disasm(isa: &Isa, max_args: usize) -> Result<TokenStream> {
         static OPCODE_PATTERNS: [OpcodePattern; #opcode_count] = [#opcode_patterns];
         #[doc = " The name of each opcode."]
         static OPCODE_NAMES: [&str; #opcode_count] = [#opcode_names];
+        #[doc = " The description of each opcode."]
+        static OPCODE_DESCRIPTIONS: [&str; #opcode_count] = [#opcode_descriptions];
+        #[doc = " The extension each opcode belongs to, if any."]
+        static OPCODE_EXTENSIONS: [Option<Extension>; #opcode_count] = [#opcode_extensions];
 
         #[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
         #[repr(u16)]
@@ -463,6 +612,36 @@ pub fn gen_disasm(isa: &Isa, max_args: usize) -> Result<TokenStream> {
                 OPCODE_NAMES.get(self as usize).copied().unwrap_or("<illegal>")
             }
 
+            #[doc = " A description of the opcode."]
+            pub fn description(self) -> &'static str {
+                OPCODE_DESCRIPTIONS.get(self as usize).copied().unwrap_or("Illegal instruction")
+            }
+
+            #[doc = " The extension the opcode belongs to, or `None` for the base instruction set."]
+            pub fn extension(self) -> Option<Extension> {
+                OPCODE_EXTENSIONS.get(self as usize).copied().flatten()
+            }
+
+            #[doc = " The arguments of the opcode, in assembly order."]
+            pub fn fields(self) -> &'static [FieldId] {
+                OPCODE_ARGS.get(self as usize).copied().unwrap_or(&[])
+            }
+
+            #[doc = " The modifiers the opcode accepts."]
+            pub fn modifiers(self) -> &'static [FieldId] {
+                OPCODE_MODIFIERS.get(self as usize).copied().unwrap_or(&[])
+            }
+
+            #[doc = " The mask of the fixed bits of the opcode."]
+            pub fn bitmask(self) -> u32 {
+                OPCODE_PATTERNS.get(self as usize).map_or(0, |op| op.bitmask)
+            }
+
+            #[doc = " The value of the fixed bits of the opcode."]
+            pub fn pattern(self) -> u32 {
+                OPCODE_PATTERNS.get(self as usize).map_or(0, |op| op.pattern)
+            }
+
             pub fn detect(code: u32, extensions: Extensions) -> Self {
                 let entry = OPCODE_ENTRIES[(code >> 26) as usize];
                 for i in entry.0..entry.1 {
@@ -495,7 +674,34 @@ pub fn gen_disasm(isa: &Isa, max_args: usize) -> Result<TokenStream> {
 
         impl Ins {
             #ins_fields
+
+            #ins_setters
+
+            #[doc = " Returns the value of the given field or modifier."]
+            pub fn field(&self, id: FieldId) -> Argument {
+                let ins = self;
+                match id {
+                    #field_match
+                }
+            }
+        }
+
+        impl InsBuilder {
+            #builder_setters
+        }
+
+        #[derive(Copy, Clone, Debug, PartialEq, Eq)]
+        #[repr(u8)]
+        #[non_exhaustive]
+        pub enum FieldId {
+            #field_id_enum
         }
+        #[doc = " The metadata for each field and modifier."]
+        pub(crate) static FIELD_INFO: [FieldInfo; #field_count] = [#field_infos];
+        #[doc = " The arguments of each opcode."]
+        static OPCODE_ARGS: [&[FieldId]; #opcode_count] = [#opcode_args];
+        #[doc = " The modifiers of each opcode."]
+        static OPCODE_MODIFIERS: [&[FieldId]; #opcode_count] = [#opcode_modifiers];
 
         pub type Arguments = [Argument; #max_args];
         pub const EMPTY_ARGS: Arguments = [#none_args];
@@ -0,0 +1,99 @@
+use crate::{Cpu, Exception};
+use powerpc::{BranchCondition, CrCondition, CtrCondition, Ins, Opcode, TrapCondition};
+
+impl Cpu {
+    /// Tests a branch condition, decrementing CTR if required.
+    fn test_condition(&mut self, cond: BranchCondition, decrement: bool) -> bool {
+        let ctr_ok = match cond.ctr {
+            Some(ctr) if decrement => {
+                self.ctr = self.trunc(self.ctr.wrapping_sub(1));
+                (self.ctr == 0) == (ctr == CtrCondition::Zero)
+            }
+            _ => true,
+        };
+        let cr_ok = match cond.cr {
+            Some(CrCondition { bit, value }) => self.cr_bit(bit) == value,
+            None => true,
+        };
+        ctr_ok && cr_ok
+    }
+
+    /// Executes a branch, trap or system instruction.
+    ///
+    /// Returns the address of the next instruction, or `None` if the instruction
+    /// is not handled by this unit.
+    pub(crate) fn execute_branch(&mut self, ins: Ins) -> Result<Option<u64>, Exception> {
+        let pc = self.pc;
+        let next = self.trunc(pc.wrapping_add(4));
+        let target = match ins.op {
+            Opcode::B => {
+                let offset = ins.field_li() as i64 as u64;
+                if ins.field_aa() {
+                    offset
+                } else {
+                    pc.wrapping_add(offset)
+                }
+            }
+            Opcode::Bc => {
+                let cond = BranchCondition::decode(ins.field_bo(), ins.field_bi());
+                if !self.test_condition(cond, true) {
+                    next
+                } else if ins.field_aa() {
+                    ins.field_bd() as i64 as u64
+                } else {
+                    pc.wrapping_add(ins.field_bd() as i64 as u64)
+                }
+            }
+            Opcode::Bclr => {
+                let cond = BranchCondition::decode(ins.field_bo(), ins.field_bi());
+                let lr = self.lr & !3;
+                if self.test_condition(cond, true) {
+                    lr
+                } else {
+                    next
+                }
+            }
+            Opcode::Bcctr => {
+                // Decrementing CTR is an invalid form of bcctr, so only CR is tested
+                let cond = BranchCondition::decode(ins.field_bo(), ins.field_bi());
+                let ctr = self.ctr & !3;
+                if self.test_condition(cond, false) {
+                    ctr
+                } else {
+                    next
+                }
+            }
+            Opcode::Tw | Opcode::Twi | Opcode::Td | Opcode::Tdi => {
+                let a = self.gpr[ins.field_ra() as usize];
+                let b = match ins.op {
+                    Opcode::Tw | Opcode::Td => self.gpr[ins.field_rb() as usize],
+                    _ => ins.field_simm() as i64 as u64,
+                };
+                // Sign-extending the low words preserves both signed and unsigned ordering
+                let (a, b) = match ins.op {
+                    Opcode::Tw | Opcode::Twi => (a as i32 as i64, b as i32 as i64),
+                    _ => (a as i64, b as i64),
+                };
+                if TrapCondition(ins.field_to()).test(a, b) {
+                    return Err(Exception::Trap { pc });
+                }
+                next
+            }
+            Opcode::Sc => {
+                self.pc = next;
+                return Err(Exception::SystemCall { pc });
+            }
+            Opcode::Rfi | Opcode::Rfid => {
+                // SRR1 and SRR0
+                self.msr = self.spr[27];
+                self.spr[26] & !3
+            }
+            _ => return Ok(None),
+        };
+        if matches!(ins.op, Opcode::B | Opcode::Bc | Opcode::Bclr | Opcode::Bcctr) && ins.field_lk()
+        {
+            self.lr = next;
+        }
+        Ok(Some(self.trunc(target)))
+    }
+}
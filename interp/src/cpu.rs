@@ -0,0 +1,309 @@
+use crate::memory::{Memory, MemoryFault};
+use core::fmt::{self, Display, Formatter};
+use powerpc::{CRBit, Extensions, Ins, Opcode, RegisterReader, GPR};
+
+/// The register state of a PowerPC processor.
+///
+/// By default, the processor runs in 32-bit mode: GPRs, LR, CTR and effective addresses
+/// are truncated to 32 bits, and CR0, CA and OV are computed from the low 32 bits of
+/// results. Set [Cpu::mode64] to execute 64-bit code.
+///
+/// ```
+/// use powerpc::Extensions;
+/// use powerpc_interp::{Cpu, Ram};
+///
+/// let mut ram = Ram { base: 0x80000000, data: [0u8; 0x100] };
+/// ram.data[..8].copy_from_slice(&[
+///     0x7C, 0x63, 0x22, 0x14, // add r3, r3, r4
+///     0x4E, 0x80, 0x00, 0x20, // blr
+/// ]);
+/// let mut cpu = Cpu::new(Extensions::gekko_broadway());
+/// cpu.gpr[3] = 2;
+/// cpu.gpr[4] = 3;
+/// cpu.call(&mut ram, 0x80000000, 100).unwrap();
+/// assert_eq!(cpu.gpr[3], 5);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Cpu {
+    /// General-purpose registers.
+    pub gpr: [u64; 32],
+    /// Floating-point registers, or the first slot of paired singles.
+    pub fpr: [f64; 32],
+    /// The second slot of paired singles.
+    pub ps1: [f64; 32],
+    /// Condition register.
+    pub cr: u32,
+    /// Fixed-point exception register. (See [Cpu::XER_SO], [Cpu::XER_OV], [Cpu::XER_CA])
+    pub xer: u32,
+    /// Floating-point status and control register. (See [Cpu::FPSCR_FX] and following)
+    pub fpscr: u32,
+    /// Vector registers. Element 0 is the most significant. VMX128 adds v32-v127.
+    pub vr: [u128; 128],
+    /// Vector status and control register. (See [Cpu::VSCR_NJ], [Cpu::VSCR_SAT])
+    pub vscr: u32,
+    /// Link register.
+    pub lr: u64,
+    /// Count register.
+    pub ctr: u64,
+    /// Address of the next instruction to execute.
+    pub pc: u64,
+    /// Machine state register.
+    pub msr: u64,
+    /// Segment registers.
+    pub sr: [u32; 16],
+    /// Special-purpose registers not stored elsewhere, indexed by number.
+    pub spr: [u64; 1024],
+    /// Time base, incremented after each executed instruction.
+    pub tb: u64,
+    /// Whether the processor is in 64-bit mode. (MSR\[SF\])
+    pub mode64: bool,
+    /// The extensions used to decode instructions.
+    pub extensions: Extensions,
+    /// The address reserved by `lwarx` or `ldarx`.
+    pub reservation: Option<u64>,
+}
+
+/// An exception raised while executing an instruction.
+///
+/// Unless noted otherwise, [Cpu::pc] is left pointing at the instruction that raised it.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Exception {
+    /// The instruction is not a valid PowerPC instruction.
+    IllegalInstruction { pc: u64, code: u32 },
+    /// The instruction is valid, but not supported by the interpreter.
+    Unimplemented { pc: u64, ins: Ins },
+    /// A memory access failed.
+    MemoryFault { pc: u64, addr: u64 },
+    /// A memory access was not aligned as required.
+    Alignment { pc: u64, addr: u64 },
+    /// A trap instruction's condition was met.
+    Trap { pc: u64 },
+    /// A system call (`sc`). [Cpu::pc] points to the next instruction.
+    SystemCall { pc: u64 },
+    /// The maximum number of steps was reached.
+    StepLimit { pc: u64 },
+}
+
+impl Display for Exception {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Exception::IllegalInstruction { pc, code } => {
+                write!(f, "illegal instruction {code:#010x} at {pc:#x}")
+            }
+            Exception::Unimplemented { pc, ins } => {
+                write!(f, "unimplemented instruction {} at {pc:#x}", ins.op.mnemonic())
+            }
+            Exception::MemoryFault { pc, addr } => {
+                write!(f, "memory fault accessing {addr:#x} at {pc:#x}")
+            }
+            Exception::Alignment { pc, addr } => {
+                write!(f, "misaligned access to {addr:#x} at {pc:#x}")
+            }
+            Exception::Trap { pc } => write!(f, "trap at {pc:#x}"),
+            Exception::SystemCall { pc } => write!(f, "system call at {pc:#x}"),
+            Exception::StepLimit { pc } => write!(f, "step limit reached at {pc:#x}"),
+        }
+    }
+}
+
+impl RegisterReader for Cpu {
+    fn gpr(&self, reg: GPR) -> u64 {
+        self.gpr[reg.0 as usize]
+    }
+
+    fn mode64(&self) -> bool {
+        self.mode64
+    }
+}
+
+impl Cpu {
+    /// Summary overflow.
+    pub const XER_SO: u32 = 0x8000_0000;
+    /// Overflow.
+    pub const XER_OV: u32 = 0x4000_0000;
+    /// Carry.
+    pub const XER_CA: u32 = 0x2000_0000;
+
+    /// The return address used by [Cpu::call].
+    pub const RETURN_ADDRESS: u64 = 0xFFFF_FFFC;
+
+    /// Creates a processor with all registers cleared.
+    pub fn new(extensions: Extensions) -> Self {
+        Self {
+            gpr: [0; 32],
+            fpr: [0.0; 32],
+            ps1: [0.0; 32],
+            cr: 0,
+            xer: 0,
+            fpscr: 0,
+            vr: [0; 128],
+            vscr: 0,
+            lr: 0,
+            ctr: 0,
+            pc: 0,
+            msr: 0,
+            sr: [0; 16],
+            spr: [0; 1024],
+            tb: 0,
+            mode64: false,
+            extensions,
+            reservation: None,
+        }
+    }
+
+    /// Truncates a value to the register width of the current mode.
+    #[inline]
+    pub(crate) fn trunc(&self, value: u64) -> u64 {
+        if self.mode64 {
+            value
+        } else {
+            value & 0xFFFF_FFFF
+        }
+    }
+
+    #[inline]
+    pub(crate) fn set_gpr(&mut self, reg: u8, value: u64) {
+        self.gpr[reg as usize] = self.trunc(value);
+    }
+
+    /// Returns the value of a 4-bit condition register field.
+    #[inline]
+    pub fn cr_field(&self, field: u8) -> u8 {
+        ((self.cr >> (28 - 4 * (field as u32 & 7))) & 0xF) as u8
+    }
+
+    /// Sets the value of a 4-bit condition register field.
+    #[inline]
+    pub fn set_cr_field(&mut self, field: u8, value: u8) {
+        let shift = 28 - 4 * (field as u32 & 7);
+        self.cr = (self.cr & !(0xF << shift)) | ((value as u32 & 0xF) << shift);
+    }
+
+    /// Returns the value of a condition register bit.
+    #[inline]
+    pub fn cr_bit(&self, bit: CRBit) -> bool {
+        self.cr & (0x8000_0000 >> (bit.0 & 31)) != 0
+    }
+
+    /// Sets the value of a condition register bit.
+    #[inline]
+    pub fn set_cr_bit(&mut self, bit: CRBit, value: bool) {
+        let mask = 0x8000_0000 >> (bit.0 & 31);
+        if value {
+            self.cr |= mask;
+        } else {
+            self.cr &= !mask;
+        }
+    }
+
+    /// The carry bit of XER.
+    #[inline]
+    pub fn ca(&self) -> bool {
+        self.xer & Self::XER_CA != 0
+    }
+
+    #[inline]
+    pub(crate) fn set_ca(&mut self, ca: bool) {
+        if ca {
+            self.xer |= Self::XER_CA;
+        } else {
+            self.xer &= !Self::XER_CA;
+        }
+    }
+
+    /// Sets XER\[OV\], and XER\[SO\] if an overflow occurred.
+    #[inline]
+    pub(crate) fn set_ov(&mut self, ov: bool) {
+        if ov {
+            self.xer |= Self::XER_OV | Self::XER_SO;
+        } else {
+            self.xer &= !Self::XER_OV;
+        }
+    }
+
+    /// The result of a comparison as a condition register field, including XER\[SO\].
+    #[inline]
+    pub(crate) fn compare<T: Ord>(&self, a: T, b: T) -> u8 {
+        let so = (self.xer >> 31) as u8;
+        match a.cmp(&b) {
+            core::cmp::Ordering::Less => 0b1000 | so,
+            core::cmp::Ordering::Greater => 0b0100 | so,
+            core::cmp::Ordering::Equal => 0b0010 | so,
+        }
+    }
+
+    /// Sets CR0 from a result, as done by instructions with Rc set.
+    #[inline]
+    pub(crate) fn record(&mut self, value: u64) {
+        let value = if self.mode64 { value as i64 } else { value as u32 as i32 as i64 };
+        self.set_cr_field(0, self.compare(value, 0));
+    }
+
+    /// Fetches, decodes and executes the instruction at [Cpu::pc].
+    pub fn step(&mut self, mem: &mut dyn Memory) -> Result<(), Exception> {
+        let pc = self.pc;
+        let code =
+            mem.read_u32(pc).map_err(|MemoryFault { addr }| Exception::MemoryFault { pc, addr })?;
+        self.execute(Ins::new(code, self.extensions), mem)
+    }
+
+    /// Executes a decoded instruction located at [Cpu::pc].
+    pub fn execute(&mut self, ins: Ins, mem: &mut dyn Memory) -> Result<(), Exception> {
+        let pc = self.pc;
+        if ins.op == Opcode::Illegal {
+            return Err(Exception::IllegalInstruction { pc, code: ins.code });
+        }
+        let next = if let Some(target) = self.execute_branch(ins)? {
+            target
+        } else if self.execute_load_store(ins, mem)?
+            || self.execute_integer(ins)?
+            || self.execute_float(ins)?
+            || self.execute_paired(ins)?
+            || self.execute_vector(ins)?
+            || self.execute_vmx128(ins)?
+        {
+            self.trunc(pc.wrapping_add(4))
+        } else {
+            return Err(Exception::Unimplemented { pc, ins });
+        };
+        self.pc = next;
+        self.tb = self.tb.wrapping_add(1);
+        Ok(())
+    }
+
+    /// Executes instructions until [Cpu::pc] reaches `until`.
+    ///
+    /// Returns the number of executed instructions, or [Exception::StepLimit] if
+    /// `max_steps` instructions were executed first.
+    pub fn run_until(
+        &mut self,
+        mem: &mut dyn Memory,
+        until: u64,
+        max_steps: u64,
+    ) -> Result<u64, Exception> {
+        let mut steps = 0;
+        while self.pc != until {
+            if steps == max_steps {
+                return Err(Exception::StepLimit { pc: self.pc });
+            }
+            self.step(mem)?;
+            steps += 1;
+        }
+        Ok(steps)
+    }
+
+    /// Calls the function at `addr`, running until it returns.
+    ///
+    /// Arguments and results are passed in registers as set up by the caller.
+    /// LR is set to [Cpu::RETURN_ADDRESS] before the call.
+    pub fn call(
+        &mut self,
+        mem: &mut dyn Memory,
+        addr: u64,
+        max_steps: u64,
+    ) -> Result<u64, Exception> {
+        self.lr = Self::RETURN_ADDRESS;
+        self.pc = addr;
+        self.run_until(mem, Self::RETURN_ADDRESS, max_steps)
+    }
+}
@@ -0,0 +1,691 @@
+use crate::integer::modifier;
+use crate::round::{Inexact, RoundingMode};
+use crate::{Cpu, Exception};
+use powerpc::{Extension, FieldId, Ins, Opcode};
+
+/// The quiet NaN produced by invalid operations.
+const DEFAULT_NAN: u64 = 0x7FF8_0000_0000_0000;
+const QUIET_BIT: u64 = 1 << 51;
+const SIGN_BIT: u64 = 1 << 63;
+
+/// Base and decrement of the linear segments used by `fres` on the 750CL,
+/// indexed by the top five fraction bits.
+const FRES_TABLE: [(u32, u32); 32] = [
+    (0x7FF800, 0x3E1),
+    (0x783800, 0x3A7),
+    (0x70EA00, 0x371),
+    (0x6A0800, 0x340),
+    (0x638800, 0x313),
+    (0x5D6200, 0x2EA),
+    (0x579000, 0x2C4),
+    (0x520800, 0x2A0),
+    (0x4CC800, 0x27F),
+    (0x47CA00, 0x261),
+    (0x430800, 0x245),
+    (0x3E8000, 0x22A),
+    (0x3A2C00, 0x212),
+    (0x360800, 0x1FB),
+    (0x321400, 0x1E5),
+    (0x2E4A00, 0x1D1),
+    (0x2AA800, 0x1BE),
+    (0x272C00, 0x1AC),
+    (0x23D600, 0x19B),
+    (0x209E00, 0x18B),
+    (0x1D8800, 0x17C),
+    (0x1A9000, 0x16E),
+    (0x17AE00, 0x15B),
+    (0x14F800, 0x15B),
+    (0x124400, 0x143),
+    (0x0FBE00, 0x143),
+    (0x0D3800, 0x12D),
+    (0x0ADE00, 0x12D),
+    (0x088400, 0x11A),
+    (0x065000, 0x11A),
+    (0x041C00, 0x108),
+    (0x020C00, 0x106),
+];
+
+/// Base and decrement of the linear segments used by `frsqrte` on the 750CL,
+/// indexed by the top four fraction bits, plus 16 for odd exponents.
+const FRSQRTE_TABLE: [(u32, u32); 32] = [
+    (0x3FFA000, 0x7A4),
+    (0x3C29000, 0x700),
+    (0x38AA000, 0x670),
+    (0x3572000, 0x5F2),
+    (0x3279000, 0x584),
+    (0x2FB7000, 0x524),
+    (0x2D26000, 0x4CC),
+    (0x2AC0000, 0x47E),
+    (0x2881000, 0x43A),
+    (0x2665000, 0x3FA),
+    (0x2468000, 0x3C2),
+    (0x2287000, 0x38E),
+    (0x20C1000, 0x35E),
+    (0x1F12000, 0x332),
+    (0x1D79000, 0x30A),
+    (0x1BF4000, 0x2E6),
+    (0x1A7E800, 0x568),
+    (0x17CB800, 0x4F3),
+    (0x1552800, 0x48D),
+    (0x130C000, 0x435),
+    (0x10F2000, 0x3E7),
+    (0x0EFF000, 0x3A2),
+    (0x0D2E000, 0x365),
+    (0x0B7C000, 0x32E),
+    (0x09E5000, 0x2FC),
+    (0x0867000, 0x2D0),
+    (0x06FF000, 0x2A8),
+    (0x05AB800, 0x283),
+    (0x046A000, 0x261),
+    (0x0339800, 0x243),
+    (0x0218800, 0x226),
+    (0x0105800, 0x20B),
+];
+
+#[inline]
+fn is_snan(value: f64) -> bool {
+    value.is_nan() && value.to_bits() & QUIET_BIT == 0
+}
+
+#[inline]
+fn quiet(value: f64) -> f64 {
+    f64::from_bits(value.to_bits() | QUIET_BIT)
+}
+
+/// Converts a single-precision value to double precision, as done by `lfs`.
+///
+/// Unlike `as`, signaling NaNs are not quieted.
+pub(crate) fn single_to_double(word: u32) -> f64 {
+    let value = f32::from_bits(word);
+    if value.is_nan() {
+        let sign = (word as u64 & 0x8000_0000) << 32;
+        f64::from_bits(sign | 0x7FF0_0000_0000_0000 | (word as u64 & 0x7F_FFFF) << 29)
+    } else {
+        value as f64
+    }
+}
+
+/// Converts a double-precision value to single precision, as done by `stfs`.
+///
+/// The value is not rounded: excess fraction bits are dropped, and values too
+/// large for single precision are undefined.
+pub(crate) fn double_to_single(value: f64) -> u32 {
+    let bits = value.to_bits();
+    let exponent = (bits >> 52) & 0x7FF;
+    if exponent > 896 || bits << 1 == 0 {
+        (((bits >> 32) & 0xC000_0000) | ((bits >> 29) & 0x3FFF_FFFF)) as u32
+    } else if exponent >= 874 {
+        // Denormalized single
+        let fraction = ((1 << 52) | (bits & ((1 << 52) - 1))) >> (897 - exponent);
+        ((bits >> 32) as u32 & 0x8000_0000) | ((fraction >> 29) as u32 & 0x7F_FFFF)
+    } else {
+        (bits >> 32) as u32 & 0x8000_0000
+    }
+}
+
+/// Estimates `1 / value` as `fres` does on the 750CL.
+pub(crate) fn reciprocal_estimate(value: f64) -> f64 {
+    let bits = value.to_bits();
+    let sign = bits & SIGN_BIT;
+    let exponent = (bits >> 52) & 0x7FF;
+    let fraction = bits & ((1 << 52) - 1);
+    if exponent == 0 && fraction == 0 {
+        return f64::INFINITY.copysign(value);
+    }
+    if exponent == 0x7FF {
+        return if fraction == 0 { 0f64.copysign(value) } else { quiet(value) };
+    }
+    if exponent < 895 {
+        return (f32::MAX as f64).copysign(value);
+    }
+    if exponent >= 1149 {
+        return 0f64.copysign(value);
+    }
+    let i = (fraction >> 37) as u32;
+    let (base, dec) = FRES_TABLE[(i / 1024) as usize];
+    let estimate = base - (dec * (i % 1024)).div_ceil(2);
+    f64::from_bits(sign | (0x7FD - exponent) << 52 | (estimate as u64) << 29)
+}
+
+/// Estimates `1 / sqrt(value)` as `frsqrte` does on the 750CL.
+pub(crate) fn reciprocal_sqrt_estimate(value: f64) -> f64 {
+    let bits = value.to_bits();
+    let mut exponent = ((bits >> 52) & 0x7FF) as i64;
+    let mut fraction = bits & ((1 << 52) - 1);
+    if exponent == 0 && fraction == 0 {
+        return f64::INFINITY.copysign(value);
+    }
+    if exponent == 0x7FF && fraction != 0 {
+        return quiet(value);
+    }
+    if value < 0.0 {
+        return f64::from_bits(DEFAULT_NAN);
+    }
+    if exponent == 0x7FF {
+        return 0.0;
+    }
+    if exponent == 0 {
+        // Normalize denormals
+        while fraction & (1 << 52) == 0 {
+            exponent -= 1;
+            fraction <<= 1;
+        }
+        fraction &= (1 << 52) - 1;
+        exponent += 1;
+    }
+    let odd_exponent = exponent & 1 == 0;
+    let result_exponent = (0x3FF * 2 - (exponent - 0x3FE)) >> 1;
+    let i = (fraction >> 37) as u32;
+    let (base, dec) = FRSQRTE_TABLE[(i / 2048) as usize + if odd_exponent { 16 } else { 0 }];
+    let estimate = base - dec * (i % 2048);
+    f64::from_bits((result_exponent as u64 & 0x7FF) << 52 | (estimate as u64) << 26)
+}
+
+/// The floating-point result flags (FPRF) classifying a value.
+fn result_flags(value: f64, single: bool) -> u32 {
+    let negative = value.is_sign_negative();
+    let min_normal = if single { f32::MIN_POSITIVE as f64 } else { f64::MIN_POSITIVE };
+    match () {
+        _ if value.is_nan() => 0b10001,
+        _ if value.is_infinite() => {
+            if negative {
+                0b01001
+            } else {
+                0b00101
+            }
+        }
+        _ if value == 0.0 => {
+            if negative {
+                0b10010
+            } else {
+                0b00010
+            }
+        }
+        _ if value.abs() < min_normal => {
+            if negative {
+                0b11000
+            } else {
+                0b10100
+            }
+        }
+        _ if negative => 0b01000,
+        _ => 0b00100,
+    }
+}
+
+/// Rounds to the nearest integer, ties to even.
+pub(crate) fn round_ties_even(value: f64) -> f64 {
+    let rounded = value.round();
+    if (rounded - value).abs() == 0.5 {
+        2.0 * (value / 2.0).round()
+    } else {
+        rounded
+    }
+}
+
+impl Cpu {
+    /// Floating-point exception summary.
+    pub const FPSCR_FX: u32 = 0x8000_0000;
+    /// Floating-point enabled exception summary.
+    pub const FPSCR_FEX: u32 = 0x4000_0000;
+    /// Floating-point invalid operation exception summary.
+    pub const FPSCR_VX: u32 = 0x2000_0000;
+    /// Overflow exception.
+    pub const FPSCR_OX: u32 = 0x1000_0000;
+    /// Underflow exception.
+    pub const FPSCR_UX: u32 = 0x0800_0000;
+    /// Zero divide exception.
+    pub const FPSCR_ZX: u32 = 0x0400_0000;
+    /// Inexact exception.
+    pub const FPSCR_XX: u32 = 0x0200_0000;
+    /// Invalid operation: signaling NaN.
+    pub const FPSCR_VXSNAN: u32 = 0x0100_0000;
+    /// Invalid operation: infinity minus infinity.
+    pub const FPSCR_VXISI: u32 = 0x0080_0000;
+    /// Invalid operation: infinity divided by infinity.
+    pub const FPSCR_VXIDI: u32 = 0x0040_0000;
+    /// Invalid operation: zero divided by zero.
+    pub const FPSCR_VXZDZ: u32 = 0x0020_0000;
+    /// Invalid operation: infinity times zero.
+    pub const FPSCR_VXIMZ: u32 = 0x0010_0000;
+    /// Invalid operation: invalid compare.
+    pub const FPSCR_VXVC: u32 = 0x0008_0000;
+    /// Fraction rounded.
+    pub const FPSCR_FR: u32 = 0x0004_0000;
+    /// Fraction inexact.
+    pub const FPSCR_FI: u32 = 0x0002_0000;
+    /// Floating-point result flags: class descriptor and condition code.
+    pub const FPSCR_FPRF: u32 = 0x0001_F000;
+    /// Floating-point condition code.
+    pub const FPSCR_FPCC: u32 = 0x0000_F000;
+    /// Invalid operation: software request.
+    pub const FPSCR_VXSOFT: u32 = 0x0000_0400;
+    /// Invalid operation: square root of a negative number.
+    pub const FPSCR_VXSQRT: u32 = 0x0000_0200;
+    /// Invalid operation: invalid integer convert.
+    pub const FPSCR_VXCVI: u32 = 0x0000_0100;
+    /// Invalid operation exception enable.
+    pub const FPSCR_VE: u32 = 0x0000_0080;
+    /// Overflow exception enable.
+    pub const FPSCR_OE: u32 = 0x0000_0040;
+    /// Underflow exception enable.
+    pub const FPSCR_UE: u32 = 0x0000_0020;
+    /// Zero divide exception enable.
+    pub const FPSCR_ZE: u32 = 0x0000_0010;
+    /// Inexact exception enable.
+    pub const FPSCR_XE: u32 = 0x0000_0008;
+    /// Non-IEEE mode: denormalized results are flushed to zero.
+    pub const FPSCR_NI: u32 = 0x0000_0004;
+    /// Rounding mode. (See [RoundingMode])
+    pub const FPSCR_RN: u32 = 0x0000_0003;
+
+    /// All invalid operation exception bits.
+    const FPSCR_VX_ALL: u32 = Self::FPSCR_VXSNAN
+        | Self::FPSCR_VXISI
+        | Self::FPSCR_VXIDI
+        | Self::FPSCR_VXZDZ
+        | Self::FPSCR_VXIMZ
+        | Self::FPSCR_VXVC
+        | Self::FPSCR_VXSOFT
+        | Self::FPSCR_VXSQRT
+        | Self::FPSCR_VXCVI;
+    /// All sticky exception bits.
+    const FPSCR_EXCEPTIONS: u32 =
+        Self::FPSCR_OX | Self::FPSCR_UX | Self::FPSCR_ZX | Self::FPSCR_XX | Self::FPSCR_VX_ALL;
+
+    /// The rounding mode selected by FPSCR\[RN\].
+    #[inline]
+    pub fn rounding_mode(&self) -> RoundingMode {
+        RoundingMode::from_bits(self.fpscr)
+    }
+
+    /// Recomputes FPSCR\[VX\] and FPSCR\[FEX\] from the exception and enable bits.
+    fn update_fpscr_summary(&mut self) {
+        let mut fpscr = self.fpscr & !(Self::FPSCR_VX | Self::FPSCR_FEX);
+        if fpscr & Self::FPSCR_VX_ALL != 0 {
+            fpscr |= Self::FPSCR_VX;
+        }
+        // Each of VX, OX, UX, ZX and XX lines up with its enable bit 22 bits lower
+        if (fpscr >> 22) & fpscr & 0xF8 != 0 {
+            fpscr |= Self::FPSCR_FEX;
+        }
+        self.fpscr = fpscr;
+    }
+
+    /// Sets sticky exception bits, and FPSCR\[FX\] if any were previously clear.
+    fn raise(&mut self, bits: u32) {
+        if bits & !self.fpscr & Self::FPSCR_EXCEPTIONS != 0 {
+            self.fpscr |= Self::FPSCR_FX;
+        }
+        self.fpscr |= bits;
+        self.update_fpscr_summary();
+    }
+
+    #[inline]
+    fn enabled(&self, enable: u32) -> bool {
+        self.fpscr & enable != 0
+    }
+
+    #[inline]
+    fn set_fi_fr(&mut self, inexact: bool, rounded_up: bool) {
+        self.fpscr &= !(Self::FPSCR_FI | Self::FPSCR_FR);
+        if inexact {
+            self.fpscr |= Self::FPSCR_FI;
+        }
+        if rounded_up {
+            self.fpscr |= Self::FPSCR_FR;
+        }
+    }
+
+    /// Sets FPSCR\[FPRF\] from a result.
+    #[inline]
+    pub(crate) fn set_fprf(&mut self, value: f64, single: bool) {
+        self.fpscr = (self.fpscr & !Self::FPSCR_FPRF) | result_flags(value, single) << 12;
+    }
+
+    /// Writes a result to an FPR and sets FPSCR\[FPRF\] from it.
+    ///
+    /// With paired singles, single-precision results are written to both slots.
+    fn set_result(&mut self, reg: usize, value: f64, single: bool) {
+        self.fpr[reg] = value;
+        if single && self.extensions.contains(Extension::PairedSingles) {
+            self.ps1[reg] = value;
+        }
+        self.set_fprf(value, single);
+    }
+
+    /// Rounds an exact result according to FPSCR\[RN\], updating FPSCR\[FR\],
+    /// FPSCR\[FI\] and the overflow, underflow and inexact exceptions.
+    ///
+    /// Enabled overflow and underflow exceptions do not scale the result.
+    fn round(&mut self, exact: Inexact, single: bool) -> f64 {
+        let mode = self.rounding_mode();
+        let rounded = if single { exact.round_single(mode) } else { exact.round_double(mode) };
+        let (mut value, mut inexact, mut rounded_up) =
+            (rounded.value, rounded.inexact, rounded.rounded_up);
+        let mut flags = 0;
+        if rounded.overflow {
+            flags |= Self::FPSCR_OX;
+        }
+        if rounded.tiny && (inexact || self.enabled(Self::FPSCR_UE)) {
+            flags |= Self::FPSCR_UX;
+        }
+        let min_normal = if single { f32::MIN_POSITIVE as f64 } else { f64::MIN_POSITIVE };
+        if self.enabled(Self::FPSCR_NI) && value != 0.0 && value.abs() < min_normal {
+            value = 0f64.copysign(value);
+            (inexact, rounded_up) = (true, false);
+        }
+        if inexact {
+            flags |= Self::FPSCR_XX;
+        }
+        self.raise(flags);
+        self.set_fi_fr(inexact, rounded_up);
+        value
+    }
+
+    /// Raises an invalid operation exception, returning the default NaN unless
+    /// the exception is enabled.
+    fn invalid(&mut self, bits: u32) -> Option<f64> {
+        self.raise(bits);
+        self.set_fi_fr(false, false);
+        if self.enabled(Self::FPSCR_VE) {
+            None
+        } else {
+            Some(f64::from_bits(DEFAULT_NAN))
+        }
+    }
+
+    /// Propagates the first NaN operand, quieted, raising an invalid operation
+    /// exception for signaling NaNs.
+    fn propagate_nan(&mut self, operands: &[f64], single: bool) -> Option<f64> {
+        let nan = operands.iter().copied().find(|v| v.is_nan())?;
+        self.set_fi_fr(false, false);
+        if operands.iter().any(|&v| is_snan(v)) {
+            self.raise(Self::FPSCR_VXSNAN);
+            if self.enabled(Self::FPSCR_VE) {
+                return None;
+            }
+        }
+        let bits = quiet(nan).to_bits();
+        Some(f64::from_bits(if single { bits & 0xFFFF_FFFF_E000_0000 } else { bits }))
+    }
+
+    /// Rounds the multiplier of a single-precision multiply to 25 significant bits,
+    /// as done by the 750CL.
+    #[inline]
+    pub(crate) fn round_multiplier(&self, value: f64) -> f64 {
+        if !self.extensions.contains(Extension::PairedSingles) || !value.is_finite() {
+            return value;
+        }
+        let bits = value.to_bits();
+        f64::from_bits((bits & 0xFFFF_FFFF_F800_0000) + (bits & 0x800_0000))
+    }
+
+    /// Evaluates an arithmetic instruction.
+    ///
+    /// Returns [None] if the result is suppressed by an enabled exception.
+    pub(crate) fn arithmetic(
+        &mut self,
+        op: Opcode,
+        a: f64,
+        b: f64,
+        c: f64,
+        single: bool,
+    ) -> Option<f64> {
+        use Opcode::*;
+        let c = if single { self.round_multiplier(c) } else { c };
+        let operands = match op {
+            Fadd | Fadds | Fsub | Fsubs | Fdiv | Fdivs => &[a, b][..],
+            Fmul | Fmuls => &[a, c],
+            Fsqrt | Fsqrts => &[b],
+            _ => &[a, b, c],
+        };
+        if operands.iter().any(|v| v.is_nan()) {
+            return self.propagate_nan(operands, single);
+        }
+        let zero_times_infinity = (a == 0.0 && c.is_infinite()) || (a.is_infinite() && c == 0.0);
+        let exact = match op {
+            Fadd | Fadds | Fsub | Fsubs => {
+                let b = if matches!(op, Fsub | Fsubs) { -b } else { b };
+                if a.is_infinite() && b == -a {
+                    return self.invalid(Self::FPSCR_VXISI);
+                }
+                Inexact::add(a, b)
+            }
+            Fmul | Fmuls => {
+                if zero_times_infinity {
+                    return self.invalid(Self::FPSCR_VXIMZ);
+                }
+                Inexact::mul(a, c)
+            }
+            Fdiv | Fdivs => {
+                if a.is_infinite() && b.is_infinite() {
+                    return self.invalid(Self::FPSCR_VXIDI);
+                }
+                if b == 0.0 {
+                    if a == 0.0 {
+                        return self.invalid(Self::FPSCR_VXZDZ);
+                    }
+                    self.raise(Self::FPSCR_ZX);
+                    self.set_fi_fr(false, false);
+                    if self.enabled(Self::FPSCR_ZE) {
+                        return None;
+                    }
+                    Inexact::exact(a / b)
+                } else {
+                    Inexact::div(a, b)
+                }
+            }
+            Fsqrt | Fsqrts => {
+                if b < 0.0 {
+                    return self.invalid(Self::FPSCR_VXSQRT);
+                }
+                Inexact::sqrt(b)
+            }
+            _ => {
+                let b = if matches!(op, Fmsub | Fmsubs | Fnmsub | Fnmsubs) { -b } else { b };
+                if zero_times_infinity {
+                    return self.invalid(Self::FPSCR_VXIMZ);
+                }
+                if (a.is_infinite() || c.is_infinite()) && b == -(a * c) {
+                    return self.invalid(Self::FPSCR_VXISI);
+                }
+                Inexact::mul_add(a, c, b)
+            }
+        };
+        let value = self.round(exact, single);
+        // The negative forms negate the rounded result
+        Some(if matches!(op, Fnmadd | Fnmadds | Fnmsub | Fnmsubs) { -value } else { value })
+    }
+
+    /// Evaluates `fres`, or `frsqrte` if `sqrt` is set, raising zero divide and
+    /// invalid operation exceptions.
+    ///
+    /// Returns [None] if the result is suppressed by an enabled exception.
+    pub(crate) fn estimate(&mut self, sqrt: bool, b: f64) -> Option<f64> {
+        let flags = match () {
+            _ if is_snan(b) => Self::FPSCR_VXSNAN,
+            _ if b == 0.0 => Self::FPSCR_ZX,
+            _ if sqrt && b < 0.0 => Self::FPSCR_VXSQRT,
+            _ => 0,
+        };
+        if flags != 0 || !b.is_finite() {
+            self.set_fi_fr(false, false);
+        }
+        self.raise(flags);
+        if (flags & Self::FPSCR_VX_ALL != 0 && self.enabled(Self::FPSCR_VE))
+            || (flags == Self::FPSCR_ZX && self.enabled(Self::FPSCR_ZE))
+        {
+            return None;
+        }
+        Some(if sqrt { reciprocal_sqrt_estimate(b) } else { reciprocal_estimate(b) })
+    }
+
+    /// Converts to a 32-bit or 64-bit integer, saturating out-of-range values.
+    fn convert_to_integer(&mut self, op: Opcode, value: f64) -> Option<u64> {
+        let mode = match op {
+            Opcode::Fctiwz | Opcode::Fctidz => RoundingMode::Zero,
+            _ => self.rounding_mode(),
+        };
+        let word = matches!(op, Opcode::Fctiw | Opcode::Fctiwz);
+        let (min, max) =
+            if word { (i32::MIN as i64, i32::MAX as i64) } else { (i64::MIN, i64::MAX) };
+        let rounded = match mode {
+            RoundingMode::Nearest => round_ties_even(value),
+            RoundingMode::Zero => value.trunc(),
+            RoundingMode::Up => value.ceil(),
+            RoundingMode::Down => value.floor(),
+        };
+        // The upper bound is exclusive, as i64::MAX is not representable
+        let integer = if value.is_nan() || rounded < min as f64 || rounded >= -(min as f64) {
+            let snan = if is_snan(value) { Self::FPSCR_VXSNAN } else { 0 };
+            self.raise(Self::FPSCR_VXCVI | snan);
+            self.set_fi_fr(false, false);
+            if self.enabled(Self::FPSCR_VE) {
+                return None;
+            }
+            if value > 0.0 {
+                max
+            } else {
+                min
+            }
+        } else {
+            let inexact = rounded != value;
+            if inexact {
+                self.raise(Self::FPSCR_XX);
+            }
+            self.set_fi_fr(inexact, rounded.abs() > value.abs());
+            rounded as i64
+        };
+        if !word {
+            return Some(integer as u64);
+        }
+        // The 750CL fills the high word, and marks negative values that convert to zero
+        let mut bits = 0xFFF8_0000_0000_0000 | integer as u32 as u64;
+        if integer == 0 && value.is_sign_negative() {
+            bits |= 0x1_0000_0000;
+        }
+        Some(bits)
+    }
+
+    /// Compares two values into a condition register field and FPSCR\[FPCC\], as
+    /// done by `fcmpu`, or `fcmpo` if `ordered` is set.
+    pub(crate) fn compare_float(&mut self, crf: u8, ordered: bool, a: f64, b: f64) {
+        let result = match a.partial_cmp(&b) {
+            Some(core::cmp::Ordering::Less) => 0b1000,
+            Some(core::cmp::Ordering::Greater) => 0b0100,
+            Some(core::cmp::Ordering::Equal) => 0b0010,
+            None => 0b0001,
+        };
+        let snan = is_snan(a) || is_snan(b);
+        let mut flags = if snan { Self::FPSCR_VXSNAN } else { 0 };
+        if ordered && result == 0b0001 && (!snan || !self.enabled(Self::FPSCR_VE)) {
+            flags |= Self::FPSCR_VXVC;
+        }
+        self.raise(flags);
+        self.fpscr = (self.fpscr & !Self::FPSCR_FPCC) | (result as u32) << 12;
+        self.set_cr_field(crf, result);
+    }
+
+    /// Writes the FPSCR bits selected by `mask`. FEX and VX are always recomputed.
+    fn write_fpscr(&mut self, mask: u32, value: u32) {
+        self.fpscr = (self.fpscr & !mask) | (value & mask);
+        self.update_fpscr_summary();
+    }
+
+    /// Executes a floating-point arithmetic, conversion, compare, move or FPSCR
+    /// instruction.
+    ///
+    /// Returns false if the instruction is not handled by this unit.
+    pub(crate) fn execute_float(&mut self, ins: Ins) -> Result<bool, Exception> {
+        use Opcode::*;
+        let frd = ins.field_frd() as usize;
+        let a = self.fpr[ins.field_fra() as usize];
+        let b = self.fpr[ins.field_frb() as usize];
+        let c = self.fpr[ins.field_frc() as usize];
+        match ins.op {
+            Fadd | Fsub | Fmul | Fdiv | Fsqrt | Fmadd | Fmsub | Fnmadd | Fnmsub => {
+                if let Some(value) = self.arithmetic(ins.op, a, b, c, false) {
+                    self.set_result(frd, value, false);
+                }
+            }
+            Fadds | Fsubs | Fmuls | Fdivs | Fsqrts | Fmadds | Fmsubs | Fnmadds | Fnmsubs => {
+                if let Some(value) = self.arithmetic(ins.op, a, b, c, true) {
+                    self.set_result(frd, value, true);
+                }
+            }
+            Frsp => {
+                let value = if b.is_nan() {
+                    self.propagate_nan(&[b], true)
+                } else {
+                    Some(self.round(Inexact::exact(b), true))
+                };
+                if let Some(value) = value {
+                    self.set_result(frd, value, true);
+                }
+            }
+            Fres => {
+                if let Some(value) = self.estimate(false, b) {
+                    self.set_result(frd, value, true);
+                }
+            }
+            Frsqrte => {
+                if let Some(value) = self.estimate(true, b) {
+                    self.set_result(frd, value, false);
+                }
+            }
+            Fctiw | Fctiwz | Fctid | Fctidz => {
+                // FPRF is left unchanged
+                if let Some(bits) = self.convert_to_integer(ins.op, b) {
+                    self.fpr[frd] = f64::from_bits(bits);
+                }
+            }
+            Fcfid => {
+                let integer = b.to_bits() as i64;
+                let value = integer as f64;
+                let error = (integer as i128).cmp(&(value as i128));
+                let value = self.round(Inexact { value, error }, false);
+                self.set_result(frd, value, false);
+            }
+            Fsel => self.fpr[frd] = if a >= 0.0 { c } else { b },
+            Fmr => self.fpr[frd] = b,
+            Fneg => self.fpr[frd] = f64::from_bits(b.to_bits() ^ SIGN_BIT),
+            Fabs => self.fpr[frd] = f64::from_bits(b.to_bits() & !SIGN_BIT),
+            Fnabs => self.fpr[frd] = f64::from_bits(b.to_bits() | SIGN_BIT),
+            Fcmpu | Fcmpo => self.compare_float(ins.field_crfd(), ins.op == Fcmpo, a, b),
+            Mffs => self.fpr[frd] = f64::from_bits(0xFFF8_0000_0000_0000 | self.fpscr as u64),
+            Mtfsf => {
+                let fm = ins.field_mtfsf_fm();
+                let mask = (0..8)
+                    .filter(|i| fm & (0x80 >> i) != 0)
+                    .fold(0, |mask, i| mask | (0xF000_0000u32 >> (4 * i)));
+                self.write_fpscr(mask, b.to_bits() as u32);
+            }
+            Mtfsfi => {
+                let shift = 28 - 4 * ins.field_crfd() as u32;
+                self.write_fpscr(0xF << shift, (ins.field_mtfsf_imm() as u32) << shift);
+            }
+            Mtfsb0 => self.write_fpscr(0x8000_0000 >> ins.field_crbd(), 0),
+            Mtfsb1 => {
+                let bit = 0x8000_0000 >> ins.field_crbd();
+                if bit & Self::FPSCR_EXCEPTIONS != 0 {
+                    self.raise(bit);
+                } else {
+                    self.write_fpscr(bit, bit);
+                }
+            }
+            Mcrfs => {
+                let shift = 28 - 4 * ins.field_crfs() as u32;
+                self.set_cr_field(ins.field_crfd(), (self.fpscr >> shift) as u8);
+                // Copied exception bits are cleared
+                let mask = (0xF << shift) & (Self::FPSCR_EXCEPTIONS | Self::FPSCR_FX);
+                self.write_fpscr(mask, 0);
+            }
+            _ => return Ok(false),
+        }
+        if modifier(ins, FieldId::Rc) {
+            self.set_cr_field(1, (self.fpscr >> 28) as u8);
+        }
+        Ok(true)
+    }
+}
@@ -0,0 +1,326 @@
+use crate::{Cpu, Exception};
+use powerpc::{mask64, CRBit, FieldId, Ins, Opcode};
+
+/// Whether an optional modifier is present and set.
+#[inline]
+pub(crate) fn modifier(ins: Ins, id: FieldId) -> bool {
+    ins.op.modifiers().contains(&id) && id.extract(ins.code) != 0
+}
+
+impl Cpu {
+    /// Computes `a + b + carry`, setting CA if `set_ca` and OV if the instruction has OE set.
+    fn add_extended(&mut self, ins: Ins, a: u64, b: u64, carry: bool, set_ca: bool) -> u64 {
+        let (result, ca, ov) = if self.mode64 {
+            let (sum, c1) = a.overflowing_add(b);
+            let (sum, c2) = sum.overflowing_add(carry as u64);
+            (sum, c1 || c2, ((a ^ sum) & (b ^ sum)) >> 63 != 0)
+        } else {
+            let (a, b) = (a as u32, b as u32);
+            let (sum, c1) = a.overflowing_add(b);
+            let (sum, c2) = sum.overflowing_add(carry as u32);
+            (sum as u64, c1 || c2, ((a ^ sum) & (b ^ sum)) >> 31 != 0)
+        };
+        if set_ca {
+            self.set_ca(ca);
+        }
+        if modifier(ins, FieldId::OE) {
+            self.set_ov(ov);
+        }
+        result
+    }
+
+    /// Sets OV if the instruction has OE set.
+    #[inline]
+    fn overflow(&mut self, ins: Ins, ov: bool) {
+        if modifier(ins, FieldId::OE) {
+            self.set_ov(ov);
+        }
+    }
+
+    /// Evaluates a rotate-and-mask instruction.
+    fn rotate(&self, ins: Ins) -> Option<u64> {
+        let rotate = ins.rotate_mask()?;
+        let rs = self.gpr[rotate.source.0 as usize];
+        let ra = self.gpr[rotate.dest.0 as usize];
+        let rb = self.gpr[ins.field_rb() as usize];
+        if rotate.doubleword || !self.mode64 {
+            return Some(rotate.evaluate(rs, rb, ra));
+        }
+        // In 64-bit mode, the rotated word is replicated into the high word,
+        // and a wrapping mask selects bits from it.
+        let n = match ins.op {
+            Opcode::Rlwnm => rb as u32 & 31,
+            _ => ins.field_sh() as u32,
+        };
+        let word = (rs as u32).rotate_left(n) as u64;
+        let rotated = word | (word << 32);
+        let mask = mask64(ins.field_mb() + 32, ins.field_me() + 32);
+        let result = rotated & mask;
+        Some(if rotate.insert { result | (ra & !mask) } else { result })
+    }
+
+    /// Executes an integer arithmetic, logical, rotate, compare, condition register
+    /// or special-purpose register instruction.
+    ///
+    /// Returns false if the instruction is not handled by this unit.
+    pub(crate) fn execute_integer(&mut self, ins: Ins) -> Result<bool, Exception> {
+        let rd = ins.field_rd();
+        let ra = ins.field_ra();
+        let a = self.gpr[ra as usize];
+        let b = self.gpr[ins.field_rb() as usize];
+        let s = self.gpr[ins.field_rs() as usize];
+        // rA, or 0 if rA is r0
+        let a0 = if ra == 0 { 0 } else { a };
+        let simm = ins.field_simm() as i64 as u64;
+        let uimm = ins.field_uimm() as u64;
+        let ca = self.ca();
+
+        // The destination register and its new value
+        let (dest, value) = match ins.op {
+            // Arithmetic
+            Opcode::Add => (rd, self.add_extended(ins, a, b, false, false)),
+            Opcode::Addc => (rd, self.add_extended(ins, a, b, false, true)),
+            Opcode::Adde => (rd, self.add_extended(ins, a, b, ca, true)),
+            Opcode::Addi => (rd, a0.wrapping_add(simm)),
+            Opcode::Addic | Opcode::Addic_ => (rd, self.add_extended(ins, a, simm, false, true)),
+            Opcode::Addis => (rd, a0.wrapping_add(simm << 16)),
+            Opcode::Addme => (rd, self.add_extended(ins, a, u64::MAX, ca, true)),
+            Opcode::Addze => (rd, self.add_extended(ins, a, 0, ca, true)),
+            Opcode::Subf => (rd, self.add_extended(ins, !a, b, true, false)),
+            Opcode::Subfc => (rd, self.add_extended(ins, !a, b, true, true)),
+            Opcode::Subfe => (rd, self.add_extended(ins, !a, b, ca, true)),
+            Opcode::Subfic => (rd, self.add_extended(ins, !a, simm, true, true)),
+            Opcode::Subfme => (rd, self.add_extended(ins, !a, u64::MAX, ca, true)),
+            Opcode::Subfze => (rd, self.add_extended(ins, !a, 0, ca, true)),
+            Opcode::Neg => (rd, self.add_extended(ins, !a, 0, true, false)),
+            Opcode::Mulli => (rd, a.wrapping_mul(simm)),
+            Opcode::Mullw => {
+                let product = (a as i32 as i64) * (b as i32 as i64);
+                self.overflow(ins, product != product as i32 as i64);
+                (rd, product as u64)
+            }
+            Opcode::Mulhw => (rd, (((a as i32 as i64) * (b as i32 as i64)) >> 32) as u64),
+            Opcode::Mulhwu => (rd, ((a as u32 as u64) * (b as u32 as u64)) >> 32),
+            Opcode::Mulld => {
+                let (product, ov) = (a as i64).overflowing_mul(b as i64);
+                self.overflow(ins, ov);
+                (rd, product as u64)
+            }
+            Opcode::Mulhd => (rd, (((a as i64 as i128) * (b as i64 as i128)) >> 64) as u64),
+            Opcode::Mulhdu => (rd, (((a as u128) * (b as u128)) >> 64) as u64),
+            Opcode::Divw => {
+                let (a, b) = (a as i32, b as i32);
+                let invalid = b == 0 || (a == i32::MIN && b == -1);
+                self.overflow(ins, invalid);
+                // The 750 returns -1 for negative dividends and 0 otherwise
+                let result = if invalid { (a >> 31) as u32 } else { (a / b) as u32 };
+                (rd, result as u64)
+            }
+            Opcode::Divwu => {
+                let (a, b) = (a as u32, b as u32);
+                self.overflow(ins, b == 0);
+                (rd, a.checked_div(b).unwrap_or(0) as u64)
+            }
+            Opcode::Divd => {
+                let (a, b) = (a as i64, b as i64);
+                let invalid = b == 0 || (a == i64::MIN && b == -1);
+                self.overflow(ins, invalid);
+                (rd, if invalid { (a >> 63) as u64 } else { (a / b) as u64 })
+            }
+            Opcode::Divdu => {
+                self.overflow(ins, b == 0);
+                (rd, a.checked_div(b).unwrap_or(0))
+            }
+
+            // Logical
+            Opcode::And => (ra, s & b),
+            Opcode::Andc => (ra, s & !b),
+            Opcode::Andi_ => (ra, s & uimm),
+            Opcode::Andis_ => (ra, s & (uimm << 16)),
+            Opcode::Or => (ra, s | b),
+            Opcode::Orc => (ra, s | !b),
+            Opcode::Ori => (ra, s | uimm),
+            Opcode::Oris => (ra, s | (uimm << 16)),
+            Opcode::Xor => (ra, s ^ b),
+            Opcode::Xori => (ra, s ^ uimm),
+            Opcode::Xoris => (ra, s ^ (uimm << 16)),
+            Opcode::Nand => (ra, !(s & b)),
+            Opcode::Nor => (ra, !(s | b)),
+            Opcode::Eqv => (ra, !(s ^ b)),
+            Opcode::Extsb => (ra, s as i8 as i64 as u64),
+            Opcode::Extsh => (ra, s as i16 as i64 as u64),
+            Opcode::Extsw => (ra, s as i32 as i64 as u64),
+            Opcode::Cntlzw => (ra, (s as u32).leading_zeros() as u64),
+            Opcode::Cntlzd => (ra, s.leading_zeros() as u64),
+
+            // Shift
+            Opcode::Slw => (ra, (s as u32).checked_shl(b as u32 & 0x3F).unwrap_or(0) as u64),
+            Opcode::Srw => (ra, (s as u32).checked_shr(b as u32 & 0x3F).unwrap_or(0) as u64),
+            Opcode::Sraw | Opcode::Srawi => {
+                let n =
+                    if ins.op == Opcode::Srawi { ins.field_sh() as u32 } else { b as u32 & 0x3F };
+                let s = s as i32;
+                let result = s >> n.min(31);
+                // CA is set if the result is negative and any 1 bits were shifted out
+                let lost = if n > 31 { s != 0 } else { n != 0 && (s as u32) << (32 - n) != 0 };
+                self.set_ca(s < 0 && lost);
+                (ra, result as i64 as u64)
+            }
+            Opcode::Sld => (ra, s.checked_shl(b as u32 & 0x7F).unwrap_or(0)),
+            Opcode::Srd => (ra, s.checked_shr(b as u32 & 0x7F).unwrap_or(0)),
+            Opcode::Srad | Opcode::Sradi => {
+                let n =
+                    if ins.op == Opcode::Sradi { ins.field_sh64() as u32 } else { b as u32 & 0x7F };
+                let s = s as i64;
+                let result = s >> n.min(63);
+                let lost = if n > 63 { s != 0 } else { n != 0 && (s as u64) << (64 - n) != 0 };
+                self.set_ca(s < 0 && lost);
+                (ra, result as u64)
+            }
+
+            // Rotate
+            Opcode::Rlwinm
+            | Opcode::Rlwnm
+            | Opcode::Rlwimi
+            | Opcode::Rldicl
+            | Opcode::Rldicr
+            | Opcode::Rldic
+            | Opcode::Rldimi
+            | Opcode::Rldcl
+            | Opcode::Rldcr => (ra, self.rotate(ins).unwrap_or(0)),
+
+            // Compare
+            Opcode::Cmp | Opcode::Cmpi | Opcode::Cmpl | Opcode::Cmpli => {
+                let b = match ins.op {
+                    Opcode::Cmp | Opcode::Cmpl => b,
+                    Opcode::Cmpi => simm,
+                    _ => uimm,
+                };
+                let doubleword = ins.field_l() != 0;
+                let result = match (ins.op, doubleword) {
+                    (Opcode::Cmp | Opcode::Cmpi, true) => self.compare(a as i64, b as i64),
+                    (Opcode::Cmp | Opcode::Cmpi, false) => self.compare(a as i32, b as i32),
+                    (_, true) => self.compare(a, b),
+                    (_, false) => self.compare(a as u32, b as u32),
+                };
+                self.set_cr_field(ins.field_crfd(), result);
+                return Ok(true);
+            }
+
+            // Condition register
+            Opcode::Crand
+            | Opcode::Crandc
+            | Opcode::Creqv
+            | Opcode::Crnand
+            | Opcode::Crnor
+            | Opcode::Cror
+            | Opcode::Crorc
+            | Opcode::Crxor => {
+                let a = self.cr_bit(CRBit(ins.field_crba()));
+                let b = self.cr_bit(CRBit(ins.field_crbb()));
+                let result = match ins.op {
+                    Opcode::Crand => a & b,
+                    Opcode::Crandc => a & !b,
+                    Opcode::Creqv => !(a ^ b),
+                    Opcode::Crnand => !(a & b),
+                    Opcode::Crnor => !(a | b),
+                    Opcode::Cror => a | b,
+                    Opcode::Crorc => a | !b,
+                    _ => a ^ b,
+                };
+                self.set_cr_bit(CRBit(ins.field_crbd()), result);
+                return Ok(true);
+            }
+            Opcode::Mcrf => {
+                self.set_cr_field(ins.field_crfd(), self.cr_field(ins.field_crfs()));
+                return Ok(true);
+            }
+            Opcode::Mcrxr => {
+                self.set_cr_field(ins.field_crfd(), (self.xer >> 28) as u8);
+                self.xer &= 0x0FFF_FFFF;
+                return Ok(true);
+            }
+            Opcode::Mfcr => (rd, self.cr as u64),
+            Opcode::Mfocrf => (rd, (self.cr & crm_mask(ins.field_crm())) as u64),
+            Opcode::Mtcrf => {
+                let mask = crm_mask(ins.field_crm());
+                self.cr = (s as u32 & mask) | (self.cr & !mask);
+                return Ok(true);
+            }
+
+            // Special-purpose registers
+            Opcode::Mfspr => (rd, self.read_spr(ins.field_spr())),
+            Opcode::Mtspr => {
+                self.write_spr(ins.field_spr(), s);
+                return Ok(true);
+            }
+            Opcode::Mftb => (rd, self.read_spr(ins.field_tbr())),
+            Opcode::Mfmsr => (rd, self.msr),
+            Opcode::Mtmsr | Opcode::Mtmsrd => {
+                self.msr = s;
+                return Ok(true);
+            }
+            Opcode::Mfsr => (rd, self.sr[ins.field_sr() as usize & 15] as u64),
+            Opcode::Mfsrin => (rd, self.sr[(b as u32 >> 28) as usize] as u64),
+            Opcode::Mtsr | Opcode::Mtsrd => {
+                self.sr[ins.field_sr() as usize & 15] = s as u32;
+                return Ok(true);
+            }
+            Opcode::Mtsrin | Opcode::Mtsrdin => {
+                self.sr[(b as u32 >> 28) as usize] = s as u32;
+                return Ok(true);
+            }
+
+            // Synchronization and cache management have no effect
+            Opcode::Sync
+            | Opcode::Isync
+            | Opcode::Eieio
+            | Opcode::Dcbf
+            | Opcode::Dcbi
+            | Opcode::Dcbst
+            | Opcode::Dcbt
+            | Opcode::Dcbtst
+            | Opcode::Icbi
+            | Opcode::Tlbie
+            | Opcode::Tlbsync => return Ok(true),
+            _ => return Ok(false),
+        };
+        self.set_gpr(dest, value);
+        if modifier(ins, FieldId::Rc)
+            || matches!(ins.op, Opcode::Addic_ | Opcode::Andi_ | Opcode::Andis_)
+        {
+            self.record(self.gpr[dest as usize]);
+        }
+        Ok(true)
+    }
+
+    /// Reads a special-purpose register by number.
+    pub fn read_spr(&self, spr: u16) -> u64 {
+        match spr {
+            1 => self.xer as u64,
+            8 => self.lr,
+            9 => self.ctr,
+            // TBL, or the full time base in 64-bit mode
+            268 => self.trunc(self.tb),
+            269 => self.tb >> 32,
+            spr => self.spr[spr as usize & 0x3FF],
+        }
+    }
+
+    /// Writes a special-purpose register by number.
+    pub fn write_spr(&mut self, spr: u16, value: u64) {
+        match spr {
+            1 => self.xer = value as u32,
+            8 => self.lr = self.trunc(value),
+            9 => self.ctr = self.trunc(value),
+            284 => self.tb = (self.tb & !0xFFFF_FFFF) | (value & 0xFFFF_FFFF),
+            285 => self.tb = (self.tb & 0xFFFF_FFFF) | (value << 32),
+            spr => self.spr[spr as usize & 0x3FF] = value,
+        }
+    }
+}
+
+/// Expands the CRM field of `mtcrf` into a mask of condition register bits.
+fn crm_mask(crm: u8) -> u32 {
+    (0..8).filter(|i| crm & (0x80 >> i) != 0).fold(0, |mask, i| mask | (0xF000_0000 >> (4 * i)))
+}
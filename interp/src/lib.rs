@@ -0,0 +1,14 @@
+mod branch;
+mod cpu;
+mod float;
+mod integer;
+mod load_store;
+mod memory;
+mod paired;
+mod round;
+mod vector;
+mod vmx128;
+
+pub use cpu::{Cpu, Exception};
+pub use memory::{Memory, MemoryFault, Ram};
+pub use round::RoundingMode;
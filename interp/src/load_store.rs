@@ -0,0 +1,292 @@
+use crate::float::{double_to_single, single_to_double};
+use crate::paired::{dequantize, quantize};
+use crate::{Cpu, Exception, Memory, MemoryFault};
+use powerpc::{
+    AccessFormat, AccessKind, Alignment, Argument, Extension, GqrConfig, Ins, MemoryAccess, FPR,
+    GPR, GQR, VR,
+};
+
+impl Cpu {
+    /// Computes the effective address of an access, checking its alignment.
+    pub(crate) fn access_address(&self, access: &MemoryAccess) -> Result<u64, Exception> {
+        let ea = self.trunc(access.effective_address(self));
+        match access.alignment {
+            Alignment::Required(n) if ea % n as u64 != 0 => {
+                Err(Exception::Alignment { pc: self.pc, addr: ea })
+            }
+            Alignment::Truncated(n) => Ok(ea & !(n as u64 - 1)),
+            _ => Ok(ea),
+        }
+    }
+
+    /// Converts a memory fault into an exception at the current instruction.
+    #[inline]
+    pub(crate) fn fault(&self, fault: MemoryFault) -> Exception {
+        Exception::MemoryFault { pc: self.pc, addr: fault.addr }
+    }
+
+    /// Executes an integer, floating-point, quantized or vector load or store, a
+    /// load or store multiple or string, or `dcbz`.
+    ///
+    /// Returns false if the instruction is not handled by this unit.
+    pub(crate) fn execute_load_store(
+        &mut self,
+        ins: Ins,
+        mem: &mut dyn Memory,
+    ) -> Result<bool, Exception> {
+        let Some(access) = ins.memory_access() else {
+            return Ok(false);
+        };
+        let handled = match (access.format, access.reg) {
+            (AccessFormat::Integer, Argument::GPR(GPR(reg))) => {
+                let ea = self.access_address(&access)?;
+                self.access_integer(&access, reg, ea, mem)?;
+                true
+            }
+            (AccessFormat::Integer, Argument::FPR(FPR(reg))) => {
+                // stfiwx stores the low word of the FPR
+                let ea = self.access_address(&access)?;
+                let value = self.fpr[reg as usize].to_bits() as u32;
+                mem.write_u32(ea, value).map_err(|e| self.fault(e))?;
+                true
+            }
+            (AccessFormat::Float, Argument::FPR(FPR(reg))) => {
+                let ea = self.access_address(&access)?;
+                self.access_float(&access, reg, ea, mem)?;
+                true
+            }
+            (AccessFormat::Quantized { gqr, single }, Argument::FPR(FPR(reg))) => {
+                let ea = self.access_address(&access)?;
+                self.access_quantized(access.kind, gqr, single, reg, ea, mem)?;
+                true
+            }
+            (AccessFormat::Vector, Argument::VR(VR(reg))) => {
+                let ea = self.access_address(&access)?;
+                self.access_vector(&access, reg, ea, mem)?;
+                true
+            }
+            (AccessFormat::Multiple, Argument::GPR(GPR(first))) => {
+                let ea = self.access_address(&access)?;
+                for (i, reg) in (first..32).enumerate() {
+                    let addr = self.trunc(ea.wrapping_add(4 * i as u64));
+                    if access.kind == AccessKind::Load {
+                        let value = mem.read_u32(addr).map_err(|e| self.fault(e))?;
+                        self.gpr[reg as usize] = value as u64;
+                    } else {
+                        let value = self.gpr[reg as usize] as u32;
+                        mem.write_u32(addr, value).map_err(|e| self.fault(e))?;
+                    }
+                }
+                true
+            }
+            (AccessFormat::String, Argument::GPR(GPR(first))) => {
+                let ea = self.access_address(&access)?;
+                let size = access.size.unwrap_or(self.xer & 0x7F);
+                self.access_string(access.kind, first, ea, size, mem)?;
+                true
+            }
+            (AccessFormat::Zero, _) => {
+                let ea = self.access_address(&access)?;
+                let size = access.size.unwrap_or(32) as usize;
+                mem.write(ea, &[0u8; 128][..size]).map_err(|e| self.fault(e))?;
+                true
+            }
+            _ => false,
+        };
+        if handled && access.update {
+            if let Some(base) = access.base {
+                let ea = self.trunc(access.effective_address(self));
+                self.gpr[base.0 as usize] = ea;
+            }
+        }
+        Ok(handled)
+    }
+
+    fn access_integer(
+        &mut self,
+        access: &MemoryAccess,
+        reg: u8,
+        ea: u64,
+        mem: &mut dyn Memory,
+    ) -> Result<(), Exception> {
+        let size = access.size.unwrap_or(4) as usize;
+        let mut buf = [0u8; 8];
+        match access.kind {
+            AccessKind::Load => {
+                mem.read(ea, &mut buf[8 - size..]).map_err(|e| self.fault(e))?;
+                if access.byte_reversed {
+                    buf[8 - size..].reverse();
+                }
+                let mut value = u64::from_be_bytes(buf);
+                if access.signed {
+                    let shift = 64 - 8 * size as u32;
+                    value = (((value << shift) as i64) >> shift) as u64;
+                }
+                self.set_gpr(reg, value);
+                if access.atomic {
+                    self.reservation = Some(ea);
+                }
+            }
+            AccessKind::Store => {
+                if access.atomic {
+                    // Store conditional: only store if the reservation is held
+                    let reserved = self.reservation.take() == Some(ea);
+                    let so = (self.xer >> 31) as u8;
+                    self.set_cr_field(0, if reserved { 0b0010 | so } else { so });
+                    if !reserved {
+                        return Ok(());
+                    }
+                }
+                buf = self.gpr[reg as usize].to_be_bytes();
+                if access.byte_reversed {
+                    buf[8 - size..].reverse();
+                }
+                mem.write(ea, &buf[8 - size..]).map_err(|e| self.fault(e))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Executes a floating-point load or store.
+    ///
+    /// Single-precision values are converted to and from double precision without
+    /// rounding. With paired singles, single-precision loads fill both slots.
+    fn access_float(
+        &mut self,
+        access: &MemoryAccess,
+        reg: u8,
+        ea: u64,
+        mem: &mut dyn Memory,
+    ) -> Result<(), Exception> {
+        let reg = reg as usize;
+        match (access.kind, access.size) {
+            (AccessKind::Load, Some(4)) => {
+                let word = mem.read_u32(ea).map_err(|e| self.fault(e))?;
+                self.fpr[reg] = single_to_double(word);
+                if self.extensions.contains(Extension::PairedSingles) {
+                    self.ps1[reg] = self.fpr[reg];
+                }
+            }
+            (AccessKind::Load, _) => {
+                let bits = mem.read_u64(ea).map_err(|e| self.fault(e))?;
+                self.fpr[reg] = f64::from_bits(bits);
+            }
+            (AccessKind::Store, Some(4)) => {
+                let word = double_to_single(self.fpr[reg]);
+                mem.write_u32(ea, word).map_err(|e| self.fault(e))?;
+            }
+            (AccessKind::Store, _) => {
+                let bits = self.fpr[reg].to_bits();
+                mem.write_u64(ea, bits).map_err(|e| self.fault(e))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Executes a paired-single load or store, converting values as configured
+    /// in the GQR.
+    ///
+    /// If `single` is set, only the first slot is transferred, and loads set the
+    /// second slot to 1.0.
+    fn access_quantized(
+        &mut self,
+        kind: AccessKind,
+        gqr: GQR,
+        single: bool,
+        reg: u8,
+        ea: u64,
+        mem: &mut dyn Memory,
+    ) -> Result<(), Exception> {
+        let reg = reg as usize;
+        let quantization =
+            GqrConfig::from_bits(self.spr[912 + gqr.0 as usize] as u32).quantization(kind);
+        let size = quantization.ty.size() as usize;
+        let count = if single { 1 } else { 2 };
+        if kind == AccessKind::Load {
+            let mut values = [0.0, 1.0];
+            for (i, value) in values.iter_mut().take(count).enumerate() {
+                let addr = self.trunc(ea.wrapping_add((i * size) as u64));
+                let mut buf = [0u8; 4];
+                mem.read(addr, &mut buf[4 - size..]).map_err(|e| self.fault(e))?;
+                *value = dequantize(quantization, u32::from_be_bytes(buf));
+            }
+            [self.fpr[reg], self.ps1[reg]] = values;
+        } else {
+            for (i, value) in [self.fpr[reg], self.ps1[reg]].into_iter().take(count).enumerate() {
+                let addr = self.trunc(ea.wrapping_add((i * size) as u64));
+                let buf = quantize(quantization, value).to_be_bytes();
+                mem.write(addr, &buf[4 - size..]).map_err(|e| self.fault(e))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Executes a vector load or store.
+    ///
+    /// Element loads (`lvebx`, ...) leave the other elements of the register unchanged.
+    /// Left and right loads (`lvlx`, `lvrx`) fill the bytes not loaded with zeros.
+    fn access_vector(
+        &mut self,
+        access: &MemoryAccess,
+        reg: u8,
+        ea: u64,
+        mem: &mut dyn Memory,
+    ) -> Result<(), Exception> {
+        let reg = reg as usize;
+        let offset = (ea & 15) as usize;
+        // The bytes of the register accessed, and the address of the first
+        let (range, addr) = match access.alignment {
+            Alignment::Left => (0..16 - offset, ea),
+            Alignment::Right => (16 - offset..16, ea - offset as u64),
+            _ => (offset..offset + access.size.unwrap_or(16) as usize, ea),
+        };
+        let mut bytes = self.vr[reg].to_be_bytes();
+        match access.kind {
+            AccessKind::Load => {
+                if matches!(access.alignment, Alignment::Left | Alignment::Right) {
+                    bytes = [0; 16];
+                }
+                mem.read(addr, &mut bytes[range]).map_err(|e| self.fault(e))?;
+                self.vr[reg] = u128::from_be_bytes(bytes);
+            }
+            AccessKind::Store => {
+                mem.write(addr, &bytes[range]).map_err(|e| self.fault(e))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Executes `lswi`, `lswx`, `stswi` or `stswx`.
+    ///
+    /// Bytes are transferred to or from consecutive registers starting at `first`,
+    /// four bytes per register, wrapping from r31 to r0.
+    fn access_string(
+        &mut self,
+        kind: AccessKind,
+        first: u8,
+        ea: u64,
+        size: u32,
+        mem: &mut dyn Memory,
+    ) -> Result<(), Exception> {
+        let mut reg = first as usize;
+        for i in 0..size {
+            let addr = self.trunc(ea.wrapping_add(i as u64));
+            let shift = 24 - 8 * (i % 4);
+            if kind == AccessKind::Load {
+                if i % 4 == 0 {
+                    // Unfilled low bytes of the last register are cleared
+                    self.gpr[reg] = 0;
+                }
+                let byte = mem.read_u8(addr).map_err(|e| self.fault(e))?;
+                self.gpr[reg] |= (byte as u64) << shift;
+            } else {
+                let byte = (self.gpr[reg] >> shift) as u8;
+                mem.write_u8(addr, byte).map_err(|e| self.fault(e))?;
+            }
+            if i % 4 == 3 {
+                reg = (reg + 1) % 32;
+            }
+        }
+        Ok(())
+    }
+}
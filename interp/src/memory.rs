@@ -0,0 +1,109 @@
+/// An error returned by [Memory] when an address cannot be accessed.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct MemoryFault {
+    /// The first address that could not be accessed.
+    pub addr: u64,
+}
+
+/// The memory accessed by the interpreter.
+///
+/// Only [Memory::read] and [Memory::write] need to be implemented. Multi-byte values
+/// are big-endian.
+pub trait Memory {
+    /// Reads `buf.len()` bytes starting at `addr`.
+    fn read(&mut self, addr: u64, buf: &mut [u8]) -> Result<(), MemoryFault>;
+
+    /// Writes `data` starting at `addr`.
+    fn write(&mut self, addr: u64, data: &[u8]) -> Result<(), MemoryFault>;
+
+    fn read_u8(&mut self, addr: u64) -> Result<u8, MemoryFault> {
+        let mut buf = [0u8; 1];
+        self.read(addr, &mut buf)?;
+        Ok(buf[0])
+    }
+
+    fn read_u16(&mut self, addr: u64) -> Result<u16, MemoryFault> {
+        let mut buf = [0u8; 2];
+        self.read(addr, &mut buf)?;
+        Ok(u16::from_be_bytes(buf))
+    }
+
+    fn read_u32(&mut self, addr: u64) -> Result<u32, MemoryFault> {
+        let mut buf = [0u8; 4];
+        self.read(addr, &mut buf)?;
+        Ok(u32::from_be_bytes(buf))
+    }
+
+    fn read_u64(&mut self, addr: u64) -> Result<u64, MemoryFault> {
+        let mut buf = [0u8; 8];
+        self.read(addr, &mut buf)?;
+        Ok(u64::from_be_bytes(buf))
+    }
+
+    fn write_u8(&mut self, addr: u64, value: u8) -> Result<(), MemoryFault> {
+        self.write(addr, &[value])
+    }
+
+    fn write_u16(&mut self, addr: u64, value: u16) -> Result<(), MemoryFault> {
+        self.write(addr, &value.to_be_bytes())
+    }
+
+    fn write_u32(&mut self, addr: u64, value: u32) -> Result<(), MemoryFault> {
+        self.write(addr, &value.to_be_bytes())
+    }
+
+    fn write_u64(&mut self, addr: u64, value: u64) -> Result<(), MemoryFault> {
+        self.write(addr, &value.to_be_bytes())
+    }
+}
+
+/// A contiguous block of memory starting at `base`.
+///
+/// ```
+/// use powerpc_interp::{Memory, Ram};
+///
+/// let mut ram = Ram { base: 0x80000000, data: [0u8; 0x100] };
+/// ram.write_u32(0x80000010, 0x12345678).unwrap();
+/// assert_eq!(ram.read_u16(0x80000012), Ok(0x5678));
+/// assert!(ram.read_u32(0x800000FE).is_err());
+/// ```
+#[derive(Clone, Debug)]
+pub struct Ram<T> {
+    /// The address of the first byte.
+    pub base: u64,
+    /// The contents of the memory.
+    pub data: T,
+}
+
+impl<T> Ram<T>
+where
+    T: AsRef<[u8]>,
+{
+    fn range(&self, addr: u64, len: usize) -> Result<core::ops::Range<usize>, MemoryFault> {
+        let size = self.data.as_ref().len() as u64;
+        match addr.checked_sub(self.base) {
+            Some(start) if start <= size && len as u64 <= size - start => {
+                Ok(start as usize..start as usize + len)
+            }
+            Some(start) if start < size => Err(MemoryFault { addr: self.base + size }),
+            _ => Err(MemoryFault { addr }),
+        }
+    }
+}
+
+impl<T> Memory for Ram<T>
+where
+    T: AsRef<[u8]> + AsMut<[u8]>,
+{
+    fn read(&mut self, addr: u64, buf: &mut [u8]) -> Result<(), MemoryFault> {
+        let range = self.range(addr, buf.len())?;
+        buf.copy_from_slice(&self.data.as_ref()[range]);
+        Ok(())
+    }
+
+    fn write(&mut self, addr: u64, data: &[u8]) -> Result<(), MemoryFault> {
+        let range = self.range(addr, data.len())?;
+        self.data.as_mut()[range].copy_from_slice(data);
+        Ok(())
+    }
+}
@@ -0,0 +1,138 @@
+use crate::float::{double_to_single, single_to_double};
+use crate::integer::modifier;
+use crate::{Cpu, Exception};
+use powerpc::{FieldId, Ins, Opcode, Quantization, QuantizedType};
+
+/// Converts a value loaded by a paired-single load.
+pub(crate) fn dequantize(quantization: Quantization, raw: u32) -> f64 {
+    let integer = match quantization.ty {
+        QuantizedType::Float => return single_to_double(raw),
+        QuantizedType::U8 => raw as u8 as f64,
+        QuantizedType::U16 => raw as u16 as f64,
+        QuantizedType::S8 => raw as i8 as f64,
+        QuantizedType::S16 => raw as i16 as f64,
+    };
+    integer * 2f64.powi(-quantization.scale as i32)
+}
+
+/// Converts a value to be stored by a paired-single store.
+///
+/// Integers are truncated toward zero and saturated, and NaNs become zero.
+pub(crate) fn quantize(quantization: Quantization, value: f64) -> u32 {
+    let scaled = value * 2f64.powi(quantization.scale as i32);
+    match quantization.ty {
+        QuantizedType::Float => double_to_single(value),
+        QuantizedType::U8 => scaled as u8 as u32,
+        QuantizedType::U16 => scaled as u16 as u32,
+        QuantizedType::S8 => scaled as i8 as u8 as u32,
+        QuantizedType::S16 => scaled as i16 as u16 as u32,
+    }
+}
+
+/// Converts the estimate of `ps_rsqrte` to single precision.
+#[inline]
+fn to_single(value: f64) -> f64 {
+    if value.is_nan() {
+        value
+    } else {
+        value as f32 as f64
+    }
+}
+
+impl Cpu {
+    /// Writes both slots of a paired-single result, setting FPSCR\[FPRF\] from the
+    /// first.
+    fn set_pair(&mut self, reg: usize, ps0: f64, ps1: f64) {
+        self.fpr[reg] = ps0;
+        self.ps1[reg] = ps1;
+        self.set_fprf(ps0, true);
+    }
+
+    /// Executes a paired-single arithmetic, compare, move or merge instruction.
+    ///
+    /// Each slot is rounded to single precision as by the equivalent scalar
+    /// instruction. The second slot is evaluated first, so FPSCR\[FR\] and
+    /// FPSCR\[FI\] describe the first. If an enabled exception suppresses either
+    /// result, the target register is left unchanged.
+    ///
+    /// Returns false if the instruction is not handled by this unit.
+    pub(crate) fn execute_paired(&mut self, ins: Ins) -> Result<bool, Exception> {
+        use Opcode::*;
+        let frd = ins.field_frd() as usize;
+        let pair = |reg: u8| [self.fpr[reg as usize], self.ps1[reg as usize]];
+        let a = pair(ins.field_fra());
+        let b = pair(ins.field_frb());
+        let c = pair(ins.field_frc());
+        match ins.op {
+            PsAdd | PsSub | PsMul | PsDiv | PsMadd | PsMsub | PsNmadd | PsNmsub | PsMuls0
+            | PsMuls1 | PsMadds0 | PsMadds1 => {
+                let op = match ins.op {
+                    PsAdd => Fadds,
+                    PsSub => Fsubs,
+                    PsMul | PsMuls0 | PsMuls1 => Fmuls,
+                    PsDiv => Fdivs,
+                    PsMsub => Fmsubs,
+                    PsNmadd => Fnmadds,
+                    PsNmsub => Fnmsubs,
+                    _ => Fmadds,
+                };
+                let c = match ins.op {
+                    PsMuls0 | PsMadds0 => [c[0], c[0]],
+                    PsMuls1 | PsMadds1 => [c[1], c[1]],
+                    _ => c,
+                };
+                let ps1 = self.arithmetic(op, a[1], b[1], c[1], true);
+                let ps0 = self.arithmetic(op, a[0], b[0], c[0], true);
+                if let (Some(ps0), Some(ps1)) = (ps0, ps1) {
+                    self.set_pair(frd, ps0, ps1);
+                }
+            }
+            PsSum0 | PsSum1 => {
+                if let Some(sum) = self.arithmetic(Fadds, a[0], b[1], 0.0, true) {
+                    let (ps0, ps1) = if ins.op == PsSum0 { (sum, c[1]) } else { (c[0], sum) };
+                    [self.fpr[frd], self.ps1[frd]] = [ps0, ps1];
+                    self.set_fprf(sum, true);
+                }
+            }
+            PsRes | PsRsqrte => {
+                let sqrt = ins.op == PsRsqrte;
+                let ps1 = self.estimate(sqrt, b[1]);
+                let ps0 = self.estimate(sqrt, b[0]);
+                if let (Some(ps0), Some(ps1)) = (ps0, ps1) {
+                    self.set_pair(frd, to_single(ps0), to_single(ps1));
+                }
+            }
+            PsSel => {
+                let select = |i: usize| if a[i] >= 0.0 { c[i] } else { b[i] };
+                [self.fpr[frd], self.ps1[frd]] = [select(0), select(1)];
+            }
+            PsMr | PsNeg | PsAbs | PsNabs => {
+                let apply = |value: f64| {
+                    let bits = value.to_bits();
+                    f64::from_bits(match ins.op {
+                        PsNeg => bits ^ (1 << 63),
+                        PsAbs => bits & !(1 << 63),
+                        PsNabs => bits | (1 << 63),
+                        _ => bits,
+                    })
+                };
+                [self.fpr[frd], self.ps1[frd]] = [apply(b[0]), apply(b[1])];
+            }
+            PsMerge00 => [self.fpr[frd], self.ps1[frd]] = [a[0], b[0]],
+            PsMerge01 => [self.fpr[frd], self.ps1[frd]] = [a[0], b[1]],
+            PsMerge10 => [self.fpr[frd], self.ps1[frd]] = [a[1], b[0]],
+            PsMerge11 => [self.fpr[frd], self.ps1[frd]] = [a[1], b[1]],
+            PsCmpu0 | PsCmpo0 => {
+                self.compare_float(ins.field_crfd(), ins.op == PsCmpo0, a[0], b[0])
+            }
+            PsCmpu1 | PsCmpo1 => {
+                self.compare_float(ins.field_crfd(), ins.op == PsCmpo1, a[1], b[1])
+            }
+            _ => return Ok(false),
+        }
+        if modifier(ins, FieldId::Rc) {
+            self.set_cr_field(1, (self.fpscr >> 28) as u8);
+        }
+        Ok(true)
+    }
+}
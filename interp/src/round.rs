@@ -0,0 +1,362 @@
+use core::cmp::Ordering;
+
+/// An IEEE 754 rounding mode, as selected by FPSCR\[RN\].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum RoundingMode {
+    /// Round to nearest, ties to even.
+    #[default]
+    Nearest,
+    /// Round toward zero.
+    Zero,
+    /// Round toward positive infinity.
+    Up,
+    /// Round toward negative infinity.
+    Down,
+}
+
+impl RoundingMode {
+    /// Decodes the RN field of FPSCR.
+    #[inline]
+    pub const fn from_bits(bits: u32) -> Self {
+        match bits & 3 {
+            0 => RoundingMode::Nearest,
+            1 => RoundingMode::Zero,
+            2 => RoundingMode::Up,
+            _ => RoundingMode::Down,
+        }
+    }
+}
+
+/// The result of an operation rounded to the nearest double, and the sign of
+/// `exact - value`.
+///
+/// When the operation overflows, `value` is infinite and `error` points back
+/// toward the finite exact result.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Inexact {
+    pub value: f64,
+    pub error: Ordering,
+}
+
+/// A rounded result.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(crate) struct Rounded {
+    pub value: f64,
+    /// The result differs from the exact value. (FPSCR\[FI\])
+    pub inexact: bool,
+    /// The magnitude of the result is greater than the exact value. (FPSCR\[FR\])
+    pub rounded_up: bool,
+    /// The exact result overflowed the target format. (FPSCR\[OX\])
+    pub overflow: bool,
+    /// The exact result is nonzero and smaller than the smallest normal number.
+    pub tiny: bool,
+}
+
+#[inline]
+fn sign(value: f64) -> Ordering {
+    if value > 0.0 {
+        Ordering::Greater
+    } else if value < 0.0 {
+        Ordering::Less
+    } else {
+        Ordering::Equal
+    }
+}
+
+/// Returns the exact error `a + b - s`, where `s` is the rounded sum. (Knuth's TwoSum)
+#[inline]
+fn two_sum(a: f64, b: f64, s: f64) -> f64 {
+    let bv = s - a;
+    let av = s - bv;
+    (a - av) + (b - bv)
+}
+
+/// Returns the sign of the exact sum of `terms`.
+///
+/// The terms are repeatedly replaced by an equal-sum sequence until the largest
+/// term is the faithfully rounded sum, which has the same sign as the exact sum.
+fn sum_sign(terms: &mut [f64]) -> Ordering {
+    for _ in 0..terms.len() * 2 {
+        let mut changed = false;
+        for i in 1..terms.len() {
+            let s = terms[i - 1] + terms[i];
+            let e = two_sum(terms[i - 1], terms[i], s);
+            changed |= s != terms[i] || e != terms[i - 1];
+            terms[i] = s;
+            terms[i - 1] = e;
+        }
+        if !changed {
+            break;
+        }
+    }
+    terms.iter().rev().map(|&t| sign(t)).find(|&s| s != Ordering::Equal).unwrap_or(Ordering::Equal)
+}
+
+impl Inexact {
+    #[inline]
+    pub fn exact(value: f64) -> Self {
+        Self { value, error: Ordering::Equal }
+    }
+
+    /// The result of an operation that rounded to infinity, which overflowed
+    /// if all operands were finite.
+    #[inline]
+    fn infinite(value: f64, operands: &[f64]) -> Self {
+        if operands.iter().all(|v| v.is_finite()) {
+            Self { value, error: sign(value).reverse() }
+        } else {
+            Self::exact(value)
+        }
+    }
+
+    pub fn add(a: f64, b: f64) -> Self {
+        let value = a + b;
+        if value.is_infinite() {
+            return Self::infinite(value, &[a, b]);
+        }
+        Self { value, error: sign(two_sum(a, b, value)) }
+    }
+
+    pub fn mul(a: f64, b: f64) -> Self {
+        let value = a * b;
+        if value.is_infinite() {
+            return Self::infinite(value, &[a, b]);
+        }
+        if a == 0.0 || b == 0.0 || !a.is_finite() || !b.is_finite() {
+            return Self::exact(value);
+        }
+        // Scale the operands to [1, 2) so that the residual cannot underflow
+        let (ma, ea) = frexp(a);
+        let (mb, eb) = frexp(b);
+        let scaled = ldexp(value, -(ea + eb));
+        Self { value, error: sign(ma.mul_add(mb, -scaled)) }
+    }
+
+    pub fn div(a: f64, b: f64) -> Self {
+        let value = a / b;
+        if value.is_infinite() {
+            return Self::infinite(value, &[a, b]);
+        }
+        if a == 0.0 || b == 0.0 || !a.is_finite() || !b.is_finite() {
+            return Self::exact(value);
+        }
+        let (ma, ea) = frexp(a);
+        let (mb, eb) = frexp(b);
+        let scaled = ldexp(value, eb - ea);
+        // ma - scaled * mb has the sign of (ma / mb - scaled) * mb
+        let remainder = (-scaled).mul_add(mb, ma);
+        Self { value, error: if b < 0.0 { sign(remainder).reverse() } else { sign(remainder) } }
+    }
+
+    pub fn sqrt(a: f64) -> Self {
+        let value = a.sqrt();
+        if a <= 0.0 || !a.is_finite() {
+            return Self::exact(value);
+        }
+        let (mut m, mut e) = frexp(a);
+        if e % 2 != 0 {
+            m *= 2.0;
+            e -= 1;
+        }
+        let scaled = ldexp(value, -e / 2);
+        Self { value, error: sign((-scaled).mul_add(scaled, m)) }
+    }
+
+    /// `a * c + b` with a single rounding.
+    pub fn mul_add(a: f64, c: f64, b: f64) -> Self {
+        let value = a.mul_add(c, b);
+        if value.is_infinite() {
+            return Self::infinite(value, &[a, c, b]);
+        }
+        if a == 0.0 || c == 0.0 || !a.is_finite() || !c.is_finite() || !b.is_finite() {
+            return Self::exact(value);
+        }
+        let (ma, ea) = frexp(a);
+        let (mc, ec) = frexp(c);
+        // Scale a small product up so that its residual cannot underflow
+        let k = (-(ea + ec)).max(0);
+        let fits = |v: f64| v == 0.0 || frexp(v).1 + k <= 1020;
+        if !fits(b) || !fits(value) {
+            // The product is far below the precision of b
+            return match sign(b - value) {
+                Ordering::Equal => Self { value, error: sign(ma * mc) },
+                error => Self { value, error },
+            };
+        }
+        let (a, c) = if k > 0 { (ma, mc) } else { (a, c) };
+        let (b, scaled) = (ldexp(b, k), ldexp(value, k));
+        let product = a * c;
+        let product_error = a.mul_add(c, -product);
+        Self { value, error: sum_sign(&mut [product_error, product, b, -scaled]) }
+    }
+
+    /// Compares a rounded result with the exact value.
+    #[inline]
+    fn compare(&self, result: f64) -> Ordering {
+        match result.partial_cmp(&self.value) {
+            Some(Ordering::Equal) | None => self.error.reverse(),
+            Some(ordering) => ordering,
+        }
+    }
+
+    /// The sign of the exact value.
+    #[inline]
+    fn exact_sign(&self) -> Ordering {
+        match sign(self.value) {
+            Ordering::Equal => self.error,
+            sign => sign,
+        }
+    }
+
+    fn finish(&self, value: f64, nearest: f64, min_normal: f64) -> Rounded {
+        let ordering = self.compare(value);
+        let rounded_up = match self.exact_sign() {
+            Ordering::Greater => ordering == Ordering::Greater,
+            Ordering::Less => ordering == Ordering::Less,
+            Ordering::Equal => false,
+        };
+        let magnitude = self.value.abs();
+        Rounded {
+            value,
+            inexact: ordering != Ordering::Equal,
+            rounded_up,
+            overflow: nearest.is_infinite()
+                && !(self.value.is_infinite() && self.error == Ordering::Equal),
+            tiny: self.exact_sign() != Ordering::Equal
+                && (magnitude < min_normal
+                    || (magnitude == min_normal && self.error == sign(self.value).reverse())),
+        }
+    }
+
+    /// Rounds the exact value to double precision.
+    pub fn round_double(&self, mode: RoundingMode) -> Rounded {
+        let value = match (mode, self.error) {
+            (_, Ordering::Equal) | (RoundingMode::Nearest, _) => self.value,
+            (RoundingMode::Up, Ordering::Greater) => next_up(self.value),
+            (RoundingMode::Down, Ordering::Less) => next_down(self.value),
+            (RoundingMode::Zero, Ordering::Less) if self.exact_sign() == Ordering::Greater => {
+                next_down(self.value)
+            }
+            (RoundingMode::Zero, Ordering::Greater) if self.exact_sign() == Ordering::Less => {
+                next_up(self.value)
+            }
+            _ => self.value,
+        };
+        self.finish(value, self.value, f64::MIN_POSITIVE)
+    }
+
+    /// Rounds the exact value to single precision.
+    pub fn round_single(&self, mode: RoundingMode) -> Rounded {
+        let value = self.single(mode) as f64;
+        self.finish(value, self.single(RoundingMode::Nearest) as f64, f32::MIN_POSITIVE as f64)
+    }
+
+    fn single(&self, mode: RoundingMode) -> f32 {
+        let r = self.value;
+        let s = r as f32;
+        if s as f64 == r {
+            // The nearest double is a single, so the exact value is within half an ulp of it
+            let stepped = match (mode, self.error) {
+                (RoundingMode::Up, Ordering::Greater) => next_up_f32(s),
+                (RoundingMode::Down, Ordering::Less) => next_down_f32(s),
+                (RoundingMode::Zero, Ordering::Less) if self.exact_sign() == Ordering::Greater => {
+                    next_down_f32(s)
+                }
+                (RoundingMode::Zero, Ordering::Greater) if self.exact_sign() == Ordering::Less => {
+                    next_up_f32(s)
+                }
+                _ => s,
+            };
+            // Keep the sign of a tiny exact value rounded to zero
+            if stepped == 0.0 && self.exact_sign() == Ordering::Less {
+                -0.0
+            } else {
+                stepped
+            }
+        } else {
+            let (lo, hi) = if (s as f64) < r { (s, next_up_f32(s)) } else { (next_down_f32(s), s) };
+            match mode {
+                RoundingMode::Nearest => {
+                    // Infinity is treated as the next power of two when finding the midpoint
+                    let widen = |v: f32| {
+                        if v.is_infinite() {
+                            2f64.powi(128).copysign(v as f64)
+                        } else {
+                            v as f64
+                        }
+                    };
+                    let mid = (widen(lo) + widen(hi)) / 2.0;
+                    match r.partial_cmp(&mid).unwrap_or(Ordering::Equal).then(self.error) {
+                        Ordering::Less => lo,
+                        Ordering::Greater => hi,
+                        Ordering::Equal if lo.to_bits() & 1 == 0 => lo,
+                        Ordering::Equal => hi,
+                    }
+                }
+                RoundingMode::Zero if r > 0.0 => lo,
+                RoundingMode::Zero => hi,
+                RoundingMode::Up => hi,
+                RoundingMode::Down => lo,
+            }
+        }
+    }
+}
+
+/// Splits a finite nonzero value into a significand in [1, 2) and an exponent.
+fn frexp(value: f64) -> (f64, i32) {
+    let (value, bias) =
+        if value.abs() < f64::MIN_POSITIVE { (value * 2f64.powi(64), 64) } else { (value, 0) };
+    let bits = value.to_bits();
+    let exponent = ((bits >> 52) & 0x7FF) as i32 - 1023;
+    (f64::from_bits((bits & !(0x7FF << 52)) | (1023 << 52)), exponent - bias)
+}
+
+/// Multiplies by `2^exponent`, which is exact unless the result overflows or
+/// becomes denormal.
+fn ldexp(mut value: f64, mut exponent: i32) -> f64 {
+    while exponent > 1000 {
+        value *= 2f64.powi(1000);
+        exponent -= 1000;
+    }
+    while exponent < -1000 {
+        value *= 2f64.powi(-1000);
+        exponent += 1000;
+    }
+    value * 2f64.powi(exponent)
+}
+
+/// The next double toward positive infinity.
+pub(crate) fn next_up(value: f64) -> f64 {
+    let bits = value.to_bits();
+    if value.is_nan() || value == f64::INFINITY {
+        value
+    } else if value == 0.0 {
+        f64::from_bits(1)
+    } else if value > 0.0 {
+        f64::from_bits(bits + 1)
+    } else {
+        f64::from_bits(bits - 1)
+    }
+}
+
+/// The next double toward negative infinity.
+pub(crate) fn next_down(value: f64) -> f64 {
+    -next_up(-value)
+}
+
+fn next_up_f32(value: f32) -> f32 {
+    let bits = value.to_bits();
+    if value.is_nan() || value == f32::INFINITY {
+        value
+    } else if value == 0.0 {
+        f32::from_bits(1)
+    } else if value > 0.0 {
+        f32::from_bits(bits + 1)
+    } else {
+        f32::from_bits(bits - 1)
+    }
+}
+
+fn next_down_f32(value: f32) -> f32 {
+    -next_up_f32(-value)
+}
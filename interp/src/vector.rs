@@ -0,0 +1,557 @@
+use crate::float::round_ties_even;
+use crate::integer::modifier;
+use crate::{Cpu, Exception};
+use core::array;
+use powerpc::{FieldId, Ins, Opcode};
+
+pub(crate) const DEFAULT_NAN: u32 = 0x7FC0_0000;
+pub(crate) const QUIET_BIT: u32 = 0x0040_0000;
+
+#[inline]
+fn bytes(v: u128) -> [u8; 16] {
+    v.to_be_bytes()
+}
+
+#[inline]
+fn halves(v: u128) -> [u16; 8] {
+    array::from_fn(|i| (v >> (112 - 16 * i)) as u16)
+}
+
+#[inline]
+pub(crate) fn words(v: u128) -> [u32; 4] {
+    array::from_fn(|i| (v >> (96 - 32 * i)) as u32)
+}
+
+#[inline]
+fn from_bytes(lanes: [u8; 16]) -> u128 {
+    u128::from_be_bytes(lanes)
+}
+
+#[inline]
+fn from_halves(lanes: [u16; 8]) -> u128 {
+    lanes.iter().fold(0, |acc, &x| acc << 16 | x as u128)
+}
+
+#[inline]
+pub(crate) fn from_words(lanes: [u32; 4]) -> u128 {
+    lanes.iter().fold(0, |acc, &x| acc << 32 | x as u128)
+}
+
+fn map8(a: u128, b: u128, mut f: impl FnMut(u8, u8) -> u8) -> u128 {
+    let (a, b) = (bytes(a), bytes(b));
+    from_bytes(array::from_fn(|i| f(a[i], b[i])))
+}
+
+fn map16(a: u128, b: u128, mut f: impl FnMut(u16, u16) -> u16) -> u128 {
+    let (a, b) = (halves(a), halves(b));
+    from_halves(array::from_fn(|i| f(a[i], b[i])))
+}
+
+fn map32(a: u128, b: u128, mut f: impl FnMut(u32, u32) -> u32) -> u128 {
+    let (a, b) = (words(a), words(b));
+    from_words(array::from_fn(|i| f(a[i], b[i])))
+}
+
+/// Returns all ones if `value` is true, for compare results.
+#[inline]
+fn mask<T: Default + core::ops::Not<Output = T>>(value: bool) -> T {
+    if value {
+        !T::default()
+    } else {
+        T::default()
+    }
+}
+
+/// Clamps a value to `min..=max`, setting `sat` if it was out of range.
+#[inline]
+fn saturate(value: i64, min: i64, max: i64, sat: &mut bool) -> i64 {
+    if value < min || value > max {
+        *sat = true;
+    }
+    value.clamp(min, max)
+}
+
+/// Interleaves the high (or low) halves of the elements of `a` and `b`.
+fn merge<T: Copy, const N: usize>(a: [T; N], b: [T; N], low: bool) -> [T; N] {
+    let base = if low { N / 2 } else { 0 };
+    array::from_fn(|i| if i % 2 == 0 { a[base + i / 2] } else { b[base + i / 2] })
+}
+
+/// Concatenates the elements of `a` and `b`, for pack instructions.
+fn concat<T: Copy + Default, const N: usize, const M: usize>(a: [T; N], b: [T; N]) -> [T; M] {
+    array::from_fn(|i| if i < N { a[i] } else { b[i - N] })
+}
+
+/// Expands a 1:5:5:5 pixel to four bytes, sign-extending the first field.
+#[inline]
+fn unpack_pixel(pixel: u16) -> u32 {
+    let alpha = if pixel & 0x8000 != 0 { 0xFF } else { 0 };
+    alpha << 24
+        | ((pixel as u32 >> 10) & 0x1F) << 16
+        | ((pixel as u32 >> 5) & 0x1F) << 8
+        | pixel as u32 & 0x1F
+}
+
+/// Packs the low bit of the first byte and the high five bits of the other bytes.
+#[inline]
+fn pack_pixel(word: u32) -> u16 {
+    ((word >> 9) & 0x8000 | (word >> 9) & 0x7C00 | (word >> 6) & 0x3E0 | (word >> 3) & 0x1F) as u16
+}
+
+/// The value of CR6 after a vector compare with Rc set.
+///
+/// Bounds compares set only bit 2, if all elements are within their bounds.
+pub(crate) fn compare_cr6(bounds: bool, result: u128) -> u8 {
+    if bounds {
+        if result == 0 {
+            0b0010
+        } else {
+            0
+        }
+    } else {
+        ((result == u128::MAX) as u8 * 0b1000) | ((result == 0) as u8 * 0b0010)
+    }
+}
+
+impl Cpu {
+    /// Non-Java mode: denormal operands and results are flushed to zero.
+    pub const VSCR_NJ: u32 = 0x0001_0000;
+    /// Saturation, set by integer instructions whose results were clamped.
+    pub const VSCR_SAT: u32 = 0x0000_0001;
+
+    /// Flushes a denormal to zero, preserving its sign, in non-Java mode.
+    #[inline]
+    pub(crate) fn flush(&self, value: f32) -> f32 {
+        if self.vscr & Self::VSCR_NJ != 0 && value.is_subnormal() {
+            f32::from_bits(value.to_bits() & 0x8000_0000)
+        } else {
+            value
+        }
+    }
+
+    /// Applies a floating-point operation to each word.
+    ///
+    /// NaN operands are quieted and returned, in order of priority `a`, `b`, `c`.
+    /// NaNs produced by the operation become the default NaN.
+    pub(crate) fn map_float(
+        &self,
+        a: u128,
+        b: u128,
+        c: u128,
+        f: impl Fn(f32, f32, f32) -> f32,
+    ) -> u128 {
+        let (a, b, c) = (words(a), words(b), words(c));
+        from_words(array::from_fn(|i| {
+            let operands = [a[i], b[i], c[i]].map(|x| self.flush(f32::from_bits(x)));
+            if let Some(nan) = operands.iter().find(|x| x.is_nan()) {
+                return nan.to_bits() | QUIET_BIT;
+            }
+            let result = self.flush(f(operands[0], operands[1], operands[2]));
+            if result.is_nan() {
+                DEFAULT_NAN
+            } else {
+                result.to_bits()
+            }
+        }))
+    }
+
+    /// Compares each word as floats. Comparisons with NaNs are false.
+    fn compare_floats(&self, a: u128, b: u128, f: impl Fn(f32, f32) -> bool) -> u128 {
+        map32(a, b, |x, y| mask(f(self.flush(f32::from_bits(x)), self.flush(f32::from_bits(y)))))
+    }
+
+    /// Computes the result of a vector integer, floating-point, permute or
+    /// compare instruction.
+    ///
+    /// `imm` is the immediate operand: UIMM, SIMM or SHB. Sets VSCR\[SAT\] if a
+    /// result saturated. Returns `None` if `op` is not handled.
+    pub(crate) fn vector_op(
+        &mut self,
+        op: Opcode,
+        a: u128,
+        b: u128,
+        c: u128,
+        imm: u32,
+    ) -> Option<u128> {
+        use Opcode::*;
+        let mut sat = false;
+        let s = &mut sat;
+        let result = match op {
+            // Integer arithmetic
+            Vaddubm => map8(a, b, u8::wrapping_add),
+            Vadduhm => map16(a, b, u16::wrapping_add),
+            Vadduwm => map32(a, b, u32::wrapping_add),
+            Vaddubs => map8(a, b, |x, y| saturate(x as i64 + y as i64, 0, 0xFF, s) as u8),
+            Vadduhs => map16(a, b, |x, y| saturate(x as i64 + y as i64, 0, 0xFFFF, s) as u16),
+            Vadduws => {
+                map32(a, b, |x, y| saturate(x as i64 + y as i64, 0, u32::MAX as i64, s) as u32)
+            }
+            Vaddsbs => map8(a, b, |x, y| {
+                saturate(x as i8 as i64 + y as i8 as i64, i8::MIN as i64, i8::MAX as i64, s) as u8
+            }),
+            Vaddshs => map16(a, b, |x, y| {
+                saturate(x as i16 as i64 + y as i16 as i64, i16::MIN as i64, i16::MAX as i64, s)
+                    as u16
+            }),
+            Vaddsws => map32(a, b, |x, y| {
+                saturate(x as i32 as i64 + y as i32 as i64, i32::MIN as i64, i32::MAX as i64, s)
+                    as u32
+            }),
+            Vaddcuw => map32(a, b, |x, y| x.overflowing_add(y).1 as u32),
+            Vsububm => map8(a, b, u8::wrapping_sub),
+            Vsubuhm => map16(a, b, u16::wrapping_sub),
+            Vsubuwm => map32(a, b, u32::wrapping_sub),
+            Vsububs => map8(a, b, |x, y| saturate(x as i64 - y as i64, 0, 0xFF, s) as u8),
+            Vsubuhs => map16(a, b, |x, y| saturate(x as i64 - y as i64, 0, 0xFFFF, s) as u16),
+            Vsubuws => {
+                map32(a, b, |x, y| saturate(x as i64 - y as i64, 0, u32::MAX as i64, s) as u32)
+            }
+            Vsubsbs => map8(a, b, |x, y| {
+                saturate(x as i8 as i64 - y as i8 as i64, i8::MIN as i64, i8::MAX as i64, s) as u8
+            }),
+            Vsubshs => map16(a, b, |x, y| {
+                saturate(x as i16 as i64 - y as i16 as i64, i16::MIN as i64, i16::MAX as i64, s)
+                    as u16
+            }),
+            Vsubsws => map32(a, b, |x, y| {
+                saturate(x as i32 as i64 - y as i32 as i64, i32::MIN as i64, i32::MAX as i64, s)
+                    as u32
+            }),
+            Vsubcuw => map32(a, b, |x, y| (x >= y) as u32),
+            Vavgub => map8(a, b, |x, y| ((x as u32 + y as u32 + 1) >> 1) as u8),
+            Vavguh => map16(a, b, |x, y| ((x as u32 + y as u32 + 1) >> 1) as u16),
+            Vavguw => map32(a, b, |x, y| ((x as u64 + y as u64 + 1) >> 1) as u32),
+            Vavgsb => map8(a, b, |x, y| ((x as i8 as i32 + y as i8 as i32 + 1) >> 1) as u8),
+            Vavgsh => map16(a, b, |x, y| ((x as i16 as i32 + y as i16 as i32 + 1) >> 1) as u16),
+            Vavgsw => map32(a, b, |x, y| ((x as i32 as i64 + y as i32 as i64 + 1) >> 1) as u32),
+            Vmaxub => map8(a, b, u8::max),
+            Vmaxuh => map16(a, b, u16::max),
+            Vmaxuw => map32(a, b, u32::max),
+            Vmaxsb => map8(a, b, |x, y| (x as i8).max(y as i8) as u8),
+            Vmaxsh => map16(a, b, |x, y| (x as i16).max(y as i16) as u16),
+            Vmaxsw => map32(a, b, |x, y| (x as i32).max(y as i32) as u32),
+            Vminub => map8(a, b, u8::min),
+            Vminuh => map16(a, b, u16::min),
+            Vminuw => map32(a, b, u32::min),
+            Vminsb => map8(a, b, |x, y| (x as i8).min(y as i8) as u8),
+            Vminsh => map16(a, b, |x, y| (x as i16).min(y as i16) as u16),
+            Vminsw => map32(a, b, |x, y| (x as i32).min(y as i32) as u32),
+
+            // Integer multiply
+            Vmuleub | Vmuloub | Vmulesb | Vmulosb => {
+                let odd = matches!(op, Vmuloub | Vmulosb);
+                let signed = matches!(op, Vmulesb | Vmulosb);
+                let (a, b) = (bytes(a), bytes(b));
+                from_halves(array::from_fn(|i| {
+                    let (x, y) = (a[2 * i + odd as usize], b[2 * i + odd as usize]);
+                    if signed {
+                        (x as i8 as i16 * y as i8 as i16) as u16
+                    } else {
+                        x as u16 * y as u16
+                    }
+                }))
+            }
+            Vmuleuh | Vmulouh | Vmulesh | Vmulosh => {
+                let odd = matches!(op, Vmulouh | Vmulosh);
+                let signed = matches!(op, Vmulesh | Vmulosh);
+                let (a, b) = (halves(a), halves(b));
+                from_words(array::from_fn(|i| {
+                    let (x, y) = (a[2 * i + odd as usize], b[2 * i + odd as usize]);
+                    if signed {
+                        (x as i16 as i32 * y as i16 as i32) as u32
+                    } else {
+                        x as u32 * y as u32
+                    }
+                }))
+            }
+            Vmhaddshs | Vmhraddshs => {
+                let round = if op == Vmhraddshs { 0x4000 } else { 0 };
+                let (a, b, c) = (halves(a), halves(b), halves(c));
+                from_halves(array::from_fn(|i| {
+                    let product = a[i] as i16 as i64 * b[i] as i16 as i64 + round;
+                    let sum = (product >> 15) + c[i] as i16 as i64;
+                    saturate(sum, i16::MIN as i64, i16::MAX as i64, s) as u16
+                }))
+            }
+            Vmladduhm => {
+                let (a, b, c) = (halves(a), halves(b), halves(c));
+                from_halves(array::from_fn(|i| a[i].wrapping_mul(b[i]).wrapping_add(c[i])))
+            }
+            Vmsumubm | Vmsummbm => {
+                let (a, b, c) = (bytes(a), bytes(b), words(c));
+                from_words(array::from_fn(|i| {
+                    (0..4).fold(c[i], |acc, j| {
+                        let (x, y) = (a[4 * i + j], b[4 * i + j] as u32);
+                        let x = if op == Vmsummbm { x as i8 as u32 } else { x as u32 };
+                        acc.wrapping_add(x.wrapping_mul(y))
+                    })
+                }))
+            }
+            Vmsumuhm | Vmsumuhs | Vmsumshm | Vmsumshs => {
+                let signed = matches!(op, Vmsumshm | Vmsumshs);
+                let (a, b, c) = (halves(a), halves(b), words(c));
+                from_words(array::from_fn(|i| {
+                    let element = |x: u16| if signed { x as i16 as i64 } else { x as i64 };
+                    let addend = if signed { c[i] as i32 as i64 } else { c[i] as i64 };
+                    let sum = (0..2)
+                        .fold(addend, |acc, j| acc + element(a[2 * i + j]) * element(b[2 * i + j]));
+                    match op {
+                        Vmsumuhs => saturate(sum, 0, u32::MAX as i64, s) as u32,
+                        Vmsumshs => saturate(sum, i32::MIN as i64, i32::MAX as i64, s) as u32,
+                        _ => sum as u32,
+                    }
+                }))
+            }
+            Vsumsws => {
+                let sum = words(a)
+                    .iter()
+                    .fold(words(b)[3] as i32 as i64, |acc, &x| acc + x as i32 as i64);
+                saturate(sum, i32::MIN as i64, i32::MAX as i64, s) as u32 as u128
+            }
+            Vsum2sws => {
+                let (a, b) = (words(a), words(b));
+                from_words(array::from_fn(|i| {
+                    if i % 2 == 0 {
+                        return 0;
+                    }
+                    let sum = a[i - 1] as i32 as i64 + a[i] as i32 as i64 + b[i] as i32 as i64;
+                    saturate(sum, i32::MIN as i64, i32::MAX as i64, s) as u32
+                }))
+            }
+            Vsum4sbs | Vsum4ubs => {
+                let (a, b) = (bytes(a), words(b));
+                from_words(array::from_fn(|i| {
+                    let elements = a[4 * i..4 * i + 4].iter();
+                    if op == Vsum4ubs {
+                        let sum = elements.fold(b[i] as i64, |acc, &x| acc + x as i64);
+                        saturate(sum, 0, u32::MAX as i64, s) as u32
+                    } else {
+                        let sum = elements.fold(b[i] as i32 as i64, |acc, &x| acc + x as i8 as i64);
+                        saturate(sum, i32::MIN as i64, i32::MAX as i64, s) as u32
+                    }
+                }))
+            }
+            Vsum4shs => {
+                let (a, b) = (halves(a), words(b));
+                from_words(array::from_fn(|i| {
+                    let sum =
+                        b[i] as i32 as i64 + a[2 * i] as i16 as i64 + a[2 * i + 1] as i16 as i64;
+                    saturate(sum, i32::MIN as i64, i32::MAX as i64, s) as u32
+                }))
+            }
+
+            // Logical
+            Vand => a & b,
+            Vandc => a & !b,
+            Vnor => !(a | b),
+            Vor => a | b,
+            Vxor => a ^ b,
+            Vsel => (a & !c) | (b & c),
+
+            // Integer compare
+            Vcmpequb => map8(a, b, |x, y| mask(x == y)),
+            Vcmpequh => map16(a, b, |x, y| mask(x == y)),
+            Vcmpequw => map32(a, b, |x, y| mask(x == y)),
+            Vcmpgtub => map8(a, b, |x, y| mask(x > y)),
+            Vcmpgtuh => map16(a, b, |x, y| mask(x > y)),
+            Vcmpgtuw => map32(a, b, |x, y| mask(x > y)),
+            Vcmpgtsb => map8(a, b, |x, y| mask(x as i8 > y as i8)),
+            Vcmpgtsh => map16(a, b, |x, y| mask(x as i16 > y as i16)),
+            Vcmpgtsw => map32(a, b, |x, y| mask(x as i32 > y as i32)),
+
+            // Rotate and shift
+            Vrlb => map8(a, b, |x, y| x.rotate_left(y as u32 & 7)),
+            Vrlh => map16(a, b, |x, y| x.rotate_left(y as u32 & 15)),
+            Vrlw => map32(a, b, |x, y| x.rotate_left(y & 31)),
+            Vslb => map8(a, b, |x, y| x << (y & 7)),
+            Vslh => map16(a, b, |x, y| x << (y & 15)),
+            Vslw => map32(a, b, |x, y| x << (y & 31)),
+            Vsrb => map8(a, b, |x, y| x >> (y & 7)),
+            Vsrh => map16(a, b, |x, y| x >> (y & 15)),
+            Vsrw => map32(a, b, |x, y| x >> (y & 31)),
+            Vsrab => map8(a, b, |x, y| ((x as i8) >> (y & 7)) as u8),
+            Vsrah => map16(a, b, |x, y| ((x as i16) >> (y & 15)) as u16),
+            Vsraw => map32(a, b, |x, y| ((x as i32) >> (y & 31)) as u32),
+            // The shift count is taken from the last byte
+            Vsl => a << (b as u32 & 7),
+            Vsr => a >> (b as u32 & 7),
+            Vslo => a << (b as u32 & 0x78),
+            Vsro => a >> (b as u32 & 0x78),
+            Vsldoi => match imm & 15 {
+                0 => a,
+                n => a << (8 * n) | b >> (128 - 8 * n),
+            },
+
+            // Permute, merge and splat
+            Vperm => {
+                let (a, b, c) = (bytes(a), bytes(b), bytes(c));
+                from_bytes(array::from_fn(|i| match c[i] & 0x1F {
+                    n @ 0..=15 => a[n as usize],
+                    n => b[n as usize - 16],
+                }))
+            }
+            Vmrghb | Vmrglb => from_bytes(merge(bytes(a), bytes(b), op == Vmrglb)),
+            Vmrghh | Vmrglh => from_halves(merge(halves(a), halves(b), op == Vmrglh)),
+            Vmrghw | Vmrglw => from_words(merge(words(a), words(b), op == Vmrglw)),
+            Vspltb => from_bytes([bytes(b)[imm as usize & 15]; 16]),
+            Vsplth => from_halves([halves(b)[imm as usize & 7]; 8]),
+            Vspltw => from_words([words(b)[imm as usize & 3]; 4]),
+            Vspltisb => from_bytes([imm as u8; 16]),
+            Vspltish => from_halves([imm as i32 as u16; 8]),
+            Vspltisw => from_words([imm; 4]),
+
+            // Pack and unpack
+            Vpkuhum | Vpkuhus | Vpkshus | Vpkshss => {
+                let elements: [u16; 16] = concat(halves(a), halves(b));
+                from_bytes(elements.map(|x| match op {
+                    Vpkuhum => x as u8,
+                    Vpkuhus => saturate(x as i64, 0, 0xFF, s) as u8,
+                    Vpkshus => saturate(x as i16 as i64, 0, 0xFF, s) as u8,
+                    _ => saturate(x as i16 as i64, i8::MIN as i64, i8::MAX as i64, s) as u8,
+                }))
+            }
+            Vpkuwum | Vpkuwus | Vpkswus | Vpkswss => {
+                let elements: [u32; 8] = concat(words(a), words(b));
+                from_halves(elements.map(|x| match op {
+                    Vpkuwum => x as u16,
+                    Vpkuwus => saturate(x as i64, 0, 0xFFFF, s) as u16,
+                    Vpkswus => saturate(x as i32 as i64, 0, 0xFFFF, s) as u16,
+                    _ => saturate(x as i32 as i64, i16::MIN as i64, i16::MAX as i64, s) as u16,
+                }))
+            }
+            Vpkpx => {
+                let elements: [u32; 8] = concat(words(a), words(b));
+                from_halves(elements.map(pack_pixel))
+            }
+            Vupkhsb | Vupklsb => {
+                let base = if op == Vupklsb { 8 } else { 0 };
+                let b = bytes(b);
+                from_halves(array::from_fn(|i| b[base + i] as i8 as u16))
+            }
+            Vupkhsh | Vupklsh => {
+                let base = if op == Vupklsh { 4 } else { 0 };
+                let b = halves(b);
+                from_words(array::from_fn(|i| b[base + i] as i16 as u32))
+            }
+            Vupkhpx | Vupklpx => {
+                let base = if op == Vupklpx { 4 } else { 0 };
+                let b = halves(b);
+                from_words(array::from_fn(|i| unpack_pixel(b[base + i])))
+            }
+
+            // Floating point
+            Vaddfp => self.map_float(a, b, 0, |x, y, _| x + y),
+            Vsubfp => self.map_float(a, b, 0, |x, y, _| x - y),
+            Vmaddfp => self.map_float(a, b, c, |x, y, z| x.mul_add(z, y)),
+            // Computed as -(a * c - b), so exact zeros are negative
+            Vnmsubfp => self.map_float(a, b, c, |x, y, z| -x.mul_add(z, -y)),
+            Vmaxfp => self.map_float(a, b, 0, |x, y, _| {
+                if x > y || (x == y && y.is_sign_negative()) {
+                    x
+                } else {
+                    y
+                }
+            }),
+            Vminfp => self.map_float(a, b, 0, |x, y, _| {
+                if x < y || (x == y && x.is_sign_negative()) {
+                    x
+                } else {
+                    y
+                }
+            }),
+            // Estimates are computed exactly, which is within the architected precision
+            Vrefp => self.map_float(0, b, 0, |_, x, _| 1.0 / x),
+            Vrsqrtefp => self.map_float(0, b, 0, |_, x, _| (1.0 / (x as f64).sqrt()) as f32),
+            Vexptefp => self.map_float(0, b, 0, |_, x, _| (x as f64).exp2() as f32),
+            Vlogefp => self.map_float(0, b, 0, |_, x, _| (x as f64).log2() as f32),
+            Vrfin => self.map_float(0, b, 0, |_, x, _| round_ties_even(x as f64) as f32),
+            Vrfiz => self.map_float(0, b, 0, |_, x, _| x.trunc()),
+            Vrfip => self.map_float(0, b, 0, |_, x, _| x.ceil()),
+            Vrfim => self.map_float(0, b, 0, |_, x, _| x.floor()),
+            Vcfux => {
+                let scale = 2f64.powi(-(imm as i32 & 31));
+                map32(0, b, |_, x| ((x as f64 * scale) as f32).to_bits())
+            }
+            Vcfsx => {
+                let scale = 2f64.powi(-(imm as i32 & 31));
+                map32(0, b, |_, x| ((x as i32 as f64 * scale) as f32).to_bits())
+            }
+            Vctuxs | Vctsxs => {
+                let scale = 2f64.powi(imm as i32 & 31);
+                let (min, max) = if op == Vctuxs {
+                    (0, u32::MAX as i64)
+                } else {
+                    (i32::MIN as i64, i32::MAX as i64)
+                };
+                map32(0, b, |_, x| {
+                    let value = self.flush(f32::from_bits(x));
+                    if value.is_nan() {
+                        *s = true;
+                        0
+                    } else {
+                        let value = (value as f64 * scale).trunc();
+                        saturate(value.clamp(i64::MIN as f64, i64::MAX as f64) as i64, min, max, s)
+                            as u32
+                    }
+                })
+            }
+
+            // Floating-point compare
+            Vcmpeqfp => self.compare_floats(a, b, |x, y| x == y),
+            Vcmpgefp => self.compare_floats(a, b, |x, y| x >= y),
+            Vcmpgtfp => self.compare_floats(a, b, |x, y| x > y),
+            Vcmpbfp => map32(a, b, |x, y| {
+                let (x, y) = (self.flush(f32::from_bits(x)), self.flush(f32::from_bits(y)));
+                // NaNs are out of both bounds
+                let (upper, lower) = (x <= y, x >= -y);
+                (!upper as u32) << 31 | (!lower as u32) << 30
+            }),
+            _ => return None,
+        };
+        if sat {
+            self.vscr |= Self::VSCR_SAT;
+        }
+        Some(result)
+    }
+
+    /// Computes the permute control vector of `lvsl` (or `lvsr`) from rA|0 + rB.
+    pub(crate) fn load_shift(&self, ins: Ins, left: bool) -> u128 {
+        let base = if ins.field_ra() == 0 { 0 } else { self.gpr[ins.field_ra() as usize] };
+        let ea = base.wrapping_add(self.gpr[ins.field_rb() as usize]);
+        let shift = if left { ea & 15 } else { 16 - (ea & 15) };
+        from_bytes(array::from_fn(|i| shift as u8 + i as u8))
+    }
+
+    /// Executes an AltiVec instruction other than a load or store.
+    ///
+    /// Returns false if the instruction is not handled by this unit.
+    pub(crate) fn execute_vector(&mut self, ins: Ins) -> Result<bool, Exception> {
+        use Opcode::*;
+        let vd = ins.field_vd() as usize;
+        let vb = self.vr[ins.field_vb() as usize];
+        match ins.op {
+            // Data stream hints have no effect
+            Dss | Dst | Dstst => {}
+            Mfvscr => self.vr[vd] = self.vscr as u128,
+            Mtvscr => self.vscr = vb as u32,
+            Lvsl | Lvsr => self.vr[vd] = self.load_shift(ins, ins.op == Lvsl),
+            _ => {
+                let a = self.vr[ins.field_va() as usize];
+                let c = self.vr[ins.field_vc() as usize];
+                let imm = match ins.op {
+                    Vsldoi => ins.field_shb() as u32,
+                    Vspltisb | Vspltish | Vspltisw => ins.field_vsimm() as i32 as u32,
+                    _ => ins.field_vuimm() as u32,
+                };
+                let Some(result) = self.vector_op(ins.op, a, vb, c, imm) else {
+                    return Ok(false);
+                };
+                self.vr[vd] = result;
+                if modifier(ins, FieldId::RcAV) {
+                    self.set_cr_field(6, compare_cr6(ins.op == Vcmpbfp, result));
+                }
+            }
+        }
+        Ok(true)
+    }
+}
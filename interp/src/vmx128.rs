@@ -0,0 +1,289 @@
+use crate::integer::modifier;
+use crate::vector::{compare_cr6, from_words, words, DEFAULT_NAN, QUIET_BIT};
+use crate::{Cpu, Exception};
+use core::array;
+use powerpc::{FieldId, Ins, Opcode};
+
+/// The bits of 3.0. Packed integers are stored in the low bits of its mantissa,
+/// in units of 2^-22.
+const THREE: u32 = 0x4040_0000;
+/// The bits of 1.0. Unpacked colors are stored in the low bits of its mantissa.
+const ONE: u32 = 0x3F80_0000;
+
+/// Returns the AltiVec instruction computing the same result from the same operands.
+fn altivec_equivalent(op: Opcode) -> Option<Opcode> {
+    use Opcode::*;
+    Some(match op {
+        Vaddfp128 => Vaddfp,
+        Vsubfp128 => Vsubfp,
+        Vmaxfp128 => Vmaxfp,
+        Vminfp128 => Vminfp,
+        Vand128 => Vand,
+        Vandc128 => Vandc,
+        Vnor128 => Vnor,
+        Vor128 => Vor,
+        Vxor128 => Vxor,
+        Vrlw128 => Vrlw,
+        Vslw128 => Vslw,
+        Vsrw128 => Vsrw,
+        Vsraw128 => Vsraw,
+        Vslo128 => Vslo,
+        Vsro128 => Vsro,
+        Vsldoi128 => Vsldoi,
+        Vmrghw128 => Vmrghw,
+        Vmrglw128 => Vmrglw,
+        Vspltw128 => Vspltw,
+        Vspltisw128 => Vspltisw,
+        Vpkshss128 => Vpkshss,
+        Vpkshus128 => Vpkshus,
+        Vpkswss128 => Vpkswss,
+        Vpkswus128 => Vpkswus,
+        Vpkuhum128 => Vpkuhum,
+        Vpkuhus128 => Vpkuhus,
+        Vpkuwum128 => Vpkuwum,
+        Vpkuwus128 => Vpkuwus,
+        Vupkhsb128 => Vupkhsb,
+        Vupklsb128 => Vupklsb,
+        Vupkhsh128 => Vupkhsh,
+        Vupklsh128 => Vupklsh,
+        Vcmpbfp128 => Vcmpbfp,
+        Vcmpeqfp128 => Vcmpeqfp,
+        Vcmpequw128 => Vcmpequw,
+        Vcmpgefp128 => Vcmpgefp,
+        Vcmpgtfp128 => Vcmpgtfp,
+        Vcfsx128 => Vcfsx,
+        Vcfux128 => Vcfux,
+        Vctsxs128 => Vctsxs,
+        Vctuxs128 => Vctuxs,
+        Vrefp128 => Vrefp,
+        Vrsqrtefp128 => Vrsqrtefp,
+        Vexptefp128 => Vexptefp,
+        Vlogefp128 => Vlogefp,
+        Vrfin128 => Vrfin,
+        Vrfiz128 => Vrfiz,
+        Vrfip128 => Vrfip,
+        Vrfim128 => Vrfim,
+        _ => return None,
+    })
+}
+
+/// Rotates the words of `b` left by `rotate` and inserts those selected by the
+/// 4-bit `mask` into `d`. Bit 3 of the mask selects word 0.
+fn rotate_insert(d: u128, b: u128, mask: u32, rotate: u32) -> u128 {
+    let (d, b) = (words(d), words(b));
+    from_words(array::from_fn(|i| {
+        if mask & (8 >> i) != 0 {
+            b[(i + rotate as usize) & 3]
+        } else {
+            d[i]
+        }
+    }))
+}
+
+/// Converts a float to half precision, rounding toward zero.
+///
+/// Values too large for half precision become the largest finite value.
+fn to_half(value: f32) -> u32 {
+    let bits = value.to_bits();
+    let sign = (bits >> 16) & 0x8000;
+    let abs = bits & 0x7FFF_FFFF;
+    let exponent = (abs >> 23) as i32 - 127;
+    let half = match exponent {
+        _ if abs > 0x7F80_0000 => 0x7E00 | (abs >> 13) & 0x3FF,
+        _ if abs == 0x7F80_0000 => 0x7C00,
+        16.. => 0x7BFF,
+        -14.. => ((exponent + 15) as u32) << 10 | (abs >> 13) & 0x3FF,
+        -24.. => (abs & 0x7F_FFFF | 0x80_0000) >> (-1 - exponent),
+        _ => 0,
+    };
+    sign | half
+}
+
+/// Converts a half-precision value in the low 16 bits to single precision.
+fn from_half(half: u32) -> u32 {
+    let sign = (half & 0x8000) << 16;
+    let mantissa = half & 0x3FF;
+    match (half >> 10) & 0x1F {
+        0 => sign | (mantissa as f32 * 2f32.powi(-24)).to_bits(),
+        31 => sign | 0x7F80_0000 | mantissa << 13,
+        exponent => sign | (exponent + 112) << 23 | mantissa << 13,
+    }
+}
+
+/// Extracts the integer stored in a float biased by 3.0, clamped to `min..=max`.
+///
+/// NaNs are packed as `min`.
+fn pack_biased(value: f32, min: i32, max: i32) -> u32 {
+    let lower = f32::from_bits(THREE.wrapping_add_signed(min));
+    let upper = f32::from_bits(THREE.wrapping_add_signed(max));
+    if value.is_nan() || value <= lower {
+        min as u32
+    } else if value >= upper {
+        max as u32
+    } else {
+        value.to_bits().wrapping_sub(THREE)
+    }
+}
+
+/// Biases the sign-extended integer in the low `width` bits of `bits` by 3.0.
+///
+/// The most negative integer is not produced by packing, and unpacks to the default NaN.
+fn unpack_biased(bits: u32, width: u32) -> u32 {
+    let value = ((bits << (32 - width)) as i32) >> (32 - width);
+    if value == -1 << (width - 1) {
+        DEFAULT_NAN
+    } else {
+        THREE.wrapping_add_signed(value)
+    }
+}
+
+/// Packs a vector of floats to a D3D vertex format, as done by `vpkd3d128`.
+///
+/// Integer formats expect values biased by 3.0, so that the integer is held in the
+/// low bits of the mantissa. 32-bit results are returned in word 3, and 64-bit
+/// results in words 2 and 3.
+fn pack_d3d(ty: u8, value: u128) -> Option<u128> {
+    let [x, y, z, w] = words(value).map(f32::from_bits);
+    let short = |v| pack_biased(v, -0x7FFF, 0x7FFF) & 0xFFFF;
+    let (high, low) = match ty {
+        // D3DCOLOR: ARGB, 8 bits each
+        0 => {
+            let [r, g, b, a] = [x, y, z, w].map(|v| pack_biased(v, 0, 0xFF));
+            (0, a << 24 | r << 16 | g << 8 | b)
+        }
+        // NORMSHORT2
+        1 => (0, short(x) << 16 | short(y)),
+        // NORMPACKED32: 2:10:10:10, with x in the low bits
+        2 => {
+            let field = |v| pack_biased(v, -0x1FF, 0x1FF) & 0x3FF;
+            (0, pack_biased(w, 0, 3) << 30 | field(z) << 20 | field(y) << 10 | field(x))
+        }
+        // FLOAT16_2
+        3 => (0, to_half(x) << 16 | to_half(y)),
+        // NORMSHORT4
+        4 => (short(x) << 16 | short(y), short(z) << 16 | short(w)),
+        // FLOAT16_4
+        5 => (to_half(x) << 16 | to_half(y), to_half(z) << 16 | to_half(w)),
+        // NORMPACKED64: 4:20:20:20, with x in the low bits
+        6 => {
+            let field = |v| (pack_biased(v, -0x7_FFFF, 0x7_FFFF) & 0xF_FFFF) as u64;
+            let packed =
+                (pack_biased(w, 0, 15) as u64) << 60 | field(z) << 40 | field(y) << 20 | field(x);
+            ((packed >> 32) as u32, packed as u32)
+        }
+        _ => return None,
+    };
+    Some(from_words([0, 0, high, low]))
+}
+
+/// Unpacks a D3D vertex format to a vector of floats, as done by `vupkd3d128`.
+///
+/// The inverse of [pack_d3d], except that colors are biased by 1.0. Formats with
+/// two components set z to 0.0 and w to 1.0.
+fn unpack_d3d(ty: u8, value: u128) -> Option<u128> {
+    let [_, _, high, low] = words(value);
+    let short = |bits| unpack_biased(bits, 16);
+    let half = |bits| from_half(bits & 0xFFFF);
+    let result = match ty {
+        0 => [low >> 16, low >> 8, low, low >> 24].map(|c| ONE | c & 0xFF),
+        1 => [short(low >> 16), short(low), 0, ONE],
+        2 => [
+            unpack_biased(low, 10),
+            unpack_biased(low >> 10, 10),
+            unpack_biased(low >> 20, 10),
+            THREE | low >> 30,
+        ],
+        3 => [half(low >> 16), half(low), 0, ONE],
+        4 => [short(high >> 16), short(high), short(low >> 16), short(low)],
+        5 => [half(high >> 16), half(high), half(low >> 16), half(low)],
+        6 => {
+            let packed = (high as u64) << 32 | low as u64;
+            let field = |shift| unpack_biased((packed >> shift) as u32, 20);
+            [field(0), field(20), field(40), THREE | (packed >> 60) as u32]
+        }
+        _ => return None,
+    };
+    Some(from_words(result))
+}
+
+impl Cpu {
+    /// Computes the dot product of the first `n` words of `a` and `b`, splatted
+    /// to all words.
+    ///
+    /// NaN operands are quieted and returned, in order of element.
+    fn dot_product(&self, a: u128, b: u128, n: usize) -> u128 {
+        let [a, b] = [a, b].map(|v| words(v).map(|x| self.flush(f32::from_bits(x))));
+        let result = if let Some(nan) = (0..n).flat_map(|i| [a[i], b[i]]).find(|x| x.is_nan()) {
+            nan.to_bits() | QUIET_BIT
+        } else {
+            let sum = (1..n).fold(a[0] * b[0], |acc, i| acc + a[i] * b[i]);
+            match self.flush(sum) {
+                sum if sum.is_nan() => DEFAULT_NAN,
+                sum => sum.to_bits(),
+            }
+        };
+        from_words([result; 4])
+    }
+
+    /// Executes a VMX128 instruction other than a load or store.
+    ///
+    /// Instructions with an AltiVec equivalent are computed by [Cpu::vector_op].
+    /// Returns false if the instruction is not handled by this unit.
+    pub(crate) fn execute_vmx128(&mut self, ins: Ins) -> Result<bool, Exception> {
+        use Opcode::*;
+        let vd = ins.field_vds128() as usize;
+        let a = self.vr[ins.field_va128() as usize];
+        let b = self.vr[ins.field_vb128() as usize];
+        let d = self.vr[vd];
+        let result = match ins.op {
+            Lvsl128 | Lvsr128 => Some(self.load_shift(ins, ins.op == Lvsl128)),
+            // The destination is also an operand of the multiply-add forms
+            Vmaddfp128 => self.vector_op(Vmaddfp, a, d, b, 0),
+            Vmaddcfp128 => self.vector_op(Vmaddfp, a, b, d, 0),
+            Vnmsubfp128 => self.vector_op(Vnmsubfp, a, d, b, 0),
+            Vsel128 => self.vector_op(Vsel, a, b, d, 0),
+            Vperm128 => self.vector_op(Vperm, a, b, self.vr[ins.field_vc128() as usize], 0),
+            Vmulfp128 => Some(self.map_float(a, b, 0, |x, y, _| x * y)),
+            Vmsum3fp128 => Some(self.dot_product(a, b, 3)),
+            Vmsum4fp128 => Some(self.dot_product(a, b, 4)),
+            Vpermwi128 => {
+                let (perm, b) = (ins.field_perm() as usize, words(b));
+                Some(from_words(array::from_fn(|i| b[(perm >> (6 - 2 * i)) & 3])))
+            }
+            Vrlimi128 => {
+                Some(rotate_insert(d, b, ins.field_vuimm() as u32 & 15, ins.field_zimm() as u32))
+            }
+            Vpkd3d128 => {
+                // The packed words are rotated into place. 64-bit values which would
+                // wrap around are only partially inserted: the low word for 64LO, and
+                // the high word for 64HI.
+                let rotate = ins.field_zimm() as u32;
+                let packed = match (ins.field_vmask(), rotate) {
+                    (1, _) | (2, 3) => 0b0001,
+                    (3, 3) => 0b0010,
+                    (2 | 3, _) => 0b0011,
+                    _ => return Ok(false),
+                };
+                let mask = (packed << rotate | packed >> (4 - rotate)) & 15;
+                pack_d3d(ins.field_d3dtype(), b).map(|p| rotate_insert(d, p, mask, rotate))
+            }
+            Vupkd3d128 => unpack_d3d(ins.field_vuimm() >> 2, b),
+            op => altivec_equivalent(op).and_then(|op| {
+                let imm = match op {
+                    Vsldoi => ins.field_shb() as u32,
+                    Vspltisw => ins.field_vsimm() as i32 as u32,
+                    _ => ins.field_vuimm() as u32,
+                };
+                self.vector_op(op, a, b, 0, imm)
+            }),
+        };
+        let Some(result) = result else {
+            return Ok(false);
+        };
+        self.vr[vd] = result;
+        if modifier(ins, FieldId::Rc128) {
+            self.set_cr_field(6, compare_cr6(ins.op == Vcmpbfp128, result));
+        }
+        Ok(true)
+    }
+}
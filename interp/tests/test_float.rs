@@ -0,0 +1,418 @@
+use powerpc::{Extensions, Ins};
+use powerpc_interp::{Cpu, Memory, Ram, RoundingMode};
+
+const BASE: u64 = 0x80000000;
+
+/// Executes a single instruction.
+fn exec(cpu: &mut Cpu, code: u32) {
+    let mut ram = Ram { base: BASE, data: vec![0u8; 0x100] };
+    cpu.pc = BASE;
+    cpu.execute(Ins::new(code, cpu.extensions), &mut ram).unwrap();
+}
+
+/// Executes a binary operation `f1 = f2 op f3` with the given rounding mode.
+fn binary(code: u32, mode: u32, a: f64, b: f64) -> Cpu {
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    cpu.fpscr = mode;
+    cpu.fpr[2] = a;
+    cpu.fpr[3] = b;
+    exec(&mut cpu, code);
+    cpu
+}
+
+const FADD: u32 = 0xFC22182A; // fadd f1, f2, f3
+const FADDS: u32 = 0xEC22182A; // fadds f1, f2, f3
+const FSUB: u32 = 0xFC221828; // fsub f1, f2, f3
+const FMUL: u32 = 0xFC2200F2; // fmul f1, f2, f3
+const FMULS: u32 = 0xEC2200F2; // fmuls f1, f2, f3
+const FDIV: u32 = 0xFC221824; // fdiv f1, f2, f3
+const FDIVS: u32 = 0xEC221824; // fdivs f1, f2, f3
+
+const FI_FR: u32 = Cpu::FPSCR_FI | Cpu::FPSCR_FR;
+
+#[test]
+fn test_rounding_modes() {
+    let tiny = 2f64.powi(-60);
+    let above_one = f64::from_bits(0x3FF0_0000_0000_0001);
+    for (mode, expected, fr) in
+        [(0, 1.0, false), (1, 1.0, false), (2, above_one, true), (3, 1.0, false)]
+    {
+        let cpu = binary(FADD, mode, 1.0, tiny);
+        assert_eq!(cpu.fpr[1], expected, "mode {mode}");
+        assert_eq!(cpu.fpscr & FI_FR, Cpu::FPSCR_FI | if fr { Cpu::FPSCR_FR } else { 0 });
+        assert_eq!(cpu.fpscr & (Cpu::FPSCR_FX | Cpu::FPSCR_XX), Cpu::FPSCR_FX | Cpu::FPSCR_XX);
+        // +normal
+        assert_eq!(cpu.fpscr & Cpu::FPSCR_FPRF, 0x04 << 12);
+
+        // Rounding toward +infinity and -infinity swap for negative values
+        let mirrored = [0, 1, 3, 2][mode as usize];
+        let cpu = binary(FADD, mirrored, -1.0, -tiny);
+        assert_eq!(cpu.fpr[1], -expected, "mode {mirrored}");
+    }
+    assert_eq!(binary(FADD, 0, 1.0, tiny).rounding_mode(), RoundingMode::Nearest);
+    assert_eq!(binary(FADD, 3, 1.0, tiny).rounding_mode(), RoundingMode::Down);
+
+    // Exact results clear FI and FR
+    let cpu = binary(FSUB, 2, 3.5, 1.25);
+    assert_eq!(cpu.fpr[1], 2.25);
+    assert_eq!(cpu.fpscr, 2 | 0x04 << 12);
+
+    // 1/3 rounds down to nearest, and up toward +infinity
+    let cpu = binary(FDIV, 0, 1.0, 3.0);
+    assert_eq!(cpu.fpr[1].to_bits(), 0x3FD5_5555_5555_5555);
+    assert_eq!(cpu.fpscr & FI_FR, Cpu::FPSCR_FI);
+    let cpu = binary(FDIV, 2, 1.0, 3.0);
+    assert_eq!(cpu.fpr[1].to_bits(), 0x3FD5_5555_5555_5556);
+    assert_eq!(cpu.fpscr & FI_FR, FI_FR);
+    let cpu = binary(FDIV, 2, -1.0, 3.0);
+    assert_eq!(cpu.fpr[1].to_bits(), 0xBFD5_5555_5555_5555);
+    assert_eq!(cpu.fpscr & FI_FR, Cpu::FPSCR_FI);
+}
+
+#[test]
+fn test_single_precision() {
+    let half_ulp = 1.0 + 2f64.powi(-24);
+    let tiny = 2f64.powi(-60);
+    // Rounded once from the exact sum, not from the nearest double
+    assert_eq!(binary(FADDS, 0, half_ulp, tiny).fpr[1], 1.0 + 2f64.powi(-23));
+    assert_eq!(binary(FADDS, 0, half_ulp, -tiny).fpr[1], 1.0);
+    // Ties to even
+    assert_eq!(binary(FADDS, 0, half_ulp, 0.0).fpr[1], 1.0);
+    assert_eq!(binary(FADDS, 2, half_ulp, 0.0).fpr[1], 1.0 + 2f64.powi(-23));
+
+    assert_eq!(binary(FDIVS, 0, 1.0, 3.0).fpr[1].to_bits(), 0x3FD5_5555_6000_0000);
+    assert_eq!(binary(FDIVS, 1, 1.0, 3.0).fpr[1].to_bits(), 0x3FD5_5555_4000_0000);
+
+    // Single-precision denormal
+    let cpu = binary(FMULS, 0, 2f64.powi(-100), 2f64.powi(-40));
+    assert_eq!(cpu.fpr[1], 2f64.powi(-140));
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_FPRF, 0x14 << 12);
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_UX, 0);
+}
+
+#[test]
+fn test_single_multiplier() {
+    // The 750CL rounds frC to 25 bits, so the product becomes a tie
+    let c = 1.0 + 2f64.powi(-24) + 2f64.powi(-30);
+    assert_eq!(binary(FMULS, 0, 1.0, c).fpr[1], 1.0);
+    let mut cpu = Cpu::new(Extensions::xenon());
+    cpu.fpr[2] = 1.0;
+    cpu.fpr[3] = c;
+    exec(&mut cpu, FMULS);
+    assert_eq!(cpu.fpr[1], 1.0 + 2f64.powi(-23));
+}
+
+#[test]
+fn test_fused_multiply_add() {
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    cpu.fpr[2] = 1.0 + 2f64.powi(-30);
+    cpu.fpr[3] = 1.0 - 2f64.powi(-30);
+    cpu.fpr[4] = -1.0;
+    exec(&mut cpu, 0xFC2220FA); // fmadd f1, f2, f3, f4
+    assert_eq!(cpu.fpr[1], -2f64.powi(-60));
+    assert_eq!(cpu.fpscr & (FI_FR | Cpu::FPSCR_XX), 0);
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_FPRF, 0x08 << 12);
+
+    exec(&mut cpu, 0xFC2220FC); // fnmsub f1, f2, f3, f4
+    assert_eq!(cpu.fpr[1], -2.0);
+    assert_eq!(cpu.fpscr & FI_FR, Cpu::FPSCR_FI | Cpu::FPSCR_FR);
+
+    // Rounded toward zero before negation
+    cpu.fpscr = 1;
+    exec(&mut cpu, 0xFC2220FC); // fnmsub f1, f2, f3, f4
+    assert_eq!(cpu.fpr[1], -(2.0 - f64::EPSILON));
+}
+
+#[test]
+fn test_invalid_operations() {
+    let default_nan = 0x7FF8_0000_0000_0000;
+    let cpu = binary(FSUB, 0, f64::INFINITY, f64::INFINITY);
+    assert_eq!(cpu.fpr[1].to_bits(), default_nan);
+    assert_eq!(
+        cpu.fpscr,
+        Cpu::FPSCR_FX | Cpu::FPSCR_VX | Cpu::FPSCR_VXISI | 0x11 << 12,
+        "{:#x}",
+        cpu.fpscr
+    );
+
+    assert_eq!(binary(FDIV, 0, 0.0, -0.0).fpscr & Cpu::FPSCR_VXZDZ, Cpu::FPSCR_VXZDZ);
+    assert_eq!(
+        binary(FDIV, 0, f64::INFINITY, -f64::INFINITY).fpscr & Cpu::FPSCR_VXIDI,
+        Cpu::FPSCR_VXIDI
+    );
+    assert_eq!(binary(FMUL, 0, f64::INFINITY, 0.0).fpscr & Cpu::FPSCR_VXIMZ, Cpu::FPSCR_VXIMZ);
+
+    // Infinite operands are exact
+    let cpu = binary(FADD, 0, f64::INFINITY, 1.0);
+    assert_eq!(cpu.fpr[1], f64::INFINITY);
+    assert_eq!(cpu.fpscr, 0x05 << 12);
+
+    // An enabled exception leaves the target unchanged
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    cpu.fpscr = Cpu::FPSCR_VE;
+    cpu.fpr[1] = 42.0;
+    cpu.fpr[2] = f64::INFINITY;
+    cpu.fpr[3] = f64::INFINITY;
+    exec(&mut cpu, FSUB);
+    assert_eq!(cpu.fpr[1], 42.0);
+    assert_eq!(
+        cpu.fpscr,
+        Cpu::FPSCR_FX | Cpu::FPSCR_FEX | Cpu::FPSCR_VX | Cpu::FPSCR_VXISI | Cpu::FPSCR_VE
+    );
+
+    // Signaling NaNs are quieted, and the first NaN operand wins
+    let snan = f64::from_bits(0x7FF0_0000_0000_0001);
+    let qnan = f64::from_bits(0x7FF8_0000_0000_0FFF);
+    let cpu = binary(FADD, 0, snan, 1.0);
+    assert_eq!(cpu.fpr[1].to_bits(), 0x7FF8_0000_0000_0001);
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_VXSNAN, Cpu::FPSCR_VXSNAN);
+    let cpu = binary(FADD, 0, qnan, snan);
+    assert_eq!(cpu.fpr[1].to_bits(), qnan.to_bits());
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_VXSNAN, Cpu::FPSCR_VXSNAN);
+    let cpu = binary(FADD, 0, 1.0, qnan);
+    assert_eq!(cpu.fpr[1].to_bits(), qnan.to_bits());
+    assert_eq!(cpu.fpscr, 0x11 << 12);
+    // Single-precision results drop the low fraction bits
+    assert_eq!(binary(FADDS, 0, qnan, 1.0).fpr[1].to_bits(), 0x7FF8_0000_0000_0000);
+}
+
+#[test]
+fn test_zero_divide() {
+    let cpu = binary(FDIV, 0, -1.0, 0.0);
+    assert_eq!(cpu.fpr[1], f64::NEG_INFINITY);
+    assert_eq!(cpu.fpscr, Cpu::FPSCR_FX | Cpu::FPSCR_ZX | 0x09 << 12);
+
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    cpu.fpscr = Cpu::FPSCR_ZE;
+    cpu.fpr[2] = 1.0;
+    exec(&mut cpu, FDIV);
+    assert_eq!(cpu.fpr[1], 0.0);
+    assert_eq!(cpu.fpscr, Cpu::FPSCR_FX | Cpu::FPSCR_FEX | Cpu::FPSCR_ZX | Cpu::FPSCR_ZE);
+
+    // FX is only set when an exception bit changes
+    let mut cpu = binary(FDIV, 0, 1.0, 0.0);
+    cpu.fpscr &= !Cpu::FPSCR_FX;
+    exec(&mut cpu, FDIV);
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_FX, 0);
+}
+
+#[test]
+fn test_overflow_underflow() {
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    cpu.fpr[2] = 1e300;
+    exec(&mut cpu, 0xFC201018); // frsp f1, f2
+    assert_eq!(cpu.fpr[1], f64::INFINITY);
+    let flags = Cpu::FPSCR_FX | Cpu::FPSCR_OX | Cpu::FPSCR_XX | FI_FR;
+    assert_eq!(cpu.fpscr, flags | 0x05 << 12);
+
+    cpu.fpscr = 1;
+    exec(&mut cpu, 0xFC201018); // frsp f1, f2
+    assert_eq!(cpu.fpr[1], f32::MAX as f64);
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_OX, Cpu::FPSCR_OX);
+
+    let cpu = binary(FMUL, 0, f64::MAX, 2.0);
+    assert_eq!(cpu.fpr[1], f64::INFINITY);
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_OX, Cpu::FPSCR_OX);
+    let cpu = binary(FMUL, 3, f64::MAX, 2.0);
+    assert_eq!(cpu.fpr[1], f64::MAX);
+
+    // The product underflows to zero
+    let cpu = binary(FMUL, 0, 1e-200, 1e-200);
+    assert_eq!(cpu.fpr[1], 0.0);
+    let flags = Cpu::FPSCR_FX | Cpu::FPSCR_UX | Cpu::FPSCR_XX | Cpu::FPSCR_FI;
+    assert_eq!(cpu.fpscr, flags | 0x02 << 12);
+    let cpu = binary(FMUL, 2, 1e-200, 1e-200);
+    assert_eq!(cpu.fpr[1], f64::from_bits(1));
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_FPRF, 0x14 << 12);
+
+    // Exact denormals do not underflow unless the exception is enabled
+    let cpu = binary(FMUL, 0, 2f64.powi(-1000), 2f64.powi(-50));
+    assert_eq!(cpu.fpr[1], f64::from_bits(1 << 24));
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_UX, 0);
+    let cpu = binary(FMUL, Cpu::FPSCR_UE, 2f64.powi(-1000), 2f64.powi(-50));
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_UX, Cpu::FPSCR_UX);
+
+    // Non-IEEE mode flushes denormal results to zero
+    let cpu = binary(FMUL, Cpu::FPSCR_NI, 2f64.powi(-1000), -2f64.powi(-50));
+    assert_eq!(cpu.fpr[1].to_bits(), (-0f64).to_bits());
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_XX, Cpu::FPSCR_XX);
+}
+
+#[test]
+fn test_convert_to_integer() {
+    let fctiw = 0xFC20101C; // fctiw f1, f2
+    let fctiwz = 0xFC20101E; // fctiwz f1, f2
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    for (code, mode, value, expected) in [
+        (fctiw, 0, 2.5, 2),
+        (fctiw, 0, 3.5, 4),
+        (fctiw, 0, -2.5, -2),
+        (fctiw, 2, 2.1, 3),
+        (fctiw, 3, -2.1, -3),
+        (fctiwz, 0, -2.7, -2),
+        (fctiwz, 0, 3e9, i32::MAX),
+        (fctiwz, 0, -3e9, i32::MIN),
+        (fctiwz, 0, f64::NAN, i32::MIN),
+    ] {
+        cpu.fpscr = mode;
+        cpu.fpr[2] = value;
+        exec(&mut cpu, code);
+        assert_eq!(cpu.fpr[1].to_bits(), 0xFFF8_0000_0000_0000 | expected as u32 as u64, "{value}");
+    }
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_VXCVI, Cpu::FPSCR_VXCVI);
+
+    // Inexact conversion sets FI and FR, but leaves FPRF alone
+    cpu.fpscr = 0;
+    cpu.fpr[2] = -0.5;
+    exec(&mut cpu, fctiwz);
+    assert_eq!(cpu.fpr[1].to_bits(), 0xFFF8_0001_0000_0000);
+    assert_eq!(cpu.fpscr, Cpu::FPSCR_FX | Cpu::FPSCR_XX | Cpu::FPSCR_FI);
+
+    let mut cpu = Cpu::new(Extensions::from_bitmask(u32::MAX));
+    cpu.fpr[2] = -1e18;
+    exec(&mut cpu, 0xFC20165C); // fctid f1, f2
+    assert_eq!(cpu.fpr[1].to_bits(), -1_000_000_000_000_000_000i64 as u64);
+    cpu.fpr[2] = f64::from_bits((1u64 << 53) + 1);
+    exec(&mut cpu, 0xFC20169C); // fcfid f1, f2
+    assert_eq!(cpu.fpr[1], 2f64.powi(53));
+    assert_eq!(cpu.fpscr & FI_FR, Cpu::FPSCR_FI);
+}
+
+#[test]
+fn test_estimates() {
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    for (value, expected) in [
+        (1.0, 0x3FEF_FF00_0000_0000),
+        (2.0, 0x3FDF_FF00_0000_0000),
+        (-0.5, 0xBFFF_FF00_0000_0000),
+        (1e-40, 0x47EF_FFFF_E000_0000),
+        (f64::INFINITY, 0),
+    ] {
+        cpu.fpr[2] = value;
+        exec(&mut cpu, 0xEC201030); // fres f1, f2
+        assert_eq!(cpu.fpr[1].to_bits(), expected, "fres {value}");
+    }
+    for (value, expected) in [
+        (1.0, 0x3FEF_FE80_0000_0000),
+        (2.0, 0x3FE6_9FA0_0000_0000),
+        (4.0, 0x3FDF_FE80_0000_0000),
+        (f64::INFINITY, 0),
+        (-1.0, 0x7FF8_0000_0000_0000),
+    ] {
+        cpu.fpr[2] = value;
+        exec(&mut cpu, 0xFC201034); // frsqrte f1, f2
+        assert_eq!(cpu.fpr[1].to_bits(), expected, "frsqrte {value}");
+    }
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_VXSQRT, Cpu::FPSCR_VXSQRT);
+
+    cpu.fpscr = 0;
+    cpu.fpr[2] = -0.0;
+    exec(&mut cpu, 0xEC201030); // fres f1, f2
+    assert_eq!(cpu.fpr[1], f64::NEG_INFINITY);
+    assert_eq!(cpu.fpscr, Cpu::FPSCR_FX | Cpu::FPSCR_ZX | 0x09 << 12);
+}
+
+#[test]
+fn test_compare_and_select() {
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    cpu.fpr[2] = 1.0;
+    cpu.fpr[3] = 2.0;
+    exec(&mut cpu, 0xFD021800); // fcmpu cr2, f2, f3
+    assert_eq!(cpu.cr_field(2), 0b1000);
+    assert_eq!(cpu.fpscr, 0b1000 << 12);
+
+    cpu.fpr[3] = f64::NAN;
+    exec(&mut cpu, 0xFD021800); // fcmpu cr2, f2, f3
+    assert_eq!(cpu.cr_field(2), 0b0001);
+    assert_eq!(cpu.fpscr, 0b0001 << 12);
+    exec(&mut cpu, 0xFD821840); // fcmpo cr3, f2, f3
+    assert_eq!(cpu.cr_field(3), 0b0001);
+    assert_eq!(cpu.fpscr, Cpu::FPSCR_FX | Cpu::FPSCR_VX | Cpu::FPSCR_VXVC | 0b0001 << 12);
+
+    // fsel f1, f2, f3, f4
+    cpu.fpr[3] = 3.0;
+    cpu.fpr[4] = 4.0;
+    for (value, expected) in [(-0.0, 3.0), (1.0, 3.0), (-1.0, 4.0), (f64::NAN, 4.0)] {
+        cpu.fpr[2] = value;
+        exec(&mut cpu, 0xFC2220EE);
+        assert_eq!(cpu.fpr[1], expected, "{value}");
+    }
+
+    cpu.fpr[2] = f64::from_bits(0x7FF0_0000_0000_0001);
+    exec(&mut cpu, 0xFC201210); // fabs f1, f2
+    assert_eq!(cpu.fpr[1].to_bits(), 0x7FF0_0000_0000_0001);
+    exec(&mut cpu, 0xFC201110); // fnabs f1, f2
+    assert_eq!(cpu.fpr[1].to_bits(), 0xFFF0_0000_0000_0001);
+    exec(&mut cpu, 0xFC201050); // fneg f1, f2
+    assert_eq!(cpu.fpr[1].to_bits(), 0xFFF0_0000_0000_0001);
+}
+
+#[test]
+fn test_fpscr_instructions() {
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    exec(&mut cpu, 0xFF80110C); // mtfsfi cr7, 1
+    assert_eq!(cpu.rounding_mode(), RoundingMode::Zero);
+    exec(&mut cpu, 0xFF00004C); // mtfsb1 cr6lt
+    assert_eq!(cpu.fpscr, Cpu::FPSCR_VE | 1);
+
+    // An enabled exception sets FEX, and Rc copies the summary bits to CR1
+    cpu.fpr[2] = f64::INFINITY;
+    cpu.fpr[3] = f64::NEG_INFINITY;
+    exec(&mut cpu, 0xFC22182B); // fadd. f1, f2, f3
+    assert_eq!(cpu.cr_field(1), 0b1110);
+
+    exec(&mut cpu, 0xFC20048E); // mffs f1
+    assert_eq!(cpu.fpr[1].to_bits(), 0xFFF8_0000_0000_0000 | cpu.fpscr as u64);
+
+    exec(&mut cpu, 0xFE080080); // mcrfs cr4, cr2
+    assert_eq!(cpu.cr_field(4), 0b1000);
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_VXISI, 0);
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_VX, 0);
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_FEX, 0);
+
+    // FEX and VX cannot be set directly
+    cpu.fpr[2] = f64::from_bits(0x6000_0000 | Cpu::FPSCR_OX as u64 | 2);
+    exec(&mut cpu, 0xFDFE158E); // mtfsf 255, f2
+    assert_eq!(cpu.fpscr, Cpu::FPSCR_OX | 2);
+    exec(&mut cpu, 0xFF00008C); // mtfsb0 cr6lt
+    assert_eq!(cpu.fpscr, Cpu::FPSCR_OX | 2);
+}
+
+#[test]
+fn test_load_store() {
+    let mut ram = Ram { base: BASE, data: vec![0u8; 0x100] };
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    cpu.gpr[3] = BASE;
+    cpu.gpr[4] = 0x30;
+    let run = |cpu: &mut Cpu, ram: &mut Ram<Vec<u8>>, code| {
+        cpu.pc = BASE;
+        cpu.execute(Ins::new(code, cpu.extensions), ram).unwrap();
+    };
+
+    // Signaling NaNs survive the conversion to double
+    ram.write_u32(BASE, 0x7F80_0001).unwrap();
+    run(&mut cpu, &mut ram, 0xC0230000); // lfs f1, 0x0(r3)
+    assert_eq!(cpu.fpr[1].to_bits(), 0x7FF0_0000_2000_0000);
+    run(&mut cpu, &mut ram, 0xD0230010); // stfs f1, 0x10(r3)
+    assert_eq!(ram.read_u32(BASE + 0x10), Ok(0x7F80_0001));
+
+    ram.write_u64(BASE + 8, 1.5f64.to_bits()).unwrap();
+    run(&mut cpu, &mut ram, 0xC8230008); // lfd f1, 0x8(r3)
+    assert_eq!(cpu.fpr[1], 1.5);
+    run(&mut cpu, &mut ram, 0xD8230018); // stfd f1, 0x18(r3)
+    assert_eq!(ram.read_u64(BASE + 0x18), Ok(1.5f64.to_bits()));
+
+    // stfs truncates instead of rounding, and denormalizes small values
+    cpu.fpr[1] = f64::from_bits(0x3FF0_0000_1FFF_FFFF);
+    run(&mut cpu, &mut ram, 0xD4230020); // stfsu f1, 0x20(r3)
+    assert_eq!(ram.read_u32(BASE + 0x20), Ok(0x3F80_0000));
+    assert_eq!(cpu.gpr[3], BASE + 0x20);
+    cpu.fpr[1] = 2f64.powi(-140);
+    run(&mut cpu, &mut ram, 0xD4230020); // stfsu f1, 0x20(r3)
+    assert_eq!(ram.read_u32(BASE + 0x40), Ok(0x0000_0200));
+
+    cpu.gpr[3] = BASE;
+    cpu.fpr[1] = f64::from_bits(0xFFF8_0000_DEAD_BEEF);
+    run(&mut cpu, &mut ram, 0x7C2327AE); // stfiwx f1, r3, r4
+    assert_eq!(ram.read_u32(BASE + 0x30), Ok(0xDEAD_BEEF));
+}
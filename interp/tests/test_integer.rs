@@ -0,0 +1,335 @@
+use powerpc::{Extensions, Ins};
+use powerpc_interp::{Cpu, Exception, Memory, Ram};
+
+const BASE: u64 = 0x80000000;
+
+fn setup(program: &[u32]) -> (Cpu, Ram<Vec<u8>>) {
+    let mut ram = Ram { base: BASE, data: vec![0u8; 0x1000] };
+    for (i, code) in program.iter().enumerate() {
+        ram.write_u32(BASE + 4 * i as u64, *code).unwrap();
+    }
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    cpu.gpr[1] = BASE + 0xF00;
+    (cpu, ram)
+}
+
+/// Executes a single instruction.
+fn exec(cpu: &mut Cpu, code: u32) {
+    let mut ram = Ram { base: BASE, data: vec![0u8; 0x100] };
+    cpu.pc = BASE;
+    cpu.execute(Ins::new(code, cpu.extensions), &mut ram).unwrap();
+}
+
+#[test]
+fn test_sum_loop() {
+    let (mut cpu, mut ram) = setup(&[
+        0x7C6903A6, // mtctr r3
+        0x38800000, // li r4, 0x0
+        0x7C841A14, // add r4, r4, r3
+        0x3863FFFF, // subi r3, r3, 0x1
+        0x4200FFF8, // bdnz -0x8
+        0x7C832378, // mr r3, r4
+        0x4E800020, // blr
+    ]);
+    cpu.gpr[3] = 10;
+    assert_eq!(cpu.call(&mut ram, BASE, 1000), Ok(34));
+    assert_eq!(cpu.gpr[3], 55);
+    assert_eq!(cpu.ctr, 0);
+}
+
+#[test]
+fn test_strlen() {
+    let (mut cpu, mut ram) = setup(&[
+        0x7C641B78, // mr r4, r3
+        0x88A40000, // lbz r5, 0x0(r4)
+        0x38840001, // addi r4, r4, 0x1
+        0x2C050000, // cmpwi r5, 0x0
+        0x4082FFF4, // bne -0xc
+        0x7C632050, // subf r3, r3, r4
+        0x3863FFFF, // subi r3, r3, 0x1
+        0x4E800020, // blr
+    ]);
+    ram.write(BASE + 0x800, b"hello\0").unwrap();
+    cpu.gpr[3] = BASE + 0x800;
+    cpu.call(&mut ram, BASE, 1000).unwrap();
+    assert_eq!(cpu.gpr[3], 5);
+}
+
+#[test]
+fn test_nested_call() {
+    let (mut cpu, mut ram) = setup(&[
+        0x9421FFF0, // stwu r1, -0x10(r1)
+        0x7C0802A6, // mflr r0
+        0x90010014, // stw r0, 0x14(r1)
+        0x48000019, // bl 0x18
+        0x80010014, // lwz r0, 0x14(r1)
+        0x7C0803A6, // mtlr r0
+        0x38210010, // addi r1, r1, 0x10
+        0x4E800020, // blr
+        0x60000000, // nop
+        0x5463083C, // slwi r3, r3, 1
+        0x4E800020, // blr
+    ]);
+    cpu.gpr[3] = 21;
+    cpu.call(&mut ram, BASE, 1000).unwrap();
+    assert_eq!(cpu.gpr[3], 42);
+    assert_eq!(cpu.gpr[1], BASE + 0xF00);
+    // The back chain and saved LR
+    assert_eq!(ram.read_u32(BASE + 0xEF0), Ok(0x80000F00));
+    assert_eq!(ram.read_u32(BASE + 0xF04), Ok(Cpu::RETURN_ADDRESS as u32));
+}
+
+#[test]
+fn test_add_carry() {
+    // 64-bit addition with addc/adde
+    let (mut cpu, mut ram) = setup(&[
+        0x7C843014, // addc r4, r4, r6
+        0x7C632914, // adde r3, r3, r5
+        0x4E800020, // blr
+    ]);
+    (cpu.gpr[3], cpu.gpr[4]) = (0x1, 0xFFFFFFFF);
+    (cpu.gpr[5], cpu.gpr[6]) = (0x2, 0x1);
+    cpu.call(&mut ram, BASE, 10).unwrap();
+    assert_eq!((cpu.gpr[3], cpu.gpr[4]), (0x4, 0x0));
+    assert!(!cpu.ca());
+
+    let mut cpu = Cpu::new(Extensions::none());
+    cpu.gpr[4] = 3;
+    cpu.gpr[5] = 5;
+    exec(&mut cpu, 0x7C642810); // subfc r3, r4, r5
+    assert_eq!(cpu.gpr[3], 2);
+    assert!(cpu.ca()); // No borrow
+    cpu.gpr[5] = 1;
+    exec(&mut cpu, 0x7C642810); // subfc r3, r4, r5
+    assert_eq!(cpu.gpr[3], 0xFFFFFFFE);
+    assert!(!cpu.ca());
+}
+
+#[test]
+fn test_record_and_overflow() {
+    let mut cpu = Cpu::new(Extensions::none());
+    cpu.gpr[3] = 0x7FFFFFFF;
+    cpu.gpr[4] = 1;
+    exec(&mut cpu, 0x7C632615); // addo. r3, r3, r4
+    assert_eq!(cpu.gpr[3], 0x80000000);
+    assert_eq!(cpu.xer, Cpu::XER_SO | Cpu::XER_OV);
+    assert_eq!(cpu.cr_field(0), 0b1001); // LT and SO
+
+    // OV is cleared by the next OE instruction, but SO is sticky
+    cpu.gpr[3] = 1;
+    exec(&mut cpu, 0x7C632614); // addo r3, r3, r4
+    assert_eq!(cpu.xer, Cpu::XER_SO);
+    exec(&mut cpu, 0x7C632215); // add. r3, r3, r4
+    assert_eq!(cpu.cr_field(0), 0b0101); // GT and SO
+
+    let mut cpu = Cpu::new(Extensions::none());
+    cpu.gpr[4] = 0x80000000;
+    exec(&mut cpu, 0x7C6404D0); // nego r3, r4
+    assert_eq!(cpu.gpr[3], 0x80000000);
+    assert_eq!(cpu.xer, Cpu::XER_SO | Cpu::XER_OV);
+
+    let mut cpu = Cpu::new(Extensions::none());
+    cpu.gpr[4] = 0x10000;
+    cpu.gpr[5] = 0x10000;
+    exec(&mut cpu, 0x7C642DD6); // mullwo r3, r4, r5
+    assert_eq!(cpu.gpr[3], 0);
+    assert_eq!(cpu.xer, Cpu::XER_SO | Cpu::XER_OV);
+    exec(&mut cpu, 0x7C642896); // mulhw r3, r4, r5
+    assert_eq!(cpu.gpr[3], 1);
+    cpu.gpr[4] = 0xFFFFFFFF;
+    exec(&mut cpu, 0x7C642896); // mulhw r3, r4, r5
+    assert_eq!(cpu.gpr[3], 0xFFFFFFFF);
+    exec(&mut cpu, 0x7C642816); // mulhwu r3, r4, r5
+    assert_eq!(cpu.gpr[3], 0xFFFF);
+
+    let mut cpu = Cpu::new(Extensions::none());
+    cpu.gpr[4] = 0xFFFFFFEC; // -20
+    cpu.gpr[5] = 3;
+    exec(&mut cpu, 0x7C642BD6); // divw r3, r4, r5
+    assert_eq!(cpu.gpr[3], 0xFFFFFFFA); // -6
+    exec(&mut cpu, 0x7C642B96); // divwu r3, r4, r5
+    assert_eq!(cpu.gpr[3], 0x5555554E);
+    cpu.gpr[5] = 0;
+    exec(&mut cpu, 0x7C642FD6); // divwo r3, r4, r5
+    assert_eq!(cpu.gpr[3], 0xFFFFFFFF);
+    assert_eq!(cpu.xer, Cpu::XER_SO | Cpu::XER_OV);
+}
+
+#[test]
+fn test_shift_rotate() {
+    let mut cpu = Cpu::new(Extensions::none());
+    cpu.gpr[4] = 0xFFFFFFF1; // -15
+    exec(&mut cpu, 0x7C832670); // srawi r3, r4, 4
+    assert_eq!(cpu.gpr[3], 0xFFFFFFFF);
+    assert!(cpu.ca());
+    cpu.gpr[4] = 0xFFFFFFF0; // -16
+    exec(&mut cpu, 0x7C832670); // srawi r3, r4, 4
+    assert_eq!(cpu.gpr[3], 0xFFFFFFFF);
+    assert!(!cpu.ca());
+    cpu.gpr[5] = 40;
+    exec(&mut cpu, 0x7C832E30); // sraw r3, r4, r5
+    assert_eq!(cpu.gpr[3], 0xFFFFFFFF);
+    assert!(cpu.ca());
+
+    cpu.gpr[3] = 0x11223344;
+    cpu.gpr[4] = 0xAABBCCDD;
+    exec(&mut cpu, 0x5083442E); // rlwimi r3, r4, 8, 16, 23
+    assert_eq!(cpu.gpr[3], 0x1122DD44);
+    exec(&mut cpu, 0x7C830034); // cntlzw r3, r4
+    assert_eq!(cpu.gpr[3], 0);
+    cpu.gpr[4] = 0x80;
+    exec(&mut cpu, 0x7C830775); // extsb. r3, r4
+    assert_eq!(cpu.gpr[3], 0xFFFFFF80);
+    assert_eq!(cpu.cr_field(0), 0b1000);
+}
+
+#[test]
+fn test_compare_cr() {
+    let mut cpu = Cpu::new(Extensions::none());
+    cpu.gpr[3] = 0xFFFFFFFF;
+    cpu.gpr[4] = 1;
+    exec(&mut cpu, 0x7C832000); // cmpw cr1, r3, r4
+    assert_eq!(cpu.cr_field(1), 0b1000);
+    exec(&mut cpu, 0x7C832040); // cmplw cr1, r3, r4
+    assert_eq!(cpu.cr_field(1), 0b0100);
+    exec(&mut cpu, 0x2C03FFFF); // cmpwi r3, -0x1
+    assert_eq!(cpu.cr_field(0), 0b0010);
+    exec(&mut cpu, 0x28030005); // cmplwi r3, 0x5
+    assert_eq!(cpu.cr_field(0), 0b0100);
+    assert_eq!(cpu.cr, 0x44000000);
+
+    exec(&mut cpu, 0x4CC63182); // crclr cr1eq
+    assert_eq!(cpu.cr, 0x44000000);
+    cpu.gpr[3] = 0x12345678;
+    exec(&mut cpu, 0x7C6FF120); // mtcrf 255, r3
+    assert_eq!(cpu.cr, 0x12345678);
+    exec(&mut cpu, 0x7C600026); // mfcr r3
+    assert_eq!(cpu.gpr[3], 0x12345678);
+
+    cpu.gpr[3] = 0xE0000000;
+    exec(&mut cpu, 0x7C6103A6); // mtxer r3
+    exec(&mut cpu, 0x7D000400); // mcrxr cr2
+    assert_eq!(cpu.cr_field(2), 0b1110);
+    exec(&mut cpu, 0x7C6102A6); // mfxer r3
+    assert_eq!(cpu.gpr[3], 0);
+}
+
+#[test]
+fn test_load_store() {
+    let (mut cpu, mut ram) = setup(&[]);
+    let data = BASE + 0x800;
+    ram.write(data, &[0x80, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07]).unwrap();
+    ram.write(data + 0x20, &[0xFF; 0x40]).unwrap();
+    cpu.gpr[4] = data;
+    let mut exec = |cpu: &mut Cpu, code: u32| {
+        cpu.pc = BASE;
+        cpu.execute(Ins::new(code, cpu.extensions), &mut ram)
+    };
+    exec(&mut cpu, 0xA8640000).unwrap(); // lha r3, 0x0(r4)
+    assert_eq!(cpu.gpr[3], 0xFFFF8001);
+    exec(&mut cpu, 0x7C60262C).unwrap(); // lhbrx r3, r0, r4
+    assert_eq!(cpu.gpr[3], 0x0180);
+    exec(&mut cpu, 0x8C640001).unwrap(); // lbzu r3, 0x1(r4)
+    assert_eq!(cpu.gpr[3], 0x01);
+    assert_eq!(cpu.gpr[4], data + 1);
+    cpu.gpr[4] = data;
+
+    exec(&mut cpu, 0xBBA40000).unwrap(); // lmw r29, 0x0(r4)
+    assert_eq!(cpu.gpr[29], 0x80010203);
+    assert_eq!(cpu.gpr[30], 0x04050607);
+    assert_eq!(cpu.gpr[31], 0);
+    exec(&mut cpu, 0xBFA40010).unwrap(); // stmw r29, 0x10(r4)
+    exec(&mut cpu, 0x7C60252C).unwrap(); // stwbrx r3, r0, r4
+    exec(&mut cpu, 0x7CA434AA).unwrap(); // lswi r5, r4, 6
+    assert_eq!(cpu.gpr[5], 0x01000000);
+    assert_eq!(cpu.gpr[6], 0x04050000);
+    cpu.gpr[4] = data + 0x20;
+    exec(&mut cpu, 0x7CA435AA).unwrap(); // stswi r5, r4, 6
+    cpu.gpr[4] = data + 0x48;
+    exec(&mut cpu, 0x7C0027EC).unwrap(); // dcbz r0, r4
+
+    let mut buf = [0u8; 0x60];
+    ram.read(data, &mut buf).unwrap();
+    assert_eq!(buf[..8], [0x01, 0x00, 0x00, 0x00, 0x04, 0x05, 0x06, 0x07]);
+    assert_eq!(buf[0x10..0x1C], [0x80, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0, 0, 0, 0]);
+    assert_eq!(buf[0x20..0x28], [0x01, 0x00, 0x00, 0x00, 0x04, 0x05, 0xFF, 0xFF]);
+    assert_eq!(buf[0x28..0x40], [0xFF; 0x18]); // Untouched
+    assert_eq!(buf[0x40..], [0; 0x20]);
+}
+
+#[test]
+fn test_reservation() {
+    let (mut cpu, mut ram) = setup(&[]);
+    let data = BASE + 0x800;
+    cpu.gpr[4] = data;
+    cpu.gpr[5] = 0x1234;
+    let mut exec = |cpu: &mut Cpu, code: u32| {
+        cpu.pc = BASE;
+        cpu.execute(Ins::new(code, cpu.extensions), &mut ram).unwrap()
+    };
+    // Without a reservation, the store fails
+    exec(&mut cpu, 0x7CA0212D); // stwcx. r5, r0, r4
+    assert_eq!(cpu.cr_field(0), 0b0000);
+    exec(&mut cpu, 0x7C602028); // lwarx r3, r0, r4
+    assert_eq!(cpu.reservation, Some(data));
+    exec(&mut cpu, 0x7CA0212D); // stwcx. r5, r0, r4
+    assert_eq!(cpu.cr_field(0), 0b0010);
+    assert_eq!(cpu.reservation, None);
+    exec(&mut cpu, 0x7C602028); // lwarx r3, r0, r4
+    assert_eq!(cpu.gpr[3], 0x1234);
+
+    cpu.gpr[4] = data + 2;
+    cpu.pc = BASE;
+    let result = cpu.execute(Ins::new(0x7C602028, cpu.extensions), &mut ram);
+    assert_eq!(result, Err(Exception::Alignment { pc: BASE, addr: data + 2 }));
+}
+
+#[test]
+fn test_exceptions() {
+    let (mut cpu, mut ram) = setup(&[
+        0x7C832008, // tweq r3, r4
+        0x44000002, // sc
+        0x7FE00008, // trap
+    ]);
+    cpu.gpr[3] = 1;
+    cpu.pc = BASE;
+    cpu.step(&mut ram).unwrap();
+    assert_eq!(cpu.step(&mut ram), Err(Exception::SystemCall { pc: BASE + 4 }));
+    assert_eq!(cpu.pc, BASE + 8);
+    assert_eq!(cpu.step(&mut ram), Err(Exception::Trap { pc: BASE + 8 }));
+    assert_eq!(cpu.pc, BASE + 8);
+
+    cpu.pc = BASE + 0x1000;
+    assert_eq!(
+        cpu.step(&mut ram),
+        Err(Exception::MemoryFault { pc: BASE + 0x1000, addr: BASE + 0x1000 })
+    );
+    cpu.pc = BASE + 0xC;
+    assert_eq!(cpu.step(&mut ram), Err(Exception::IllegalInstruction { pc: BASE + 0xC, code: 0 }));
+    cpu.pc = BASE;
+    assert_eq!(
+        cpu.run_until(&mut ram, BASE + 0x100, 1),
+        Err(Exception::StepLimit { pc: BASE + 4 })
+    );
+}
+
+#[test]
+fn test_mode64() {
+    let mut cpu = Cpu::new(Extensions::from_bitmask(u32::MAX));
+    cpu.gpr[3] = 0xFFFFFFFF;
+    cpu.gpr[4] = 1;
+    exec(&mut cpu, 0x7C632014); // addc r3, r3, r4
+    assert_eq!(cpu.gpr[3], 0);
+    assert!(cpu.ca());
+
+    cpu.mode64 = true;
+    cpu.gpr[3] = 0xFFFFFFFF;
+    exec(&mut cpu, 0x7C632014); // addc r3, r3, r4
+    assert_eq!(cpu.gpr[3], 0x100000000);
+    assert!(!cpu.ca());
+    exec(&mut cpu, 0x7C232000); // cmpd r3, r4
+    assert_eq!(cpu.cr_field(0), 0b0100);
+    cpu.gpr[4] = 0x1122334455667788;
+    exec(&mut cpu, 0x78834620); // rldicl r3, r4, 8, 56
+    assert_eq!(cpu.gpr[3], 0x11);
+}
@@ -0,0 +1,294 @@
+use powerpc::{Extensions, Ins};
+use powerpc_interp::{Cpu, Memory, Ram};
+
+const BASE: u64 = 0x80000000;
+
+/// Executes a single instruction.
+fn run(cpu: &mut Cpu, ram: &mut Ram<Vec<u8>>, code: u32) {
+    cpu.pc = BASE;
+    cpu.execute(Ins::new(code, cpu.extensions), ram).unwrap();
+}
+
+/// Executes a single instruction without memory access.
+fn exec(cpu: &mut Cpu, code: u32) {
+    run(cpu, &mut Ram { base: BASE, data: vec![0u8; 0x100] }, code);
+}
+
+/// Sets both slots of a paired-single register.
+fn set(cpu: &mut Cpu, reg: usize, ps0: f64, ps1: f64) {
+    cpu.fpr[reg] = ps0;
+    cpu.ps1[reg] = ps1;
+}
+
+/// Reads both slots of a paired-single register.
+fn get(cpu: &Cpu, reg: usize) -> (f64, f64) {
+    (cpu.fpr[reg], cpu.ps1[reg])
+}
+
+/// Builds a GQR from its load and store type and scale fields.
+fn gqr(ld_type: u64, ld_scale: u64, st_type: u64, st_scale: u64) -> u64 {
+    (ld_scale & 0x3F) << 24 | ld_type << 16 | (st_scale & 0x3F) << 8 | st_type
+}
+
+#[test]
+fn test_quantized_load() {
+    let mut ram = Ram { base: BASE, data: vec![0u8; 0x100] };
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    cpu.gpr[3] = BASE;
+    cpu.gpr[4] = 0x20;
+
+    // s16 scaled by 2^-8
+    cpu.spr[914] = gqr(7, 8, 0, 0);
+    ram.write_u16(BASE, 0x0180).unwrap();
+    ram.write_u16(BASE + 2, 0xFF00).unwrap();
+    run(&mut cpu, &mut ram, 0xE0232000); // psq_l f1, 0x0(r3), 0, qr2
+    assert_eq!(get(&cpu, 1), (1.5, -1.0));
+
+    // The second slot is 1.0 when W=1
+    ram.write_u16(BASE + 4, 0xFF00).unwrap();
+    run(&mut cpu, &mut ram, 0xE023A004); // psq_l f1, 0x4(r3), 1, qr2
+    assert_eq!(get(&cpu, 1), (-1.0, 1.0));
+
+    // u8 with a negative scale multiplies by 2^2
+    cpu.spr[913] = gqr(4, -2i64 as u64, 0, 0);
+    ram.write_u8(BASE + 8, 200).unwrap();
+    ram.write_u8(BASE + 9, 7).unwrap();
+    run(&mut cpu, &mut ram, 0xE0231008); // psq_l f1, 0x8(r3), 0, qr1
+    assert_eq!(get(&cpu, 1), (800.0, 28.0));
+
+    // Floats ignore the scale, and reserved types behave as floats
+    for ty in [0, 1, 2, 3] {
+        cpu.spr[912] = gqr(ty, 5, 0, 0);
+        ram.write_u32(BASE + 0x10, 0.25f32.to_bits()).unwrap();
+        ram.write_u32(BASE + 0x14, (-3.0f32).to_bits()).unwrap();
+        run(&mut cpu, &mut ram, 0xE0230010); // psq_l f1, 0x10(r3), 0, qr0
+        assert_eq!(get(&cpu, 1), (0.25, -3.0), "type {ty}");
+    }
+
+    // Indexed form
+    ram.write_u16(BASE + 0x20, 0x8000).unwrap();
+    ram.write_u16(BASE + 0x22, 0x0040).unwrap();
+    run(&mut cpu, &mut ram, 0x1023210C); // psq_lx f1, r3, r4, 0, qr2
+    assert_eq!(get(&cpu, 1), (-128.0, 0.25));
+}
+
+#[test]
+fn test_quantized_store() {
+    let mut ram = Ram { base: BASE, data: vec![0u8; 0x100] };
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    cpu.gpr[3] = BASE;
+
+    // u8 scaled by 2^4, saturating
+    cpu.spr[915] = gqr(0, 0, 4, 4);
+    set(&mut cpu, 1, 2.5, 20.0);
+    run(&mut cpu, &mut ram, 0xF0233000); // psq_st f1, 0x0(r3), 0, qr3
+    assert_eq!(ram.read_u16(BASE), Ok(0x28FF));
+
+    // Negative values and NaNs store zero
+    set(&mut cpu, 1, -1.0, f64::NAN);
+    run(&mut cpu, &mut ram, 0xF0233000); // psq_st f1, 0x0(r3), 0, qr3
+    assert_eq!(ram.read_u16(BASE), Ok(0x0000));
+
+    // W=1 stores only the first slot
+    ram.write_u16(BASE, 0xAAAA).unwrap();
+    set(&mut cpu, 1, 1.0, 1.0);
+    run(&mut cpu, &mut ram, 0xF023B000); // psq_st f1, 0x0(r3), 1, qr3
+    assert_eq!(ram.read_u16(BASE), Ok(0x10AA));
+
+    // s16 with a negative scale truncates toward zero, and updates the base
+    cpu.spr[916] = gqr(0, 0, 7, -1i64 as u64);
+    set(&mut cpu, 1, -7.5, 1e9);
+    run(&mut cpu, &mut ram, 0xF4234008); // psq_stu f1, 0x8(r3), 0, qr4
+    assert_eq!(ram.read_u32(BASE + 8), Ok(0xFFFD_7FFF));
+    assert_eq!(cpu.gpr[3], BASE + 8);
+
+    // Floats are stored as by stfs
+    cpu.gpr[3] = BASE;
+    cpu.spr[915] = gqr(0, 0, 0, 4);
+    set(&mut cpu, 1, 1.5, -0.0);
+    run(&mut cpu, &mut ram, 0xF0233000); // psq_st f1, 0x0(r3), 0, qr3
+    assert_eq!(ram.read_u32(BASE), Ok(1.5f32.to_bits()));
+    assert_eq!(ram.read_u32(BASE + 4), Ok(0x8000_0000));
+}
+
+#[test]
+fn test_scalar_singles() {
+    let mut ram = Ram { base: BASE, data: vec![0u8; 0x100] };
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    cpu.gpr[3] = BASE;
+
+    // Single-precision loads and arithmetic fill both slots
+    ram.write_u32(BASE, 2.0f32.to_bits()).unwrap();
+    run(&mut cpu, &mut ram, 0xC0230000); // lfs f1, 0x0(r3)
+    assert_eq!(get(&cpu, 1), (2.0, 2.0));
+    set(&mut cpu, 2, 1.0, 5.0);
+    set(&mut cpu, 3, 0.5, 6.0);
+    exec(&mut cpu, 0xEC22182A); // fadds f1, f2, f3
+    assert_eq!(get(&cpu, 1), (1.5, 1.5));
+
+    // Double-precision results leave the second slot alone
+    exec(&mut cpu, 0xFC22182A); // fadd f1, f2, f3
+    assert_eq!(get(&cpu, 1), (1.5, 1.5));
+    ram.write_u64(BASE, 4.0f64.to_bits()).unwrap();
+    run(&mut cpu, &mut ram, 0xC8230000); // lfd f1, 0x0(r3)
+    assert_eq!(get(&cpu, 1), (4.0, 1.5));
+
+    // Other extensions have no second slot
+    let mut cpu = Cpu::new(Extensions::none());
+    cpu.gpr[3] = BASE;
+    ram.write_u32(BASE, 2.0f32.to_bits()).unwrap();
+    run(&mut cpu, &mut ram, 0xC0230000); // lfs f1, 0x0(r3)
+    assert_eq!(get(&cpu, 1), (2.0, 0.0));
+}
+
+#[test]
+fn test_arithmetic() {
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    set(&mut cpu, 2, 1.5, -2.0);
+    set(&mut cpu, 3, 0.25, 8.0);
+    set(&mut cpu, 4, 3.0, 0.5);
+
+    exec(&mut cpu, 0x1022182A); // ps_add f1, f2, f3
+    assert_eq!(get(&cpu, 1), (1.75, 6.0));
+    exec(&mut cpu, 0x10221828); // ps_sub f1, f2, f3
+    assert_eq!(get(&cpu, 1), (1.25, -10.0));
+    exec(&mut cpu, 0x10220132); // ps_mul f1, f2, f4
+    assert_eq!(get(&cpu, 1), (4.5, -1.0));
+    exec(&mut cpu, 0x10221824); // ps_div f1, f2, f3
+    assert_eq!(get(&cpu, 1), (6.0, -0.25));
+    exec(&mut cpu, 0x1022193A); // ps_madd f1, f2, f4, f3
+    assert_eq!(get(&cpu, 1), (4.75, 7.0));
+    exec(&mut cpu, 0x1022193C); // ps_nmsub f1, f2, f4, f3
+    assert_eq!(get(&cpu, 1), (-4.25, 9.0));
+
+    // The scalar forms take one slot of frC for both results
+    exec(&mut cpu, 0x10220118); // ps_muls0 f1, f2, f4
+    assert_eq!(get(&cpu, 1), (4.5, -6.0));
+    exec(&mut cpu, 0x1022011A); // ps_muls1 f1, f2, f4
+    assert_eq!(get(&cpu, 1), (0.75, -1.0));
+    exec(&mut cpu, 0x1022191C); // ps_madds0 f1, f2, f4, f3
+    assert_eq!(get(&cpu, 1), (4.75, 2.0));
+    exec(&mut cpu, 0x1022191E); // ps_madds1 f1, f2, f4, f3
+    assert_eq!(get(&cpu, 1), (1.0, 7.0));
+
+    // The sums add frA[0] and frB[1], taking the other slot from frC
+    exec(&mut cpu, 0x10221914); // ps_sum0 f1, f2, f4, f3
+    assert_eq!(get(&cpu, 1), (9.5, 0.5));
+    exec(&mut cpu, 0x10221916); // ps_sum1 f1, f2, f4, f3
+    assert_eq!(get(&cpu, 1), (3.0, 9.5));
+
+    // Each slot is rounded to single precision
+    set(&mut cpu, 2, 1.0, 2.0);
+    set(&mut cpu, 3, 3.0, 3.0);
+    exec(&mut cpu, 0x10221824); // ps_div f1, f2, f3
+    assert_eq!(get(&cpu, 1), ((1.0f32 / 3.0) as f64, (2.0f32 / 3.0) as f64));
+    assert_ne!(cpu.fpscr & Cpu::FPSCR_FI, 0);
+    // +normal
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_FPRF, 0x04 << 12);
+
+    // Exceptions in either slot are recorded, and the record form copies them to CR1
+    set(&mut cpu, 2, 1.0, f64::INFINITY);
+    set(&mut cpu, 3, 1.0, f64::NEG_INFINITY);
+    cpu.fpscr = 0;
+    exec(&mut cpu, 0x1022182B); // ps_add. f1, f2, f3
+    assert!(cpu.ps1[1].is_nan());
+    assert_eq!(cpu.fpr[1], 2.0);
+    let expected = Cpu::FPSCR_FX | Cpu::FPSCR_VX | Cpu::FPSCR_VXISI;
+    assert_eq!(cpu.fpscr & expected, expected);
+    assert_eq!(cpu.cr_field(1), 0b1010);
+
+    // An enabled exception leaves the target unchanged
+    set(&mut cpu, 1, 7.0, 7.0);
+    cpu.fpscr = Cpu::FPSCR_ZE;
+    set(&mut cpu, 2, 1.0, 1.0);
+    set(&mut cpu, 3, 1.0, 0.0);
+    exec(&mut cpu, 0x10221824); // ps_div f1, f2, f3
+    assert_eq!(get(&cpu, 1), (7.0, 7.0));
+    assert_ne!(cpu.fpscr & Cpu::FPSCR_ZX, 0);
+}
+
+#[test]
+fn test_estimates() {
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    set(&mut cpu, 3, 1.0, 4.0);
+
+    // ps_res uses the same table as fres
+    exec(&mut cpu, 0x10201830); // ps_res f1, f3
+    assert_eq!(cpu.fpr[1].to_bits(), 0x3FEF_FF00_0000_0000);
+    assert_eq!(cpu.ps1[1], cpu.fpr[1] / 4.0);
+
+    // ps_rsqrte rounds the estimate of frsqrte to single precision
+    exec(&mut cpu, 0xFC201834); // frsqrte f1, f3
+    let expected = cpu.fpr[1] as f32 as f64;
+    exec(&mut cpu, 0x10201834); // ps_rsqrte f1, f3
+    assert_eq!(cpu.fpr[1], expected);
+    assert_eq!(cpu.ps1[1], expected / 2.0);
+
+    // Zeros produce infinities and raise ZX
+    cpu.fpscr = 0;
+    set(&mut cpu, 3, 2.0, -0.0);
+    exec(&mut cpu, 0x10201830); // ps_res f1, f3
+    assert_eq!(cpu.ps1[1], f64::NEG_INFINITY);
+    assert_ne!(cpu.fpscr & Cpu::FPSCR_ZX, 0);
+}
+
+#[test]
+fn test_moves_and_merges() {
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    set(&mut cpu, 2, 1.0, 2.0);
+    set(&mut cpu, 3, -3.0, 4.0);
+
+    exec(&mut cpu, 0x10201890); // ps_mr f1, f3
+    assert_eq!(get(&cpu, 1), (-3.0, 4.0));
+    exec(&mut cpu, 0x10201850); // ps_neg f1, f3
+    assert_eq!(get(&cpu, 1), (3.0, -4.0));
+    exec(&mut cpu, 0x10201A10); // ps_abs f1, f3
+    assert_eq!(get(&cpu, 1), (3.0, 4.0));
+    exec(&mut cpu, 0x10201910); // ps_nabs f1, f3
+    assert_eq!(get(&cpu, 1), (-3.0, -4.0));
+
+    exec(&mut cpu, 0x10221C20); // ps_merge00 f1, f2, f3
+    assert_eq!(get(&cpu, 1), (1.0, -3.0));
+    exec(&mut cpu, 0x10221C60); // ps_merge01 f1, f2, f3
+    assert_eq!(get(&cpu, 1), (1.0, 4.0));
+    exec(&mut cpu, 0x10221CA0); // ps_merge10 f1, f2, f3
+    assert_eq!(get(&cpu, 1), (2.0, -3.0));
+    exec(&mut cpu, 0x10221CE0); // ps_merge11 f1, f2, f3
+    assert_eq!(get(&cpu, 1), (2.0, 4.0));
+
+    // Merging a register with itself swaps its slots
+    exec(&mut cpu, 0x104214A0); // ps_merge10 f2, f2, f2
+    assert_eq!(get(&cpu, 2), (2.0, 1.0));
+
+    // Moves leave signaling NaNs untouched
+    set(&mut cpu, 3, f64::from_bits(0x7FF0_0000_0000_0001), 0.0);
+    cpu.fpscr = 0;
+    exec(&mut cpu, 0x10201890); // ps_mr f1, f3
+    assert_eq!(cpu.fpr[1].to_bits(), 0x7FF0_0000_0000_0001);
+    assert_eq!(cpu.fpscr, 0);
+}
+
+#[test]
+fn test_compare_and_select() {
+    let mut cpu = Cpu::new(Extensions::gekko_broadway());
+    set(&mut cpu, 2, 1.0, -0.0);
+    set(&mut cpu, 3, 2.0, f64::NAN);
+    set(&mut cpu, 4, 5.0, 6.0);
+
+    exec(&mut cpu, 0x11021800); // ps_cmpu0 cr2, f2, f3
+    assert_eq!(cpu.cr_field(2), 0b1000);
+    assert_eq!(cpu.fpscr & Cpu::FPSCR_FPCC, 0b1000 << 12);
+
+    // Ordered comparisons with quiet NaNs raise VXVC
+    exec(&mut cpu, 0x118218C0); // ps_cmpo1 cr3, f2, f3
+    assert_eq!(cpu.cr_field(3), 0b0001);
+    assert_ne!(cpu.fpscr & Cpu::FPSCR_VXVC, 0);
+
+    // -0.0 selects frC like +0.0
+    exec(&mut cpu, 0x1022192E); // ps_sel f1, f2, f4, f3
+    assert_eq!(get(&cpu, 1), (5.0, 6.0));
+    set(&mut cpu, 2, -1.0, f64::NAN);
+    exec(&mut cpu, 0x1022192E); // ps_sel f1, f2, f4, f3
+    assert_eq!(cpu.fpr[1], 2.0);
+    assert!(cpu.ps1[1].is_nan());
+}
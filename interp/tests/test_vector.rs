@@ -0,0 +1,459 @@
+use powerpc::{Extension, Extensions, Ins};
+use powerpc_interp::{Cpu, Memory, Ram};
+
+const BASE: u64 = 0x80000000;
+
+fn cpu() -> Cpu {
+    Cpu::new(Extensions::from_extension(Extension::AltiVec))
+}
+
+/// Executes a single instruction.
+fn run(cpu: &mut Cpu, ram: &mut Ram<Vec<u8>>, code: u32) {
+    cpu.pc = BASE;
+    cpu.execute(Ins::new(code, cpu.extensions), ram).unwrap();
+}
+
+/// Executes a single instruction without memory access.
+fn exec(cpu: &mut Cpu, code: u32) {
+    run(cpu, &mut Ram { base: BASE, data: vec![0u8; 0x100] }, code);
+}
+
+/// Executes `code` with v2, v3 and v4 set to `a`, `b` and `c`, returning v1.
+fn vector(cpu: &mut Cpu, code: u32, a: u128, b: u128, c: u128) -> u128 {
+    cpu.vr[2] = a;
+    cpu.vr[3] = b;
+    cpu.vr[4] = c;
+    exec(cpu, code);
+    cpu.vr[1]
+}
+
+/// Executes `code` and returns v1 and whether it set VSCR\[SAT\], clearing it.
+fn saturating(cpu: &mut Cpu, code: u32, a: u128, b: u128, c: u128) -> (u128, bool) {
+    let result = vector(cpu, code, a, b, c);
+    let sat = cpu.vscr & Cpu::VSCR_SAT != 0;
+    cpu.vscr &= !Cpu::VSCR_SAT;
+    (result, sat)
+}
+
+fn floats(values: [f32; 4]) -> u128 {
+    values.iter().fold(0, |acc, x| acc << 32 | x.to_bits() as u128)
+}
+
+fn to_floats(v: u128) -> [f32; 4] {
+    core::array::from_fn(|i| f32::from_bits((v >> (96 - 32 * i)) as u32))
+}
+
+fn to_words(v: u128) -> [u32; 4] {
+    core::array::from_fn(|i| (v >> (96 - 32 * i)) as u32)
+}
+
+#[test]
+fn test_integer_arithmetic() {
+    let mut cpu = cpu();
+    let a = 0x01FF7F80_00010002_FFFFFFFF_7FFFFFFF;
+    let b = 0x01010101_FFFF0001_00000001_00000001;
+    for (code, expected, sat) in [
+        (0x10221800, 0x02008081_FF000003_FFFFFF00_7FFFFF00, false), // vaddubm v1, v2, v3
+        (0x10221A00, 0x02FF8081_FFFF0003_FFFFFFFF_7FFFFFFF, true),  // vaddubs v1, v2, v3
+        (0x10221B00, 0x02007F81_FF000003_FFFFFF00_7FFFFF00, true),  // vaddsbs v1, v2, v3
+        (0x10221880, 0x03008081_00000003_00000000_80000000, false), // vadduwm v1, v2, v3
+        (0x10221B80, 0x03008081_00000003_00000000_7FFFFFFF, true),  // vaddsws v1, v2, v3
+        (0x10221980, 0x00000000_00000001_00000001_00000000, false), // vaddcuw v1, v2, v3
+        (0x10221D80, 0x00000001_00000000_00000001_00000001, false), // vsubcuw v1, v2, v3
+        (0x10221E00, 0x00FE7E7F_00000001_FFFFFFFE_7FFFFFFE, true),  // vsububs v1, v2, v3
+        (0x10221F80, 0x00FE7E7F_00020001_FFFFFFFE_7FFFFFFE, false), // vsubsws v1, v2, v3
+        (0x10221C02, 0x01804041_80800002_80808080_40808080, false), // vavgub v1, v2, v3
+        (0x10221D02, 0x010040C1_00000002_00000000_40000000, false), // vavgsb v1, v2, v3
+        (0x10221902, 0x01017F01_00010002_00000001_7F000001, false), // vmaxsb v1, v2, v3
+        (0x10221A02, 0x01010101_00010001_00000001_00000001, false), // vminub v1, v2, v3
+        (0x10221842, 0x01FF7F80_FFFF0002_FFFFFFFF_7FFFFFFF, false), // vmaxuh v1, v2, v3
+        (0x10221B82, 0x01010101_FFFF0001_FFFFFFFF_00000001, false), // vminsw v1, v2, v3
+    ] {
+        assert_eq!(saturating(&mut cpu, code, a, b, 0), (expected, sat), "{code:#010x}");
+    }
+
+    // SAT is sticky, and can be read and written with mfvscr and mtvscr
+    vector(&mut cpu, 0x10221A00, a, b, 0); // vaddubs v1, v2, v3
+    vector(&mut cpu, 0x10221800, a, b, 0); // vaddubm v1, v2, v3
+    exec(&mut cpu, 0x10200604); // mfvscr v1
+    assert_eq!(cpu.vr[1], Cpu::VSCR_SAT as u128);
+    cpu.vr[3] = 0xFFFFFFFF_FFFFFFFF_FFFFFFFF_00010000;
+    exec(&mut cpu, 0x10001E44); // mtvscr v3
+    assert_eq!(cpu.vscr, Cpu::VSCR_NJ);
+}
+
+#[test]
+fn test_integer_multiply() {
+    let mut cpu = cpu();
+    let a = 0x7FFF8000_00020003_FFFE0100_12345678;
+    let b = 0x7FFF7FFF_00040005_00030200_9ABCDEF0;
+    let c = 0x00010002_00030004_7FFF8000_FFFFFFFF;
+    for (code, expected, sat) in [
+        (0x10221A08, 0x3F013F80_00000000_00000002_0AD44A94, false), // vmuleub v1, v2, v3
+        (0x10221808, 0xFE010000_0008000F_02FA0000_26307080, false), // vmuloub v1, v2, v3
+        (0x10221B48, 0x3FFF0001_00000008_FFFFFFFA_F8CCA630, false), // vmulesh v1, v2, v3
+        (0x10221948, 0xC0008000_0000000F_00020000_F4D52080, false), // vmulosh v1, v2, v3
+        (0x10221920, 0x7FFF8003_00030004_7FFE8004_F198E9A9, false), // vmhaddshs v1, v2, v3, v4
+        (0x10221921, 0x7FFF8003_00030004_7FFF8004_F198E9A9, false), // vmhraddshs v1, v2, v3, v4
+        (0x10221922, 0x00028002_000B0013_7FF98000_A62F207F, false), // vmladduhm v1, v2, v3, v4
+        (0x10221924, 0x00027C84_0003001B_7FFF82FC_0000EC17, false), // vmsumubm v1, v2, v3, v4
+        (0x10221925, 0x0000FE84_0003001B_7FFF7FFC_0000EC17, false), // vmsummbm v1, v2, v3, v4
+        (0x10221927, 0x7FFF8003_0003001B_80047FFA_FFFFFFFF, true),  // vmsumuhs v1, v2, v3, v4
+        (0x10221928, 0x00008003_0003001B_80017FFA_EDA1C6AF, false), // vmsumshm v1, v2, v3, v4
+        (0x10221929, 0x00008003_0003001B_7FFFFFFF_EDA1C6AF, true),  // vmsumshs v1, v2, v3, v4
+    ] {
+        assert_eq!(saturating(&mut cpu, code, a, b, c), (expected, sat), "{code:#010x}");
+    }
+
+    // Saturation of the high halfword sums
+    let (result, sat) =
+        saturating(&mut cpu, 0x10221920, 0x8000 << 112, 0x8000 << 112, 0x7FFF << 112);
+    assert_eq!((result >> 112, sat), (0x7FFF, true)); // vmhaddshs v1, v2, v3, v4
+}
+
+#[test]
+fn test_sum_across() {
+    let mut cpu = cpu();
+    let a = 0x7FFFFFFF_00000001_FFFFFFFE_80000000;
+    let b = 0x00000000_00000005_00000000_00000010;
+    for (code, expected, sat) in [
+        (0x10221F88, 0x00000000_00000000_00000000_0000000E, false), // vsumsws v1, v2, v3
+        (0x10221E88, 0x00000000_7FFFFFFF_00000000_8000000E, true),  // vsum2sws v1, v2, v3
+    ] {
+        assert_eq!(saturating(&mut cpu, code, a, b, 0), (expected, sat), "{code:#010x}");
+    }
+    let a = 0x7F7F7F7F_80808080_01020304_FFFFFFFF;
+    let b = 0x7FFFFFF0_80000005_00000010_FFFFFFFF;
+    for (code, expected) in [
+        (0x10221F08, 0x7FFFFFFF_80000000_0000001A_FFFFFFFB), // vsum4sbs v1, v2, v3
+        (0x10221E08, 0x800001EC_80000205_0000001A_FFFFFFFF), // vsum4ubs v1, v2, v3
+        (0x10221E48, 0x7FFFFFFF_80000000_00000416_FFFFFFFD), // vsum4shs v1, v2, v3
+    ] {
+        assert_eq!(saturating(&mut cpu, code, a, b, 0), (expected, true), "{code:#010x}");
+    }
+}
+
+#[test]
+fn test_logical_and_shift() {
+    let mut cpu = cpu();
+    let a = 0x00112233_44556677_8899AABB_CCDDEEFF;
+    let b = 0x01020304_05060708_090A0B0C_0D0E0F1F;
+    let c = 0x1F001E01_1D021C03_10111213_00FF0F0E;
+    for (code, expected) in [
+        (0x10221C04, a & b),                                 // vand v1, v2, v3
+        (0x10221C44, a & !b),                                // vandc v1, v2, v3
+        (0x10221D04, !(a | b)),                              // vnor v1, v2, v3
+        (0x10221C84, a | b),                                 // vor v1, v2, v3
+        (0x10221CC4, a ^ b),                                 // vxor v1, v2, v3
+        (0x1022192A, 0x01112232_45576674_8888AAA8_CC0EEFFF), // vsel v1, v2, v3, v4
+        (0x10221804, 0x00441133_88553377_116655BB_997777FF), // vrlb v1, v2, v3
+        (0x10221884, 0x01122330_55667744_9AABB889_E66EF77F), // vrlw v1, v2, v3
+        (0x10221944, 0x00442330_15407700_6400B000_40008000), // vslh v1, v2, v3
+        (0x10221A84, 0x00011223_00445566_0008899A_00000001), // vsrw v1, v2, v3
+        (0x10221B84, 0x00011223_00445566_FFF8899A_FFFFFFFF), // vsraw v1, v2, v3
+        (0x10221B04, 0x00040403_02010077_C4E6F5FB_FEFFFFFF), // vsrab v1, v2, v3
+        (0x102219C4, 0x089119A2_2AB33BC4_4CD55DE6_6EF77F80), // vsl v1, v2, v3
+        (0x10221AC4, 0x00002244_6688AACC_EF113355_7799BBDD), // vsr v1, v2, v3
+        (0x10221C0C, 0x33445566_778899AA_BBCCDDEE_FF000000), // vslo v1, v2, v3
+        (0x10221C4C, 0x00000000_11223344_55667788_99AABBCC), // vsro v1, v2, v3
+        (0x102218EC, 0x33445566_778899AA_BBCCDDEE_FF010203), // vsldoi v1, v2, v3, 3
+    ] {
+        assert_eq!(vector(&mut cpu, code, a, b, c), expected, "{code:#010x}");
+    }
+}
+
+#[test]
+fn test_permute() {
+    let mut cpu = cpu();
+    let a = 0x00112233_44556677_8899AABB_CCDDEEFF;
+    let b = 0x01020304_05060708_090A0B0C_0D0E0F1F;
+    let c = 0x1F001E01_1D021C03_10111213_00FF0F0E;
+    for (code, expected) in [
+        (0x1022192B, 0x1F000F11_0E220D33_01020304_001FFFEE), // vperm v1, v2, v3, v4
+        (0x1022180C, 0x00011102_22033304_44055506_66077708), // vmrghb v1, v2, v3
+        (0x1022194C, 0x8899090A_AABB0B0C_CCDD0D0E_EEFF0F1F), // vmrglh v1, v2, v3
+        (0x1022188C, 0x00112233_01020304_44556677_05060708), // vmrghw v1, v2, v3
+        (0x1022198C, 0x8899AABB_090A0B0C_CCDDEEFF_0D0E0F1F), // vmrglw v1, v2, v3
+        (0x10251A0C, 0x06060606_06060606_06060606_06060606), // vspltb v1, v3, 5
+        (0x10271A4C, 0x0F1F0F1F_0F1F0F1F_0F1F0F1F_0F1F0F1F), // vsplth v1, v3, 7
+        (0x10221A8C, 0x090A0B0C_090A0B0C_090A0B0C_090A0B0C), // vspltw v1, v3, 2
+        (0x103D030C, 0xFDFDFDFD_FDFDFDFD_FDFDFDFD_FDFDFDFD), // vspltisb v1, -3
+        (0x102F034C, 0x000F000F_000F000F_000F000F_000F000F), // vspltish v1, 15
+        (0x1030038C, 0xFFFFFFF0_FFFFFFF0_FFFFFFF0_FFFFFFF0), // vspltisw v1, -16
+    ] {
+        assert_eq!(vector(&mut cpu, code, a, b, c), expected, "{code:#010x}");
+    }
+}
+
+#[test]
+fn test_pack_unpack() {
+    let mut cpu = cpu();
+    let a = 0x0001_00FF_0100_7FFF_8000_FFFF_FF80_007F;
+    let b = 0x1234_0080_FF7F_0000_0001_8001_7F00_00FE;
+    for (code, expected, sat) in [
+        (0x1022180E, 0x01FF00FF_00FF807F_34807F00_010100FE, false), // vpkuhum v1, v2, v3
+        (0x1022188E, 0x01FFFFFF_FFFFFF7F_FF80FF00_01FFFFFE, true),  // vpkuhus v1, v2, v3
+        (0x1022198E, 0x017F7F7F_80FF807F_7F7F8000_01807F7F, true),  // vpkshss v1, v2, v3
+        (0x1022190E, 0x01FFFFFF_0000007F_FF800000_0100FFFE, true),  // vpkshus v1, v2, v3
+    ] {
+        assert_eq!(saturating(&mut cpu, code, a, b, 0), (expected, sat), "{code:#010x}");
+    }
+    let a = 0x00000001_0000FFFF_00010000_7FFFFFFF;
+    let b = 0x80000000_FFFFFFFF_FFFF8000_00008000;
+    for (code, expected, sat) in [
+        (0x1022184E, 0x0001FFFF_0000FFFF_0000FFFF_80008000, false), // vpkuwum v1, v2, v3
+        (0x102218CE, 0x0001FFFF_FFFFFFFF_FFFFFFFF_FFFF8000, true),  // vpkuwus v1, v2, v3
+        (0x102219CE, 0x00017FFF_7FFF7FFF_8000FFFF_80007FFF, true),  // vpkswss v1, v2, v3
+        (0x1022194E, 0x0001FFFF_FFFFFFFF_00000000_00008000, true),  // vpkswus v1, v2, v3
+    ] {
+        assert_eq!(saturating(&mut cpu, code, a, b, 0), (expected, sat), "{code:#010x}");
+    }
+
+    let a = 0x01F8F8F8_00000000_FF80C0E0_12345678;
+    let b = 0x00FFFFFF_01070707_00000000_FE123456;
+    let result = vector(&mut cpu, 0x10221B0E, a, b, 0); // vpkpx v1, v2, v3
+    assert_eq!(result, 0xFFFF0000_C31C194F_7FFF8000_000008CA);
+
+    let b = 0x807F_0001_FF00_7C1F_8000_03E0_FFFF_1234;
+    for (code, expected) in [
+        (0x10201A0E, 0xFF80007F_00000001_FFFF0000_007C001F), // vupkhsb v1, v3
+        (0x10201A8E, 0xFF800000_0003FFE0_FFFFFFFF_00120034), // vupklsb v1, v3
+        (0x10201A4E, 0xFFFF807F_00000001_FFFFFF00_00007C1F), // vupkhsh v1, v3
+        (0x10201ACE, 0xFFFF8000_000003E0_FFFFFFFF_00001234), // vupklsh v1, v3
+        (0x10201B4E, 0xFF00031F_00000001_FF1F1800_001F001F), // vupkhpx v1, v3
+        (0x10201BCE, 0xFF000000_00001F00_FF1F1F1F_00041114), // vupklpx v1, v3
+    ] {
+        assert_eq!(vector(&mut cpu, code, 0, b, 0), expected, "{code:#010x}");
+    }
+}
+
+#[test]
+fn test_compare() {
+    let mut cpu = cpu();
+    let a = 0x00112233_44556677_8899AABB_CCDDEEFF;
+    let b = 0x00112233_44556677_8899AABB_CCDDEE00;
+
+    // CR6 records whether all or none of the elements matched
+    assert_eq!(vector(&mut cpu, 0x10221806, a, b, 0), !0xFF); // vcmpequb v1, v2, v3
+    assert_eq!(cpu.cr, 0);
+    vector(&mut cpu, 0x10221C06, a, b, 0); // vcmpequb. v1, v2, v3
+    assert_eq!(cpu.cr_field(6), 0b0000);
+    vector(&mut cpu, 0x10221C06, a, a, 0); // vcmpequb. v1, v2, v2
+    assert_eq!((cpu.vr[1], cpu.cr_field(6)), (u128::MAX, 0b1000));
+    vector(&mut cpu, 0x10221C06, a, !a, 0); // vcmpequb. v1, v2, v3
+    assert_eq!((cpu.vr[1], cpu.cr_field(6)), (0, 0b0010));
+
+    let result = vector(&mut cpu, 0x10221B46, a, b, 0); // vcmpgtsh v1, v2, v3
+    assert_eq!(result, 0xFFFF);
+    let result = vector(&mut cpu, 0x10221B86, a, b, 0); // vcmpgtsw v1, v2, v3
+    assert_eq!(result, 0xFFFFFFFF);
+    let result = vector(&mut cpu, 0x10221E86, a, b, 0); // vcmpgtuw. v1, v2, v3
+    assert_eq!((result, cpu.cr_field(6)), (0xFFFFFFFF, 0b0000));
+
+    let a = floats([1.0, f32::NAN, -0.0, 2.0]);
+    let b = floats([1.0, f32::NAN, 0.0, 3.0]);
+    let result = vector(&mut cpu, 0x102218C6, a, b, 0); // vcmpeqfp v1, v2, v3
+    assert_eq!(to_words(result), [!0, 0, !0, 0]);
+    let result = vector(&mut cpu, 0x10221AC6, b, a, 0); // vcmpgtfp v1, v3, v2
+    assert_eq!(to_words(result), [0, 0, 0, !0]);
+    let result = vector(&mut cpu, 0x10221DC6, b, a, 0); // vcmpgefp. v1, v3, v2
+    assert_eq!((to_words(result), cpu.cr_field(6)), ([!0, 0, !0, !0], 0b0000));
+    vector(&mut cpu, 0x10221CC6, b, b, 0); // vcmpeqfp. v1, v3, v3
+    assert_eq!(cpu.cr_field(6), 0b0000);
+
+    // Bounds compares set bit 0 if above the bound and bit 1 if below its negation
+    let a = floats([1.0, -3.0, 2.0, f32::NAN]);
+    let b = floats([2.0, 2.0, 2.0, 1.0]);
+    let result = vector(&mut cpu, 0x10221FC6, a, b, 0); // vcmpbfp. v1, v2, v3
+    assert_eq!(to_words(result), [0, 0x4000_0000, 0, 0xC000_0000]);
+    assert_eq!(cpu.cr_field(6), 0b0000);
+    let a = floats([1.0, -2.0, 2.0, 0.0]);
+    let result = vector(&mut cpu, 0x10221FC6, a, b, 0); // vcmpbfp. v1, v2, v3
+    assert_eq!((result, cpu.cr_field(6)), (0, 0b0010));
+    let a = floats([5.0, -2.0, 2.0, 0.0]);
+    let result = vector(&mut cpu, 0x10221FC6, a, b, 0); // vcmpbfp. v1, v2, v3
+    assert_eq!((to_words(result)[0], cpu.cr_field(6)), (0x8000_0000, 0b0000));
+}
+
+#[test]
+fn test_float_arithmetic() {
+    let mut cpu = cpu();
+    let a = floats([1.5, -2.0, 3.0e38, 1.0]);
+    let b = floats([2.25, 2.0, 3.0e38, f32::EPSILON / 2.0]);
+    let result = vector(&mut cpu, 0x1022180A, a, b, 0); // vaddfp v1, v2, v3
+    assert_eq!(to_floats(result), [3.75, 0.0, f32::INFINITY, 1.0]);
+    let result = vector(&mut cpu, 0x1022184A, a, b, 0); // vsubfp v1, v2, v3
+    assert_eq!(to_floats(result), [-0.75, -4.0, 0.0, 1.0 - f32::EPSILON / 2.0]);
+
+    // Multiply-add is fused
+    let x = 1.0 + 2f32.powi(-12);
+    let y = 1.0 + 2f32.powi(-11);
+    let a = floats([x, 1.0, 2.0, -1.0]);
+    let c = floats([x, 1.0, 3.0, 1.0]);
+    let result = vector(&mut cpu, 0x1022192E, a, floats([-y, -1.0, 1.0, 0.0]), c); // vmaddfp v1, v2, v4, v3
+    assert_eq!(to_floats(result), [2f32.powi(-24), 0.0, 7.0, -1.0]);
+    let result = vector(&mut cpu, 0x1022192F, a, floats([y, 1.0, 1.0, 0.0]), c); // vnmsubfp v1, v2, v4, v3
+    assert_eq!(to_floats(result), [-2f32.powi(-24), -0.0, -5.0, 1.0]);
+    assert!(to_floats(result)[1].is_sign_negative());
+
+    let a = floats([-0.0, 0.0, 1.0, -3.0]);
+    let b = floats([0.0, -0.0, 2.0, -4.0]);
+    let result = vector(&mut cpu, 0x10221C0A, a, b, 0); // vmaxfp v1, v2, v3
+    assert_eq!(to_words(result), [0, 0, 2f32.to_bits(), (-3f32).to_bits()]);
+    let result = vector(&mut cpu, 0x10221C4A, a, b, 0); // vminfp v1, v2, v3
+    assert_eq!(to_words(result), [0x8000_0000, 0x8000_0000, 1f32.to_bits(), (-4f32).to_bits()]);
+
+    // NaN operands are quieted in order vA, vB, vC, and invalid operations produce the default NaN
+    let a = (0x7F80_0001u128 << 96) | (1f32.to_bits() as u128) << 64 | 0x7F80_0000 << 32;
+    let b = (0x7FC0_0002u128 << 96) | 0xFF80_0003 << 64 | 0xFF80_0000 << 32;
+    let result = vector(&mut cpu, 0x1022180A, a, b, 0); // vaddfp v1, v2, v3
+    assert_eq!(to_words(result), [0x7FC0_0001, 0xFFC0_0003, 0x7FC0_0000, 0]);
+    let c = floats([1.0, f32::NAN, 1.0, 1.0]);
+    let result = vector(&mut cpu, 0x1022192E, floats([1.0; 4]), b, c); // vmaddfp v1, v2, v4, v3
+    assert_eq!(to_words(result)[..2], [0x7FC0_0002, 0xFFC0_0003]);
+    let result = vector(&mut cpu, 0x1022192E, floats([1.0; 4]), 0, c); // vmaddfp v1, v2, v4, v3
+    assert_eq!(to_words(result)[1], f32::NAN.to_bits());
+}
+
+#[test]
+fn test_denormals() {
+    let mut cpu = cpu();
+    let a = 0x0000_0001_8000_0001_0080_0001_0080_0000;
+    let b = 0x0000_0000_8000_0000_0080_0000_0000_0000;
+
+    // Java mode keeps denormal operands and results
+    let result = vector(&mut cpu, 0x1022180A, a, b, 0); // vaddfp v1, v2, v3
+    assert_eq!(to_words(result), [0x0000_0001, 0x8000_0001, 0x0100_0000, 0x0080_0000]);
+    let result = vector(&mut cpu, 0x1022184A, a, b, 0); // vsubfp v1, v2, v3
+    assert_eq!(to_words(result)[2], 0x0000_0001);
+
+    // Non-Java mode flushes them to zero, preserving the sign
+    cpu.vscr = Cpu::VSCR_NJ;
+    let result = vector(&mut cpu, 0x1022180A, a, b, 0); // vaddfp v1, v2, v3
+    assert_eq!(to_words(result), [0, 0x8000_0000, 0x0100_0000, 0x0080_0000]);
+    let result = vector(&mut cpu, 0x1022184A, a, b, 0); // vsubfp v1, v2, v3
+    assert_eq!(to_words(result)[2], 0);
+    let result = vector(&mut cpu, 0x102218C6, a, b, 0); // vcmpeqfp v1, v2, v3
+    assert_eq!(to_words(result), [!0, !0, 0, 0]);
+}
+
+#[test]
+fn test_float_conversions() {
+    let mut cpu = cpu();
+    let b = 0xFFFFFFFD_00000001_7FFFFFFF_00000000;
+    let result = vector(&mut cpu, 0x10211B4A, 0, b, 0); // vcfsx v1, v3, 1
+    assert_eq!(to_floats(result), [-1.5, 0.5, 2f32.powi(30), 0.0]);
+    let result = vector(&mut cpu, 0x10241B0A, 0, b, 0); // vcfux v1, v3, 4
+    assert_eq!(to_floats(result), [2f32.powi(28), 0.0625, 2f32.powi(27), 0.0]);
+
+    let b = floats([1.9, -1.9, 3.0e9, f32::NAN]);
+    let (result, sat) = saturating(&mut cpu, 0x10201BCA, 0, b, 0); // vctsxs v1, v3, 0
+    assert_eq!((to_words(result), sat), ([1, -1i32 as u32, 0x7FFF_FFFF, 0], true));
+    let b = floats([1.9, -1.9, 1.0, -0.5]);
+    let (result, sat) = saturating(&mut cpu, 0x10281BCA, 0, b, 0); // vctsxs v1, v3, 8
+    assert_eq!((to_words(result), sat), ([486, -486i32 as u32, 256, -128i32 as u32], false));
+    let b = floats([1.25, -1.0, 0.3, 2.0e9]);
+    let (result, sat) = saturating(&mut cpu, 0x10221B8A, 0, b, 0); // vctuxs v1, v3, 2
+    assert_eq!((to_words(result), sat), ([5, 0, 1, 0xFFFF_FFFF], true));
+    let b = floats([-0.2, 0.0, 1.0, 2.0]);
+    let (result, sat) = saturating(&mut cpu, 0x10221B8A, 0, b, 0); // vctuxs v1, v3, 2
+    assert_eq!((to_words(result), sat), ([0, 0, 4, 8], false));
+
+    let b = floats([2.5, -2.5, 1.5, -0.4]);
+    for (code, expected) in [
+        (0x10201A0A, [2.0, -2.0, 2.0, -0.0]), // vrfin v1, v3
+        (0x10201A4A, [2.0, -2.0, 1.0, -0.0]), // vrfiz v1, v3
+        (0x10201A8A, [3.0, -2.0, 2.0, -0.0]), // vrfip v1, v3
+        (0x10201ACA, [2.0, -3.0, 1.0, -1.0]), // vrfim v1, v3
+    ] {
+        let result = to_floats(vector(&mut cpu, code, 0, b, 0));
+        assert_eq!(result, expected, "{code:#010x}");
+        assert!(result[3].is_sign_negative());
+    }
+}
+
+#[test]
+fn test_estimates() {
+    let mut cpu = cpu();
+    let result = vector(&mut cpu, 0x1020190A, 0, floats([2.0, -0.0, f32::INFINITY, 4.0]), 0); // vrefp v1, v3
+    assert_eq!(to_floats(result), [0.5, f32::NEG_INFINITY, 0.0, 0.25]);
+    let result = vector(&mut cpu, 0x1020194A, 0, floats([4.0, -1.0, 0.0, f32::INFINITY]), 0); // vrsqrtefp v1, v3
+    assert_eq!(to_words(result), [0.5f32.to_bits(), 0x7FC0_0000, f32::INFINITY.to_bits(), 0]);
+    let result = vector(&mut cpu, 0x1020198A, 0, floats([3.0, -1.0, 0.0, 10.0]), 0); // vexptefp v1, v3
+    assert_eq!(to_floats(result), [8.0, 0.5, 1.0, 1024.0]);
+    let result = vector(&mut cpu, 0x102019CA, 0, floats([8.0, 0.5, 0.0, -1.0]), 0); // vlogefp v1, v3
+    assert_eq!(
+        to_words(result),
+        [3f32.to_bits(), (-1f32).to_bits(), f32::NEG_INFINITY.to_bits(), 0x7FC0_0000]
+    );
+}
+
+#[test]
+fn test_load_store() {
+    let mut ram = Ram { base: BASE, data: (0..0x100).map(|x| x as u8).collect() };
+    let mut cpu = cpu();
+    cpu.gpr[3] = BASE;
+
+    // The effective address is aligned down to 16 bytes
+    cpu.gpr[4] = 0x13;
+    run(&mut cpu, &mut ram, 0x7C2320CE); // lvx v1, r3, r4
+    assert_eq!(cpu.vr[1], 0x10111213_14151617_18191A1B_1C1D1E1F);
+    cpu.gpr[4] = 0x2F;
+    cpu.vr[1] = 0xFFEEDDCC_BBAA9988_77665544_33221100;
+    run(&mut cpu, &mut ram, 0x7C2321CE); // stvx v1, r3, r4
+    assert_eq!(ram.read_u64(BASE + 0x20), Ok(0xFFEEDDCC_BBAA9988));
+    assert_eq!(ram.read_u64(BASE + 0x28), Ok(0x77665544_33221100));
+    cpu.gpr[4] = 0x23;
+    run(&mut cpu, &mut ram, 0x7C2322CE); // lvxl v1, r3, r4
+    assert_eq!(cpu.vr[1], 0xFFEEDDCC_BBAA9988_77665544_33221100);
+
+    // Shift vectors for vperm
+    run(&mut cpu, &mut ram, 0x7C23200C); // lvsl v1, r3, r4
+    assert_eq!(cpu.vr[1], 0x03040506_0708090A_0B0C0D0E_0F101112);
+    run(&mut cpu, &mut ram, 0x7C23204C); // lvsr v1, r3, r4
+    assert_eq!(cpu.vr[1], 0x0D0E0F10_11121314_15161718_191A1B1C);
+    cpu.gpr[4] = 0x40;
+    run(&mut cpu, &mut ram, 0x7C23204C); // lvsr v1, r3, r4
+    assert_eq!(cpu.vr[1], 0x10111213_14151617_18191A1B_1C1D1E1F);
+
+    // Element accesses use the position of the address within the vector
+    cpu.vr[1] = 0;
+    cpu.gpr[4] = 0x13;
+    run(&mut cpu, &mut ram, 0x7C23200E); // lvebx v1, r3, r4
+    cpu.gpr[4] = 0x15;
+    run(&mut cpu, &mut ram, 0x7C23204E); // lvehx v1, r3, r4
+    cpu.gpr[4] = 0x1B;
+    run(&mut cpu, &mut ram, 0x7C23208E); // lvewx v1, r3, r4
+    assert_eq!(cpu.vr[1], 0x00000013_14150000_18191A1B_00000000);
+    cpu.vr[1] = 0xA0A1A2A3_A4A5A6A7_A8A9AAAB_ACADAEAF;
+    cpu.gpr[4] = 0x31;
+    run(&mut cpu, &mut ram, 0x7C23210E); // stvebx v1, r3, r4
+    cpu.gpr[4] = 0x33;
+    run(&mut cpu, &mut ram, 0x7C23214E); // stvehx v1, r3, r4
+    cpu.gpr[4] = 0x3E;
+    run(&mut cpu, &mut ram, 0x7C23218E); // stvewx v1, r3, r4
+    assert_eq!(ram.read_u32(BASE + 0x30), Ok(0x30A1A2A3));
+    assert_eq!(ram.read_u32(BASE + 0x3C), Ok(0xACADAEAF));
+    assert_eq!(ram.read_u32(BASE + 0x38), Ok(0x38393A3B));
+
+    // Left and right accesses split an unaligned vector at the 16-byte boundary
+    cpu.gpr[4] = 0x1D;
+    run(&mut cpu, &mut ram, 0x7C23240E); // lvlx v1, r3, r4
+    assert_eq!(cpu.vr[1], 0x1D1E1F00_00000000_00000000_00000000);
+    run(&mut cpu, &mut ram, 0x7C23244E); // lvrx v1, r3, r4
+    assert_eq!(cpu.vr[1], 0x00000010_11121314_15161718_191A1B1C);
+    cpu.gpr[4] = 0x10;
+    run(&mut cpu, &mut ram, 0x7C23244E); // lvrx v1, r3, r4
+    assert_eq!(cpu.vr[1], 0);
+    cpu.vr[1] = 0xA0A1A2A3_A4A5A6A7_A8A9AAAB_ACADAEAF;
+    cpu.gpr[4] = 0x4D;
+    run(&mut cpu, &mut ram, 0x7C23250E); // stvlx v1, r3, r4
+    cpu.gpr[4] = 0x5D;
+    run(&mut cpu, &mut ram, 0x7C23254E); // stvrx v1, r3, r4
+    assert_eq!(ram.read_u64(BASE + 0x48), Ok(0x48494A4B_4CA0A1A2));
+    assert_eq!(ram.read_u64(BASE + 0x50), Ok(0xA3A4A5A6_A7A8A9AA));
+    assert_eq!(ram.read_u64(BASE + 0x58), Ok(0xABACADAE_AF5D5E5F));
+
+    // Data stream hints are accepted
+    run(&mut cpu, &mut ram, 0x7C2322AC); // dst r3, r4, 1
+}
@@ -0,0 +1,236 @@
+use powerpc::{Extensions, Ins};
+use powerpc_interp::{Cpu, Exception, Ram};
+
+const BASE: u64 = 0x80000000;
+
+/// Executes `code` with v65, v98 and v100 set to `a`, `b` and `d`, returning v100.
+fn vmx(cpu: &mut Cpu, code: u32, a: u128, b: u128, d: u128) -> Result<u128, Exception> {
+    cpu.vr[65] = a;
+    cpu.vr[98] = b;
+    cpu.vr[100] = d;
+    cpu.pc = BASE;
+    let mut ram = Ram { base: BASE, data: vec![0u8; 0x100] };
+    cpu.execute(Ins::new(code, cpu.extensions), &mut ram)?;
+    Ok(cpu.vr[100])
+}
+
+fn floats(values: [f32; 4]) -> u128 {
+    values.iter().fold(0, |acc, x| acc << 32 | x.to_bits() as u128)
+}
+
+fn from_words(words: [u32; 4]) -> u128 {
+    words.iter().fold(0, |acc, &x| acc << 32 | x as u128)
+}
+
+fn to_words(v: u128) -> [u32; 4] {
+    core::array::from_fn(|i| (v >> (96 - 32 * i)) as u32)
+}
+
+const D: u128 = 0xDDDDDDD0_DDDDDDD1_DDDDDDD2_DDDDDDD3;
+
+#[test]
+fn test_arithmetic() {
+    let mut cpu = Cpu::new(Extensions::xenon());
+    let a = floats([1.0, 2.0, 3.0, 4.0]);
+    let b = floats([0.5; 4]);
+    let d = floats([10.0; 4]);
+    for (code, expected) in [
+        (0x1481141F, [1.5, 2.5, 3.5, 4.5]),     // vaddfp128 v100, v65, v98
+        (0x1481149F, [0.5, 1.0, 1.5, 2.0]),     // vmulfp128 v100, v65, v98
+        (0x148114DF, [10.5, 11.0, 11.5, 12.0]), // vmaddfp128 v100, v65, v98
+        (0x1481151F, [10.5, 20.5, 30.5, 40.5]), // vmaddcfp128 v100, v65, v98
+        (0x1481155F, [9.5, 9.0, 8.5, 8.0]),     // vnmsubfp128 v100, v65, v98
+    ] {
+        assert_eq!(vmx(&mut cpu, code, a, b, d), Ok(floats(expected)), "{code:#010x}");
+    }
+
+    // Dot products are splatted to all words
+    let b = floats([5.0, 6.0, 7.0, 8.0]);
+    let result = vmx(&mut cpu, 0x1481159F, a, b, 0); // vmsum3fp128 v100, v65, v98
+    assert_eq!(result, Ok(floats([38.0; 4])));
+    let result = vmx(&mut cpu, 0x148115DF, a, b, 0); // vmsum4fp128 v100, v65, v98
+    assert_eq!(result, Ok(floats([70.0; 4])));
+    let b = from_words([0, 0, 0, 0x7F80_0005]);
+    let result = vmx(&mut cpu, 0x148115DF, a, b, 0); // vmsum4fp128 v100, v65, v98
+    assert_eq!(result, Ok(from_words([0x7FC0_0005; 4])));
+    let result = vmx(&mut cpu, 0x1481159F, a, b, 0); // vmsum3fp128 v100, v65, v98
+    assert_eq!(result, Ok(0));
+    let a = floats([f32::INFINITY, 1.0, 1.0, 1.0]);
+    let result = vmx(&mut cpu, 0x1481159F, a, 0, 0); // vmsum3fp128 v100, v65, v98
+    assert_eq!(result, Ok(from_words([0x7FC0_0000; 4])));
+
+    // Operations shared with AltiVec
+    let a = floats([1.0, -2.0, 2.0, 0.0]);
+    let b = floats([2.0; 4]);
+    let result = vmx(&mut cpu, 0x188115CF, a, b, 0); // vcmpbfp128. v100, v65, v98
+    assert_eq!((result, cpu.cr_field(6)), (Ok(0), 0b0010));
+    let result = vmx(&mut cpu, 0x1881144F, a, a, 0); // vcmpeqfp128. v100, v65, v98
+    assert_eq!((result, cpu.cr_field(6)), (Ok(u128::MAX), 0b1000));
+    let b = floats([1.5, -1.5, 0.25, 1e10]);
+    let result = vmx(&mut cpu, 0x1881123F, 0, b, 0); // vctsxs128 v100, v98, 0x1
+    assert_eq!(result.map(to_words), Ok([3, -3i32 as u32, 0, 0x7FFF_FFFF]));
+    assert_eq!(cpu.vscr, Cpu::VSCR_SAT);
+}
+
+#[test]
+fn test_permute() {
+    let mut cpu = Cpu::new(Extensions::xenon());
+    let a = 0x00010203_04050607_08090A0B_0C0D0E0F;
+    let b = 0x10111213_14151617_18191A1B_1C1D1E1F;
+
+    // vperm128 takes its control vector from v0-v7
+    cpu.vr[5] = 0x1F1E1D1C_1B1A1918_17161514_13121110;
+    let result = vmx(&mut cpu, 0x1481154F, a, b, 0); // vperm128 v100, v65, v98, v5
+    assert_eq!(result, Ok(0x1F1E1D1C_1B1A1918_17161514_13121110));
+    let mask = 0x0000FFFF_FFFF0000_00FF00FF_0F0F0F0F;
+    let result = vmx(&mut cpu, 0x1481175F, a, b, mask); // vsel128 v100, v65, v98
+    assert_eq!(result, Ok(0x00011213_14150607_08190A1B_0C0D0E0F));
+    let result = vmx(&mut cpu, 0x1081151F, a, b, 0); // vsldoi128 v100, v65, v98, 4
+    assert_eq!(result, Ok(0x04050607_08090A0B_0C0D0E0F_10111213));
+    let result = vmx(&mut cpu, 0x1883173F, 0, b, 0); // vspltw128 v100, v98, 0x3
+    assert_eq!(result, Ok(0x1C1D1E1F_1C1D1E1F_1C1D1E1F_1C1D1E1F));
+    let result = vmx(&mut cpu, 0x189E177F, 0, 0, 0); // vspltisw128 v100, v98, -0x2
+    assert_eq!(result.map(to_words), Ok([0xFFFF_FFFE; 4]));
+    let halves = 0x8000_7FFF_0001_FFFF << 64;
+    let result = vmx(&mut cpu, 0x188017AF, 0, halves, 0); // vupkhsh128 v100, v98
+    assert_eq!(result, Ok(0xFFFF8000_00007FFF_00000001_FFFFFFFF));
+
+    // Each pair of bits of PERM selects a word
+    let result = vmx(&mut cpu, 0x189B121F, 0, b, 0); // vpermwi128 v100, v98, xyzw
+    assert_eq!(result, Ok(b));
+    let result = vmx(&mut cpu, 0x188413DF, 0, b, 0); // vpermwi128 v100, v98, wzyx
+    assert_eq!(result, Ok(0x1C1D1E1F_18191A1B_14151617_10111213));
+
+    // Rotated words are inserted where the mask is set
+    let result = vmx(&mut cpu, 0x1886175F, 0, b, D); // vrlimi128 v100, v98, 0x6, 1
+    assert_eq!(result, Ok(0xDDDDDDD0_18191A1B_1C1D1E1F_DDDDDDD3));
+}
+
+#[test]
+fn test_pack_d3d() {
+    let mut cpu = Cpu::new(Extensions::xenon());
+
+    // Values are biased by 3.0, and rotated into place: a shift of 3 moves a 32-bit
+    // result to word 0
+    let b = from_words([0x4040_0012, 0x4040_0034, 0x4040_0056, 0x4040_0078]);
+    let result = vmx(&mut cpu, 0x188116DF, 0, b, D); // vpkd3d128 v100, v98, D3DCOLOR, VPACK_32, 3
+    assert_eq!(result.map(to_words), Ok([0x7812_3456, 0xDDDD_DDD1, 0xDDDD_DDD2, 0xDDDD_DDD3]));
+    let b = floats([5.0, 2.0, f32::NAN, -1.0]);
+    let result = vmx(&mut cpu, 0x188116DF, 0, b, D); // vpkd3d128 v100, v98, D3DCOLOR, VPACK_32, 3
+    assert_eq!(result.map(|v| to_words(v)[0]), Ok(0x00FF_0000));
+
+    for (code, b, expected) in [
+        // vpkd3d128 v100, v98, NORMSHORT2, VPACK_32, 0
+        (0x1885161F, [0x4040_1234, 0x403F_FFFE, 0, 0], [0, 0, 0, 0x1234_FFFE]),
+        (0x1885161F, [0xC120_0000, 0x4100_0000, 0, 0], [0, 0, 0, 0x8001_7FFF]),
+        // vpkd3d128 v100, v98, NORMPACKED32, VPACK_32, 0
+        (0x1889161F, [0x4040_0001, 0x403F_FFFF, 0x4040_01FF, 0x4040_0002], [0, 0, 0, 0x9FFF_FC01]),
+        // vpkd3d128 v100, v98, FLOAT16_2, VPACK_32, 1
+        (0x188D165F, [0x3F80_0000, 0xC020_0000, 0, 0], [0, 0, 0x3C00_C100, 0]),
+        // vpkd3d128 v100, v98, NORMSHORT4, VPACK_64LO, 0
+        (
+            0x1892161F,
+            [0x4040_0001, 0x403F_FFFF, 0x4040_7FFF, 0xC2C8_0000],
+            [0, 0, 0x0001_FFFF, 0x7FFF_8001],
+        ),
+        // vpkd3d128 v100, v98, NORMPACKED64, VPACK_64LO, 0
+        (
+            0x189A161F,
+            [0x4040_0001, 0x403F_FFFF, 0x4047_FFFF, 0x4040_000F],
+            [0, 0, 0xF7FF_FFFF, 0xFFF0_0001],
+        ),
+    ] {
+        let result = vmx(&mut cpu, code, 0, from_words(b), 0);
+        assert_eq!(result, Ok(from_words(expected)), "{code:#010x}");
+    }
+
+    // Half precision rounds toward zero, and saturates instead of overflowing
+    let b = from_words([0x3F80_1800, 65536f32.to_bits(), 0x8000_0000, 0x7FC0_0000]);
+    let result = vmx(&mut cpu, 0x1896169F, 0, b, D); // vpkd3d128 v100, v98, FLOAT16_4, VPACK_64LO, 2
+    assert_eq!(result.map(to_words), Ok([0x3C00_7BFF, 0x8000_7E00, 0xDDDD_DDD2, 0xDDDD_DDD3]));
+
+    // 64-bit results which would wrap around insert only the low or high word
+    let b = from_words([0x4040_0001, 0x403F_FFFF, 0x4040_7FFF, 0xC2C8_0000]);
+    let result = vmx(&mut cpu, 0x189216DF, 0, b, D); // vpkd3d128 v100, v98, NORMSHORT4, VPACK_64LO, 3
+    assert_eq!(result.map(to_words), Ok([0x7FFF_8001, 0xDDDD_DDD1, 0xDDDD_DDD2, 0xDDDD_DDD3]));
+    let result = vmx(&mut cpu, 0x189316DF, 0, b, D); // vpkd3d128 v100, v98, NORMSHORT4, VPACK_64HI, 3
+    assert_eq!(result.map(to_words), Ok([0xDDDD_DDD0, 0xDDDD_DDD1, 0xDDDD_DDD2, 0x0001_FFFF]));
+
+    // Reserved types and masks are not supported
+    for code in [
+        0x189D161F, // vpkd3d128 v100, v98, 7, VPACK_32, 0
+        0x1880161F, // vpkd3d128 v100, v98, D3DCOLOR, 0, 0
+    ] {
+        let ins = Ins::new(code, cpu.extensions);
+        assert_eq!(vmx(&mut cpu, code, 0, 0, 0), Err(Exception::Unimplemented { pc: BASE, ins }));
+    }
+}
+
+#[test]
+fn test_unpack_d3d() {
+    let mut cpu = Cpu::new(Extensions::xenon());
+    for (code, high, low, expected) in [
+        // vupkd3d128 v100, v98, 0x0
+        (0x188017FF, 0, 0x7812_3456, [0x3F80_0012, 0x3F80_0034, 0x3F80_0056, 0x3F80_0078]),
+        // vupkd3d128 v100, v98, 0x4
+        (0x188417FF, 0, 0x1234_FFFE, [0x4040_1234, 0x403F_FFFE, 0, 0x3F80_0000]),
+        (0x188417FF, 0, 0x8000_0001, [0x7FC0_0000, 0x4040_0001, 0, 0x3F80_0000]),
+        // vupkd3d128 v100, v98, 0x8
+        (0x188817FF, 0, 0x9FFF_FC01, [0x4040_0001, 0x403F_FFFF, 0x4040_01FF, 0x4040_0002]),
+        // vupkd3d128 v100, v98, 0xc
+        (0x188C17FF, 0, 0x3C00_C100, [0x3F80_0000, 0xC020_0000, 0, 0x3F80_0000]),
+        // vupkd3d128 v100, v98, 0x10
+        (
+            0x189017FF,
+            0x0001_FFFF,
+            0x7FFF_8001,
+            [0x4040_0001, 0x403F_FFFF, 0x4040_7FFF, 0x403F_8001],
+        ),
+        // vupkd3d128 v100, v98, 0x14
+        (
+            0x189417FF,
+            0x3C00_0001,
+            0xFC00_7E00,
+            [0x3F80_0000, 0x3380_0000, 0xFF80_0000, 0x7FC0_0000],
+        ),
+        // vupkd3d128 v100, v98, 0x18
+        (
+            0x189817FF,
+            0xF7FF_FFFF,
+            0xFFF0_0001,
+            [0x4040_0001, 0x403F_FFFF, 0x4047_FFFF, 0x4040_000F],
+        ),
+    ] {
+        let result = vmx(&mut cpu, code, 0, from_words([0xEEEE_EEEE, 0xEEEE_EEEE, high, low]), 0);
+        assert_eq!(result.map(to_words), Ok(expected), "{code:#010x}");
+    }
+}
+
+#[test]
+fn test_load_store() {
+    let mut ram = Ram { base: BASE, data: (0..0x40).map(|x| x as u8).collect::<Vec<_>>() };
+    let mut cpu = Cpu::new(Extensions::xenon());
+    cpu.gpr[3] = BASE;
+    cpu.gpr[4] = 0x13;
+    let mut run = |cpu: &mut Cpu, code| {
+        cpu.pc = BASE;
+        cpu.execute(Ins::new(code, cpu.extensions), &mut ram).unwrap();
+    };
+
+    run(&mut cpu, 0x108320CF); // lvx128 v100, r3, r4
+    assert_eq!(cpu.vr[100], 0x10111213_14151617_18191A1B_1C1D1E1F);
+    run(&mut cpu, 0x1083240F); // lvlx128 v100, r3, r4
+    assert_eq!(cpu.vr[100], 0x13141516_1718191A_1B1C1D1E_1F000000);
+    run(&mut cpu, 0x1083200F); // lvsl128 v100, r3, r4
+    assert_eq!(cpu.vr[100], 0x03040506_0708090A_0B0C0D0E_0F101112);
+    cpu.vr[100] = 0xA0A1A2A3_A4A5A6A7_A8A9AAAB_ACADAEAF;
+    cpu.gpr[4] = 0x2B;
+    run(&mut cpu, 0x1083218F); // stvewx128 v100, r3, r4
+    cpu.gpr[4] = 0x3F;
+    run(&mut cpu, 0x108321CF); // stvx128 v100, r3, r4
+    assert_eq!(
+        &ram.data[0x20..0x30],
+        b"\x20\x21\x22\x23\x24\x25\x26\x27\xA8\xA9\xAA\xAB\x2C\x2D\x2E\x2F"
+    );
+    assert_eq!(ram.data[0x30..0x40], cpu.vr[100].to_be_bytes());
+}
@@ -0,0 +1,176 @@
+use crate::jump_table::detect_jump_table;
+use core::fmt::{self, Display, Formatter};
+use powerpc::{Extensions, FlowControl, Ins};
+use powerpc_interp::{Memory, MemoryFault};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// An error raised while recovering or analyzing functions.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum RecompError {
+    /// An instruction could not be read.
+    MemoryFault { addr: u64 },
+    /// An instruction has effects not represented in the IR.
+    /// (See [Stmt::Intrinsic](crate::ir::Stmt::Intrinsic))
+    Unsupported { addr: u32 },
+}
+
+impl Display for RecompError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            RecompError::MemoryFault { addr } => {
+                write!(f, "memory fault reading code at {addr:#x}")
+            }
+            RecompError::Unsupported { addr } => write!(f, "unsupported instruction at {addr:#x}"),
+        }
+    }
+}
+
+/// Recovers the control flow of functions, for recompilation.
+///
+/// ```
+/// use powerpc::Extensions;
+/// use powerpc_interp::{Memory, Ram};
+/// use powerpc_recomp::Recompiler;
+///
+/// let mut ram = Ram { base: 0x80000000, data: vec![0u8; 0x100] };
+/// ram.write_u32(0x80000000, 0x38630001).unwrap(); // addi r3, r3, 0x1
+/// ram.write_u32(0x80000004, 0x4E800020).unwrap(); // blr
+/// let mut recompiler = Recompiler::new(Extensions::gekko_broadway());
+/// recompiler.add_function(0x80000000, None);
+/// let program = recompiler.analyze(&mut ram).unwrap();
+/// assert_eq!(program.functions[&0x80000000].blocks.len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Recompiler {
+    extensions: Extensions,
+    functions: BTreeMap<u32, Option<u32>>,
+    jump_tables: BTreeMap<u32, Vec<u32>>,
+}
+
+/// Recovered functions, ready to be emitted as source code.
+#[derive(Clone, Debug, Default)]
+pub struct Program {
+    /// Functions by address.
+    pub functions: BTreeMap<u32, Function>,
+}
+
+/// A recovered function.
+#[derive(Clone, Debug)]
+pub struct Function {
+    /// The address of the entry point.
+    pub address: u32,
+    /// Basic blocks by address.
+    ///
+    /// Blocks start at the entry point and at branch targets. Conditional branches
+    /// and calls do not end a block.
+    pub blocks: BTreeMap<u32, Vec<Ins>>,
+    /// The targets of jump tables, by the address of their `bctr`.
+    pub jump_tables: BTreeMap<u32, Vec<u32>>,
+}
+
+impl Function {
+    /// The name of the recompiled function.
+    pub fn name(&self) -> String {
+        format!("fn_{:08x}", self.address)
+    }
+}
+
+impl Recompiler {
+    /// Creates a recompiler for code using the given extensions.
+    pub fn new(extensions: Extensions) -> Self {
+        Self { extensions, functions: BTreeMap::new(), jump_tables: BTreeMap::new() }
+    }
+
+    /// Adds a function at `address`.
+    ///
+    /// If `size` is known (e.g. from a symbol table), branches leaving the function are
+    /// tail calls. Otherwise, the function spans all code reachable from its entry point
+    /// without crossing the entry point of another function.
+    pub fn add_function(&mut self, address: u32, size: Option<u32>) {
+        self.functions.insert(address, size);
+    }
+
+    /// Sets the targets of the jump table used by the `bctr` at `bctr`.
+    ///
+    /// Jump tables not added are detected from the instructions preceding the `bctr`.
+    pub fn add_jump_table(&mut self, bctr: u32, targets: Vec<u32>) {
+        self.jump_tables.insert(bctr, targets);
+    }
+
+    /// Recovers the control flow of all added functions.
+    pub fn analyze(&self, mem: &mut dyn Memory) -> Result<Program, RecompError> {
+        let mut program = Program::default();
+        for (&address, &size) in &self.functions {
+            program.functions.insert(address, self.analyze_function(mem, address, size)?);
+        }
+        Ok(program)
+    }
+
+    fn analyze_function(
+        &self,
+        mem: &mut dyn Memory,
+        address: u32,
+        size: Option<u32>,
+    ) -> Result<Function, RecompError> {
+        // Whether an address belongs to this function, rather than being a tail call
+        let inside = |target: u32| {
+            (target == address || !self.functions.contains_key(&target))
+                && size.map_or(true, |size| target.wrapping_sub(address) < size)
+        };
+        let mut leaders = BTreeSet::from([address]);
+        let mut code = BTreeMap::new();
+        let mut jump_tables = BTreeMap::new();
+        let mut pending = vec![address];
+        while let Some(mut addr) = pending.pop() {
+            while !code.contains_key(&addr) {
+                let raw = mem
+                    .read_u32(addr as u64)
+                    .map_err(|MemoryFault { addr }| RecompError::MemoryFault { addr })?;
+                let ins = Ins::new(raw, self.extensions);
+                code.insert(addr, ins);
+                let flow = ins.flow_control();
+                let targets = match flow {
+                    FlowControl::Branch { .. } => ins.branch_dest(addr).into_iter().collect(),
+                    FlowControl::IndirectJump { .. } => {
+                        let table = match self.jump_tables.get(&addr) {
+                            Some(table) => Some(table.clone()),
+                            None => detect_jump_table(mem, self.extensions, addr, address),
+                        };
+                        let targets = table.clone().unwrap_or_default();
+                        if let Some(table) = table {
+                            jump_tables.insert(addr, table);
+                        }
+                        targets
+                    }
+                    _ => vec![],
+                };
+                for target in targets.into_iter().filter(|&target| inside(target)) {
+                    leaders.insert(target);
+                    pending.push(target);
+                }
+                let next = addr.wrapping_add(4);
+                if !flow.falls_through() || !inside(next) {
+                    break;
+                }
+                addr = next;
+            }
+        }
+
+        // Split the code at branch targets and gaps
+        let mut blocks = BTreeMap::<u32, Vec<Ins>>::new();
+        let mut current = None;
+        for (addr, ins) in code {
+            match current {
+                Some((start, next)) if next == addr && !leaders.contains(&addr) => {
+                    blocks.entry(start).or_default().push(ins);
+                    current = Some((start, addr.wrapping_add(4)));
+                }
+                _ => {
+                    blocks.insert(addr, vec![ins]);
+                    current = Some((addr, addr.wrapping_add(4)));
+                }
+            }
+        }
+        Ok(Function { address, blocks, jump_tables })
+    }
+}
@@ -0,0 +1,640 @@
+//! A small typed SSA intermediate representation of PowerPC code.
+//!
+//! Instructions are lifted to statements on [Value]s, each defined once by a
+//! [Stmt::Let]. Registers are read and written explicitly with [Expr::Get] and
+//! [Stmt::Set], so values are local to a block and the state between blocks is
+//! held in registers. Condition register bits and XER flags are separate
+//! registers, and the flags set by an instruction are computed by explicit
+//! operations, which [Function::eliminate_dead_flags] removes if they are never read.
+//!
+//! Code is lifted in 32-bit mode: GPRs, LR, CTR and SPRs are `i32`, and
+//! instructions operating on doublewords are [intrinsics](Stmt::Intrinsic).
+//! FPRs are `f64`, and only moved and loaded or stored as doubles; floating-point
+//! arithmetic, which depends on and updates the FPSCR, is also left to intrinsics.
+
+use core::fmt::{self, Display, Formatter};
+use powerpc::Ins;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// The type of a value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Type {
+    I1,
+    I8,
+    I16,
+    I32,
+    I64,
+    F64,
+}
+
+impl Type {
+    /// The number of bits.
+    pub fn bits(self) -> u32 {
+        match self {
+            Type::I1 => 1,
+            Type::I8 => 8,
+            Type::I16 => 16,
+            Type::I32 => 32,
+            Type::I64 | Type::F64 => 64,
+        }
+    }
+
+    /// The integer type with the given number of bytes. (1, 2, 4 or 8)
+    pub fn from_size(size: u32) -> Option<Type> {
+        match size {
+            1 => Some(Type::I8),
+            2 => Some(Type::I16),
+            4 => Some(Type::I32),
+            8 => Some(Type::I64),
+            _ => None,
+        }
+    }
+
+    /// Returns the mask of the bits of the type.
+    pub fn mask(self) -> u64 {
+        u64::MAX >> (64 - self.bits())
+    }
+}
+
+impl Display for Type {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Type::F64 => f.write_str("f64"),
+            _ => write!(f, "i{}", self.bits()),
+        }
+    }
+}
+
+/// A register of the processor state.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Reg {
+    /// A general-purpose register.
+    Gpr(u8),
+    /// A floating-point register.
+    Fpr(u8),
+    /// A condition register bit, numbered from the most significant bit.
+    Cr(u8),
+    /// XER\[SO\], the summary overflow flag.
+    So,
+    /// XER\[OV\], the overflow flag.
+    Ov,
+    /// XER\[CA\], the carry flag.
+    Ca,
+    /// The link register.
+    Lr,
+    /// The count register.
+    Ctr,
+    /// Another special-purpose register.
+    Spr(u16),
+}
+
+impl Reg {
+    /// Returns the type of the register.
+    pub fn ty(self) -> Type {
+        match self {
+            Reg::Cr(_) | Reg::So | Reg::Ov | Reg::Ca => Type::I1,
+            Reg::Gpr(_) | Reg::Lr | Reg::Ctr | Reg::Spr(_) => Type::I32,
+            Reg::Fpr(_) => Type::F64,
+        }
+    }
+
+    /// Whether the register is a condition register bit or an XER flag.
+    pub fn is_flag(self) -> bool {
+        self.ty() == Type::I1
+    }
+
+    /// The bit of the register in a [FlagSet].
+    fn flag_bit(self) -> Option<u64> {
+        match self {
+            Reg::Cr(bit) => Some(1 << bit),
+            Reg::So => Some(1 << 32),
+            Reg::Ov => Some(1 << 33),
+            Reg::Ca => Some(1 << 34),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Reg {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match *self {
+            Reg::Gpr(reg) => write!(f, "r{reg}"),
+            Reg::Fpr(reg) => write!(f, "f{reg}"),
+            Reg::Cr(bit) => {
+                write!(f, "cr{}.{}", bit / 4, ["lt", "gt", "eq", "so"][bit as usize % 4])
+            }
+            Reg::So => f.write_str("xer.so"),
+            Reg::Ov => f.write_str("xer.ov"),
+            Reg::Ca => f.write_str("xer.ca"),
+            Reg::Lr => f.write_str("lr"),
+            Reg::Ctr => f.write_str("ctr"),
+            Reg::Spr(spr) => write!(f, "spr{spr}"),
+        }
+    }
+}
+
+/// An SSA value, defined by a [Stmt::Let].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Value(pub u32);
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "%{}", self.0)
+    }
+}
+
+/// An operation on one value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum UnaryOp {
+    /// Bitwise complement.
+    Not,
+    /// Two's complement negation.
+    Neg,
+    /// The number of leading zero bits.
+    Clz,
+    /// Reverses the order of the bytes.
+    Bswap,
+    /// Zero-extends to the type of the result.
+    Zext,
+    /// Sign-extends to the type of the result.
+    Sext,
+    /// Truncates to the type of the result.
+    Trunc,
+    /// Reinterprets the bits as the type of the result. (`i64` and `f64`)
+    Bitcast,
+}
+
+impl Display for UnaryOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            UnaryOp::Not => "not",
+            UnaryOp::Neg => "neg",
+            UnaryOp::Clz => "clz",
+            UnaryOp::Bswap => "bswap",
+            UnaryOp::Zext => "zext",
+            UnaryOp::Sext => "sext",
+            UnaryOp::Trunc => "trunc",
+            UnaryOp::Bitcast => "bitcast",
+        })
+    }
+}
+
+impl UnaryOp {
+    /// Evaluates the operation on a value of type `from`, giving a value of type `ty`.
+    pub fn eval(self, ty: Type, from: Type, a: u64) -> u64 {
+        let result = match self {
+            UnaryOp::Not => !a,
+            UnaryOp::Neg => a.wrapping_neg(),
+            UnaryOp::Clz => (a.leading_zeros() - (64 - ty.bits())) as u64,
+            UnaryOp::Bswap => a.swap_bytes() >> (64 - ty.bits()),
+            UnaryOp::Zext | UnaryOp::Trunc | UnaryOp::Bitcast => a,
+            UnaryOp::Sext => sext(a, from) as u64,
+        };
+        result & ty.mask()
+    }
+}
+
+/// Sign-extends a value of the given type.
+fn sext(value: u64, ty: Type) -> i64 {
+    let shift = 64 - ty.bits();
+    ((value << shift) as i64) >> shift
+}
+
+/// Rotates a value of the given type left.
+pub(crate) fn rotl(ty: Type, value: u64, n: u32) -> u64 {
+    match n % ty.bits() {
+        0 => value,
+        n => ((value << n) | (value >> (ty.bits() - n))) & ty.mask(),
+    }
+}
+
+/// An operation on two values of the same type.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    /// The high half of the signed product.
+    MulHs,
+    /// The high half of the unsigned product.
+    MulHu,
+    /// Signed division. Dividing by zero or overflowing gives -1 for negative
+    /// dividends and 0 otherwise, as `divw` on the 750.
+    DivS,
+    /// Unsigned division. Dividing by zero gives 0.
+    DivU,
+    And,
+    Or,
+    Xor,
+    /// Shifts left, giving 0 if the amount is at least the width of the type.
+    Shl,
+    /// Shifts right logically, giving 0 if the amount is at least the width of the type.
+    LShr,
+    /// Shifts right arithmetically, giving the sign if the amount is at least the width
+    /// of the type.
+    AShr,
+    /// Rotates left, modulo the width of the type.
+    Rotl,
+    /// Comparisons, giving an `i1`.
+    Eq,
+    Ne,
+    Slt,
+    Sgt,
+    Ult,
+    Ugt,
+}
+
+impl BinaryOp {
+    /// Whether the operation is a comparison, giving an `i1`.
+    pub fn is_comparison(self) -> bool {
+        matches!(
+            self,
+            BinaryOp::Eq
+                | BinaryOp::Ne
+                | BinaryOp::Slt
+                | BinaryOp::Sgt
+                | BinaryOp::Ult
+                | BinaryOp::Ugt
+        )
+    }
+
+    /// Evaluates the operation on values of type `ty`. Comparisons give 0 or 1.
+    pub fn eval(self, ty: Type, a: u64, b: u64) -> u64 {
+        let bits = ty.bits() as u64;
+        let (sa, sb) = (sext(a, ty), sext(b, ty));
+        let result = match self {
+            BinaryOp::Add => a.wrapping_add(b),
+            BinaryOp::Sub => a.wrapping_sub(b),
+            BinaryOp::Mul => a.wrapping_mul(b),
+            BinaryOp::MulHs => ((sa as i128 * sb as i128) >> bits) as u64,
+            BinaryOp::MulHu => ((a as u128 * b as u128) >> bits) as u64,
+            BinaryOp::DivS if sb == 0 || (sa == sext(1 << (bits - 1), ty) && sb == -1) => {
+                (sa >> 63) as u64
+            }
+            BinaryOp::DivS => (sa / sb) as u64,
+            BinaryOp::DivU => a.checked_div(b).unwrap_or(0),
+            BinaryOp::And => a & b,
+            BinaryOp::Or => a | b,
+            BinaryOp::Xor => a ^ b,
+            BinaryOp::Shl if b >= bits => 0,
+            BinaryOp::Shl => a << b,
+            BinaryOp::LShr if b >= bits => 0,
+            BinaryOp::LShr => a >> b,
+            BinaryOp::AShr => (sa >> b.min(63)) as u64,
+            BinaryOp::Rotl => rotl(ty, a, (b % bits) as u32),
+            BinaryOp::Eq => return (a == b) as u64,
+            BinaryOp::Ne => return (a != b) as u64,
+            BinaryOp::Slt => return (sa < sb) as u64,
+            BinaryOp::Sgt => return (sa > sb) as u64,
+            BinaryOp::Ult => return (a < b) as u64,
+            BinaryOp::Ugt => return (a > b) as u64,
+        };
+        result & ty.mask()
+    }
+}
+
+impl Display for BinaryOp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            BinaryOp::Add => "add",
+            BinaryOp::Sub => "sub",
+            BinaryOp::Mul => "mul",
+            BinaryOp::MulHs => "mulhs",
+            BinaryOp::MulHu => "mulhu",
+            BinaryOp::DivS => "divs",
+            BinaryOp::DivU => "divu",
+            BinaryOp::And => "and",
+            BinaryOp::Or => "or",
+            BinaryOp::Xor => "xor",
+            BinaryOp::Shl => "shl",
+            BinaryOp::LShr => "lshr",
+            BinaryOp::AShr => "ashr",
+            BinaryOp::Rotl => "rotl",
+            BinaryOp::Eq => "eq",
+            BinaryOp::Ne => "ne",
+            BinaryOp::Slt => "slt",
+            BinaryOp::Sgt => "sgt",
+            BinaryOp::Ult => "ult",
+            BinaryOp::Ugt => "ugt",
+        })
+    }
+}
+
+/// The definition of a value.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Expr {
+    /// A constant, truncated to the type of the value.
+    Const(u64),
+    /// The current value of a register.
+    Get(Reg),
+    Unary(UnaryOp, Value),
+    Binary(BinaryOp, Value, Value),
+    /// A big-endian load of the size of the value's type.
+    Load(Value),
+}
+
+impl Expr {
+    /// Whether the expression has no effect besides defining its value.
+    pub fn is_pure(&self) -> bool {
+        !matches!(self, Expr::Load(_))
+    }
+
+    /// Returns the values used by the expression.
+    pub fn operands(&self) -> impl Iterator<Item = Value> {
+        let operands = match *self {
+            Expr::Const(_) | Expr::Get(_) => [None, None],
+            Expr::Unary(_, a) | Expr::Load(a) => [Some(a), None],
+            Expr::Binary(_, a, b) => [Some(a), Some(b)],
+        };
+        operands.into_iter().flatten()
+    }
+}
+
+impl Display for Expr {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Const(value) => write!(f, "const {value:#x}"),
+            Expr::Get(reg) => write!(f, "get {reg}"),
+            Expr::Unary(op, a) => write!(f, "{op} {a}"),
+            Expr::Binary(op, a, b) => write!(f, "{op} {a}, {b}"),
+            Expr::Load(addr) => write!(f, "load {addr}"),
+        }
+    }
+}
+
+/// The target of a call.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Callee {
+    Direct(u32),
+    Indirect(Value),
+}
+
+impl Display for Callee {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Callee::Direct(addr) => write!(f, "{addr:#x}"),
+            Callee::Indirect(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// A statement.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Stmt {
+    /// Defines a value.
+    Let { value: Value, ty: Type, expr: Expr },
+    /// Writes a register.
+    Set(Reg, Value),
+    /// A big-endian store of a value.
+    Store { ty: Type, addr: Value, value: Value },
+    /// Calls a function, if the condition is set or absent. LR holds the return address.
+    Call { cond: Option<Value>, callee: Callee },
+    /// Raises a trap exception if the value is set.
+    Trap(Value),
+    /// An instruction with effects not represented in the IR, such as floating-point
+    /// arithmetic and system instructions.
+    Intrinsic { addr: u32, ins: Ins },
+}
+
+impl Display for Stmt {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Stmt::Let { value, ty, expr } => write!(f, "{value}: {ty} = {expr}"),
+            Stmt::Set(reg, value) => write!(f, "set {reg}, {value}"),
+            Stmt::Store { ty, addr, value } => write!(f, "store {ty} {addr}, {value}"),
+            Stmt::Call { cond: None, callee } => write!(f, "call {callee}"),
+            Stmt::Call { cond: Some(cond), callee } => write!(f, "call {callee} if {cond}"),
+            Stmt::Trap(cond) => write!(f, "trap if {cond}"),
+            Stmt::Intrinsic { ins, .. } => write!(f, "intrinsic {}", ins.simplified()),
+        }
+    }
+}
+
+/// Where control continues after a block.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Target {
+    /// The block or function at an address.
+    Address(u32),
+    /// A computed address, with the possible targets if known. (Jump tables)
+    Indirect { value: Value, targets: Vec<u32> },
+    /// Return to the caller.
+    Return,
+}
+
+impl Display for Target {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Target::Address(addr) => write!(f, "{addr:#x}"),
+            Target::Indirect { value, targets } if targets.is_empty() => write!(f, "{value}"),
+            Target::Indirect { value, targets } => {
+                write!(f, "{value} [")?;
+                for (i, target) in targets.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{target:#x}")?;
+                }
+                f.write_str("]")
+            }
+            Target::Return => f.write_str("return"),
+        }
+    }
+}
+
+/// How control leaves a block.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum Terminator {
+    Jump(Target),
+    /// Continues at the target if the condition is set, and at `next` otherwise.
+    Branch {
+        cond: Value,
+        target: Target,
+        next: u32,
+    },
+    /// An unconditional trap.
+    Trap,
+}
+
+impl Display for Terminator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Terminator::Jump(Target::Return) => f.write_str("return"),
+            Terminator::Jump(target) => write!(f, "jump {target}"),
+            Terminator::Branch { cond, target, next } => {
+                write!(f, "branch {cond}, {target}, {next:#x}")
+            }
+            Terminator::Trap => f.write_str("trap"),
+        }
+    }
+}
+
+/// A basic block.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Block {
+    pub stmts: Vec<Stmt>,
+    pub terminator: Terminator,
+}
+
+/// A lifted function.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Function {
+    /// The address of the entry point.
+    pub address: u32,
+    /// Blocks by address.
+    pub blocks: BTreeMap<u32, Block>,
+}
+
+impl Display for Function {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "fn {:#x}:", self.address)?;
+        for (addr, block) in &self.blocks {
+            write!(f, "\n{addr:#x}:")?;
+            for stmt in &block.stmts {
+                write!(f, "\n    {stmt}")?;
+            }
+            write!(f, "\n    {}", block.terminator)?;
+        }
+        Ok(())
+    }
+}
+
+/// A set of flag registers, as a bitset of [Reg::flag_bit].
+type FlagSet = u64;
+
+/// All condition register bits and XER flags.
+const ALL_FLAGS: FlagSet = (1 << 35) - 1;
+
+/// The condition register fields preserved across calls. (`cr2` to `cr4`)
+const NONVOLATILE_FLAGS: FlagSet = 0xFFF << 8;
+
+/// The condition register bits read by callees. (`cr1.eq`, set before variadic calls)
+const ARGUMENT_FLAGS: FlagSet = 1 << 6;
+
+impl Function {
+    /// Removes writes to condition register bits and XER flags that are never read,
+    /// and the computations of their values.
+    ///
+    /// Following the ABI, calls and returns preserve only `cr2` to `cr4`, so other
+    /// flags set before them are dead, except `cr1.eq`, which tells variadic callees
+    /// whether floating-point arguments are passed in registers. Flags are assumed
+    /// to be read by intrinsics, traps and branches leaving the function.
+    pub fn eliminate_dead_flags(&mut self) {
+        loop {
+            let live_out = self.live_flags();
+            let mut changed = false;
+            for (addr, block) in &mut self.blocks {
+                changed |= eliminate_block(block, live_out[addr]);
+            }
+            if !changed {
+                break;
+            }
+        }
+    }
+
+    /// Returns the flags live at the end of each block.
+    fn live_flags(&self) -> BTreeMap<u32, FlagSet> {
+        let mut live_in = self.blocks.keys().map(|&addr| (addr, 0)).collect::<BTreeMap<_, _>>();
+        let mut live_out = BTreeMap::new();
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for (&addr, block) in self.blocks.iter().rev() {
+                let out = self.terminator_flags(&block.terminator, &live_in);
+                live_out.insert(addr, out);
+                let flags = block.stmts.iter().rev().fold(out, |live, stmt| transfer(stmt, live));
+                if live_in.insert(addr, flags) != Some(flags) {
+                    changed = true;
+                }
+            }
+        }
+        live_out
+    }
+
+    /// Returns the flags live before a terminator.
+    fn terminator_flags(
+        &self,
+        terminator: &Terminator,
+        live_in: &BTreeMap<u32, FlagSet>,
+    ) -> FlagSet {
+        let target = |target: &Target| match target {
+            Target::Address(addr) => live_in.get(addr).copied().unwrap_or(ALL_FLAGS),
+            Target::Indirect { targets, .. } if !targets.is_empty() => targets
+                .iter()
+                .fold(0, |live, addr| live | live_in.get(addr).copied().unwrap_or(ALL_FLAGS)),
+            Target::Indirect { .. } => ALL_FLAGS,
+            Target::Return => NONVOLATILE_FLAGS,
+        };
+        match terminator {
+            Terminator::Jump(t) => target(t),
+            Terminator::Branch { target: t, next, .. } => {
+                target(t) | live_in.get(next).copied().unwrap_or(ALL_FLAGS)
+            }
+            Terminator::Trap => ALL_FLAGS,
+        }
+    }
+}
+
+/// Returns the flags live before a statement, given those live after it.
+fn transfer(stmt: &Stmt, live: FlagSet) -> FlagSet {
+    match stmt {
+        Stmt::Let { expr: Expr::Get(reg), .. } => live | reg.flag_bit().unwrap_or(0),
+        Stmt::Set(reg, _) => live & !reg.flag_bit().unwrap_or(0),
+        Stmt::Call { cond: None, .. } => (live & NONVOLATILE_FLAGS) | ARGUMENT_FLAGS,
+        // The call may not happen
+        Stmt::Call { cond: Some(_), .. } => live | ARGUMENT_FLAGS,
+        Stmt::Trap(_) | Stmt::Intrinsic { .. } => ALL_FLAGS,
+        Stmt::Let { .. } | Stmt::Store { .. } => live,
+    }
+}
+
+/// Removes dead flag writes and unused values from a block, returning whether
+/// anything was removed.
+fn eliminate_block(block: &mut Block, live_out: FlagSet) -> bool {
+    let mut used = BTreeSet::new();
+    let mut terminator_uses = |value: Value| {
+        used.insert(value);
+    };
+    match &block.terminator {
+        Terminator::Jump(Target::Indirect { value, .. }) => terminator_uses(*value),
+        Terminator::Branch { cond, target, .. } => {
+            terminator_uses(*cond);
+            if let Target::Indirect { value, .. } = target {
+                terminator_uses(*value);
+            }
+        }
+        _ => {}
+    }
+
+    let mut live = live_out;
+    let mut keep = vec![true; block.stmts.len()];
+    for (i, stmt) in block.stmts.iter().enumerate().rev() {
+        let needed = match stmt {
+            Stmt::Let { value, expr, .. } => !expr.is_pure() || used.contains(value),
+            Stmt::Set(reg, _) => reg.flag_bit().map_or(true, |bit| live & bit != 0),
+            _ => true,
+        };
+        if !needed {
+            keep[i] = false;
+            continue;
+        }
+        live = transfer(stmt, live);
+        match stmt {
+            Stmt::Let { expr, .. } => used.extend(expr.operands()),
+            Stmt::Set(_, value) | Stmt::Trap(value) => {
+                used.insert(*value);
+            }
+            Stmt::Store { addr, value, .. } => used.extend([*addr, *value]),
+            Stmt::Call { cond, callee } => {
+                used.extend(*cond);
+                if let Callee::Indirect(value) = callee {
+                    used.insert(*value);
+                }
+            }
+            Stmt::Intrinsic { .. } => {}
+        }
+    }
+    let removed = keep.iter().any(|&keep| !keep);
+    let mut keep = keep.into_iter();
+    block.stmts.retain(|_| keep.next().unwrap());
+    removed
+}
@@ -0,0 +1,95 @@
+use powerpc::{Argument, Extensions, Ins, Opcode, GPR};
+use powerpc_interp::Memory;
+
+/// The number of instructions searched before a `bctr`.
+const WINDOW: u32 = 16;
+
+/// The maximum number of jump table entries.
+const MAX_ENTRIES: u32 = 1024;
+
+/// Detects the targets of the jump table used by the `bctr` at `bctr`, by matching
+/// the sequence emitted by compilers for `switch` statements:
+///
+/// ```text
+/// cmplwi r3, 3             # Bounds check, for 4 entries
+/// bgt default
+/// lis r4, table@ha
+/// addi r4, r4, table@l
+/// slwi r0, r3, 2
+/// lwzx r0, r4, r0
+/// mtctr r0
+/// bctr
+/// ```
+///
+/// The search does not extend before `start`, the entry point of the function.
+pub(crate) fn detect_jump_table(
+    mem: &mut dyn Memory,
+    extensions: Extensions,
+    bctr: u32,
+    start: u32,
+) -> Option<Vec<u32>> {
+    let first = bctr.saturating_sub(WINDOW * 4).max(start);
+    let code = (first..bctr)
+        .step_by(4)
+        .map(|addr| mem.read_u32(addr as u64).ok().map(|code| Ins::new(code, extensions)))
+        .collect::<Option<Vec<_>>>()?;
+
+    // mtctr rT
+    let mtctr = code.iter().rposition(|ins| ins.op == Opcode::Mtspr && ins.field_spr() == 9)?;
+    // lwzx rT, rA, rB
+    let load = find_def(&code, mtctr, code[mtctr].field_rs())?;
+    if code[load].op != Opcode::Lwzx {
+        return None;
+    }
+    let (ra, rb) = (code[load].field_ra(), code[load].field_rb());
+    let (table, index) = match constant(&code, load, ra) {
+        Some(table) => (table, rb),
+        None => (constant(&code, load, rb)?, ra),
+    };
+    // slwi rB, rI, 2
+    let scale = find_def(&code, load, index)?;
+    let ins = code[scale];
+    if ins.op != Opcode::Rlwinm
+        || ins.field_sh() != 2
+        || ins.field_mb() != 0
+        || ins.field_me() != 29
+    {
+        return None;
+    }
+    // cmplwi rI, N
+    let index = ins.field_rs();
+    let count = code[..scale]
+        .iter()
+        .rev()
+        .find(|ins| ins.op == Opcode::Cmpli && ins.field_l() == 0 && ins.field_ra() == index)?
+        .field_uimm() as u32
+        + 1;
+    if count > MAX_ENTRIES {
+        return None;
+    }
+    (0..count)
+        .map(|i| {
+            let target = mem.read_u32(table.wrapping_add(4 * i) as u64).ok()?;
+            (target & 3 == 0).then_some(target)
+        })
+        .collect()
+}
+
+/// Returns the index of the last instruction before `end` that writes `reg`.
+fn find_def(code: &[Ins], end: usize, reg: u8) -> Option<usize> {
+    code[..end].iter().rposition(|ins| ins.defs().contains(&Argument::GPR(GPR(reg))))
+}
+
+/// Returns the value of `reg` before instruction `end`, if it is built from immediates.
+/// (`lis`, `addi`, `ori`)
+fn constant(code: &[Ins], end: usize, reg: u8) -> Option<u32> {
+    let i = find_def(code, end, reg)?;
+    let ins = code[i];
+    let base = |reg: u8| if reg == 0 { Some(0) } else { constant(code, i, reg) };
+    match ins.op {
+        Opcode::Addis => Some(base(ins.field_ra())?.wrapping_add((ins.field_simm() as u32) << 16)),
+        Opcode::Addi => Some(base(ins.field_ra())?.wrapping_add(ins.field_simm() as u32)),
+        Opcode::Ori => Some(constant(code, i, ins.field_rs())? | ins.field_uimm() as u32),
+        _ => None,
+    }
+}
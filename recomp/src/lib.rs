@@ -0,0 +1,13 @@
+mod function;
+pub mod ir;
+mod jump_table;
+mod lift;
+mod llvm;
+pub mod runtime;
+mod rust;
+pub mod symbolic;
+
+pub use function::{Function, Program, RecompError, Recompiler};
+pub use lift::lift;
+pub use llvm::LlvmSource;
+pub use rust::RustSource;
@@ -0,0 +1,835 @@
+use crate::ir::{
+    self, BinaryOp, Block, Callee, Expr, Reg, Stmt, Target, Terminator, Type, UnaryOp, Value,
+};
+use powerpc::{
+    mask32, AccessFormat, AccessKind, AccessOffset, Argument, BranchCondition, CtrCondition,
+    FieldId, FlowControl, Ins, MemoryAccess, Opcode, TrapCondition, FPR, GPR,
+};
+use std::collections::BTreeMap;
+
+/// Lifts a sequence of instructions starting at `address` to the IR.
+///
+/// Branch targets within the sequence start blocks, and branches to other addresses
+/// leave the function.
+///
+/// ```
+/// use powerpc::{Extensions, Ins};
+///
+/// let code = [
+///     Ins::new(0x7C632214, Extensions::none()), // add r3, r3, r4
+///     Ins::new(0x4E800020, Extensions::none()), // blr
+/// ];
+/// let function = powerpc_recomp::lift(0x80000000, &code);
+/// assert_eq!(
+///     function.to_string(),
+///     "fn 0x80000000:
+/// 0x80000000:
+///     %0: i32 = get r3
+///     %1: i32 = get r4
+///     %2: i32 = add %0, %1
+///     set r3, %2
+///     return"
+/// );
+/// ```
+pub fn lift(address: u32, code: &[Ins]) -> ir::Function {
+    let end = address.wrapping_add(4 * code.len() as u32);
+    let inside = |addr: u32| addr.wrapping_sub(address) < end.wrapping_sub(address);
+    let mut leaders = vec![false; code.len()];
+    for (i, ins) in code.iter().enumerate() {
+        let addr = address.wrapping_add(4 * i as u32);
+        if let (FlowControl::Branch { .. }, Some(target)) =
+            (ins.flow_control(), ins.branch_dest(addr))
+        {
+            if inside(target) && target & 3 == 0 {
+                leaders[(target.wrapping_sub(address) / 4) as usize] = true;
+            }
+        }
+    }
+    let mut lifter = Lifter::new(address);
+    let mut start = 0;
+    for i in 1..=code.len() {
+        if i == code.len() || leaders[i] {
+            lifter.lift_run(
+                address.wrapping_add(4 * start as u32),
+                &code[start..i],
+                &BTreeMap::new(),
+            );
+            start = i;
+        }
+    }
+    lifter.finish()
+}
+
+impl crate::Function {
+    /// Lifts the function to the IR, with a block for each basic block and jump table.
+    pub fn lift(&self) -> ir::Function {
+        let mut lifter = Lifter::new(self.address);
+        for (&start, code) in &self.blocks {
+            lifter.lift_run(start, code, &self.jump_tables);
+        }
+        lifter.finish()
+    }
+}
+
+/// Whether an optional modifier is present and set.
+fn modifier(ins: Ins, id: FieldId) -> bool {
+    ins.op.modifiers().contains(&id) && id.extract(ins.code) != 0
+}
+
+struct Lifter {
+    address: u32,
+    blocks: BTreeMap<u32, Block>,
+    /// The address of the current block.
+    start: u32,
+    stmts: Vec<Stmt>,
+    /// The types of values, by number.
+    types: Vec<Type>,
+}
+
+impl Lifter {
+    fn new(address: u32) -> Self {
+        Self { address, blocks: BTreeMap::new(), start: address, stmts: vec![], types: vec![] }
+    }
+
+    fn finish(self) -> ir::Function {
+        ir::Function { address: self.address, blocks: self.blocks }
+    }
+
+    /// Lifts consecutive instructions, starting blocks after instructions ending one.
+    fn lift_run(&mut self, start: u32, code: &[Ins], jump_tables: &BTreeMap<u32, Vec<u32>>) {
+        let mut open = false;
+        let mut addr = start;
+        for &ins in code {
+            if !open {
+                self.start = addr;
+                open = true;
+            }
+            if let Some(terminator) = self.instruction(addr, ins, jump_tables.get(&addr)) {
+                self.close(terminator);
+                open = false;
+            }
+            addr = addr.wrapping_add(4);
+        }
+        if open {
+            self.close(Terminator::Jump(Target::Address(addr)));
+        }
+    }
+
+    fn close(&mut self, terminator: Terminator) {
+        let stmts = core::mem::take(&mut self.stmts);
+        self.blocks.insert(self.start, Block { stmts, terminator });
+    }
+
+    fn emit(&mut self, ty: Type, expr: Expr) -> Value {
+        let value = Value(self.types.len() as u32);
+        self.types.push(ty);
+        self.stmts.push(Stmt::Let { value, ty, expr });
+        value
+    }
+
+    fn ty(&self, value: Value) -> Type {
+        self.types[value.0 as usize]
+    }
+
+    fn constant(&mut self, ty: Type, value: u64) -> Value {
+        self.emit(ty, Expr::Const(value & ty.mask()))
+    }
+
+    fn imm(&mut self, value: u32) -> Value {
+        self.constant(Type::I32, value as u64)
+    }
+
+    fn get(&mut self, reg: Reg) -> Value {
+        self.emit(reg.ty(), Expr::Get(reg))
+    }
+
+    fn set(&mut self, reg: Reg, value: Value) {
+        self.stmts.push(Stmt::Set(reg, value));
+    }
+
+    fn gpr(&mut self, reg: u8) -> Value {
+        self.get(Reg::Gpr(reg))
+    }
+
+    /// Applies an operation keeping the type of the operand.
+    fn unary(&mut self, op: UnaryOp, a: Value) -> Value {
+        let ty = self.ty(a);
+        self.emit(ty, Expr::Unary(op, a))
+    }
+
+    /// Converts a value to another type.
+    fn cast(&mut self, op: UnaryOp, ty: Type, a: Value) -> Value {
+        self.emit(ty, Expr::Unary(op, a))
+    }
+
+    fn binary(&mut self, op: BinaryOp, a: Value, b: Value) -> Value {
+        let ty = if op.is_comparison() { Type::I1 } else { self.ty(a) };
+        self.emit(ty, Expr::Binary(op, a, b))
+    }
+
+    /// Applies an operation with an immediate second operand.
+    fn binary_imm(&mut self, op: BinaryOp, a: Value, b: u64) -> Value {
+        let b = self.constant(self.ty(a), b);
+        self.binary(op, a, b)
+    }
+
+    /// Sets CR0 from a result, as done by instructions with Rc set.
+    fn record(&mut self, value: Value) {
+        let zero = self.imm(0);
+        self.compare(0, value, zero, true);
+    }
+
+    /// Sets a condition register field from a comparison, including XER\[SO\].
+    fn compare(&mut self, field: u8, a: Value, b: Value, signed: bool) {
+        let (lt, gt) =
+            if signed { (BinaryOp::Slt, BinaryOp::Sgt) } else { (BinaryOp::Ult, BinaryOp::Ugt) };
+        let lt = self.binary(lt, a, b);
+        let gt = self.binary(gt, a, b);
+        let eq = self.binary(BinaryOp::Eq, a, b);
+        let so = self.get(Reg::So);
+        for (i, bit) in [lt, gt, eq, so].into_iter().enumerate() {
+            self.set(Reg::Cr(4 * field + i as u8), bit);
+        }
+    }
+
+    /// Sets XER\[OV\], and XER\[SO\] if it is set.
+    fn overflow(&mut self, ov: Value) {
+        self.set(Reg::Ov, ov);
+        let so = self.get(Reg::So);
+        let so = self.binary(BinaryOp::Or, so, ov);
+        self.set(Reg::So, so);
+    }
+
+    /// Sets XER\[OV\] if a 64-bit result does not fit in a signed word.
+    fn overflow_wide(&mut self, wide: Value) {
+        let word = self.cast(UnaryOp::Trunc, Type::I32, wide);
+        let extended = self.cast(UnaryOp::Sext, Type::I64, word);
+        let ov = self.binary(BinaryOp::Ne, extended, wide);
+        self.overflow(ov);
+    }
+
+    /// Computes `a + b + carry`, setting XER\[CA\] and XER\[OV\] if requested.
+    fn add(&mut self, a: Value, b: Value, carry: Option<Value>, ca: bool, ov: bool) -> Value {
+        let mut sum = None;
+        for (signed, needed) in [(false, ca), (true, ov)] {
+            if !needed {
+                continue;
+            }
+            let extend = if signed { UnaryOp::Sext } else { UnaryOp::Zext };
+            let a = self.cast(extend, Type::I64, a);
+            let b = self.cast(extend, Type::I64, b);
+            let mut wide = self.binary(BinaryOp::Add, a, b);
+            if let Some(carry) = carry {
+                let carry = self.cast(UnaryOp::Zext, Type::I64, carry);
+                wide = self.binary(BinaryOp::Add, wide, carry);
+            }
+            if signed {
+                self.overflow_wide(wide);
+            } else {
+                let high = self.binary_imm(BinaryOp::LShr, wide, 32);
+                let ca = self.cast(UnaryOp::Trunc, Type::I1, high);
+                self.set(Reg::Ca, ca);
+            }
+            if sum.is_none() {
+                sum = Some(self.cast(UnaryOp::Trunc, Type::I32, wide));
+            }
+        }
+        sum.unwrap_or_else(|| {
+            let sum = self.binary(BinaryOp::Add, a, b);
+            match carry {
+                Some(carry) => {
+                    let carry = self.cast(UnaryOp::Zext, Type::I32, carry);
+                    self.binary(BinaryOp::Add, sum, carry)
+                }
+                None => sum,
+            }
+        })
+    }
+
+    /// Evaluates a branch condition, decrementing CTR if `decrement` is set and the
+    /// condition tests it. Returns `None` if the branch is always taken.
+    fn condition(&mut self, condition: BranchCondition, decrement: bool) -> Option<Value> {
+        let mut cond = None;
+        if let (true, Some(test)) = (decrement, condition.ctr) {
+            let ctr = self.get(Reg::Ctr);
+            let ctr = self.binary_imm(BinaryOp::Sub, ctr, 1);
+            self.set(Reg::Ctr, ctr);
+            let op = match test {
+                CtrCondition::Zero => BinaryOp::Eq,
+                CtrCondition::NonZero => BinaryOp::Ne,
+            };
+            cond = Some(self.binary_imm(op, ctr, 0));
+        }
+        if let Some(cr) = condition.cr {
+            let mut bit = self.get(Reg::Cr(cr.bit.0));
+            if !cr.value {
+                bit = self.unary(UnaryOp::Not, bit);
+            }
+            cond = Some(match cond {
+                Some(ctr) => self.binary(BinaryOp::And, ctr, bit),
+                None => bit,
+            });
+        }
+        cond
+    }
+
+    /// Returns the terminator continuing at `target` if `cond` is set or absent.
+    fn exit(cond: Option<Value>, target: Target, next: u32) -> Terminator {
+        match cond {
+            Some(cond) => Terminator::Branch { cond, target, next },
+            None => Terminator::Jump(target),
+        }
+    }
+
+    /// Lifts an instruction, returning a terminator if it ends the block.
+    fn instruction(&mut self, addr: u32, ins: Ins, table: Option<&Vec<u32>>) -> Option<Terminator> {
+        let next = addr.wrapping_add(4);
+        match ins.flow_control() {
+            FlowControl::Sequential => {
+                if !self.operation(ins) {
+                    self.stmts.push(Stmt::Intrinsic { addr, ins });
+                }
+                None
+            }
+            FlowControl::SystemCall | FlowControl::Trap { doubleword: true, .. } => {
+                self.stmts.push(Stmt::Intrinsic { addr, ins });
+                None
+            }
+            FlowControl::Trap { condition, .. } if condition.never() => None,
+            FlowControl::Trap { condition, .. } if condition.always() => Some(Terminator::Trap),
+            FlowControl::Trap { condition, .. } => {
+                let a = self.gpr(ins.field_ra());
+                let b = match ins.op {
+                    Opcode::Twi => self.imm(ins.field_simm() as u32),
+                    _ => self.gpr(ins.field_rb()),
+                };
+                let tests = [
+                    (TrapCondition::LT, BinaryOp::Slt),
+                    (TrapCondition::GT, BinaryOp::Sgt),
+                    (TrapCondition::EQ, BinaryOp::Eq),
+                    (TrapCondition::LLT, BinaryOp::Ult),
+                    (TrapCondition::LGT, BinaryOp::Ugt),
+                ];
+                let mut cond = None;
+                for (bit, op) in tests {
+                    if condition.0 & bit != 0 {
+                        let test = self.binary(op, a, b);
+                        cond = Some(match cond {
+                            Some(cond) => self.binary(BinaryOp::Or, cond, test),
+                            None => test,
+                        });
+                    }
+                }
+                self.stmts.push(Stmt::Trap(cond?));
+                None
+            }
+            FlowControl::ExceptionReturn => {
+                self.stmts.push(Stmt::Intrinsic { addr, ins });
+                // SRR0
+                let value = self.get(Reg::Spr(26));
+                Some(Terminator::Jump(Target::Indirect { value, targets: vec![] }))
+            }
+            FlowControl::Branch { condition } => {
+                let cond = self.condition(condition, true);
+                let target = ins.branch_dest(addr).unwrap_or(next);
+                Some(Self::exit(cond, Target::Address(target), next))
+            }
+            FlowControl::IndirectJump { condition } => {
+                // Decrementing CTR is an invalid form of bcctr
+                let cond = self.condition(condition, false);
+                let ctr = self.get(Reg::Ctr);
+                let value = self.binary_imm(BinaryOp::And, ctr, !3);
+                let targets = table.cloned().unwrap_or_default();
+                Some(Self::exit(cond, Target::Indirect { value, targets }, next))
+            }
+            FlowControl::Call { condition, indirect: false } => {
+                let cond = self.condition(condition, true);
+                let target = ins.branch_dest(addr).unwrap_or(next);
+                let lr = self.imm(next);
+                self.set(Reg::Lr, lr);
+                // `bcl 20, 31, $+4` only reads the address of the next instruction
+                if target != next {
+                    self.stmts.push(Stmt::Call { cond, callee: Callee::Direct(target) });
+                }
+                None
+            }
+            FlowControl::Call { condition, indirect: true } => {
+                let (reg, decrement) =
+                    if ins.op == Opcode::Bcctr { (Reg::Ctr, false) } else { (Reg::Lr, true) };
+                let target = self.get(reg);
+                let target = self.binary_imm(BinaryOp::And, target, !3);
+                let cond = self.condition(condition, decrement);
+                let lr = self.imm(next);
+                self.set(Reg::Lr, lr);
+                self.stmts.push(Stmt::Call { cond, callee: Callee::Indirect(target) });
+                None
+            }
+            FlowControl::Return { condition } => {
+                let cond = self.condition(condition, true);
+                Some(Self::exit(cond, Target::Return, next))
+            }
+        }
+    }
+
+    /// Lifts an instruction that does not affect control flow, returning `false` if it
+    /// is not supported.
+    fn operation(&mut self, ins: Ins) -> bool {
+        use BinaryOp as B;
+        use UnaryOp as U;
+
+        let (rd, ra, rb, rs) = (ins.field_rd(), ins.field_ra(), ins.field_rb(), ins.field_rs());
+        let simm = ins.field_simm() as i32 as u32;
+        let uimm = ins.field_uimm() as u32;
+        let oe = modifier(ins, FieldId::OE);
+        let mut rc = modifier(ins, FieldId::Rc);
+
+        // The destination register and its new value
+        let (dest, value) = match ins.op {
+            // Arithmetic
+            Opcode::Addi | Opcode::Addis => {
+                let imm = if ins.op == Opcode::Addis { simm << 16 } else { simm };
+                let value = if ra == 0 {
+                    self.imm(imm)
+                } else {
+                    let a = self.gpr(ra);
+                    self.binary_imm(B::Add, a, imm as u64)
+                };
+                (rd, value)
+            }
+            Opcode::Subf if !oe => {
+                let (a, b) = (self.gpr(ra), self.gpr(rb));
+                (rd, self.binary(B::Sub, b, a))
+            }
+            Opcode::Neg if !oe => {
+                let a = self.gpr(ra);
+                (rd, self.unary(U::Neg, a))
+            }
+            Opcode::Add
+            | Opcode::Addc
+            | Opcode::Adde
+            | Opcode::Addme
+            | Opcode::Addze
+            | Opcode::Subf
+            | Opcode::Subfc
+            | Opcode::Subfe
+            | Opcode::Subfme
+            | Opcode::Subfze
+            | Opcode::Neg => {
+                let mut a = self.gpr(ra);
+                let b = match ins.op {
+                    Opcode::Addme | Opcode::Subfme => self.imm(u32::MAX),
+                    Opcode::Addze | Opcode::Subfze | Opcode::Neg => self.imm(0),
+                    _ => self.gpr(rb),
+                };
+                let carry = match ins.op {
+                    Opcode::Add | Opcode::Addc => None,
+                    Opcode::Subf | Opcode::Subfc | Opcode::Neg => Some(self.constant(Type::I1, 1)),
+                    _ => Some(self.get(Reg::Ca)),
+                };
+                if !matches!(
+                    ins.op,
+                    Opcode::Add | Opcode::Addc | Opcode::Adde | Opcode::Addme | Opcode::Addze
+                ) {
+                    a = self.unary(U::Not, a);
+                }
+                let ca = !matches!(ins.op, Opcode::Add | Opcode::Subf | Opcode::Neg);
+                (rd, self.add(a, b, carry, ca, oe))
+            }
+            Opcode::Addic | Opcode::Addic_ => {
+                rc = ins.op == Opcode::Addic_;
+                let a = self.gpr(ra);
+                let b = self.imm(simm);
+                (rd, self.add(a, b, None, true, false))
+            }
+            Opcode::Subfic => {
+                let a = self.gpr(ra);
+                let a = self.unary(U::Not, a);
+                let b = self.imm(simm);
+                let carry = self.constant(Type::I1, 1);
+                (rd, self.add(a, b, Some(carry), true, false))
+            }
+            Opcode::Mulli => {
+                let a = self.gpr(ra);
+                (rd, self.binary_imm(B::Mul, a, simm as u64))
+            }
+            Opcode::Mullw | Opcode::Mulhw | Opcode::Mulhwu | Opcode::Divw | Opcode::Divwu => {
+                let (a, b) = (self.gpr(ra), self.gpr(rb));
+                let op = match ins.op {
+                    Opcode::Mullw => B::Mul,
+                    Opcode::Mulhw => B::MulHs,
+                    Opcode::Mulhwu => B::MulHu,
+                    Opcode::Divw => B::DivS,
+                    _ => B::DivU,
+                };
+                let value = self.binary(op, a, b);
+                if oe {
+                    match ins.op {
+                        Opcode::Mullw => {
+                            let a = self.cast(U::Sext, Type::I64, a);
+                            let b = self.cast(U::Sext, Type::I64, b);
+                            let wide = self.binary(B::Mul, a, b);
+                            self.overflow_wide(wide);
+                        }
+                        Opcode::Divw => {
+                            let zero = self.binary_imm(B::Eq, b, 0);
+                            let min = self.binary_imm(B::Eq, a, 0x8000_0000);
+                            let minus_one = self.binary_imm(B::Eq, b, u32::MAX as u64);
+                            let overflow = self.binary(B::And, min, minus_one);
+                            let ov = self.binary(B::Or, zero, overflow);
+                            self.overflow(ov);
+                        }
+                        _ => {
+                            let ov = self.binary_imm(B::Eq, b, 0);
+                            self.overflow(ov);
+                        }
+                    }
+                }
+                (rd, value)
+            }
+
+            // Logical
+            Opcode::Or if rs == rb => (ra, self.gpr(rs)),
+            Opcode::And
+            | Opcode::Andc
+            | Opcode::Or
+            | Opcode::Orc
+            | Opcode::Xor
+            | Opcode::Nand
+            | Opcode::Nor
+            | Opcode::Eqv => {
+                let (s, mut b) = (self.gpr(rs), self.gpr(rb));
+                if matches!(ins.op, Opcode::Andc | Opcode::Orc) {
+                    b = self.unary(U::Not, b);
+                }
+                let op = match ins.op {
+                    Opcode::And | Opcode::Andc | Opcode::Nand => B::And,
+                    Opcode::Or | Opcode::Orc | Opcode::Nor => B::Or,
+                    _ => B::Xor,
+                };
+                let mut value = self.binary(op, s, b);
+                if matches!(ins.op, Opcode::Nand | Opcode::Nor | Opcode::Eqv) {
+                    value = self.unary(U::Not, value);
+                }
+                (ra, value)
+            }
+            // nop
+            Opcode::Ori if rs == 0 && ra == 0 && uimm == 0 => return true,
+            Opcode::Andi_
+            | Opcode::Andis_
+            | Opcode::Ori
+            | Opcode::Oris
+            | Opcode::Xori
+            | Opcode::Xoris => {
+                rc = matches!(ins.op, Opcode::Andi_ | Opcode::Andis_);
+                let (op, imm) = match ins.op {
+                    Opcode::Andi_ => (B::And, uimm),
+                    Opcode::Andis_ => (B::And, uimm << 16),
+                    Opcode::Ori => (B::Or, uimm),
+                    Opcode::Oris => (B::Or, uimm << 16),
+                    Opcode::Xori => (B::Xor, uimm),
+                    _ => (B::Xor, uimm << 16),
+                };
+                let s = self.gpr(rs);
+                (ra, self.binary_imm(op, s, imm as u64))
+            }
+            Opcode::Extsb | Opcode::Extsh => {
+                let ty = if ins.op == Opcode::Extsb { Type::I8 } else { Type::I16 };
+                let s = self.gpr(rs);
+                let narrow = self.cast(U::Trunc, ty, s);
+                (ra, self.cast(U::Sext, Type::I32, narrow))
+            }
+            Opcode::Cntlzw => {
+                let s = self.gpr(rs);
+                (ra, self.unary(U::Clz, s))
+            }
+
+            // Shift
+            Opcode::Slw | Opcode::Srw | Opcode::Sraw | Opcode::Srawi => {
+                let s = self.gpr(rs);
+                let n = match ins.op {
+                    Opcode::Srawi => self.imm(ins.field_sh() as u32),
+                    _ => {
+                        let b = self.gpr(rb);
+                        self.binary_imm(B::And, b, 0x3F)
+                    }
+                };
+                let op = match ins.op {
+                    Opcode::Slw => B::Shl,
+                    Opcode::Srw => B::LShr,
+                    _ => B::AShr,
+                };
+                let value = self.binary(op, s, n);
+                if matches!(ins.op, Opcode::Sraw | Opcode::Srawi) {
+                    // CA is set if the value is negative and any 1 bits were shifted out
+                    let back = self.binary(B::Shl, value, n);
+                    let lost = self.binary(B::Ne, back, s);
+                    let negative = self.binary_imm(B::Slt, s, 0);
+                    let ca = self.binary(B::And, negative, lost);
+                    self.set(Reg::Ca, ca);
+                }
+                (ra, value)
+            }
+
+            // Rotate
+            Opcode::Rlwinm | Opcode::Rlwnm | Opcode::Rlwimi => {
+                let s = self.gpr(rs);
+                let rotated = match (ins.op, ins.field_sh()) {
+                    (Opcode::Rlwnm, _) => {
+                        let b = self.gpr(rb);
+                        self.binary(B::Rotl, s, b)
+                    }
+                    (_, 0) => s,
+                    (_, sh) => self.binary_imm(B::Rotl, s, sh as u64),
+                };
+                let mask = mask32(ins.field_mb(), ins.field_me());
+                let mut value = match mask {
+                    u32::MAX => rotated,
+                    _ => self.binary_imm(B::And, rotated, mask as u64),
+                };
+                if ins.op == Opcode::Rlwimi {
+                    let a = self.gpr(ra);
+                    let kept = self.binary_imm(B::And, a, !mask as u64);
+                    value = self.binary(B::Or, value, kept);
+                }
+                (ra, value)
+            }
+
+            // Compare
+            Opcode::Cmp | Opcode::Cmpi | Opcode::Cmpl | Opcode::Cmpli if ins.field_l() == 0 => {
+                let a = self.gpr(ra);
+                let b = match ins.op {
+                    Opcode::Cmpi => self.imm(simm),
+                    Opcode::Cmpli => self.imm(uimm),
+                    _ => self.gpr(rb),
+                };
+                let signed = matches!(ins.op, Opcode::Cmp | Opcode::Cmpi);
+                self.compare(ins.field_crfd(), a, b, signed);
+                return true;
+            }
+
+            // Condition register
+            Opcode::Crand
+            | Opcode::Crandc
+            | Opcode::Creqv
+            | Opcode::Crnand
+            | Opcode::Crnor
+            | Opcode::Cror
+            | Opcode::Crorc
+            | Opcode::Crxor => {
+                let a = self.get(Reg::Cr(ins.field_crba()));
+                let mut b = self.get(Reg::Cr(ins.field_crbb()));
+                if matches!(ins.op, Opcode::Crandc | Opcode::Crorc) {
+                    b = self.unary(U::Not, b);
+                }
+                let op = match ins.op {
+                    Opcode::Crand | Opcode::Crandc | Opcode::Crnand => B::And,
+                    Opcode::Cror | Opcode::Crorc | Opcode::Crnor => B::Or,
+                    _ => B::Xor,
+                };
+                let mut value = self.binary(op, a, b);
+                if matches!(ins.op, Opcode::Creqv | Opcode::Crnand | Opcode::Crnor) {
+                    value = self.unary(U::Not, value);
+                }
+                self.set(Reg::Cr(ins.field_crbd()), value);
+                return true;
+            }
+            Opcode::Mcrf => {
+                let (d, s) = (4 * ins.field_crfd(), 4 * ins.field_crfs());
+                let bits = (0..4).map(|i| self.get(Reg::Cr(s + i))).collect::<Vec<_>>();
+                for (i, bit) in bits.into_iter().enumerate() {
+                    self.set(Reg::Cr(d + i as u8), bit);
+                }
+                return true;
+            }
+            Opcode::Mfcr => {
+                let mut value = None;
+                for bit in 0..32 {
+                    let flag = self.get(Reg::Cr(bit));
+                    let mut word = self.cast(U::Zext, Type::I32, flag);
+                    if bit != 31 {
+                        word = self.binary_imm(B::Shl, word, 31 - bit as u64);
+                    }
+                    value = Some(match value {
+                        Some(value) => self.binary(B::Or, value, word),
+                        None => word,
+                    });
+                }
+                (rd, value.unwrap())
+            }
+            Opcode::Mtcrf => {
+                let s = self.gpr(rs);
+                for field in (0..8).filter(|i| ins.field_crm() & (0x80 >> i) != 0) {
+                    for bit in 4 * field..4 * field + 4 {
+                        let shifted = self.binary_imm(B::LShr, s, 31 - bit as u64);
+                        let flag = self.cast(U::Trunc, Type::I1, shifted);
+                        self.set(Reg::Cr(bit), flag);
+                    }
+                }
+                return true;
+            }
+
+            // Special-purpose registers, except XER whose flags are separate registers
+            Opcode::Mfspr if ins.field_spr() != 1 => {
+                let reg = match ins.field_spr() {
+                    8 => Reg::Lr,
+                    9 => Reg::Ctr,
+                    spr => Reg::Spr(spr),
+                };
+                (rd, self.get(reg))
+            }
+            Opcode::Mtspr if ins.field_spr() != 1 => {
+                let reg = match ins.field_spr() {
+                    8 => Reg::Lr,
+                    9 => Reg::Ctr,
+                    spr => Reg::Spr(spr),
+                };
+                let s = self.gpr(rs);
+                self.set(reg, s);
+                return true;
+            }
+
+            // Floating-point moves, which only copy and change the sign bit
+            Opcode::Fmr | Opcode::Fneg | Opcode::Fabs | Opcode::Fnabs if !rc => {
+                let b = self.get(Reg::Fpr(ins.field_frb()));
+                let value = match ins.op {
+                    Opcode::Fmr => b,
+                    op => {
+                        const SIGN_BIT: u64 = 1 << 63;
+                        let bits = self.cast(U::Bitcast, Type::I64, b);
+                        let bits = match op {
+                            Opcode::Fneg => self.binary_imm(B::Xor, bits, SIGN_BIT),
+                            Opcode::Fabs => self.binary_imm(B::And, bits, !SIGN_BIT),
+                            _ => self.binary_imm(B::Or, bits, SIGN_BIT),
+                        };
+                        self.cast(U::Bitcast, Type::F64, bits)
+                    }
+                };
+                self.set(Reg::Fpr(ins.field_frd()), value);
+                return true;
+            }
+
+            // Synchronization and cache management have no effect
+            Opcode::Sync
+            | Opcode::Isync
+            | Opcode::Eieio
+            | Opcode::Dcbf
+            | Opcode::Dcbst
+            | Opcode::Dcbt
+            | Opcode::Dcbtst
+            | Opcode::Icbi => return true,
+
+            _ => return ins.memory_access().is_some_and(|access| self.access(access)),
+        };
+        self.set(Reg::Gpr(dest), value);
+        if rc {
+            self.record(value);
+        }
+        true
+    }
+
+    /// Computes the effective address of an access.
+    fn effective_address(&mut self, access: &MemoryAccess) -> Value {
+        let base = access.base.map(|GPR(base)| self.gpr(base));
+        let offset = match access.offset {
+            AccessOffset::None => None,
+            AccessOffset::Displacement(0) if base.is_some() => None,
+            AccessOffset::Displacement(offset) => Some(self.imm(offset as u32)),
+            AccessOffset::Index(GPR(index)) => Some(self.gpr(index)),
+        };
+        match (base, offset) {
+            (Some(base), Some(offset)) => self.binary(BinaryOp::Add, base, offset),
+            (Some(value), None) | (None, Some(value)) => value,
+            (None, None) => self.imm(0),
+        }
+    }
+
+    /// Lifts an integer or double-precision load or store, returning `false` if it is not
+    /// supported.
+    fn access(&mut self, access: MemoryAccess) -> bool {
+        if access.atomic {
+            return false;
+        }
+        if let (Argument::FPR(FPR(reg)), AccessFormat::Float, Some(8)) =
+            (access.reg, access.format, access.size)
+        {
+            // Doubles are moved as their bits, without conversion
+            let ea = self.effective_address(&access);
+            match access.kind {
+                AccessKind::Load => {
+                    let bits = self.emit(Type::I64, Expr::Load(ea));
+                    let value = self.cast(UnaryOp::Bitcast, Type::F64, bits);
+                    self.set(Reg::Fpr(reg), value);
+                }
+                AccessKind::Store => {
+                    let value = self.get(Reg::Fpr(reg));
+                    let bits = self.cast(UnaryOp::Bitcast, Type::I64, value);
+                    self.stmts.push(Stmt::Store { ty: Type::I64, addr: ea, value: bits });
+                }
+            }
+            if let (true, Some(GPR(base))) = (access.update, access.base) {
+                self.set(Reg::Gpr(base), ea);
+            }
+            return true;
+        }
+        let Argument::GPR(GPR(reg)) = access.reg else {
+            return false;
+        };
+        match access.format {
+            AccessFormat::Integer => {
+                let ty = match access.size.and_then(Type::from_size) {
+                    Some(ty @ (Type::I8 | Type::I16 | Type::I32)) => ty,
+                    _ => return false,
+                };
+                let ea = self.effective_address(&access);
+                match access.kind {
+                    AccessKind::Load => {
+                        let mut value = self.emit(ty, Expr::Load(ea));
+                        if access.byte_reversed {
+                            value = self.unary(UnaryOp::Bswap, value);
+                        }
+                        if ty != Type::I32 {
+                            let extend = if access.signed { UnaryOp::Sext } else { UnaryOp::Zext };
+                            value = self.cast(extend, Type::I32, value);
+                        }
+                        self.set(Reg::Gpr(reg), value);
+                    }
+                    AccessKind::Store => {
+                        let mut value = self.gpr(reg);
+                        if ty != Type::I32 {
+                            value = self.cast(UnaryOp::Trunc, ty, value);
+                        }
+                        if access.byte_reversed {
+                            value = self.unary(UnaryOp::Bswap, value);
+                        }
+                        self.stmts.push(Stmt::Store { ty, addr: ea, value });
+                    }
+                }
+                if let (true, Some(GPR(base))) = (access.update, access.base) {
+                    self.set(Reg::Gpr(base), ea);
+                }
+                true
+            }
+            AccessFormat::Multiple => {
+                let ea = self.effective_address(&access);
+                for (i, reg) in (reg..32).enumerate() {
+                    let addr = match i {
+                        0 => ea,
+                        _ => self.binary_imm(BinaryOp::Add, ea, 4 * i as u64),
+                    };
+                    match access.kind {
+                        AccessKind::Load => {
+                            let value = self.emit(Type::I32, Expr::Load(addr));
+                            self.set(Reg::Gpr(reg), value);
+                        }
+                        AccessKind::Store => {
+                            let value = self.gpr(reg);
+                            self.stmts.push(Stmt::Store { ty: Type::I32, addr, value });
+                        }
+                    }
+                }
+                true
+            }
+            _ => false,
+        }
+    }
+}
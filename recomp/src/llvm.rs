@@ -0,0 +1,547 @@
+use crate::ir::{
+    self, BinaryOp, Callee, Expr, Reg, Stmt, Target, Terminator, Type, UnaryOp, Value,
+};
+use core::fmt::{self, Display, Formatter, Write};
+use std::collections::{BTreeSet, HashMap};
+
+/// LLVM IR for a lifted function, as an `.ll` module. (See [ir::Function::llvm])
+#[derive(Copy, Clone, Debug)]
+pub struct LlvmSource<'a> {
+    function: &'a ir::Function,
+}
+
+impl ir::Function {
+    /// Returns the function as a textual LLVM IR module, for `opt`, `llc` or `clang`.
+    ///
+    /// The function is defined as `void @fn_XXXXXXXX(ptr %ctx, ptr %mem)`, where `%ctx`
+    /// points to the processor state, laid out as the C structure
+    ///
+    /// ```c
+    /// struct Context {
+    ///     uint32_t gpr[32];
+    ///     double fpr[32];
+    ///     uint8_t cr[32]; // One bit per byte, from CR0[LT]
+    ///     uint8_t so, ov, ca;
+    ///     uint32_t lr, ctr;
+    ///     uint32_t spr[1024];
+    /// };
+    /// ```
+    ///
+    /// and `%mem` to the host memory at guest address 0. Loads and stores are unaligned,
+    /// and byte-swapped explicitly with `@llvm.bswap` to access memory as big-endian.
+    ///
+    /// Registers are held in allocas, loaded from the context on entry, which `opt`
+    /// promotes to SSA values. They are written back to the context before leaving the
+    /// function and around calls to the runtime, which provides:
+    ///
+    /// - `@fn_XXXXXXXX` for functions called or branched to directly,
+    /// - `@ppc_dispatch(ptr, ptr, i32)` to call the function at a computed address,
+    /// - `@ppc_interpret(ptr, ptr, i32, i32)` to execute an [intrinsic](Stmt::Intrinsic)
+    ///   with its address and encoding,
+    /// - `@ppc_trap(ptr)`, which does not return.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use powerpc::{Extensions, Ins};
+    ///
+    /// let code = [0x7C632214, 0x4E800020]; // add r3, r3, r4; blr
+    /// let code = code.map(|code| Ins::new(code, Extensions::none()));
+    /// let source = powerpc_recomp::lift(0x80000000, &code).llvm().to_string();
+    /// assert!(source.contains("define void @fn_80000000(ptr %ctx, ptr %mem)"));
+    /// assert!(source.contains("%v2 = add i32 %v0, %v1"));
+    /// ```
+    #[inline]
+    pub fn llvm(&self) -> LlvmSource<'_> {
+        LlvmSource { function: self }
+    }
+}
+
+impl Display for LlvmSource<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let mut writer = FunctionWriter::new(self.function);
+        writer.write()?;
+        writeln!(f, "; Code generated by powerpc-recomp. DO NOT EDIT.")?;
+        writeln!(
+            f,
+            "%Context = type {{ [32 x i32], [32 x double], [32 x i8], i8, i8, i8, i32, i32, [1024 x i32] }}"
+        )?;
+        writeln!(f)?;
+        for declaration in &writer.declarations {
+            writeln!(f, "{declaration}")?;
+        }
+        writeln!(f)?;
+        f.write_str(&writer.out)
+    }
+}
+
+/// Returns the LLVM type of a value.
+fn llvm_type(ty: Type) -> &'static str {
+    match ty {
+        Type::I1 => "i1",
+        Type::I8 => "i8",
+        Type::I16 => "i16",
+        Type::I32 => "i32",
+        Type::I64 => "i64",
+        Type::F64 => "double",
+    }
+}
+
+/// Returns the type of a register in the context, and the indices of its field.
+fn context_field(reg: Reg) -> (&'static str, String) {
+    match reg {
+        Reg::Gpr(reg) => ("i32", format!("i32 0, i32 0, i32 {reg}")),
+        Reg::Fpr(reg) => ("double", format!("i32 0, i32 1, i32 {reg}")),
+        Reg::Cr(bit) => ("i8", format!("i32 0, i32 2, i32 {bit}")),
+        Reg::So => ("i8", "i32 0, i32 3".into()),
+        Reg::Ov => ("i8", "i32 0, i32 4".into()),
+        Reg::Ca => ("i8", "i32 0, i32 5".into()),
+        Reg::Lr => ("i32", "i32 0, i32 6".into()),
+        Reg::Ctr => ("i32", "i32 0, i32 7".into()),
+        Reg::Spr(spr) => ("i32", format!("i32 0, i32 8, i32 {spr}")),
+    }
+}
+
+/// Returns the label of the block at an address.
+fn label(addr: u32) -> String {
+    format!("bb_{addr:08x}")
+}
+
+struct FunctionWriter<'a> {
+    function: &'a ir::Function,
+    /// The registers read or written by the function.
+    regs: BTreeSet<Reg>,
+    /// The registers written by the function.
+    written: BTreeSet<Reg>,
+    /// The types of the values, and the operands of constants.
+    types: HashMap<Value, Type>,
+    constants: HashMap<Value, String>,
+    /// External functions and intrinsics used by the function.
+    declarations: BTreeSet<String>,
+    /// Blocks leaving the function, emitted after the current block.
+    exits: Vec<(String, Target)>,
+    /// The number of temporaries and generated labels.
+    temps: u32,
+    out: String,
+}
+
+impl<'a> FunctionWriter<'a> {
+    fn new(function: &'a ir::Function) -> Self {
+        let mut regs = BTreeSet::new();
+        let mut written = BTreeSet::new();
+        for block in function.blocks.values() {
+            for stmt in &block.stmts {
+                match *stmt {
+                    Stmt::Let { expr: Expr::Get(reg), .. } => {
+                        regs.insert(reg);
+                    }
+                    Stmt::Set(reg, _) => {
+                        regs.insert(reg);
+                        written.insert(reg);
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Self {
+            function,
+            regs,
+            written,
+            types: HashMap::new(),
+            constants: HashMap::new(),
+            declarations: BTreeSet::new(),
+            exits: Vec::new(),
+            temps: 0,
+            out: String::new(),
+        }
+    }
+
+    /// Returns a new temporary name, with the given prefix.
+    fn temp(&mut self, prefix: &str) -> String {
+        self.temps += 1;
+        format!("{prefix}{}", self.temps - 1)
+    }
+
+    /// Returns the operand for a value: its name, or a constant.
+    fn operand(&self, value: Value) -> String {
+        match self.constants.get(&value) {
+            Some(constant) => constant.clone(),
+            None => format!("%v{}", value.0),
+        }
+    }
+
+    /// Emits an instruction defining a new temporary, returning its name.
+    fn emit(&mut self, instruction: fmt::Arguments<'_>) -> Result<String, fmt::Error> {
+        let name = self.temp("%t");
+        writeln!(self.out, "  {name} = {instruction}")?;
+        Ok(name)
+    }
+
+    /// Declares the function at an address, unless it is the one emitted, returning its name.
+    fn function(&mut self, addr: u32) -> String {
+        let function = format!("@fn_{addr:08x}");
+        if addr != self.function.address {
+            self.declarations.insert(format!("declare void {function}(ptr, ptr)"));
+        }
+        function
+    }
+
+    /// Declares an overloaded LLVM intrinsic, returning its name.
+    fn intrinsic(&mut self, name: &str, ty: &str, params: &[&str]) -> String {
+        let function = format!("@llvm.{name}.{ty}");
+        let params = params.join(", ");
+        self.declarations.insert(format!("declare {ty} {function}({params})"));
+        function
+    }
+
+    fn write(&mut self) -> fmt::Result {
+        let function = self.function;
+        writeln!(self.out, "define void @fn_{:08x}(ptr %ctx, ptr %mem) {{", function.address)?;
+        writeln!(self.out, "entry:")?;
+        for reg in self.regs.clone() {
+            let (_, field) = context_field(reg);
+            writeln!(self.out, "  %{reg} = alloca {}", llvm_type(reg.ty()))?;
+            writeln!(self.out, "  %ctx.{reg} = getelementptr %Context, ptr %ctx, {field}")?;
+        }
+        self.reload()?;
+        writeln!(self.out, "  br label %{}", label(function.address))?;
+        for (&addr, block) in &function.blocks {
+            writeln!(self.out)?;
+            writeln!(self.out, "{}:", label(addr))?;
+            for stmt in &block.stmts {
+                self.stmt(stmt)?;
+            }
+            self.terminator(&block.terminator)?;
+            for (name, target) in core::mem::take(&mut self.exits) {
+                writeln!(self.out)?;
+                writeln!(self.out, "{name}:")?;
+                self.leave(&target)?;
+            }
+        }
+        writeln!(self.out, "}}")
+    }
+
+    /// Writes the registers written by the function back to the context.
+    fn spill(&mut self) -> fmt::Result {
+        for reg in self.written.clone() {
+            let (ty, _) = context_field(reg);
+            let value = self.emit(format_args!("load {}, ptr %{reg}", llvm_type(reg.ty())))?;
+            let value = match reg.ty() {
+                Type::I1 => self.emit(format_args!("zext i1 {value} to i8"))?,
+                _ => value,
+            };
+            writeln!(self.out, "  store {ty} {value}, ptr %ctx.{reg}")?;
+        }
+        Ok(())
+    }
+
+    /// Loads the registers used by the function from the context.
+    fn reload(&mut self) -> fmt::Result {
+        for reg in self.regs.clone() {
+            let (ty, _) = context_field(reg);
+            let value = self.emit(format_args!("load {ty}, ptr %ctx.{reg}"))?;
+            let value = match reg.ty() {
+                Type::I1 => self.emit(format_args!("trunc i8 {value} to i1"))?,
+                _ => value,
+            };
+            writeln!(self.out, "  store {} {value}, ptr %{reg}", llvm_type(reg.ty()))?;
+        }
+        Ok(())
+    }
+
+    /// Emits a call to the runtime, with the registers in the context.
+    fn call(&mut self, function: &str, args: &str) -> fmt::Result {
+        self.spill()?;
+        writeln!(self.out, "  call void {function}(ptr %ctx, ptr %mem{args})")?;
+        self.reload()
+    }
+
+    /// Returns the pointer to the host memory at a guest address.
+    fn address(&mut self, addr: Value) -> Result<String, fmt::Error> {
+        let addr = self.operand(addr);
+        let offset = self.emit(format_args!("zext i32 {addr} to i64"))?;
+        self.emit(format_args!("getelementptr i8, ptr %mem, i64 {offset}"))
+    }
+
+    /// Starts a block continuing after a conditional statement, branching to `then` if
+    /// the condition is set.
+    fn branch_around(&mut self, cond: Value, then: &str) -> Result<String, fmt::Error> {
+        let next = self.temp("next_");
+        let cond = self.operand(cond);
+        writeln!(self.out, "  br i1 {cond}, label %{then}, label %{next}")?;
+        writeln!(self.out)?;
+        writeln!(self.out, "{then}:")?;
+        Ok(next)
+    }
+
+    fn stmt(&mut self, stmt: &Stmt) -> fmt::Result {
+        match *stmt {
+            Stmt::Let { value, ty, ref expr } => {
+                self.types.insert(value, ty);
+                self.expr(value, ty, expr)
+            }
+            Stmt::Set(reg, value) => {
+                let value = self.operand(value);
+                writeln!(self.out, "  store {} {value}, ptr %{reg}", llvm_type(reg.ty()))
+            }
+            Stmt::Store { ty, addr, value } => {
+                let ptr = self.address(addr)?;
+                let lty = llvm_type(ty);
+                let mut value = self.operand(value);
+                if ty.bits() >= 16 {
+                    let bswap = self.intrinsic("bswap", lty, &[lty]);
+                    value = self.emit(format_args!("call {lty} {bswap}({lty} {value})"))?;
+                }
+                writeln!(self.out, "  store {lty} {value}, ptr {ptr}, align 1")
+            }
+            Stmt::Call { cond, callee } => {
+                let next = match cond {
+                    Some(cond) => {
+                        let then = self.temp("call_");
+                        Some(self.branch_around(cond, &then)?)
+                    }
+                    None => None,
+                };
+                match callee {
+                    Callee::Direct(addr) => {
+                        let function = self.function(addr);
+                        self.call(&function, "")?;
+                    }
+                    Callee::Indirect(value) => {
+                        self.declare_dispatch();
+                        let args = format!(", i32 {}", self.operand(value));
+                        self.call("@ppc_dispatch", &args)?;
+                    }
+                }
+                if let Some(next) = next {
+                    writeln!(self.out, "  br label %{next}")?;
+                    writeln!(self.out)?;
+                    writeln!(self.out, "{next}:")?;
+                }
+                Ok(())
+            }
+            Stmt::Trap(cond) => {
+                let then = self.temp("trap_");
+                let next = self.branch_around(cond, &then)?;
+                self.trap()?;
+                writeln!(self.out)?;
+                writeln!(self.out, "{next}:")
+            }
+            Stmt::Intrinsic { addr, ins } => {
+                self.declarations.insert("declare void @ppc_interpret(ptr, ptr, i32, i32)".into());
+                let args = format!(", i32 {}, i32 {}", addr as i32, ins.code as i32);
+                self.call("@ppc_interpret", &args)
+            }
+        }
+    }
+
+    fn declare_dispatch(&mut self) {
+        self.declarations.insert("declare void @ppc_dispatch(ptr, ptr, i32)".into());
+    }
+
+    /// Emits a trap, ending the block.
+    fn trap(&mut self) -> fmt::Result {
+        self.declarations.insert("declare void @ppc_trap(ptr) noreturn".into());
+        self.spill()?;
+        writeln!(self.out, "  call void @ppc_trap(ptr %ctx)")?;
+        writeln!(self.out, "  unreachable")
+    }
+
+    fn expr(&mut self, value: Value, ty: Type, expr: &Expr) -> fmt::Result {
+        let name = format!("%v{}", value.0);
+        let lty = llvm_type(ty);
+        match *expr {
+            Expr::Const(constant) => {
+                let constant = match ty {
+                    Type::I1 => (if constant & 1 != 0 { "true" } else { "false" }).into(),
+                    _ => {
+                        let shift = 64 - ty.bits();
+                        (((constant << shift) as i64) >> shift).to_string()
+                    }
+                };
+                self.constants.insert(value, constant);
+                Ok(())
+            }
+            Expr::Get(reg) => writeln!(self.out, "  {name} = load {lty}, ptr %{reg}"),
+            Expr::Load(addr) => {
+                let ptr = self.address(addr)?;
+                if ty.bits() < 16 {
+                    return writeln!(self.out, "  {name} = load {lty}, ptr {ptr}, align 1");
+                }
+                let loaded = self.emit(format_args!("load {lty}, ptr {ptr}, align 1"))?;
+                let bswap = self.intrinsic("bswap", lty, &[lty]);
+                writeln!(self.out, "  {name} = call {lty} {bswap}({lty} {loaded})")
+            }
+            Expr::Unary(op, a) => {
+                let a_ty = llvm_type(self.types[&a]);
+                let a = self.operand(a);
+                match op {
+                    UnaryOp::Not => writeln!(self.out, "  {name} = xor {lty} {a}, -1"),
+                    UnaryOp::Neg => writeln!(self.out, "  {name} = sub {lty} 0, {a}"),
+                    UnaryOp::Clz => {
+                        let ctlz = self.intrinsic("ctlz", lty, &[lty, "i1"]);
+                        writeln!(self.out, "  {name} = call {lty} {ctlz}({lty} {a}, i1 false)")
+                    }
+                    UnaryOp::Bswap => {
+                        let bswap = self.intrinsic("bswap", lty, &[lty]);
+                        writeln!(self.out, "  {name} = call {lty} {bswap}({lty} {a})")
+                    }
+                    UnaryOp::Zext | UnaryOp::Sext | UnaryOp::Trunc | UnaryOp::Bitcast => {
+                        writeln!(self.out, "  {name} = {op} {a_ty} {a} to {lty}")
+                    }
+                }
+            }
+            Expr::Binary(op, a, b) => {
+                let ty = self.types[&a];
+                let lty = llvm_type(ty);
+                let bits = ty.bits();
+                let (a, b) = (self.operand(a), self.operand(b));
+                let instruction = match op {
+                    BinaryOp::Add => "add",
+                    BinaryOp::Sub => "sub",
+                    BinaryOp::Mul => "mul",
+                    BinaryOp::And => "and",
+                    BinaryOp::Or => "or",
+                    BinaryOp::Xor => "xor",
+                    BinaryOp::Eq => "icmp eq",
+                    BinaryOp::Ne => "icmp ne",
+                    BinaryOp::Slt => "icmp slt",
+                    BinaryOp::Sgt => "icmp sgt",
+                    BinaryOp::Ult => "icmp ult",
+                    BinaryOp::Ugt => "icmp ugt",
+                    BinaryOp::MulHs | BinaryOp::MulHu => {
+                        let (wide, extend) = (
+                            format!("i{}", 2 * bits),
+                            if op == BinaryOp::MulHs { "sext" } else { "zext" },
+                        );
+                        let a = self.emit(format_args!("{extend} {lty} {a} to {wide}"))?;
+                        let b = self.emit(format_args!("{extend} {lty} {b} to {wide}"))?;
+                        let product = self.emit(format_args!("mul {wide} {a}, {b}"))?;
+                        let high = self.emit(format_args!("lshr {wide} {product}, {bits}"))?;
+                        return writeln!(self.out, "  {name} = trunc {wide} {high} to {lty}");
+                    }
+                    BinaryOp::DivS => {
+                        // Division by zero and overflow are undefined in LLVM
+                        let min = i64::MIN >> (64 - bits);
+                        let zero = self.emit(format_args!("icmp eq {lty} {b}, 0"))?;
+                        let is_min = self.emit(format_args!("icmp eq {lty} {a}, {min}"))?;
+                        let minus_one = self.emit(format_args!("icmp eq {lty} {b}, -1"))?;
+                        let overflow = self.emit(format_args!("and i1 {is_min}, {minus_one}"))?;
+                        let invalid = self.emit(format_args!("or i1 {zero}, {overflow}"))?;
+                        let divisor =
+                            self.emit(format_args!("select i1 {invalid}, {lty} 1, {lty} {b}"))?;
+                        let quotient = self.emit(format_args!("sdiv {lty} {a}, {divisor}"))?;
+                        let sign = self.emit(format_args!("ashr {lty} {a}, {}", bits - 1))?;
+                        return writeln!(
+                            self.out,
+                            "  {name} = select i1 {invalid}, {lty} {sign}, {lty} {quotient}"
+                        );
+                    }
+                    BinaryOp::DivU => {
+                        let zero = self.emit(format_args!("icmp eq {lty} {b}, 0"))?;
+                        let divisor =
+                            self.emit(format_args!("select i1 {zero}, {lty} 1, {lty} {b}"))?;
+                        let quotient = self.emit(format_args!("udiv {lty} {a}, {divisor}"))?;
+                        return writeln!(
+                            self.out,
+                            "  {name} = select i1 {zero}, {lty} 0, {lty} {quotient}"
+                        );
+                    }
+                    BinaryOp::Shl | BinaryOp::LShr => {
+                        // Shifting by the width or more is poison in LLVM
+                        let instruction = if op == BinaryOp::Shl { "shl" } else { "lshr" };
+                        let valid = self.emit(format_args!("icmp ult {lty} {b}, {bits}"))?;
+                        let shifted = self.emit(format_args!("{instruction} {lty} {a}, {b}"))?;
+                        return writeln!(
+                            self.out,
+                            "  {name} = select i1 {valid}, {lty} {shifted}, {lty} 0"
+                        );
+                    }
+                    BinaryOp::AShr => {
+                        let valid = self.emit(format_args!("icmp ult {lty} {b}, {bits}"))?;
+                        let amount = self.emit(format_args!(
+                            "select i1 {valid}, {lty} {b}, {lty} {}",
+                            bits - 1
+                        ))?;
+                        return writeln!(self.out, "  {name} = ashr {lty} {a}, {amount}");
+                    }
+                    BinaryOp::Rotl => {
+                        let fshl = self.intrinsic("fshl", lty, &[lty, lty, lty]);
+                        return writeln!(
+                            self.out,
+                            "  {name} = call {lty} {fshl}({lty} {a}, {lty} {a}, {lty} {b})"
+                        );
+                    }
+                };
+                writeln!(self.out, "  {name} = {instruction} {lty} {a}, {b}")
+            }
+        }
+    }
+
+    /// Returns the label continuing at a target, queueing a block if it leaves the function.
+    fn target(&mut self, target: &Target) -> String {
+        match *target {
+            Target::Address(addr) if self.function.blocks.contains_key(&addr) => label(addr),
+            _ => {
+                let name = self.temp("exit_");
+                self.exits.push((name.clone(), target.clone()));
+                name
+            }
+        }
+    }
+
+    fn terminator(&mut self, terminator: &Terminator) -> fmt::Result {
+        match terminator {
+            Terminator::Jump(target) => match target {
+                Target::Address(addr) if self.function.blocks.contains_key(addr) => {
+                    writeln!(self.out, "  br label %{}", label(*addr))
+                }
+                target => self.leave(target),
+            },
+            Terminator::Branch { cond, target, next } => {
+                let cond = self.operand(*cond);
+                let target = self.target(target);
+                let next = self.target(&Target::Address(*next));
+                writeln!(self.out, "  br i1 {cond}, label %{target}, label %{next}")
+            }
+            Terminator::Trap => self.trap(),
+        }
+    }
+
+    /// Emits the end of a block continuing at a target other than a block of the function.
+    fn leave(&mut self, target: &Target) -> fmt::Result {
+        match target {
+            Target::Address(addr) if self.function.blocks.contains_key(addr) => {
+                return writeln!(self.out, "  br label %{}", label(*addr));
+            }
+            Target::Address(addr) => {
+                // A tail call
+                let function = self.function(*addr);
+                self.spill()?;
+                writeln!(self.out, "  call void {function}(ptr %ctx, ptr %mem)")?;
+            }
+            Target::Indirect { value, targets } => {
+                let value = self.operand(*value);
+                let cases = targets
+                    .iter()
+                    .filter(|addr| self.function.blocks.contains_key(addr))
+                    .collect::<BTreeSet<_>>();
+                if !cases.is_empty() {
+                    let default = self.temp("dispatch_");
+                    writeln!(self.out, "  switch i32 {value}, label %{default} [")?;
+                    for &addr in cases {
+                        writeln!(self.out, "    i32 {}, label %{}", addr as i32, label(addr))?;
+                    }
+                    writeln!(self.out, "  ]")?;
+                    writeln!(self.out)?;
+                    writeln!(self.out, "{default}:")?;
+                }
+                self.declare_dispatch();
+                self.spill()?;
+                writeln!(self.out, "  call void @ppc_dispatch(ptr %ctx, ptr %mem, i32 {value})")?;
+            }
+            Target::Return => self.spill()?,
+        }
+        writeln!(self.out, "  ret void")
+    }
+}
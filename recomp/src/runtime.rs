@@ -0,0 +1,168 @@
+//! Helpers called by recompiled code.
+//!
+//! Recompiled functions only keep [Cpu::pc] up to date when falling back to the
+//! interpreter, so exceptions raised by recompiled code carry the address of the
+//! faulting instruction themselves.
+
+use powerpc::Ins;
+use powerpc_interp::{Cpu, Exception, Memory, MemoryFault};
+
+/// A recompiled function.
+pub type RecompiledFn = fn(&mut Cpu, &mut dyn Memory) -> Result<(), Exception>;
+
+/// Truncates a value to the register width of the current mode.
+#[inline]
+pub fn trunc(cpu: &Cpu, value: u64) -> u64 {
+    if cpu.mode64 {
+        value
+    } else {
+        value & 0xFFFF_FFFF
+    }
+}
+
+/// Sets a GPR, truncating the value to the register width of the current mode.
+#[inline]
+pub fn set_gpr(cpu: &mut Cpu, reg: usize, value: u64) {
+    cpu.gpr[reg] = trunc(cpu, value);
+}
+
+/// The result of a comparison as a condition register field, including XER\[SO\].
+#[inline]
+pub fn compare<T: Ord>(cpu: &Cpu, a: T, b: T) -> u8 {
+    let so = (cpu.xer >> 31) as u8;
+    match a.cmp(&b) {
+        core::cmp::Ordering::Less => 0b1000 | so,
+        core::cmp::Ordering::Greater => 0b0100 | so,
+        core::cmp::Ordering::Equal => 0b0010 | so,
+    }
+}
+
+/// Sets CR0 from a result, as done by instructions with Rc set.
+#[inline]
+pub fn record(cpu: &mut Cpu, value: u64) {
+    let value = if cpu.mode64 { value as i64 } else { value as u32 as i32 as i64 };
+    let result = compare(cpu, value, 0);
+    cpu.set_cr_field(0, result);
+}
+
+/// Computes `a + b + carry`, setting XER\[CA\].
+pub fn add_carry(cpu: &mut Cpu, a: u64, b: u64, carry: bool) -> u64 {
+    let (result, ca) = if cpu.mode64 {
+        let (sum, c1) = a.overflowing_add(b);
+        let (sum, c2) = sum.overflowing_add(carry as u64);
+        (sum, c1 || c2)
+    } else {
+        let (sum, c1) = (a as u32).overflowing_add(b as u32);
+        let (sum, c2) = sum.overflowing_add(carry as u32);
+        (sum as u64, c1 || c2)
+    };
+    set_ca(cpu, ca);
+    result
+}
+
+#[inline]
+fn set_ca(cpu: &mut Cpu, ca: bool) {
+    if ca {
+        cpu.xer |= Cpu::XER_CA;
+    } else {
+        cpu.xer &= !Cpu::XER_CA;
+    }
+}
+
+/// Shifts a word right algebraically, setting XER\[CA\] if the value is negative
+/// and any 1 bits were shifted out. (`sraw`, `srawi`)
+pub fn sraw(cpu: &mut Cpu, value: u64, n: u32) -> u64 {
+    let value = value as i32;
+    let result = value >> n.min(31);
+    let lost = if n > 31 { value != 0 } else { n != 0 && (value as u32) << (32 - n) != 0 };
+    set_ca(cpu, value < 0 && lost);
+    result as i64 as u64
+}
+
+/// Divides signed words. (`divw`)
+///
+/// Invalid divisions return -1 for negative dividends and 0 otherwise, as on the 750.
+pub fn divw(a: u64, b: u64) -> u64 {
+    let (a, b) = (a as i32, b as i32);
+    if b == 0 || (a == i32::MIN && b == -1) {
+        (a >> 31) as u32 as u64
+    } else {
+        (a / b) as u32 as u64
+    }
+}
+
+/// Divides unsigned words, returning 0 when dividing by zero. (`divwu`)
+pub fn divwu(a: u64, b: u64) -> u64 {
+    (a as u32).checked_div(b as u32).unwrap_or(0) as u64
+}
+
+/// Rotates the low word left, replicating it into the high word.
+///
+/// Used by `rlwinm`, `rlwnm` and `rlwimi` with a wrapping mask, which selects bits
+/// from the high word in 64-bit mode.
+#[inline]
+pub fn rotlw(value: u64, n: u32) -> u64 {
+    let word = (value as u32).rotate_left(n) as u64;
+    word | (word << 32)
+}
+
+/// Decrements CTR, returning its new value.
+#[inline]
+pub fn decrement_ctr(cpu: &mut Cpu) -> u64 {
+    cpu.ctr = trunc(cpu, cpu.ctr.wrapping_sub(1));
+    cpu.ctr
+}
+
+macro_rules! access {
+    ($read:ident, $write:ident, $typ:ident) => {
+        #[doc = concat!("Reads a big-endian `", stringify!($typ), "` for the instruction at `pc`.")]
+        #[inline]
+        pub fn $read(mem: &mut dyn Memory, pc: u64, ea: u64) -> Result<$typ, Exception> {
+            mem.$read(ea).map_err(|MemoryFault { addr }| Exception::MemoryFault { pc, addr })
+        }
+
+        #[doc = concat!("Writes a big-endian `", stringify!($typ), "` for the instruction at `pc`.")]
+        #[inline]
+        pub fn $write(
+            mem: &mut dyn Memory,
+            pc: u64,
+            ea: u64,
+            value: $typ,
+        ) -> Result<(), Exception> {
+            mem.$write(ea, value).map_err(|MemoryFault { addr }| Exception::MemoryFault { pc, addr })
+        }
+    };
+}
+
+access!(read_u8, write_u8, u8);
+access!(read_u16, write_u16, u16);
+access!(read_u32, write_u32, u32);
+access!(read_u64, write_u64, u64);
+
+/// Executes the instruction at `pc` with the interpreter.
+#[inline]
+pub fn interpret(cpu: &mut Cpu, mem: &mut dyn Memory, pc: u64, ins: Ins) -> Result<(), Exception> {
+    cpu.pc = pc;
+    cpu.execute(ins, mem)
+}
+
+/// Runs the function at `addr` with the interpreter, until it returns to LR.
+pub fn interpret_call(cpu: &mut Cpu, mem: &mut dyn Memory, addr: u64) -> Result<(), Exception> {
+    let ret = cpu.lr;
+    cpu.pc = addr;
+    cpu.run_until(mem, ret, u64::MAX).map(|_| ())
+}
+
+/// Calls the function at `addr` through a table of recompiled functions sorted by address,
+/// or runs it with the interpreter if it was not recompiled.
+pub fn call_indirect(
+    table: &[(u64, RecompiledFn)],
+    cpu: &mut Cpu,
+    mem: &mut dyn Memory,
+    addr: u64,
+) -> Result<(), Exception> {
+    match table.binary_search_by_key(&addr, |&(address, _)| address) {
+        Ok(i) => (table[i].1)(cpu, mem),
+        Err(_) => interpret_call(cpu, mem, addr),
+    }
+}
@@ -0,0 +1,596 @@
+use crate::{Function, Program};
+use core::fmt::{self, Display, Formatter};
+use powerpc::{
+    mask32, mask64, AccessFormat, AccessKind, AccessOffset, Alignment, Argument, BranchCondition,
+    CtrCondition, FieldId, FlowControl, Ins, Opcode, GPR,
+};
+
+/// Rust source code for a recompiled program. (See [Program::rust])
+#[derive(Copy, Clone, Debug)]
+pub struct RustSource<'a> {
+    program: &'a Program,
+}
+
+impl Program {
+    /// Returns the program as a Rust module, with one function per recovered function.
+    ///
+    /// Recompiled functions operate on a [Cpu](powerpc_interp::Cpu) and a
+    /// [Memory](powerpc_interp::Memory), using the helpers in [runtime](crate::runtime).
+    /// The module also contains a `dispatch` function for indirect calls, which runs
+    /// functions that were not recompiled with the interpreter.
+    ///
+    /// Integer arithmetic, logical, rotate, compare and condition register instructions,
+    /// and integer loads and stores, are translated to Rust. Other instructions are
+    /// executed with the interpreter.
+    ///
+    /// Calls are assumed to return to the instruction following them, and `blr` to
+    /// return from the function.
+    #[inline]
+    pub fn rust(&self) -> RustSource<'_> {
+        RustSource { program: self }
+    }
+}
+
+impl Display for RustSource<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let functions = &self.program.functions;
+        writeln!(f, "// Code generated by powerpc-recomp. DO NOT EDIT.")?;
+        writeln!(f, "#![allow(unused, clippy::all)]")?;
+        writeln!(f, "use powerpc::{{CRBit, Ins, Opcode}};")?;
+        writeln!(f, "use powerpc_interp::{{Cpu, Exception, Memory}};")?;
+        writeln!(f, "use powerpc_recomp::runtime::*;")?;
+        writeln!(f)?;
+        writeln!(f, "/// Recompiled functions, sorted by address.")?;
+        writeln!(f, "pub static FUNCTIONS: [(u64, RecompiledFn); {}] = [", functions.len())?;
+        for function in functions.values() {
+            writeln!(f, "    ({:#x}, {}),", function.address, function.name())?;
+        }
+        writeln!(f, "];")?;
+        writeln!(f)?;
+        writeln!(f, "/// Calls the function at `addr`, interpreting it if it was not recompiled.")?;
+        writeln!(
+            f,
+            "pub fn dispatch(cpu: &mut Cpu, mem: &mut dyn Memory, addr: u64) -> Result<(), Exception> {{"
+        )?;
+        writeln!(f, "    call_indirect(&FUNCTIONS, cpu, mem, addr)")?;
+        writeln!(f, "}}")?;
+        for function in functions.values() {
+            writeln!(f)?;
+            FunctionWriter::new(self.program, function).write(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// How control leaves a block.
+enum Exit {
+    /// Continue with a block of the function.
+    Block(u32),
+    /// Continue with the block at the address in CTR. (Jump tables)
+    Computed,
+    /// Return the value of an expression from the function.
+    Return(String),
+}
+
+struct FunctionWriter<'a> {
+    program: &'a Program,
+    function: &'a Function,
+    /// Whether blocks are selected by a loop, or the function is a single block.
+    looped: bool,
+}
+
+impl<'a> FunctionWriter<'a> {
+    fn new(program: &'a Program, function: &'a Function) -> Self {
+        let address = function.address;
+        let looped = function.blocks.len() > 1
+            || !function.jump_tables.is_empty()
+            || function.blocks.iter().any(|(&start, code)| {
+                code.iter().enumerate().any(|(i, ins)| {
+                    let addr = start.wrapping_add(4 * i as u32);
+                    matches!(ins.flow_control(), FlowControl::Branch { .. })
+                        && ins.branch_dest(addr) == Some(address)
+                })
+            });
+        Self { program, function, looped }
+    }
+
+    fn write(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "pub fn {}(cpu: &mut Cpu, mem: &mut dyn Memory) -> Result<(), Exception> {{",
+            self.function.name()
+        )?;
+        if self.looped {
+            writeln!(f, "    let mut block: u64 = {:#x};", self.function.address)?;
+            writeln!(f, "    loop {{")?;
+            writeln!(f, "        match block {{")?;
+            for (&start, code) in &self.function.blocks {
+                writeln!(f, "            {start:#x} => {{")?;
+                self.write_block(f, "                ", start, code)?;
+                writeln!(f, "            }}")?;
+            }
+            writeln!(f, "            _ => return dispatch(cpu, mem, block),")?;
+            writeln!(f, "        }}")?;
+            writeln!(f, "    }}")?;
+        } else if let Some((&start, code)) = self.function.blocks.first_key_value() {
+            self.write_block(f, "    ", start, code)?;
+        }
+        writeln!(f, "}}")
+    }
+
+    fn write_block(
+        &self,
+        f: &mut Formatter<'_>,
+        indent: &str,
+        start: u32,
+        code: &[Ins],
+    ) -> fmt::Result {
+        let mut addr = start;
+        let mut falls_through = true;
+        for (i, &ins) in code.iter().enumerate() {
+            writeln!(f, "{indent}// {addr:08x}: {}", ins.simplified())?;
+            let last = i == code.len() - 1;
+            let flow = ins.flow_control();
+            falls_through = flow.falls_through();
+            for line in self.instruction(addr, ins, flow, last && !falls_through) {
+                writeln!(f, "{indent}{line}")?;
+            }
+            addr = addr.wrapping_add(4);
+        }
+        if falls_through {
+            for line in self.exit(self.jump(addr), false, true) {
+                writeln!(f, "{indent}{line}")?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the exit for a direct branch to `target`.
+    fn jump(&self, target: u32) -> Exit {
+        if self.looped && self.function.blocks.contains_key(&target) {
+            Exit::Block(target)
+        } else {
+            Exit::Return(self.call(target))
+        }
+    }
+
+    /// Returns an expression calling the function at `target`.
+    fn call(&self, target: u32) -> String {
+        match self.program.functions.get(&target) {
+            Some(function) => format!("{}(cpu, mem)", function.name()),
+            None => format!("dispatch(cpu, mem, {target:#x})"),
+        }
+    }
+
+    /// Returns the statements leaving the block, from within a condition if `guarded`.
+    ///
+    /// If `tail` is set, nothing follows the statements in the block.
+    fn exit(&self, exit: Exit, guarded: bool, tail: bool) -> Vec<String> {
+        let mut lines = match exit {
+            Exit::Block(target) => vec![format!("block = {target:#x};")],
+            Exit::Computed => vec!["block = cpu.ctr & !3;".into()],
+            Exit::Return(expr) if tail && !guarded && !self.looped => return vec![expr],
+            Exit::Return(expr) => return vec![format!("return {expr};")],
+        };
+        if guarded {
+            lines.push("continue;".into());
+        }
+        lines
+    }
+
+    /// Returns the statements of an instruction.
+    ///
+    /// If `tail` is set, the instruction ends the function or block.
+    fn instruction(&self, addr: u32, ins: Ins, flow: FlowControl, tail: bool) -> Vec<String> {
+        let next = addr.wrapping_add(4);
+        let (condition, lines) = match flow {
+            FlowControl::Sequential | FlowControl::SystemCall => return operation(addr, ins),
+            FlowControl::Trap { .. } => {
+                let mut lines = operation(addr, ins);
+                if !flow.falls_through() {
+                    lines.push("unreachable!()".into());
+                }
+                return lines;
+            }
+            FlowControl::ExceptionReturn => {
+                let mut lines = operation(addr, ins);
+                lines.push("let target = cpu.pc;".into());
+                lines.extend(self.exit(
+                    Exit::Return("dispatch(cpu, mem, target)".into()),
+                    false,
+                    tail,
+                ));
+                return lines;
+            }
+            FlowControl::Branch { condition } => {
+                let target = ins.branch_dest(addr).unwrap_or(next);
+                let guard = guard(condition, true);
+                (guard.clone(), self.exit(self.jump(target), guard.is_some(), tail))
+            }
+            FlowControl::IndirectJump { condition } => {
+                // Decrementing CTR is an invalid form of bcctr, so only CR is tested
+                let guard = guard(condition, false);
+                let lines = if self.function.jump_tables.contains_key(&addr) {
+                    self.exit(Exit::Computed, guard.is_some(), tail)
+                } else {
+                    let mut lines = vec!["let target = cpu.ctr & !3;".into()];
+                    let exit = Exit::Return("dispatch(cpu, mem, target)".into());
+                    lines.extend(self.exit(exit, guard.is_some(), tail));
+                    lines
+                };
+                (guard, lines)
+            }
+            // LR is written whether or not the branch is taken
+            FlowControl::Call { condition, indirect: false } => {
+                let target = ins.branch_dest(addr).unwrap_or(next);
+                let mut lines = vec![format!("cpu.lr = {next:#x};")];
+                // `bcl 20, 31, $+4` only reads the address of the next instruction
+                if target != next {
+                    lines.extend(guarded(
+                        guard(condition, true),
+                        vec![format!("{}?;", self.call(target))],
+                    ));
+                }
+                return lines;
+            }
+            FlowControl::Call { condition, indirect: true } => {
+                let (register, decrement) =
+                    if ins.op == Opcode::Bcctr { ("ctr", false) } else { ("lr", true) };
+                let mut lines = vec![
+                    format!("let target = cpu.{register} & !3;"),
+                    format!("cpu.lr = {next:#x};"),
+                ];
+                let call = vec!["dispatch(cpu, mem, target)?;".into()];
+                lines.extend(guarded(guard(condition, decrement), call));
+                return lines;
+            }
+            FlowControl::Return { condition } => {
+                let guard = guard(condition, true);
+                (guard.clone(), self.exit(Exit::Return("Ok(())".into()), guard.is_some(), tail))
+            }
+        };
+        guarded(condition, lines)
+    }
+}
+
+/// Returns statements executed only if `condition` holds.
+fn guarded(condition: Option<String>, lines: Vec<String>) -> Vec<String> {
+    match condition {
+        Some(condition) => {
+            let mut guarded = vec![format!("if {condition} {{")];
+            guarded.extend(lines.into_iter().map(|line| format!("    {line}")));
+            guarded.push("}".into());
+            guarded
+        }
+        None => lines,
+    }
+}
+
+/// Returns the condition of a branch as an expression, or `None` if it is always taken.
+///
+/// CTR is decremented first, as the expression is evaluated from left to right.
+fn guard(condition: BranchCondition, decrement: bool) -> Option<String> {
+    let mut terms = Vec::new();
+    match condition.ctr {
+        Some(CtrCondition::Zero) if decrement => terms.push("decrement_ctr(cpu) == 0".to_string()),
+        Some(CtrCondition::NonZero) if decrement => {
+            terms.push("decrement_ctr(cpu) != 0".to_string())
+        }
+        _ => {}
+    }
+    if let Some(cr) = condition.cr {
+        let not = if cr.value { "" } else { "!" };
+        terms.push(format!("{not}cpu.cr_bit(CRBit({}))", cr.bit.0));
+    }
+    (!terms.is_empty()).then(|| terms.join(" && "))
+}
+
+/// Whether an optional modifier is present and set.
+fn modifier(ins: Ins, id: FieldId) -> bool {
+    ins.op.modifiers().contains(&id) && id.extract(ins.code) != 0
+}
+
+fn gpr(reg: u8) -> String {
+    format!("cpu.gpr[{reg}]")
+}
+
+/// Returns `rA`, or 0 if `rA` is r0.
+fn gpr0(reg: u8) -> String {
+    if reg == 0 {
+        "0".into()
+    } else {
+        gpr(reg)
+    }
+}
+
+/// Returns `base + imm`.
+fn add_imm(base: &str, imm: i64) -> String {
+    match (base, imm) {
+        ("0", _) => format!("{:#x}", imm as u64),
+        (_, 0) => base.into(),
+        (_, imm) if imm < 0 => format!("{base}.wrapping_sub({:#x})", imm.unsigned_abs()),
+        (_, imm) => format!("{base}.wrapping_add({imm:#x})"),
+    }
+}
+
+/// Returns a signed immediate as a literal.
+fn signed(imm: i64) -> String {
+    if imm < 0 {
+        format!("-{:#x}", imm.unsigned_abs())
+    } else {
+        format!("{imm:#x}")
+    }
+}
+
+/// Returns the statements of an instruction that does not affect control flow,
+/// falling back to the interpreter if it is not translated.
+fn operation(addr: u32, ins: Ins) -> Vec<String> {
+    translate(addr, ins).unwrap_or_else(|| {
+        vec![format!(
+            "interpret(cpu, mem, {addr:#x}, Ins {{ code: {:#010x}, op: Opcode::{:?} }})?;",
+            ins.code, ins.op
+        )]
+    })
+}
+
+/// Translates an instruction to Rust statements, if supported.
+fn translate(addr: u32, ins: Ins) -> Option<Vec<String>> {
+    if modifier(ins, FieldId::OE) {
+        return None;
+    }
+    let (rd, ra, rb, rs) = (ins.field_rd(), ins.field_ra(), ins.field_rb(), ins.field_rs());
+    let (a, b, s) = (gpr(ra), gpr(rb), gpr(rs));
+    let simm = ins.field_simm() as i64;
+    let uimm = ins.field_uimm() as u64;
+    let mut lines = Vec::new();
+
+    // The destination register and an expression of its new value
+    let (dest, value) = match ins.op {
+        // Arithmetic
+        Opcode::Addi => (rd, add_imm(&gpr0(ra), simm)),
+        Opcode::Addis => (rd, add_imm(&gpr0(ra), simm << 16)),
+        Opcode::Add => (rd, format!("{a}.wrapping_add({b})")),
+        Opcode::Subf => (rd, format!("{b}.wrapping_sub({a})")),
+        Opcode::Neg => (rd, format!("{a}.wrapping_neg()")),
+        Opcode::Addc
+        | Opcode::Adde
+        | Opcode::Addic
+        | Opcode::Addic_
+        | Opcode::Addme
+        | Opcode::Addze
+        | Opcode::Subfc
+        | Opcode::Subfe
+        | Opcode::Subfic
+        | Opcode::Subfme
+        | Opcode::Subfze => {
+            let simm = format!("{:#x}", simm as u64);
+            let (x, y, carry) = match ins.op {
+                Opcode::Addc => (a, b, "false"),
+                Opcode::Adde => (a, b, "cpu.ca()"),
+                Opcode::Addic | Opcode::Addic_ => (a, simm, "false"),
+                Opcode::Addme => (a, "u64::MAX".into(), "cpu.ca()"),
+                Opcode::Addze => (a, "0".into(), "cpu.ca()"),
+                Opcode::Subfc => (format!("!{a}"), b, "true"),
+                Opcode::Subfe => (format!("!{a}"), b, "cpu.ca()"),
+                Opcode::Subfic => (format!("!{a}"), simm, "true"),
+                Opcode::Subfme => (format!("!{a}"), "u64::MAX".into(), "cpu.ca()"),
+                _ => (format!("!{a}"), "0".into(), "cpu.ca()"),
+            };
+            lines.push(format!("let (a, b, carry) = ({x}, {y}, {carry});"));
+            (rd, "add_carry(cpu, a, b, carry)".into())
+        }
+        Opcode::Mulli => (rd, format!("{a}.wrapping_mul({:#x})", simm as u64)),
+        Opcode::Mullw => (rd, format!("(({a} as i32 as i64) * ({b} as i32 as i64)) as u64")),
+        Opcode::Mulhw => {
+            (rd, format!("((({a} as i32 as i64) * ({b} as i32 as i64)) >> 32) as u64"))
+        }
+        Opcode::Mulhwu => (rd, format!("(({a} as u32 as u64) * ({b} as u32 as u64)) >> 32")),
+        Opcode::Divw => (rd, format!("divw({a}, {b})")),
+        Opcode::Divwu => (rd, format!("divwu({a}, {b})")),
+
+        // Logical
+        Opcode::And => (ra, format!("{s} & {b}")),
+        Opcode::Andc => (ra, format!("{s} & !{b}")),
+        Opcode::Andi_ => (ra, format!("{s} & {uimm:#x}")),
+        Opcode::Andis_ => (ra, format!("{s} & {:#x}", uimm << 16)),
+        Opcode::Or if rs == rb => (ra, s),
+        Opcode::Or => (ra, format!("{s} | {b}")),
+        Opcode::Orc => (ra, format!("{s} | !{b}")),
+        // nop
+        Opcode::Ori if rs == ra && uimm == 0 => return Some(vec![]),
+        Opcode::Ori => (ra, format!("{s} | {uimm:#x}")),
+        Opcode::Oris => (ra, format!("{s} | {:#x}", uimm << 16)),
+        Opcode::Xor => (ra, format!("{s} ^ {b}")),
+        Opcode::Xori => (ra, format!("{s} ^ {uimm:#x}")),
+        Opcode::Xoris => (ra, format!("{s} ^ {:#x}", uimm << 16)),
+        Opcode::Nand => (ra, format!("!({s} & {b})")),
+        Opcode::Nor => (ra, format!("!({s} | {b})")),
+        Opcode::Eqv => (ra, format!("!({s} ^ {b})")),
+        Opcode::Extsb => (ra, format!("{s} as i8 as i64 as u64")),
+        Opcode::Extsh => (ra, format!("{s} as i16 as i64 as u64")),
+        Opcode::Extsw => (ra, format!("{s} as i32 as i64 as u64")),
+        Opcode::Cntlzw => (ra, format!("({s} as u32).leading_zeros() as u64")),
+
+        // Shift
+        Opcode::Slw => {
+            (ra, format!("({s} as u32).checked_shl({b} as u32 & 0x3f).unwrap_or(0) as u64"))
+        }
+        Opcode::Srw => {
+            (ra, format!("({s} as u32).checked_shr({b} as u32 & 0x3f).unwrap_or(0) as u64"))
+        }
+        Opcode::Sraw => {
+            lines.push(format!("let (s, n) = ({s}, {b} as u32 & 0x3f);"));
+            (ra, "sraw(cpu, s, n)".into())
+        }
+        Opcode::Srawi => {
+            lines.push(format!("let s = {s};"));
+            (ra, format!("sraw(cpu, s, {})", ins.field_sh()))
+        }
+
+        // Rotate
+        Opcode::Rlwinm | Opcode::Rlwnm | Opcode::Rlwimi => {
+            let n = match ins.op {
+                Opcode::Rlwnm => format!("{b} as u32 & 31"),
+                _ => ins.field_sh().to_string(),
+            };
+            let (mb, me) = (ins.field_mb(), ins.field_me());
+            // In 64-bit mode, a wrapping mask selects bits of the word replicated
+            // into the high word
+            let (rotated, mask) = if mb <= me {
+                let mask = mask32(mb, me);
+                let word = match n.as_str() {
+                    "0" => format!("{s} as u32"),
+                    _ => format!("({s} as u32).rotate_left({n})"),
+                };
+                (format!("({word} & {mask:#x}) as u64"), mask as u64)
+            } else {
+                let mask = mask64(mb + 32, me + 32);
+                (format!("rotlw({s}, {n}) & {mask:#x}"), mask)
+            };
+            match ins.op {
+                Opcode::Rlwimi => (ra, format!("{rotated} | ({a} & {:#x})", !mask)),
+                _ => (ra, rotated),
+            }
+        }
+
+        // Compare
+        Opcode::Cmp | Opcode::Cmpi | Opcode::Cmpl | Opcode::Cmpli => {
+            let ty = match (ins.op, ins.field_l() != 0) {
+                (Opcode::Cmp | Opcode::Cmpi, false) => "i32",
+                (Opcode::Cmp | Opcode::Cmpi, true) => "i64",
+                (_, false) => "u32",
+                (_, true) => "u64",
+            };
+            let cast = |reg: String| if ty == "u64" { reg } else { format!("{reg} as {ty}") };
+            let y = match ins.op {
+                Opcode::Cmp | Opcode::Cmpl => cast(b),
+                Opcode::Cmpi => signed(simm),
+                _ => format!("{uimm:#x}"),
+            };
+            lines.push(format!("let cr = compare(cpu, {}, {y});", cast(a)));
+            lines.push(format!("cpu.set_cr_field({}, cr);", ins.field_crfd()));
+            return Some(lines);
+        }
+
+        // Condition register
+        Opcode::Crand
+        | Opcode::Crandc
+        | Opcode::Creqv
+        | Opcode::Crnand
+        | Opcode::Crnor
+        | Opcode::Cror
+        | Opcode::Crorc
+        | Opcode::Crxor => {
+            let value = match ins.op {
+                Opcode::Crand => "a & b",
+                Opcode::Crandc => "a & !b",
+                Opcode::Creqv => "!(a ^ b)",
+                Opcode::Crnand => "!(a & b)",
+                Opcode::Crnor => "!(a | b)",
+                Opcode::Cror => "a | b",
+                Opcode::Crorc => "a | !b",
+                _ => "a ^ b",
+            };
+            lines.push(format!(
+                "let (a, b) = (cpu.cr_bit(CRBit({})), cpu.cr_bit(CRBit({})));",
+                ins.field_crba(),
+                ins.field_crbb()
+            ));
+            lines.push(format!("cpu.set_cr_bit(CRBit({}), {value});", ins.field_crbd()));
+            return Some(lines);
+        }
+        Opcode::Mcrf => {
+            lines.push(format!("let value = cpu.cr_field({});", ins.field_crfs()));
+            lines.push(format!("cpu.set_cr_field({}, value);", ins.field_crfd()));
+            return Some(lines);
+        }
+        Opcode::Mfcr => (rd, "cpu.cr as u64".into()),
+        Opcode::Mtcrf => {
+            let mask = (0..8)
+                .filter(|i| ins.field_crm() & (0x80 >> i) != 0)
+                .fold(0u32, |mask, i| mask | (0xF000_0000 >> (4 * i)));
+            lines.push(format!("cpu.cr = ({s} as u32 & {mask:#x}) | (cpu.cr & {:#x});", !mask));
+            return Some(lines);
+        }
+
+        // Special-purpose registers
+        Opcode::Mfspr => match ins.field_spr() {
+            8 => (rd, "cpu.lr".into()),
+            9 => (rd, "cpu.ctr".into()),
+            spr => (rd, format!("cpu.read_spr({spr})")),
+        },
+        Opcode::Mtspr => {
+            lines.push(match ins.field_spr() {
+                8 => format!("cpu.lr = trunc(cpu, {s});"),
+                9 => format!("cpu.ctr = trunc(cpu, {s});"),
+                spr => format!("cpu.write_spr({spr}, {s});"),
+            });
+            return Some(lines);
+        }
+
+        // Synchronization and cache management have no effect
+        Opcode::Sync
+        | Opcode::Isync
+        | Opcode::Eieio
+        | Opcode::Dcbf
+        | Opcode::Dcbst
+        | Opcode::Dcbt
+        | Opcode::Dcbtst
+        | Opcode::Icbi => return Some(lines),
+
+        _ => return translate_access(addr, ins),
+    };
+    lines.push(format!("let value = {value};"));
+    lines.push(format!("set_gpr(cpu, {dest}, value);"));
+    if modifier(ins, FieldId::Rc)
+        || matches!(ins.op, Opcode::Addic_ | Opcode::Andi_ | Opcode::Andis_)
+    {
+        lines.push("record(cpu, value);".into());
+    }
+    Some(lines)
+}
+
+/// Translates an integer load or store, if supported.
+fn translate_access(addr: u32, ins: Ins) -> Option<Vec<String>> {
+    let access = ins.memory_access()?;
+    let (Argument::GPR(GPR(reg)), Some(size)) = (access.reg, access.size) else {
+        return None;
+    };
+    if access.format != AccessFormat::Integer || access.alignment != Alignment::Any {
+        return None;
+    }
+    let base = access.base.map_or("0".into(), |base| gpr(base.0));
+    let ea = match access.offset {
+        AccessOffset::None => base,
+        AccessOffset::Displacement(offset) => add_imm(&base, offset as i64),
+        AccessOffset::Index(index) if base == "0" => gpr(index.0),
+        AccessOffset::Index(index) => format!("{base}.wrapping_add({})", gpr(index.0)),
+    };
+    let bits = size * 8;
+    let swap = if access.byte_reversed { ".swap_bytes()" } else { "" };
+    let mut lines = vec![format!("let ea = trunc(cpu, {ea});")];
+    match access.kind {
+        AccessKind::Load => {
+            let read = format!("read_u{bits}(mem, {addr:#x}, ea)?{swap}");
+            let value = match (access.signed, bits) {
+                (true, _) => format!("{read} as i{bits} as i64 as u64"),
+                (false, 64) => read,
+                (false, _) => format!("{read} as u64"),
+            };
+            lines.push(format!("let value = {value};"));
+            lines.push(format!("set_gpr(cpu, {reg}, value);"));
+        }
+        AccessKind::Store => {
+            let value = match (bits, swap) {
+                (64, _) => format!("{}{swap}", gpr(reg)),
+                (_, "") => format!("{} as u{bits}", gpr(reg)),
+                _ => format!("({} as u{bits}){swap}", gpr(reg)),
+            };
+            lines.push(format!("write_u{bits}(mem, {addr:#x}, ea, {value})?;"));
+        }
+    }
+    if let (true, Some(base)) = (access.update, access.base) {
+        lines.push(format!("cpu.gpr[{}] = ea;", base.0));
+    }
+    Some(lines)
+}
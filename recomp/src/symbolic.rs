@@ -0,0 +1,673 @@
+//! Symbolic execution of short instruction sequences.
+//!
+//! Instructions are [lifted](crate::lift) and evaluated on [Sym] expressions over the
+//! registers and memory before execution, giving the values of registers and stores, and
+//! the conditions and targets of branches, in terms of the inputs.
+//!
+//! Expressions are simplified as they are built. Logical, rotate and shift operations
+//! with constant operands are normalized to an OR of rotated and masked terms, so the
+//! different ways of extracting and inserting bit fields have the same form:
+//!
+//! ```
+//! use powerpc::{Extensions, Ins};
+//! use powerpc_recomp::ir::Reg;
+//! use powerpc_recomp::symbolic::execute;
+//!
+//! let code = |code: &[u32]| -> Vec<Ins> {
+//!     code.iter().map(|&code| Ins::new(code, Extensions::none())).collect()
+//! };
+//! // rlwinm r3, r4, 24, 16, 31
+//! let a = execute(0x80000000, &code(&[0x5483C43E])).unwrap();
+//! // slwi r3, r4, 8; srwi r3, r3, 16
+//! let b = execute(0x80000000, &code(&[0x5483402E, 0x5463843E])).unwrap();
+//! assert!(a.equivalent(&b, &[Reg::Gpr(3)]));
+//! assert_eq!(a.reg(Reg::Gpr(3)).to_string(), "and(rotl(r4, 0x18), 0xffff)");
+//! ```
+//!
+//! Equivalence is proven by comparing simplified expressions, so the check is sound
+//! but incomplete: sequences reported equivalent always are, but some equivalent
+//! sequences may not be detected.
+
+use crate::ir::{
+    rotl, BinaryOp, Callee, Expr, Reg, Stmt, Target, Terminator, Type, UnaryOp, Value,
+};
+use crate::{lift, RecompError};
+use core::cmp::Ordering;
+use core::fmt::{self, Display, Formatter};
+use powerpc::{CRBit, Ins};
+use powerpc_interp::{Cpu, Memory, MemoryFault};
+use std::collections::{BTreeMap, HashMap};
+use std::rc::Rc;
+
+/// A symbolic value, as an expression over the state before execution.
+#[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Sym {
+    /// A constant, within the mask of its type.
+    Const(Type, u64),
+    /// The value of a register before execution.
+    Reg(Reg),
+    /// A big-endian load from a memory state.
+    Load(Type, Rc<Mem>, Rc<Sym>),
+    /// An operation on one value, with the type of its result.
+    Unary(UnaryOp, Type, Rc<Sym>),
+    /// An operation on two values, with the type of its result.
+    Binary(BinaryOp, Type, Rc<Sym>, Rc<Sym>),
+}
+
+/// A symbolic memory state.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Mem {
+    /// The memory before execution.
+    #[default]
+    Initial,
+    /// A big-endian store on a previous state.
+    Store { prev: Rc<Mem>, ty: Type, addr: Sym, value: Sym },
+}
+
+impl Sym {
+    /// Returns a constant, truncated to the type.
+    pub fn constant(ty: Type, value: u64) -> Sym {
+        Sym::Const(ty, value & ty.mask())
+    }
+
+    /// Returns the type of the value.
+    pub fn ty(&self) -> Type {
+        match *self {
+            Sym::Const(ty, _) | Sym::Load(ty, ..) | Sym::Unary(_, ty, _) => ty,
+            Sym::Binary(_, ty, ..) => ty,
+            Sym::Reg(reg) => reg.ty(),
+        }
+    }
+
+    /// Returns the value if it is a constant.
+    pub fn as_const(&self) -> Option<u64> {
+        match *self {
+            Sym::Const(_, value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Splits the value into a base and a constant offset.
+    fn offset(&self) -> (Option<&Sym>, u64) {
+        match self {
+            Sym::Const(_, value) => (None, *value),
+            Sym::Binary(BinaryOp::Add, _, a, b) => match b.as_const() {
+                Some(offset) => (Some(a), offset),
+                None => (Some(self), 0),
+            },
+            _ => (Some(self), 0),
+        }
+    }
+
+    /// Returns a unary operation giving a value of type `ty`, simplified.
+    pub fn unary(op: UnaryOp, ty: Type, a: Sym) -> Sym {
+        use UnaryOp as U;
+        if let Some(value) = a.as_const() {
+            return Sym::constant(ty, op.eval(ty, a.ty(), value));
+        }
+        match (op, &a) {
+            (U::Not, _) => return Sym::binary(BinaryOp::Xor, a, Sym::constant(ty, u64::MAX)),
+            (U::Neg | U::Bswap, Sym::Unary(inner, _, x)) if *inner == op => return (**x).clone(),
+            (U::Bitcast, Sym::Unary(U::Bitcast, _, x)) if x.ty() == ty => return (**x).clone(),
+            (U::Zext | U::Sext, Sym::Unary(inner, _, x)) if *inner == op => {
+                return Sym::unary(op, ty, (**x).clone());
+            }
+            // Clearing the high bits is a mask, which combines with rotates and other masks
+            (U::Zext, Sym::Unary(U::Trunc, narrow, x)) if x.ty() == ty => {
+                return Sym::binary(BinaryOp::And, (**x).clone(), Sym::constant(ty, narrow.mask()));
+            }
+            (U::Trunc, Sym::Unary(inner @ (U::Zext | U::Sext | U::Trunc), _, x)) => {
+                let x = (**x).clone();
+                return match x.ty().bits().cmp(&ty.bits()) {
+                    Ordering::Equal => x,
+                    Ordering::Less => Sym::unary(*inner, ty, x),
+                    Ordering::Greater => Sym::unary(U::Trunc, ty, x),
+                };
+            }
+            // The low bits of these operations only depend on the low bits of the operands
+            (
+                U::Trunc,
+                Sym::Binary(
+                    inner @ (BinaryOp::Add
+                    | BinaryOp::Sub
+                    | BinaryOp::Mul
+                    | BinaryOp::And
+                    | BinaryOp::Or
+                    | BinaryOp::Xor),
+                    _,
+                    x,
+                    y,
+                ),
+            ) => {
+                let x = Sym::unary(U::Trunc, ty, (**x).clone());
+                let y = Sym::unary(U::Trunc, ty, (**y).clone());
+                return Sym::binary(*inner, x, y);
+            }
+            _ => {}
+        }
+        Sym::Unary(op, ty, Rc::new(a))
+    }
+
+    /// Returns a binary operation, simplified.
+    pub fn binary(op: BinaryOp, a: Sym, b: Sym) -> Sym {
+        use BinaryOp as B;
+        let ty = a.ty();
+        let (bits, ones) = (ty.bits(), ty.mask());
+        let result = if op.is_comparison() { Type::I1 } else { ty };
+        if let (Some(x), Some(y)) = (a.as_const(), b.as_const()) {
+            return Sym::constant(result, op.eval(ty, x, y));
+        }
+
+        // Constants are on the right, and the operands of commutative operations in order
+        let swap = a.as_const().is_some() || (b.as_const().is_none() && b < a);
+        let (op, a, b) = match op {
+            B::Add | B::Mul | B::MulHs | B::MulHu | B::And | B::Or | B::Xor | B::Eq | B::Ne
+                if swap =>
+            {
+                (op, b, a)
+            }
+            B::Slt if a.as_const().is_some() => (B::Sgt, b, a),
+            B::Sgt if a.as_const().is_some() => (B::Slt, b, a),
+            B::Ult if a.as_const().is_some() => (B::Ugt, b, a),
+            B::Ugt if a.as_const().is_some() => (B::Ult, b, a),
+            _ => (op, a, b),
+        };
+        let c = b.as_const();
+        match op {
+            B::Add => match (a.offset(), c) {
+                (_, Some(0)) => return a,
+                ((Some(x), offset), Some(c)) if offset != 0 => {
+                    return Sym::binary(
+                        B::Add,
+                        x.clone(),
+                        Sym::constant(ty, offset.wrapping_add(c)),
+                    );
+                }
+                // Constant offsets are outermost
+                ((Some(x), offset), None) if offset != 0 => {
+                    let sum = Sym::binary(B::Add, x.clone(), b);
+                    return Sym::binary(B::Add, sum, Sym::constant(ty, offset));
+                }
+                (_, None) => {
+                    if let (Some(y), offset @ 1..) = b.offset() {
+                        let sum = Sym::binary(B::Add, a, y.clone());
+                        return Sym::binary(B::Add, sum, Sym::constant(ty, offset));
+                    }
+                }
+                _ => {}
+            },
+            B::Sub => match c {
+                Some(c) => return Sym::binary(B::Add, a, Sym::constant(ty, c.wrapping_neg())),
+                None if a == b => return Sym::constant(ty, 0),
+                None => {}
+            },
+            B::Mul => match c {
+                Some(1) => return a,
+                Some(0) => return Sym::constant(ty, 0),
+                Some(c) => {
+                    if let Sym::Binary(B::Mul, _, x, y) = &a {
+                        if let Some(d) = y.as_const() {
+                            return Sym::binary(
+                                B::Mul,
+                                (**x).clone(),
+                                Sym::constant(ty, c.wrapping_mul(d)),
+                            );
+                        }
+                    }
+                }
+                None => {}
+            },
+            B::Xor => match (c, &a) {
+                (Some(0), _) => return a,
+                (None, _) if a == b => return Sym::constant(ty, 0),
+                (Some(1), Sym::Binary(inner @ (B::Eq | B::Ne), _, x, y)) if ty == Type::I1 => {
+                    let op = if *inner == B::Eq { B::Ne } else { B::Eq };
+                    return Sym::binary(op, (**x).clone(), (**y).clone());
+                }
+                (Some(c), Sym::Binary(B::Xor, _, x, y)) => {
+                    if let Some(d) = y.as_const() {
+                        return Sym::binary(B::Xor, (**x).clone(), Sym::constant(ty, c ^ d));
+                    }
+                }
+                _ => {}
+            },
+            B::Or => return Fields::new(&a).or(Fields::new(&b)).build(),
+            B::And => match c {
+                Some(mask) => return Fields::new(&a).and(mask).build(),
+                None if a == b => return a,
+                None => {}
+            },
+            B::Shl | B::LShr => match c {
+                Some(n) if n >= bits as u64 => return Sym::constant(ty, 0),
+                Some(n) if op == B::Shl => {
+                    return Fields::new(&a).rotate(n as u32).and(ones << n).build();
+                }
+                Some(n) => {
+                    return Fields::new(&a).rotate(bits - n as u32).and(ones >> n).build();
+                }
+                None => {}
+            },
+            B::Rotl => {
+                if let Some(n) = c {
+                    return Fields::new(&a).rotate((n % bits as u64) as u32).build();
+                }
+            }
+            B::AShr | B::DivS | B::DivU => {
+                if c == Some(if op == B::AShr { 0 } else { 1 }) {
+                    return a;
+                }
+            }
+            B::Eq | B::Ne => {
+                if a == b {
+                    return Sym::constant(Type::I1, (op == B::Eq) as u64);
+                }
+                if let (Some(c), (Some(x), offset @ 1..)) = (c, a.offset()) {
+                    return Sym::binary(op, x.clone(), Sym::constant(ty, c.wrapping_sub(offset)));
+                }
+            }
+            B::Slt | B::Sgt | B::Ult | B::Ugt => {
+                if a == b {
+                    return Sym::constant(Type::I1, 0);
+                }
+            }
+            B::MulHs | B::MulHu => {}
+        }
+        Sym::Binary(op, result, Rc::new(a), Rc::new(b))
+    }
+
+    /// Evaluates the value, with the registers of `cpu` and the memory `mem` before
+    /// execution.
+    pub fn eval(&self, cpu: &Cpu, mem: &mut dyn Memory) -> Result<u64, MemoryFault> {
+        Ok(match self {
+            Sym::Const(_, value) => *value,
+            Sym::Reg(reg) => initial(cpu, *reg),
+            Sym::Load(ty, memory, addr) => {
+                let addr = addr.eval(cpu, mem)?;
+                let mut value = 0;
+                for i in 0..ty.bits() as u64 / 8 {
+                    let byte = memory.read_u8(addr.wrapping_add(i) & 0xFFFF_FFFF, cpu, mem)?;
+                    value = (value << 8) | byte as u64;
+                }
+                value
+            }
+            Sym::Unary(op, ty, a) => op.eval(*ty, a.ty(), a.eval(cpu, mem)?),
+            Sym::Binary(op, _, a, b) => op.eval(a.ty(), a.eval(cpu, mem)?, b.eval(cpu, mem)?),
+        })
+    }
+}
+
+/// Returns the value of a register of `cpu`.
+fn initial(cpu: &Cpu, reg: Reg) -> u64 {
+    let value = match reg {
+        Reg::Gpr(reg) => cpu.gpr[reg as usize],
+        Reg::Fpr(reg) => cpu.fpr[reg as usize].to_bits(),
+        Reg::Cr(bit) => cpu.cr_bit(CRBit(bit)) as u64,
+        Reg::So => (cpu.xer & Cpu::XER_SO != 0) as u64,
+        Reg::Ov => (cpu.xer & Cpu::XER_OV != 0) as u64,
+        Reg::Ca => cpu.ca() as u64,
+        Reg::Lr => cpu.lr,
+        Reg::Ctr => cpu.ctr,
+        Reg::Spr(spr) => cpu.read_spr(spr),
+    };
+    value & reg.ty().mask()
+}
+
+impl Display for Sym {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Sym::Const(_, value) => write!(f, "{value:#x}"),
+            Sym::Reg(reg) => write!(f, "{reg}"),
+            Sym::Load(ty, _, addr) => write!(f, "load.{ty}({addr})"),
+            Sym::Unary(
+                op @ (UnaryOp::Zext | UnaryOp::Sext | UnaryOp::Trunc | UnaryOp::Bitcast),
+                ty,
+                a,
+            ) => write!(f, "{op}.{ty}({a})"),
+            Sym::Unary(op, _, a) => write!(f, "{op}({a})"),
+            Sym::Binary(op, _, a, b) => write!(f, "{op}({a}, {b})"),
+        }
+    }
+}
+
+/// A value as the OR of rotated and masked terms and a constant, the normal form of
+/// logical, rotate and shift operations with constant operands.
+struct Fields {
+    ty: Type,
+    /// The mask of each term, by its value and rotation.
+    terms: BTreeMap<(Sym, u32), u64>,
+    constant: u64,
+}
+
+impl Fields {
+    fn new(sym: &Sym) -> Fields {
+        let ty = sym.ty();
+        if let Sym::Const(_, constant) = *sym {
+            return Fields { ty, terms: BTreeMap::new(), constant };
+        }
+        if let Sym::Binary(op, _, a, b) = sym {
+            match (op, b.as_const()) {
+                (BinaryOp::Or, _) => return Fields::new(a).or(Fields::new(b)),
+                (BinaryOp::And, Some(mask)) => return Fields::new(a).and(mask),
+                (BinaryOp::Rotl, Some(n)) => return Fields::new(a).rotate(n as u32),
+                _ => {}
+            }
+        }
+        let terms = BTreeMap::from([((sym.clone(), 0), ty.mask())]);
+        Fields { ty, terms, constant: 0 }
+    }
+
+    fn or(mut self, other: Fields) -> Fields {
+        for (term, mask) in other.terms {
+            *self.terms.entry(term).or_default() |= mask;
+        }
+        self.constant |= other.constant;
+        self
+    }
+
+    fn and(mut self, mask: u64) -> Fields {
+        for term in self.terms.values_mut() {
+            *term &= mask;
+        }
+        self.constant &= mask;
+        self
+    }
+
+    fn rotate(self, n: u32) -> Fields {
+        let ty = self.ty;
+        let terms = self
+            .terms
+            .into_iter()
+            .map(|((sym, rot), mask)| ((sym, (rot + n) % ty.bits()), rotl(ty, mask, n)))
+            .collect();
+        Fields { ty, terms, constant: rotl(ty, self.constant, n) }
+    }
+
+    fn build(self) -> Sym {
+        let ty = self.ty;
+        let constant = Sym::constant(ty, self.constant);
+        let mut result = None;
+        for ((sym, rot), mask) in self.terms {
+            // Bits set in the constant are set in the result
+            let mask = mask & !self.constant & ty.mask();
+            if mask == 0 {
+                continue;
+            }
+            let mut term = sym;
+            if rot != 0 {
+                term = Sym::Binary(
+                    BinaryOp::Rotl,
+                    ty,
+                    Rc::new(term),
+                    Rc::new(Sym::constant(ty, rot as u64)),
+                );
+            }
+            if mask != ty.mask() {
+                term =
+                    Sym::Binary(BinaryOp::And, ty, Rc::new(term), Rc::new(Sym::constant(ty, mask)));
+            }
+            result = Some(match result {
+                Some(result) => Sym::Binary(BinaryOp::Or, ty, Rc::new(result), Rc::new(term)),
+                None => term,
+            });
+        }
+        match result {
+            Some(result) if self.constant != 0 => {
+                Sym::Binary(BinaryOp::Or, ty, Rc::new(result), Rc::new(constant))
+            }
+            Some(result) => result,
+            None => constant,
+        }
+    }
+}
+
+/// Returns `b - a` if the addresses differ by a constant.
+fn distance(a: &Sym, b: &Sym) -> Option<u64> {
+    let ((a, x), (b, y)) = (a.offset(), b.offset());
+    (a == b).then(|| y.wrapping_sub(x) & 0xFFFF_FFFF)
+}
+
+/// Whether accesses of `a_size` bytes at `a` and `b_size` bytes at `b` do not overlap.
+fn disjoint(a: &Sym, a_size: u64, b: &Sym, b_size: u64) -> bool {
+    distance(a, b).is_some_and(|d| d >= a_size && d <= (1 << 32) - b_size)
+}
+
+impl Mem {
+    /// Returns a load from the memory, forwarding the value of a store containing it.
+    fn load(self: &Rc<Mem>, ty: Type, addr: Sym) -> Sym {
+        let size = ty.bits() as u64 / 8;
+        let mut memory = self;
+        while let Mem::Store { prev, ty: store_ty, addr: store, value } = &**memory {
+            let store_size = store_ty.bits() as u64 / 8;
+            match distance(store, &addr) {
+                Some(offset) if offset + size <= store_size => {
+                    let shift = 8 * (store_size - size - offset);
+                    let value =
+                        Sym::binary(BinaryOp::LShr, value.clone(), Sym::constant(*store_ty, shift));
+                    return match *store_ty == ty {
+                        true => value,
+                        false => Sym::unary(UnaryOp::Trunc, ty, value),
+                    };
+                }
+                _ if disjoint(store, store_size, &addr, size) => memory = prev,
+                _ => break,
+            }
+        }
+        Sym::Load(ty, memory.clone(), Rc::new(addr))
+    }
+
+    /// Evaluates the byte at an address.
+    fn read_u8(&self, addr: u64, cpu: &Cpu, mem: &mut dyn Memory) -> Result<u8, MemoryFault> {
+        let mut memory = self;
+        while let Mem::Store { prev, ty, addr: store, value } = memory {
+            let size = ty.bits() as u64 / 8;
+            let offset = addr.wrapping_sub(store.eval(cpu, mem)?) & 0xFFFF_FFFF;
+            if offset < size {
+                return Ok((value.eval(cpu, mem)? >> (8 * (size - 1 - offset))) as u8);
+            }
+            memory = prev;
+        }
+        mem.read_u8(addr)
+    }
+}
+
+/// Where a branch leaving a sequence goes.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub enum ExitKind {
+    /// A branch to an address, which is constant if it was resolved.
+    Jump(Sym),
+    /// A call of the function at an address, which ends execution.
+    Call(Sym),
+    /// A return to the caller.
+    Return,
+    /// A trap exception.
+    Trap,
+}
+
+/// A branch leaving a sequence.
+#[derive(Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Exit {
+    /// The address of the instruction.
+    pub addr: u32,
+    /// The condition of the branch, as an `i1`, given that no earlier exit was taken.
+    pub cond: Sym,
+    pub kind: ExitKind,
+    /// The values of the registers written before the branch.
+    pub regs: BTreeMap<Reg, Sym>,
+    /// The memory before the branch, as the stores made.
+    pub mem: Rc<Mem>,
+}
+
+impl Exit {
+    /// Returns the value of a register when the branch is taken.
+    pub fn reg(&self, reg: Reg) -> Sym {
+        self.regs.get(&reg).cloned().unwrap_or(Sym::Reg(reg))
+    }
+
+    /// Returns the stores made when the branch is taken. (See [State::stores])
+    pub fn stores(&self) -> Vec<(Type, Sym, Sym)> {
+        stores(&self.mem)
+    }
+}
+
+/// Returns the stores made on the memory, oldest first, except those overwritten by later
+/// stores.
+///
+/// Stores are sorted if they are all known not to overlap.
+fn stores(mut memory: &Mem) -> Vec<(Type, Sym, Sym)> {
+    let mut stores: Vec<(Type, Sym, Sym)> = Vec::new();
+    while let Mem::Store { prev, ty, addr, value } = memory {
+        if !stores.iter().any(|(later, later_addr, _)| later == ty && later_addr == addr) {
+            stores.push((*ty, addr.clone(), value.clone()));
+        }
+        memory = prev;
+    }
+    stores.reverse();
+    let size = |ty: Type| ty.bits() as u64 / 8;
+    let sorted = stores.iter().enumerate().all(|(i, (a_ty, a, _))| {
+        stores[i + 1..].iter().all(|(b_ty, b, _)| disjoint(a, size(*a_ty), b, size(*b_ty)))
+    });
+    if sorted {
+        stores.sort();
+    }
+    stores
+}
+
+/// The symbolic state after executing a sequence. (See [execute])
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct State {
+    /// The values of the registers written.
+    pub regs: BTreeMap<Reg, Sym>,
+    /// The memory, as the stores made.
+    pub mem: Rc<Mem>,
+    /// The branches leaving the sequence, in order.
+    pub exits: Vec<Exit>,
+    /// The address execution continues at, unless it left the sequence unconditionally.
+    pub next: Option<u32>,
+}
+
+/// Executes a sequence of instructions starting at `address` symbolically.
+///
+/// The instructions are executed in order, assuming that conditional branches are not
+/// taken; they are recorded as [exits](State::exits) with the conditions under which they
+/// are taken. Execution stops at calls, unconditional branches and the end of the sequence.
+///
+/// Returns [RecompError::Unsupported] for instructions that are not lifted to the IR,
+/// such as floating-point arithmetic.
+pub fn execute(address: u32, code: &[Ins]) -> Result<State, RecompError> {
+    let mut state = State { next: Some(address), ..State::default() };
+    for &ins in code {
+        match state.next {
+            Some(addr) => state.step(addr, ins)?,
+            None => break,
+        }
+    }
+    Ok(state)
+}
+
+impl State {
+    /// Returns the value of a register.
+    pub fn reg(&self, reg: Reg) -> Sym {
+        self.regs.get(&reg).cloned().unwrap_or(Sym::Reg(reg))
+    }
+
+    /// Returns the stores made, oldest first, except those overwritten by later stores.
+    ///
+    /// Stores are sorted if they are all known not to overlap.
+    pub fn stores(&self) -> Vec<(Type, Sym, Sym)> {
+        stores(&self.mem)
+    }
+
+    /// Whether the states are proven equivalent: the same branches leave the sequences, and
+    /// the registers `regs` have the same values and the same stores are made at each of
+    /// them and at the end.
+    pub fn equivalent(&self, other: &State, regs: &[Reg]) -> bool {
+        let same_exit = |a: &Exit, b: &Exit| {
+            a.cond == b.cond
+                && a.kind == b.kind
+                && regs.iter().all(|&reg| a.reg(reg) == b.reg(reg))
+                && a.stores() == b.stores()
+        };
+        self.exits.len() == other.exits.len()
+            && self.exits.iter().zip(&other.exits).all(|(a, b)| same_exit(a, b))
+            && regs.iter().all(|&reg| self.reg(reg) == other.reg(reg))
+            && self.stores() == other.stores()
+    }
+
+    /// Records a branch leaving the sequence, with the current registers and memory.
+    fn exit(&mut self, addr: u32, cond: Sym, kind: ExitKind) {
+        let (regs, mem) = (self.regs.clone(), self.mem.clone());
+        self.exits.push(Exit { addr, cond, kind, regs, mem });
+    }
+
+    /// Executes an instruction.
+    fn step(&mut self, addr: u32, ins: Ins) -> Result<(), RecompError> {
+        let next = addr.wrapping_add(4);
+        let function = lift(addr, &[ins]);
+        let block = &function.blocks[&addr];
+        let mut values: HashMap<Value, Sym> = HashMap::new();
+        let always = Sym::constant(Type::I1, 1);
+        for stmt in &block.stmts {
+            match *stmt {
+                Stmt::Let { value, ty, ref expr } => {
+                    let sym = match *expr {
+                        Expr::Const(value) => Sym::constant(ty, value),
+                        Expr::Get(reg) => self.reg(reg),
+                        Expr::Unary(op, a) => Sym::unary(op, ty, values[&a].clone()),
+                        Expr::Binary(op, a, b) => {
+                            Sym::binary(op, values[&a].clone(), values[&b].clone())
+                        }
+                        Expr::Load(addr) => self.mem.load(ty, values[&addr].clone()),
+                    };
+                    values.insert(value, sym);
+                }
+                Stmt::Set(reg, value) => {
+                    self.regs.insert(reg, values[&value].clone());
+                }
+                Stmt::Store { ty, addr, value } => {
+                    let (addr, value) = (values[&addr].clone(), values[&value].clone());
+                    let prev = core::mem::take(&mut self.mem);
+                    self.mem = Rc::new(Mem::Store { prev, ty, addr, value });
+                }
+                Stmt::Call { cond, callee } => {
+                    let target = match callee {
+                        Callee::Direct(target) => Sym::constant(Type::I32, target as u64),
+                        Callee::Indirect(value) => values[&value].clone(),
+                    };
+                    let cond = cond.map_or(always, |cond| values[&cond].clone());
+                    self.exit(addr, cond, ExitKind::Call(target));
+                    self.next = None;
+                    return Ok(());
+                }
+                Stmt::Trap(cond) => {
+                    let cond = values[&cond].clone();
+                    self.exit(addr, cond, ExitKind::Trap);
+                }
+                Stmt::Intrinsic { addr, .. } => return Err(RecompError::Unsupported { addr }),
+            }
+        }
+        let kind = |target: &Target| match target {
+            Target::Address(target) => ExitKind::Jump(Sym::constant(Type::I32, *target as u64)),
+            Target::Indirect { value, .. } => ExitKind::Jump(values[value].clone()),
+            Target::Return => ExitKind::Return,
+        };
+        self.next = match &block.terminator {
+            Terminator::Jump(Target::Address(target)) if *target == next => Some(next),
+            Terminator::Jump(target) => {
+                self.exit(addr, always, kind(target));
+                None
+            }
+            Terminator::Branch { cond, target, .. } => {
+                if *target != Target::Address(next) {
+                    let cond = values[cond].clone();
+                    self.exit(addr, cond, kind(target));
+                }
+                Some(next)
+            }
+            Terminator::Trap => {
+                self.exit(addr, always, ExitKind::Trap);
+                None
+            }
+        };
+        Ok(())
+    }
+}
@@ -0,0 +1,41 @@
+//! Fixtures for comparing lifted instructions with the interpreter.
+#![allow(dead_code)]
+
+use powerpc::{CRBit, Extensions, Ins};
+use powerpc_interp::{Cpu, Ram};
+use powerpc_recomp::ir::Reg;
+
+/// The base address of [ram].
+pub const BASE: u64 = 0x80000000;
+/// The address of the code being tested.
+pub const ADDR: u32 = 0x80000100;
+
+/// Register values covering the edge cases of 32-bit arithmetic.
+pub const VALUES: [u64; 10] =
+    [0, 1, 2, 5, 31, 0x7FFF_FFFF, 0x8000_0000, 0x8000_0001, 0xFFFF_FFFB, 0xFFFF_FFFF];
+
+/// Decodes instructions.
+pub fn code(code: &[u32]) -> Vec<Ins> {
+    code.iter().map(|&code| Ins::new(code, Extensions::gekko_broadway())).collect()
+}
+
+/// Returns memory at [BASE], filled with a pattern.
+pub fn ram() -> Ram<Vec<u8>> {
+    let data = (0..0x200u32).map(|i| (i * 0x9D + 0x31) as u8).collect();
+    Ram { base: BASE, data }
+}
+
+/// Returns the value of a register, as represented in the IR.
+pub fn get(cpu: &Cpu, reg: Reg) -> u64 {
+    match reg {
+        Reg::Gpr(reg) => cpu.gpr[reg as usize] & 0xFFFF_FFFF,
+        Reg::Fpr(reg) => cpu.fpr[reg as usize].to_bits(),
+        Reg::Cr(bit) => cpu.cr_bit(CRBit(bit)) as u64,
+        Reg::So => (cpu.xer & Cpu::XER_SO != 0) as u64,
+        Reg::Ov => (cpu.xer & Cpu::XER_OV != 0) as u64,
+        Reg::Ca => cpu.ca() as u64,
+        Reg::Lr => cpu.lr,
+        Reg::Ctr => cpu.ctr,
+        Reg::Spr(spr) => cpu.read_spr(spr),
+    }
+}
@@ -0,0 +1,426 @@
+; Code generated by powerpc-recomp. DO NOT EDIT.
+%Context = type { [32 x i32], [32 x double], [32 x i8], i8, i8, i8, i32, i32, [1024 x i32] }
+
+declare i16 @llvm.bswap.i16(i16)
+declare i32 @llvm.bswap.i32(i32)
+declare i32 @llvm.fshl.i32(i32, i32, i32)
+declare i64 @llvm.bswap.i64(i64)
+declare void @fn_80000140(ptr, ptr)
+declare void @ppc_interpret(ptr, ptr, i32, i32)
+declare void @ppc_trap(ptr) noreturn
+
+define void @fn_80000000(ptr %ctx, ptr %mem) {
+entry:
+  %r3 = alloca i32
+  %ctx.r3 = getelementptr %Context, ptr %ctx, i32 0, i32 0, i32 3
+  %r4 = alloca i32
+  %ctx.r4 = getelementptr %Context, ptr %ctx, i32 0, i32 0, i32 4
+  %r5 = alloca i32
+  %ctx.r5 = getelementptr %Context, ptr %ctx, i32 0, i32 0, i32 5
+  %r6 = alloca i32
+  %ctx.r6 = getelementptr %Context, ptr %ctx, i32 0, i32 0, i32 6
+  %r7 = alloca i32
+  %ctx.r7 = getelementptr %Context, ptr %ctx, i32 0, i32 0, i32 7
+  %r8 = alloca i32
+  %ctx.r8 = getelementptr %Context, ptr %ctx, i32 0, i32 0, i32 8
+  %r9 = alloca i32
+  %ctx.r9 = getelementptr %Context, ptr %ctx, i32 0, i32 0, i32 9
+  %r10 = alloca i32
+  %ctx.r10 = getelementptr %Context, ptr %ctx, i32 0, i32 0, i32 10
+  %f1 = alloca double
+  %ctx.f1 = getelementptr %Context, ptr %ctx, i32 0, i32 1, i32 1
+  %cr0.lt = alloca i1
+  %ctx.cr0.lt = getelementptr %Context, ptr %ctx, i32 0, i32 2, i32 0
+  %cr0.gt = alloca i1
+  %ctx.cr0.gt = getelementptr %Context, ptr %ctx, i32 0, i32 2, i32 1
+  %cr0.eq = alloca i1
+  %ctx.cr0.eq = getelementptr %Context, ptr %ctx, i32 0, i32 2, i32 2
+  %cr0.so = alloca i1
+  %ctx.cr0.so = getelementptr %Context, ptr %ctx, i32 0, i32 2, i32 3
+  %xer.so = alloca i1
+  %ctx.xer.so = getelementptr %Context, ptr %ctx, i32 0, i32 3
+  %xer.ca = alloca i1
+  %ctx.xer.ca = getelementptr %Context, ptr %ctx, i32 0, i32 5
+  %lr = alloca i32
+  %ctx.lr = getelementptr %Context, ptr %ctx, i32 0, i32 6
+  %ctr = alloca i32
+  %ctx.ctr = getelementptr %Context, ptr %ctx, i32 0, i32 7
+  %t0 = load i32, ptr %ctx.r3
+  store i32 %t0, ptr %r3
+  %t1 = load i32, ptr %ctx.r4
+  store i32 %t1, ptr %r4
+  %t2 = load i32, ptr %ctx.r5
+  store i32 %t2, ptr %r5
+  %t3 = load i32, ptr %ctx.r6
+  store i32 %t3, ptr %r6
+  %t4 = load i32, ptr %ctx.r7
+  store i32 %t4, ptr %r7
+  %t5 = load i32, ptr %ctx.r8
+  store i32 %t5, ptr %r8
+  %t6 = load i32, ptr %ctx.r9
+  store i32 %t6, ptr %r9
+  %t7 = load i32, ptr %ctx.r10
+  store i32 %t7, ptr %r10
+  %t8 = load double, ptr %ctx.f1
+  store double %t8, ptr %f1
+  %t9 = load i8, ptr %ctx.cr0.lt
+  %t10 = trunc i8 %t9 to i1
+  store i1 %t10, ptr %cr0.lt
+  %t11 = load i8, ptr %ctx.cr0.gt
+  %t12 = trunc i8 %t11 to i1
+  store i1 %t12, ptr %cr0.gt
+  %t13 = load i8, ptr %ctx.cr0.eq
+  %t14 = trunc i8 %t13 to i1
+  store i1 %t14, ptr %cr0.eq
+  %t15 = load i8, ptr %ctx.cr0.so
+  %t16 = trunc i8 %t15 to i1
+  store i1 %t16, ptr %cr0.so
+  %t17 = load i8, ptr %ctx.xer.so
+  %t18 = trunc i8 %t17 to i1
+  store i1 %t18, ptr %xer.so
+  %t19 = load i8, ptr %ctx.xer.ca
+  %t20 = trunc i8 %t19 to i1
+  store i1 %t20, ptr %xer.ca
+  %t21 = load i32, ptr %ctx.lr
+  store i32 %t21, ptr %lr
+  %t22 = load i32, ptr %ctx.ctr
+  store i32 %t22, ptr %ctr
+  br label %bb_80000000
+
+bb_80000000:
+  %v0 = load i32, ptr %r3
+  %v2 = icmp slt i32 %v0, 0
+  %v3 = icmp sgt i32 %v0, 0
+  %v4 = icmp eq i32 %v0, 0
+  %v5 = load i1, ptr %xer.so
+  store i1 %v2, ptr %cr0.lt
+  store i1 %v3, ptr %cr0.gt
+  store i1 %v4, ptr %cr0.eq
+  store i1 %v5, ptr %cr0.so
+  %v6 = load i1, ptr %cr0.eq
+  br i1 %v6, label %bb_80000030, label %bb_80000008
+
+bb_80000008:
+  %v7 = load i32, ptr %r3
+  store i32 %v7, ptr %ctr
+  br label %bb_8000000c
+
+bb_8000000c:
+  %v8 = load i32, ptr %r4
+  %v10 = add i32 %v8, 4
+  %t23 = zext i32 %v10 to i64
+  %t24 = getelementptr i8, ptr %mem, i64 %t23
+  %t25 = load i32, ptr %t24, align 1
+  %v11 = call i32 @llvm.bswap.i32(i32 %t25)
+  store i32 %v11, ptr %r5
+  store i32 %v10, ptr %r4
+  %v12 = load i32, ptr %r6
+  %v13 = load i32, ptr %r5
+  %v14 = trunc i32 %v13 to i16
+  %v15 = call i16 @llvm.bswap.i16(i16 %v14)
+  %t26 = zext i32 %v12 to i64
+  %t27 = getelementptr i8, ptr %mem, i64 %t26
+  %t28 = call i16 @llvm.bswap.i16(i16 %v15)
+  store i16 %t28, ptr %t27, align 1
+  %v16 = load i32, ptr %r5
+  %v17 = load i32, ptr %r3
+  %t29 = sext i32 %v16 to i64
+  %t30 = sext i32 %v17 to i64
+  %t31 = mul i64 %t29, %t30
+  %t32 = lshr i64 %t31, 32
+  %v18 = trunc i64 %t32 to i32
+  store i32 %v18, ptr %r7
+  %v19 = load i32, ptr %r5
+  %v20 = load i32, ptr %r3
+  %t33 = icmp eq i32 %v20, 0
+  %t34 = select i1 %t33, i32 1, i32 %v20
+  %t35 = udiv i32 %v19, %t34
+  %v21 = select i1 %t33, i32 0, i32 %t35
+  store i32 %v21, ptr %r8
+  %v22 = load i32, ptr %r5
+  %v23 = load i32, ptr %r8
+  %v25 = and i32 %v23, 63
+  %t36 = icmp ult i32 %v25, 32
+  %t37 = shl i32 %v22, %v25
+  %v26 = select i1 %t36, i32 %t37, i32 0
+  store i32 %v26, ptr %r9
+  %v27 = load i32, ptr %r9
+  %v28 = load i32, ptr %r7
+  %v30 = and i32 %v28, 63
+  %t38 = icmp ult i32 %v30, 32
+  %t39 = select i1 %t38, i32 %v30, i32 31
+  %v31 = ashr i32 %v27, %t39
+  %t40 = icmp ult i32 %v30, 32
+  %t41 = shl i32 %v31, %v30
+  %v32 = select i1 %t40, i32 %t41, i32 0
+  %v33 = icmp ne i32 %v32, %v27
+  %v35 = icmp slt i32 %v27, 0
+  %v36 = and i1 %v35, %v33
+  store i1 %v36, ptr %xer.ca
+  store i32 %v31, ptr %r9
+  %v37 = load i32, ptr %r9
+  %v39 = call i32 @llvm.fshl.i32(i32 %v37, i32 %v37, i32 3)
+  %v41 = and i32 %v39, -16
+  store i32 %v41, ptr %r10
+  %v43 = icmp slt i32 %v41, 0
+  %v44 = icmp sgt i32 %v41, 0
+  %v45 = icmp eq i32 %v41, 0
+  %v46 = load i1, ptr %xer.so
+  store i1 %v43, ptr %cr0.lt
+  store i1 %v44, ptr %cr0.gt
+  store i1 %v45, ptr %cr0.eq
+  store i1 %v46, ptr %cr0.so
+  %v47 = load i32, ptr %r10
+  %v49 = icmp eq i32 %v47, -1
+  br i1 %v49, label %trap_42, label %next_43
+
+trap_42:
+  %t44 = load i32, ptr %r4
+  store i32 %t44, ptr %ctx.r4
+  %t45 = load i32, ptr %r5
+  store i32 %t45, ptr %ctx.r5
+  %t46 = load i32, ptr %r7
+  store i32 %t46, ptr %ctx.r7
+  %t47 = load i32, ptr %r8
+  store i32 %t47, ptr %ctx.r8
+  %t48 = load i32, ptr %r9
+  store i32 %t48, ptr %ctx.r9
+  %t49 = load i32, ptr %r10
+  store i32 %t49, ptr %ctx.r10
+  %t50 = load double, ptr %f1
+  store double %t50, ptr %ctx.f1
+  %t51 = load i1, ptr %cr0.lt
+  %t52 = zext i1 %t51 to i8
+  store i8 %t52, ptr %ctx.cr0.lt
+  %t53 = load i1, ptr %cr0.gt
+  %t54 = zext i1 %t53 to i8
+  store i8 %t54, ptr %ctx.cr0.gt
+  %t55 = load i1, ptr %cr0.eq
+  %t56 = zext i1 %t55 to i8
+  store i8 %t56, ptr %ctx.cr0.eq
+  %t57 = load i1, ptr %cr0.so
+  %t58 = zext i1 %t57 to i8
+  store i8 %t58, ptr %ctx.cr0.so
+  %t59 = load i1, ptr %xer.ca
+  %t60 = zext i1 %t59 to i8
+  store i8 %t60, ptr %ctx.xer.ca
+  %t61 = load i32, ptr %lr
+  store i32 %t61, ptr %ctx.lr
+  %t62 = load i32, ptr %ctr
+  store i32 %t62, ptr %ctx.ctr
+  call void @ppc_trap(ptr %ctx)
+  unreachable
+
+next_43:
+  %v50 = load i32, ptr %ctr
+  %v52 = sub i32 %v50, 1
+  store i32 %v52, ptr %ctr
+  %v54 = icmp ne i32 %v52, 0
+  br i1 %v54, label %bb_8000000c, label %bb_80000030
+
+bb_80000030:
+  %v55 = load i32, ptr %r4
+  %v57 = add i32 %v55, 8
+  %t63 = zext i32 %v57 to i64
+  %t64 = getelementptr i8, ptr %mem, i64 %t63
+  %t65 = load i64, ptr %t64, align 1
+  %v58 = call i64 @llvm.bswap.i64(i64 %t65)
+  %v59 = bitcast i64 %v58 to double
+  store double %v59, ptr %f1
+  %v60 = load double, ptr %f1
+  %v61 = bitcast double %v60 to i64
+  %v63 = xor i64 %v61, -9223372036854775808
+  %v64 = bitcast i64 %v63 to double
+  store double %v64, ptr %f1
+  %t66 = load i32, ptr %r4
+  store i32 %t66, ptr %ctx.r4
+  %t67 = load i32, ptr %r5
+  store i32 %t67, ptr %ctx.r5
+  %t68 = load i32, ptr %r7
+  store i32 %t68, ptr %ctx.r7
+  %t69 = load i32, ptr %r8
+  store i32 %t69, ptr %ctx.r8
+  %t70 = load i32, ptr %r9
+  store i32 %t70, ptr %ctx.r9
+  %t71 = load i32, ptr %r10
+  store i32 %t71, ptr %ctx.r10
+  %t72 = load double, ptr %f1
+  store double %t72, ptr %ctx.f1
+  %t73 = load i1, ptr %cr0.lt
+  %t74 = zext i1 %t73 to i8
+  store i8 %t74, ptr %ctx.cr0.lt
+  %t75 = load i1, ptr %cr0.gt
+  %t76 = zext i1 %t75 to i8
+  store i8 %t76, ptr %ctx.cr0.gt
+  %t77 = load i1, ptr %cr0.eq
+  %t78 = zext i1 %t77 to i8
+  store i8 %t78, ptr %ctx.cr0.eq
+  %t79 = load i1, ptr %cr0.so
+  %t80 = zext i1 %t79 to i8
+  store i8 %t80, ptr %ctx.cr0.so
+  %t81 = load i1, ptr %xer.ca
+  %t82 = zext i1 %t81 to i8
+  store i8 %t82, ptr %ctx.xer.ca
+  %t83 = load i32, ptr %lr
+  store i32 %t83, ptr %ctx.lr
+  %t84 = load i32, ptr %ctr
+  store i32 %t84, ptr %ctx.ctr
+  call void @ppc_interpret(ptr %ctx, ptr %mem, i32 -2147483592, i32 -64942038)
+  %t85 = load i32, ptr %ctx.r3
+  store i32 %t85, ptr %r3
+  %t86 = load i32, ptr %ctx.r4
+  store i32 %t86, ptr %r4
+  %t87 = load i32, ptr %ctx.r5
+  store i32 %t87, ptr %r5
+  %t88 = load i32, ptr %ctx.r6
+  store i32 %t88, ptr %r6
+  %t89 = load i32, ptr %ctx.r7
+  store i32 %t89, ptr %r7
+  %t90 = load i32, ptr %ctx.r8
+  store i32 %t90, ptr %r8
+  %t91 = load i32, ptr %ctx.r9
+  store i32 %t91, ptr %r9
+  %t92 = load i32, ptr %ctx.r10
+  store i32 %t92, ptr %r10
+  %t93 = load double, ptr %ctx.f1
+  store double %t93, ptr %f1
+  %t94 = load i8, ptr %ctx.cr0.lt
+  %t95 = trunc i8 %t94 to i1
+  store i1 %t95, ptr %cr0.lt
+  %t96 = load i8, ptr %ctx.cr0.gt
+  %t97 = trunc i8 %t96 to i1
+  store i1 %t97, ptr %cr0.gt
+  %t98 = load i8, ptr %ctx.cr0.eq
+  %t99 = trunc i8 %t98 to i1
+  store i1 %t99, ptr %cr0.eq
+  %t100 = load i8, ptr %ctx.cr0.so
+  %t101 = trunc i8 %t100 to i1
+  store i1 %t101, ptr %cr0.so
+  %t102 = load i8, ptr %ctx.xer.so
+  %t103 = trunc i8 %t102 to i1
+  store i1 %t103, ptr %xer.so
+  %t104 = load i8, ptr %ctx.xer.ca
+  %t105 = trunc i8 %t104 to i1
+  store i1 %t105, ptr %xer.ca
+  %t106 = load i32, ptr %ctx.lr
+  store i32 %t106, ptr %lr
+  %t107 = load i32, ptr %ctx.ctr
+  store i32 %t107, ptr %ctr
+  %v65 = load i32, ptr %r4
+  %v67 = add i32 %v65, 16
+  %v68 = load double, ptr %f1
+  %v69 = bitcast double %v68 to i64
+  %t108 = zext i32 %v67 to i64
+  %t109 = getelementptr i8, ptr %mem, i64 %t108
+  %t110 = call i64 @llvm.bswap.i64(i64 %v69)
+  store i64 %t110, ptr %t109, align 1
+  store i32 -2147483580, ptr %lr
+  %t111 = load i32, ptr %r4
+  store i32 %t111, ptr %ctx.r4
+  %t112 = load i32, ptr %r5
+  store i32 %t112, ptr %ctx.r5
+  %t113 = load i32, ptr %r7
+  store i32 %t113, ptr %ctx.r7
+  %t114 = load i32, ptr %r8
+  store i32 %t114, ptr %ctx.r8
+  %t115 = load i32, ptr %r9
+  store i32 %t115, ptr %ctx.r9
+  %t116 = load i32, ptr %r10
+  store i32 %t116, ptr %ctx.r10
+  %t117 = load double, ptr %f1
+  store double %t117, ptr %ctx.f1
+  %t118 = load i1, ptr %cr0.lt
+  %t119 = zext i1 %t118 to i8
+  store i8 %t119, ptr %ctx.cr0.lt
+  %t120 = load i1, ptr %cr0.gt
+  %t121 = zext i1 %t120 to i8
+  store i8 %t121, ptr %ctx.cr0.gt
+  %t122 = load i1, ptr %cr0.eq
+  %t123 = zext i1 %t122 to i8
+  store i8 %t123, ptr %ctx.cr0.eq
+  %t124 = load i1, ptr %cr0.so
+  %t125 = zext i1 %t124 to i8
+  store i8 %t125, ptr %ctx.cr0.so
+  %t126 = load i1, ptr %xer.ca
+  %t127 = zext i1 %t126 to i8
+  store i8 %t127, ptr %ctx.xer.ca
+  %t128 = load i32, ptr %lr
+  store i32 %t128, ptr %ctx.lr
+  %t129 = load i32, ptr %ctr
+  store i32 %t129, ptr %ctx.ctr
+  call void @fn_80000140(ptr %ctx, ptr %mem)
+  %t130 = load i32, ptr %ctx.r3
+  store i32 %t130, ptr %r3
+  %t131 = load i32, ptr %ctx.r4
+  store i32 %t131, ptr %r4
+  %t132 = load i32, ptr %ctx.r5
+  store i32 %t132, ptr %r5
+  %t133 = load i32, ptr %ctx.r6
+  store i32 %t133, ptr %r6
+  %t134 = load i32, ptr %ctx.r7
+  store i32 %t134, ptr %r7
+  %t135 = load i32, ptr %ctx.r8
+  store i32 %t135, ptr %r8
+  %t136 = load i32, ptr %ctx.r9
+  store i32 %t136, ptr %r9
+  %t137 = load i32, ptr %ctx.r10
+  store i32 %t137, ptr %r10
+  %t138 = load double, ptr %ctx.f1
+  store double %t138, ptr %f1
+  %t139 = load i8, ptr %ctx.cr0.lt
+  %t140 = trunc i8 %t139 to i1
+  store i1 %t140, ptr %cr0.lt
+  %t141 = load i8, ptr %ctx.cr0.gt
+  %t142 = trunc i8 %t141 to i1
+  store i1 %t142, ptr %cr0.gt
+  %t143 = load i8, ptr %ctx.cr0.eq
+  %t144 = trunc i8 %t143 to i1
+  store i1 %t144, ptr %cr0.eq
+  %t145 = load i8, ptr %ctx.cr0.so
+  %t146 = trunc i8 %t145 to i1
+  store i1 %t146, ptr %cr0.so
+  %t147 = load i8, ptr %ctx.xer.so
+  %t148 = trunc i8 %t147 to i1
+  store i1 %t148, ptr %xer.so
+  %t149 = load i8, ptr %ctx.xer.ca
+  %t150 = trunc i8 %t149 to i1
+  store i1 %t150, ptr %xer.ca
+  %t151 = load i32, ptr %ctx.lr
+  store i32 %t151, ptr %lr
+  %t152 = load i32, ptr %ctx.ctr
+  store i32 %t152, ptr %ctr
+  %t153 = load i32, ptr %r4
+  store i32 %t153, ptr %ctx.r4
+  %t154 = load i32, ptr %r5
+  store i32 %t154, ptr %ctx.r5
+  %t155 = load i32, ptr %r7
+  store i32 %t155, ptr %ctx.r7
+  %t156 = load i32, ptr %r8
+  store i32 %t156, ptr %ctx.r8
+  %t157 = load i32, ptr %r9
+  store i32 %t157, ptr %ctx.r9
+  %t158 = load i32, ptr %r10
+  store i32 %t158, ptr %ctx.r10
+  %t159 = load double, ptr %f1
+  store double %t159, ptr %ctx.f1
+  %t160 = load i1, ptr %cr0.lt
+  %t161 = zext i1 %t160 to i8
+  store i8 %t161, ptr %ctx.cr0.lt
+  %t162 = load i1, ptr %cr0.gt
+  %t163 = zext i1 %t162 to i8
+  store i8 %t163, ptr %ctx.cr0.gt
+  %t164 = load i1, ptr %cr0.eq
+  %t165 = zext i1 %t164 to i8
+  store i8 %t165, ptr %ctx.cr0.eq
+  %t166 = load i1, ptr %cr0.so
+  %t167 = zext i1 %t166 to i8
+  store i8 %t167, ptr %ctx.cr0.so
+  %t168 = load i1, ptr %xer.ca
+  %t169 = zext i1 %t168 to i8
+  store i8 %t169, ptr %ctx.xer.ca
+  %t170 = load i32, ptr %lr
+  store i32 %t170, ptr %ctx.lr
+  %t171 = load i32, ptr %ctr
+  store i32 %t171, ptr %ctx.ctr
+  ret void
+}
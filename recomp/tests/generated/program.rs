@@ -0,0 +1,261 @@
+// Code generated by powerpc-recomp. DO NOT EDIT.
+#![allow(unused, clippy::all)]
+use powerpc::{CRBit, Ins, Opcode};
+use powerpc_interp::{Cpu, Exception, Memory};
+use powerpc_recomp::runtime::*;
+
+/// Recompiled functions, sorted by address.
+pub static FUNCTIONS: [(u64, RecompiledFn); 5] = [
+    (0x80000000, fn_80000000),
+    (0x80000040, fn_80000040),
+    (0x800000c0, fn_800000c0),
+    (0x80000140, fn_80000140),
+    (0x800001c0, fn_800001c0),
+];
+
+/// Calls the function at `addr`, interpreting it if it was not recompiled.
+pub fn dispatch(cpu: &mut Cpu, mem: &mut dyn Memory, addr: u64) -> Result<(), Exception> {
+    call_indirect(&FUNCTIONS, cpu, mem, addr)
+}
+
+pub fn fn_80000000(cpu: &mut Cpu, mem: &mut dyn Memory) -> Result<(), Exception> {
+    let mut block: u64 = 0x80000000;
+    loop {
+        match block {
+            0x80000000 => {
+                // 80000000: cmpwi r3, 0x0
+                let cr = compare(cpu, cpu.gpr[3] as i32, 0x0);
+                cpu.set_cr_field(0, cr);
+                // 80000004: li r4, 0x0
+                let value = 0x0;
+                set_gpr(cpu, 4, value);
+                // 80000008: ble 0x14
+                if !cpu.cr_bit(CRBit(1)) {
+                    block = 0x8000001c;
+                    continue;
+                }
+                // 8000000c: mtctr r3
+                cpu.ctr = trunc(cpu, cpu.gpr[3]);
+                block = 0x80000010;
+            }
+            0x80000010 => {
+                // 80000010: add r4, r4, r3
+                let value = cpu.gpr[4].wrapping_add(cpu.gpr[3]);
+                set_gpr(cpu, 4, value);
+                // 80000014: subi r3, r3, 0x1
+                let value = cpu.gpr[3].wrapping_sub(0x1);
+                set_gpr(cpu, 3, value);
+                // 80000018: bdnz -0x8
+                if decrement_ctr(cpu) != 0 {
+                    block = 0x80000010;
+                    continue;
+                }
+                block = 0x8000001c;
+            }
+            0x8000001c => {
+                // 8000001c: mr r3, r4
+                let value = cpu.gpr[4];
+                set_gpr(cpu, 3, value);
+                // 80000020: blr
+                return Ok(());
+            }
+            _ => return dispatch(cpu, mem, block),
+        }
+    }
+}
+
+pub fn fn_80000040(cpu: &mut Cpu, mem: &mut dyn Memory) -> Result<(), Exception> {
+    let mut block: u64 = 0x80000040;
+    loop {
+        match block {
+            0x80000040 => {
+                // 80000040: cmplwi r3, 0x3
+                let cr = compare(cpu, cpu.gpr[3] as u32, 0x3);
+                cpu.set_cr_field(0, cr);
+                // 80000044: bgt 0x3c
+                if cpu.cr_bit(CRBit(1)) {
+                    block = 0x80000080;
+                    continue;
+                }
+                // 80000048: lis r4, 0x8000
+                let value = 0xffffffff80000000;
+                set_gpr(cpu, 4, value);
+                // 8000004c: addi r4, r4, 0x200
+                let value = cpu.gpr[4].wrapping_add(0x200);
+                set_gpr(cpu, 4, value);
+                // 80000050: slwi r0, r3, 2
+                let value = ((cpu.gpr[3] as u32).rotate_left(2) & 0xfffffffc) as u64;
+                set_gpr(cpu, 0, value);
+                // 80000054: lwzx r0, r4, r0
+                let ea = trunc(cpu, cpu.gpr[4].wrapping_add(cpu.gpr[0]));
+                let value = read_u32(mem, 0x80000054, ea)? as u64;
+                set_gpr(cpu, 0, value);
+                // 80000058: mtctr r0
+                cpu.ctr = trunc(cpu, cpu.gpr[0]);
+                // 8000005c: bctr
+                block = cpu.ctr & !3;
+            }
+            0x80000060 => {
+                // 80000060: li r3, 0xa
+                let value = 0xa;
+                set_gpr(cpu, 3, value);
+                // 80000064: blr
+                return Ok(());
+            }
+            0x80000068 => {
+                // 80000068: li r3, 0x14
+                let value = 0x14;
+                set_gpr(cpu, 3, value);
+                // 8000006c: blr
+                return Ok(());
+            }
+            0x80000070 => {
+                // 80000070: li r3, 0x1e
+                let value = 0x1e;
+                set_gpr(cpu, 3, value);
+                // 80000074: blr
+                return Ok(());
+            }
+            0x80000078 => {
+                // 80000078: li r3, 0x28
+                let value = 0x28;
+                set_gpr(cpu, 3, value);
+                // 8000007c: blr
+                return Ok(());
+            }
+            0x80000080 => {
+                // 80000080: li r3, -0x1
+                let value = 0xffffffffffffffff;
+                set_gpr(cpu, 3, value);
+                // 80000084: blr
+                return Ok(());
+            }
+            _ => return dispatch(cpu, mem, block),
+        }
+    }
+}
+
+pub fn fn_800000c0(cpu: &mut Cpu, mem: &mut dyn Memory) -> Result<(), Exception> {
+    // 800000c0: mflr r0
+    let value = cpu.lr;
+    set_gpr(cpu, 0, value);
+    // 800000c4: stw r0, 0x4(r1)
+    let ea = trunc(cpu, cpu.gpr[1].wrapping_add(0x4));
+    write_u32(mem, 0x800000c4, ea, cpu.gpr[0] as u32)?;
+    // 800000c8: stwu r1, -0x10(r1)
+    let ea = trunc(cpu, cpu.gpr[1].wrapping_sub(0x10));
+    write_u32(mem, 0x800000c8, ea, cpu.gpr[1] as u32)?;
+    cpu.gpr[1] = ea;
+    // 800000cc: stmw r30, 0x8(r1)
+    interpret(cpu, mem, 0x800000cc, Ins { code: 0xbfc10008, op: Opcode::Stmw })?;
+    // 800000d0: mr r31, r3
+    let value = cpu.gpr[3];
+    set_gpr(cpu, 31, value);
+    // 800000d4: bl 0x6c
+    cpu.lr = 0x800000d8;
+    fn_80000140(cpu, mem)?;
+    // 800000d8: clrlwi r30, r3, 30
+    let value = (cpu.gpr[3] as u32 & 0x3) as u64;
+    set_gpr(cpu, 30, value);
+    // 800000dc: mr r3, r30
+    let value = cpu.gpr[30];
+    set_gpr(cpu, 3, value);
+    // 800000e0: lis r12, 0x8000
+    let value = 0xffffffff80000000;
+    set_gpr(cpu, 12, value);
+    // 800000e4: addi r12, r12, 0x40
+    let value = cpu.gpr[12].wrapping_add(0x40);
+    set_gpr(cpu, 12, value);
+    // 800000e8: mtctr r12
+    cpu.ctr = trunc(cpu, cpu.gpr[12]);
+    // 800000ec: bctrl
+    let target = cpu.ctr & !3;
+    cpu.lr = 0x800000f0;
+    dispatch(cpu, mem, target)?;
+    // 800000f0: add r3, r3, r31
+    let value = cpu.gpr[3].wrapping_add(cpu.gpr[31]);
+    set_gpr(cpu, 3, value);
+    // 800000f4: bl 0x8c
+    cpu.lr = 0x800000f8;
+    dispatch(cpu, mem, 0x80000180)?;
+    // 800000f8: srawi r5, r3, 1
+    let s = cpu.gpr[3];
+    let value = sraw(cpu, s, 1);
+    set_gpr(cpu, 5, value);
+    // 800000fc: addze r5, r5
+    let (a, b, carry) = (cpu.gpr[5], 0, cpu.ca());
+    let value = add_carry(cpu, a, b, carry);
+    set_gpr(cpu, 5, value);
+    // 80000100: rlwimi r3, r5, 8, 16, 23
+    let value = ((cpu.gpr[5] as u32).rotate_left(8) & 0xff00) as u64 | (cpu.gpr[3] & 0xffffffffffff00ff);
+    set_gpr(cpu, 3, value);
+    // 80000104: lmw r30, 0x8(r1)
+    interpret(cpu, mem, 0x80000104, Ins { code: 0xbbc10008, op: Opcode::Lmw })?;
+    // 80000108: addi r1, r1, 0x10
+    let value = cpu.gpr[1].wrapping_add(0x10);
+    set_gpr(cpu, 1, value);
+    // 8000010c: lwz r0, 0x4(r1)
+    let ea = trunc(cpu, cpu.gpr[1].wrapping_add(0x4));
+    let value = read_u32(mem, 0x8000010c, ea)? as u64;
+    set_gpr(cpu, 0, value);
+    // 80000110: mtlr r0
+    cpu.lr = trunc(cpu, cpu.gpr[0]);
+    // 80000114: blr
+    Ok(())
+}
+
+pub fn fn_80000140(cpu: &mut Cpu, mem: &mut dyn Memory) -> Result<(), Exception> {
+    // 80000140: addi r3, r3, 0x2
+    let value = cpu.gpr[3].wrapping_add(0x2);
+    set_gpr(cpu, 3, value);
+    // 80000144: b -0x144
+    fn_80000000(cpu, mem)
+}
+
+pub fn fn_800001c0(cpu: &mut Cpu, mem: &mut dyn Memory) -> Result<(), Exception> {
+    // 800001c0: mflr r0
+    let value = cpu.lr;
+    set_gpr(cpu, 0, value);
+    // 800001c4: stw r0, 0x4(r1)
+    let ea = trunc(cpu, cpu.gpr[1].wrapping_add(0x4));
+    write_u32(mem, 0x800001c4, ea, cpu.gpr[0] as u32)?;
+    // 800001c8: cmpwi r3, 0x0
+    let cr = compare(cpu, cpu.gpr[3] as i32, 0x0);
+    cpu.set_cr_field(0, cr);
+    // 800001cc: beql -0x1cc
+    cpu.lr = 0x800001d0;
+    if cpu.cr_bit(CRBit(2)) {
+        fn_80000000(cpu, mem)?;
+    }
+    // 800001d0: mflr r4
+    let value = cpu.lr;
+    set_gpr(cpu, 4, value);
+    // 800001d4: cmpwi r3, 0x1
+    let cr = compare(cpu, cpu.gpr[3] as i32, 0x1);
+    cpu.set_cr_field(0, cr);
+    // 800001d8: lis r12, 0x8000
+    let value = 0xffffffff80000000;
+    set_gpr(cpu, 12, value);
+    // 800001dc: addi r12, r12, 0x40
+    let value = cpu.gpr[12].wrapping_add(0x40);
+    set_gpr(cpu, 12, value);
+    // 800001e0: mtlr r12
+    cpu.lr = trunc(cpu, cpu.gpr[12]);
+    // 800001e4: beqlrl
+    let target = cpu.lr & !3;
+    cpu.lr = 0x800001e8;
+    if cpu.cr_bit(CRBit(2)) {
+        dispatch(cpu, mem, target)?;
+    }
+    // 800001e8: mflr r5
+    let value = cpu.lr;
+    set_gpr(cpu, 5, value);
+    // 800001ec: lwz r0, 0x4(r1)
+    let ea = trunc(cpu, cpu.gpr[1].wrapping_add(0x4));
+    let value = read_u32(mem, 0x800001ec, ea)? as u64;
+    set_gpr(cpu, 0, value);
+    // 800001f0: mtlr r0
+    cpu.lr = trunc(cpu, cpu.gpr[0]);
+    // 800001f4: blr
+    Ok(())
+}
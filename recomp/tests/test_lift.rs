@@ -0,0 +1,482 @@
+use powerpc::{CRBit, Extensions, Ins};
+use powerpc_interp::{Cpu, Exception, Memory, Ram};
+use powerpc_recomp::ir::{self, Callee, Expr, Reg, Stmt, Target, Terminator, Type};
+use powerpc_recomp::{lift, Recompiler};
+use std::collections::HashMap;
+
+mod common;
+
+use common::{get, ram, ADDR, BASE, VALUES};
+
+const OPS: &[u32] = &[
+    0x7C642A14, // add r3, r4, r5
+    0x7C642A15, // add. r3, r4, r5
+    0x7C642E15, // addo. r3, r4, r5
+    0x7C642814, // addc r3, r4, r5
+    0x7C642815, // addc. r3, r4, r5
+    0x7C642C15, // addco. r3, r4, r5
+    0x7C642915, // adde. r3, r4, r5
+    0x7C642D15, // addeo. r3, r4, r5
+    0x7C642850, // subf r3, r4, r5
+    0x7C642851, // subf. r3, r4, r5
+    0x7C642C51, // subfo. r3, r4, r5
+    0x7C642811, // subfc. r3, r4, r5
+    0x7C642C11, // subfco. r3, r4, r5
+    0x7C642911, // subfe. r3, r4, r5
+    0x7C642D11, // subfeo. r3, r4, r5
+    0x7C6429D7, // mullw. r3, r4, r5
+    0x7C642DD7, // mullwo. r3, r4, r5
+    0x7C642897, // mulhw. r3, r4, r5
+    0x7C642817, // mulhwu. r3, r4, r5
+    0x7C642BD7, // divw. r3, r4, r5
+    0x7C642FD7, // divwo. r3, r4, r5
+    0x7C642B97, // divwu. r3, r4, r5
+    0x7C642F97, // divwuo. r3, r4, r5
+    0x7C832831, // slw. r3, r4, r5
+    0x7C832C31, // srw. r3, r4, r5
+    0x7C832E31, // sraw. r3, r4, r5
+    0x7C832839, // and. r3, r4, r5
+    0x7C832879, // andc. r3, r4, r5
+    0x7C832B79, // or. r3, r4, r5
+    0x7C832B39, // orc. r3, r4, r5
+    0x7C832A79, // xor. r3, r4, r5
+    0x7C832BB9, // nand. r3, r4, r5
+    0x7C8328F9, // nor. r3, r4, r5
+    0x7C832A39, // eqv. r3, r4, r5
+    0x7C6401D5, // addme. r3, r4
+    0x7C6405D5, // addmeo. r3, r4
+    0x7C640195, // addze. r3, r4
+    0x7C640595, // addzeo. r3, r4
+    0x7C6401D1, // subfme. r3, r4
+    0x7C6405D1, // subfmeo. r3, r4
+    0x7C640191, // subfze. r3, r4
+    0x7C640591, // subfzeo. r3, r4
+    0x7C6400D0, // neg r3, r4
+    0x7C6400D1, // neg. r3, r4
+    0x7C6404D1, // nego. r3, r4
+    0x7C830775, // extsb. r3, r4
+    0x7C830735, // extsh. r3, r4
+    0x7C830035, // cntlzw. r3, r4
+    0x5C83283F, // rotlw. r3, r4, r5
+    0x3864FFFB, // subi r3, r4, 0x5
+    0x3860FFFB, // li r3, -0x5
+    0x3C608001, // lis r3, 0x8001
+    0x3C64FFFE, // subis r3, r4, 0x2
+    0x3064FFFB, // subic r3, r4, 0x5
+    0x34640007, // addic. r3, r4, 0x7
+    0x20640007, // subfic r3, r4, 0x7
+    0x1C64FFFD, // mulli r3, r4, -0x3
+    0x7083F0F0, // andi. r3, r4, 0xf0f0
+    0x74838001, // andis. r3, r4, 0x8001
+    0x60831234, // ori r3, r4, 0x1234
+    0x64831234, // oris r3, r4, 0x1234
+    0x68838234, // xori r3, r4, 0x8234
+    0x6C838234, // xoris r3, r4, 0x8234
+    0x7C832378, // mr r3, r4
+    0x60000000, // nop
+    0x7C832E71, // srawi. r3, r4, 5
+    0x7C830670, // srawi r3, r4, 0
+    0x548328F9, // rlwinm. r3, r4, 5, 3, 28
+    0x54834706, // rlwinm r3, r4, 8, 28, 3
+    0x50836227, // rlwimi. r3, r4, 12, 8, 19
+    0x7D042800, // cmpw cr2, r4, r5
+    0x7D842840, // cmplw cr3, r4, r5
+    0x2C84FFFF, // cmpwi cr1, r4, -0x1
+    0x2B848000, // cmplwi cr7, r4, 0x8000
+    0x4C221A02, // crand gt, eq, un
+    0x4CAAF902, // crandc cr1gt, cr2eq, cr7un
+    0x4D004A42, // creqv cr2lt, lt, cr2gt
+    0x4FC489C2, // crnand cr7eq, cr1lt, cr4gt
+    0x4C011042, // crnor lt, gt, eq
+    0x4CCC6B82, // cror cr1eq, cr3lt, cr3gt
+    0x4CEEAB42, // crorc cr1un, cr3eq, cr5gt
+    0x4C421182, // crclr eq
+    0x4D140000, // mcrf cr2, cr5
+    0x7C600026, // mfcr r3
+    0x7C85A120, // mtcrf 90, r4
+    0x7C8FF120, // mtcrf 255, r4
+    0x7C6802A6, // mflr r3
+    0x7C8903A6, // mtctr r4
+    0x7C7042A6, // mfsprg r3, 0
+    0x7C9143A6, // mtsprg 1, r4
+    0x7E042808, // tw 16, r4, r5
+    0x0CA4FFFF, // twi 5, r4, -0x1
+];
+const ACCESS: &[u32] = &[
+    0x8864FFFD, // lbz r3, -0x3(r4)
+    0x8C640001, // lbzu r3, 0x1(r4)
+    0xA0640002, // lhz r3, 0x2(r4)
+    0xA8640006, // lha r3, 0x6(r4)
+    0xAC640006, // lhau r3, 0x6(r4)
+    0x80640004, // lwz r3, 0x4(r4)
+    0x7C64282E, // lwzx r3, r4, r5
+    0x7C64286E, // lwzux r3, r4, r5
+    0x7C60262C, // lhbrx r3, r0, r4
+    0x7C642C2C, // lwbrx r3, r4, r5
+    0x98640000, // stb r3, 0x0(r4)
+    0xB064FFFE, // sth r3, -0x2(r4)
+    0x90640004, // stw r3, 0x4(r4)
+    0x9464FFF8, // stwu r3, -0x8(r4)
+    0x7C642F2C, // sthbrx r3, r4, r5
+    0x7C642D2C, // stwbrx r3, r4, r5
+    0x7C6429AE, // stbx r3, r4, r5
+    0xBBA40008, // lmw r29, 0x8(r4)
+    0xBFA4FFFC, // stmw r29, -0x4(r4)
+];
+const FLOAT: &[u32] = &[
+    0xC8240008, // lfd f1, 0x8(r4)
+    0xCC24FFF8, // lfdu f1, -0x8(r4)
+    0x7C242CAE, // lfdx f1, r4, r5
+    0x7C242CEE, // lfdux f1, r4, r5
+    0xD8440000, // stfd f2, 0x0(r4)
+    0xDC44FFF0, // stfdu f2, -0x10(r4)
+    0x7C442DAE, // stfdx f2, r4, r5
+    0x7C442DEE, // stfdux f2, r4, r5
+    0xFC201090, // fmr f1, f2
+    0xFC201050, // fneg f1, f2
+    0xFC201210, // fabs f1, f2
+    0xFC201110, // fnabs f1, f2
+];
+const BRANCH: &[u32] = &[
+    0x42000040, // bdnz 0x40
+    0x4240FFC0, // bdz -0x40
+    0x41820010, // beq 0x10
+    0x408E0010, // bne cr3, 0x10
+    0x40020020, // bdnzf eq, 0x20
+    0x48000100, // b 0x100
+    0x4E800020, // blr
+    0x4E800420, // bctr
+    0x4D820020, // beqlr
+    0x4E000020, // bdnzlr
+    0x4C860420, // bnectr cr1
+    0x48000101, // bl 0x100
+    0x4E800421, // bctrl
+    0x4E800021, // blrl
+    0x429F0005, // bcl 20, cr7un, 0x4
+    0x41820021, // beql 0x20
+];
+
+fn set(cpu: &mut Cpu, reg: Reg, value: u64) {
+    let flag = |xer: &mut u32, mask: u32| {
+        if value != 0 {
+            *xer |= mask
+        } else {
+            *xer &= !mask
+        }
+    };
+    match reg {
+        Reg::Gpr(reg) => cpu.gpr[reg as usize] = value,
+        Reg::Fpr(reg) => cpu.fpr[reg as usize] = f64::from_bits(value),
+        Reg::Cr(bit) => cpu.set_cr_bit(CRBit(bit), value != 0),
+        Reg::So => flag(&mut cpu.xer, Cpu::XER_SO),
+        Reg::Ov => flag(&mut cpu.xer, Cpu::XER_OV),
+        Reg::Ca => flag(&mut cpu.xer, Cpu::XER_CA),
+        Reg::Lr => cpu.lr = value,
+        Reg::Ctr => cpu.ctr = value,
+        Reg::Spr(spr) => cpu.write_spr(spr, value),
+    }
+}
+
+/// Evaluates a single-block function, returning the address of the next instruction,
+/// or `None` if it trapped.
+fn eval(function: &ir::Function, cpu: &mut Cpu, mem: &mut dyn Memory) -> Option<u64> {
+    assert_eq!(function.blocks.len(), 1, "{function}");
+    let block = &function.blocks[&function.address];
+    let mut values = HashMap::new();
+    for stmt in &block.stmts {
+        match *stmt {
+            Stmt::Let { value, ty, ref expr } => {
+                let result = match *expr {
+                    Expr::Const(value) => value,
+                    Expr::Get(reg) => get(cpu, reg),
+                    Expr::Unary(op, a) => {
+                        let (a_ty, a) = values[&a];
+                        op.eval(ty, a_ty, a)
+                    }
+                    Expr::Binary(op, a, b) => {
+                        let (a_ty, a) = values[&a];
+                        op.eval(a_ty, a, values[&b].1)
+                    }
+                    Expr::Load(addr) => {
+                        let addr = values[&addr].1;
+                        match ty {
+                            Type::I8 => mem.read_u8(addr).unwrap() as u64,
+                            Type::I16 => mem.read_u16(addr).unwrap() as u64,
+                            Type::I32 => mem.read_u32(addr).unwrap() as u64,
+                            _ => mem.read_u64(addr).unwrap(),
+                        }
+                    }
+                };
+                values.insert(value, (ty, result & ty.mask()));
+            }
+            Stmt::Set(reg, value) => set(cpu, reg, values[&value].1),
+            Stmt::Store { ty, addr, value } => {
+                let (addr, value) = (values[&addr].1, values[&value].1);
+                match ty {
+                    Type::I8 => mem.write_u8(addr, value as u8),
+                    Type::I16 => mem.write_u16(addr, value as u16),
+                    Type::I32 => mem.write_u32(addr, value as u32),
+                    _ => mem.write_u64(addr, value),
+                }
+                .unwrap()
+            }
+            Stmt::Call { cond, callee } => {
+                if cond.map_or(true, |cond| values[&cond].1 != 0) {
+                    return Some(match callee {
+                        Callee::Direct(addr) => addr as u64,
+                        Callee::Indirect(value) => values[&value].1,
+                    });
+                }
+            }
+            Stmt::Trap(cond) => {
+                if values[&cond].1 != 0 {
+                    return None;
+                }
+            }
+            Stmt::Intrinsic { ins, .. } => panic!("not lifted: {}", ins.simplified()),
+        }
+    }
+    let target = |target: &Target| match target {
+        Target::Address(addr) => *addr as u64,
+        Target::Indirect { value, .. } => values[value].1,
+        Target::Return => cpu.lr,
+    };
+    match &block.terminator {
+        Terminator::Jump(t) => Some(target(t)),
+        Terminator::Branch { cond, target: t, next } => {
+            Some(if values[cond].1 != 0 { target(t) } else { *next as u64 })
+        }
+        Terminator::Trap => None,
+    }
+}
+
+/// Executes an instruction with the interpreter and its lifted form, comparing the results.
+fn compare(code: u32, cpu: &Cpu) {
+    let ins = Ins::new(code, Extensions::gekko_broadway());
+    let function = lift(ADDR, &[ins]);
+    let (mut expected, mut actual) = (cpu.clone(), cpu.clone());
+    let (mut expected_ram, mut actual_ram) = (ram(), ram());
+    expected.pc = ADDR as u64;
+    let trapped = match expected.execute(ins, &mut expected_ram) {
+        Ok(()) => false,
+        Err(Exception::Trap { .. }) => true,
+        Err(e) => panic!("{e}"),
+    };
+    let next = eval(&function, &mut actual, &mut actual_ram);
+    let message = format!("{}\n{function}\n{cpu:x?}", ins.simplified());
+    assert_eq!(next.is_none(), trapped, "{message}");
+    if trapped {
+        return;
+    }
+    assert_eq!(next, Some(expected.pc), "{message}");
+    assert_eq!(actual.gpr, expected.gpr, "{message}");
+    assert_eq!(actual.fpr.map(f64::to_bits), expected.fpr.map(f64::to_bits), "{message}");
+    assert_eq!(actual.cr, expected.cr, "{message}");
+    assert_eq!(actual.xer, expected.xer, "{message}");
+    assert_eq!(actual.lr, expected.lr, "{message}");
+    assert_eq!(actual.ctr, expected.ctr, "{message}");
+    assert_eq!(actual.spr, expected.spr, "{message}");
+    assert_eq!(actual_ram.data, expected_ram.data, "{message}");
+}
+
+#[test]
+fn test_lift_operations() {
+    for &code in OPS {
+        for (i, &a) in VALUES.iter().enumerate() {
+            for &b in &VALUES {
+                let mut cpu = Cpu::new(Extensions::gekko_broadway());
+                cpu.gpr[3] = 0x1234_5678;
+                cpu.gpr[4] = a;
+                cpu.gpr[5] = b;
+                cpu.cr = 0x9A3C_5E71 ^ (b as u32);
+                cpu.xer = [0, Cpu::XER_CA, Cpu::XER_SO | Cpu::XER_OV][i % 3];
+                cpu.lr = 0x8000_0400;
+                cpu.spr[272] = 0x600D_F00D;
+                compare(code, &cpu);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_lift_accesses() {
+    for &code in ACCESS {
+        for (base, index) in [(0x40, 8), (0x80, 0x11), (0x100, 0x3A)] {
+            let mut cpu = Cpu::new(Extensions::gekko_broadway());
+            cpu.gpr[3] = 0x1234_5678;
+            cpu.gpr[4] = BASE + base;
+            cpu.gpr[5] = index;
+            for reg in 29..32 {
+                cpu.gpr[reg] = 0x0101_0101 * reg as u64;
+            }
+            compare(code, &cpu);
+        }
+    }
+}
+
+#[test]
+fn test_lift_floats() {
+    // Doubles are moved as their bits, including NaN payloads
+    let values = [0.0, -0.0, 1.5, -2.25, f64::INFINITY, f64::from_bits(0x7FF0_0000_DEAD_BEEF)];
+    for &code in FLOAT {
+        for (i, &value) in values.iter().enumerate() {
+            let mut cpu = Cpu::new(Extensions::gekko_broadway());
+            cpu.gpr[4] = BASE + 0x40 + 0x40 * (i as u64 % 3);
+            cpu.gpr[5] = 0x18;
+            cpu.fpr[1] = 3.0;
+            cpu.fpr[2] = value;
+            compare(code, &cpu);
+        }
+    }
+}
+
+#[test]
+fn test_lift_branches() {
+    for &code in BRANCH {
+        for (cr, ctr) in [(0, 0), (0x2000_0000, 1), (0x0020_0000, 2), (0x2020_0000, 0x10)] {
+            let mut cpu = Cpu::new(Extensions::gekko_broadway());
+            cpu.cr = cr;
+            cpu.ctr = ctr;
+            cpu.lr = 0x8000_0400;
+            compare(code, &cpu);
+            cpu.ctr = 0x8000_0800;
+            compare(code, &cpu);
+        }
+    }
+}
+
+fn lift_code(code: &[u32]) -> ir::Function {
+    lift(ADDR, &common::code(code))
+}
+
+#[test]
+fn test_eliminate_dead_flags() {
+    let mut function = lift_code(&[
+        0x7C642A15, // add. r3, r4, r5
+        0x2C030000, // cmpwi r3, 0x0
+        0x41820008, // beq 0x8
+        0x7C632014, // addc r3, r3, r4
+        0x4E800020, // blr
+    ]);
+    function.eliminate_dead_flags();
+    // Only CR0[EQ] is read, and the carry is dead at the return
+    assert_eq!(
+        function.to_string(),
+        "fn 0x80000100:
+0x80000100:
+    %0: i32 = get r4
+    %1: i32 = get r5
+    %2: i32 = add %0, %1
+    set r3, %2
+    %8: i32 = get r3
+    %9: i32 = const 0x0
+    %12: i1 = eq %8, %9
+    set cr0.eq, %12
+    %14: i1 = get cr0.eq
+    branch %14, 0x80000110, 0x8000010c
+0x8000010c:
+    %15: i32 = get r3
+    %16: i32 = get r4
+    %17: i64 = zext %15
+    %18: i64 = zext %16
+    %19: i64 = add %17, %18
+    %23: i32 = trunc %19
+    set r3, %23
+    jump 0x80000110
+0x80000110:
+    return"
+    );
+
+    // Calls clobber all but cr2 to cr4, which are preserved by returns
+    let mut function = lift_code(&[
+        0x2C030000, // cmpwi r3, 0x0
+        0x2D030000, // cmpwi cr2, r3, 0x0
+        0x48000101, // bl 0x100
+        0x4E800020, // blr
+    ]);
+    function.eliminate_dead_flags();
+    assert_eq!(
+        function.to_string(),
+        "fn 0x80000100:
+0x80000100:
+    %6: i32 = get r3
+    %7: i32 = const 0x0
+    %8: i1 = slt %6, %7
+    %9: i1 = sgt %6, %7
+    %10: i1 = eq %6, %7
+    %11: i1 = get xer.so
+    set cr2.lt, %8
+    set cr2.gt, %9
+    set cr2.eq, %10
+    set cr2.so, %11
+    %12: i32 = const 0x8000010c
+    set lr, %12
+    call 0x80000208
+    return"
+    );
+
+    // Variadic callees read cr1.eq
+    for crbit in [0x4CC63182, 0x4CC63242] {
+        let mut function = lift_code(&[
+            crbit,      // crclr/crset cr1eq
+            0x48000101, // bl 0x100
+            0x4E800020, // blr
+        ]);
+        function.eliminate_dead_flags();
+        assert!(function.to_string().contains("set cr1.eq"), "{function}");
+    }
+}
+
+#[test]
+fn test_lift_function() {
+    #[rustfmt::skip]
+    let code = [
+        0x28030003, // cmplwi r3, 0x3
+        0x41810024, // bgt 0x24
+        0x3C808000, // lis r4, 0x8000
+        0x38840200, // addi r4, r4, 0x200
+        0x5460103A, // slwi r0, r3, 2
+        0x7C04002E, // lwzx r0, r4, r0
+        0x7C0903A6, // mtctr r0
+        0x4E800420, // bctr
+        0x3860000A, // li r3, 0xa
+        0x4E800020, // blr
+        0x3860FFFF, // li r3, -0x1
+        0x4E800020, // blr
+    ];
+    let mut ram = Ram { base: BASE, data: vec![0u8; 0x210] };
+    for (i, &code) in code.iter().enumerate() {
+        ram.write_u32(BASE + 4 * i as u64, code).unwrap();
+    }
+    for i in 0..4 {
+        ram.write_u32(BASE + 0x200 + 4 * i, BASE as u32 + 0x20).unwrap();
+    }
+    let mut recompiler = Recompiler::new(Extensions::gekko_broadway());
+    recompiler.add_function(BASE as u32, None);
+    let program = recompiler.analyze(&mut ram).unwrap();
+    let mut function = program.functions[&(BASE as u32)].lift();
+    function.eliminate_dead_flags();
+
+    // The conditional branch splits the first basic block
+    assert_eq!(
+        function.blocks.keys().map(|&addr| addr - BASE as u32).collect::<Vec<_>>(),
+        [0x0, 0x8, 0x20, 0x28]
+    );
+    let Terminator::Jump(Target::Indirect { targets, .. }) =
+        &function.blocks[&0x80000008].terminator
+    else {
+        panic!("{function}");
+    };
+    assert_eq!(targets, &[0x80000020; 4]);
+    // The bounds check only needs CR0[GT]
+    let block = &function.blocks[&0x80000000];
+    let flags = block
+        .stmts
+        .iter()
+        .filter_map(|stmt| match stmt {
+            Stmt::Set(reg, _) => Some(*reg),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(flags, [Reg::Cr(1)]);
+}
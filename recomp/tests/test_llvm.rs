@@ -0,0 +1,92 @@
+use powerpc::{Extensions, Ins};
+use powerpc_interp::{Memory, Ram};
+use powerpc_recomp::{lift, Recompiler};
+
+const BASE: u64 = 0x80000000;
+
+#[test]
+fn test_llvm_source() {
+    #[rustfmt::skip]
+    let code = [
+        0x2C030000, // cmpwi r3, 0x0
+        0x4182002C, // beq 0x2c
+        0x7C6903A6, // mtctr r3
+        0x84A40004, // lwzu r5, 0x4(r4)
+        0x7CA0372C, // sthbrx r5, r0, r6
+        0x7CE51896, // mulhw r7, r5, r3
+        0x7D051B96, // divwu r8, r5, r3
+        0x7CA94030, // slw r9, r5, r8
+        0x7D293E30, // sraw r9, r9, r7
+        0x552A1837, // extlwi. r10, r9, 28, 3
+        0x0C8AFFFF, // twi 4, r10, -0x1
+        0x4200FFE0, // bdnz -0x20
+        0xC8240008, // lfd f1, 0x8(r4)
+        0xFC200850, // fneg f1, f1
+        0xFC21102A, // fadd f1, f1, f2
+        0xD8240010, // stfd f1, 0x10(r4)
+        0x48000101, // bl 0x100
+        0x4E800020, // blr
+    ];
+    let code = code.map(|code| Ins::new(code, Extensions::gekko_broadway()));
+    let mut function = lift(BASE as u32, &code);
+    function.eliminate_dead_flags();
+    let source = function.llvm().to_string();
+    if std::env::var_os("UPDATE_GENERATED").is_some() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/generated/function.ll");
+        std::fs::write(path, &source).unwrap();
+    }
+    assert_eq!(source, include_str!("generated/function.ll"));
+}
+
+#[test]
+fn test_llvm_jump_table() {
+    #[rustfmt::skip]
+    let code = [
+        0x28030001, // cmplwi r3, 0x1
+        0x4181001C, // bgt 0x1c
+        0x3C808000, // lis r4, 0x8000
+        0x5460103A, // slwi r0, r3, 2
+        0x7C04002E, // lwzx r0, r4, r0
+        0x7C0903A6, // mtctr r0
+        0x4E800420, // bctr
+        0x38630001, // addi r3, r3, 0x1
+        0x4E800020, // blr
+    ];
+    let mut ram = Ram { base: BASE, data: vec![0u8; 0x100] };
+    for (i, &code) in code.iter().enumerate() {
+        ram.write_u32(BASE + 0x40 + 4 * i as u64, code).unwrap();
+    }
+    ram.write_u32(BASE, BASE as u32 + 0x5C).unwrap();
+    ram.write_u32(BASE + 4, BASE as u32 + 0x60).unwrap();
+    let mut recompiler = Recompiler::new(Extensions::gekko_broadway());
+    recompiler.add_function(BASE as u32 + 0x40, None);
+    let program = recompiler.analyze(&mut ram).unwrap();
+    let source = program.functions[&(BASE as u32 + 0x40)].lift().llvm().to_string();
+
+    // The table's targets are blocks, and other addresses are dispatched
+    let switch = source.lines().skip_while(|line| !line.contains("switch")).collect::<Vec<_>>();
+    assert!(switch[0].starts_with("  switch i32 %v"), "{source}");
+    assert_eq!(switch[1], "    i32 -2147483556, label %bb_8000005c");
+    assert_eq!(switch[2], "    i32 -2147483552, label %bb_80000060");
+    assert!(source.contains("call void @ppc_dispatch(ptr %ctx, ptr %mem, i32 %v"), "{source}");
+    assert!(source.contains("declare void @ppc_dispatch(ptr, ptr, i32)"));
+}
+
+#[test]
+fn test_llvm_recursion() {
+    #[rustfmt::skip]
+    let code = [
+        0x2C030000, // cmpwi r3, 0x0
+        0x4D820020, // beqlr
+        0x3863FFFF, // subi r3, r3, 0x1
+        0x4BFFFFF5, // bl -0xc
+        0x4E800020, // blr
+    ];
+    let code = code.map(|code| Ins::new(code, Extensions::gekko_broadway()));
+    let source = lift(BASE as u32, &code).llvm().to_string();
+
+    // The function is defined, and not declared again for the call
+    assert!(source.contains("define void @fn_80000000(ptr %ctx, ptr %mem)"), "{source}");
+    assert!(source.contains("call void @fn_80000000(ptr %ctx, ptr %mem)"), "{source}");
+    assert!(!source.contains("declare void @fn_80000000"), "{source}");
+}
@@ -0,0 +1,210 @@
+use powerpc::Extensions;
+use powerpc_interp::{Cpu, Memory, Ram};
+use powerpc_recomp::{Program, RecompError, Recompiler};
+
+#[rustfmt::skip]
+#[path = "generated/program.rs"]
+mod program;
+
+const BASE: u32 = 0x80000000;
+const STACK: u64 = 0x80000F00;
+
+const SUM_TO: u32 = BASE;
+const SWITCH: u32 = BASE + 0x40;
+const MAIN: u32 = BASE + 0xC0;
+const SCRAMBLE: u32 = BASE + 0x140;
+const UNKNOWN: u32 = BASE + 0x180;
+const CONDITIONAL: u32 = BASE + 0x1C0;
+const TABLE: u32 = BASE + 0x200;
+
+#[rustfmt::skip]
+const CODE: &[(u32, u32)] = &[
+    // Sums the integers from 1 to r3
+    (0x000, 0x2C030000), // cmpwi r3, 0x0
+    (0x004, 0x38800000), // li r4, 0x0
+    (0x008, 0x40810014), // ble 0x14
+    (0x00c, 0x7C6903A6), // mtctr r3
+    (0x010, 0x7C841A14), // add r4, r4, r3
+    (0x014, 0x3863FFFF), // subi r3, r3, 0x1
+    (0x018, 0x4200FFF8), // bdnz -0x8
+    (0x01c, 0x7C832378), // mr r3, r4
+    (0x020, 0x4E800020), // blr
+    // switch (r3) with a jump table
+    (0x040, 0x28030003), // cmplwi r3, 0x3
+    (0x044, 0x4181003C), // bgt 0x3c
+    (0x048, 0x3C808000), // lis r4, 0x8000
+    (0x04c, 0x38840200), // addi r4, r4, 0x200
+    (0x050, 0x5460103A), // slwi r0, r3, 2
+    (0x054, 0x7C04002E), // lwzx r0, r4, r0
+    (0x058, 0x7C0903A6), // mtctr r0
+    (0x05c, 0x4E800420), // bctr
+    (0x060, 0x3860000A), // li r3, 0xa
+    (0x064, 0x4E800020), // blr
+    (0x068, 0x38600014), // li r3, 0x14
+    (0x06c, 0x4E800020), // blr
+    (0x070, 0x3860001E), // li r3, 0x1e
+    (0x074, 0x4E800020), // blr
+    (0x078, 0x38600028), // li r3, 0x28
+    (0x07c, 0x4E800020), // blr
+    (0x080, 0x3860FFFF), // li r3, -0x1
+    (0x084, 0x4E800020), // blr
+    // Calls the other functions, directly and indirectly
+    (0x0c0, 0x7C0802A6), // mflr r0
+    (0x0c4, 0x90010004), // stw r0, 0x4(r1)
+    (0x0c8, 0x9421FFF0), // stwu r1, -0x10(r1)
+    (0x0cc, 0xBFC10008), // stmw r30, 0x8(r1)
+    (0x0d0, 0x7C7F1B78), // mr r31, r3
+    (0x0d4, 0x4800006D), // bl 0x6c
+    (0x0d8, 0x547E07BE), // clrlwi r30, r3, 30
+    (0x0dc, 0x7FC3F378), // mr r3, r30
+    (0x0e0, 0x3D808000), // lis r12, 0x8000
+    (0x0e4, 0x398C0040), // addi r12, r12, 0x40
+    (0x0e8, 0x7D8903A6), // mtctr r12
+    (0x0ec, 0x4E800421), // bctrl
+    (0x0f0, 0x7C63FA14), // add r3, r3, r31
+    (0x0f4, 0x4800008D), // bl 0x8c
+    (0x0f8, 0x7C650E70), // srawi r5, r3, 1
+    (0x0fc, 0x7CA50194), // addze r5, r5
+    (0x100, 0x50A3442E), // rlwimi r3, r5, 8, 16, 23
+    (0x104, 0xBBC10008), // lmw r30, 0x8(r1)
+    (0x108, 0x38210010), // addi r1, r1, 0x10
+    (0x10c, 0x80010004), // lwz r0, 0x4(r1)
+    (0x110, 0x7C0803A6), // mtlr r0
+    (0x114, 0x4E800020), // blr
+    // Tail call
+    (0x140, 0x38630002), // addi r3, r3, 0x2
+    (0x144, 0x4BFFFEBC), // b -0x144
+    // Not recompiled
+    (0x180, 0x1C630003), // mulli r3, r3, 0x3
+    (0x184, 0x68630055), // xori r3, r3, 0x55
+    (0x188, 0x4E800020), // blr
+    // Conditional calls, which set LR even if not taken
+    (0x1c0, 0x7C0802A6), // mflr r0
+    (0x1c4, 0x90010004), // stw r0, 0x4(r1)
+    (0x1c8, 0x2C030000), // cmpwi r3, 0x0
+    (0x1cc, 0x4182FE35), // beql -0x1cc
+    (0x1d0, 0x7C8802A6), // mflr r4
+    (0x1d4, 0x2C030001), // cmpwi r3, 0x1
+    (0x1d8, 0x3D808000), // lis r12, 0x8000
+    (0x1dc, 0x398C0040), // addi r12, r12, 0x40
+    (0x1e0, 0x7D8803A6), // mtlr r12
+    (0x1e4, 0x4D820021), // beqlrl
+    (0x1e8, 0x7CA802A6), // mflr r5
+    (0x1ec, 0x80010004), // lwz r0, 0x4(r1)
+    (0x1f0, 0x7C0803A6), // mtlr r0
+    (0x1f4, 0x4E800020), // blr
+];
+
+fn ram() -> Ram<Vec<u8>> {
+    let mut ram = Ram { base: BASE as u64, data: vec![0u8; 0x1000] };
+    for &(offset, code) in CODE {
+        ram.write_u32((BASE + offset) as u64, code).unwrap();
+    }
+    for (i, offset) in [0x60, 0x68, 0x70, 0x78].into_iter().enumerate() {
+        ram.write_u32((TABLE + 4 * i as u32) as u64, BASE + offset).unwrap();
+    }
+    ram
+}
+
+fn analyze() -> Program {
+    let mut recompiler = Recompiler::new(Extensions::gekko_broadway());
+    for address in [SUM_TO, SWITCH, MAIN, SCRAMBLE, CONDITIONAL] {
+        recompiler.add_function(address, None);
+    }
+    recompiler.analyze(&mut ram()).unwrap()
+}
+
+#[test]
+fn test_analyze() {
+    let program = analyze();
+    assert_eq!(
+        program.functions.keys().copied().collect::<Vec<_>>(),
+        [SUM_TO, SWITCH, MAIN, SCRAMBLE, CONDITIONAL]
+    );
+
+    let sum_to = &program.functions[&SUM_TO];
+    assert_eq!(
+        sum_to.blocks.keys().copied().collect::<Vec<_>>(),
+        [SUM_TO, SUM_TO + 0x10, SUM_TO + 0x1C]
+    );
+    assert_eq!(sum_to.blocks[&SUM_TO].len(), 4);
+
+    // The jump table is detected, and its targets and the default case are blocks
+    let switch = &program.functions[&SWITCH];
+    assert_eq!(
+        switch.jump_tables[&(SWITCH + 0x1C)],
+        [BASE + 0x60, BASE + 0x68, BASE + 0x70, BASE + 0x78]
+    );
+    assert_eq!(
+        switch.blocks.keys().copied().collect::<Vec<_>>(),
+        [SWITCH, BASE + 0x60, BASE + 0x68, BASE + 0x70, BASE + 0x78, BASE + 0x80]
+    );
+
+    // Calls do not end blocks
+    assert_eq!(program.functions[&MAIN].blocks.len(), 1);
+    assert_eq!(program.functions[&MAIN].blocks[&MAIN].len(), 22);
+
+    // The branch to another function is a tail call
+    assert_eq!(program.functions[&SCRAMBLE].blocks[&SCRAMBLE].len(), 2);
+}
+
+#[test]
+fn test_analyze_errors() {
+    let mut recompiler = Recompiler::new(Extensions::gekko_broadway());
+    recompiler.add_function(BASE + 0xFFC, None);
+    let err = recompiler.analyze(&mut ram()).unwrap_err();
+    assert_eq!(err, RecompError::MemoryFault { addr: (BASE + 0x1000) as u64 });
+    assert_eq!(err.to_string(), "memory fault reading code at 0x80001000");
+
+    // Jump tables can be given explicitly
+    let mut recompiler = Recompiler::new(Extensions::gekko_broadway());
+    recompiler.add_function(SWITCH, Some(0x48));
+    recompiler.add_jump_table(SWITCH + 0x1C, vec![BASE + 0x60, BASE + 0x80]);
+    let program = recompiler.analyze(&mut ram()).unwrap();
+    assert_eq!(program.functions[&SWITCH].blocks.len(), 3);
+}
+
+#[test]
+fn test_rust_source() {
+    let source = analyze().rust().to_string();
+    if std::env::var_os("UPDATE_GENERATED").is_some() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/generated/program.rs");
+        std::fs::write(path, &source).unwrap();
+    }
+    assert_eq!(source, include_str!("generated/program.rs"));
+
+    // Functions that were not recompiled are called through the dispatcher
+    assert!(source.contains(&format!("dispatch(cpu, mem, {UNKNOWN:#x})?;")));
+}
+
+/// Runs the function at `addr` with the interpreter and recompiled, comparing the results.
+fn compare(addr: u32, inputs: &[u64]) {
+    for &input in inputs {
+        let mut expected = Cpu::new(Extensions::gekko_broadway());
+        expected.gpr[1] = STACK;
+        expected.gpr[3] = input;
+        let mut actual = expected.clone();
+        let mut expected_ram = ram();
+        let mut actual_ram = ram();
+
+        expected.call(&mut expected_ram, addr as u64, u64::MAX).unwrap();
+        actual.lr = Cpu::RETURN_ADDRESS;
+        program::dispatch(&mut actual, &mut actual_ram, addr as u64).unwrap();
+
+        assert_eq!(actual.gpr, expected.gpr, "{addr:#x}({input})");
+        assert_eq!(actual.cr, expected.cr, "{addr:#x}({input})");
+        assert_eq!(actual.xer, expected.xer, "{addr:#x}({input})");
+        assert_eq!(actual.lr, expected.lr, "{addr:#x}({input})");
+        assert_eq!(actual.ctr, expected.ctr, "{addr:#x}({input})");
+        assert_eq!(actual_ram.data, expected_ram.data, "{addr:#x}({input})");
+    }
+}
+
+#[test]
+fn test_recompiled() {
+    compare(SUM_TO, &[0, 1, 10, 100, 0xFFFF_FFFF]);
+    compare(SWITCH, &[0, 1, 2, 3, 4, 0xFFFF_FFFF]);
+    compare(SCRAMBLE, &[0, 5, 0xFFFF_FFFE]);
+    compare(MAIN, &[0, 1, 2, 3, 7, 42, 0x7FFF_FFFF, 0xFFFF_FFFF]);
+    compare(CONDITIONAL, &[0, 1, 2]);
+}
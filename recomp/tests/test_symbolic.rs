@@ -0,0 +1,333 @@
+use powerpc::Extensions;
+use powerpc_interp::{Cpu, Exception, Memory, Ram};
+use powerpc_recomp::ir::{Reg, Type};
+use powerpc_recomp::symbolic::{execute, ExitKind, State, Sym};
+use powerpc_recomp::RecompError;
+
+mod common;
+
+use common::{code, get, ram, ADDR, BASE, VALUES};
+
+#[rustfmt::skip]
+const SEQUENCES: &[&[u32]] = &[
+    &[
+        0x5483C43E, // extrwi r3, r4, 16, 8
+    ],
+    &[
+        0x5483402E, // slwi r3, r4, 8
+        0x5463843E, // srwi r3, r3, 16
+    ],
+    &[
+        0x5483443E, // rlwinm r3, r4, 8, 16, 31
+    ],
+    &[
+        0x5083442E, // rlwimi r3, r4, 8, 16, 23
+    ],
+    &[
+        0x5480442E, // clrlslwi r0, r4, 24, 8
+        0x3CA0FFFF, // lis r5, 0xffff
+        0x60A500FF, // ori r5, r5, 0xff
+        0x7C632838, // and r3, r3, r5
+        0x7C630378, // or r3, r3, r0
+    ],
+    &[
+        0x5483043E, // clrlwi r3, r4, 16
+    ],
+    &[
+        0x7083FFFF, // andi. r3, r4, 0xffff
+    ],
+    &[
+        0x7C642814, // addc r3, r4, r5
+        0x7CC42914, // adde r6, r4, r5
+    ],
+    &[
+        0x7C642A14, // add r3, r4, r5
+    ],
+    &[
+        0x7C832378, // mr r3, r4
+    ],
+    &[
+        0x38640000, // addi r3, r4, 0x0
+    ],
+    &[
+        0x38830001, // addi r4, r3, 0x1
+        0x2C040000, // cmpwi r4, 0x0
+        0x40820008, // bne 0x8
+        0x38600000, // li r3, 0x0
+    ],
+    &[
+        0x90610008, // stw r3, 0x8(r1)
+        0xA081000A, // lhz r4, 0xa(r1)
+        0x88A10009, // lbz r5, 0x9(r1)
+        0x98A1000C, // stb r5, 0xc(r1)
+        0x80C1000C, // lwz r6, 0xc(r1)
+    ],
+    &[
+        0x90660000, // stw r3, 0x0(r6)
+        0x80A10004, // lwz r5, 0x4(r1)
+    ],
+    &[
+        0x90610008, // stw r3, 0x8(r1)
+        0x9081000C, // stw r4, 0xc(r1)
+    ],
+    &[
+        0x9081000C, // stw r4, 0xc(r1)
+        0x90610008, // stw r3, 0x8(r1)
+    ],
+    &[
+        0x90610008, // stw r3, 0x8(r1)
+        0x90810008, // stw r4, 0x8(r1)
+    ],
+    &[
+        0x90810008, // stw r4, 0x8(r1)
+    ],
+    &[
+        0x7C652670, // srawi r5, r3, 4
+        0x7CA50194, // addze r5, r5
+        0x7CC51850, // subf r6, r5, r3
+        0x7CE600D0, // neg r7, r6
+        0x7D0721D6, // mullw r8, r7, r4
+        0x7D281B96, // divwu r9, r8, r3
+    ],
+    &[
+        0x7C650034, // cntlzw r5, r3
+        0x7C860734, // extsh r6, r4
+        0x7CA73278, // xor r7, r5, r6
+        0x7CE838F8, // nor r8, r7, r7
+        0x7D092078, // andc r9, r8, r4
+        0x5D2A183E, // rotlw r10, r9, r3
+    ],
+    &[
+        0x3D808000, // lis r12, 0x8000
+        0x398C0040, // addi r12, r12, 0x40
+        0x7D8903A6, // mtctr r12
+        0x4E800420, // bctr
+    ],
+    &[
+        0x28030003, // cmplwi r3, 0x3
+        0x41810020, // bgt 0x20
+        0x3C808000, // lis r4, 0x8000
+        0x5460103A, // slwi r0, r3, 2
+        0x7C04002E, // lwzx r0, r4, r0
+        0x7C0903A6, // mtctr r0
+        0x4E800420, // bctr
+    ],
+    &[
+        0xD8210010, // stfd f1, 0x10(r1)
+        0x80610010, // lwz r3, 0x10(r1)
+        0x80810014, // lwz r4, 0x14(r1)
+        0xC8410010, // lfd f2, 0x10(r1)
+    ],
+    &[
+        0x0C83FFFF, // twi 4, r3, -0x1
+        0x7C0802A6, // mflr r0
+        0x48000101, // bl 0x100
+    ],
+    &[
+        0x38600001, // li r3, 0x1
+        0x41820010, // beq 0x10
+        0x38600002, // li r3, 0x2
+    ],
+    &[
+        0x41820014, // beq 0x14
+        0x38600002, // li r3, 0x2
+    ],
+    &[
+        0x90660000, // stw r3, 0x0(r6)
+        0x41820010, // beq 0x10
+    ],
+    &[
+        0x41820014, // beq 0x14
+        0x90660000, // stw r3, 0x0(r6)
+    ],
+];
+
+fn run(code: &[u32]) -> State {
+    execute(ADDR, &common::code(code)).unwrap()
+}
+
+/// Compares the registers and memory after executing a sequence with the interpreter,
+/// with the symbolic values evaluated on the initial state.
+fn compare_state(state: &State, cpu: &Cpu, expected: &Cpu, expected_ram: &Ram<Vec<u8>>) {
+    let message = format!("{state:#?}\n{cpu:x?}");
+    let mut initial_ram = ram();
+    for (&reg, sym) in &state.regs {
+        let value = sym.eval(cpu, &mut initial_ram).unwrap();
+        assert_eq!(value, get(expected, reg), "{reg} = {sym}\n{message}");
+    }
+    for reg in 0..32 {
+        if !state.regs.contains_key(&Reg::Gpr(reg)) {
+            assert_eq!(expected.gpr[reg as usize], cpu.gpr[reg as usize], "{message}");
+        }
+    }
+    let mut actual_ram = ram();
+    let stores = state.stores();
+    let stores = stores.iter().map(|(ty, addr, value)| {
+        (*ty, addr.eval(cpu, &mut initial_ram).unwrap(), value.eval(cpu, &mut initial_ram).unwrap())
+    });
+    for (ty, addr, value) in stores.collect::<Vec<_>>() {
+        match ty {
+            Type::I8 => actual_ram.write_u8(addr, value as u8),
+            Type::I16 => actual_ram.write_u16(addr, value as u16),
+            Type::I32 => actual_ram.write_u32(addr, value as u32),
+            _ => actual_ram.write_u64(addr, value),
+        }
+        .unwrap();
+    }
+    assert_eq!(actual_ram.data, expected_ram.data, "{message}");
+}
+
+/// Executes a sequence with the interpreter and symbolically, comparing the results.
+fn compare(code: &[u32], cpu: &Cpu) {
+    let state = run(code);
+    let (mut expected, mut expected_ram) = (cpu.clone(), ram());
+    expected.pc = ADDR as u64;
+    for (addr, ins) in (ADDR..).step_by(4).zip(common::code(code)) {
+        let result = expected.execute(ins, &mut expected_ram);
+        let taken = state
+            .exits
+            .iter()
+            .find(|exit| exit.addr == addr && exit.cond.eval(cpu, &mut ram()).unwrap() != 0);
+        let Some(exit) = taken else {
+            result.unwrap();
+            assert_eq!(expected.pc, addr as u64 + 4, "{}", ins.simplified());
+            continue;
+        };
+        let target = match &exit.kind {
+            ExitKind::Jump(target) | ExitKind::Call(target) => target,
+            ExitKind::Return => &state.reg(Reg::Lr),
+            ExitKind::Trap => {
+                assert!(matches!(result, Err(Exception::Trap { .. })), "{}", ins.simplified());
+                return;
+            }
+        };
+        result.unwrap();
+        assert_eq!(expected.pc, target.eval(cpu, &mut ram()).unwrap(), "{}", ins.simplified());
+        let state = State { regs: exit.regs.clone(), mem: exit.mem.clone(), ..State::default() };
+        compare_state(&state, cpu, &expected, &expected_ram);
+        return;
+    }
+    compare_state(&state, cpu, &expected, &expected_ram);
+}
+
+#[test]
+fn test_symbolic_execution() {
+    for &code in SEQUENCES {
+        for (i, &a) in VALUES.iter().enumerate() {
+            for &b in &VALUES {
+                let mut cpu = Cpu::new(Extensions::gekko_broadway());
+                cpu.gpr[1] = BASE + 0x100;
+                cpu.gpr[3] = a;
+                cpu.gpr[4] = b;
+                cpu.gpr[5] = a ^ b.rotate_left(7) & 0xFFFF_FFFF;
+                cpu.gpr[6] = BASE + 0x80;
+                cpu.fpr[1] = -1.5;
+                cpu.cr = 0x9A3C_5E71 ^ (b as u32);
+                cpu.xer = [0, Cpu::XER_CA, Cpu::XER_SO | Cpu::XER_OV][i % 3];
+                cpu.lr = 0x8000_0400;
+                compare(code, &cpu);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_equivalent() {
+    let r3 = [Reg::Gpr(3)];
+    let extract = run(SEQUENCES[0]);
+    assert_eq!(extract.reg(Reg::Gpr(3)).to_string(), "and(rotl(r4, 0x18), 0xffff)");
+    assert!(extract.equivalent(&run(SEQUENCES[1]), &r3));
+    assert!(!extract.equivalent(&run(SEQUENCES[2]), &r3));
+
+    // A bit field insertion, and a patch with a scratch register
+    let insert = run(SEQUENCES[3]);
+    assert!(insert.equivalent(&run(SEQUENCES[4]), &r3));
+    assert!(!insert.equivalent(&run(SEQUENCES[4]), &[Reg::Gpr(0), Reg::Gpr(3)]));
+
+    // Flags are only compared if asked for
+    let (clear, andi) = (run(SEQUENCES[5]), run(SEQUENCES[6]));
+    assert!(clear.equivalent(&andi, &r3));
+    assert!(!clear.equivalent(&andi, &[Reg::Gpr(3), Reg::Cr(2)]));
+    let (addc, add) = (run(SEQUENCES[7]), run(SEQUENCES[8]));
+    assert!(addc.equivalent(&add, &r3));
+    assert!(!addc.equivalent(&add, &[Reg::Ca]));
+    assert!(run(SEQUENCES[9]).equivalent(&run(SEQUENCES[10]), &r3));
+
+    // Stores to different addresses in any order, and overwritten stores
+    assert!(run(SEQUENCES[14]).equivalent(&run(SEQUENCES[15]), &[]));
+    assert!(run(SEQUENCES[16]).equivalent(&run(SEQUENCES[17]), &[]));
+    assert!(!run(SEQUENCES[14]).equivalent(&run(SEQUENCES[17]), &[]));
+
+    // The registers and stores are also compared where branches leave the sequences
+    let (early, late) = (run(SEQUENCES[24]), run(SEQUENCES[25]));
+    assert_eq!(early.reg(Reg::Gpr(3)), late.reg(Reg::Gpr(3)));
+    assert!(early.equivalent(&late, &[]));
+    assert!(!early.equivalent(&late, &r3));
+    let (early, late) = (run(SEQUENCES[26]), run(SEQUENCES[27]));
+    assert_eq!(early.stores(), late.stores());
+    assert!(!early.equivalent(&late, &[]));
+}
+
+#[test]
+fn test_memory() {
+    // Loads within a store are forwarded, and other loads read the memory before it
+    let state = run(SEQUENCES[12]);
+    assert_eq!(state.reg(Reg::Gpr(4)).to_string(), "and(r3, 0xffff)");
+    assert_eq!(state.reg(Reg::Gpr(5)).to_string(), "and(rotl(r3, 0x10), 0xff)");
+    assert!(state.reg(Reg::Gpr(6)).to_string().starts_with("load.i32(add(r1, 0xc))"));
+
+    // Addresses with different bases may overlap
+    let state = run(SEQUENCES[13]);
+    assert_eq!(state.stores().len(), 1);
+    let Sym::Load(_, mem, _) = state.reg(Reg::Gpr(5)) else {
+        panic!("{state:?}");
+    };
+    assert_eq!(*mem, *state.mem);
+
+    // Doubles are stored as their bits
+    let state = run(SEQUENCES[22]);
+    assert_eq!(state.reg(Reg::Gpr(3)).to_string(), "trunc.i32(rotl(bitcast.i64(f1), 0x20))");
+    assert_eq!(state.reg(Reg::Fpr(2)), Sym::Reg(Reg::Fpr(1)));
+}
+
+#[test]
+fn test_exits() {
+    // Branch conditions in terms of the inputs
+    let state = run(SEQUENCES[11]);
+    assert_eq!(state.exits.len(), 1);
+    assert_eq!(state.exits[0].addr, ADDR + 8);
+    assert_eq!(state.exits[0].cond.to_string(), "ne(r3, 0xffffffff)");
+    assert_eq!(state.exits[0].kind, ExitKind::Jump(Sym::constant(Type::I32, ADDR as u64 + 0x10)));
+    assert_eq!(state.next, Some(ADDR + 0x10));
+
+    // Indirect branches to constant addresses are resolved
+    let state = run(SEQUENCES[20]);
+    assert_eq!(state.exits[0].kind, ExitKind::Jump(Sym::constant(Type::I32, 0x80000040)));
+    assert_eq!(state.next, None);
+
+    // Jump tables are loads indexed by the inputs
+    let state = run(SEQUENCES[21]);
+    assert_eq!(state.exits[0].cond.to_string(), "ugt(r3, 0x3)");
+    let ExitKind::Jump(target) = &state.exits[1].kind else {
+        panic!("{state:?}");
+    };
+    assert_eq!(
+        target.to_string(),
+        "and(load.i32(add(and(rotl(r3, 0x2), 0xfffffffc), 0x80000000)), 0xfffffffc)"
+    );
+
+    // Traps and calls
+    let state = run(SEQUENCES[23]);
+    assert_eq!(state.exits[0].kind, ExitKind::Trap);
+    assert_eq!(state.exits[0].cond.to_string(), "eq(r3, 0xffffffff)");
+    assert_eq!(state.exits[1].kind, ExitKind::Call(Sym::constant(Type::I32, ADDR as u64 + 0x108)));
+    assert_eq!(state.reg(Reg::Gpr(0)), Sym::Reg(Reg::Lr));
+    assert_eq!(state.reg(Reg::Lr), Sym::constant(Type::I32, ADDR as u64 + 0xC));
+}
+
+#[test]
+fn test_unsupported() {
+    let err = execute(ADDR, &code(&[0x7C632214, 0xFC21102A])).unwrap_err(); // add; fadd
+    assert_eq!(err, RecompError::Unsupported { addr: ADDR + 4 });
+    assert_eq!(err.to_string(), "unsupported instruction at 0x80000104");
+}